@@ -1,7 +1,9 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::decisions::DecisionSpec;
 use crate::llm::schema::ActionInterpretation;
+use crate::maneuver::Maneuver;
 use crate::scenario::ScenarioRuntime;
 use crate::state::{AppState, Direction};
 
@@ -19,6 +21,9 @@ pub enum Action {
     OpenInventory,
     InventorySelect(usize),
     OpenCustomAction,
+    Recruit,
+    ToggleSneak,
+    DismissHireling(usize),
     CloseOverlay,
     MenuSelect(usize),
     MenuConfirm,
@@ -27,10 +32,29 @@ pub enum Action {
     PauseClose,
     PauseSelect(usize),
     PauseConfirm,
+    TravelSelect(usize),
+    TravelConfirm,
+    TravelClose,
+    JournalSelect(usize),
+    JournalClose,
 
     DialogueInputChanged(String),
     DialogueSubmit,
-    DialogueResponse { npc_id: String, line: String },
+    DialogueResponse {
+        npc_id: String,
+        line: String,
+        decision: Option<DecisionSpec>,
+    },
+    BanterResponse {
+        npc_id: String,
+        line: String,
+    },
+    BanterError(String),
+    HirelingRemark {
+        name: String,
+        line: String,
+    },
+    HirelingRemarkError(String),
 
     CustomActionInputChanged(String),
     CustomActionSubmit,
@@ -38,6 +62,10 @@ pub enum Action {
 
     CombatAttack,
     CombatEndTurn,
+    CombatManeuver(Maneuver),
+    ManeuverMenuOpen,
+    ManeuverMenuClose,
+    ManeuverSelect(usize),
 
     ScrollLog(i16),
 
@@ -55,10 +83,36 @@ pub enum Action {
     LoadComplete(Box<AppState>),
     LoadError(String),
 
-    ScenarioLoaded { scenario: ScenarioRuntime },
-    ScenarioLoadError { error: String },
+    ScenarioLoaded {
+        scenario: ScenarioRuntime,
+    },
+    ScenarioLoadError {
+        error: String,
+    },
 
     LlmError(String),
 
+    ConsoleOpen,
+    ConsoleClose,
+    ConsoleInputChanged(String),
+    ConsoleComplete,
+    ConsoleSubmit,
+    ConsoleRejected(String),
+    ConsoleHelp,
+    ConsoleClear,
+    DebugGiveItem {
+        id: String,
+        qty: u16,
+    },
+    DebugTeleport {
+        x: u16,
+        y: u16,
+    },
+    DebugTeleportToNpc(String),
+    DebugSetFlag {
+        flag: String,
+        set: bool,
+    },
+
     Quit,
 }
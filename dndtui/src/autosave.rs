@@ -0,0 +1,121 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Quiet ticks (200ms each) a requested save waits for, so a burst of
+/// events, say a victory that also sends a hireling home, is one write.
+pub const DEBOUNCE_TICKS: u8 = 5;
+/// Ticks the "Saved" note stays in the status bar, roughly two seconds.
+pub const SAVED_NOTE_TICKS: u8 = 10;
+
+/// Background saves after the moments worth keeping: a fight ending, a
+/// decision or trigger moving the story on, a new map. The pause menu's
+/// Save writes at once and goes through the same bookkeeping.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct AutosaveState {
+    /// Ticks until the requested save is written; `None` when none is waiting.
+    pub due_in: Option<u8>,
+    /// A save is on its way to disk.
+    pub writing: bool,
+    /// Ticks left on the "Saved" note.
+    pub saved_note: u8,
+}
+
+/// What the status bar shows about saving.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Indicator {
+    Saving,
+    Saved,
+}
+
+impl AutosaveState {
+    /// Asks for a save; another ask before it's written starts the wait over.
+    pub fn request(&mut self) {
+        self.due_in = Some(DEBOUNCE_TICKS);
+    }
+
+    /// A save is being written now, which also covers any waiting request.
+    pub fn begin(&mut self) {
+        self.due_in = None;
+        self.writing = true;
+    }
+
+    pub fn finish(&mut self, ok: bool) {
+        self.writing = false;
+        self.saved_note = if ok { SAVED_NOTE_TICKS } else { 0 };
+    }
+
+    /// Advances the clocks by a tick. Returns whether the waiting save should
+    /// be written now; it's held back while `ready` is false or another save
+    /// is still being written.
+    pub fn tick(&mut self, ready: bool) -> bool {
+        self.saved_note = self.saved_note.saturating_sub(1);
+        match self.due_in {
+            Some(0) => ready && !self.writing,
+            Some(ticks) => {
+                self.due_in = Some(ticks - 1);
+                false
+            }
+            None => false,
+        }
+    }
+
+    pub fn indicator(&self) -> Option<Indicator> {
+        if self.writing || self.due_in == Some(0) {
+            Some(Indicator::Saving)
+        } else if self.saved_note > 0 {
+            Some(Indicator::Saved)
+        } else {
+            None
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn requests_in_a_burst_are_written_once_after_the_wait() {
+        let mut autosave = AutosaveState::default();
+        autosave.request();
+        assert!(!autosave.tick(true));
+        autosave.request();
+        let due: Vec<bool> = (0..=DEBOUNCE_TICKS).map(|_| autosave.tick(true)).collect();
+        assert_eq!(due.iter().filter(|due| **due).count(), 1);
+        assert!(due[DEBOUNCE_TICKS as usize]);
+    }
+
+    #[test]
+    fn a_due_save_waits_while_not_ready_or_still_writing() {
+        let mut autosave = AutosaveState {
+            due_in: Some(0),
+            writing: true,
+            ..Default::default()
+        };
+        assert!(!autosave.tick(true));
+        autosave.finish(true);
+        assert!(!autosave.tick(false));
+        assert_eq!(autosave.indicator(), Some(Indicator::Saving));
+        assert!(autosave.tick(true));
+        autosave.begin();
+        assert_eq!(autosave.due_in, None);
+    }
+
+    #[test]
+    fn saved_note_fades_after_a_few_ticks() {
+        let mut autosave = AutosaveState::default();
+        autosave.begin();
+        assert_eq!(autosave.indicator(), Some(Indicator::Saving));
+        autosave.finish(true);
+        assert_eq!(autosave.indicator(), Some(Indicator::Saved));
+        for _ in 0..SAVED_NOTE_TICKS {
+            autosave.tick(true);
+        }
+        assert_eq!(autosave.indicator(), None);
+
+        autosave.begin();
+        autosave.finish(false);
+        assert_eq!(autosave.indicator(), None);
+    }
+}
@@ -0,0 +1,175 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::rules::next_u32;
+use crate::state::{AppState, GameMode, NpcState};
+
+/// Ticks (200ms each) between banter lines, so roughly 20 seconds.
+pub const BANTER_COOLDOWN_TICKS: u32 = 100;
+/// Once the cooldown has elapsed, each tick has a 1 in N chance to start banter.
+const BANTER_CHANCE: u32 = 25;
+/// Manhattan distance within which an NPC can be overheard.
+const BANTER_RADIUS: u16 = 6;
+/// Hard cap on a banter line; longer replies are cut at a word boundary.
+pub const BANTER_MAX_CHARS: usize = 90;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct BanterState {
+    pub enabled: bool,
+    /// Ticks left before another line may be requested.
+    pub cooldown: u32,
+    /// NPC whose line is in flight; only one banter request runs at a time.
+    pub pending: Option<String>,
+    pub last_speaker: Option<String>,
+}
+
+impl Default for BanterState {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            cooldown: BANTER_COOLDOWN_TICKS,
+            pending: None,
+            last_speaker: None,
+        }
+    }
+}
+
+/// Advance the banter clock by one tick and return the NPC who should speak, if any.
+///
+/// Banter only happens while freely exploring with no other LLM request in flight,
+/// and only from NPCs close enough to be overheard.
+pub fn due_speaker(state: &mut AppState) -> Option<NpcState> {
+    let banter = &mut state.banter;
+    banter.cooldown = banter.cooldown.saturating_sub(1);
+    if !banter.enabled || banter.pending.is_some() || banter.cooldown > 0 {
+        return None;
+    }
+    if state.mode != GameMode::Exploration
+        || state.pending_llm.is_some()
+        || state.pause_menu.is_open
+    {
+        return None;
+    }
+    if !next_u32(&mut state.rng_seed).is_multiple_of(BANTER_CHANCE) {
+        return None;
+    }
+    nearby_speaker(state).cloned()
+}
+
+/// Closest NPC in earshot, preferring someone other than the last speaker.
+fn nearby_speaker(state: &AppState) -> Option<&NpcState> {
+    let (x, y) = state.player_pos();
+    let mut nearby: Vec<&NpcState> = state
+        .npcs
+        .iter()
+        .filter(|npc| npc.x.abs_diff(x) + npc.y.abs_diff(y) <= BANTER_RADIUS)
+        .collect();
+    nearby.sort_by_key(|npc| {
+        let repeat = state.banter.last_speaker.as_deref() == Some(npc.id.as_str());
+        (repeat, npc.x.abs_diff(x) + npc.y.abs_diff(y))
+    });
+    nearby.first().copied()
+}
+
+/// Reduce an LLM reply to a single short line, or `None` if nothing usable is left.
+pub fn clamp_line(raw: &str) -> Option<String> {
+    let line = raw.lines().find(|line| !line.trim().is_empty())?;
+    let line = line.split_whitespace().collect::<Vec<_>>().join(" ");
+    let line = line.trim_matches(|c: char| c == '"' || c == '\'' || c.is_whitespace());
+    if line.is_empty() {
+        return None;
+    }
+    if line.chars().count() <= BANTER_MAX_CHARS {
+        return Some(line.to_string());
+    }
+    let cut: String = line.chars().take(BANTER_MAX_CHARS - 1).collect();
+    let cut = match cut.rfind(' ') {
+        Some(pos) if pos > BANTER_MAX_CHARS / 2 => &cut[..pos],
+        _ => cut.as_str(),
+    };
+    Some(format!("{}…", cut.trim_end_matches([',', ';', ':', ' '])))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn npc(id: &str, x: u16, y: u16) -> NpcState {
+        NpcState {
+            id: id.to_string(),
+            name: id.to_string(),
+            x,
+            y,
+            persona: String::new(),
+            dialogue_prompt: String::new(),
+            hire_cost: None,
+        }
+    }
+
+    fn exploring() -> AppState {
+        let mut state = AppState {
+            mode: GameMode::Exploration,
+            rng_seed: 3,
+            ..Default::default()
+        };
+        state.banter.cooldown = 0;
+        state
+    }
+
+    fn first_speaker(state: &mut AppState) -> Option<String> {
+        (0..BANTER_CHANCE * 20).find_map(|_| due_speaker(state).map(|npc| npc.id))
+    }
+
+    #[test]
+    fn picks_nearest_npc_in_earshot() {
+        let mut state = exploring();
+        state.npcs = vec![npc("far", 30, 30), npc("near", 2, 1), npc("nearer", 1, 0)];
+        assert_eq!(first_speaker(&mut state).as_deref(), Some("nearer"));
+    }
+
+    #[test]
+    fn rotates_away_from_last_speaker() {
+        let mut state = exploring();
+        state.npcs = vec![npc("a", 1, 0), npc("b", 3, 0)];
+        state.banter.last_speaker = Some("a".to_string());
+        assert_eq!(first_speaker(&mut state).as_deref(), Some("b"));
+    }
+
+    #[test]
+    fn silent_without_listeners_or_outside_exploration() {
+        let mut state = exploring();
+        state.npcs = vec![npc("far", 30, 30)];
+        assert_eq!(first_speaker(&mut state), None);
+
+        state.npcs = vec![npc("near", 1, 0)];
+        state.mode = GameMode::Combat;
+        assert_eq!(first_speaker(&mut state), None);
+    }
+
+    #[test]
+    fn cooldown_and_pending_block_new_lines() {
+        let mut state = exploring();
+        state.npcs = vec![npc("near", 1, 0)];
+        state.banter.pending = Some("near".to_string());
+        assert_eq!(first_speaker(&mut state), None);
+
+        state.banter.pending = None;
+        state.banter.cooldown = u32::MAX;
+        assert_eq!(first_speaker(&mut state), None);
+    }
+
+    #[test]
+    fn clamp_line_keeps_one_short_line() {
+        assert_eq!(
+            clamp_line("  \"Mind the  bridge.\"\nSecond line"),
+            Some("Mind the bridge.".to_string())
+        );
+        assert_eq!(clamp_line(" \n \"\" "), None);
+
+        let long = "word ".repeat(40);
+        let clamped = clamp_line(&long).unwrap();
+        assert!(clamped.chars().count() <= BANTER_MAX_CHARS);
+        assert!(clamped.ends_with("word…"));
+    }
+}
@@ -0,0 +1,302 @@
+//! Developer console, opened with the backtick key when the game runs with
+//! `--dev`.
+//!
+//! A line typed into it is parsed into one of the `Debug*` actions, so
+//! everything it does goes through the reducer and shows up in recordings
+//! like any other input. Tab completes command names and the item, NPC and
+//! flag ids of the loaded scenario.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::action::Action;
+use crate::state::{trigger_key, AppState, Trigger};
+
+/// Output lines kept before the oldest scroll away.
+pub const SCROLLBACK: usize = 50;
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct ConsoleState {
+    /// Set by `--dev`; without it the console never opens.
+    pub enabled: bool,
+    pub open: bool,
+    pub input: String,
+    /// Echoed commands and their replies, oldest first.
+    pub output: Vec<String>,
+}
+
+impl ConsoleState {
+    pub fn reply(&mut self, line: impl Into<String>) {
+        self.output.push(line.into());
+        if self.output.len() > SCROLLBACK {
+            let excess = self.output.len() - SCROLLBACK;
+            self.output.drain(..excess);
+        }
+    }
+}
+
+/// What the first argument of a command names, for completion.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IdKind {
+    Item,
+    Npc,
+    Flag,
+}
+
+struct Command {
+    name: &'static str,
+    usage: &'static str,
+    completes: Option<IdKind>,
+}
+
+const COMMANDS: [Command; 6] = [
+    Command {
+        name: "give",
+        usage: "give <item> [qty]",
+        completes: Some(IdKind::Item),
+    },
+    Command {
+        name: "tp",
+        usage: "tp <x> <y> | tp <npc>",
+        completes: Some(IdKind::Npc),
+    },
+    Command {
+        name: "flag",
+        usage: "flag <id>",
+        completes: Some(IdKind::Flag),
+    },
+    Command {
+        name: "unflag",
+        usage: "unflag <id>",
+        completes: Some(IdKind::Flag),
+    },
+    Command {
+        name: "help",
+        usage: "help",
+        completes: None,
+    },
+    Command {
+        name: "clear",
+        usage: "clear",
+        completes: None,
+    },
+];
+
+/// One usage line per command, for `help`.
+pub fn usage_lines() -> Vec<String> {
+    COMMANDS
+        .iter()
+        .map(|command| format!("  {}", command.usage))
+        .collect()
+}
+
+/// The action a console line stands for, or what's wrong with it.
+pub fn parse(input: &str) -> Result<Action, String> {
+    let words: Vec<&str> = input.split_whitespace().collect();
+    let Some((&name, args)) = words.split_first() else {
+        return Err("Type a command, or `help` for a list.".to_string());
+    };
+    let action = match (name, args) {
+        ("give", [id]) => Action::DebugGiveItem {
+            id: id.to_string(),
+            qty: 1,
+        },
+        ("give", [id, qty]) => match qty.parse::<u16>() {
+            Ok(qty) if qty > 0 => Action::DebugGiveItem {
+                id: id.to_string(),
+                qty,
+            },
+            _ => return Err(format!("`{qty}` is not a quantity.")),
+        },
+        ("tp", [x, y]) => match (x.parse(), y.parse()) {
+            (Ok(x), Ok(y)) => Action::DebugTeleport { x, y },
+            _ => return Err(format!("`{x} {y}` is not a map position.")),
+        },
+        ("tp", [npc]) => Action::DebugTeleportToNpc(npc.to_string()),
+        ("flag", [flag]) => Action::DebugSetFlag {
+            flag: flag.to_string(),
+            set: true,
+        },
+        ("unflag", [flag]) => Action::DebugSetFlag {
+            flag: flag.to_string(),
+            set: false,
+        },
+        ("help", []) => Action::ConsoleHelp,
+        ("clear", []) => Action::ConsoleClear,
+        _ => {
+            return Err(match COMMANDS.iter().find(|command| command.name == name) {
+                Some(command) => format!("Usage: {}", command.usage),
+                None => format!("Unknown command `{name}`. Try `help`."),
+            })
+        }
+    };
+    Ok(action)
+}
+
+/// Completes the last word of `input`: a command name first, then the id
+/// the command takes. Returns the new input and every candidate that
+/// matched; the input only grows when the candidates agree on more letters.
+pub fn complete(input: &str, ids: impl Fn(IdKind) -> Vec<String>) -> (String, Vec<String>) {
+    let head_len = input
+        .rfind(char::is_whitespace)
+        .map_or(0, |index| index + 1);
+    let (head, word) = input.split_at(head_len);
+    let previous: Vec<&str> = head.split_whitespace().collect();
+    let candidates = match previous.as_slice() {
+        [] => COMMANDS
+            .iter()
+            .map(|command| command.name.to_string())
+            .collect(),
+        [name] => COMMANDS
+            .iter()
+            .find(|command| command.name == *name)
+            .and_then(|command| command.completes)
+            .map(ids)
+            .unwrap_or_default(),
+        _ => Vec::new(),
+    };
+    let matches: Vec<String> = candidates
+        .into_iter()
+        .filter(|candidate| candidate.starts_with(word))
+        .collect();
+
+    let completed = match matches.as_slice() {
+        [] => input.to_string(),
+        [only] => format!("{head}{only} "),
+        [first, rest @ ..] => {
+            let common = rest.iter().fold(first.as_str(), |common, other| {
+                let len = common
+                    .char_indices()
+                    .zip(other.chars())
+                    .take_while(|((_, a), b)| a == b)
+                    .last()
+                    .map_or(0, |((index, a), _)| index + a.len_utf8());
+                &common[..len]
+            });
+            format!("{head}{common}")
+        }
+    };
+    (completed, matches)
+}
+
+/// Ids of `kind` anywhere in the loaded scenario, sorted.
+pub fn scenario_ids(state: &AppState, kind: IdKind) -> Vec<String> {
+    let floors = std::iter::once((state.floors.current.as_str(), None)).chain(
+        state
+            .floors
+            .stashed
+            .iter()
+            .map(|(id, floor)| (id.as_str(), Some(floor))),
+    );
+    let mut ids = Vec::new();
+    for (floor_id, stashed) in floors {
+        match kind {
+            IdKind::Item => {
+                let items = stashed.map_or(&state.items, |floor| &floor.items);
+                ids.extend(items.iter().map(|item| item.id.clone()));
+            }
+            IdKind::Npc => {
+                let npcs = stashed.map_or(&state.npcs, |floor| &floor.npcs);
+                ids.extend(npcs.iter().map(|npc| npc.id.clone()));
+            }
+            IdKind::Flag => {
+                let triggers = stashed.map_or(&state.triggers, |floor| &floor.triggers);
+                ids.extend(triggers.iter().map(|trigger| {
+                    let (kind, x, y) = match trigger {
+                        Trigger::OnEnter { x, y, .. } => ("enter", *x, *y),
+                        Trigger::OnInteract { x, y, .. } => ("interact", *x, *y),
+                    };
+                    trigger_key(floor_id, kind, x, y)
+                }));
+            }
+        }
+    }
+    match kind {
+        IdKind::Item => ids.extend(state.player.inventory.iter().map(|item| item.id.clone())),
+        IdKind::Npc => {}
+        IdKind::Flag => ids.extend(state.fired_triggers.iter().cloned()),
+    }
+    ids.sort();
+    ids.dedup();
+    ids
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(kind: IdKind) -> Vec<String> {
+        let ids: &[&str] = match kind {
+            IdKind::Item => &["healing_potion", "health_tonic", "rope"],
+            IdKind::Npc => &["innkeeper"],
+            IdKind::Flag => &["enter:3:4"],
+        };
+        ids.iter().map(|id| id.to_string()).collect()
+    }
+
+    #[test]
+    fn lines_parse_into_debug_actions() {
+        assert_eq!(
+            parse("give rope"),
+            Ok(Action::DebugGiveItem {
+                id: "rope".to_string(),
+                qty: 1
+            })
+        );
+        assert_eq!(
+            parse("  give rope 3 "),
+            Ok(Action::DebugGiveItem {
+                id: "rope".to_string(),
+                qty: 3
+            })
+        );
+        assert_eq!(parse("tp 4 9"), Ok(Action::DebugTeleport { x: 4, y: 9 }));
+        assert_eq!(
+            parse("tp innkeeper"),
+            Ok(Action::DebugTeleportToNpc("innkeeper".to_string()))
+        );
+        assert_eq!(
+            parse("unflag enter:3:4"),
+            Ok(Action::DebugSetFlag {
+                flag: "enter:3:4".to_string(),
+                set: false
+            })
+        );
+        assert_eq!(parse("help"), Ok(Action::ConsoleHelp));
+    }
+
+    #[test]
+    fn bad_lines_explain_themselves() {
+        assert_eq!(
+            parse("give rope 0"),
+            Err("`0` is not a quantity.".to_string())
+        );
+        assert_eq!(
+            parse("tp 4 north"),
+            Err("`4 north` is not a map position.".to_string())
+        );
+        assert_eq!(parse("flag"), Err("Usage: flag <id>".to_string()));
+        assert_eq!(
+            parse("fly 3"),
+            Err("Unknown command `fly`. Try `help`.".to_string())
+        );
+        assert!(parse("   ").is_err());
+    }
+
+    #[test]
+    fn tab_completes_commands_then_their_ids() {
+        assert_eq!(
+            complete("gi", ids),
+            ("give ".to_string(), vec!["give".to_string()])
+        );
+        assert_eq!(complete("give h", ids).0, "give heal");
+        assert_eq!(complete("give h", ids).1.len(), 2);
+        assert_eq!(complete("give healt", ids).0, "give health_tonic ");
+        assert_eq!(complete("tp inn", ids).0, "tp innkeeper ");
+        assert_eq!(complete("flag ", ids).0, "flag enter:3:4 ");
+        // Only the first argument is an id, and `help` takes none.
+        assert_eq!(complete("give rope 2", ids).0, "give rope 2");
+        assert_eq!(complete("help x", ids).1, Vec::<String>::new());
+    }
+}
@@ -0,0 +1,164 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::state::AppState;
+use crate::travel::clock_label;
+
+/// Decisions the prompts are reminded of, newest last.
+pub const PROMPT_DECISIONS: usize = 5;
+
+/// A decision as a scenario trigger or the LLM states it: what was decided,
+/// and the world-state flags it leaves behind.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct DecisionSpec {
+    /// Short past-tense summary, e.g. "Spared the bandit chief".
+    pub title: String,
+    /// Lasting consequences in snake_case, e.g. "bandits_owe_a_favor".
+    #[serde(default)]
+    pub flags: Vec<String>,
+}
+
+/// One entry on the decision timeline.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Decision {
+    pub title: String,
+    pub flags: Vec<String>,
+    /// In-game time it was made, on the travel clock.
+    pub clock: u32,
+    /// NPC the decision was made with; `None` for scripted events.
+    #[serde(default)]
+    pub npc: Option<String>,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct DecisionLog {
+    /// Oldest first.
+    pub entries: Vec<Decision>,
+    /// Selected row while the journal is open.
+    pub menu: Option<usize>,
+}
+
+/// Put a decision on the timeline. Returns the log line, or `None` when the
+/// title is blank or already recorded, so a model repeating itself doesn't
+/// fill the journal.
+pub fn record(state: &mut AppState, spec: DecisionSpec, npc: Option<String>) -> Option<String> {
+    let title = spec.title.trim().to_string();
+    if title.is_empty()
+        || state
+            .decisions
+            .entries
+            .iter()
+            .any(|entry| entry.title.eq_ignore_ascii_case(&title))
+    {
+        return None;
+    }
+    let mut flags: Vec<String> = Vec::new();
+    for flag in spec.flags {
+        let flag = flag.trim().to_ascii_lowercase().replace(' ', "_");
+        if !flag.is_empty() && !flags.contains(&flag) {
+            flags.push(flag);
+        }
+    }
+    state.decisions.entries.push(Decision {
+        title: title.clone(),
+        flags,
+        clock: state.travel.clock,
+        npc,
+    });
+    Some(format!("Your journal records a decision: {title}."))
+}
+
+/// Every flag the decisions so far have left set, in the order they were set.
+pub fn flags(state: &AppState) -> Vec<&str> {
+    let mut flags: Vec<&str> = Vec::new();
+    for flag in state
+        .decisions
+        .entries
+        .iter()
+        .flat_map(|entry| &entry.flags)
+    {
+        if !flags.contains(&flag.as_str()) {
+            flags.push(flag);
+        }
+    }
+    flags
+}
+
+/// The latest decisions for a prompt, e.g.
+/// "Day 1, 09:12: Spared the bandit chief [bandits_owe_a_favor]".
+pub fn prompt_summary(state: &AppState) -> String {
+    let entries = &state.decisions.entries;
+    if entries.is_empty() {
+        return "(none)".to_string();
+    }
+    entries[entries.len().saturating_sub(PROMPT_DECISIONS)..]
+        .iter()
+        .map(|entry| {
+            let mut line = format!("{}: {}", clock_label(entry.clock), entry.title);
+            if !entry.flags.is_empty() {
+                line.push_str(&format!(" [{}]", entry.flags.join(", ")));
+            }
+            line
+        })
+        .collect::<Vec<_>>()
+        .join(" | ")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn spec(title: &str, flags: &[&str]) -> DecisionSpec {
+        DecisionSpec {
+            title: title.to_string(),
+            flags: flags.iter().map(|flag| flag.to_string()).collect(),
+        }
+    }
+
+    #[test]
+    fn recording_stamps_the_clock_and_tidies_flags() {
+        let mut state = AppState::default();
+        state.travel.clock = 7;
+        let line = record(
+            &mut state,
+            spec(
+                " Sided with the miller ",
+                &["Miller Trusts", "miller_trusts", ""],
+            ),
+            Some("Mara".to_string()),
+        );
+        assert_eq!(
+            line.as_deref(),
+            Some("Your journal records a decision: Sided with the miller.")
+        );
+        let entry = &state.decisions.entries[0];
+        assert_eq!(entry.clock, 7);
+        assert_eq!(entry.flags, vec!["miller_trusts".to_string()]);
+        assert_eq!(entry.npc.as_deref(), Some("Mara"));
+        assert_eq!(flags(&state), vec!["miller_trusts"]);
+    }
+
+    #[test]
+    fn blank_and_repeated_titles_are_ignored() {
+        let mut state = AppState::default();
+        assert!(record(&mut state, spec("Burned the bridge", &[]), None).is_some());
+        assert!(record(&mut state, spec("burned the bridge", &["again"]), None).is_none());
+        assert!(record(&mut state, spec("   ", &["ghost"]), None).is_none());
+        assert_eq!(state.decisions.entries.len(), 1);
+        assert!(flags(&state).is_empty());
+    }
+
+    #[test]
+    fn prompt_summary_keeps_only_the_latest() {
+        let mut state = AppState::default();
+        assert_eq!(prompt_summary(&state), "(none)");
+        for n in 0..PROMPT_DECISIONS + 2 {
+            record(&mut state, spec(&format!("Choice {n}"), &["flag"]), None);
+        }
+        let summary = prompt_summary(&state);
+        assert!(!summary.contains("Choice 1 ["));
+        assert!(summary.starts_with("Day 1, 08:00: Choice 2 [flag]"));
+        assert!(summary.ends_with(&format!("Choice {} [flag]", PROMPT_DECISIONS + 1)));
+    }
+}
@@ -5,6 +5,8 @@ use crate::state::AppState;
 pub enum Effect {
     CallLlmDialogue { npc_id: String, request: LlmRequest },
     CallLlmInterpretAction { request: LlmRequest },
+    CallLlmBanter { npc_id: String, request: LlmRequest },
+    CallLlmHireling { name: String, request: LlmRequest },
     SaveGame { state: Box<AppState>, since: usize },
     LoadGame { path: String },
     LoadScenario { path: String },
@@ -0,0 +1,331 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::rules::next_u32;
+use crate::state::{AppState, NpcState};
+
+/// Gold a new character starts with.
+pub const STARTING_GOLD: u32 = 30;
+/// Most companions the player can have at once.
+pub const MAX_HIRELINGS: usize = 2;
+/// Steps walked in exploration that make up one day of upkeep.
+pub const DAY_STEPS: u32 = 150;
+/// Loyalty a fresh hire starts at, out of 100.
+const STARTING_LOYALTY: i32 = 60;
+/// Below this a hireling quits at the next check.
+const LEAVE_BELOW: i32 = 30;
+/// Below this they don't just quit, they may rob the player on the way out.
+const BETRAY_BELOW: i32 = 15;
+/// Share of the player's purse a betrayer walks off with, in percent.
+const BETRAY_TAKE_PERCENT: u32 = 50;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Hireling {
+    /// The NPC as they stood on the map, kept for prompts and their name.
+    pub npc: NpcState,
+    /// 0-100; events move it, thresholds decide whether they stay.
+    pub loyalty: i32,
+    /// Gold owed at the start of each day.
+    pub wage: u32,
+    pub days_served: u32,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct PartyState {
+    pub hirelings: Vec<Hireling>,
+    /// Exploration steps since the last upkeep was due.
+    pub steps: u32,
+}
+
+/// Things that happen to the party and how companions feel about them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum LoyaltyEvent {
+    Paid,
+    Unpaid,
+    Victory,
+    PlayerDowned,
+}
+
+impl LoyaltyEvent {
+    fn delta(self) -> i32 {
+        match self {
+            LoyaltyEvent::Paid => 3,
+            LoyaltyEvent::Unpaid => -20,
+            LoyaltyEvent::Victory => 6,
+            LoyaltyEvent::PlayerDowned => -15,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Departure {
+    Left(NpcState),
+    Betrayed { npc: NpcState, stolen: u32 },
+}
+
+impl Departure {
+    pub fn npc(&self) -> &NpcState {
+        match self {
+            Departure::Left(npc) => npc,
+            Departure::Betrayed { npc, .. } => npc,
+        }
+    }
+
+    /// Plain log line; the LLM only adds color on top of it.
+    pub fn log_line(&self) -> String {
+        match self {
+            Departure::Left(npc) => format!("{} has had enough and leaves your service.", npc.name),
+            Departure::Betrayed { npc, stolen: 0 } => {
+                format!("{} turns on you and slips away in the night.", npc.name)
+            }
+            Departure::Betrayed { npc, stolen } => format!(
+                "{} turns on you and slips away with {stolen} of your gold.",
+                npc.name
+            ),
+        }
+    }
+
+    /// Short description of what happened, for the narration prompt.
+    pub fn summary(&self) -> &'static str {
+        match self {
+            Departure::Left(_) => "is quitting the party over poor treatment",
+            Departure::Betrayed { .. } => "is betraying the party and stealing from it",
+        }
+    }
+}
+
+/// Daily wage for a hire; roughly a fifth of the signing fee.
+pub fn wage_for(hire_cost: u32) -> u32 {
+    (hire_cost / 5).max(1)
+}
+
+/// Word for a loyalty value, used in the sidebar instead of a raw number.
+pub fn loyalty_label(loyalty: i32) -> &'static str {
+    match loyalty {
+        80.. => "devoted",
+        55..=79 => "steady",
+        LEAVE_BELOW..=54 => "wavering",
+        _ => "mutinous",
+    }
+}
+
+/// Why `npc` can't be hired right now, or `None` if they can.
+pub fn hire_blocker(state: &AppState, npc: &NpcState) -> Option<String> {
+    let cost = npc.hire_cost?;
+    if state.party.hirelings.len() >= MAX_HIRELINGS {
+        return Some(format!(
+            "You can't afford to lead more than {MAX_HIRELINGS} companions."
+        ));
+    }
+    if state.player.gold < cost {
+        return Some(format!(
+            "{} asks {cost} gold up front; you have {}.",
+            npc.name, state.player.gold
+        ));
+    }
+    None
+}
+
+/// Take `npc` off the map and into the party, paying their fee. The caller
+/// checks `hire_blocker` first.
+pub fn hire(state: &mut AppState, npc_id: &str) -> Option<Hireling> {
+    let index = state.npcs.iter().position(|npc| npc.id == npc_id)?;
+    let cost = state.npcs[index].hire_cost?;
+    state.player.gold = state.player.gold.checked_sub(cost)?;
+    let npc = state.npcs.remove(index);
+    let hireling = Hireling {
+        npc,
+        loyalty: STARTING_LOYALTY,
+        wage: wage_for(cost),
+        days_served: 0,
+    };
+    state.party.hirelings.push(hireling.clone());
+    Some(hireling)
+}
+
+/// Let a hireling go on good terms.
+pub fn dismiss(state: &mut AppState, slot: usize) -> Option<Hireling> {
+    (slot < state.party.hirelings.len()).then(|| state.party.hirelings.remove(slot))
+}
+
+/// Count one exploration step; when a day has passed, pay wages and return
+/// whoever walked out over it.
+pub fn step(state: &mut AppState) -> Vec<Departure> {
+    if state.party.hirelings.is_empty() {
+        state.party.steps = 0;
+        return Vec::new();
+    }
+    state.party.steps += 1;
+    if state.party.steps < DAY_STEPS {
+        return Vec::new();
+    }
+    state.party.steps = 0;
+    pay_upkeep(state);
+    settle(state)
+}
+
+/// Every hireling reacts to `event`; returns whoever leaves over it.
+pub fn apply_event(state: &mut AppState, event: LoyaltyEvent) -> Vec<Departure> {
+    for hireling in &mut state.party.hirelings {
+        adjust(hireling, event);
+    }
+    settle(state)
+}
+
+/// Pay each hireling in hiring order until the purse runs dry; the rest go unpaid.
+fn pay_upkeep(state: &mut AppState) {
+    let gold = &mut state.player.gold;
+    for hireling in &mut state.party.hirelings {
+        hireling.days_served += 1;
+        match gold.checked_sub(hireling.wage) {
+            Some(left) => {
+                *gold = left;
+                adjust(hireling, LoyaltyEvent::Paid);
+            }
+            None => adjust(hireling, LoyaltyEvent::Unpaid),
+        }
+    }
+}
+
+fn adjust(hireling: &mut Hireling, event: LoyaltyEvent) {
+    hireling.loyalty = (hireling.loyalty + event.delta()).clamp(0, 100);
+}
+
+/// Drop everyone under the leave threshold. The very disloyal get a coin flip
+/// at robbing the player first.
+fn settle(state: &mut AppState) -> Vec<Departure> {
+    let mut departures = Vec::new();
+    let mut index = 0;
+    while index < state.party.hirelings.len() {
+        let loyalty = state.party.hirelings[index].loyalty;
+        if loyalty >= LEAVE_BELOW {
+            index += 1;
+            continue;
+        }
+        let npc = state.party.hirelings.remove(index).npc;
+        if loyalty < BETRAY_BELOW && next_u32(&mut state.rng_seed).is_multiple_of(2) {
+            let stolen = state.player.gold * BETRAY_TAKE_PERCENT / 100;
+            state.player.gold -= stolen;
+            departures.push(Departure::Betrayed { npc, stolen });
+        } else {
+            departures.push(Departure::Left(npc));
+        }
+    }
+    departures
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn npc(id: &str, hire_cost: Option<u32>) -> NpcState {
+        NpcState {
+            id: id.to_string(),
+            name: id.to_string(),
+            x: 1,
+            y: 0,
+            persona: String::new(),
+            dialogue_prompt: String::new(),
+            hire_cost,
+        }
+    }
+
+    fn with_hire(gold: u32) -> AppState {
+        let mut state = AppState {
+            rng_seed: 5,
+            ..Default::default()
+        };
+        state.player.gold = gold;
+        state.npcs = vec![npc("mara", Some(20))];
+        state
+    }
+
+    #[test]
+    fn hiring_pays_the_fee_and_moves_npc_into_party() {
+        let mut state = with_hire(25);
+        assert_eq!(hire_blocker(&state, &state.npcs[0]), None);
+
+        let hireling = hire(&mut state, "mara").expect("hired");
+        assert_eq!(state.player.gold, 5);
+        assert_eq!(hireling.wage, 4);
+        assert!(state.npcs.is_empty());
+        assert_eq!(state.party.hirelings.len(), 1);
+    }
+
+    #[test]
+    fn cannot_hire_without_gold_or_past_the_cap() {
+        let state = with_hire(10);
+        let blocker = hire_blocker(&state, &state.npcs[0]).expect("too poor");
+        assert!(blocker.contains("asks 20 gold"));
+
+        let mut state = with_hire(100);
+        for id in ["a", "b"] {
+            state.npcs.push(npc(id, Some(1)));
+            hire(&mut state, id);
+        }
+        assert!(hire_blocker(&state, &state.npcs[0]).is_some());
+        assert_eq!(hire_blocker(&state, &npc("plain", None)), None);
+    }
+
+    #[test]
+    fn upkeep_comes_due_once_a_day() {
+        let mut state = with_hire(28);
+        hire(&mut state, "mara");
+        for _ in 0..DAY_STEPS - 1 {
+            assert!(step(&mut state).is_empty());
+        }
+        assert_eq!(state.player.gold, 8);
+
+        step(&mut state);
+        assert_eq!(state.player.gold, 4);
+        assert_eq!(state.party.hirelings[0].days_served, 1);
+        assert_eq!(state.party.hirelings[0].loyalty, STARTING_LOYALTY + 3);
+    }
+
+    #[test]
+    fn unpaid_hirelings_walk_out() {
+        let mut state = with_hire(20);
+        hire(&mut state, "mara");
+        let mut departures = Vec::new();
+        for _ in 0..DAY_STEPS * 3 {
+            departures.extend(step(&mut state));
+        }
+        assert!(state.party.hirelings.is_empty());
+        assert_eq!(departures.len(), 1);
+        assert_eq!(departures[0].npc().id, "mara");
+    }
+
+    #[test]
+    fn victories_build_loyalty_and_defeats_erode_it() {
+        let mut state = with_hire(20);
+        hire(&mut state, "mara");
+        apply_event(&mut state, LoyaltyEvent::Victory);
+        assert_eq!(state.party.hirelings[0].loyalty, STARTING_LOYALTY + 6);
+
+        let mut departures = Vec::new();
+        for _ in 0..4 {
+            departures.extend(apply_event(&mut state, LoyaltyEvent::PlayerDowned));
+        }
+        assert_eq!(departures.len(), 1);
+        assert!(state.party.hirelings.is_empty());
+    }
+
+    #[test]
+    fn betrayers_take_half_the_purse() {
+        let mut state = with_hire(40);
+        hire(&mut state, "mara");
+        state.party.hirelings[0].loyalty = 0;
+        let before = state.player.gold;
+
+        let departures = settle(&mut state);
+        match &departures[..] {
+            [Departure::Betrayed { stolen, .. }] => {
+                assert_eq!(*stolen, before / 2);
+                assert_eq!(state.player.gold, before - stolen);
+            }
+            [Departure::Left(_)] => assert_eq!(state.player.gold, before),
+            other => panic!("unexpected departures: {other:?}"),
+        }
+    }
+}
@@ -2,6 +2,7 @@ mod ollama;
 mod openai;
 pub mod prompt;
 pub mod schema;
+pub mod template;
 
 use async_trait::async_trait;
 use schemars::JsonSchema;
@@ -1,22 +1,22 @@
+use std::collections::HashMap;
+
+use crate::banter::BANTER_MAX_CHARS;
+use crate::decisions;
 use crate::llm::schema::{action_schema_string, dialogue_schema_string};
+use crate::llm::template::{interpolate, PromptTemplates};
 use crate::llm::{ChatMessage, LlmRequest};
 use crate::state::{AppState, DialogueLine, NpcState};
 
 const HISTORY_LIMIT: usize = 6;
 
 pub fn build_dialogue_request(state: &AppState, npc: &NpcState, player_text: &str) -> LlmRequest {
-    let system = format!(
-        "You are an NPC in a rules-driven fantasy game.\n\
-Respond ONLY with a single JSON object matching this schema:\n{}\n\n\
-Return strict JSON: use double quotes, no trailing commas, no markdown, no backticks, no extra text.\n\n\
-NPC name: {}\nPersona: {}\nDialogue notes: {}\n\n\
-Setting lore: {}\n",
-        dialogue_schema_string(),
-        npc.name,
-        npc.persona,
-        npc.dialogue_prompt,
-        format_lore(state),
-    );
+    let templates = scenario_templates(state);
+    let mut vars = base_vars(state, &templates);
+    vars.insert("schema", dialogue_schema_string());
+    vars.insert("npc_name", npc.name.clone());
+    vars.insert("persona", npc.persona.clone());
+    vars.insert("dialogue_notes", npc.dialogue_prompt.clone());
+    let system = interpolate(templates.dialogue(), &vars);
 
     let mut messages = vec![ChatMessage {
         role: "system".to_string(),
@@ -43,20 +43,10 @@ Setting lore: {}\n",
 }
 
 pub fn build_action_request(state: &AppState, player_text: &str) -> LlmRequest {
-    let system = format!(
-        "You interpret player actions into a single rules check.\n\
-Return ONLY a single JSON object matching this schema:\n{}\n\n\
-Return strict JSON: use double quotes, no trailing commas, no markdown, no backticks, no extra text.\n\n\
-Always set \"kind\" to \"skill_check\".\n\
-Allowed skills: athletics, acrobatics, stealth, perception, persuasion, arcana.\n\
-Allowed abilities: strength, dexterity, constitution, intelligence, wisdom, charisma.\n\
-Difficulties: easy, medium, hard.\n\n\
-Setting lore: {}\n\
-Player location: {}\n",
-        action_schema_string(),
-        format_lore(state),
-        state.map.name,
-    );
+    let templates = scenario_templates(state);
+    let mut vars = base_vars(state, &templates);
+    vars.insert("schema", action_schema_string());
+    let system = interpolate(templates.interpretation(), &vars);
 
     let messages = vec![
         ChatMessage {
@@ -76,6 +66,65 @@ Player location: {}\n",
     }
 }
 
+/// Ambient remark from an NPC the player is not talking to. Reuses the dialogue
+/// response schema so replies parse the same way.
+pub fn build_banter_request(state: &AppState, npc: &NpcState) -> LlmRequest {
+    let templates = scenario_templates(state);
+    let mut vars = base_vars(state, &templates);
+    vars.insert("schema", dialogue_schema_string());
+    vars.insert("npc_name", npc.name.clone());
+    vars.insert("persona", npc.persona.clone());
+    vars.insert("max_chars", BANTER_MAX_CHARS.to_string());
+    let system = interpolate(templates.banter(), &vars);
+
+    let messages = vec![
+        ChatMessage {
+            role: "system".to_string(),
+            content: system,
+        },
+        ChatMessage {
+            role: "user".to_string(),
+            content: format!("{} overhears {}.", player_name(state), npc.name),
+        },
+    ];
+
+    LlmRequest {
+        id: state.rng_seed,
+        messages,
+        stream: false,
+    }
+}
+
+/// One line from a companion as they join or leave. `event` is what the rules
+/// already settled, phrased to follow the NPC's name.
+pub fn build_hireling_request(state: &AppState, npc: &NpcState, event: &str) -> LlmRequest {
+    let templates = scenario_templates(state);
+    let mut vars = base_vars(state, &templates);
+    vars.insert("schema", dialogue_schema_string());
+    vars.insert("npc_name", npc.name.clone());
+    vars.insert("persona", npc.persona.clone());
+    vars.insert("hireling_event", event.to_string());
+    vars.insert("max_chars", BANTER_MAX_CHARS.to_string());
+    let system = interpolate(templates.hireling(), &vars);
+
+    let messages = vec![
+        ChatMessage {
+            role: "system".to_string(),
+            content: system,
+        },
+        ChatMessage {
+            role: "user".to_string(),
+            content: format!("{} {event}.", npc.name),
+        },
+    ];
+
+    LlmRequest {
+        id: state.rng_seed,
+        messages,
+        stream: false,
+    }
+}
+
 fn recent_dialogue(lines: &[DialogueLine]) -> Vec<DialogueLine> {
     if lines.len() <= HISTORY_LIMIT {
         return lines.to_vec();
@@ -83,6 +132,47 @@ fn recent_dialogue(lines: &[DialogueLine]) -> Vec<DialogueLine> {
     lines[lines.len() - HISTORY_LIMIT..].to_vec()
 }
 
+fn scenario_templates(state: &AppState) -> PromptTemplates {
+    state
+        .scenario
+        .as_ref()
+        .map(|s| s.prompts.clone())
+        .unwrap_or_default()
+}
+
+/// Variables shared by every template. `{narration}` is itself a rendered template,
+/// so scenarios can restyle all prompts by overriding only the narration file.
+fn base_vars(state: &AppState, templates: &PromptTemplates) -> HashMap<&'static str, String> {
+    let mut vars = HashMap::new();
+    vars.insert("player_name", player_name(state));
+    vars.insert("location", state.map.name.clone());
+    vars.insert("quest_state", format_quest_state(state));
+    vars.insert("lore", format_lore(state));
+    vars.insert("decisions", decisions::prompt_summary(state));
+    let narration = interpolate(templates.narration(), &vars);
+    vars.insert("narration", narration);
+    vars
+}
+
+fn player_name(state: &AppState) -> String {
+    if state.player.name.is_empty() {
+        "the adventurer".to_string()
+    } else {
+        state.player.name.clone()
+    }
+}
+
+fn format_quest_state(state: &AppState) -> String {
+    let defeated = state.encounters.iter().filter(|e| e.defeated).count();
+    format!(
+        "{} events discovered, {}/{} foes defeated, {} items carried",
+        state.fired_triggers.len(),
+        defeated,
+        state.encounters.len(),
+        state.player.inventory.len(),
+    )
+}
+
 fn format_lore(state: &AppState) -> String {
     state
         .scenario
@@ -90,3 +180,87 @@ fn format_lore(state: &AppState) -> String {
         .map(|s| s.lore.join(" | "))
         .unwrap_or_else(|| "(none)".to_string())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::ScenarioManifestSummary;
+
+    fn scenario_state(prompts: PromptTemplates) -> AppState {
+        let mut state = AppState::default();
+        state.player.name = "Rook".to_string();
+        state.map.name = "Green Hollow".to_string();
+        state.scenario = Some(ScenarioManifestSummary {
+            id: "test".to_string(),
+            name: "Test".to_string(),
+            lore: vec!["Old roads.".to_string()],
+            prompts,
+        });
+        state
+    }
+
+    #[test]
+    fn builtin_templates_render_without_placeholders() {
+        let state = scenario_state(PromptTemplates::default());
+        let request = build_action_request(&state, "climb the wall");
+        let system = &request.messages[0].content;
+        assert!(system.contains("Player location: Green Hollow"));
+        assert!(system.contains("addressed to Rook"));
+        assert!(!system.contains("{location}"));
+    }
+
+    #[test]
+    fn scenario_templates_override_builtins() {
+        let state = scenario_state(PromptTemplates {
+            interpretation: Some("{player_name} at {location}. {narration}".to_string()),
+            narration: Some("Speak like a ballad.".to_string()),
+            ..Default::default()
+        });
+        let request = build_action_request(&state, "sing");
+        assert_eq!(
+            request.messages[0].content,
+            "Rook at Green Hollow. Speak like a ballad."
+        );
+    }
+
+    #[test]
+    fn banter_prompt_names_speaker_and_length_cap() {
+        let state = scenario_state(PromptTemplates::default());
+        let npc = NpcState {
+            id: "mara".to_string(),
+            name: "Mara".to_string(),
+            x: 0,
+            y: 0,
+            persona: "Village scout.".to_string(),
+            dialogue_prompt: String::new(),
+            hire_cost: None,
+        };
+        let request = build_banter_request(&state, &npc);
+        let system = &request.messages[0].content;
+        assert!(system.contains("Nobody is addressing Mara"));
+        assert!(system.contains(&format!("at most {BANTER_MAX_CHARS} characters")));
+        assert_eq!(request.messages[1].content, "Rook overhears Mara.");
+    }
+
+    #[test]
+    fn hireling_prompt_states_the_settled_event() {
+        let state = scenario_state(PromptTemplates::default());
+        let npc = NpcState {
+            id: "garrick".to_string(),
+            name: "Garrick".to_string(),
+            x: 0,
+            y: 0,
+            persona: "Old ranger.".to_string(),
+            dialogue_prompt: String::new(),
+            hire_cost: Some(30),
+        };
+        let request = build_hireling_request(&state, &npc, "agrees to join the party");
+        let system = &request.messages[0].content;
+        assert!(system.contains("already decided that Garrick agrees to join the party"));
+        assert!(!system.contains("{hireling_event}"));
+        assert_eq!(
+            request.messages[1].content,
+            "Garrick agrees to join the party."
+        );
+    }
+}
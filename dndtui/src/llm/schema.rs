@@ -2,9 +2,15 @@ use schemars::JsonSchema;
 use serde::de::DeserializeOwned;
 use serde::{Deserialize, Serialize};
 
+use crate::decisions::DecisionSpec;
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct DialogueResponse {
     pub npc_line: String,
+    /// Set only when the player commits to a major choice in this exchange;
+    /// it goes on the decision timeline.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub record_decision: Option<DecisionSpec>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -57,7 +63,10 @@ pub fn parse_dialogue_response(raw: &str) -> Result<DialogueResponse, String> {
         return Ok(parsed);
     }
     if let Some(line) = extract_field(raw, "npc_line") {
-        return Ok(DialogueResponse { npc_line: line });
+        return Ok(DialogueResponse {
+            npc_line: line,
+            record_decision: None,
+        });
     }
     Err("invalid dialogue response".to_string())
 }
@@ -202,6 +211,15 @@ mod tests {
         assert_eq!(response.skill, "athletics");
     }
 
+    #[test]
+    fn parse_dialogue_with_decision() {
+        let input = r#"{"npc_line":"Then it's settled.","record_decision":{"title":"Promised to clear the mill","flags":["mill_contract"]}}"#;
+        let response = parse_dialogue_response(input).unwrap();
+        let decision = response.record_decision.unwrap();
+        assert_eq!(decision.title, "Promised to clear the mill");
+        assert_eq!(decision.flags, vec!["mill_contract".to_string()]);
+    }
+
     #[test]
     fn parse_fenced_json() {
         let input = "```json\n{\"npc_line\":\"Hi.\"}\n```";
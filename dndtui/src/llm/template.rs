@@ -0,0 +1,180 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+pub const DEFAULT_DIALOGUE: &str = "You are an NPC in a rules-driven fantasy game.\n\
+Respond ONLY with a single JSON object matching this schema:\n{schema}\n\n\
+Return strict JSON: use double quotes, no trailing commas, no markdown, no backticks, no extra text.\n\n\
+NPC name: {npc_name}\nPersona: {persona}\nDialogue notes: {dialogue_notes}\n\n\
+Only when the player commits to a major choice in this exchange (an alliance, a promise, \
+a betrayal), add \"record_decision\" with a short past-tense title and snake_case flags \
+for its lasting consequences; leave it out otherwise.\n\n\
+Setting lore: {lore}\n\
+Decisions so far: {decisions}\n\
+Narration style: {narration}\n";
+
+pub const DEFAULT_INTERPRETATION: &str = "You interpret player actions into a single rules check.\n\
+Return ONLY a single JSON object matching this schema:\n{schema}\n\n\
+Return strict JSON: use double quotes, no trailing commas, no markdown, no backticks, no extra text.\n\n\
+Always set \"kind\" to \"skill_check\".\n\
+Allowed skills: athletics, acrobatics, stealth, perception, persuasion, arcana.\n\
+Allowed abilities: strength, dexterity, constitution, intelligence, wisdom, charisma.\n\
+Difficulties: easy, medium, hard.\n\n\
+Setting lore: {lore}\n\
+Player location: {location}\n\
+Decisions so far: {decisions}\n\
+Narration style: {narration}\n";
+
+pub const DEFAULT_BANTER: &str = "You voice an NPC making a passing remark in a rules-driven fantasy game.\n\
+Respond ONLY with a single JSON object matching this schema:\n{schema}\n\n\
+Return strict JSON: use double quotes, no trailing commas, no markdown, no backticks, no extra text.\n\n\
+NPC name: {npc_name}\nPersona: {persona}\n\n\
+Nobody is addressing {npc_name}; they mutter, hum, or remark to no one in particular while \
+{player_name} passes through {location}. One sentence, at most {max_chars} characters, \
+no questions that demand an answer and no new quest hooks.\n\n\
+Setting lore: {lore}\n\
+Narration style: {narration}\n";
+
+pub const DEFAULT_HIRELING: &str = "You voice a hired companion in a rules-driven fantasy game.\n\
+Respond ONLY with a single JSON object matching this schema:\n{schema}\n\n\
+Return strict JSON: use double quotes, no trailing commas, no markdown, no backticks, no extra text.\n\n\
+NPC name: {npc_name}\nPersona: {persona}\n\n\
+The game has already decided that {npc_name} {hireling_event}. Give the one thing {npc_name} \
+says to {player_name} as it happens. One sentence, at most {max_chars} characters. Do not \
+mention amounts, change what happened, or promise anything further.\n\n\
+Setting lore: {lore}\n\
+Narration style: {narration}\n";
+
+pub const DEFAULT_NARRATION: &str =
+    "Second person, present tense, one or two grounded sentences addressed to {player_name}.";
+
+/// Scenario-provided prompt templates. `None` falls back to the built-in template.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct PromptTemplates {
+    #[serde(default)]
+    pub dialogue: Option<String>,
+    #[serde(default)]
+    pub interpretation: Option<String>,
+    #[serde(default)]
+    pub narration: Option<String>,
+    #[serde(default)]
+    pub banter: Option<String>,
+    #[serde(default)]
+    pub hireling: Option<String>,
+}
+
+impl PromptTemplates {
+    pub fn dialogue(&self) -> &str {
+        self.dialogue.as_deref().unwrap_or(DEFAULT_DIALOGUE)
+    }
+
+    pub fn interpretation(&self) -> &str {
+        self.interpretation
+            .as_deref()
+            .unwrap_or(DEFAULT_INTERPRETATION)
+    }
+
+    pub fn narration(&self) -> &str {
+        self.narration.as_deref().unwrap_or(DEFAULT_NARRATION)
+    }
+
+    pub fn banter(&self) -> &str {
+        self.banter.as_deref().unwrap_or(DEFAULT_BANTER)
+    }
+
+    pub fn hireling(&self) -> &str {
+        self.hireling.as_deref().unwrap_or(DEFAULT_HIRELING)
+    }
+}
+
+/// Replace `{name}` placeholders with values from `vars`.
+///
+/// `{{` and `}}` produce literal braces. Unknown placeholders are kept verbatim so
+/// authoring mistakes stay visible in the prompt instead of silently vanishing.
+pub fn interpolate(template: &str, vars: &HashMap<&str, String>) -> String {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(pos) = rest.find(['{', '}']) {
+        out.push_str(&rest[..pos]);
+        let tail = &rest[pos..];
+        if tail.starts_with("{{") || tail.starts_with("}}") {
+            out.push_str(&tail[..1]);
+            rest = &tail[2..];
+            continue;
+        }
+        if tail.starts_with('{') {
+            if let Some(end) = tail.find('}') {
+                let name = &tail[1..end];
+                if let Some(value) = vars.get(name) {
+                    out.push_str(value);
+                    rest = &tail[end + 1..];
+                    continue;
+                }
+            }
+        }
+        out.push_str(&tail[..1]);
+        rest = &tail[1..];
+    }
+    out.push_str(rest);
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn vars(pairs: &[(&'static str, &str)]) -> HashMap<&'static str, String> {
+        pairs
+            .iter()
+            .map(|(key, value)| (*key, value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn interpolates_known_variables() {
+        let vars = vars(&[("player_name", "Rook"), ("location", "Green Hollow")]);
+        assert_eq!(
+            interpolate("{player_name} stands in {location}.", &vars),
+            "Rook stands in Green Hollow."
+        );
+    }
+
+    #[test]
+    fn keeps_unknown_placeholders() {
+        let vars = vars(&[("player_name", "Rook")]);
+        assert_eq!(
+            interpolate("{player_name} meets {stranger}", &vars),
+            "Rook meets {stranger}"
+        );
+    }
+
+    #[test]
+    fn escaped_braces_are_literal() {
+        let vars = vars(&[("location", "the mill")]);
+        assert_eq!(
+            interpolate("{{\"at\": \"{location}\"}}", &vars),
+            "{\"at\": \"the mill\"}"
+        );
+    }
+
+    #[test]
+    fn values_are_not_reinterpolated() {
+        let vars = vars(&[("schema", "{\"type\": \"object\"}"), ("type", "oops")]);
+        assert_eq!(interpolate("{schema}", &vars), "{\"type\": \"object\"}");
+    }
+
+    #[test]
+    fn unterminated_placeholder_is_literal() {
+        assert_eq!(interpolate("open {brace", &HashMap::new()), "open {brace");
+    }
+
+    #[test]
+    fn missing_templates_fall_back_to_builtins() {
+        let templates = PromptTemplates {
+            narration: Some("Grim and terse.".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(templates.dialogue(), DEFAULT_DIALOGUE);
+        assert_eq!(templates.narration(), "Grim and terse.");
+    }
+}
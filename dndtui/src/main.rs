@@ -1,7 +1,13 @@
 mod action;
+mod autosave;
+mod banter;
+mod console;
+mod decisions;
 mod effect;
+mod hireling;
 mod icons;
 mod llm;
+mod maneuver;
 mod persist;
 mod reducer;
 mod rules;
@@ -9,6 +15,8 @@ mod scenario;
 mod sprite;
 mod sprite_backend;
 mod state;
+mod stealth;
+mod travel;
 mod ui;
 
 use std::cell::RefCell;
@@ -53,6 +61,12 @@ struct Args {
     model: String,
     #[arg(long)]
     save_dir: Option<String>,
+    /// Disable ambient NPC banter (saves LLM calls)
+    #[arg(long)]
+    no_banter: bool,
+    /// Enable the developer console (backtick key)
+    #[arg(long)]
+    dev: bool,
 }
 
 #[derive(Clone, Debug)]
@@ -62,11 +76,15 @@ struct RuntimeConfig {
     model: String,
     save_path: String,
     ollama_base_url: Option<String>,
+    banter: bool,
+    dev: bool,
 }
 
 #[tokio::main]
 async fn main() -> io::Result<()> {
     let args = Args::parse();
+    let (palette, theme_warning) = tui_theme::load("dndtui", "tavern");
+    tui_theme::install(palette);
     let debug = DebugSession::new(args.debug);
     debug.save_state_schema::<AppState>().map_err(debug_error)?;
     debug.save_actions_schema::<Action>().map_err(debug_error)?;
@@ -78,6 +96,8 @@ async fn main() -> io::Result<()> {
         model: args.model.clone(),
         save_path: save_path.clone(),
         ollama_base_url: std::env::var("OLLAMA_BASE_URL").ok(),
+        banter: !args.no_banter,
+        dev: args.dev,
     };
 
     let mut state = debug
@@ -99,6 +119,8 @@ async fn main() -> io::Result<()> {
     state.save_path = config.save_path.clone();
     state.provider = config.provider.clone();
     state.model = config.model.clone();
+    state.banter.enabled = config.banter;
+    state.console.enabled = config.dev;
 
     let replay_actions = debug.load_replay_items().map_err(debug_error)?;
     let (middleware, recorder) = debug.middleware_with_recorder();
@@ -124,6 +146,9 @@ async fn main() -> io::Result<()> {
         )?;
         terminal.show_cursor()?;
     }
+    if let Some(warning) = theme_warning {
+        eprintln!("{warning}");
+    }
 
     let run_output = result?;
     run_output.write_render_output()?;
@@ -194,6 +219,7 @@ fn handle_effect(effect: Effect, ctx: &mut EffectContext<Action>, config: Arc<Ru
                         Ok(parsed) => Action::DialogueResponse {
                             npc_id,
                             line: parsed.npc_line,
+                            decision: parsed.record_decision,
                         },
                         Err(err) => Action::LlmError(err),
                     },
@@ -223,6 +249,53 @@ fn handle_effect(effect: Effect, ctx: &mut EffectContext<Action>, config: Arc<Ru
                 }
             });
         }
+        Effect::CallLlmBanter { npc_id, request } => {
+            let provider = config.provider.clone();
+            let model = config.model.clone();
+            let base_url = config.ollama_base_url.clone();
+            ctx.tasks().spawn(TaskKey::new("llm_banter"), async move {
+                let api_key = std::env::var("OPENAI_API_KEY").ok();
+                let client = match client_for(provider, model, api_key, base_url) {
+                    Ok(client) => client,
+                    Err(err) => return Action::BanterError(err.to_string()),
+                };
+                let mut sink = |_| {};
+                match client.stream_chat(&request, &mut sink).await {
+                    Ok(raw_json) => match crate::llm::schema::parse_dialogue_response(&raw_json) {
+                        Ok(parsed) => Action::BanterResponse {
+                            npc_id,
+                            line: parsed.npc_line,
+                        },
+                        Err(err) => Action::BanterError(err),
+                    },
+                    Err(err) => Action::BanterError(err.to_string()),
+                }
+            });
+        }
+        Effect::CallLlmHireling { name, request } => {
+            let provider = config.provider.clone();
+            let model = config.model.clone();
+            let base_url = config.ollama_base_url.clone();
+            let key = format!("llm_hireling_{name}");
+            ctx.tasks().spawn(TaskKey::new(key), async move {
+                let api_key = std::env::var("OPENAI_API_KEY").ok();
+                let client = match client_for(provider, model, api_key, base_url) {
+                    Ok(client) => client,
+                    Err(err) => return Action::HirelingRemarkError(err.to_string()),
+                };
+                let mut sink = |_| {};
+                match client.stream_chat(&request, &mut sink).await {
+                    Ok(raw_json) => match crate::llm::schema::parse_dialogue_response(&raw_json) {
+                        Ok(parsed) => Action::HirelingRemark {
+                            name,
+                            line: parsed.npc_line,
+                        },
+                        Err(err) => Action::HirelingRemarkError(err),
+                    },
+                    Err(err) => Action::HirelingRemarkError(err.to_string()),
+                }
+            });
+        }
         Effect::SaveGame { state, since } => {
             ctx.tasks().spawn(TaskKey::new("save"), async move {
                 match persist::save_game(&state, since).await {
@@ -0,0 +1,243 @@
+//! Special combat maneuvers taken in place of a basic attack.
+//!
+//! Grapple and shove are contested Athletics checks against the enemy, whose
+//! attack bonus stands in for its Athletics or Acrobatics. What they leave
+//! behind is tracked as conditions on the fight, which change who rolls with
+//! advantage and what each side can do on its turn.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::rules::roll_d20;
+
+/// Attack bonus a hireling strikes with when the player takes the Help action.
+pub const HELPER_ATTACK_BONUS: i32 = 2;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum Maneuver {
+    Grapple,
+    Shove,
+    Disengage,
+    Dodge,
+    Help,
+}
+
+impl Maneuver {
+    pub const ALL: [Maneuver; 5] = [
+        Maneuver::Grapple,
+        Maneuver::Shove,
+        Maneuver::Disengage,
+        Maneuver::Dodge,
+        Maneuver::Help,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Maneuver::Grapple => "Grapple",
+            Maneuver::Shove => "Shove",
+            Maneuver::Disengage => "Disengage",
+            Maneuver::Dodge => "Dodge",
+            Maneuver::Help => "Help",
+        }
+    }
+
+    /// One line for the maneuvers menu.
+    pub fn summary(self) -> &'static str {
+        match self {
+            Maneuver::Grapple => "Athletics contest; a grappled foe can't stand up",
+            Maneuver::Shove => "Athletics contest; knock the foe prone",
+            Maneuver::Disengage => "Move away this turn without an opportunity attack",
+            Maneuver::Dodge => "Attacks against you have disadvantage until your turn",
+            Maneuver::Help => "A hireling strikes with advantage",
+        }
+    }
+
+    /// Whether the maneuver needs the enemy within reach.
+    pub fn needs_reach(self) -> bool {
+        matches!(self, Maneuver::Grapple | Maneuver::Shove)
+    }
+
+    /// Disengage spends the action but leaves the movement to use it.
+    pub fn ends_turn(self) -> bool {
+        self != Maneuver::Disengage
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum Condition {
+    /// Held by the player: speed 0, so a prone foe can't get up.
+    Grappled,
+    /// Attacks against it have advantage; its own attacks have disadvantage.
+    Prone,
+    /// The player is dodging until the start of their next turn.
+    Dodging,
+    /// The player's movement this turn draws no opportunity attacks.
+    Disengaged,
+}
+
+impl Condition {
+    pub fn label(self) -> &'static str {
+        match self {
+            Condition::Grappled => "Grappled",
+            Condition::Prone => "Prone",
+            Condition::Dodging => "Dodging",
+            Condition::Disengaged => "Disengaged",
+        }
+    }
+}
+
+/// Add `condition` unless it's already there.
+pub fn apply(conditions: &mut Vec<Condition>, condition: Condition) {
+    if !conditions.contains(&condition) {
+        conditions.push(condition);
+    }
+}
+
+/// Remove `condition`; returns whether it was there.
+pub fn clear(conditions: &mut Vec<Condition>, condition: Condition) -> bool {
+    let before = conditions.len();
+    conditions.retain(|c| *c != condition);
+    conditions.len() != before
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Edge {
+    Normal,
+    Advantage,
+    Disadvantage,
+}
+
+impl Edge {
+    /// Advantage and disadvantage cancel out, however many sources each has.
+    pub fn from_sources(advantage: bool, disadvantage: bool) -> Self {
+        match (advantage, disadvantage) {
+            (true, false) => Edge::Advantage,
+            (false, true) => Edge::Disadvantage,
+            _ => Edge::Normal,
+        }
+    }
+}
+
+/// A d20 rolled with `edge`; a normal roll uses a single die.
+pub fn roll_with_edge(seed: &mut u64, edge: Edge) -> i32 {
+    match edge {
+        Edge::Normal => roll_d20(seed),
+        Edge::Advantage => roll_d20(seed).max(roll_d20(seed)),
+        Edge::Disadvantage => roll_d20(seed).min(roll_d20(seed)),
+    }
+}
+
+/// Totals of a contested check.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Contest {
+    pub initiator: i32,
+    pub defender: i32,
+}
+
+impl Contest {
+    /// A tie keeps things as they were, so the initiator has to beat it.
+    pub fn won(self) -> bool {
+        self.initiator > self.defender
+    }
+}
+
+pub fn contest(seed: &mut u64, initiator_mod: i32, defender_mod: i32) -> Contest {
+    Contest {
+        initiator: roll_d20(seed) + initiator_mod,
+        defender: roll_d20(seed) + defender_mod,
+    }
+}
+
+/// Edge on the player's attacks against an enemy with `enemy` conditions.
+pub fn player_attack_edge(enemy: &[Condition]) -> Edge {
+    Edge::from_sources(enemy.contains(&Condition::Prone), false)
+}
+
+/// Edge on the enemy's attacks against the player.
+pub fn enemy_attack_edge(enemy: &[Condition], player: &[Condition]) -> Edge {
+    Edge::from_sources(
+        false,
+        enemy.contains(&Condition::Prone) || player.contains(&Condition::Dodging),
+    )
+}
+
+/// Standing up takes movement, and a grappled enemy has none.
+pub fn can_stand(enemy: &[Condition]) -> bool {
+    !enemy.contains(&Condition::Grappled)
+}
+
+/// Whether leaving the enemy's reach gives it an opportunity attack.
+pub fn provokes_opportunity(player: &[Condition]) -> bool {
+    !player.contains(&Condition::Disengaged)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn advantage_and_disadvantage_cancel() {
+        assert_eq!(Edge::from_sources(true, true), Edge::Normal);
+        assert_eq!(Edge::from_sources(true, false), Edge::Advantage);
+        assert_eq!(Edge::from_sources(false, true), Edge::Disadvantage);
+    }
+
+    #[test]
+    fn edge_keeps_the_better_or_worse_die() {
+        for start in 0..50 {
+            let mut seed = start;
+            let first = roll_d20(&mut seed);
+            let second = roll_d20(&mut seed);
+
+            let mut seed = start;
+            assert_eq!(
+                roll_with_edge(&mut seed, Edge::Advantage),
+                first.max(second)
+            );
+            let mut seed = start;
+            assert_eq!(
+                roll_with_edge(&mut seed, Edge::Disadvantage),
+                first.min(second)
+            );
+            let mut seed = start;
+            assert_eq!(roll_with_edge(&mut seed, Edge::Normal), first);
+        }
+    }
+
+    #[test]
+    fn contests_need_to_beat_the_defender() {
+        let tie = Contest {
+            initiator: 12,
+            defender: 12,
+        };
+        assert!(!tie.won());
+        let win = Contest {
+            initiator: 13,
+            defender: 12,
+        };
+        assert!(win.won());
+    }
+
+    #[test]
+    fn conditions_shape_attack_rolls() {
+        use Condition::*;
+        assert_eq!(player_attack_edge(&[]), Edge::Normal);
+        assert_eq!(player_attack_edge(&[Grappled, Prone]), Edge::Advantage);
+        assert_eq!(enemy_attack_edge(&[Prone], &[]), Edge::Disadvantage);
+        assert_eq!(enemy_attack_edge(&[], &[Dodging]), Edge::Disadvantage);
+        assert_eq!(enemy_attack_edge(&[Grappled], &[Disengaged]), Edge::Normal);
+        assert!(!can_stand(&[Prone, Grappled]));
+        assert!(can_stand(&[Prone]));
+        assert!(!provokes_opportunity(&[Disengaged]));
+    }
+
+    #[test]
+    fn conditions_do_not_stack() {
+        let mut conditions = Vec::new();
+        apply(&mut conditions, Condition::Prone);
+        apply(&mut conditions, Condition::Prone);
+        assert_eq!(conditions, [Condition::Prone]);
+        assert!(clear(&mut conditions, Condition::Prone));
+        assert!(!clear(&mut conditions, Condition::Prone));
+    }
+}
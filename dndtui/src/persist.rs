@@ -1,31 +1,27 @@
 use std::path::PathBuf;
 
 use tokio::io::AsyncWriteExt;
+use tui_persist::SaveFile;
 
 use crate::state::{AppState, LogEntry};
 
-pub async fn save_game(state: &AppState, since: usize) -> Result<(), String> {
-    let path = PathBuf::from(&state.save_path);
-    if let Some(parent) = path.parent() {
-        tokio::fs::create_dir_all(parent)
-            .await
-            .map_err(|e| format!("Failed to create save directory: {}", e))?;
-    }
-    let json = serde_json::to_string_pretty(state)
-        .map_err(|e| format!("Failed to serialize state: {}", e))?;
-    tokio::fs::write(&path, json)
-        .await
-        .map_err(|e| format!("Failed to write save file: {}", e))?;
+/// Current save schema version.
+const SAVE_VERSION: u32 = 1;
 
+fn save_file(path: &str) -> SaveFile {
+    SaveFile::new(path).version(SAVE_VERSION)
+}
+
+pub async fn save_game(state: &AppState, since: usize) -> Result<(), String> {
+    save_file(&state.save_path)
+        .save(state)
+        .map_err(|e| e.to_string())?;
     append_transcript(state, since).await?;
     Ok(())
 }
 
 pub async fn load_game(path: &str) -> Result<AppState, String> {
-    let json = tokio::fs::read_to_string(path)
-        .await
-        .map_err(|e| format!("Failed to read save file: {}", e))?;
-    serde_json::from_str(&json).map_err(|e| format!("Save file corrupted: {}", e))
+    save_file(path).load().map_err(|e| e.to_string())
 }
 
 async fn append_transcript(state: &AppState, since: usize) -> Result<(), String> {
@@ -1,9 +1,15 @@
 use tui_dispatch::DispatchResult;
 
 use crate::action::Action;
+use crate::autosave::AutosaveState;
+use crate::banter::{self, BANTER_COOLDOWN_TICKS};
+use crate::console;
+use crate::decisions::{self, DecisionSpec};
 use crate::effect::Effect;
+use crate::hireling::{self, Departure, LoyaltyEvent};
 use crate::llm::prompt;
 use crate::llm::schema::ActionInterpretation;
+use crate::maneuver::{self, Condition, Edge, Maneuver, HELPER_ATTACK_BONUS};
 use crate::rules::{
     ability_modifier, clamp_score, class_base_hp, difficulty_dc, parse_difficulty,
     parse_skill_or_ability, points_remaining, roll_d20, roll_damage, skill_to_ability, Ability,
@@ -11,11 +17,16 @@ use crate::rules::{
 };
 use crate::scenario::ScenarioRuntime;
 use crate::state::{
-    AppState, CombatState, Direction, GameMode, LogSpeaker, MenuState, PauseMenuState, PendingLlm,
-    Trigger,
+    trigger_key, AppState, CombatState, Direction, GameMode, LogSpeaker, MenuState, NpcState,
+    PauseMenuState, PendingLlm, Trigger, MAIN_FLOOR,
 };
+use crate::stealth::{self, Alert};
+use crate::travel;
 
 const MOVEMENT_PER_TURN: u8 = 4;
+/// Gold found on a defeated foe; bosses carry more.
+const VICTORY_GOLD: u32 = 8;
+const BOSS_GOLD: u32 = 40;
 
 pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
     match action {
@@ -41,11 +52,34 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
         }
         Action::UiRender => DispatchResult::changed(),
         Action::Tick => {
-            if state.pending_llm.is_some() || state.pending_transcript_index.is_some() {
-                state.spinner_frame = state.spinner_frame.wrapping_add(1);
-                DispatchResult::changed()
-            } else if state.spinner_frame != 0 {
-                state.spinner_frame = 0;
+            let stealth_before = state.stealth.clone();
+            if let Some(alert) = stealth::tick(state) {
+                if let Some(result) = handle_alert(state, alert) {
+                    return result;
+                }
+            }
+            let stealth_changed = state.stealth != stealth_before;
+            let banter = banter::due_speaker(state).map(|npc| start_banter(state, &npc));
+            let indicator_before = state.autosave.indicator();
+            let save = state
+                .autosave
+                .tick(autosave_ready(state))
+                .then(|| save_effect(state));
+            let indicator_changed = state.autosave.indicator() != indicator_before;
+            let spinner_changed =
+                if state.pending_llm.is_some() || state.pending_transcript_index.is_some() {
+                    state.spinner_frame = state.spinner_frame.wrapping_add(1);
+                    true
+                } else if state.spinner_frame != 0 {
+                    state.spinner_frame = 0;
+                    true
+                } else {
+                    false
+                };
+            let effects: Vec<Effect> = banter.into_iter().chain(save).collect();
+            if !effects.is_empty() {
+                DispatchResult::changed_with_many(effects)
+            } else if spinner_changed || stealth_changed || indicator_changed {
                 DispatchResult::changed()
             } else {
                 DispatchResult::unchanged()
@@ -69,6 +103,33 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
             state.custom_action.input.clear();
             DispatchResult::changed()
         }
+        Action::Recruit => handle_recruit(state),
+        Action::ToggleSneak => {
+            if state.mode != GameMode::Exploration {
+                return DispatchResult::unchanged();
+            }
+            let line = if stealth::toggle_sneak(state) {
+                "You slow down and move quietly."
+            } else {
+                "You stop sneaking."
+            };
+            state.push_log(LogSpeaker::System, line);
+            DispatchResult::changed()
+        }
+        Action::DismissHireling(slot) => {
+            if state.mode != GameMode::Exploration {
+                return DispatchResult::unchanged();
+            }
+            let Some(hireling) = hireling::dismiss(state, slot) else {
+                return DispatchResult::unchanged();
+            };
+            state.push_log(
+                LogSpeaker::System,
+                format!("You settle up with {} and part ways.", hireling.npc.name),
+            );
+            state.autosave.request();
+            DispatchResult::changed()
+        }
         Action::CloseOverlay => {
             state.mode = crate::state::GameMode::Exploration;
             DispatchResult::changed()
@@ -94,22 +155,87 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
             DispatchResult::changed()
         }
         Action::PauseConfirm => pause_confirm(state),
+        Action::TravelSelect(index) => match state.travel.menu {
+            Some(_) => {
+                let last = travel::destinations(state).len().saturating_sub(1);
+                state.travel.menu = Some(index.min(last));
+                DispatchResult::changed()
+            }
+            None => DispatchResult::unchanged(),
+        },
+        Action::TravelConfirm => fast_travel(state),
+        Action::TravelClose => {
+            state.travel.menu = None;
+            DispatchResult::changed()
+        }
+        Action::JournalSelect(index) => match state.decisions.menu {
+            Some(_) => {
+                let last = state.decisions.entries.len().saturating_sub(1);
+                state.decisions.menu = Some(index.min(last));
+                DispatchResult::changed()
+            }
+            None => DispatchResult::unchanged(),
+        },
+        Action::JournalClose => {
+            state.decisions.menu = None;
+            DispatchResult::changed()
+        }
         Action::DialogueInputChanged(input) => {
             state.dialogue.input = input;
             DispatchResult::changed()
         }
         Action::DialogueSubmit => handle_dialogue_submit(state),
-        Action::DialogueResponse { npc_id, line } => {
+        Action::DialogueResponse {
+            npc_id,
+            line,
+            decision,
+        } => {
             state.pending_llm = None;
             state.dialogue.history.push(crate::state::DialogueLine {
                 speaker: "assistant".to_string(),
                 text: line.clone(),
             });
             state.push_log(LogSpeaker::Npc, format!("{npc_id}: {line}"));
+            let npc = state.npc_by_id(&npc_id).map(|npc| npc.name.clone());
+            record_decision(state, decision, npc);
             state.dialogue.active_npc = None;
             state.mode = crate::state::GameMode::Exploration;
-            DispatchResult::changed_with(save_effect(state))
+            state.autosave.request();
+            DispatchResult::changed()
+        }
+        Action::BanterResponse { npc_id, line } => {
+            state.banter.pending = None;
+            state.banter.cooldown = BANTER_COOLDOWN_TICKS;
+            let name = match state.npc_by_id(&npc_id) {
+                Some(npc) => npc.name.clone(),
+                None => return DispatchResult::unchanged(),
+            };
+            let Some(line) = banter::clamp_line(&line) else {
+                return DispatchResult::unchanged();
+            };
+            // Drop lines that land after the player has moved on to something else.
+            if state.mode != GameMode::Exploration {
+                return DispatchResult::unchanged();
+            }
+            state.banter.last_speaker = Some(npc_id);
+            state.push_log(LogSpeaker::Npc, format!("{name}: {line}"));
+            DispatchResult::changed()
         }
+        Action::BanterError(_) => {
+            // Ambient chatter is optional; failures just wait out another cooldown.
+            state.banter.pending = None;
+            state.banter.cooldown = BANTER_COOLDOWN_TICKS;
+            DispatchResult::unchanged()
+        }
+        Action::HirelingRemark { name, line } => {
+            let Some(line) = banter::clamp_line(&line) else {
+                return DispatchResult::unchanged();
+            };
+            state.push_log(LogSpeaker::Npc, format!("{name}: {line}"));
+            DispatchResult::changed()
+        }
+        // The rules already logged what happened; the remark is only color.
+        Action::HirelingRemarkError(_) => DispatchResult::unchanged(),
         Action::CustomActionInputChanged(input) => {
             state.custom_action.input = input;
             DispatchResult::changed()
@@ -121,6 +247,28 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
         }
         Action::CombatAttack => handle_combat_attack(state),
         Action::CombatEndTurn => handle_combat_end_turn(state),
+        Action::CombatManeuver(maneuver) => handle_combat_maneuver(state, maneuver),
+        Action::ManeuverMenuOpen => match state.combat.as_mut() {
+            Some(combat) if combat.player_turn && state.mode == GameMode::Combat => {
+                combat.maneuver_menu = Some(0);
+                DispatchResult::changed()
+            }
+            _ => DispatchResult::unchanged(),
+        },
+        Action::ManeuverMenuClose => match state.combat.as_mut() {
+            Some(combat) if combat.maneuver_menu.is_some() => {
+                combat.maneuver_menu = None;
+                DispatchResult::changed()
+            }
+            _ => DispatchResult::unchanged(),
+        },
+        Action::ManeuverSelect(index) => match state.combat.as_mut() {
+            Some(combat) if combat.maneuver_menu.is_some() => {
+                combat.maneuver_menu = Some(index.min(Maneuver::ALL.len() - 1));
+                DispatchResult::changed()
+            }
+            _ => DispatchResult::unchanged(),
+        },
         Action::ScrollLog(delta) => {
             let current = i32::from(state.log_scroll);
             let next = (current + i32::from(delta)).clamp(0, i32::from(u16::MAX)) as u16;
@@ -163,10 +311,12 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
             if let Some(menu) = state.menu.as_mut() {
                 menu.has_save = true;
             }
+            state.autosave.finish(true);
             DispatchResult::changed()
         }
         Action::SaveError(error) => {
             state.pending_transcript_index = None;
+            state.autosave.finish(false);
             state.push_log(LogSpeaker::System, format!("Save failed: {error}"));
             DispatchResult::changed()
         }
@@ -175,12 +325,21 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
             let save_path = state.save_path.clone();
             let provider = state.provider.clone();
             let model = state.model.clone();
+            let banter_enabled = state.banter.enabled;
+            let console = std::mem::take(&mut state.console);
             *state = *loaded;
+            state.console = console;
             state.scenario_dir = scenario_dir;
             state.save_path = save_path;
             state.provider = provider;
             state.model = model;
+            state.banter = banter::BanterState {
+                enabled: banter_enabled,
+                ..Default::default()
+            };
+            state.autosave = AutosaveState::default();
             clamp_inventory_selection(state);
+            state.reveal_around_player();
             DispatchResult::changed()
         }
         Action::SaveExists(exists) => {
@@ -214,6 +373,65 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
             }
             DispatchResult::changed()
         }
+        Action::ConsoleOpen => {
+            if !state.console.enabled || state.console.open {
+                return DispatchResult::unchanged();
+            }
+            state.console.open = true;
+            DispatchResult::changed()
+        }
+        Action::ConsoleClose => {
+            state.console.open = false;
+            DispatchResult::changed()
+        }
+        Action::ConsoleInputChanged(input) => {
+            state.console.input = input;
+            DispatchResult::changed()
+        }
+        Action::ConsoleComplete => {
+            let (input, matches) = console::complete(&state.console.input, |kind| {
+                console::scenario_ids(state, kind)
+            });
+            if matches.len() > 1 && input == state.console.input {
+                state.console.reply(matches.join("  "));
+            }
+            state.console.input = input;
+            DispatchResult::changed()
+        }
+        Action::ConsoleSubmit => {
+            let input = std::mem::take(&mut state.console.input);
+            state.console.reply(format!("> {}", input.trim()));
+            DispatchResult::changed()
+        }
+        Action::ConsoleRejected(reason) => {
+            state.console.reply(reason);
+            DispatchResult::changed()
+        }
+        Action::ConsoleHelp => {
+            for line in console::usage_lines() {
+                state.console.reply(line);
+            }
+            DispatchResult::changed()
+        }
+        Action::ConsoleClear => {
+            state.console.output.clear();
+            DispatchResult::changed()
+        }
+        Action::DebugGiveItem { id, qty } => debug_give_item(state, id, qty),
+        Action::DebugTeleport { x, y } => debug_teleport(state, x, y),
+        Action::DebugTeleportToNpc(id) => debug_teleport_to_npc(state, &id),
+        Action::DebugSetFlag { flag, set } => {
+            let reply = if set {
+                state.fired_triggers.insert(flag.clone());
+                format!("Flag `{flag}` set.")
+            } else if state.fired_triggers.remove(&flag) {
+                format!("Flag `{flag}` cleared.")
+            } else {
+                format!("Flag `{flag}` was not set.")
+            };
+            state.console.reply(reply);
+            DispatchResult::changed()
+        }
         Action::Quit => DispatchResult::unchanged(),
     }
 }
@@ -227,7 +445,7 @@ fn handle_move(state: &mut AppState, direction: Direction) -> DispatchResult<Eff
         return handle_combat_move(state, direction);
     }
 
-    if state.mode != crate::state::GameMode::Exploration {
+    if state.mode != crate::state::GameMode::Exploration || !stealth::can_step(state) {
         return DispatchResult::unchanged();
     }
 
@@ -245,10 +463,48 @@ fn handle_move(state: &mut AppState, direction: Direction) -> DispatchResult<Eff
         return DispatchResult::unchanged();
     }
     if let Some(enemy_id) = encounter_at(state, x, y) {
-        return start_combat(state, enemy_id);
+        return start_combat(state, enemy_id, Initiative::Rolled);
+    }
+    state.set_player_pos(x, y);
+    stealth::on_step(state);
+    state.travel.clock += 1;
+    if let Some(stair) = state.stair_at(x, y).cloned() {
+        return take_stairs(state, &stair.to_floor, stair.to_x, stair.to_y);
+    }
+    state.reveal_around_player();
+    if let Some(waypoint) = travel::discover(state) {
+        state.push_log(
+            LogSpeaker::System,
+            format!(
+                "You mark {} as a waypoint; fast travel there from the pause menu.",
+                waypoint.name
+            ),
+        );
+    }
+    check_triggers(state, TriggerKind::OnEnter);
+    let departures = hireling::step(state);
+    if departures.is_empty() {
+        return DispatchResult::changed();
+    }
+    state.autosave.request();
+    let effects = party_news(state, departures);
+    DispatchResult::changed_with_many(effects)
+}
+
+fn take_stairs(state: &mut AppState, floor: &str, x: u16, y: u16) -> DispatchResult<Effect> {
+    if !state.enter_floor(floor) {
+        state.push_log(LogSpeaker::System, "The stairs are blocked.");
+        return DispatchResult::changed();
     }
     state.set_player_pos(x, y);
+    stealth::reset(state);
+    state.reveal_around_player();
+    state.push_log(
+        LogSpeaker::System,
+        format!("You take the stairs to {}.", state.map.name),
+    );
     check_triggers(state, TriggerKind::OnEnter);
+    state.autosave.request();
     DispatchResult::changed()
 }
 
@@ -291,20 +547,142 @@ fn pause_confirm(state: &mut AppState) -> DispatchResult<Effect> {
             DispatchResult::changed()
         }
         1 => DispatchResult::changed_with(save_effect(state)),
+        2 => open_travel_menu(state),
+        3 => {
+            state.pause_menu.is_open = false;
+            if state.decisions.entries.is_empty() {
+                state.push_log(
+                    LogSpeaker::System,
+                    "Your journal has no decisions in it yet.",
+                );
+            } else {
+                // Open on the latest entry.
+                state.decisions.menu = Some(state.decisions.entries.len() - 1);
+            }
+            DispatchResult::changed()
+        }
         _ => {
+            // Write a save still waiting out its debounce before leaving.
+            let mut effects = Vec::new();
+            if state.autosave.due_in.is_some() {
+                effects.push(save_effect(state));
+            }
             state.pause_menu.is_open = false;
             state.mode = GameMode::MainMenu;
             state.menu = Some(MenuState {
                 selected: 0,
                 has_save: false,
             });
-            DispatchResult::changed_with(Effect::CheckSaveExists {
+            effects.push(Effect::CheckSaveExists {
                 path: state.save_path.clone(),
-            })
+            });
+            DispatchResult::changed_with_many(effects)
         }
     }
 }
 
+fn open_travel_menu(state: &mut AppState) -> DispatchResult<Effect> {
+    state.pause_menu.is_open = false;
+    let blocker = if state.mode != GameMode::Exploration {
+        Some("You can't travel in the middle of a fight.")
+    } else if state.stealth.chaser.is_some() {
+        Some("You can't slip away with something on your heels.")
+    } else if travel::destinations(state).is_empty() {
+        Some("You haven't found any waypoints yet.")
+    } else {
+        None
+    };
+    match blocker {
+        Some(line) => state.push_log(LogSpeaker::System, line),
+        None => state.travel.menu = Some(0),
+    }
+    DispatchResult::changed()
+}
+
+/// Jump to the waypoint picked in the travel menu. The road still takes its
+/// time: the clock and hireling wages run for every step skipped, and a long
+/// trip may end in an ambush at the destination.
+fn fast_travel(state: &mut AppState) -> DispatchResult<Effect> {
+    let Some(index) = state.travel.menu else {
+        return DispatchResult::unchanged();
+    };
+    if state.mode != GameMode::Exploration {
+        return DispatchResult::unchanged();
+    }
+    let journey = match travel::plan(state, index) {
+        Ok(journey) => journey,
+        Err(reason) => {
+            state.push_log(LogSpeaker::System, reason);
+            return DispatchResult::changed();
+        }
+    };
+    state.travel.menu = None;
+    let to = journey.to;
+    if to.floor != state.floors.current && !state.enter_floor(&to.floor) {
+        state.push_log(
+            LogSpeaker::System,
+            format!("The way to {} is blocked.", to.name),
+        );
+        return DispatchResult::changed();
+    }
+    state.set_player_pos(to.x, to.y);
+    stealth::reset(state);
+    state.reveal_around_player();
+    state.travel.clock += journey.steps;
+    let mut departures = Vec::new();
+    for _ in 0..journey.steps {
+        departures.extend(hireling::step(state));
+    }
+    state.push_log(
+        LogSpeaker::System,
+        format!(
+            "You travel to {} ({}h on the road). It is {}.",
+            to.name,
+            travel::hours(journey.steps),
+            travel::clock_label(state.travel.clock)
+        ),
+    );
+    check_triggers(state, TriggerKind::OnEnter);
+    let mut effects = party_news(state, departures);
+
+    if let Some(ambush) = place_ambush(state, journey.steps) {
+        state.push_log(
+            LogSpeaker::Combat,
+            format!("{} waylay you as you arrive!", ambush.name),
+        );
+        let id = ambush.id.clone();
+        state.encounters.push(ambush);
+        let mut result = start_combat(state, id, Initiative::EnemyFirst);
+        effects.append(&mut result.effects);
+        result.effects = effects;
+        return result;
+    }
+    state.autosave.request();
+    DispatchResult::changed_with_many(effects)
+}
+
+/// Roll for an ambush and stand it next to the player; none if the roll
+/// fails or there's no room.
+fn place_ambush(state: &mut AppState, steps: u32) -> Option<crate::state::EncounterState> {
+    let mut ambush = travel::roll_ambush(state, steps)?;
+    let (x, y) = state.player_pos();
+    let neighbors = [
+        (x, y.saturating_sub(1)),
+        (x.saturating_add(1), y),
+        (x, y.saturating_add(1)),
+        (x.saturating_sub(1), y),
+    ];
+    let (ax, ay) = neighbors.into_iter().find(|&(nx, ny)| {
+        (nx, ny) != (x, y)
+            && state.map.is_walkable(nx, ny)
+            && !has_npc_at(state, nx, ny)
+            && !has_active_encounter_at(state, nx, ny)
+    })?;
+    ambush.x = ax;
+    ambush.y = ay;
+    Some(ambush)
+}
+
 fn handle_interact(state: &mut AppState) -> DispatchResult<Effect> {
     if state.mode != crate::state::GameMode::Exploration {
         return DispatchResult::unchanged();
@@ -322,7 +700,8 @@ fn handle_interact(state: &mut AppState) -> DispatchResult<Effect> {
         state.push_log(LogSpeaker::System, format!("Picked up {} x{}", name, qty));
     }
     check_triggers(state, TriggerKind::OnInteract);
-    DispatchResult::changed_with(save_effect(state))
+    state.autosave.request();
+    DispatchResult::changed()
 }
 
 fn handle_talk(state: &mut AppState) -> DispatchResult<Effect> {
@@ -377,6 +756,73 @@ fn handle_dialogue_submit(state: &mut AppState) -> DispatchResult<Effect> {
     DispatchResult::changed_with(Effect::CallLlmDialogue { npc_id, request })
 }
 
+fn handle_recruit(state: &mut AppState) -> DispatchResult<Effect> {
+    if state.mode != GameMode::Exploration {
+        return DispatchResult::unchanged();
+    }
+    let (x, y) = state.player_pos();
+    let nearby: Vec<&NpcState> = state
+        .npcs
+        .iter()
+        .filter(|npc| distance(npc.x, npc.y, x, y) <= 1)
+        .collect();
+    let Some(npc) = nearby.iter().find(|npc| npc.hire_cost.is_some()) else {
+        let message = match nearby.first() {
+            Some(npc) => format!("{} isn't looking for work.", npc.name),
+            None => "No one nearby to hire.".to_string(),
+        };
+        state.push_log(LogSpeaker::System, message);
+        return DispatchResult::changed();
+    };
+    if let Some(reason) = hireling::hire_blocker(state, npc) {
+        state.push_log(LogSpeaker::System, reason);
+        return DispatchResult::changed();
+    }
+    let npc_id = npc.id.clone();
+    let Some(hired) = hireling::hire(state, &npc_id) else {
+        return DispatchResult::unchanged();
+    };
+    state.push_log(
+        LogSpeaker::System,
+        format!(
+            "{} joins you for {} gold, at {} gold a day.",
+            hired.npc.name,
+            hired.npc.hire_cost.unwrap_or_default(),
+            hired.wage
+        ),
+    );
+    let remark = hireling_remark(state, &hired.npc, "agrees to join the party for pay");
+    state.autosave.request();
+    DispatchResult::changed_with(remark)
+}
+
+/// Log each departure and ask for the companion's parting line.
+fn party_news(state: &mut AppState, departures: Vec<Departure>) -> Vec<Effect> {
+    departures
+        .into_iter()
+        .map(|departure| {
+            state.push_log(LogSpeaker::System, departure.log_line());
+            hireling_remark(state, departure.npc(), departure.summary())
+        })
+        .collect()
+}
+
+fn hireling_remark(state: &AppState, npc: &NpcState, event: &str) -> Effect {
+    Effect::CallLlmHireling {
+        name: npc.name.clone(),
+        request: prompt::build_hireling_request(state, npc, event),
+    }
+}
+
+fn start_banter(state: &mut AppState, npc: &NpcState) -> Effect {
+    let request = prompt::build_banter_request(state, npc);
+    state.banter.pending = Some(npc.id.clone());
+    Effect::CallLlmBanter {
+        npc_id: npc.id.clone(),
+        request,
+    }
+}
+
 fn handle_custom_action_submit(state: &mut AppState) -> DispatchResult<Effect> {
     if state.pending_llm.is_some() {
         return DispatchResult::unchanged();
@@ -435,7 +881,8 @@ fn handle_custom_action_result(
         state.push_log(LogSpeaker::System, result.on_failure);
     }
     state.mode = crate::state::GameMode::Exploration;
-    DispatchResult::changed_with(save_effect(state))
+    state.autosave.request();
+    DispatchResult::changed()
 }
 
 fn handle_combat_move(state: &mut AppState, direction: Direction) -> DispatchResult<Effect> {
@@ -459,24 +906,71 @@ fn handle_combat_move(state: &mut AppState, direction: Direction) -> DispatchRes
     if has_npc_at(state, x, y) || has_active_encounter_at(state, x, y) {
         return DispatchResult::unchanged();
     }
+    let from = state.player_pos();
     state.set_player_pos(x, y);
     if let Some(combat) = state.combat.as_mut() {
         combat.movement_left = combat.movement_left.saturating_sub(1);
     }
+    if let Some(result) = leave_reach(state, from) {
+        return result;
+    }
     DispatchResult::changed()
 }
 
+/// Stepping out of the engaged enemy's reach lets go of a grapple and, unless
+/// the player disengaged, draws its opportunity attack.
+fn leave_reach(state: &mut AppState, from: (u16, u16)) -> Option<DispatchResult<Effect>> {
+    let enemy_id = state.combat.as_ref()?.enemy_id.clone();
+    let enemy_index = state
+        .encounters
+        .iter()
+        .position(|e| e.id == enemy_id && !e.defeated)?;
+    let (enemy_name, ex, ey) = {
+        let enemy = &state.encounters[enemy_index];
+        (enemy.name.clone(), enemy.x, enemy.y)
+    };
+    let (px, py) = state.player_pos();
+    if distance(from.0, from.1, ex, ey) > 1 || distance(px, py, ex, ey) <= 1 {
+        return None;
+    }
+    let combat = state.combat.as_mut()?;
+    let released = maneuver::clear(&mut combat.enemy_conditions, Condition::Grappled);
+    let reacts = !combat.enemy_reacted && maneuver::provokes_opportunity(&combat.player_conditions);
+    if reacts {
+        combat.enemy_reacted = true;
+    }
+    if released {
+        state.push_log(LogSpeaker::Combat, format!("You let go of {enemy_name}."));
+    }
+    if !reacts {
+        return None;
+    }
+    state.push_log(
+        LogSpeaker::Combat,
+        format!("{enemy_name} strikes as you pull away!"),
+    );
+    enemy_strike(state, enemy_index)
+}
+
 fn handle_combat_attack(state: &mut AppState) -> DispatchResult<Effect> {
     if state.mode != crate::state::GameMode::Combat {
         return DispatchResult::unchanged();
     }
-    let (player_turn, enemy_id) = match state.combat.as_ref() {
-        Some(combat) => (combat.player_turn, combat.enemy_id.clone()),
+    let (player_turn, action_used, enemy_id) = match state.combat.as_ref() {
+        Some(combat) => (
+            combat.player_turn,
+            combat.action_used,
+            combat.enemy_id.clone(),
+        ),
         None => return DispatchResult::unchanged(),
     };
     if !player_turn {
         return DispatchResult::unchanged();
     }
+    if action_used {
+        state.push_log(LogSpeaker::Combat, "You've already used your action.");
+        return DispatchResult::changed();
+    }
     let (px, py) = state.player_pos();
     let enemy_index = match state.encounters.iter().position(|e| e.id == enemy_id) {
         Some(index) => index,
@@ -494,30 +988,23 @@ fn handle_combat_attack(state: &mut AppState) -> DispatchResult<Effect> {
         return DispatchResult::changed();
     }
 
-    let roll = roll_d20(&mut state.rng_seed);
+    let edge = state
+        .combat
+        .as_ref()
+        .map(|combat| maneuver::player_attack_edge(&combat.enemy_conditions))
+        .unwrap_or(Edge::Normal);
+    let roll = maneuver::roll_with_edge(&mut state.rng_seed, edge);
     let modifier = ability_modifier(state.ability_score(Ability::Strength));
     let total = roll + modifier;
     let hit = total >= 10;
     if hit {
         let damage = (roll_damage(&mut state.rng_seed, 6) + modifier).max(1);
-        let mut defeated = false;
-        {
-            let enemy = &mut state.encounters[enemy_index];
-            enemy.hp -= damage;
-            if enemy.hp <= 0 {
-                enemy.defeated = true;
-                defeated = true;
-            }
-        }
         state.push_log(
             LogSpeaker::Combat,
             format!("You hit {} for {} damage.", enemy_name, damage),
         );
-        if defeated {
-            state.push_log(LogSpeaker::Combat, format!("{} is defeated.", enemy_name));
-            state.combat = None;
-            state.mode = crate::state::GameMode::Exploration;
-            return DispatchResult::changed_with(save_effect(state));
+        if let Some(result) = wound_enemy(state, enemy_index, damage) {
+            return result;
         }
     } else {
         state.push_log(LogSpeaker::Combat, "You miss.");
@@ -526,6 +1013,157 @@ fn handle_combat_attack(state: &mut AppState) -> DispatchResult<Effect> {
     handle_combat_end_turn(state)
 }
 
+/// Take `damage` off the enemy. A killing blow ends the fight, and its result
+/// is returned.
+fn wound_enemy(
+    state: &mut AppState,
+    enemy_index: usize,
+    damage: i32,
+) -> Option<DispatchResult<Effect>> {
+    let (enemy_name, enemy_boss) = {
+        let enemy = &mut state.encounters[enemy_index];
+        enemy.hp -= damage;
+        if enemy.hp > 0 {
+            return None;
+        }
+        enemy.defeated = true;
+        (enemy.name.clone(), enemy.boss)
+    };
+    state.push_log(LogSpeaker::Combat, format!("{} is defeated.", enemy_name));
+    let bounty = if enemy_boss { BOSS_GOLD } else { VICTORY_GOLD };
+    state.player.gold = state.player.gold.saturating_add(bounty);
+    state.push_log(
+        LogSpeaker::System,
+        format!("You find {bounty} gold on the {enemy_name}."),
+    );
+    state.combat = None;
+    state.mode = crate::state::GameMode::Exploration;
+    let departures = hireling::apply_event(state, LoyaltyEvent::Victory);
+    state.autosave.request();
+    let effects = party_news(state, departures);
+    Some(DispatchResult::changed_with_many(effects))
+}
+
+fn handle_combat_maneuver(state: &mut AppState, maneuver: Maneuver) -> DispatchResult<Effect> {
+    if state.mode != crate::state::GameMode::Combat {
+        return DispatchResult::unchanged();
+    }
+    let Some(combat) = state.combat.as_mut() else {
+        return DispatchResult::unchanged();
+    };
+    combat.maneuver_menu = None;
+    if !combat.player_turn {
+        return DispatchResult::changed();
+    }
+    if combat.action_used {
+        state.push_log(LogSpeaker::Combat, "You've already used your action.");
+        return DispatchResult::changed();
+    }
+    let enemy_id = combat.enemy_id.clone();
+    let Some(enemy_index) = state
+        .encounters
+        .iter()
+        .position(|e| e.id == enemy_id && !e.defeated)
+    else {
+        return DispatchResult::changed();
+    };
+    let (enemy_name, enemy_x, enemy_y, enemy_atk) = {
+        let enemy = &state.encounters[enemy_index];
+        (enemy.name.clone(), enemy.x, enemy.y, enemy.atk)
+    };
+    let (px, py) = state.player_pos();
+    if maneuver.needs_reach() && distance(px, py, enemy_x, enemy_y) > 1 {
+        state.push_log(LogSpeaker::Combat, "Enemy out of range.");
+        return DispatchResult::changed();
+    }
+    let athletics = ability_modifier(state.ability_score(Ability::Strength));
+
+    match maneuver {
+        Maneuver::Grapple | Maneuver::Shove => {
+            let (condition, label) = if maneuver == Maneuver::Grapple {
+                (Condition::Grappled, "grappled")
+            } else {
+                (Condition::Prone, "prone")
+            };
+            let already = state
+                .combat
+                .as_ref()
+                .is_some_and(|combat| combat.enemy_conditions.contains(&condition));
+            if already {
+                state.push_log(
+                    LogSpeaker::Combat,
+                    format!("{enemy_name} is already {label}."),
+                );
+                return DispatchResult::changed();
+            }
+            let contest = maneuver::contest(&mut state.rng_seed, athletics, enemy_atk);
+            let scores = format!("({} vs {})", contest.initiator, contest.defender);
+            let line = match (maneuver, contest.won()) {
+                (Maneuver::Grapple, true) => format!("You grapple {enemy_name} {scores}."),
+                (Maneuver::Grapple, false) => format!("{enemy_name} slips your grasp {scores}."),
+                (_, true) => format!("You shove {enemy_name} to the ground {scores}."),
+                (_, false) => format!("{enemy_name} stands firm {scores}."),
+            };
+            if contest.won() {
+                if let Some(combat) = state.combat.as_mut() {
+                    maneuver::apply(&mut combat.enemy_conditions, condition);
+                }
+            }
+            state.push_log(LogSpeaker::Combat, line);
+        }
+        Maneuver::Disengage => {
+            if let Some(combat) = state.combat.as_mut() {
+                maneuver::apply(&mut combat.player_conditions, Condition::Disengaged);
+                combat.action_used = true;
+            }
+            state.push_log(
+                LogSpeaker::Combat,
+                "You disengage; you can move away freely this turn.",
+            );
+        }
+        Maneuver::Dodge => {
+            if let Some(combat) = state.combat.as_mut() {
+                maneuver::apply(&mut combat.player_conditions, Condition::Dodging);
+            }
+            state.push_log(LogSpeaker::Combat, "You focus on dodging.");
+        }
+        Maneuver::Help => {
+            let Some(helper) = state
+                .party
+                .hirelings
+                .first()
+                .map(|hireling| hireling.npc.name.clone())
+            else {
+                state.push_log(LogSpeaker::Combat, "There's no one with you to help.");
+                return DispatchResult::changed();
+            };
+            let roll = maneuver::roll_with_edge(&mut state.rng_seed, Edge::Advantage);
+            if roll + HELPER_ATTACK_BONUS >= 10 {
+                let damage = roll_damage(&mut state.rng_seed, 6);
+                state.push_log(
+                    LogSpeaker::Combat,
+                    format!(
+                        "You draw {enemy_name}'s eye and {helper} hits it for {damage} damage."
+                    ),
+                );
+                if let Some(result) = wound_enemy(state, enemy_index, damage) {
+                    return result;
+                }
+            } else {
+                state.push_log(
+                    LogSpeaker::Combat,
+                    format!("You draw {enemy_name}'s eye, but {helper} misses."),
+                );
+            }
+        }
+    }
+
+    if !maneuver.ends_turn() {
+        return DispatchResult::changed();
+    }
+    handle_combat_end_turn(state)
+}
+
 fn handle_combat_end_turn(state: &mut AppState) -> DispatchResult<Effect> {
     let (player_turn, enemy_id) = match state.combat.as_ref() {
         Some(combat) => (combat.player_turn, combat.enemy_id.clone()),
@@ -537,6 +1175,7 @@ fn handle_combat_end_turn(state: &mut AppState) -> DispatchResult<Effect> {
 
     if let Some(combat) = state.combat.as_mut() {
         combat.player_turn = false;
+        combat.maneuver_menu = None;
     }
     resolve_enemy_turn(state, &enemy_id)
 }
@@ -600,23 +1239,83 @@ fn finalize_creation(state: &mut AppState) -> DispatchResult<Effect> {
         LogSpeaker::System,
         format!("Welcome, {} the {}.", state.player.name, class_name),
     );
-    DispatchResult::changed_with(save_effect(state))
+    state.autosave.request();
+    DispatchResult::changed()
+}
+
+/// Log a stealth alert; a caught player is thrown into combat, and that
+/// result is returned so the tick can stop there.
+fn handle_alert(state: &mut AppState, alert: Alert) -> Option<DispatchResult<Effect>> {
+    let name_of = |state: &AppState, id: &str| {
+        state
+            .encounters
+            .iter()
+            .find(|enemy| enemy.id == id)
+            .map(|enemy| enemy.name.clone())
+            .unwrap_or_default()
+    };
+    match alert {
+        Alert::Spotted { enemy_id } => {
+            let name = name_of(state, &enemy_id);
+            state.push_log(
+                LogSpeaker::Combat,
+                format!("{name} spots you and gives chase!"),
+            );
+            None
+        }
+        Alert::Caught { enemy_id } => {
+            let name = name_of(state, &enemy_id);
+            state.push_log(LogSpeaker::Combat, format!("{name} is on you!"));
+            Some(start_combat(state, enemy_id, Initiative::EnemyFirst))
+        }
+        Alert::LostTrail { enemy_id } => {
+            let name = name_of(state, &enemy_id);
+            state.push_log(LogSpeaker::System, format!("{name} loses your trail."));
+            None
+        }
+    }
+}
+
+/// Who opens a fight.
+#[derive(Copy, Clone, Debug)]
+enum Initiative {
+    Rolled,
+    /// The enemy saw the player first and gets the opening turn.
+    EnemyFirst,
 }
 
-fn start_combat(state: &mut AppState, enemy_id: String) -> DispatchResult<Effect> {
+fn start_combat(
+    state: &mut AppState,
+    enemy_id: String,
+    initiative: Initiative,
+) -> DispatchResult<Effect> {
     let enemy_name = match state.encounters.iter().find(|e| e.id == enemy_id) {
         Some(enemy) if !enemy.defeated => enemy.name.clone(),
         _ => return DispatchResult::unchanged(),
     };
-    let player_init =
-        roll_d20(&mut state.rng_seed) + ability_modifier(state.ability_score(Ability::Dexterity));
-    let enemy_init = roll_d20(&mut state.rng_seed);
-    let player_turn = player_init >= enemy_init;
+    let player_turn = match initiative {
+        Initiative::Rolled => {
+            let player_init = roll_d20(&mut state.rng_seed)
+                + ability_modifier(state.ability_score(Ability::Dexterity));
+            let enemy_init = roll_d20(&mut state.rng_seed);
+            player_init >= enemy_init
+        }
+        Initiative::EnemyFirst => false,
+    };
+    state.stealth.chaser = None;
+    state.stealth.detection.remove(&enemy_id);
+    state.travel.menu = None;
+    state.decisions.menu = None;
     state.combat = Some(CombatState {
         enemy_id: enemy_id.clone(),
         player_turn,
         movement_left: MOVEMENT_PER_TURN,
         round: 1,
+        enemy_conditions: Vec::new(),
+        player_conditions: Vec::new(),
+        action_used: false,
+        enemy_reacted: false,
+        maneuver_menu: None,
     });
     state.mode = crate::state::GameMode::Combat;
     state.push_log(
@@ -636,6 +1335,7 @@ fn apply_scenario(state: &mut AppState, scenario: ScenarioRuntime) {
             id: scenario.manifest.id.clone(),
             name: scenario.manifest.name.clone(),
             lore: scenario.manifest.lore.clone(),
+            prompts: scenario.prompts.clone(),
         });
         return;
     }
@@ -644,15 +1344,119 @@ fn apply_scenario(state: &mut AppState, scenario: ScenarioRuntime) {
     state.items = scenario.items.clone();
     state.encounters = scenario.encounters.clone();
     state.triggers = scenario.triggers.clone();
+    state.stairs = scenario.stairs.clone();
+    state.floors = crate::state::FloorRegistry {
+        current: MAIN_FLOOR.to_string(),
+        stashed: scenario.floors.clone(),
+    };
+    state.waypoints = scenario.waypoints.clone();
+    state.travel_encounters = scenario.manifest.travel_encounters.clone();
     state.scenario = Some(crate::state::ScenarioManifestSummary {
         id: scenario.manifest.id.clone(),
         name: scenario.manifest.name.clone(),
         lore: scenario.manifest.lore.clone(),
+        prompts: scenario.prompts.clone(),
     });
     if state.player.x == 0 && state.player.y == 0 {
         state.player.x = scenario.manifest.player_start.x;
         state.player.y = scenario.manifest.player_start.y;
     }
+    state.reveal_around_player();
+}
+
+fn debug_give_item(state: &mut AppState, id: String, qty: u16) -> DispatchResult<Effect> {
+    let stashed = state.floors.stashed.values().flat_map(|floor| &floor.items);
+    let name = state
+        .items
+        .iter()
+        .chain(stashed)
+        .find(|item| item.id == id)
+        .map(|item| item.name.clone())
+        .or_else(|| {
+            let stack = state.player.inventory.iter().find(|item| item.id == id)?;
+            Some(stack.name.clone())
+        });
+    let Some(name) = name else {
+        state
+            .console
+            .reply(format!("No item `{id}` in this scenario."));
+        return DispatchResult::changed();
+    };
+    state.console.reply(format!("Gave {qty} x {name}."));
+    add_item_to_inventory(state, id, name, qty);
+    DispatchResult::changed()
+}
+
+fn debug_teleport(state: &mut AppState, x: u16, y: u16) -> DispatchResult<Effect> {
+    if state.mode != GameMode::Exploration {
+        state
+            .console
+            .reply("Teleporting only works while exploring.");
+        return DispatchResult::changed();
+    }
+    if x >= state.map.width || y >= state.map.height {
+        let (width, height) = (state.map.width, state.map.height);
+        state
+            .console
+            .reply(format!("({x}, {y}) is off the {width}x{height} map."));
+        return DispatchResult::changed();
+    }
+    state.set_player_pos(x, y);
+    state.reveal_around_player();
+    state.console.reply(format!("Teleported to ({x}, {y})."));
+    DispatchResult::changed()
+}
+
+/// Moves the player next to an NPC, switching floors if it lives on another.
+fn debug_teleport_to_npc(state: &mut AppState, id: &str) -> DispatchResult<Effect> {
+    if state.mode != GameMode::Exploration {
+        state
+            .console
+            .reply("Teleporting only works while exploring.");
+        return DispatchResult::changed();
+    }
+    if state.npc_by_id(id).is_none() {
+        let floor = state
+            .floors
+            .stashed
+            .iter()
+            .find(|(_, floor)| floor.npcs.iter().any(|npc| npc.id == id))
+            .map(|(floor_id, _)| floor_id.clone());
+        let Some(floor) = floor else {
+            state
+                .console
+                .reply(format!("No NPC `{id}` in this scenario."));
+            return DispatchResult::changed();
+        };
+        state.enter_floor(&floor);
+    }
+    let Some(npc) = state.npc_by_id(id).cloned() else {
+        return DispatchResult::changed();
+    };
+    let neighbors = [
+        (npc.x, npc.y.saturating_add(1)),
+        (npc.x.saturating_add(1), npc.y),
+        (npc.x, npc.y.saturating_sub(1)),
+        (npc.x.saturating_sub(1), npc.y),
+    ];
+    let spot = neighbors.into_iter().find(|&(x, y)| {
+        state.map.is_walkable(x, y)
+            && !has_npc_at(state, x, y)
+            && !has_active_encounter_at(state, x, y)
+    });
+    let Some((x, y)) = spot else {
+        state
+            .console
+            .reply(format!("There is no room next to {}.", npc.name));
+        return DispatchResult::changed();
+    };
+    state.set_player_pos(x, y);
+    state.reveal_around_player();
+    state.console.reply(format!(
+        "Teleported next to {} on {}.",
+        npc.name, state.floors.current
+    ));
+    DispatchResult::changed()
 }
 
 fn add_item_to_inventory(state: &mut AppState, id: String, name: String, qty: u16) {
@@ -683,29 +1487,9 @@ fn resolve_enemy_turn(state: &mut AppState, enemy_id: &str) -> DispatchResult<Ef
         Some(index) => index,
         None => return DispatchResult::unchanged(),
     };
-    let (enemy_name, enemy_atk, enemy_defeated) = {
-        let enemy = &state.encounters[enemy_index];
-        (enemy.name.clone(), enemy.atk, enemy.defeated)
-    };
-    if !enemy_defeated {
-        let roll = roll_d20(&mut state.rng_seed);
-        let player_ac = 10 + ability_modifier(state.ability_score(Ability::Dexterity));
-        let hit = roll + enemy_atk >= player_ac;
-        if hit {
-            let damage = (roll_damage(&mut state.rng_seed, 6) + enemy_atk).max(1);
-            state.player.hp -= damage;
-            state.push_log(
-                LogSpeaker::Combat,
-                format!("{} hits you for {} damage.", enemy_name, damage),
-            );
-            if state.player.hp <= 0 {
-                state.push_log(LogSpeaker::Combat, "You fall unconscious.");
-                state.combat = None;
-                state.mode = crate::state::GameMode::Exploration;
-                return DispatchResult::changed_with(save_effect(state));
-            }
-        } else {
-            state.push_log(LogSpeaker::Combat, format!("{} misses.", enemy_name));
+    if !state.encounters[enemy_index].defeated {
+        if let Some(result) = enemy_act(state, enemy_index) {
+            return result;
         }
     }
 
@@ -713,10 +1497,151 @@ fn resolve_enemy_turn(state: &mut AppState, enemy_id: &str) -> DispatchResult<Ef
         combat.player_turn = true;
         combat.movement_left = MOVEMENT_PER_TURN;
         combat.round = combat.round.saturating_add(1);
+        combat.action_used = false;
+        combat.enemy_reacted = false;
+        maneuver::clear(&mut combat.player_conditions, Condition::Dodging);
+        maneuver::clear(&mut combat.player_conditions, Condition::Disengaged);
     }
     DispatchResult::changed()
 }
 
+/// The enemy's turn: get up, or struggle against a grapple that pins it
+/// down, then close in and attack if the player is within reach.
+fn enemy_act(state: &mut AppState, enemy_index: usize) -> Option<DispatchResult<Effect>> {
+    let (enemy_name, enemy_atk) = {
+        let enemy = &state.encounters[enemy_index];
+        (enemy.name.clone(), enemy.atk)
+    };
+    let conditions = state
+        .combat
+        .as_ref()
+        .map(|combat| combat.enemy_conditions.clone())
+        .unwrap_or_default();
+    let mut movement = MOVEMENT_PER_TURN;
+    if conditions.contains(&Condition::Prone) {
+        if !maneuver::can_stand(&conditions) {
+            let athletics = ability_modifier(state.ability_score(Ability::Strength));
+            let contest = maneuver::contest(&mut state.rng_seed, enemy_atk, athletics);
+            let scores = format!("({} vs {})", contest.initiator, contest.defender);
+            if contest.won() {
+                if let Some(combat) = state.combat.as_mut() {
+                    maneuver::clear(&mut combat.enemy_conditions, Condition::Grappled);
+                }
+                state.push_log(
+                    LogSpeaker::Combat,
+                    format!("{enemy_name} breaks free of your grip {scores}."),
+                );
+            } else {
+                state.push_log(
+                    LogSpeaker::Combat,
+                    format!("{enemy_name} struggles against your grip {scores}."),
+                );
+            }
+            return None;
+        }
+        // Standing up costs half its movement
+        movement /= 2;
+        if let Some(combat) = state.combat.as_mut() {
+            maneuver::clear(&mut combat.enemy_conditions, Condition::Prone);
+        }
+        state.push_log(LogSpeaker::Combat, format!("{enemy_name} gets back up."));
+    }
+
+    let grappled = conditions.contains(&Condition::Grappled);
+    let mut moved = false;
+    while movement > 0 && !grappled && !enemy_in_reach(state, enemy_index) {
+        if !step_enemy_toward_player(state, enemy_index) {
+            break;
+        }
+        moved = true;
+        movement -= 1;
+    }
+    if moved {
+        state.push_log(LogSpeaker::Combat, format!("{enemy_name} closes in."));
+    }
+    if !enemy_in_reach(state, enemy_index) {
+        return None;
+    }
+    enemy_strike(state, enemy_index)
+}
+
+fn enemy_in_reach(state: &AppState, enemy_index: usize) -> bool {
+    let enemy = &state.encounters[enemy_index];
+    let (px, py) = state.player_pos();
+    distance(px, py, enemy.x, enemy.y) <= 1
+}
+
+/// Move the enemy one open tile closer to the player, if there is one.
+fn step_enemy_toward_player(state: &mut AppState, enemy_index: usize) -> bool {
+    let (px, py) = state.player_pos();
+    let (ex, ey) = {
+        let enemy = &state.encounters[enemy_index];
+        (enemy.x, enemy.y)
+    };
+    let current = distance(px, py, ex, ey);
+    let next = [
+        (ex, ey.saturating_sub(1)),
+        (ex, ey.saturating_add(1)),
+        (ex.saturating_sub(1), ey),
+        (ex.saturating_add(1), ey),
+    ]
+    .into_iter()
+    .filter(|&(x, y)| {
+        distance(px, py, x, y) < current
+            && (x, y) != (px, py)
+            && state.map.is_walkable(x, y)
+            && !has_npc_at(state, x, y)
+            && !has_active_encounter_at(state, x, y)
+    })
+    .min_by_key(|&(x, y)| distance(px, py, x, y));
+    let Some((x, y)) = next else {
+        return false;
+    };
+    let enemy = &mut state.encounters[enemy_index];
+    enemy.x = x;
+    enemy.y = y;
+    true
+}
+
+/// One enemy attack against the player. Knocking them out ends the fight, and
+/// that result is returned.
+fn enemy_strike(state: &mut AppState, enemy_index: usize) -> Option<DispatchResult<Effect>> {
+    let (enemy_name, enemy_atk) = {
+        let enemy = &state.encounters[enemy_index];
+        (enemy.name.clone(), enemy.atk)
+    };
+    let edge = state
+        .combat
+        .as_ref()
+        .map(|combat| {
+            maneuver::enemy_attack_edge(&combat.enemy_conditions, &combat.player_conditions)
+        })
+        .unwrap_or(Edge::Normal);
+    let roll = maneuver::roll_with_edge(&mut state.rng_seed, edge);
+    let player_ac = 10 + ability_modifier(state.ability_score(Ability::Dexterity));
+    let hit = roll + enemy_atk >= player_ac;
+    if !hit {
+        state.push_log(LogSpeaker::Combat, format!("{} misses.", enemy_name));
+        return None;
+    }
+    let damage = (roll_damage(&mut state.rng_seed, 6) + enemy_atk).max(1);
+    state.player.hp -= damage;
+    state.push_log(
+        LogSpeaker::Combat,
+        format!("{} hits you for {} damage.", enemy_name, damage),
+    );
+    if state.player.hp > 0 {
+        return None;
+    }
+    state.push_log(LogSpeaker::Combat, "You fall unconscious.");
+    state.combat = None;
+    state.mode = crate::state::GameMode::Exploration;
+    let departures = hireling::apply_event(state, LoyaltyEvent::PlayerDowned);
+    state.autosave.request();
+    let effects = party_news(state, departures);
+    Some(DispatchResult::changed_with_many(effects))
+}
+
 fn check_triggers(state: &mut AppState, kind: TriggerKind) {
     let (x, y) = state.player_pos();
     let triggers = state.triggers.clone();
@@ -727,12 +1652,15 @@ fn check_triggers(state: &mut AppState, kind: TriggerKind) {
                     x: tx,
                     y: ty,
                     message,
+                    decision,
                 },
                 TriggerKind::OnEnter,
             ) if tx == x && ty == y => {
-                let id = format!("enter:{tx}:{ty}");
+                let id = trigger_id(state, "enter", tx, ty);
                 if state.fired_triggers.insert(id) {
+                    state.autosave.request();
                     state.push_log(LogSpeaker::System, message.clone());
+                    record_decision(state, decision, None);
                 }
             }
             (
@@ -740,12 +1668,15 @@ fn check_triggers(state: &mut AppState, kind: TriggerKind) {
                     x: tx,
                     y: ty,
                     message,
+                    decision,
                 },
                 TriggerKind::OnInteract,
             ) if tx == x && ty == y => {
-                let id = format!("interact:{tx}:{ty}");
+                let id = trigger_id(state, "interact", tx, ty);
                 if state.fired_triggers.insert(id) {
+                    state.autosave.request();
                     state.push_log(LogSpeaker::System, message.clone());
+                    record_decision(state, decision, None);
                 }
             }
             _ => {}
@@ -753,6 +1684,18 @@ fn check_triggers(state: &mut AppState, kind: TriggerKind) {
     }
 }
 
+fn record_decision(state: &mut AppState, decision: Option<DecisionSpec>, npc: Option<String>) {
+    let Some(line) = decision.and_then(|spec| decisions::record(state, spec, npc)) else {
+        return;
+    };
+    state.autosave.request();
+    state.push_log(LogSpeaker::System, line);
+}
+
+fn trigger_id(state: &AppState, kind: &str, x: u16, y: u16) -> String {
+    trigger_key(&state.floors.current, kind, x, y)
+}
+
 fn encounter_at(state: &AppState, x: u16, y: u16) -> Option<String> {
     state
         .encounters
@@ -766,6 +1709,7 @@ fn distance(ax: u16, ay: u16, bx: u16, by: u16) -> u16 {
 }
 
 fn save_effect(state: &mut AppState) -> Effect {
+    state.autosave.begin();
     let since = state.transcript_index;
     state.pending_transcript_index = Some(state.log.len());
     Effect::SaveGame {
@@ -774,6 +1718,12 @@ fn save_effect(state: &mut AppState) -> Effect {
     }
 }
 
+/// Background saves wait for a settled moment: in the world or a fight,
+/// with no LLM reply still on its way.
+fn autosave_ready(state: &AppState) -> bool {
+    state.pending_llm.is_none() && matches!(state.mode, GameMode::Exploration | GameMode::Combat)
+}
+
 fn is_missing_save(error: &str) -> bool {
     let lower = error.to_lowercase();
     lower.contains("no such file") || lower.contains("not found")
@@ -792,10 +1742,16 @@ enum TriggerKind {
 
 #[cfg(test)]
 mod tests {
-    use super::reducer;
+    use super::{reducer, MOVEMENT_PER_TURN};
     use crate::action::Action;
+    use crate::autosave::{Indicator, DEBOUNCE_TICKS};
+    use crate::effect::Effect;
     use crate::llm::schema::ActionInterpretation;
-    use crate::state::{AppState, EncounterState, GameMode, ItemStack, NpcState, Tile};
+    use crate::maneuver::{Condition, Maneuver};
+    use crate::state::{
+        AppState, CombatState, Direction, EncounterState, FloorState, GameMode, ItemStack,
+        ItemState, NpcState, PendingLlm, Stair, Tile,
+    };
 
     fn item(id: &str) -> ItemStack {
         ItemStack {
@@ -879,6 +1835,7 @@ mod tests {
             y: 0,
             persona: "stern".to_string(),
             dialogue_prompt: "halt".to_string(),
+            hire_cost: None,
         });
 
         let _ = reducer(&mut state, Action::Move(crate::state::Direction::Right));
@@ -899,7 +1856,9 @@ mod tests {
             x: 1,
             y: 0,
             hp: 10,
+            max_hp: 10,
             atk: 0,
+            boss: false,
             defeated: false,
         });
 
@@ -922,7 +1881,9 @@ mod tests {
             x: 1,
             y: 0,
             hp: 10,
+            max_hp: 10,
             atk: 0,
+            boss: false,
             defeated: false,
         });
 
@@ -938,6 +1899,52 @@ mod tests {
         );
     }
 
+    #[test]
+    fn enemy_that_spots_player_up_close_opens_combat() {
+        let mut state = AppState::default();
+        state.mode = GameMode::Exploration;
+        set_floor_map(&mut state, 5, 5);
+        state.set_player_pos(2, 2);
+        state.rng_seed = 1;
+        state.encounters.push(EncounterState {
+            id: "enc-1".to_string(),
+            name: "Bandit".to_string(),
+            x: 3,
+            y: 2,
+            hp: 10,
+            max_hp: 10,
+            atk: 0,
+            boss: false,
+            defeated: false,
+        });
+        state
+            .stealth
+            .detection
+            .insert("enc-1".to_string(), crate::stealth::DETECTION_MAX - 1);
+
+        let _ = reducer(&mut state, Action::Tick);
+        let combat = state.combat.as_ref().expect("combat should start");
+        assert_eq!(combat.round, 2, "the bandit should take the first turn");
+        assert!(state.stealth.detection.is_empty());
+    }
+
+    #[test]
+    fn sneaking_steps_wait_out_the_cooldown() {
+        let mut state = AppState::default();
+        state.mode = GameMode::Exploration;
+        set_floor_map(&mut state, 5, 5);
+        state.set_player_pos(2, 2);
+
+        let _ = reducer(&mut state, Action::ToggleSneak);
+        let _ = reducer(&mut state, Action::Move(crate::state::Direction::Right));
+        let _ = reducer(&mut state, Action::Move(crate::state::Direction::Right));
+        assert_eq!(state.player_pos(), (3, 2));
+
+        let _ = reducer(&mut state, Action::Tick);
+        let _ = reducer(&mut state, Action::Move(crate::state::Direction::Right));
+        assert_eq!(state.player_pos(), (4, 2));
+    }
+
     #[test]
     fn custom_action_accepts_non_skill_check_kind() {
         let mut state = AppState::default();
@@ -969,4 +1976,541 @@ mod tests {
             .any(|entry| entry.text == "Your throw lands cleanly."
                 || entry.text == "The torch misses wide."));
     }
+
+    fn stair(x: u16, y: u16, to_floor: &str) -> Stair {
+        Stair {
+            x,
+            y,
+            to_floor: to_floor.to_string(),
+            to_x: 0,
+            to_y: 0,
+        }
+    }
+
+    #[test]
+    fn stairs_swap_floors_and_keep_each_floors_state() {
+        let mut state = AppState::default();
+        state.mode = GameMode::Exploration;
+        set_floor_map(&mut state, 3, 1);
+        state.map.tiles[1] = Tile::Stairs;
+        state.stairs.push(stair(1, 0, "cellar"));
+        state.items.push(ItemState {
+            id: "torch".to_string(),
+            name: "Torch".to_string(),
+            x: 2,
+            y: 0,
+            qty: 1,
+        });
+        let mut cellar = state.map.clone();
+        cellar.name = "Cellar".to_string();
+        state.floors.stashed.insert(
+            "cellar".to_string(),
+            FloorState {
+                map: cellar,
+                npcs: Vec::new(),
+                items: Vec::new(),
+                encounters: Vec::new(),
+                triggers: Vec::new(),
+                stairs: vec![stair(0, 0, "main")],
+            },
+        );
+
+        let result = reducer(&mut state, Action::Move(crate::state::Direction::Right));
+        assert!(result.effects.is_empty());
+        assert!(state.autosave.due_in.is_some());
+        assert_eq!(state.floors.current, "cellar");
+        assert_eq!(state.map.name, "Cellar");
+        assert!(state.items.is_empty());
+        assert_eq!(state.player_pos(), (0, 0));
+        assert!(state.map.is_explored(0, 0));
+
+        // Back upstairs: the main floor comes back as it was left
+        let _ = reducer(&mut state, Action::Move(crate::state::Direction::Right));
+        let _ = reducer(&mut state, Action::Move(crate::state::Direction::Left));
+        assert_eq!(state.floors.current, "main");
+        assert_eq!(state.items.len(), 1);
+        assert!(state.floors.stashed.contains_key("cellar"));
+    }
+
+    #[test]
+    fn autosave_waits_out_a_burst_then_saves_once_and_shows_it() {
+        let mut state = AppState {
+            mode: GameMode::Exploration,
+            ..Default::default()
+        };
+        state.autosave.request();
+        state.autosave.request();
+
+        let saves: usize = (0..=DEBOUNCE_TICKS)
+            .map(|_| {
+                let result = reducer(&mut state, Action::Tick);
+                result
+                    .effects
+                    .iter()
+                    .filter(|effect| matches!(effect, Effect::SaveGame { .. }))
+                    .count()
+            })
+            .sum();
+        assert_eq!(saves, 1);
+        assert_eq!(state.autosave.indicator(), Some(Indicator::Saving));
+
+        let _ = reducer(&mut state, Action::SaveComplete);
+        assert_eq!(state.autosave.indicator(), Some(Indicator::Saved));
+        let result = reducer(&mut state, Action::Tick);
+        assert!(result.effects.is_empty());
+    }
+
+    #[test]
+    fn autosave_holds_while_an_llm_reply_is_pending() {
+        let mut state = AppState {
+            mode: GameMode::Exploration,
+            pending_llm: Some(PendingLlm::Dialogue {
+                npc_id: "garrick".to_string(),
+            }),
+            ..Default::default()
+        };
+        state.autosave.request();
+        for _ in 0..=DEBOUNCE_TICKS * 2 {
+            let result = reducer(&mut state, Action::Tick);
+            assert!(result.effects.is_empty());
+        }
+        state.pending_llm = None;
+        let result = reducer(&mut state, Action::Tick);
+        assert!(matches!(result.effects[..], [Effect::SaveGame { .. }]));
+    }
+
+    #[test]
+    fn stairs_to_missing_floor_are_blocked() {
+        let mut state = AppState::default();
+        state.mode = GameMode::Exploration;
+        set_floor_map(&mut state, 2, 1);
+        state.stairs.push(stair(1, 0, "attic"));
+
+        let _ = reducer(&mut state, Action::Move(crate::state::Direction::Right));
+        assert_eq!(state.floors.current, "main");
+        assert_eq!(
+            state.log.last().map(|entry| entry.text.as_str()),
+            Some("The stairs are blocked.")
+        );
+    }
+
+    fn npc(id: &str, name: &str, x: u16, y: u16) -> NpcState {
+        NpcState {
+            id: id.to_string(),
+            name: name.to_string(),
+            x,
+            y,
+            persona: "chatty".to_string(),
+            dialogue_prompt: String::new(),
+            hire_cost: None,
+        }
+    }
+
+    #[test]
+    fn idle_ticks_near_npc_request_one_banter_line() {
+        let mut state = AppState::default();
+        state.mode = GameMode::Exploration;
+        state.banter.cooldown = 0;
+        state.npcs.push(npc("mara", "Mara", 1, 0));
+
+        let requested = (0..1000)
+            .filter(|_| {
+                let result = reducer(&mut state, Action::Tick);
+                matches!(result.effects[..], [Effect::CallLlmBanter { .. }])
+            })
+            .count();
+        assert_eq!(requested, 1, "pending banter should block further requests");
+        assert_eq!(state.banter.pending.as_deref(), Some("mara"));
+    }
+
+    #[test]
+    fn banter_response_logs_short_line_and_resets_cooldown() {
+        let mut state = AppState::default();
+        state.mode = GameMode::Exploration;
+        state.npcs.push(npc("mara", "Mara", 1, 0));
+        state.banter.pending = Some("mara".to_string());
+        state.banter.cooldown = 0;
+
+        let _ = reducer(
+            &mut state,
+            Action::BanterResponse {
+                npc_id: "mara".to_string(),
+                line: "\"Fog's rolling in off the marsh again.\"".to_string(),
+            },
+        );
+
+        assert_eq!(state.banter.pending, None);
+        assert_eq!(state.banter.cooldown, crate::banter::BANTER_COOLDOWN_TICKS);
+        assert_eq!(state.banter.last_speaker.as_deref(), Some("mara"));
+        let entry = state.log.last().expect("banter logged");
+        assert_eq!(entry.text, "Mara: Fog's rolling in off the marsh again.");
+    }
+
+    #[test]
+    fn late_banter_is_dropped_outside_exploration() {
+        let mut state = AppState::default();
+        state.mode = GameMode::Combat;
+        state.npcs.push(npc("mara", "Mara", 1, 0));
+        state.banter.pending = Some("mara".to_string());
+
+        let _ = reducer(
+            &mut state,
+            Action::BanterResponse {
+                npc_id: "mara".to_string(),
+                line: "Quiet out here.".to_string(),
+            },
+        );
+
+        assert_eq!(state.banter.pending, None);
+        assert!(state.log.is_empty());
+    }
+
+    #[test]
+    fn recruiting_adjacent_npc_pays_fee_and_requests_a_remark() {
+        let mut state = AppState::default();
+        state.mode = GameMode::Exploration;
+        state.player.gold = 25;
+        let mut garrick = npc("garrick", "Garrick", 1, 0);
+        garrick.hire_cost = Some(20);
+        state.npcs.push(garrick);
+
+        let result = reducer(&mut state, Action::Recruit);
+        assert!(matches!(
+            result.effects[..],
+            [Effect::CallLlmHireling { .. }]
+        ));
+        assert!(state.autosave.due_in.is_some());
+        assert_eq!(state.player.gold, 5);
+        assert!(state.npcs.is_empty());
+        assert_eq!(state.party.hirelings[0].npc.name, "Garrick");
+
+        let _ = reducer(
+            &mut state,
+            Action::HirelingRemark {
+                name: "Garrick".to_string(),
+                line: "\"Keep your blade dry.\"".to_string(),
+            },
+        );
+        assert_eq!(
+            state.log.last().map(|entry| entry.text.as_str()),
+            Some("Garrick: Keep your blade dry.")
+        );
+    }
+
+    #[test]
+    fn npcs_without_a_fee_cannot_be_recruited() {
+        let mut state = AppState::default();
+        state.mode = GameMode::Exploration;
+        state.npcs.push(npc("mara", "Mara", 1, 0));
+
+        let result = reducer(&mut state, Action::Recruit);
+        assert!(result.effects.is_empty());
+        assert_eq!(state.npcs.len(), 1);
+        assert_eq!(
+            state.log.last().map(|entry| entry.text.as_str()),
+            Some("Mara isn't looking for work.")
+        );
+    }
+
+    #[test]
+    fn debug_actions_give_teleport_and_flag() {
+        let mut state = AppState::default();
+        state.mode = GameMode::Exploration;
+        set_floor_map(&mut state, 4, 4);
+        let mut cellar = state.map.clone();
+        cellar.name = "Cellar".to_string();
+        state.floors.stashed.insert(
+            "cellar".to_string(),
+            FloorState {
+                map: cellar,
+                npcs: vec![npc("rat_king", "Rat King", 3, 3)],
+                items: vec![ItemState {
+                    id: "cheese".to_string(),
+                    name: "Cheese".to_string(),
+                    x: 0,
+                    y: 0,
+                    qty: 1,
+                }],
+                encounters: Vec::new(),
+                triggers: Vec::new(),
+                stairs: Vec::new(),
+            },
+        );
+
+        let _ = reducer(
+            &mut state,
+            Action::DebugGiveItem {
+                id: "cheese".to_string(),
+                qty: 2,
+            },
+        );
+        assert_eq!(state.player.inventory[0].name, "Cheese");
+        assert_eq!(state.player.inventory[0].qty, 2);
+        let _ = reducer(
+            &mut state,
+            Action::DebugGiveItem {
+                id: "sword".to_string(),
+                qty: 1,
+            },
+        );
+        assert_eq!(
+            state.console.output.last().map(String::as_str),
+            Some("No item `sword` in this scenario.")
+        );
+
+        let _ = reducer(&mut state, Action::DebugTeleport { x: 9, y: 0 });
+        assert_eq!(state.player_pos(), (0, 0));
+        let _ = reducer(&mut state, Action::DebugTeleport { x: 2, y: 1 });
+        assert_eq!(state.player_pos(), (2, 1));
+
+        let _ = reducer(
+            &mut state,
+            Action::DebugTeleportToNpc("rat_king".to_string()),
+        );
+        assert_eq!(state.floors.current, "cellar");
+        assert_eq!(state.player_pos(), (3, 2));
+
+        let _ = reducer(
+            &mut state,
+            Action::DebugSetFlag {
+                flag: "enter:1:1".to_string(),
+                set: true,
+            },
+        );
+        assert!(state.fired_triggers.contains("enter:1:1"));
+        let _ = reducer(
+            &mut state,
+            Action::DebugSetFlag {
+                flag: "enter:1:1".to_string(),
+                set: false,
+            },
+        );
+        assert!(state.fired_triggers.is_empty());
+    }
+
+    /// A fight with a bandit standing right of the player on an open 7x7
+    /// floor. The player is far stronger, so every contest goes their way,
+    /// and the bandit can't land a hit.
+    fn engaged_bandit(state: &mut AppState, bandit_x: u16) {
+        state.mode = GameMode::Combat;
+        set_floor_map(state, 7, 7);
+        state.set_player_pos(1, 3);
+        state.player.stats.strength = 40;
+        state.rng_seed = 3;
+        state.encounters.push(EncounterState {
+            id: "enc-1".to_string(),
+            name: "Bandit".to_string(),
+            x: bandit_x,
+            y: 3,
+            hp: 30,
+            max_hp: 30,
+            atk: -30,
+            boss: false,
+            defeated: false,
+        });
+        state.combat = Some(CombatState {
+            enemy_id: "enc-1".to_string(),
+            player_turn: true,
+            movement_left: MOVEMENT_PER_TURN,
+            round: 1,
+            enemy_conditions: Vec::new(),
+            player_conditions: Vec::new(),
+            action_used: false,
+            enemy_reacted: false,
+            maneuver_menu: None,
+        });
+    }
+
+    fn enemy_conditions(state: &AppState) -> Vec<Condition> {
+        state
+            .combat
+            .as_ref()
+            .map(|combat| combat.enemy_conditions.clone())
+            .unwrap_or_default()
+    }
+
+    fn logged(state: &AppState, text: &str) -> bool {
+        state.log.iter().any(|entry| entry.text.contains(text))
+    }
+
+    #[test]
+    fn shoved_enemy_gets_back_up_on_its_turn() {
+        let mut state = AppState::default();
+        engaged_bandit(&mut state, 2);
+
+        let _ = reducer(&mut state, Action::CombatManeuver(Maneuver::Shove));
+        assert!(logged(&state, "You shove Bandit to the ground"));
+        assert!(logged(&state, "Bandit gets back up."));
+        assert!(enemy_conditions(&state).is_empty());
+        assert!(state
+            .combat
+            .as_ref()
+            .is_some_and(|combat| combat.player_turn));
+    }
+
+    #[test]
+    fn grappled_and_prone_enemy_stays_pinned() {
+        let mut state = AppState::default();
+        engaged_bandit(&mut state, 2);
+
+        let _ = reducer(&mut state, Action::CombatManeuver(Maneuver::Grapple));
+        assert_eq!(enemy_conditions(&state), [Condition::Grappled]);
+        let _ = reducer(&mut state, Action::CombatManeuver(Maneuver::Shove));
+        assert!(logged(&state, "Bandit struggles against your grip"));
+        assert_eq!(
+            enemy_conditions(&state),
+            [Condition::Grappled, Condition::Prone]
+        );
+
+        // Walking off lets go, and the bandit gets its swing at the player.
+        let _ = reducer(&mut state, Action::Move(Direction::Left));
+        assert!(logged(&state, "You let go of Bandit."));
+        assert!(logged(&state, "Bandit strikes as you pull away!"));
+        assert_eq!(enemy_conditions(&state), [Condition::Prone]);
+    }
+
+    #[test]
+    fn disengaging_avoids_the_opportunity_attack() {
+        let mut state = AppState::default();
+        engaged_bandit(&mut state, 2);
+
+        let _ = reducer(&mut state, Action::CombatManeuver(Maneuver::Disengage));
+        let combat = state.combat.as_ref().expect("still fighting");
+        assert!(combat.player_turn && combat.action_used);
+
+        let _ = reducer(&mut state, Action::Move(Direction::Left));
+        assert_eq!(state.player_pos(), (0, 3));
+        assert!(!logged(&state, "strikes as you pull away"));
+
+        let _ = reducer(&mut state, Action::CombatAttack);
+        assert!(logged(&state, "You've already used your action."));
+    }
+
+    #[test]
+    fn enemy_out_of_reach_closes_in() {
+        let mut state = AppState::default();
+        engaged_bandit(&mut state, 5);
+
+        let _ = reducer(&mut state, Action::CombatManeuver(Maneuver::Dodge));
+        assert!(logged(&state, "Bandit closes in."));
+        assert_eq!(
+            (state.encounters[0].x, state.encounters[0].y),
+            (2, 3),
+            "the bandit should stop next to the player"
+        );
+        assert!(logged(&state, "Bandit misses."));
+        assert!(state
+            .combat
+            .as_ref()
+            .is_some_and(|combat| combat.player_conditions.is_empty()));
+    }
+
+    #[test]
+    fn help_needs_a_hireling() {
+        let mut state = AppState::default();
+        engaged_bandit(&mut state, 2);
+
+        let _ = reducer(&mut state, Action::CombatManeuver(Maneuver::Help));
+        assert!(logged(&state, "There's no one with you to help."));
+        assert!(state
+            .combat
+            .as_ref()
+            .is_some_and(|combat| combat.round == 1));
+
+        state.party.hirelings.push(crate::hireling::Hireling {
+            npc: npc("garrick", "Garrick", 0, 0),
+            loyalty: 60,
+            wage: 2,
+            days_served: 0,
+        });
+        let _ = reducer(&mut state, Action::CombatManeuver(Maneuver::Help));
+        assert!(logged(&state, "You draw Bandit's eye"));
+        assert!(state
+            .combat
+            .as_ref()
+            .is_some_and(|combat| combat.round == 2));
+    }
+
+    fn waypoint(id: &str, x: u16, y: u16) -> crate::travel::Waypoint {
+        crate::travel::Waypoint {
+            id: id.to_string(),
+            name: id.to_string(),
+            floor: "main".to_string(),
+            x,
+            y,
+        }
+    }
+
+    #[test]
+    fn fast_travel_jumps_to_a_discovered_waypoint_and_takes_time() {
+        let mut state = AppState {
+            mode: GameMode::Exploration,
+            ..Default::default()
+        };
+        set_floor_map(&mut state, 30, 3);
+        state.waypoints = vec![waypoint("gate", 1, 1), waypoint("ford", 25, 1)];
+        state.set_player_pos(2, 1);
+
+        let _ = reducer(&mut state, Action::Move(Direction::Left));
+        assert_eq!(state.travel.discovered, vec!["gate".to_string()]);
+        assert_eq!(state.travel.clock, 1);
+
+        // Only discovered waypoints can be picked
+        state.pause_menu.selected = 2;
+        let _ = reducer(&mut state, Action::PauseConfirm);
+        let _ = reducer(&mut state, Action::TravelSelect(5));
+        assert_eq!(state.travel.menu, Some(0));
+        let _ = reducer(&mut state, Action::TravelConfirm);
+        assert_eq!(state.player_pos(), (1, 1));
+
+        state.travel.discovered.push("ford".to_string());
+        let _ = reducer(&mut state, Action::TravelSelect(1));
+        let result = reducer(&mut state, Action::TravelConfirm);
+        assert!(result.effects.is_empty());
+        assert!(state.autosave.due_in.is_some());
+        assert_eq!(state.player_pos(), (25, 1));
+        assert_eq!(state.travel.clock, 25);
+        assert_eq!(state.travel.menu, None);
+        assert_eq!(state.mode, GameMode::Exploration);
+    }
+
+    #[test]
+    fn fast_travel_can_end_in_an_ambush() {
+        let mut state = AppState {
+            mode: GameMode::Exploration,
+            rng_seed: 11,
+            ..Default::default()
+        };
+        set_floor_map(&mut state, 200, 3);
+        state.waypoints = vec![waypoint("gate", 0, 1), waypoint("ford", 199, 1)];
+        state.travel.discovered = vec!["gate".to_string(), "ford".to_string()];
+        state.travel_encounters = vec![crate::travel::TravelEncounter {
+            name: "Highwaymen".to_string(),
+            hp: 9,
+            atk: 0,
+        }];
+
+        let mut ambushed = false;
+        for _ in 0..20 {
+            let index = if state.player.x == 0 { 1 } else { 0 };
+            state.travel.menu = Some(index);
+            let _ = reducer(&mut state, Action::TravelConfirm);
+            if state.mode == GameMode::Combat {
+                ambushed = true;
+                break;
+            }
+        }
+        assert!(ambushed, "a long road should eventually be waylaid");
+        let combat = state.combat.as_ref().expect("combat");
+        let foe = state
+            .encounters
+            .iter()
+            .find(|encounter| encounter.id == combat.enemy_id)
+            .expect("ambusher on the map");
+        assert_eq!(foe.name, "Highwaymen");
+        assert_eq!(
+            foe.x.abs_diff(state.player.x) + foe.y.abs_diff(state.player.y),
+            1
+        );
+    }
 }
@@ -1,10 +1,17 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::path::Path;
 use tui_map::core::TileKind;
 use tui_map::parse::{parse_char_grid, Legend, ParseOptions, TrimMode};
 
-use crate::state::{EncounterState, ItemState, MapState, NpcState, Trigger};
+use crate::decisions::DecisionSpec;
+use crate::llm::template::PromptTemplates;
+use crate::state::{
+    EncounterState, FloorState, ItemState, MapState, NpcState, Stair, Trigger, MAIN_FLOOR,
+    STAIRS_TILE_ID,
+};
+use crate::travel::{TravelEncounter, Waypoint};
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct ScenarioRuntime {
@@ -14,6 +21,11 @@ pub struct ScenarioRuntime {
     pub items: Vec<ItemState>,
     pub encounters: Vec<EncounterState>,
     pub triggers: Vec<Trigger>,
+    pub stairs: Vec<Stair>,
+    /// Floors other than the starting one, keyed by floor id.
+    pub floors: BTreeMap<String, FloorState>,
+    pub waypoints: Vec<Waypoint>,
+    pub prompts: PromptTemplates,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -32,7 +44,52 @@ pub struct ScenarioManifest {
     #[serde(default)]
     pub triggers: Vec<TriggerSpec>,
     #[serde(default)]
+    pub stairs: Vec<StairSpec>,
+    /// Extra floors reached by stairs; the top-level map is the `main` floor.
+    #[serde(default)]
+    pub floors: Vec<FloorSpec>,
+    /// Fast-travel spots; each unlocks once the player steps on it.
+    #[serde(default)]
+    pub waypoints: Vec<WaypointSpec>,
+    /// Foes that may ambush a fast-travel journey; none means travel is always safe.
+    #[serde(default)]
+    pub travel_encounters: Vec<TravelEncounter>,
+    #[serde(default)]
     pub lore: Vec<String>,
+    #[serde(default)]
+    pub prompts: PromptFiles,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct FloorSpec {
+    pub id: String,
+    pub name: String,
+    pub map_path: String,
+    #[serde(default)]
+    pub npcs: Vec<NpcSpec>,
+    #[serde(default)]
+    pub items: Vec<ItemSpec>,
+    #[serde(default)]
+    pub encounters: Vec<EncounterSpec>,
+    #[serde(default)]
+    pub triggers: Vec<TriggerSpec>,
+    #[serde(default)]
+    pub stairs: Vec<StairSpec>,
+}
+
+/// Template file paths, relative to the scenario directory.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct PromptFiles {
+    #[serde(default)]
+    pub dialogue: Option<String>,
+    #[serde(default)]
+    pub interpretation: Option<String>,
+    #[serde(default)]
+    pub narration: Option<String>,
+    #[serde(default)]
+    pub banter: Option<String>,
+    #[serde(default)]
+    pub hireling: Option<String>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -55,6 +112,9 @@ pub struct NpcSpec {
     pub y: u16,
     pub persona: String,
     pub dialogue_prompt: String,
+    /// Gold it takes to recruit this NPC as a companion; omit for NPCs who won't join.
+    #[serde(default)]
+    pub hire_cost: Option<u32>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -74,13 +134,53 @@ pub struct EncounterSpec {
     pub y: u16,
     pub hp: i32,
     pub atk: i32,
+    #[serde(default)]
+    pub boss: bool,
+}
+
+/// Stair tile at `x`,`y` leading to `to_x`,`to_y` on floor `to_floor`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct StairSpec {
+    pub x: u16,
+    pub y: u16,
+    pub to_floor: String,
+    pub to_x: u16,
+    pub to_y: u16,
+}
+
+/// Fast-travel spot at `x`,`y` on `floor` (the main map if omitted).
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct WaypointSpec {
+    pub id: String,
+    pub name: String,
+    pub x: u16,
+    pub y: u16,
+    #[serde(default = "main_floor")]
+    pub floor: String,
+}
+
+fn main_floor() -> String {
+    MAIN_FLOOR.to_string()
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub enum TriggerSpec {
-    OnEnter { x: u16, y: u16, message: String },
-    OnInteract { x: u16, y: u16, message: String },
+    OnEnter {
+        x: u16,
+        y: u16,
+        message: String,
+        /// Recorded on the decision timeline when the trigger fires.
+        #[serde(default)]
+        decision: Option<DecisionSpec>,
+    },
+    OnInteract {
+        x: u16,
+        y: u16,
+        message: String,
+        #[serde(default)]
+        decision: Option<DecisionSpec>,
+    },
 }
 
 pub async fn load_scenario(path: &Path) -> Result<ScenarioRuntime, String> {
@@ -95,10 +195,73 @@ pub async fn load_scenario(path: &Path) -> Result<ScenarioRuntime, String> {
     let map_str = tokio::fs::read_to_string(&map_path)
         .await
         .map_err(|e| format!("Failed to read {}: {}", map_path.display(), e))?;
-    let map = parse_map(&manifest, &map_str)?;
+    let map = parse_map(&manifest.name, &manifest.legend, &map_str)?;
+    let prompts = load_prompts(path, &manifest.prompts).await?;
+
+    let mut floors = BTreeMap::new();
+    for spec in &manifest.floors {
+        let floor = load_floor(path, &manifest.legend, spec).await?;
+        if spec.id == MAIN_FLOOR || floors.insert(spec.id.clone(), floor).is_some() {
+            return Err(format!("Duplicate floor id: {}", spec.id));
+        }
+    }
+    let stairs = stair_states(&manifest.stairs);
+    let links = stairs
+        .iter()
+        .chain(floors.values().flat_map(|floor| floor.stairs.iter()));
+    for stair in links {
+        if stair.to_floor != MAIN_FLOOR && !floors.contains_key(&stair.to_floor) {
+            return Err(format!(
+                "Stairs at {},{} lead to unknown floor: {}",
+                stair.x, stair.y, stair.to_floor
+            ));
+        }
+    }
+
+    for waypoint in &manifest.waypoints {
+        if waypoint.floor != MAIN_FLOOR && !floors.contains_key(&waypoint.floor) {
+            return Err(format!(
+                "Waypoint {} is on unknown floor: {}",
+                waypoint.id, waypoint.floor
+            ));
+        }
+    }
+
+    Ok(ScenarioRuntime {
+        npcs: npc_states(&manifest.npcs),
+        items: item_states(&manifest.items),
+        encounters: encounter_states(&manifest.encounters),
+        triggers: trigger_states(&manifest.triggers),
+        stairs,
+        floors,
+        waypoints: waypoint_states(&manifest.waypoints),
+        manifest,
+        map,
+        prompts,
+    })
+}
+
+async fn load_floor(
+    dir: &Path,
+    legend: &[LegendEntry],
+    spec: &FloorSpec,
+) -> Result<FloorState, String> {
+    let map_path = dir.join(&spec.map_path);
+    let map_str = tokio::fs::read_to_string(&map_path)
+        .await
+        .map_err(|e| format!("Failed to read {}: {}", map_path.display(), e))?;
+    Ok(FloorState {
+        map: parse_map(&spec.name, legend, &map_str)?,
+        npcs: npc_states(&spec.npcs),
+        items: item_states(&spec.items),
+        encounters: encounter_states(&spec.encounters),
+        triggers: trigger_states(&spec.triggers),
+        stairs: stair_states(&spec.stairs),
+    })
+}
 
-    let npcs = manifest
-        .npcs
+fn npc_states(specs: &[NpcSpec]) -> Vec<NpcState> {
+    specs
         .iter()
         .map(|spec| NpcState {
             id: spec.id.clone(),
@@ -107,11 +270,13 @@ pub async fn load_scenario(path: &Path) -> Result<ScenarioRuntime, String> {
             y: spec.y,
             persona: spec.persona.clone(),
             dialogue_prompt: spec.dialogue_prompt.clone(),
+            hire_cost: spec.hire_cost,
         })
-        .collect();
+        .collect()
+}
 
-    let items = manifest
-        .items
+fn item_states(specs: &[ItemSpec]) -> Vec<ItemState> {
+    specs
         .iter()
         .map(|spec| ItemState {
             id: spec.id.clone(),
@@ -120,10 +285,11 @@ pub async fn load_scenario(path: &Path) -> Result<ScenarioRuntime, String> {
             y: spec.y,
             qty: spec.qty,
         })
-        .collect();
+        .collect()
+}
 
-    let encounters = manifest
-        .encounters
+fn encounter_states(specs: &[EncounterSpec]) -> Vec<EncounterState> {
+    specs
         .iter()
         .map(|spec| EncounterState {
             id: spec.id.clone(),
@@ -131,42 +297,95 @@ pub async fn load_scenario(path: &Path) -> Result<ScenarioRuntime, String> {
             x: spec.x,
             y: spec.y,
             hp: spec.hp,
+            max_hp: spec.hp,
             atk: spec.atk,
+            boss: spec.boss,
             defeated: false,
         })
-        .collect();
+        .collect()
+}
 
-    let triggers = manifest
-        .triggers
+fn trigger_states(specs: &[TriggerSpec]) -> Vec<Trigger> {
+    specs
         .iter()
         .map(|spec| match spec {
-            TriggerSpec::OnEnter { x, y, message } => Trigger::OnEnter {
+            TriggerSpec::OnEnter {
+                x,
+                y,
+                message,
+                decision,
+            } => Trigger::OnEnter {
                 x: *x,
                 y: *y,
                 message: message.clone(),
+                decision: decision.clone(),
             },
-            TriggerSpec::OnInteract { x, y, message } => Trigger::OnInteract {
+            TriggerSpec::OnInteract {
+                x,
+                y,
+                message,
+                decision,
+            } => Trigger::OnInteract {
                 x: *x,
                 y: *y,
                 message: message.clone(),
+                decision: decision.clone(),
             },
         })
-        .collect();
+        .collect()
+}
 
-    Ok(ScenarioRuntime {
-        manifest,
-        map,
-        npcs,
-        items,
-        encounters,
-        triggers,
+fn stair_states(specs: &[StairSpec]) -> Vec<Stair> {
+    specs
+        .iter()
+        .map(|spec| Stair {
+            x: spec.x,
+            y: spec.y,
+            to_floor: spec.to_floor.clone(),
+            to_x: spec.to_x,
+            to_y: spec.to_y,
+        })
+        .collect()
+}
+
+fn waypoint_states(specs: &[WaypointSpec]) -> Vec<Waypoint> {
+    specs
+        .iter()
+        .map(|spec| Waypoint {
+            id: spec.id.clone(),
+            name: spec.name.clone(),
+            floor: spec.floor.clone(),
+            x: spec.x,
+            y: spec.y,
+        })
+        .collect()
+}
+
+async fn load_prompts(dir: &Path, files: &PromptFiles) -> Result<PromptTemplates, String> {
+    Ok(PromptTemplates {
+        dialogue: load_prompt(dir, files.dialogue.as_deref()).await?,
+        interpretation: load_prompt(dir, files.interpretation.as_deref()).await?,
+        narration: load_prompt(dir, files.narration.as_deref()).await?,
+        banter: load_prompt(dir, files.banter.as_deref()).await?,
+        hireling: load_prompt(dir, files.hireling.as_deref()).await?,
     })
 }
 
-fn parse_map(manifest: &ScenarioManifest, map_str: &str) -> Result<MapState, String> {
-    let legend = build_legend(&manifest.legend)?;
+async fn load_prompt(dir: &Path, file: Option<&str>) -> Result<Option<String>, String> {
+    let Some(file) = file else {
+        return Ok(None);
+    };
+    let prompt_path = dir.join(file);
+    let template = tokio::fs::read_to_string(&prompt_path)
+        .await
+        .map_err(|e| format!("Failed to read {}: {}", prompt_path.display(), e))?;
+    Ok(Some(template))
+}
+
+fn parse_map(name: &str, legend: &[LegendEntry], map_str: &str) -> Result<MapState, String> {
+    let legend = build_legend(legend)?;
     let grid = parse_char_grid(
-        &manifest.name,
+        name,
         map_str,
         &legend,
         &ParseOptions {
@@ -204,6 +423,7 @@ fn tile_from_name(name: &str) -> Option<TileKind> {
         "floor" => Some(TileKind::Floor),
         "wall" => Some(TileKind::Wall),
         "water" => Some(TileKind::Water),
+        "stairs" => Some(TileKind::Custom(STAIRS_TILE_ID)),
         _ => None,
     }
 }
@@ -221,4 +441,21 @@ mod tests {
         let legend = build_legend(&entries).expect("legend");
         assert_eq!(legend.tile_for('#'), Some(TileKind::Wall));
     }
+
+    #[test]
+    fn stairs_legend_parses_to_stair_tiles() {
+        let entries = vec![
+            LegendEntry {
+                ch: ".".to_string(),
+                tile: "floor".to_string(),
+            },
+            LegendEntry {
+                ch: ">".to_string(),
+                tile: "stairs".to_string(),
+            },
+        ];
+        let map = parse_map("Cellar", &entries, "..>\n...\n").expect("map");
+        assert_eq!(map.tile(2, 0), crate::state::Tile::Stairs);
+        assert!(map.is_walkable(2, 0));
+    }
 }
@@ -1,12 +1,21 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
 use std::time::{SystemTime, UNIX_EPOCH};
 use tui_map::core::{MapGrid, MapRead, MapSize, TileKind};
 use tui_dispatch_debug::debug::{DebugSection, DebugState};
 
+use crate::autosave::AutosaveState;
+use crate::banter::BanterState;
+use crate::console::ConsoleState;
+use crate::decisions::{DecisionLog, DecisionSpec};
+use crate::hireling::{PartyState, STARTING_GOLD};
+use crate::llm::template::PromptTemplates;
 use crate::llm::Provider;
+use crate::maneuver::Condition;
 use crate::rules::{Ability, AbilityScores};
+use crate::stealth::StealthState;
+use crate::travel::{TravelEncounter, TravelState, Waypoint};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum GameMode {
@@ -27,6 +36,13 @@ pub enum Direction {
     Right,
 }
 
+/// `TileKind::Custom` id used for stair tiles.
+pub const STAIRS_TILE_ID: u16 = 1;
+/// Tiles around the player revealed on every step.
+pub const EXPLORE_RADIUS: u16 = 4;
+/// Floor id of the scenario's top-level map.
+pub const MAIN_FLOOR: &str = "main";
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum Tile {
     Grass,
@@ -34,6 +50,7 @@ pub enum Tile {
     Floor,
     Wall,
     Water,
+    Stairs,
 }
 
 impl Tile {
@@ -44,6 +61,7 @@ impl Tile {
             Tile::Floor => TileKind::Floor,
             Tile::Wall => TileKind::Wall,
             Tile::Water => TileKind::Water,
+            Tile::Stairs => TileKind::Custom(STAIRS_TILE_ID),
         }
     }
 
@@ -55,6 +73,7 @@ impl Tile {
             TileKind::Floor => Tile::Floor,
             TileKind::Wall => Tile::Wall,
             TileKind::Water => Tile::Water,
+            TileKind::Custom(STAIRS_TILE_ID) => Tile::Stairs,
             TileKind::Custom(_) => Tile::Floor,
         }
     }
@@ -66,6 +85,9 @@ pub struct MapState {
     pub width: u16,
     pub height: u16,
     pub tiles: Vec<Tile>,
+    /// Per-tile fog of war, indexed like `tiles`; empty until the first reveal
+    #[serde(default)]
+    pub explored: Vec<bool>,
 }
 
 impl MapState {
@@ -75,6 +97,7 @@ impl MapState {
             width: grid.size.width,
             height: grid.size.height,
             tiles: grid.tiles.into_iter().map(Tile::from_tile_kind).collect(),
+            explored: Vec::new(),
         }
     }
 
@@ -87,7 +110,33 @@ impl MapState {
     }
 
     pub fn is_walkable(&self, x: u16, y: u16) -> bool {
-        matches!(self.tile(x, y), Tile::Grass | Tile::Road | Tile::Floor)
+        matches!(
+            self.tile(x, y),
+            Tile::Grass | Tile::Road | Tile::Floor | Tile::Stairs
+        )
+    }
+
+    pub fn is_explored(&self, x: u16, y: u16) -> bool {
+        if x >= self.width || y >= self.height {
+            return false;
+        }
+        self.explored.get(self.index(x, y)).copied().unwrap_or(false)
+    }
+
+    /// Mark every tile within `radius` (Chebyshev) of `(x, y)` as explored.
+    pub fn reveal(&mut self, x: u16, y: u16, radius: u16) {
+        let len = self.width as usize * self.height as usize;
+        if self.explored.len() != len {
+            self.explored.resize(len, false);
+        }
+        let max_x = x.saturating_add(radius).min(self.width.saturating_sub(1));
+        let max_y = y.saturating_add(radius).min(self.height.saturating_sub(1));
+        for ty in y.saturating_sub(radius)..=max_y {
+            for tx in x.saturating_sub(radius)..=max_x {
+                let idx = self.index(tx, ty);
+                self.explored[idx] = true;
+            }
+        }
     }
 
     fn index(&self, x: u16, y: u16) -> usize {
@@ -113,6 +162,9 @@ pub struct NpcState {
     pub y: u16,
     pub persona: String,
     pub dialogue_prompt: String,
+    /// Signing fee in gold for NPCs willing to join the party; `None` won't be hired
+    #[serde(default)]
+    pub hire_cost: Option<u32>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -131,16 +183,92 @@ pub struct EncounterState {
     pub x: u16,
     pub y: u16,
     pub hp: i32,
+    /// Starting HP, for health bars; older saves fall back to current HP
+    #[serde(default)]
+    pub max_hp: i32,
     pub atk: i32,
     #[serde(default)]
+    pub boss: bool,
+    #[serde(default)]
     pub defeated: bool,
 }
 
+impl EncounterState {
+    /// Remaining health in `0.0..=1.0`.
+    pub fn hp_ratio(&self) -> f32 {
+        let max = self.max_hp.max(self.hp).max(1);
+        self.hp.clamp(0, max) as f32 / max as f32
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
 #[serde(tag = "kind", rename_all = "snake_case")]
 pub enum Trigger {
-    OnEnter { x: u16, y: u16, message: String },
-    OnInteract { x: u16, y: u16, message: String },
+    OnEnter {
+        x: u16,
+        y: u16,
+        message: String,
+        #[serde(default)]
+        decision: Option<DecisionSpec>,
+    },
+    OnInteract {
+        x: u16,
+        y: u16,
+        message: String,
+        #[serde(default)]
+        decision: Option<DecisionSpec>,
+    },
+}
+
+/// Key a trigger at `x`,`y` on `floor` is recorded under in `fired_triggers`
+/// once it fires; `kind` is `enter` or `interact`. The main floor keeps the
+/// unprefixed form older saves use.
+pub fn trigger_key(floor: &str, kind: &str, x: u16, y: u16) -> String {
+    if floor == MAIN_FLOOR {
+        format!("{kind}:{x}:{y}")
+    } else {
+        format!("{floor}:{kind}:{x}:{y}")
+    }
+}
+
+/// Stair tile linking to a spot on another floor.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Stair {
+    pub x: u16,
+    pub y: u16,
+    pub to_floor: String,
+    pub to_x: u16,
+    pub to_y: u16,
+}
+
+/// Everything that belongs to one floor while the player is elsewhere.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct FloorState {
+    pub map: MapState,
+    pub npcs: Vec<NpcState>,
+    pub items: Vec<ItemState>,
+    pub encounters: Vec<EncounterState>,
+    pub triggers: Vec<Trigger>,
+    pub stairs: Vec<Stair>,
+}
+
+/// Map registry: which floor is active, and the stashed state of the others.
+///
+/// The active floor lives in `AppState`'s own map/npcs/items/... fields so the
+/// rest of the game never has to look a floor up.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct FloorRegistry {
+    pub current: String,
+    pub stashed: BTreeMap<String, FloorState>,
+}
+
+impl Default for FloorRegistry {
+    fn default() -> Self {
+        Self {
+            current: MAIN_FLOOR.to_string(),
+            stashed: BTreeMap::new(),
+        }
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -155,6 +283,8 @@ pub struct PlayerState {
     pub stats: AbilityScores,
     #[serde(default)]
     pub inventory: Vec<ItemStack>,
+    #[serde(default)]
+    pub gold: u32,
 }
 
 impl Default for PlayerState {
@@ -169,6 +299,7 @@ impl Default for PlayerState {
             max_hp: 10,
             stats: AbilityScores::default(),
             inventory: Vec::new(),
+            gold: STARTING_GOLD,
         }
     }
 }
@@ -205,6 +336,21 @@ pub struct CombatState {
     pub player_turn: bool,
     pub movement_left: u8,
     pub round: u32,
+    /// Conditions on the engaged enemy; they all end with the fight.
+    #[serde(default)]
+    pub enemy_conditions: Vec<Condition>,
+    #[serde(default)]
+    pub player_conditions: Vec<Condition>,
+    /// The player's action for the turn went to something other than an
+    /// attack that ends it, e.g. Disengage.
+    #[serde(default)]
+    pub action_used: bool,
+    /// The enemy took its opportunity attack since its last turn.
+    #[serde(default)]
+    pub enemy_reacted: bool,
+    /// Selected row while the maneuvers menu is open.
+    #[serde(default)]
+    pub maneuver_menu: Option<usize>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -260,6 +406,8 @@ pub struct ScenarioManifestSummary {
     pub name: String,
     #[serde(default)]
     pub lore: Vec<String>,
+    #[serde(default)]
+    pub prompts: PromptTemplates,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -278,6 +426,21 @@ pub struct AppState {
     pub items: Vec<ItemState>,
     pub encounters: Vec<EncounterState>,
     pub triggers: Vec<Trigger>,
+    #[serde(default)]
+    pub stairs: Vec<Stair>,
+    #[serde(default)]
+    pub floors: FloorRegistry,
+    /// Fast-travel spots on every floor, discovered or not.
+    #[serde(default)]
+    pub waypoints: Vec<Waypoint>,
+    /// What may waylay the player on a fast-travel journey.
+    #[serde(default)]
+    pub travel_encounters: Vec<TravelEncounter>,
+    #[serde(default)]
+    pub travel: TravelState,
+    /// Major choices so far; the journal's timeline.
+    #[serde(default)]
+    pub decisions: DecisionLog,
     pub fired_triggers: HashSet<String>,
     pub dialogue: DialogueState,
     pub custom_action: CustomActionState,
@@ -294,7 +457,17 @@ pub struct AppState {
     pub scenario: Option<ScenarioManifestSummary>,
     pub pending_llm: Option<PendingLlm>,
     #[serde(default)]
+    pub banter: BanterState,
+    #[serde(default)]
+    pub party: PartyState,
+    #[serde(default)]
+    pub stealth: StealthState,
+    #[serde(default)]
+    pub console: ConsoleState,
+    #[serde(default)]
     pub spinner_frame: u8,
+    #[serde(default)]
+    pub autosave: AutosaveState,
     pub transcript_index: usize,
     pub pending_transcript_index: Option<usize>,
     pub rng_seed: u64,
@@ -325,12 +498,19 @@ impl AppState {
                 width: 1,
                 height: 1,
                 tiles: vec![Tile::Floor],
+                explored: Vec::new(),
             },
             player: PlayerState::default(),
             npcs: Vec::new(),
             items: Vec::new(),
             encounters: Vec::new(),
             triggers: Vec::new(),
+            stairs: Vec::new(),
+            floors: FloorRegistry::default(),
+            waypoints: Vec::new(),
+            travel_encounters: Vec::new(),
+            travel: TravelState::default(),
+            decisions: DecisionLog::default(),
             fired_triggers: HashSet::new(),
             dialogue: DialogueState {
                 active_npc: None,
@@ -357,7 +537,12 @@ impl AppState {
             log_scroll: 0,
             scenario: None,
             pending_llm: None,
+            banter: BanterState::default(),
+            party: PartyState::default(),
+            stealth: StealthState::default(),
+            console: ConsoleState::default(),
             spinner_frame: 0,
+            autosave: AutosaveState::default(),
             transcript_index: 0,
             pending_transcript_index: None,
             rng_seed: seed_from_time(),
@@ -400,6 +585,33 @@ impl AppState {
     pub fn ability_score(&self, ability: Ability) -> i32 {
         self.player.stats.get(ability)
     }
+
+    pub fn stair_at(&self, x: u16, y: u16) -> Option<&Stair> {
+        self.stairs.iter().find(|stair| stair.x == x && stair.y == y)
+    }
+
+    pub fn reveal_around_player(&mut self) {
+        let (x, y) = self.player_pos();
+        self.map.reveal(x, y, EXPLORE_RADIUS);
+    }
+
+    /// Stash the active floor and bring `id` in; false if no such floor is stored.
+    pub fn enter_floor(&mut self, id: &str) -> bool {
+        let Some(next) = self.floors.stashed.remove(id) else {
+            return false;
+        };
+        let previous = FloorState {
+            map: std::mem::replace(&mut self.map, next.map),
+            npcs: std::mem::replace(&mut self.npcs, next.npcs),
+            items: std::mem::replace(&mut self.items, next.items),
+            encounters: std::mem::replace(&mut self.encounters, next.encounters),
+            triggers: std::mem::replace(&mut self.triggers, next.triggers),
+            stairs: std::mem::replace(&mut self.stairs, next.stairs),
+        };
+        let left = std::mem::replace(&mut self.floors.current, id.to_string());
+        self.floors.stashed.insert(left, previous);
+        true
+    }
 }
 
 impl DebugState for AppState {
@@ -408,14 +620,43 @@ impl DebugState for AppState {
             DebugSection::new("Mode")
                 .entry("mode", format!("{:?}", self.mode))
                 .entry("pending_llm", format!("{:?}", self.pending_llm)),
+            DebugSection::new("Banter")
+                .entry("enabled", self.banter.enabled.to_string())
+                .entry("cooldown", self.banter.cooldown.to_string())
+                .entry("pending", format!("{:?}", self.banter.pending))
+                .entry("last_speaker", format!("{:?}", self.banter.last_speaker)),
             DebugSection::new("Player")
                 .entry("name", self.player.name.clone())
                 .entry("class", self.player.class_name.clone())
                 .entry("background", self.player.background.clone())
                 .entry("pos", format!("{},{}", self.player.x, self.player.y))
-                .entry("hp", format!("{}/{}", self.player.hp, self.player.max_hp)),
+                .entry("hp", format!("{}/{}", self.player.hp, self.player.max_hp))
+                .entry("gold", self.player.gold.to_string()),
+            DebugSection::new("Party")
+                .entry("hirelings", self.party.hirelings.len().to_string())
+                .entry("steps", self.party.steps.to_string()),
+            DebugSection::new("Travel")
+                .entry("clock", crate::travel::clock_label(self.travel.clock))
+                .entry("discovered", format!("{:?}", self.travel.discovered))
+                .entry("ambushes", self.travel.ambushes.to_string())
+                .entry("menu", format!("{:?}", self.travel.menu)),
+            DebugSection::new("Decisions")
+                .entry("entries", self.decisions.entries.len().to_string())
+                .entry("flags", crate::decisions::flags(self).join(", "))
+                .entry("menu", format!("{:?}", self.decisions.menu)),
+            DebugSection::new("Stealth")
+                .entry("sneaking", self.stealth.sneaking.to_string())
+                .entry("noise", self.stealth.noise.to_string())
+                .entry("detection", format!("{:?}", self.stealth.detection))
+                .entry("chaser", format!("{:?}", self.stealth.chaser)),
+            DebugSection::new("Console")
+                .entry("enabled", self.console.enabled.to_string())
+                .entry("open", self.console.open.to_string())
+                .entry("input", self.console.input.clone()),
             DebugSection::new("Scenario")
                 .entry("map", self.map.name.clone())
+                .entry("floor", self.floors.current.clone())
+                .entry("other_floors", self.floors.stashed.len().to_string())
                 .entry("npcs", self.npcs.len().to_string())
                 .entry("items", self.items.len().to_string())
                 .entry("encounters", self.encounters.len().to_string()),
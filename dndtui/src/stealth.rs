@@ -0,0 +1,361 @@
+use std::collections::BTreeMap;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::rules::{ability_modifier, skill_to_ability, Skill};
+use crate::state::{AppState, GameMode, Tile};
+
+/// A detection meter at this value means the enemy has seen the player.
+pub const DETECTION_MAX: u8 = 100;
+/// Noise is kept on a 0..=MAX_NOISE scale.
+pub const MAX_NOISE: u8 = 10;
+/// Manhattan distance within which an enemy can notice the player at all.
+const SIGHT_RADIUS: u16 = 7;
+/// Noise added by one step at a walk and while sneaking.
+const WALK_NOISE: u8 = 4;
+const SNEAK_NOISE: u8 = 1;
+/// Noise lost per tick (200ms) of quiet.
+const NOISE_DECAY: u8 = 1;
+/// Detection lost per tick while an enemy has nothing to go on.
+const DETECTION_DECAY: u8 = 3;
+/// A chasing enemy moves one tile every this many ticks.
+const CHASE_STEP_TICKS: u8 = 2;
+/// A chase is over once the player is this far from the chaser.
+const CHASE_LOSE_DISTANCE: u16 = 12;
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct StealthState {
+    pub sneaking: bool,
+    /// Noise from recent footsteps, `0..=MAX_NOISE`; fades while standing still.
+    pub noise: u8,
+    /// Detection meter per encounter id, `0..=DETECTION_MAX`. Missing means zero.
+    pub detection: BTreeMap<String, u8>,
+    /// Enemy that spotted the player from a distance and is closing in.
+    pub chaser: Option<String>,
+    /// Ticks until the chaser takes its next step.
+    pub chase_clock: u8,
+    /// Ticks before another sneaking step is allowed; sneaking is half speed.
+    pub sneak_cooldown: u8,
+}
+
+/// How exposed the player's tile is.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Light {
+    /// In the shadow of a wall; enemies can only hear the player.
+    Dark,
+    Dim,
+    /// Out on a road.
+    Bright,
+}
+
+impl Light {
+    pub fn label(self) -> &'static str {
+        match self {
+            Light::Dark => "Dark",
+            Light::Dim => "Dim",
+            Light::Bright => "Bright",
+        }
+    }
+
+    fn sight_weight(self) -> u16 {
+        match self {
+            Light::Dark => 0,
+            Light::Dim => 2,
+            Light::Bright => 4,
+        }
+    }
+}
+
+/// What the detection meters led to this tick.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Alert {
+    /// Spotted from a distance; the enemy gives chase.
+    Spotted { enemy_id: String },
+    /// Spotted up close, or caught at the end of a chase.
+    Caught { enemy_id: String },
+    /// The player got far enough away for the chaser to give up.
+    LostTrail { enemy_id: String },
+}
+
+pub fn light_at(state: &AppState, x: u16, y: u16) -> Light {
+    let beside_wall = [(0, -1), (0, 1), (-1, 0), (1, 0)].iter().any(|&(dx, dy)| {
+        match (x.checked_add_signed(dx), y.checked_add_signed(dy)) {
+            (Some(nx), Some(ny)) => state.map.tile(nx, ny) == Tile::Wall,
+            _ => false,
+        }
+    });
+    if beside_wall {
+        return Light::Dark;
+    }
+    match state.map.tile(x, y) {
+        Tile::Road | Tile::Stairs => Light::Bright,
+        _ => Light::Dim,
+    }
+}
+
+/// Whether a step may be taken now. Sneaking steps are spaced a tick apart.
+pub fn can_step(state: &AppState) -> bool {
+    !state.stealth.sneaking || state.stealth.sneak_cooldown == 0
+}
+
+/// Record the noise of one exploration step.
+pub fn on_step(state: &mut AppState) {
+    let stealth = &mut state.stealth;
+    let noise = if stealth.sneaking {
+        stealth.sneak_cooldown = 1;
+        SNEAK_NOISE
+    } else {
+        WALK_NOISE
+    };
+    stealth.noise = stealth.noise.saturating_add(noise).min(MAX_NOISE);
+}
+
+pub fn toggle_sneak(state: &mut AppState) -> bool {
+    state.stealth.sneaking = !state.stealth.sneaking;
+    state.stealth.sneak_cooldown = 0;
+    state.stealth.sneaking
+}
+
+/// Forget every meter, e.g. after changing floors.
+pub fn reset(state: &mut AppState) {
+    let stealth = &mut state.stealth;
+    stealth.noise = 0;
+    stealth.detection.clear();
+    stealth.chaser = None;
+}
+
+/// Detection gained in one tick by an enemy `distance` tiles away.
+///
+/// Sight scales with closeness and light, hearing with closeness and noise,
+/// and the player's Stealth modifier shaves a little off both.
+pub fn detection_gain(distance: u16, light: Light, noise: u8, stealth_mod: i32) -> u8 {
+    if distance > SIGHT_RADIUS {
+        return 0;
+    }
+    let closeness = SIGHT_RADIUS + 1 - distance;
+    let sight = closeness * light.sight_weight();
+    let hearing = u16::from(noise) * closeness / (SIGHT_RADIUS + 1);
+    let gain = (sight + hearing).saturating_sub(stealth_mod.max(0) as u16);
+    gain.min(u16::from(DETECTION_MAX)) as u8
+}
+
+/// Advance noise, meters and any chase by one tick.
+pub fn tick(state: &mut AppState) -> Option<Alert> {
+    if state.mode != GameMode::Exploration || state.pause_menu.is_open {
+        return None;
+    }
+    state.stealth.sneak_cooldown = state.stealth.sneak_cooldown.saturating_sub(1);
+    let alert = match state.stealth.chaser.clone() {
+        Some(enemy_id) => advance_chase(state, enemy_id),
+        None => fill_meters(state),
+    };
+    state.stealth.noise = state.stealth.noise.saturating_sub(NOISE_DECAY);
+    alert
+}
+
+fn fill_meters(state: &mut AppState) -> Option<Alert> {
+    let (px, py) = state.player_pos();
+    let light = light_at(state, px, py);
+    let stealth_mod = ability_modifier(state.ability_score(skill_to_ability(Skill::Stealth)));
+    let noise = state.stealth.noise;
+
+    let mut meters = BTreeMap::new();
+    let mut spotted = None;
+    for enemy in state.encounters.iter().filter(|enemy| !enemy.defeated) {
+        let distance = enemy.x.abs_diff(px) + enemy.y.abs_diff(py);
+        let previous = state.stealth.detection.get(&enemy.id).copied().unwrap_or(0);
+        let gain = detection_gain(distance, light, noise, stealth_mod);
+        let meter = if gain == 0 {
+            previous.saturating_sub(DETECTION_DECAY)
+        } else {
+            previous.saturating_add(gain).min(DETECTION_MAX)
+        };
+        if meter == DETECTION_MAX && spotted.is_none() {
+            spotted = Some((enemy.id.clone(), distance));
+        }
+        if meter > 0 {
+            meters.insert(enemy.id.clone(), meter);
+        }
+    }
+    state.stealth.detection = meters;
+
+    let (enemy_id, distance) = spotted?;
+    if distance <= 1 {
+        return Some(Alert::Caught { enemy_id });
+    }
+    state.stealth.chaser = Some(enemy_id.clone());
+    state.stealth.chase_clock = CHASE_STEP_TICKS;
+    Some(Alert::Spotted { enemy_id })
+}
+
+/// Step the chaser toward the player; it catches them once adjacent.
+fn advance_chase(state: &mut AppState, enemy_id: String) -> Option<Alert> {
+    let (px, py) = state.player_pos();
+    let Some(enemy) = state
+        .encounters
+        .iter()
+        .find(|enemy| enemy.id == enemy_id && !enemy.defeated)
+    else {
+        state.stealth.chaser = None;
+        return None;
+    };
+    let (ex, ey) = (enemy.x, enemy.y);
+    let distance = ex.abs_diff(px) + ey.abs_diff(py);
+    if distance > CHASE_LOSE_DISTANCE {
+        state.stealth.chaser = None;
+        state
+            .stealth
+            .detection
+            .insert(enemy_id.clone(), DETECTION_MAX / 2);
+        return Some(Alert::LostTrail { enemy_id });
+    }
+    if distance <= 1 {
+        return Some(Alert::Caught { enemy_id });
+    }
+    state.stealth.chase_clock = state.stealth.chase_clock.saturating_sub(1);
+    if state.stealth.chase_clock > 0 {
+        return None;
+    }
+    state.stealth.chase_clock = CHASE_STEP_TICKS;
+    if let Some((nx, ny)) = chase_step(state, (ex, ey), (px, py)) {
+        if let Some(enemy) = state.encounter_by_id_mut(&enemy_id) {
+            enemy.x = nx;
+            enemy.y = ny;
+        }
+    }
+    None
+}
+
+/// The open neighbouring tile that gets `from` closest to `to`, if any does.
+fn chase_step(state: &AppState, from: (u16, u16), to: (u16, u16)) -> Option<(u16, u16)> {
+    let current = from.0.abs_diff(to.0) + from.1.abs_diff(to.1);
+    [(0, -1), (0, 1), (-1, 0), (1, 0)]
+        .iter()
+        .filter_map(|&(dx, dy)| {
+            Some((
+                from.0.checked_add_signed(dx)?,
+                from.1.checked_add_signed(dy)?,
+            ))
+        })
+        .filter(|&(x, y)| (x, y) != to && is_open(state, x, y))
+        .map(|(x, y)| (x.abs_diff(to.0) + y.abs_diff(to.1), (x, y)))
+        .filter(|&(distance, _)| distance < current)
+        .min_by_key(|&(distance, _)| distance)
+        .map(|(_, tile)| tile)
+}
+
+fn is_open(state: &AppState, x: u16, y: u16) -> bool {
+    state.map.is_walkable(x, y)
+        && !state.npcs.iter().any(|npc| npc.x == x && npc.y == y)
+        && !state
+            .encounters
+            .iter()
+            .any(|enemy| enemy.x == x && enemy.y == y && !enemy.defeated)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::EncounterState;
+
+    fn open_map(state: &mut AppState, width: u16, height: u16) {
+        state.map.width = width;
+        state.map.height = height;
+        state.map.tiles = vec![Tile::Grass; width as usize * height as usize];
+    }
+
+    fn enemy(x: u16, y: u16) -> EncounterState {
+        EncounterState {
+            id: "wolf".to_string(),
+            name: "Wolf".to_string(),
+            x,
+            y,
+            hp: 5,
+            max_hp: 5,
+            atk: 0,
+            boss: false,
+            defeated: false,
+        }
+    }
+
+    #[test]
+    fn gain_falls_with_distance_and_darkness() {
+        assert!(detection_gain(1, Light::Bright, 0, 0) > detection_gain(5, Light::Bright, 0, 0));
+        assert!(detection_gain(3, Light::Bright, 0, 0) > detection_gain(3, Light::Dim, 0, 0));
+        assert_eq!(detection_gain(3, Light::Dark, 0, 0), 0);
+        assert!(detection_gain(3, Light::Dark, MAX_NOISE, 0) > 0);
+        assert_eq!(
+            detection_gain(SIGHT_RADIUS + 1, Light::Bright, MAX_NOISE, 0),
+            0
+        );
+        assert_eq!(detection_gain(6, Light::Dim, 0, 5), 0);
+    }
+
+    #[test]
+    fn sneaking_is_quieter_and_half_speed() {
+        let mut state = AppState::default();
+        on_step(&mut state);
+        assert_eq!(state.stealth.noise, WALK_NOISE);
+        assert!(can_step(&state));
+
+        state.stealth.noise = 0;
+        toggle_sneak(&mut state);
+        on_step(&mut state);
+        assert_eq!(state.stealth.noise, SNEAK_NOISE);
+        assert!(!can_step(&state));
+    }
+
+    #[test]
+    fn distant_detection_starts_a_chase_that_closes_in() {
+        let mut state = AppState::default();
+        state.mode = GameMode::Exploration;
+        open_map(&mut state, 10, 3);
+        state.set_player_pos(0, 1);
+        state.encounters.push(enemy(4, 1));
+        state
+            .stealth
+            .detection
+            .insert("wolf".to_string(), DETECTION_MAX - 1);
+
+        assert_eq!(
+            tick(&mut state),
+            Some(Alert::Spotted {
+                enemy_id: "wolf".to_string()
+            })
+        );
+        assert_eq!(state.stealth.chaser.as_deref(), Some("wolf"));
+
+        let mut caught = None;
+        for _ in 0..10 {
+            caught = tick(&mut state);
+            if caught.is_some() {
+                break;
+            }
+        }
+        assert_eq!(
+            caught,
+            Some(Alert::Caught {
+                enemy_id: "wolf".to_string()
+            })
+        );
+        assert_eq!(state.encounters[0].x, 1);
+    }
+
+    #[test]
+    fn meters_drain_out_of_sight() {
+        let mut state = AppState::default();
+        state.mode = GameMode::Exploration;
+        open_map(&mut state, 20, 1);
+        state.set_player_pos(0, 0);
+        state.encounters.push(enemy(15, 0));
+        state
+            .stealth
+            .detection
+            .insert("wolf".to_string(), DETECTION_DECAY);
+
+        assert_eq!(tick(&mut state), None);
+        assert!(state.stealth.detection.is_empty());
+    }
+}
@@ -0,0 +1,254 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::hireling::DAY_STEPS;
+use crate::rules::next_u32;
+use crate::state::{AppState, EncounterState};
+
+/// Hour of the first day a new game starts at.
+const START_HOUR: u32 = 8;
+/// Steps a floor change adds to a journey, on top of the distance on the map.
+const FLOOR_CHANGE_STEPS: u32 = 20;
+/// Ambush chance per step of road skipped, in tenths of a percent.
+const AMBUSH_PER_MILLE_PER_STEP: u32 = 4;
+/// No journey is riskier than this, in percent.
+const MAX_AMBUSH_PERCENT: u32 = 35;
+
+/// A spot the player can fast travel to once they've walked onto it.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Waypoint {
+    pub id: String,
+    pub name: String,
+    pub floor: String,
+    pub x: u16,
+    pub y: u16,
+}
+
+/// Foe that can waylay the player on the road; spawned fresh each time.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct TravelEncounter {
+    pub name: String,
+    pub hp: i32,
+    pub atk: i32,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct TravelState {
+    /// Waypoint ids in the order they were found.
+    pub discovered: Vec<String>,
+    /// Exploration steps since the game began; walking and travel both add to it.
+    pub clock: u32,
+    /// Ambushes so far, to keep spawned encounter ids unique.
+    pub ambushes: u32,
+    /// Selected row while the fast-travel menu is open.
+    pub menu: Option<usize>,
+}
+
+/// Where a fast-travel jump goes and what it costs.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Journey {
+    pub to: Waypoint,
+    pub steps: u32,
+}
+
+/// In-game time for a step count, e.g. "Day 2, 14:24". A day is the same
+/// `DAY_STEPS` hirelings are paid by.
+pub fn clock_label(clock: u32) -> String {
+    let minutes = START_HOUR * 60 + clock % DAY_STEPS * 24 * 60 / DAY_STEPS;
+    let day = clock / DAY_STEPS + 1 + minutes / (24 * 60);
+    let minutes = minutes % (24 * 60);
+    format!("Day {day}, {:02}:{:02}", minutes / 60, minutes % 60)
+}
+
+/// Hours a journey of `steps` takes, rounded up.
+pub fn hours(steps: u32) -> u32 {
+    (steps * 24).div_ceil(DAY_STEPS).max(1)
+}
+
+/// Mark the waypoint under the player as discovered; returns it if it's new.
+pub fn discover(state: &mut AppState) -> Option<Waypoint> {
+    let waypoint = waypoint_here(state)?.clone();
+    if state.travel.discovered.contains(&waypoint.id) {
+        return None;
+    }
+    state.travel.discovered.push(waypoint.id.clone());
+    Some(waypoint)
+}
+
+/// Waypoint on the player's tile of the current floor.
+pub fn waypoint_here(state: &AppState) -> Option<&Waypoint> {
+    let (x, y) = state.player_pos();
+    state.waypoints.iter().find(|waypoint| {
+        waypoint.floor == state.floors.current && waypoint.x == x && waypoint.y == y
+    })
+}
+
+/// Discovered waypoints, in the order they were found; the fast-travel menu rows.
+pub fn destinations(state: &AppState) -> Vec<&Waypoint> {
+    state
+        .travel
+        .discovered
+        .iter()
+        .filter_map(|id| state.waypoints.iter().find(|waypoint| &waypoint.id == id))
+        .collect()
+}
+
+/// The trip to menu row `index`, or why there is none.
+pub fn plan(state: &AppState, index: usize) -> Result<Journey, String> {
+    let to = destinations(state)
+        .get(index)
+        .map(|waypoint| (*waypoint).clone())
+        .ok_or_else(|| "You haven't found anywhere to travel to yet.".to_string())?;
+    if waypoint_here(state).is_some_and(|here| here.id == to.id) {
+        return Err(format!("You are already at {}.", to.name));
+    }
+    let (x, y) = state.player_pos();
+    let mut steps = u32::from(x.abs_diff(to.x)) + u32::from(y.abs_diff(to.y));
+    if to.floor != state.floors.current {
+        steps += FLOOR_CHANGE_STEPS;
+    }
+    Ok(Journey { to, steps })
+}
+
+/// Chance in percent of being waylaid on a journey of `steps`.
+pub fn ambush_chance(steps: u32) -> u32 {
+    (steps * AMBUSH_PER_MILLE_PER_STEP / 10).min(MAX_AMBUSH_PERCENT)
+}
+
+/// Roll for an ambush on a journey of `steps`; on a hit, returns a fresh
+/// encounter from the scenario's table, not yet placed on the map.
+pub fn roll_ambush(state: &mut AppState, steps: u32) -> Option<EncounterState> {
+    if state.travel_encounters.is_empty() {
+        return None;
+    }
+    if next_u32(&mut state.rng_seed) % 100 >= ambush_chance(steps) {
+        return None;
+    }
+    let pick = next_u32(&mut state.rng_seed) as usize % state.travel_encounters.len();
+    let foe = state.travel_encounters[pick].clone();
+    state.travel.ambushes += 1;
+    Some(EncounterState {
+        id: format!("ambush_{}", state.travel.ambushes),
+        name: foe.name,
+        x: 0,
+        y: 0,
+        hp: foe.hp,
+        max_hp: foe.hp,
+        atk: foe.atk,
+        boss: false,
+        defeated: false,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{MapState, Tile};
+
+    fn waypoint(id: &str, floor: &str, x: u16, y: u16) -> Waypoint {
+        Waypoint {
+            id: id.to_string(),
+            name: id.to_string(),
+            floor: floor.to_string(),
+            x,
+            y,
+        }
+    }
+
+    fn with_waypoints() -> AppState {
+        let mut state = AppState {
+            rng_seed: 3,
+            ..Default::default()
+        };
+        state.map = MapState {
+            name: "Test".to_string(),
+            width: 40,
+            height: 10,
+            tiles: vec![Tile::Floor; 400],
+            explored: Vec::new(),
+        };
+        state.waypoints = vec![
+            waypoint("square", "main", 2, 2),
+            waypoint("mill", "main", 32, 8),
+            waypoint("cellar", "cellar", 1, 1),
+        ];
+        state
+    }
+
+    #[test]
+    fn clock_starts_in_the_morning_and_rolls_over() {
+        assert_eq!(clock_label(0), "Day 1, 08:00");
+        assert_eq!(clock_label(DAY_STEPS / 2), "Day 1, 20:00");
+        assert_eq!(clock_label(DAY_STEPS * 2 / 3), "Day 2, 00:00");
+        assert_eq!(clock_label(DAY_STEPS), "Day 2, 08:00");
+        assert_eq!(hours(1), 1);
+        assert_eq!(hours(DAY_STEPS), 24);
+    }
+
+    #[test]
+    fn walking_onto_a_waypoint_discovers_it_once() {
+        let mut state = with_waypoints();
+        state.set_player_pos(2, 2);
+        assert_eq!(discover(&mut state).map(|w| w.id), Some("square".into()));
+        assert_eq!(discover(&mut state), None);
+
+        // Same tile on another floor doesn't count.
+        state.floors.current = "cellar".to_string();
+        state.set_player_pos(32, 8);
+        assert_eq!(discover(&mut state), None);
+        assert_eq!(state.travel.discovered, vec!["square".to_string()]);
+    }
+
+    #[test]
+    fn only_discovered_waypoints_are_destinations() {
+        let mut state = with_waypoints();
+        assert!(plan(&state, 0).is_err());
+
+        state.travel.discovered = vec!["cellar".into(), "square".into()];
+        let names: Vec<_> = destinations(&state).iter().map(|w| w.id.as_str()).collect();
+        assert_eq!(names, ["cellar", "square"]);
+    }
+
+    #[test]
+    fn journeys_cost_the_walk_plus_floor_changes() {
+        let mut state = with_waypoints();
+        state.travel.discovered = vec!["square".into(), "mill".into(), "cellar".into()];
+        state.set_player_pos(2, 2);
+
+        let error = plan(&state, 0).expect_err("already there");
+        assert!(error.contains("already at square"));
+        assert_eq!(plan(&state, 1).map(|j| j.steps), Ok(36));
+        assert_eq!(plan(&state, 2).map(|j| j.steps), Ok(2 + FLOOR_CHANGE_STEPS));
+    }
+
+    #[test]
+    fn long_roads_are_riskier_up_to_a_cap() {
+        assert_eq!(ambush_chance(0), 0);
+        assert_eq!(ambush_chance(50), 20);
+        assert_eq!(ambush_chance(500), MAX_AMBUSH_PERCENT);
+    }
+
+    #[test]
+    fn ambushes_need_a_table_and_get_unique_ids() {
+        let mut state = with_waypoints();
+        assert_eq!(roll_ambush(&mut state, 1000), None);
+
+        state.travel_encounters = vec![TravelEncounter {
+            name: "Highwaymen".to_string(),
+            hp: 9,
+            atk: 3,
+        }];
+        let mut ids = Vec::new();
+        for _ in 0..200 {
+            if let Some(foe) = roll_ambush(&mut state, 1000) {
+                assert_eq!(foe.name, "Highwaymen");
+                ids.push(foe.id);
+            }
+        }
+        assert!(!ids.is_empty() && ids.len() < 200);
+        assert_eq!(ids[0], "ambush_1");
+        assert_eq!(ids.last(), Some(&format!("ambush_{}", ids.len())));
+        assert_eq!(roll_ambush(&mut state, 0), None);
+    }
+}
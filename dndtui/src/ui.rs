@@ -3,7 +3,7 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span, Text},
-    widgets::{Block, BorderType, Borders, Paragraph, Wrap},
+    widgets::{Block, BorderType, Borders, Clear, Paragraph, Wrap},
     Frame,
 };
 use std::sync::OnceLock;
@@ -17,23 +17,25 @@ use tui_dispatch_components::{
     SelectionStyle, StatusBar, StatusBarHint, StatusBarProps, StatusBarSection, StatusBarStyle,
     TextInput, TextInputProps, TextInputStyle,
 };
+use tui_theme::theme;
 
 use crate::action::Action;
+use crate::autosave::Indicator;
+use crate::console;
+use crate::hireling;
 use crate::icons;
+use crate::maneuver::Maneuver;
 use crate::rules::{BACKGROUND_OPTIONS, CLASS_OPTIONS};
 use crate::sprite;
 use crate::sprite_backend;
 use crate::state::{
-    AppState, CreationStep, Direction as MoveDir, GameMode, LogSpeaker, MenuState,
+    AppState, CreationStep, Direction as MoveDir, EncounterState, GameMode, LogSpeaker, MapState,
+    MenuState, STAIRS_TILE_ID,
 };
+use crate::stealth::{self, DETECTION_MAX, MAX_NOISE};
+use crate::travel;
 
-const BG_BASE: Color = Color::Rgb(16, 18, 20);
-const PANEL_BG: Color = Color::Rgb(26, 28, 32);
-const TEXT_MAIN: Color = Color::Rgb(232, 232, 232);
-const TEXT_DIM: Color = Color::Rgb(160, 160, 160);
-const ACCENT: Color = Color::Rgb(126, 200, 180);
-const ACCENT_GOLD: Color = Color::Rgb(222, 196, 120);
-const ACCENT_RED: Color = Color::Rgb(204, 90, 90);
+const FOG: Color = Color::Rgb(10, 11, 12);
 
 const CELL_ASPECT: f32 = 2.0;
 const MAP_TILES_V: u16 = 10;
@@ -41,6 +43,8 @@ const SPRITE_ID_PLAYER: u32 = 0x1000_0001;
 const SPRITE_ID_NPC_PREFIX: u32 = 0x2000_0000;
 const SPRITE_ID_ITEM_PREFIX: u32 = 0x3000_0000;
 const SPRITE_ID_ENCOUNTER_PREFIX: u32 = 0x4000_0000;
+const HEALTH_BAR_MIN_WIDTH: u16 = 8;
+const HEALTH_BAR_MAX_WIDTH: u16 = 16;
 static MAP_RENDERER: OnceLock<MapRenderer> = OnceLock::new();
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -55,6 +59,9 @@ pub struct DndUi {
     menu_list: SelectList,
     pause_list: SelectList,
     inventory_list: SelectList,
+    maneuver_list: SelectList,
+    travel_list: SelectList,
+    journal_list: SelectList,
     class_list: SelectList,
     background_list: SelectList,
     stats_list: SelectList,
@@ -77,6 +84,9 @@ impl DndUi {
             menu_list: SelectList::new(),
             pause_list: SelectList::new(),
             inventory_list: SelectList::new(),
+            maneuver_list: SelectList::new(),
+            travel_list: SelectList::new(),
+            journal_list: SelectList::new(),
             class_list: SelectList::new(),
             background_list: SelectList::new(),
             stats_list: SelectList::new(),
@@ -124,7 +134,10 @@ impl DndUi {
     pub fn render(&mut self, frame: &mut Frame, area: Rect, state: &AppState, _ctx: RenderContext) {
         self.normalize_focus(state);
         sprite_backend::clear_sprites();
-        frame.render_widget(Block::default().style(Style::default().bg(BG_BASE)), area);
+        frame.render_widget(
+            Block::default().style(Style::default().bg(theme().bg)),
+            area,
+        );
         match state.mode {
             GameMode::MainMenu => {
                 render_main_menu(frame, area, state, &mut self.menu_list);
@@ -162,6 +175,9 @@ impl DndUi {
             &mut self.custom_input,
             &mut self.status_bar,
         );
+        if state.console.open {
+            render_console(frame, layout.log.union(layout.input), state);
+        }
 
         if state.pause_menu.is_open {
             render_pause_menu(frame, area, state, &mut self.modal, &mut self.pause_list);
@@ -173,6 +189,32 @@ impl DndUi {
                 &mut self.modal,
                 &mut self.inventory_list,
             );
+        } else if let Some(selected) = maneuver_menu(state) {
+            render_maneuver_menu(
+                frame,
+                area,
+                selected,
+                &mut self.modal,
+                &mut self.maneuver_list,
+            );
+        } else if let Some(selected) = travel_menu(state) {
+            render_travel_menu(
+                frame,
+                area,
+                state,
+                selected,
+                &mut self.modal,
+                &mut self.travel_list,
+            );
+        } else if let Some(selected) = state.decisions.menu {
+            render_journal(
+                frame,
+                area,
+                state,
+                selected,
+                &mut self.modal,
+                &mut self.journal_list,
+            );
         }
     }
 
@@ -206,12 +248,31 @@ impl DndUi {
             return EventOutcome::ignored();
         }
 
+        if state.console.open {
+            return handle_console_key(key, state);
+        }
+        if key.code == KeyCode::Char('`')
+            && state.console.enabled
+            && !state.pause_menu.is_open
+            && matches!(state.mode, GameMode::Exploration | GameMode::Combat)
+        {
+            return EventOutcome::action(Action::ConsoleOpen);
+        }
         if state.pause_menu.is_open {
             return self.handle_pause_event(event, state);
         }
         if state.mode == GameMode::Inventory {
             return self.handle_inventory_event(event, state);
         }
+        if let Some(selected) = maneuver_menu(state) {
+            return self.handle_maneuver_event(event, state, selected);
+        }
+        if let Some(selected) = travel_menu(state) {
+            return self.handle_travel_event(event, state, selected);
+        }
+        if let Some(selected) = state.decisions.menu {
+            return self.handle_journal_event(event, state, selected);
+        }
         if state.mode == GameMode::MainMenu {
             return self.handle_menu_key(key, event, state);
         }
@@ -470,6 +531,208 @@ impl DndUi {
         EventOutcome::from_actions(self.inventory_list.handle_event(event, props))
     }
 
+    fn handle_maneuver_event(
+        &mut self,
+        event: &EventKind,
+        state: &AppState,
+        selected: usize,
+    ) -> EventOutcome<Action> {
+        let modal_area = maneuver_modal_area(full_area(state));
+        let mut noop_render = |_frame: &mut Frame, _area: Rect| {};
+        let modal_props = ModalProps {
+            is_open: true,
+            is_focused: true,
+            area: modal_area,
+            style: inventory_modal_style(),
+            behavior: ModalBehavior {
+                close_on_esc: true,
+                close_on_backdrop: false,
+            },
+            on_close: maneuver_close,
+            render_content: &mut noop_render,
+        };
+        let modal_actions: Vec<_> = self
+            .modal
+            .handle_event(event, modal_props)
+            .into_iter()
+            .collect();
+        if !modal_actions.is_empty() {
+            return EventOutcome::actions(modal_actions);
+        }
+
+        let last = Maneuver::ALL.len() - 1;
+        if let EventKind::Key(key) = event {
+            if key.kind != KeyEventKind::Press {
+                return EventOutcome::ignored();
+            }
+            match key.code {
+                KeyCode::Enter | KeyCode::Char('f') => {
+                    return EventOutcome::action(Action::CombatManeuver(Maneuver::ALL[selected]));
+                }
+                KeyCode::Char('m') => return EventOutcome::action(Action::ManeuverMenuClose),
+                KeyCode::Char(digit @ '1'..='5') => {
+                    let index = digit as usize - '1' as usize;
+                    return EventOutcome::action(Action::CombatManeuver(Maneuver::ALL[index]));
+                }
+                KeyCode::Char('w') => {
+                    let next = if selected == 0 { last } else { selected - 1 };
+                    return EventOutcome::action(Action::ManeuverSelect(next));
+                }
+                KeyCode::Char('s') => {
+                    let next = if selected >= last { 0 } else { selected + 1 };
+                    return EventOutcome::action(Action::ManeuverSelect(next));
+                }
+                _ => {}
+            }
+        }
+
+        let items = maneuver_items();
+        let props = SelectListProps {
+            items: &items,
+            count: items.len(),
+            selected,
+            is_focused: true,
+            style: inventory_list_style(),
+            behavior: SelectListBehavior {
+                show_scrollbar: false,
+                wrap_navigation: true,
+            },
+            on_select: Action::ManeuverSelect,
+            render_item: &render_line,
+        };
+        EventOutcome::from_actions(self.maneuver_list.handle_event(event, props))
+    }
+
+    fn handle_travel_event(
+        &mut self,
+        event: &EventKind,
+        state: &AppState,
+        selected: usize,
+    ) -> EventOutcome<Action> {
+        let modal_area = travel_modal_area(full_area(state));
+        let mut noop_render = |_frame: &mut Frame, _area: Rect| {};
+        let modal_props = ModalProps {
+            is_open: true,
+            is_focused: true,
+            area: modal_area,
+            style: inventory_modal_style(),
+            behavior: ModalBehavior {
+                close_on_esc: true,
+                close_on_backdrop: false,
+            },
+            on_close: travel_close,
+            render_content: &mut noop_render,
+        };
+        let modal_actions: Vec<_> = self
+            .modal
+            .handle_event(event, modal_props)
+            .into_iter()
+            .collect();
+        if !modal_actions.is_empty() {
+            return EventOutcome::actions(modal_actions);
+        }
+
+        let items = travel_items(state);
+        let last = items.len().saturating_sub(1);
+        if let EventKind::Key(key) = event {
+            if key.kind != KeyEventKind::Press {
+                return EventOutcome::ignored();
+            }
+            match key.code {
+                KeyCode::Enter => return EventOutcome::action(Action::TravelConfirm),
+                KeyCode::Char('w') => {
+                    let next = if selected == 0 { last } else { selected - 1 };
+                    return EventOutcome::action(Action::TravelSelect(next));
+                }
+                KeyCode::Char('s') => {
+                    let next = if selected >= last { 0 } else { selected + 1 };
+                    return EventOutcome::action(Action::TravelSelect(next));
+                }
+                _ => {}
+            }
+        }
+
+        let props = SelectListProps {
+            items: &items,
+            count: items.len(),
+            selected: selected.min(last),
+            is_focused: true,
+            style: inventory_list_style(),
+            behavior: SelectListBehavior {
+                show_scrollbar: items.len() > 8,
+                wrap_navigation: true,
+            },
+            on_select: Action::TravelSelect,
+            render_item: &render_line,
+        };
+        EventOutcome::from_actions(self.travel_list.handle_event(event, props))
+    }
+
+    fn handle_journal_event(
+        &mut self,
+        event: &EventKind,
+        state: &AppState,
+        selected: usize,
+    ) -> EventOutcome<Action> {
+        let modal_area = journal_modal_area(full_area(state));
+        let mut noop_render = |_frame: &mut Frame, _area: Rect| {};
+        let modal_props = ModalProps {
+            is_open: true,
+            is_focused: true,
+            area: modal_area,
+            style: inventory_modal_style(),
+            behavior: ModalBehavior {
+                close_on_esc: true,
+                close_on_backdrop: false,
+            },
+            on_close: journal_close,
+            render_content: &mut noop_render,
+        };
+        let modal_actions: Vec<_> = self
+            .modal
+            .handle_event(event, modal_props)
+            .into_iter()
+            .collect();
+        if !modal_actions.is_empty() {
+            return EventOutcome::actions(modal_actions);
+        }
+
+        let items = journal_items(state);
+        let last = items.len().saturating_sub(1);
+        if let EventKind::Key(key) = event {
+            if key.kind != KeyEventKind::Press {
+                return EventOutcome::ignored();
+            }
+            match key.code {
+                KeyCode::Enter => return EventOutcome::action(Action::JournalClose),
+                KeyCode::Char('w') => {
+                    let next = if selected == 0 { last } else { selected - 1 };
+                    return EventOutcome::action(Action::JournalSelect(next));
+                }
+                KeyCode::Char('s') => {
+                    let next = if selected >= last { 0 } else { selected + 1 };
+                    return EventOutcome::action(Action::JournalSelect(next));
+                }
+                _ => {}
+            }
+        }
+
+        let props = SelectListProps {
+            items: &items,
+            count: items.len(),
+            selected: selected.min(last),
+            is_focused: true,
+            style: inventory_list_style(),
+            behavior: SelectListBehavior {
+                show_scrollbar: items.len() > 8,
+                wrap_navigation: true,
+            },
+            on_select: Action::JournalSelect,
+            render_item: &render_line,
+        };
+        EventOutcome::from_actions(self.journal_list.handle_event(event, props))
+    }
+
     fn handle_creation_event(
         &mut self,
         event: &EventKind,
@@ -675,7 +938,13 @@ fn menu_option_labels(menu: &MenuState) -> Vec<&'static str> {
 }
 
 fn pause_option_labels() -> Vec<&'static str> {
-    vec!["Resume", "Save Game", "Quit to Menu"]
+    vec![
+        "Resume",
+        "Save Game",
+        "Fast Travel",
+        "Journal",
+        "Quit to Menu",
+    ]
 }
 
 fn menu_items(menu: &MenuState) -> Vec<CLine<'static>> {
@@ -688,6 +957,70 @@ fn pause_items() -> Vec<CLine<'static>> {
     list_items(&options)
 }
 
+fn maneuver_items() -> Vec<CLine<'static>> {
+    Maneuver::ALL
+        .iter()
+        .enumerate()
+        .map(|(index, maneuver)| CLine::from(format!("{} {}", index + 1, maneuver.label())))
+        .collect()
+}
+
+/// Selected row of the maneuvers menu, while it's open.
+fn maneuver_menu(state: &AppState) -> Option<usize> {
+    state
+        .combat
+        .as_ref()
+        .filter(|_| state.mode == GameMode::Combat)
+        .and_then(|combat| combat.maneuver_menu)
+}
+
+/// Selected row of the fast-travel menu, while it's open.
+fn travel_menu(state: &AppState) -> Option<usize> {
+    state
+        .travel
+        .menu
+        .filter(|_| state.mode == GameMode::Exploration)
+}
+
+/// One row per discovered waypoint, with the hours it takes to get there.
+fn travel_items(state: &AppState) -> Vec<CLine<'static>> {
+    travel::destinations(state)
+        .iter()
+        .enumerate()
+        .map(|(index, waypoint)| {
+            let cost = match travel::plan(state, index) {
+                Ok(journey) => format!("{}h", travel::hours(journey.steps)),
+                Err(_) => "here".to_string(),
+            };
+            CLine::from(format!("{:<24} {cost:>5}", waypoint.name))
+        })
+        .collect()
+}
+
+/// The decision timeline, oldest first, one row per decision.
+fn journal_items(state: &AppState) -> Vec<CLine<'static>> {
+    state
+        .decisions
+        .entries
+        .iter()
+        .map(|entry| {
+            CLine::from(format!(
+                "{:<14} {}",
+                travel::clock_label(entry.clock),
+                entry.title
+            ))
+        })
+        .collect()
+}
+
+fn condition_list(conditions: &[crate::maneuver::Condition]) -> String {
+    conditions
+        .iter()
+        .map(|condition| condition.label())
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
 fn inventory_items(state: &AppState) -> Vec<CLine<'static>> {
     state
         .player
@@ -759,18 +1092,30 @@ fn creation_stat_items(state: &AppState) -> Vec<CLine<'static>> {
 }
 
 fn pause_modal_area(area: Rect) -> Rect {
-    centered_rect(32, 11, area)
+    centered_rect(32, 12, area)
 }
 
 fn inventory_modal_area(area: Rect) -> Rect {
     centered_rect(56, 16, area)
 }
 
+fn maneuver_modal_area(area: Rect) -> Rect {
+    centered_rect(60, 14, area)
+}
+
+fn travel_modal_area(area: Rect) -> Rect {
+    centered_rect(48, 16, area)
+}
+
+fn journal_modal_area(area: Rect) -> Rect {
+    centered_rect(64, 18, area)
+}
+
 fn panel_border_style() -> BorderStyle {
     BorderStyle {
         borders: Borders::ALL,
-        style: Style::default().fg(TEXT_DIM),
-        focused_style: Some(Style::default().fg(ACCENT)),
+        style: Style::default().fg(theme().focus_border(false)),
+        focused_style: Some(Style::default().fg(theme().focus_border(true))),
     }
 }
 
@@ -781,16 +1126,16 @@ fn panel_block<'a>(title: &'a str, is_focused: bool) -> Block<'a> {
         .borders(border.borders)
         .border_style(border.style_for_focus(is_focused))
         .border_type(BorderType::Rounded)
-        .style(Style::default().bg(PANEL_BG))
+        .style(Style::default().bg(theme().panel))
 }
 
 fn panel_block_for_mode<'a>(title: &'a str, is_focused: bool, mode: GameMode) -> Block<'a> {
     let mut block = panel_block(title, is_focused);
     if mode == GameMode::Combat {
         let border = if is_focused {
-            ACCENT_RED
+            theme().danger
         } else {
-            Color::Rgb(136, 78, 78)
+            theme().danger_muted
         };
         block = block.border_style(Style::default().fg(border));
     }
@@ -803,11 +1148,11 @@ fn pause_modal_style() -> ModalStyle {
         base: BaseStyle {
             border: Some(BorderStyle {
                 borders: Borders::ALL,
-                style: Style::default().fg(TEXT_DIM),
-                focused_style: Some(Style::default().fg(ACCENT_GOLD)),
+                style: Style::default().fg(theme().text_dim),
+                focused_style: Some(Style::default().fg(theme().gold)),
             }),
             padding: Padding::all(1),
-            bg: Some(PANEL_BG),
+            bg: Some(theme().panel),
             fg: None,
         },
     }
@@ -819,11 +1164,11 @@ fn inventory_modal_style() -> ModalStyle {
         base: BaseStyle {
             border: Some(BorderStyle {
                 borders: Borders::ALL,
-                style: Style::default().fg(TEXT_DIM),
-                focused_style: Some(Style::default().fg(ACCENT)),
+                style: Style::default().fg(theme().text_dim),
+                focused_style: Some(Style::default().fg(theme().accent)),
             }),
             padding: Padding::all(1),
-            bg: Some(PANEL_BG),
+            bg: Some(theme().panel),
             fg: None,
         },
     }
@@ -837,17 +1182,29 @@ fn inventory_close() -> Action {
     Action::CloseOverlay
 }
 
+fn maneuver_close() -> Action {
+    Action::ManeuverMenuClose
+}
+
+fn travel_close() -> Action {
+    Action::TravelClose
+}
+
+fn journal_close() -> Action {
+    Action::JournalClose
+}
+
 fn menu_list_style() -> SelectListStyle {
     SelectListStyle {
         base: BaseStyle {
             border: None,
             padding: Padding::xy(1, 0),
-            bg: Some(PANEL_BG),
-            fg: Some(TEXT_MAIN),
+            bg: Some(theme().panel),
+            fg: Some(theme().text),
         },
         selection: SelectionStyle::style_only(
             Style::default()
-                .fg(ACCENT_GOLD)
+                .fg(theme().gold)
                 .add_modifier(Modifier::BOLD),
         ),
         scrollbar: Default::default(),
@@ -859,13 +1216,13 @@ fn inventory_list_style() -> SelectListStyle {
         base: BaseStyle {
             border: None,
             padding: Padding::xy(1, 0),
-            bg: Some(PANEL_BG),
-            fg: Some(TEXT_MAIN),
+            bg: Some(theme().panel),
+            fg: Some(theme().text),
         },
         selection: SelectionStyle {
             style: Some(
                 Style::default()
-                    .fg(ACCENT_GOLD)
+                    .fg(theme().gold)
                     .add_modifier(Modifier::BOLD),
             ),
             marker: Some("› "),
@@ -880,11 +1237,15 @@ fn creation_list_style() -> SelectListStyle {
         base: BaseStyle {
             border: None,
             padding: Padding::xy(1, 0),
-            bg: Some(PANEL_BG),
-            fg: Some(TEXT_MAIN),
+            bg: Some(theme().panel),
+            fg: Some(theme().text),
         },
         selection: SelectionStyle {
-            style: Some(Style::default().fg(ACCENT).add_modifier(Modifier::BOLD)),
+            style: Some(
+                Style::default()
+                    .fg(theme().accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
             marker: Some("> "),
             disabled: false,
         },
@@ -897,11 +1258,11 @@ fn input_style() -> TextInputStyle {
         base: BaseStyle {
             border: None,
             padding: Padding::xy(1, 0),
-            bg: Some(PANEL_BG),
-            fg: Some(TEXT_MAIN),
+            bg: Some(theme().panel),
+            fg: Some(theme().text),
         },
-        placeholder_style: Some(Style::default().fg(TEXT_DIM)),
-        cursor_style: Some(Style::default().bg(ACCENT_GOLD).fg(BG_BASE)),
+        placeholder_style: Some(Style::default().fg(theme().text_dim)),
+        cursor_style: Some(Style::default().bg(theme().gold).fg(theme().bg)),
     }
 }
 
@@ -910,8 +1271,8 @@ fn log_scroll_style() -> ScrollViewStyle {
         base: BaseStyle {
             border: None,
             padding: Padding::xy(1, 0),
-            bg: Some(PANEL_BG),
-            fg: Some(TEXT_MAIN),
+            bg: Some(theme().panel),
+            fg: Some(theme().text),
         },
         scrollbar: Default::default(),
     }
@@ -922,15 +1283,15 @@ fn status_bar_style() -> StatusBarStyle {
         base: BaseStyle {
             border: None,
             padding: Padding::xy(1, 0),
-            bg: Some(PANEL_BG),
+            bg: Some(theme().panel),
             fg: None,
         },
-        text: Style::default().fg(TEXT_MAIN),
+        text: Style::default().fg(theme().text),
         hint_key: Style::default()
-            .fg(ACCENT_GOLD)
+            .fg(theme().gold)
             .add_modifier(Modifier::BOLD),
-        hint_label: Style::default().fg(TEXT_DIM),
-        separator: Style::default().fg(TEXT_DIM),
+        hint_label: Style::default().fg(theme().text_dim),
+        separator: Style::default().fg(theme().text_dim),
     }
 }
 
@@ -942,7 +1303,7 @@ struct StatusHints {
 
 fn status_hints(state: &AppState, focus: PaneFocus) -> StatusHints {
     let hint = |key: &'static str, label: &'static str| StatusBarHint::new(key, label);
-    match state.mode {
+    let mut hints = match state.mode {
         GameMode::Exploration if focus == PaneFocus::Log => StatusHints {
             left: vec![hint("Up/Down", "Scroll"), hint("PgUp/Dn", "Page")],
             center: vec![hint("Home/End", "Jump")],
@@ -950,12 +1311,17 @@ fn status_hints(state: &AppState, focus: PaneFocus) -> StatusHints {
         },
         GameMode::Exploration if focus == PaneFocus::Sidebar => StatusHints {
             left: vec![hint("Tab", "Focus")],
-            center: vec![hint("B", "Inventory")],
+            center: vec![hint("B", "Inventory"), hint("1-2", "Dismiss")],
             right: vec![hint("Esc", "Pause"), hint("PgUp/Dn", "Log")],
         },
         GameMode::Exploration => StatusHints {
             left: vec![hint("Arrows", "Move"), hint("WASD", "Alt Move")],
-            center: vec![hint("E", "Interact"), hint("T", "Talk")],
+            center: vec![
+                hint("E", "Interact"),
+                hint("T", "Talk"),
+                hint("H", "Hire"),
+                hint("Z", "Sneak"),
+            ],
             right: vec![
                 hint("Tab", "Focus"),
                 hint("B", "Inventory"),
@@ -974,7 +1340,11 @@ fn status_hints(state: &AppState, focus: PaneFocus) -> StatusHints {
         },
         GameMode::Combat => StatusHints {
             left: vec![hint("Arrows", "Move"), hint("WASD", "Alt Move")],
-            center: vec![hint("F/Enter", "Attack"), hint("E", "End Turn")],
+            center: vec![
+                hint("F/Enter", "Attack"),
+                hint("M", "Maneuver"),
+                hint("E", "End Turn"),
+            ],
             right: vec![hint("Tab", "Focus"), hint("Esc", "Pause")],
         },
         GameMode::Inventory => StatusHints {
@@ -1007,7 +1377,14 @@ fn status_hints(state: &AppState, focus: PaneFocus) -> StatusHints {
             center: Vec::new(),
             right: Vec::new(),
         },
+    };
+    // Autosave note, first on the right so it's the part that stands out.
+    match state.autosave.indicator() {
+        Some(Indicator::Saving) => hints.right.insert(0, hint("…", "Saving")),
+        Some(Indicator::Saved) => hints.right.insert(0, hint("✓", "Saved")),
+        None => {}
     }
+    hints
 }
 
 fn ui_render(_: usize) -> Action {
@@ -1035,6 +1412,27 @@ fn is_tab_key(key: KeyEvent) -> bool {
         || (key.code == KeyCode::Char('i') && key.modifiers.contains(KeyModifiers::CONTROL))
 }
 
+fn handle_console_key(key: KeyEvent, state: &AppState) -> EventOutcome<Action> {
+    let input = &state.console.input;
+    match key.code {
+        KeyCode::Esc | KeyCode::Char('`') => EventOutcome::action(Action::ConsoleClose),
+        KeyCode::Enter => EventOutcome::actions(vec![
+            Action::ConsoleSubmit,
+            console::parse(input).unwrap_or_else(Action::ConsoleRejected),
+        ]),
+        _ if is_tab_key(key) => EventOutcome::action(Action::ConsoleComplete),
+        KeyCode::Backspace => {
+            let mut input = input.clone();
+            input.pop();
+            EventOutcome::action(Action::ConsoleInputChanged(input))
+        }
+        KeyCode::Char(ch) if !key.modifiers.contains(KeyModifiers::CONTROL) => {
+            EventOutcome::action(Action::ConsoleInputChanged(format!("{input}{ch}")))
+        }
+        _ => EventOutcome::ignored(),
+    }
+}
+
 fn is_inventory_open_key(key: KeyEvent) -> bool {
     key.code == KeyCode::Char('b')
         && key.kind == KeyEventKind::Press
@@ -1145,6 +1543,15 @@ fn handle_exploration_key(key: KeyEvent, focus: PaneFocus) -> EventOutcome<Actio
         KeyCode::Char('c') if focus == PaneFocus::Map && is_press => {
             EventOutcome::action(Action::OpenCustomAction)
         }
+        KeyCode::Char('h') if focus == PaneFocus::Map && is_press => {
+            EventOutcome::action(Action::Recruit)
+        }
+        KeyCode::Char('z') if focus == PaneFocus::Map && is_press => {
+            EventOutcome::action(Action::ToggleSneak)
+        }
+        KeyCode::Char(slot @ '1'..='9') if focus == PaneFocus::Sidebar && is_press => {
+            EventOutcome::action(Action::DismissHireling(slot as usize - '1' as usize))
+        }
         _ => EventOutcome::ignored(),
     }
 }
@@ -1181,6 +1588,9 @@ fn handle_combat_key(key: KeyEvent, focus: PaneFocus) -> EventOutcome<Action> {
         KeyCode::Char('e') if focus == PaneFocus::Map && is_press => {
             EventOutcome::action(Action::CombatEndTurn)
         }
+        KeyCode::Char('m') if focus == PaneFocus::Map && is_press => {
+            EventOutcome::action(Action::ManeuverMenuOpen)
+        }
         _ => EventOutcome::ignored(),
     }
 }
@@ -1210,7 +1620,9 @@ fn render_character_creation(
     let (step_idx, step_label) = creation_step_label(state.creation.step);
     let header = Paragraph::new(Text::from(vec![Line::from(vec![Span::styled(
         format!("Step {step_idx}/5 • {step_label}"),
-        Style::default().fg(ACCENT).add_modifier(Modifier::BOLD),
+        Style::default()
+            .fg(theme().accent)
+            .add_modifier(Modifier::BOLD),
     )])]))
     .alignment(Alignment::Left);
     frame.render_widget(header, chunks[0]);
@@ -1241,7 +1653,7 @@ fn render_character_creation(
 
     let footer = Paragraph::new(Text::from(vec![Line::from(vec![Span::styled(
         creation_footer_text(state.creation.step),
-        Style::default().fg(TEXT_DIM),
+        Style::default().fg(theme().text_dim),
     )])]))
     .alignment(Alignment::Left);
     frame.render_widget(footer, chunks[2]);
@@ -1325,9 +1737,9 @@ fn render_creation_stats(
     stats_list.render(frame, chunks[0], props);
 
     let points_color = if state.creation.points_remaining < 0 {
-        ACCENT_RED
+        theme().danger
     } else {
-        ACCENT_GOLD
+        theme().gold
     };
     let footer = Paragraph::new(Line::from(Span::styled(
         format!("Points remaining: {}", state.creation.points_remaining),
@@ -1341,7 +1753,7 @@ fn render_creation_confirm(frame: &mut Frame, area: Rect, state: &AppState) {
     let mut lines = Vec::new();
     lines.push(Line::from(Span::styled(
         "Review",
-        Style::default().fg(ACCENT),
+        Style::default().fg(theme().accent),
     )));
     lines.push(Line::from(Span::raw("")));
     lines.push(Line::from(format!("Name: {}", state.creation.name)));
@@ -1358,7 +1770,7 @@ fn render_creation_confirm(frame: &mut Frame, area: Rect, state: &AppState) {
     lines.push(Line::from(Span::raw("")));
     lines.push(Line::from(Span::styled(
         "Press Enter to begin.",
-        Style::default().fg(ACCENT_GOLD),
+        Style::default().fg(theme().gold),
     )));
     let paragraph = Paragraph::new(Text::from(lines)).wrap(Wrap { trim: false });
     frame.render_widget(paragraph, area);
@@ -1386,11 +1798,13 @@ fn render_main_menu(frame: &mut Frame, area: Rect, state: &AppState, menu_list:
     let header = Paragraph::new(Text::from(vec![
         Line::from(Span::styled(
             "DNDTUI",
-            Style::default().fg(ACCENT).add_modifier(Modifier::BOLD),
+            Style::default()
+                .fg(theme().accent)
+                .add_modifier(Modifier::BOLD),
         )),
         Line::from(Span::styled(
             "A tabletop tale in the terminal",
-            Style::default().fg(TEXT_DIM),
+            Style::default().fg(theme().text_dim),
         )),
     ]))
     .alignment(Alignment::Center);
@@ -1415,7 +1829,7 @@ fn render_main_menu(frame: &mut Frame, area: Rect, state: &AppState, menu_list:
 
     let footer = Paragraph::new(Line::from(Span::styled(
         "Arrows/WASD: Navigate  |  Enter: Select  |  Esc: Quit",
-        Style::default().fg(TEXT_DIM),
+        Style::default().fg(theme().text_dim),
     )))
     .alignment(Alignment::Center);
     frame.render_widget(footer, layout[2]);
@@ -1428,7 +1842,7 @@ fn render_map(frame: &mut Frame, area: Rect, state: &AppState, is_focused: bool)
 
     if inner.width < 8 || inner.height < 4 {
         let warning = Paragraph::new("Resize for map view.")
-            .style(Style::default().fg(TEXT_DIM))
+            .style(Style::default().fg(theme().text_dim))
             .alignment(Alignment::Center)
             .wrap(Wrap { trim: true });
         frame.render_widget(warning, inner);
@@ -1451,11 +1865,29 @@ fn render_map(frame: &mut Frame, area: Rect, state: &AppState, is_focused: bool)
     }
 
     let buf = frame.buffer_mut();
+    draw_fog(buf, &state.map, render);
+    let seen = |x: u16, y: u16| state.map.is_explored(x, y);
+    let waypoints = state
+        .waypoints
+        .iter()
+        .filter(|waypoint| waypoint.floor == state.floors.current && seen(waypoint.x, waypoint.y));
+    for waypoint in waypoints {
+        let color = if state.travel.discovered.contains(&waypoint.id) {
+            theme().gold
+        } else {
+            theme().text_dim
+        };
+        draw_marker(buf, waypoint.x, waypoint.y, render, 'W', color);
+    }
+    for stair in state.stairs.iter().filter(|stair| seen(stair.x, stair.y)) {
+        draw_marker(buf, stair.x, stair.y, render, '>', theme().gold);
+    }
+
     let icons = icons::icon_set();
     let use_icons = render.cols_per_tile >= 3 && render.rows_per_tile >= 2;
 
     if let Some(icon) = icons.item.as_ref().filter(|_| use_icons) {
-        for item in &state.items {
+        for item in state.items.iter().filter(|item| seen(item.x, item.y)) {
             if item.x == player_x && item.y == player_y {
                 continue;
             }
@@ -1468,13 +1900,13 @@ fn render_map(frame: &mut Frame, area: Rect, state: &AppState, is_focused: bool)
             );
         }
     } else {
-        for item in &state.items {
-            draw_marker(buf, item.x, item.y, render, '*', ACCENT_GOLD);
+        for item in state.items.iter().filter(|item| seen(item.x, item.y)) {
+            draw_marker(buf, item.x, item.y, render, '*', theme().gold);
         }
     }
 
     if let Some(icon) = icons.npc.as_ref().filter(|_| use_icons) {
-        for npc in &state.npcs {
+        for npc in state.npcs.iter().filter(|npc| seen(npc.x, npc.y)) {
             if npc.x == player_x && npc.y == player_y {
                 continue;
             }
@@ -1487,13 +1919,13 @@ fn render_map(frame: &mut Frame, area: Rect, state: &AppState, is_focused: bool)
             );
         }
     } else {
-        for npc in &state.npcs {
-            draw_marker(buf, npc.x, npc.y, render, 'N', ACCENT);
+        for npc in state.npcs.iter().filter(|npc| seen(npc.x, npc.y)) {
+            draw_marker(buf, npc.x, npc.y, render, 'N', theme().accent);
         }
     }
 
     if let Some(icon) = icons.encounter.as_ref().filter(|_| use_icons) {
-        for encounter in &state.encounters {
+        for encounter in state.encounters.iter().filter(|e| seen(e.x, e.y)) {
             if encounter.defeated {
                 continue;
             }
@@ -1509,11 +1941,11 @@ fn render_map(frame: &mut Frame, area: Rect, state: &AppState, is_focused: bool)
             );
         }
     } else {
-        for encounter in &state.encounters {
+        for encounter in state.encounters.iter().filter(|e| seen(e.x, e.y)) {
             if encounter.defeated {
                 continue;
             }
-            draw_marker(buf, encounter.x, encounter.y, render, 'E', ACCENT_RED);
+            draw_marker(buf, encounter.x, encounter.y, render, 'E', theme().danger);
         }
     }
 
@@ -1526,16 +1958,222 @@ fn render_map(frame: &mut Frame, area: Rect, state: &AppState, is_focused: bool)
             render,
         );
     } else {
-        draw_marker(buf, player_x, player_y, render, '@', TEXT_MAIN);
+        draw_marker(buf, player_x, player_y, render, '@', theme().text);
+    }
+
+    if state.mode == GameMode::Combat {
+        let engaged = state.combat.as_ref().map(|combat| combat.enemy_id.as_str());
+        for encounter in state.encounters.iter().filter(|e| !e.defeated) {
+            draw_health_bar(
+                buf,
+                inner,
+                encounter,
+                engaged == Some(encounter.id.as_str()),
+                render,
+            );
+        }
+    } else if state.mode == GameMode::Exploration {
+        for encounter in state.encounters.iter().filter(|e| !e.defeated) {
+            let chasing = state.stealth.chaser.as_deref() == Some(encounter.id.as_str());
+            let meter = if chasing {
+                DETECTION_MAX
+            } else {
+                state.stealth.detection.get(&encounter.id).copied().unwrap_or(0)
+            };
+            if meter > 0 && seen(encounter.x, encounter.y) {
+                draw_detection_meter(buf, inner, encounter, meter, chasing, render);
+            }
+        }
     }
 }
 
-fn draw_marker(
+/// How close an unaware enemy is to noticing the player: `?` while the
+/// meter fills, `!` once it has and the enemy is giving chase.
+fn draw_detection_meter(
     buf: &mut ratatui::buffer::Buffer,
-    map_x: u16,
-    map_y: u16,
+    bounds: Rect,
+    encounter: &EncounterState,
+    meter: u8,
+    chasing: bool,
     render: MapRenderResult,
-    ch: char,
+) {
+    let Some((tile_x, tile_y)) = render.tile_cell_origin(encounter.x, encounter.y) else {
+        return;
+    };
+    let (mark, color) = if chasing {
+        ('!', theme().danger)
+    } else {
+        ('?', theme().gold)
+    };
+    let label = format!("{mark} {}", encounter.name);
+    let width = (label.chars().count() as u16).clamp(HEALTH_BAR_MIN_WIDTH, HEALTH_BAR_MAX_WIDTH);
+    let Some(area) = health_bar_area(
+        (tile_x, tile_y),
+        (render.cols_per_tile, render.rows_per_tile),
+        width,
+        bounds,
+    ) else {
+        return;
+    };
+
+    let label: String = label.chars().take(area.width as usize).collect();
+    let pad = (area.width as usize).saturating_sub(label.chars().count()) / 2;
+    buf.set_string(
+        area.x,
+        area.y,
+        " ".repeat(area.width as usize),
+        Style::default().bg(theme().panel),
+    );
+    buf.set_string(
+        area.x + pad as u16,
+        area.y,
+        label,
+        Style::default()
+            .fg(color)
+            .bg(theme().panel)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let filled = (u32::from(meter) * u32::from(area.width)).div_ceil(u32::from(DETECTION_MAX));
+    for offset in 0..area.width {
+        if let Some(cell) = buf.cell_mut((area.x + offset, area.y + 1)) {
+            if u32::from(offset) < filled {
+                cell.set_char('▮').set_fg(color).set_bg(theme().panel);
+            } else {
+                cell.set_char('·')
+                    .set_fg(theme().text_dim)
+                    .set_bg(theme().panel);
+            }
+        }
+    }
+}
+
+/// Name label and HP bar floating over an encounter's tile.
+fn draw_health_bar(
+    buf: &mut ratatui::buffer::Buffer,
+    bounds: Rect,
+    encounter: &EncounterState,
+    engaged: bool,
+    render: MapRenderResult,
+) {
+    let Some((tile_x, tile_y)) = render.tile_cell_origin(encounter.x, encounter.y) else {
+        return;
+    };
+    let label = if encounter.boss {
+        format!("★ {}", encounter.name)
+    } else {
+        encounter.name.clone()
+    };
+    let width = (label.chars().count() as u16).clamp(HEALTH_BAR_MIN_WIDTH, HEALTH_BAR_MAX_WIDTH);
+    let Some(area) = health_bar_area(
+        (tile_x, tile_y),
+        (render.cols_per_tile, render.rows_per_tile),
+        width,
+        bounds,
+    ) else {
+        return;
+    };
+
+    let name_color = match (encounter.boss, engaged) {
+        (true, _) => theme().gold,
+        (false, true) => theme().text,
+        (false, false) => theme().text_dim,
+    };
+    let label: String = label.chars().take(area.width as usize).collect();
+    let pad = (area.width as usize).saturating_sub(label.chars().count()) / 2;
+    buf.set_string(
+        area.x,
+        area.y,
+        " ".repeat(area.width as usize),
+        Style::default().bg(theme().panel),
+    );
+    buf.set_string(
+        area.x + pad as u16,
+        area.y,
+        label,
+        Style::default()
+            .fg(name_color)
+            .bg(theme().panel)
+            .add_modifier(Modifier::BOLD),
+    );
+
+    let ratio = encounter.hp_ratio();
+    let filled = ((ratio * area.width as f32).ceil() as u16).min(area.width);
+    let bar_color = if ratio > 0.5 {
+        theme().accent
+    } else if ratio > 0.25 {
+        theme().gold
+    } else {
+        theme().danger
+    };
+    for offset in 0..area.width {
+        if let Some(cell) = buf.cell_mut((area.x + offset, area.y + 1)) {
+            if offset < filled {
+                cell.set_char('█').set_fg(bar_color).set_bg(theme().panel);
+            } else {
+                cell.set_char('░')
+                    .set_fg(theme().text_dim)
+                    .set_bg(theme().panel);
+            }
+        }
+    }
+}
+
+/// Place a two-row label of `width` centered over a tile. Sits above the tile
+/// when it fits inside `bounds`, flips below it near the top edge, and is
+/// clamped horizontally so it never spills past the map's left/right edges.
+fn health_bar_area(
+    (tile_x, tile_y): (u16, u16),
+    (cols_per_tile, rows_per_tile): (u16, u16),
+    width: u16,
+    bounds: Rect,
+) -> Option<Rect> {
+    const HEIGHT: u16 = 2;
+    let width = width.min(bounds.width);
+    if width == 0 {
+        return None;
+    }
+    let center = tile_x + cols_per_tile / 2;
+    let x = center
+        .saturating_sub(width / 2)
+        .clamp(bounds.x, bounds.right() - width);
+    let y = if tile_y >= bounds.y + HEIGHT {
+        tile_y - HEIGHT
+    } else if tile_y + rows_per_tile + HEIGHT <= bounds.bottom() {
+        tile_y + rows_per_tile
+    } else {
+        return None;
+    };
+    Some(Rect::new(x, y, width, HEIGHT))
+}
+
+/// Blank out tiles the player hasn't explored yet.
+fn draw_fog(buf: &mut ratatui::buffer::Buffer, map: &MapState, render: MapRenderResult) {
+    for y in render.start_y..render.start_y + render.view_tiles_v {
+        for x in render.start_x..render.start_x + render.view_tiles_h {
+            if map.is_explored(x, y) {
+                continue;
+            }
+            let Some((cell_x, cell_y)) = render.tile_cell_origin(x, y) else {
+                continue;
+            };
+            for dy in 0..render.rows_per_tile {
+                for dx in 0..render.cols_per_tile {
+                    if let Some(cell) = buf.cell_mut((cell_x + dx, cell_y + dy)) {
+                        cell.set_char(' ').set_bg(FOG);
+                    }
+                }
+            }
+        }
+    }
+}
+
+fn draw_marker(
+    buf: &mut ratatui::buffer::Buffer,
+    map_x: u16,
+    map_y: u16,
+    render: MapRenderResult,
+    ch: char,
     fg: Color,
 ) {
     if let Some((center_x, center_y)) = render.marker_cell(map_x, map_y) {
@@ -1609,15 +2247,18 @@ fn render_sidebar(frame: &mut Frame, area: Rect, state: &AppState, is_focused: b
     let mut lines = Vec::new();
     let row = |label: &str, value: String| {
         Line::from(vec![
-            Span::styled(format!("{label:<11}"), Style::default().fg(TEXT_DIM)),
-            Span::styled(value, Style::default().fg(TEXT_MAIN)),
+            Span::styled(
+                format!("{label:<11}"),
+                Style::default().fg(theme().text_dim),
+            ),
+            Span::styled(value, Style::default().fg(theme().text)),
         ])
     };
 
     lines.push(Line::from(Span::styled(
         "Player",
         Style::default()
-            .fg(ACCENT_GOLD)
+            .fg(theme().gold)
             .add_modifier(Modifier::BOLD),
     )));
     lines.push(row("Name", state.player.name.clone()));
@@ -1631,17 +2272,86 @@ fn render_sidebar(frame: &mut Frame, area: Rect, state: &AppState, is_focused: b
         "Position",
         format!("{},{}", state.player.x, state.player.y),
     ));
+    lines.push(row("Gold", state.player.gold.to_string()));
+    lines.push(row("Time", travel::clock_label(state.travel.clock)));
     lines.push(Line::from(Span::raw("")));
 
+    if state.mode == GameMode::Exploration {
+        let (x, y) = state.player_pos();
+        let noise = usize::from(state.stealth.noise);
+        lines.push(Line::from(Span::styled(
+            "Stealth",
+            Style::default()
+                .fg(theme().gold)
+                .add_modifier(Modifier::BOLD),
+        )));
+        lines.push(row(
+            "Moving",
+            if state.stealth.sneaking {
+                "Sneaking".to_string()
+            } else {
+                "Walking".to_string()
+            },
+        ));
+        lines.push(row("Light", stealth::light_at(state, x, y).label().to_string()));
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{:<11}", "Noise"),
+                Style::default().fg(theme().text_dim),
+            ),
+            Span::styled("▮".repeat(noise), Style::default().fg(theme().gold)),
+            Span::styled(
+                "·".repeat(usize::from(MAX_NOISE).saturating_sub(noise)),
+                Style::default().fg(theme().text_dim),
+            ),
+        ]));
+        lines.push(Line::from(Span::raw("")));
+    }
+
+    if !state.party.hirelings.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "Party",
+            Style::default()
+                .fg(theme().gold)
+                .add_modifier(Modifier::BOLD),
+        )));
+        for (slot, hireling) in state.party.hirelings.iter().enumerate() {
+            lines.push(Line::from(vec![
+                Span::styled(
+                    format!("{} ", slot + 1),
+                    Style::default().fg(theme().text_dim),
+                ),
+                Span::styled(hireling.npc.name.clone(), Style::default().fg(theme().text)),
+                Span::styled(
+                    format!(
+                        " {} · {}g/day",
+                        hireling::loyalty_label(hireling.loyalty),
+                        hireling.wage
+                    ),
+                    Style::default().fg(theme().text_dim),
+                ),
+            ]));
+        }
+        lines.push(Line::from(Span::raw("")));
+    }
+
     if let Some(combat) = &state.combat {
         if let Some(enemy) = state.encounters.iter().find(|e| e.id == combat.enemy_id) {
             lines.push(Line::from(Span::styled(
                 "Combat",
-                Style::default().fg(ACCENT_RED).add_modifier(Modifier::BOLD),
+                Style::default()
+                    .fg(theme().danger)
+                    .add_modifier(Modifier::BOLD),
             )));
             lines.push(row("Enemy", enemy.name.clone()));
             lines.push(row("Enemy HP", format!("{}", enemy.hp.max(0))));
             lines.push(row("Move left", format!("{}", combat.movement_left)));
+            if !combat.enemy_conditions.is_empty() {
+                lines.push(row("Enemy is", condition_list(&combat.enemy_conditions)));
+            }
+            if !combat.player_conditions.is_empty() {
+                lines.push(row("You are", condition_list(&combat.player_conditions)));
+            }
             lines.push(Line::from(Span::raw("")));
         }
     }
@@ -1654,7 +2364,7 @@ fn render_sidebar(frame: &mut Frame, area: Rect, state: &AppState, is_focused: b
         let spinner = spinner_frame(state.spinner_frame);
         lines.push(Line::from(Span::styled(
             format!("DM {spinner} {label}..."),
-            Style::default().fg(ACCENT),
+            Style::default().fg(theme().accent),
         )));
         lines.push(Line::from(Span::raw("")));
     }
@@ -1662,7 +2372,7 @@ fn render_sidebar(frame: &mut Frame, area: Rect, state: &AppState, is_focused: b
         let spinner = spinner_frame(state.spinner_frame);
         lines.push(Line::from(Span::styled(
             format!("Save {spinner} Writing..."),
-            Style::default().fg(ACCENT_GOLD),
+            Style::default().fg(theme().gold),
         )));
         lines.push(Line::from(Span::raw("")));
     }
@@ -1670,13 +2380,13 @@ fn render_sidebar(frame: &mut Frame, area: Rect, state: &AppState, is_focused: b
     lines.push(Line::from(Span::styled(
         "Inventory",
         Style::default()
-            .fg(ACCENT_GOLD)
+            .fg(theme().gold)
             .add_modifier(Modifier::BOLD),
     )));
     if state.player.inventory.is_empty() {
         lines.push(Line::from(Span::styled(
             "(empty)",
-            Style::default().fg(TEXT_DIM),
+            Style::default().fg(theme().text_dim),
         )));
     } else {
         for item in &state.player.inventory {
@@ -1704,15 +2414,15 @@ fn render_log(
     if state.log.is_empty() {
         lines.push(Line::from(Span::styled(
             "No log entries yet.",
-            Style::default().fg(TEXT_DIM),
+            Style::default().fg(theme().text_dim),
         )));
     } else {
         for entry in &state.log {
             let (label, color) = match entry.speaker {
-                LogSpeaker::System => ("[System]", TEXT_DIM),
-                LogSpeaker::Player => ("[You]", ACCENT),
-                LogSpeaker::Npc => ("[NPC]", TEXT_MAIN),
-                LogSpeaker::Combat => ("[Combat]", ACCENT_RED),
+                LogSpeaker::System => ("[System]", theme().text_dim),
+                LogSpeaker::Player => ("[You]", theme().accent),
+                LogSpeaker::Npc => ("[NPC]", theme().text),
+                LogSpeaker::Combat => ("[Combat]", theme().danger),
             };
             let prefix_width = label.chars().count() + 1;
             let wrap_width = width.saturating_sub(prefix_width).max(1);
@@ -1727,7 +2437,7 @@ fn render_log(
                 } else {
                     let indent = " ".repeat(prefix_width);
                     lines.push(Line::from(vec![
-                        Span::styled(indent, Style::default().fg(TEXT_DIM)),
+                        Span::styled(indent, Style::default().fg(theme().text_dim)),
                         Span::raw(chunk),
                     ]));
                 }
@@ -1864,6 +2574,39 @@ fn render_input(
     }
 }
 
+fn render_console(frame: &mut Frame, area: Rect, state: &AppState) {
+    frame.render_widget(Clear, area);
+    let block = panel_block("Console", true);
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let history = inner.height.saturating_sub(1) as usize;
+    let skip = state.console.output.len().saturating_sub(history);
+    let mut lines: Vec<Line> = state.console.output[skip..]
+        .iter()
+        .map(|line| {
+            let color = if line.starts_with("> ") {
+                theme().accent
+            } else {
+                theme().text_dim
+            };
+            Line::from(Span::styled(line.as_str(), Style::default().fg(color)))
+        })
+        .collect();
+    lines.push(Line::from(vec![
+        Span::styled("> ", Style::default().fg(theme().accent)),
+        Span::raw(state.console.input.as_str()),
+        Span::styled("_", Style::default().fg(theme().text_dim)),
+    ]));
+    let top = history.saturating_sub(lines.len() - 1) as u16;
+    let text_area = Rect {
+        y: inner.y + top,
+        height: inner.height.saturating_sub(top),
+        ..inner
+    };
+    frame.render_widget(Paragraph::new(lines), text_area);
+}
+
 fn render_pause_menu(
     frame: &mut Frame,
     area: Rect,
@@ -1885,7 +2628,7 @@ fn render_pause_menu(
         let title = Paragraph::new(Line::from(Span::styled(
             "PAUSED",
             Style::default()
-                .fg(ACCENT_GOLD)
+                .fg(theme().gold)
                 .add_modifier(Modifier::BOLD),
         )))
         .alignment(Alignment::Center);
@@ -1910,7 +2653,7 @@ fn render_pause_menu(
 
         let footer = Paragraph::new(Line::from(Span::styled(
             "Enter: Select  |  Esc: Close",
-            Style::default().fg(TEXT_DIM),
+            Style::default().fg(theme().text_dim),
         )))
         .alignment(Alignment::Center);
         frame.render_widget(footer, layout[2]);
@@ -1931,6 +2674,186 @@ fn render_pause_menu(
     modal.render(frame, area, props);
 }
 
+fn render_travel_menu(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    selected: usize,
+    modal: &mut Modal,
+    travel_list: &mut SelectList,
+) {
+    let modal_area = travel_modal_area(area);
+    let mut render_content = |frame: &mut Frame, inner: Rect| {
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2),
+                Constraint::Min(4),
+                Constraint::Length(2),
+                Constraint::Length(1),
+            ])
+            .split(inner);
+
+        let title = Paragraph::new(Line::from(Span::styled(
+            "FAST TRAVEL",
+            Style::default()
+                .fg(theme().gold)
+                .add_modifier(Modifier::BOLD),
+        )))
+        .alignment(Alignment::Center);
+        frame.render_widget(title, layout[0]);
+
+        let items = travel_items(state);
+        let props = SelectListProps {
+            items: &items,
+            count: items.len(),
+            selected: selected.min(items.len().saturating_sub(1)),
+            is_focused: true,
+            style: inventory_list_style(),
+            behavior: SelectListBehavior {
+                show_scrollbar: items.len() > layout[1].height as usize,
+                wrap_navigation: true,
+            },
+            on_select: Action::TravelSelect,
+            render_item: &render_line,
+        };
+        travel_list.render(frame, layout[1], props);
+
+        let detail = match travel::plan(state, selected) {
+            Ok(journey) => {
+                let mut spans = vec![
+                    Span::styled("Arrive ", Style::default().fg(theme().text_dim)),
+                    Span::styled(
+                        travel::clock_label(state.travel.clock + journey.steps),
+                        Style::default().fg(theme().text),
+                    ),
+                ];
+                if !state.travel_encounters.is_empty() {
+                    spans.push(Span::styled(
+                        "  Ambush ",
+                        Style::default().fg(theme().text_dim),
+                    ));
+                    spans.push(Span::styled(
+                        format!("{}%", travel::ambush_chance(journey.steps)),
+                        Style::default().fg(theme().danger),
+                    ));
+                }
+                Line::from(spans)
+            }
+            Err(reason) => Line::from(Span::styled(reason, Style::default().fg(theme().text_dim))),
+        };
+        frame.render_widget(Paragraph::new(detail).wrap(Wrap { trim: true }), layout[2]);
+
+        let footer = Paragraph::new(Line::from(Span::styled(
+            "↑/↓ or W/S: Select  |  Enter: Travel  |  Esc: Close",
+            Style::default().fg(theme().text_dim),
+        )))
+        .alignment(Alignment::Center);
+        frame.render_widget(footer, layout[3]);
+    };
+
+    let props = ModalProps {
+        is_open: true,
+        is_focused: true,
+        area: modal_area,
+        style: inventory_modal_style(),
+        behavior: ModalBehavior {
+            close_on_esc: true,
+            close_on_backdrop: false,
+        },
+        on_close: travel_close,
+        render_content: &mut render_content,
+    };
+    modal.render(frame, area, props);
+}
+
+fn render_journal(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    selected: usize,
+    modal: &mut Modal,
+    journal_list: &mut SelectList,
+) {
+    let modal_area = journal_modal_area(area);
+    let mut render_content = |frame: &mut Frame, inner: Rect| {
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2),
+                Constraint::Min(4),
+                Constraint::Length(3),
+                Constraint::Length(1),
+            ])
+            .split(inner);
+
+        let title = Paragraph::new(Line::from(Span::styled(
+            "JOURNAL: DECISIONS",
+            Style::default()
+                .fg(theme().gold)
+                .add_modifier(Modifier::BOLD),
+        )))
+        .alignment(Alignment::Center);
+        frame.render_widget(title, layout[0]);
+
+        let items = journal_items(state);
+        let selected = selected.min(items.len().saturating_sub(1));
+        let props = SelectListProps {
+            items: &items,
+            count: items.len(),
+            selected,
+            is_focused: true,
+            style: inventory_list_style(),
+            behavior: SelectListBehavior {
+                show_scrollbar: items.len() > layout[1].height as usize,
+                wrap_navigation: true,
+            },
+            on_select: Action::JournalSelect,
+            render_item: &render_line,
+        };
+        journal_list.render(frame, layout[1], props);
+
+        if let Some(entry) = state.decisions.entries.get(selected) {
+            let dim = Style::default().fg(theme().text_dim);
+            let source = match &entry.npc {
+                Some(name) => format!("Decided with {name}"),
+                None => "Decided on the road".to_string(),
+            };
+            let flags = if entry.flags.is_empty() {
+                Span::styled("No lasting consequences", dim)
+            } else {
+                Span::styled(entry.flags.join(", "), Style::default().fg(theme().accent))
+            };
+            let detail = vec![
+                Line::from(Span::styled(source, dim)),
+                Line::from(vec![Span::styled("Consequences ", dim), flags]),
+            ];
+            frame.render_widget(Paragraph::new(detail).wrap(Wrap { trim: true }), layout[2]);
+        }
+
+        let footer = Paragraph::new(Line::from(Span::styled(
+            "↑/↓ or W/S: Select  |  Esc: Close",
+            Style::default().fg(theme().text_dim),
+        )))
+        .alignment(Alignment::Center);
+        frame.render_widget(footer, layout[3]);
+    };
+
+    let props = ModalProps {
+        is_open: true,
+        is_focused: true,
+        area: modal_area,
+        style: inventory_modal_style(),
+        behavior: ModalBehavior {
+            close_on_esc: true,
+            close_on_backdrop: false,
+        },
+        on_close: journal_close,
+        render_content: &mut render_content,
+    };
+    modal.render(frame, area, props);
+}
+
 fn render_inventory_modal(
     frame: &mut Frame,
     area: Rect,
@@ -1953,7 +2876,7 @@ fn render_inventory_modal(
         let title = Paragraph::new(Line::from(Span::styled(
             "INVENTORY",
             Style::default()
-                .fg(ACCENT_GOLD)
+                .fg(theme().gold)
                 .add_modifier(Modifier::BOLD),
         )))
         .alignment(Alignment::Center);
@@ -1963,7 +2886,7 @@ fn render_inventory_modal(
         if items.is_empty() {
             let empty = Paragraph::new(Line::from(Span::styled(
                 "Your pack is empty.",
-                Style::default().fg(TEXT_DIM),
+                Style::default().fg(theme().text_dim),
             )))
             .alignment(Alignment::Center);
             frame.render_widget(empty, layout[1]);
@@ -1990,15 +2913,18 @@ fn render_inventory_modal(
             .get(state.inventory_selected)
             .map(|item| {
                 Line::from(vec![
-                    Span::styled("Selected: ", Style::default().fg(TEXT_DIM)),
-                    Span::styled(item.name.clone(), Style::default().fg(TEXT_MAIN)),
-                    Span::styled(format!("  x{}", item.qty), Style::default().fg(ACCENT_GOLD)),
+                    Span::styled("Selected: ", Style::default().fg(theme().text_dim)),
+                    Span::styled(item.name.clone(), Style::default().fg(theme().text)),
+                    Span::styled(
+                        format!("  x{}", item.qty),
+                        Style::default().fg(theme().gold),
+                    ),
                 ])
             })
             .unwrap_or_else(|| {
                 Line::from(Span::styled(
                     "Selected: none",
-                    Style::default().fg(TEXT_DIM),
+                    Style::default().fg(theme().text_dim),
                 ))
             });
         let detail = Paragraph::new(detail_line).alignment(Alignment::Left);
@@ -2006,7 +2932,7 @@ fn render_inventory_modal(
 
         let footer = Paragraph::new(Line::from(Span::styled(
             "↑/↓ or W/S: Select  |  Esc/Tab: Close",
-            Style::default().fg(TEXT_DIM),
+            Style::default().fg(theme().text_dim),
         )))
         .alignment(Alignment::Center);
         frame.render_widget(footer, layout[3]);
@@ -2027,6 +2953,84 @@ fn render_inventory_modal(
     modal.render(frame, area, props);
 }
 
+fn render_maneuver_menu(
+    frame: &mut Frame,
+    area: Rect,
+    selected: usize,
+    modal: &mut Modal,
+    maneuver_list: &mut SelectList,
+) {
+    let modal_area = maneuver_modal_area(area);
+    let mut render_content = |frame: &mut Frame, inner: Rect| {
+        let layout = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Length(2),
+                Constraint::Min(5),
+                Constraint::Length(2),
+                Constraint::Length(1),
+            ])
+            .split(inner);
+
+        let title = Paragraph::new(Line::from(Span::styled(
+            "MANEUVERS",
+            Style::default()
+                .fg(theme().danger)
+                .add_modifier(Modifier::BOLD),
+        )))
+        .alignment(Alignment::Center);
+        frame.render_widget(title, layout[0]);
+
+        let items = maneuver_items();
+        let props = SelectListProps {
+            items: &items,
+            count: items.len(),
+            selected,
+            is_focused: true,
+            style: inventory_list_style(),
+            behavior: SelectListBehavior {
+                show_scrollbar: false,
+                wrap_navigation: true,
+            },
+            on_select: Action::ManeuverSelect,
+            render_item: &render_line,
+        };
+        maneuver_list.render(frame, layout[1], props);
+
+        let summary = Maneuver::ALL
+            .get(selected)
+            .map(|maneuver| maneuver.summary())
+            .unwrap_or_default();
+        let detail = Paragraph::new(Line::from(Span::styled(
+            summary,
+            Style::default().fg(theme().text),
+        )))
+        .wrap(Wrap { trim: true });
+        frame.render_widget(detail, layout[2]);
+
+        let footer = Paragraph::new(Line::from(Span::styled(
+            "Enter/1-5: Use instead of attacking  |  Esc: Close",
+            Style::default().fg(theme().text_dim),
+        )))
+        .alignment(Alignment::Center);
+        frame.render_widget(footer, layout[3]);
+    };
+
+    let props = ModalProps {
+        is_open: true,
+        is_focused: true,
+        area: modal_area,
+        style: inventory_modal_style(),
+        behavior: ModalBehavior {
+            close_on_esc: true,
+            close_on_backdrop: false,
+        },
+        on_close: maneuver_close,
+        render_content: &mut render_content,
+    };
+    modal.render(frame, area, props);
+}
+
 fn adjust_color(color: Color, delta: i16) -> Color {
     match color {
         Color::Rgb(r, g, b) => {
@@ -2052,6 +3056,8 @@ fn dnd_map_theme() -> TileTheme {
     let wall_alt = Color::Rgb(60, 68, 60);
     let water_base = Color::Rgb(48, 86, 146);
     let water_alt = Color::Rgb(52, 92, 150);
+    let stairs_base = Color::Rgb(112, 96, 78);
+    let stairs_alt = Color::Rgb(104, 90, 72);
 
     let grass = TilePalette::new(
         grass_base,
@@ -2099,6 +3105,16 @@ fn dnd_map_theme() -> TileTheme {
         ],
     );
 
+    let stairs = TilePalette::new(
+        stairs_base,
+        stairs_alt,
+        [
+            TextureVariant::new('=', adjust_color(stairs_base, 14), 6),
+            TextureVariant::new('-', adjust_color(stairs_base, 8), 7),
+            TextureVariant::new('.', adjust_color(stairs_base, -6), 8),
+        ],
+    );
+
     TileTheme::builder()
         .fallback(grass)
         .tile(TileKind::Grass, grass)
@@ -2107,6 +3123,7 @@ fn dnd_map_theme() -> TileTheme {
         .tile(TileKind::Sand, trail)
         .tile(TileKind::Wall, wall)
         .tile(TileKind::Water, water)
+        .tile(TileKind::Custom(STAIRS_TILE_ID), stairs)
         .build()
 }
 
@@ -2225,6 +3242,33 @@ mod tests {
         assert!(!is_inventory_open_key(key));
     }
 
+    #[test]
+    fn health_bar_sits_above_tile_and_clamps_to_edges() {
+        let bounds = Rect::new(10, 5, 40, 20);
+
+        let area = health_bar_area((20, 12), (4, 2), 8, bounds).unwrap();
+        assert_eq!(area, Rect::new(18, 10, 8, 2));
+
+        // Top row: flip below the tile
+        let area = health_bar_area((20, 5), (4, 2), 8, bounds).unwrap();
+        assert_eq!(area.y, 7);
+
+        // Left and right edges: slide inside the map
+        assert_eq!(health_bar_area((10, 12), (4, 2), 8, bounds).unwrap().x, 10);
+        assert_eq!(
+            health_bar_area((48, 12), (4, 2), 8, bounds)
+                .unwrap()
+                .right(),
+            50
+        );
+
+        // No room above or below
+        assert_eq!(
+            health_bar_area((20, 5), (4, 2), 8, Rect::new(10, 5, 40, 3)),
+            None
+        );
+    }
+
     #[test]
     fn log_focus_routes_arrow_keys_to_log_scroll() {
         let up_actions = handle_exploration_key(press(KeyCode::Up), PaneFocus::Log).actions;
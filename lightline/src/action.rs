@@ -12,13 +12,28 @@ pub enum Action {
 
     Move(Direction, bool),
     Interact,
+    /// Use the pack item in this slot (0-based).
+    UseItem(usize),
+    /// Hood or unhood the lantern.
+    ToggleHood,
     Tick,
     DangerAdvance,
     Descend,
+    /// Take the offered run modifier at this index (0-based).
+    PickModifier(usize),
     GameOver,
 
     PauseOpen,
     PauseClose,
+    LogScroll(i32),
+    /// Only does anything in a `--dev` run.
+    DevOverlayToggle,
+
+    ExportView {
+        png: bool,
+    },
+    ExportDidSave(String),
+    ExportDidError(String),
 
     Quit,
 }
@@ -0,0 +1,162 @@
+//! Exploration tracking and the exit compass.
+//!
+//! Every step marks the open tiles around the player as explored. Once enough
+//! of the floor has been seen, the HUD shows a rough arrow toward the exit:
+//! eight headings only, so it tells you which way to lean, not the route.
+
+use crate::narrative;
+use crate::state::AppState;
+
+// Compass tuning:
+// - REVEAL_RADIUS: tiles (Chebyshev) around the player marked explored per step.
+// - UNLOCK_PERCENT: share of the floor's open tiles to explore before the compass shows.
+const REVEAL_RADIUS: u16 = 2;
+const UNLOCK_PERCENT: u8 = 35;
+
+const ARROWS: [char; 8] = ['→', '↗', '↑', '↖', '←', '↙', '↓', '↘'];
+
+/// Marks open tiles within `REVEAL_RADIUS` of the player as explored.
+pub fn reveal(state: &mut AppState) {
+    let (width, height) = (state.map.width, state.map.height);
+    let len = width as usize * height as usize;
+    if state.explored.len() != len {
+        state.explored = vec![false; len];
+    }
+    let (px, py) = state.player_pos();
+    let max_x = px
+        .saturating_add(REVEAL_RADIUS)
+        .min(width.saturating_sub(1));
+    let max_y = py
+        .saturating_add(REVEAL_RADIUS)
+        .min(height.saturating_sub(1));
+    for y in py.saturating_sub(REVEAL_RADIUS)..=max_y {
+        for x in px.saturating_sub(REVEAL_RADIUS)..=max_x {
+            if state.map.is_walkable(x, y) {
+                state.explored[y as usize * width as usize + x as usize] = true;
+            }
+        }
+    }
+}
+
+/// Share of the floor's walkable tiles explored so far, 0-100.
+pub fn explored_percent(state: &AppState) -> u8 {
+    let mut open = 0usize;
+    let mut seen = 0usize;
+    for y in 0..state.map.height {
+        for x in 0..state.map.width {
+            if !state.map.is_walkable(x, y) {
+                continue;
+            }
+            open += 1;
+            let idx = y as usize * state.map.width as usize + x as usize;
+            if state.explored.get(idx).copied().unwrap_or(false) {
+                seen += 1;
+            }
+        }
+    }
+    if open == 0 {
+        return 0;
+    }
+    (seen * 100 / open) as u8
+}
+
+pub fn unlocked(state: &AppState) -> bool {
+    explored_percent(state) >= UNLOCK_PERCENT
+}
+
+/// Arrow toward the exit, once the compass is unlocked.
+pub fn exit_arrow(state: &AppState) -> Option<char> {
+    if !unlocked(state) {
+        return None;
+    }
+    let (dx, dy) = exit_offset(state)?;
+    Some(arrow(dx, dy))
+}
+
+/// Log line for the step that unlocks the compass.
+pub fn unlock_line(state: &AppState) -> Option<String> {
+    let (dx, dy) = exit_offset(state)?;
+    Some(format!(
+        "You get your bearings: the way down lies {}.",
+        narrative::direction_phrase(dx, dy)
+    ))
+}
+
+fn exit_offset(state: &AppState) -> Option<(i32, i32)> {
+    let (x, y) = state.exit_pos()?;
+    Some((
+        x as i32 - state.player.x as i32,
+        y as i32 - state.player.y as i32,
+    ))
+}
+
+/// Nearest of eight headings for an offset in map space (+y is south).
+fn arrow(dx: i32, dy: i32) -> char {
+    if dx == 0 && dy == 0 {
+        return '·';
+    }
+    // Terminal cells are about twice as tall as wide, but map tiles are drawn
+    // square, so the angle is taken in tile space as-is.
+    let angle = (-dy as f32).atan2(dx as f32);
+    let sector = (angle / std::f32::consts::FRAC_PI_4).round() as i32;
+    ARROWS[sector.rem_euclid(8) as usize]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{MapState, RuntimeAnchor, RuntimeAnchorKind};
+    use tui_map::core::{MapSize, TileKind};
+
+    fn open_floor(width: u16, height: u16) -> AppState {
+        let mut state = AppState::new(1);
+        state.map = MapState::filled("test", MapSize::new(width, height), TileKind::Floor);
+        state.player.x = 0;
+        state.player.y = 0;
+        state.anchors.push(RuntimeAnchor {
+            kind: RuntimeAnchorKind::Exit,
+            x: width - 1,
+            y: 0,
+            tag: None,
+        });
+        state
+    }
+
+    #[test]
+    fn arrows_cover_all_eight_headings() {
+        assert_eq!(arrow(5, 0), '→');
+        assert_eq!(arrow(0, -5), '↑');
+        assert_eq!(arrow(-3, 3), '↙');
+        assert_eq!(arrow(4, 4), '↘');
+        assert_eq!(arrow(-7, -6), '↖');
+        // Mostly east with a little north still reads as east.
+        assert_eq!(arrow(10, -2), '→');
+    }
+
+    #[test]
+    fn reveal_counts_only_open_tiles_near_the_player() {
+        let mut state = open_floor(10, 10);
+        reveal(&mut state);
+        // A 3x3 corner of a 10x10 floor.
+        assert_eq!(explored_percent(&state), 9);
+
+        state.map = MapState::filled("walls", MapSize::new(10, 10), TileKind::Wall);
+        assert_eq!(explored_percent(&state), 0);
+    }
+
+    #[test]
+    fn compass_unlocks_once_enough_is_explored() {
+        let mut state = open_floor(10, 2);
+        reveal(&mut state);
+        assert!(exit_arrow(&state).is_none());
+
+        state.player.x = 3;
+        reveal(&mut state);
+        assert!(unlocked(&state));
+        assert_eq!(exit_arrow(&state), Some('→'));
+        assert_eq!(
+            unlock_line(&state).as_deref(),
+            Some("You get your bearings: the way down lies to the east.")
+        );
+    }
+}
@@ -1,15 +1,41 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
+use crate::procgen::step_distances;
 use crate::state::MapState;
 
+// Stealth tuning, all in tiles of Manhattan distance:
+// - DARK_DETECTION: how close the hunter notices a player whose lantern
+//   barely glows.
+// - DETECTION_PER_LIGHT: extra detection per tile of lantern range, so a
+//   dimmer or hooded lantern lets the player slip closer.
+// - SUSPICION_MARGIN: band past the detection radius where the hunter is
+//   uneasy but holds still.
+// - SEARCH_STEPS: player steps the hunter keeps chasing after losing sight.
+pub const DARK_DETECTION: u16 = 2;
+pub const DETECTION_PER_LIGHT: u16 = 2;
+pub const SUSPICION_MARGIN: u16 = 3;
+pub const SEARCH_STEPS: u8 = 6;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum Awareness {
+    Unaware,
+    /// Something is off; shown as `?` over the hunter.
+    Suspicious,
+    /// Closing in; shown as `!` over the hunter.
+    Hunting,
+}
+
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub struct HunterState {
     pub x: u16,
     pub y: u16,
     pub step_interval: u8,
     pub step_cooldown: u8,
+    /// Detection never reaches past this, however bright the lantern.
     pub hearing_radius: u16,
+    pub awareness: Awareness,
+    pub search_left: u8,
 }
 
 impl HunterState {
@@ -20,8 +46,52 @@ impl HunterState {
             step_interval: 2,
             step_cooldown: 0,
             hearing_radius: 8,
+            awareness: Awareness::Unaware,
+            search_left: 0,
         }
     }
+
+    pub fn pos(&self) -> (u16, u16) {
+        (self.x, self.y)
+    }
+}
+
+/// Places the hunter on the open tile farthest from the player's start.
+pub fn spawn(map: &MapState, start: (u16, u16)) -> Option<HunterState> {
+    let distances = step_distances(map, start, false);
+    let (idx, _) = distances
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, distance)| distance.map(|distance| (idx, distance)))
+        .max_by_key(|&(idx, distance)| (distance, std::cmp::Reverse(idx)))?;
+    let width = map.width as usize;
+    Some(HunterState::new((idx % width) as u16, (idx / width) as u16))
+}
+
+/// How close the player has to be before the hunter notices a lantern
+/// throwing `light_range` tiles.
+pub fn detection_radius(state: &HunterState, light_range: u16) -> u16 {
+    DARK_DETECTION
+        .saturating_add(light_range.saturating_mul(DETECTION_PER_LIGHT))
+        .min(state.hearing_radius)
+}
+
+/// Updates awareness after a player step and returns it. A hunter that loses
+/// the player keeps chasing for `SEARCH_STEPS` before settling down.
+pub fn update_awareness(state: &mut HunterState, player: (u16, u16), radius: u16) -> Awareness {
+    let distance = manhattan(state.x, state.y, player.0, player.1);
+    state.awareness = if distance <= radius as u32 {
+        state.search_left = SEARCH_STEPS;
+        Awareness::Hunting
+    } else if state.awareness == Awareness::Hunting && state.search_left > 0 {
+        state.search_left -= 1;
+        Awareness::Hunting
+    } else if distance <= radius as u32 + SUSPICION_MARGIN as u32 {
+        Awareness::Suspicious
+    } else {
+        Awareness::Unaware
+    };
+    state.awareness
 }
 
 pub fn ready_to_advance(state: &mut HunterState) -> bool {
@@ -63,6 +133,36 @@ mod tests {
     use crate::state::MapState;
     use tui_map::core::{MapSize, TileKind};
 
+    #[test]
+    fn dimmer_lanterns_are_noticed_closer() {
+        let mut hunter = HunterState::new(0, 0);
+        assert_eq!(detection_radius(&hunter, 1), 4);
+        assert_eq!(detection_radius(&hunter, 2), 6);
+        assert_eq!(detection_radius(&hunter, 6), hunter.hearing_radius);
+
+        // Five tiles away: unseen with a dim lantern, spotted with a bright one.
+        let player = (5, 0);
+        assert_eq!(
+            update_awareness(&mut hunter, player, 4),
+            Awareness::Suspicious
+        );
+        assert_eq!(update_awareness(&mut hunter, player, 6), Awareness::Hunting);
+
+        // Once hunting, it keeps searching for a while after losing the player.
+        let far = (20, 0);
+        for _ in 0..SEARCH_STEPS {
+            assert_eq!(update_awareness(&mut hunter, far, 4), Awareness::Hunting);
+        }
+        assert_eq!(update_awareness(&mut hunter, far, 4), Awareness::Unaware);
+    }
+
+    #[test]
+    fn hunter_spawns_at_the_far_end() {
+        let map = MapState::filled("test", MapSize::new(6, 1), TileKind::Floor);
+        let hunter = spawn(&map, (1, 0)).expect("open tile");
+        assert_eq!(hunter.pos(), (5, 0));
+    }
+
     #[test]
     fn hunter_moves_toward_target_on_open_map() {
         let map = MapState::filled("test", MapSize::new(8, 8), TileKind::Floor);
@@ -1,2 +1,3 @@
 pub mod collapse;
 pub mod hunter;
+pub mod score;
@@ -0,0 +1,75 @@
+use crate::procgen::step_distances;
+use crate::state::{Direction, MapState, Tile};
+
+pub const MAX_SCORE: u8 = 9;
+/// Share of the scale that comes from distance; water and dead ends add the rest.
+const DISTANCE_SCORE: u32 = 7;
+
+/// How bad a place each tile is to be caught in, 0-9, for tuning floors:
+/// the walk back to the exit scaled against the floor's longest, plus a
+/// point for wading and a point for dead ends. Tiles the exit can't be
+/// reached from score the maximum; walls are `None`.
+pub fn tile_scores(map: &MapState, exit: Option<(u16, u16)>) -> Vec<Option<u8>> {
+    let to_exit = match exit {
+        Some(exit) => step_distances(map, exit, true),
+        None => vec![None; map.tiles.len()],
+    };
+    let longest = to_exit.iter().flatten().copied().max().unwrap_or(0).max(1) as u32;
+
+    let mut scores = Vec::with_capacity(map.tiles.len());
+    for y in 0..map.height {
+        for x in 0..map.width {
+            let tile = map.tile(x, y);
+            if tile == Tile::Wall {
+                scores.push(None);
+                continue;
+            }
+            let Some(distance) = to_exit[y as usize * map.width as usize + x as usize] else {
+                scores.push(Some(MAX_SCORE));
+                continue;
+            };
+            let mut score = distance as u32 * DISTANCE_SCORE / longest;
+            if tile == Tile::Water {
+                score += 1;
+            }
+            if is_dead_end(map, x, y) {
+                score += 1;
+            }
+            scores.push(Some((score as u8).min(MAX_SCORE)));
+        }
+    }
+    scores
+}
+
+fn is_dead_end(map: &MapState, x: u16, y: u16) -> bool {
+    let exits = Direction::ALL
+        .into_iter()
+        .filter(|&heading| map.can_step((x, y), heading))
+        .count();
+    exits <= 1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_map::core::{MapSize, TileKind};
+
+    #[test]
+    fn scores_grow_away_from_the_exit() {
+        // A corridor with the exit at its west end and water halfway along.
+        let mut map = MapState::filled("test", MapSize::new(8, 3), TileKind::Wall);
+        for x in 1..7 {
+            map.tiles[8 + x] = Tile::Floor;
+        }
+        map.tiles[8 + 3] = Tile::Water;
+
+        let scores = tile_scores(&map, Some((1, 1)));
+        let row: Vec<Option<u8>> = scores[8..16].to_vec();
+        assert_eq!(row[0], None);
+        assert_eq!(row[1], Some(1));
+        assert!(row[2] < row[4]);
+        assert_eq!(row[3], Some(3));
+        assert_eq!(row[6], Some(8));
+        assert_eq!(tile_scores(&map, None)[9], Some(MAX_SCORE));
+    }
+}
@@ -0,0 +1,142 @@
+//! Where the light went. Every burn is booked against its cause as it
+//! happens, and the game-over screen breaks the run's total down, biggest
+//! drain first, with a hint for the worst of them.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum DrainCause {
+    /// Steps onto unlit ground.
+    Walking,
+    /// The extra cost of wading through water.
+    Wading,
+    /// A leaking lantern, from a cursed relic.
+    Leak,
+    /// Feeling the walls for a false one.
+    Searching,
+    /// Light lost when a curse clouded the glass.
+    Dimmed,
+}
+
+impl DrainCause {
+    pub const ALL: [DrainCause; 5] = [
+        DrainCause::Walking,
+        DrainCause::Wading,
+        DrainCause::Leak,
+        DrainCause::Searching,
+        DrainCause::Dimmed,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            DrainCause::Walking => "Distance walked",
+            DrainCause::Wading => "Wading",
+            DrainCause::Leak => "Leaking lantern",
+            DrainCause::Searching => "Wall searches",
+            DrainCause::Dimmed => "Clouded glass",
+        }
+    }
+
+    /// What to try next run when this was the biggest drain.
+    pub fn hint(self) -> &'static str {
+        match self {
+            DrainCause::Walking => {
+                "Walking your own trail is free, and Shift+move takes back what you leave behind."
+            }
+            DrainCause::Wading => {
+                "Water costs extra light and time; a dry way round is usually cheaper."
+            }
+            DrainCause::Leak | DrainCause::Dimmed => {
+                "That was a cursed relic. Read your pack at a beacon before using what's in it."
+            }
+            DrainCause::Searching => {
+                "Searches add up; save them for walls where a room's shape looks wrong."
+            }
+        }
+    }
+}
+
+/// Shown in place of the top drain's hint when the hunter ended the run.
+pub const HUNTER_HINT: &str =
+    "The hunter follows lantern light; hood it (H) when the footsteps close in.";
+
+/// Light burned so far this run, by cause.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct DrainLedger {
+    /// Indexed like `DrainCause::ALL`.
+    burned: [u32; 5],
+    /// Times the hunter reached an explorer; the first one ends the run.
+    pub hunter_contacts: u32,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct DrainShare {
+    pub cause: DrainCause,
+    pub light: u32,
+    /// Rounded share of all the light burned.
+    pub percent: u32,
+}
+
+impl DrainLedger {
+    /// Books `burns` against a lantern holding `available`, in order, so a
+    /// burn that empties it only counts the light that was left.
+    pub fn book(&mut self, available: u16, burns: &[(DrainCause, u16)]) {
+        let mut left = available;
+        for &(cause, amount) in burns {
+            let spent = amount.min(left);
+            left -= spent;
+            self.burned[cause as usize] += spent as u32;
+        }
+    }
+
+    pub fn total(&self) -> u32 {
+        self.burned.iter().sum()
+    }
+
+    /// Causes that cost any light, biggest first.
+    pub fn breakdown(&self) -> Vec<DrainShare> {
+        let total = self.total();
+        let mut shares: Vec<DrainShare> = DrainCause::ALL
+            .iter()
+            .map(|&cause| (cause, self.burned[cause as usize]))
+            .filter(|&(_, light)| light > 0)
+            .map(|(cause, light)| DrainShare {
+                cause,
+                light,
+                percent: (light * 100 + total / 2) / total,
+            })
+            .collect();
+        shares.sort_by_key(|share| std::cmp::Reverse(share.light));
+        shares
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn booking_stops_at_the_light_that_was_left() {
+        let mut ledger = DrainLedger::default();
+        ledger.book(10, &[(DrainCause::Walking, 1), (DrainCause::Wading, 1)]);
+        ledger.book(2, &[(DrainCause::Walking, 1), (DrainCause::Leak, 3)]);
+        assert_eq!(ledger.total(), 4);
+        assert_eq!(ledger.burned[DrainCause::Leak as usize], 1);
+    }
+
+    #[test]
+    fn breakdown_puts_the_biggest_drain_first_with_its_share() {
+        let mut ledger = DrainLedger::default();
+        ledger.book(100, &[(DrainCause::Searching, 10)]);
+        for _ in 0..30 {
+            ledger.book(100, &[(DrainCause::Walking, 1)]);
+        }
+        let shares = ledger.breakdown();
+        assert_eq!(shares.len(), 2);
+        assert_eq!(shares[0].cause, DrainCause::Walking);
+        assert_eq!(shares[0].percent, 75);
+        assert_eq!(shares[1].percent, 25);
+        assert!(DrainLedger::default().breakdown().is_empty());
+    }
+}
@@ -6,4 +6,7 @@ pub enum Effect {
         width: u16,
         height: u16,
     },
+    ExportView {
+        png: bool,
+    },
 }
@@ -0,0 +1,367 @@
+use std::fmt::Write as _;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use ratatui::{
+    Terminal,
+    backend::TestBackend,
+    buffer::Buffer,
+    style::{Color, Modifier},
+    text::Span,
+};
+
+use crate::state::AppState;
+use crate::ui;
+
+// Cell rasterizer geometry: terminal cells are roughly twice as tall as wide.
+const CELL_PX_W: u32 = 8;
+const CELL_PX_H: u32 = 16;
+
+const DEFAULT_FG: (u8, u8, u8) = (230, 228, 218);
+const DEFAULT_BG: (u8, u8, u8) = (16, 18, 24);
+
+/// Files written by a single export.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ExportedFiles {
+    pub ansi: PathBuf,
+    pub png: Option<PathBuf>,
+}
+
+/// Render the current view offscreen at the terminal's size and write it to `dir`.
+pub fn export_view(
+    state: &AppState,
+    (width, height): (u16, u16),
+    dir: &Path,
+    png: bool,
+) -> io::Result<ExportedFiles> {
+    let mut terminal = Terminal::new(TestBackend::new(width.max(1), height.max(1)))?;
+    terminal.draw(|frame| ui::render(frame, frame.area(), state))?;
+    let buffer = terminal.backend().buffer().clone();
+
+    let stem = format!(
+        "lightline-{:x}-floor{}-{}",
+        state.seed,
+        state.floor_index + 1,
+        unix_seconds()
+    );
+    let ansi = dir.join(format!("{stem}.ans"));
+    std::fs::write(&ansi, buffer_to_ansi(&buffer))?;
+
+    let png = if png {
+        let path = dir.join(format!("{stem}.png"));
+        std::fs::write(&path, buffer_to_png(&buffer))?;
+        Some(path)
+    } else {
+        None
+    };
+
+    Ok(ExportedFiles { ansi, png })
+}
+
+/// Serialize a buffer as text with 24-bit SGR escapes, one terminal row per line.
+pub fn buffer_to_ansi(buffer: &Buffer) -> String {
+    let area = buffer.area;
+    let mut out = String::new();
+    for y in area.top()..area.bottom() {
+        let mut last_style = None;
+        let mut skip = 0usize;
+        for x in area.left()..area.right() {
+            if skip > 0 {
+                skip -= 1;
+                continue;
+            }
+            let cell = &buffer[(x, y)];
+            let style = (cell.fg, cell.bg, cell.modifier);
+            if last_style != Some(style) {
+                out.push_str("\x1b[0");
+                push_modifiers(&mut out, cell.modifier);
+                push_color(&mut out, cell.fg, false);
+                push_color(&mut out, cell.bg, true);
+                out.push('m');
+                last_style = Some(style);
+            }
+            let symbol = cell.symbol();
+            out.push_str(symbol);
+            skip = Span::raw(symbol).width().saturating_sub(1);
+        }
+        out.push_str("\x1b[0m\n");
+    }
+    out
+}
+
+fn push_modifiers(out: &mut String, modifier: Modifier) {
+    for (flag, code) in [
+        (Modifier::BOLD, 1),
+        (Modifier::DIM, 2),
+        (Modifier::ITALIC, 3),
+        (Modifier::UNDERLINED, 4),
+        (Modifier::REVERSED, 7),
+    ] {
+        if modifier.contains(flag) {
+            let _ = write!(out, ";{code}");
+        }
+    }
+}
+
+fn push_color(out: &mut String, color: Color, background: bool) {
+    let base = if background { 40 } else { 30 };
+    let _ = match color {
+        Color::Reset => Ok(()),
+        Color::Rgb(r, g, b) => write!(out, ";{};2;{r};{g};{b}", base + 8),
+        Color::Indexed(index) => write!(out, ";{};5;{index}", base + 8),
+        named => {
+            let index = named_index(named);
+            if index < 8 {
+                write!(out, ";{}", base + index)
+            } else {
+                write!(out, ";{}", base + 60 + index - 8)
+            }
+        }
+    };
+}
+
+fn named_index(color: Color) -> u8 {
+    match color {
+        Color::Black => 0,
+        Color::Red => 1,
+        Color::Green => 2,
+        Color::Yellow => 3,
+        Color::Blue => 4,
+        Color::Magenta => 5,
+        Color::Cyan => 6,
+        Color::Gray => 7,
+        Color::DarkGray => 8,
+        Color::LightRed => 9,
+        Color::LightGreen => 10,
+        Color::LightYellow => 11,
+        Color::LightBlue => 12,
+        Color::LightMagenta => 13,
+        Color::LightCyan => 14,
+        _ => 15,
+    }
+}
+
+fn color_rgb(color: Color, fallback: (u8, u8, u8)) -> (u8, u8, u8) {
+    const PALETTE: [(u8, u8, u8); 16] = [
+        (0, 0, 0),
+        (205, 49, 49),
+        (13, 188, 121),
+        (229, 229, 16),
+        (36, 114, 200),
+        (188, 63, 188),
+        (17, 168, 205),
+        (204, 204, 204),
+        (102, 102, 102),
+        (241, 76, 76),
+        (35, 209, 139),
+        (245, 245, 67),
+        (59, 142, 234),
+        (214, 112, 214),
+        (41, 184, 219),
+        (242, 242, 242),
+    ];
+    match color {
+        Color::Reset => fallback,
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Indexed(index) if index < 16 => PALETTE[index as usize],
+        Color::Indexed(_) => fallback,
+        named => PALETTE[named_index(named) as usize],
+    }
+}
+
+/// Fraction of a cell covered by foreground ink. Shade blocks map to their density;
+/// any other visible glyph is drawn as a centered stroke, which reads well at map scale.
+fn glyph_coverage(symbol: &str) -> Option<f32> {
+    match symbol {
+        "" | " " => None,
+        "█" => Some(1.0),
+        "▓" => Some(0.75),
+        "▒" => Some(0.5),
+        "░" => Some(0.25),
+        _ => Some(0.0),
+    }
+}
+
+fn rasterize(buffer: &Buffer) -> (u32, u32, Vec<u8>) {
+    let area = buffer.area;
+    let width = area.width as u32 * CELL_PX_W;
+    let height = area.height as u32 * CELL_PX_H;
+    let mut pixels = vec![0u8; (width * height * 3) as usize];
+
+    for cy in 0..area.height {
+        for cx in 0..area.width {
+            let cell = &buffer[(area.x + cx, area.y + cy)];
+            let (mut fg, mut bg) = (
+                color_rgb(cell.fg, DEFAULT_FG),
+                color_rgb(cell.bg, DEFAULT_BG),
+            );
+            if cell.modifier.contains(Modifier::REVERSED) {
+                std::mem::swap(&mut fg, &mut bg);
+            }
+            let coverage = glyph_coverage(cell.symbol());
+            for py in 0..CELL_PX_H {
+                for px in 0..CELL_PX_W {
+                    let ink = match coverage {
+                        None => false,
+                        // Ordered dither keeps shade blocks textured rather than flat.
+                        Some(c) if c > 0.0 => ((px + py * 3) % 4) as f32 / 4.0 < c,
+                        Some(_) => (2..6).contains(&px) && (4..12).contains(&py),
+                    };
+                    let (r, g, b) = if ink { fg } else { bg };
+                    let x = cx as u32 * CELL_PX_W + px;
+                    let y = cy as u32 * CELL_PX_H + py;
+                    let idx = ((y * width + x) * 3) as usize;
+                    pixels[idx..idx + 3].copy_from_slice(&[r, g, b]);
+                }
+            }
+        }
+    }
+
+    (width, height, pixels)
+}
+
+/// Rasterize a buffer into an RGB PNG using stored (uncompressed) deflate blocks.
+pub fn buffer_to_png(buffer: &Buffer) -> Vec<u8> {
+    let (width, height, pixels) = rasterize(buffer);
+
+    // Each scanline is prefixed with filter type 0 (none).
+    let stride = width as usize * 3;
+    let mut raw = Vec::with_capacity((stride + 1) * height as usize);
+    for row in pixels.chunks(stride.max(1)) {
+        raw.push(0);
+        raw.extend_from_slice(row);
+    }
+
+    let mut ihdr = Vec::with_capacity(13);
+    ihdr.extend_from_slice(&width.to_be_bytes());
+    ihdr.extend_from_slice(&height.to_be_bytes());
+    // 8-bit depth, RGB, default compression/filter, no interlace
+    ihdr.extend_from_slice(&[8, 2, 0, 0, 0]);
+
+    let mut png = b"\x89PNG\r\n\x1a\n".to_vec();
+    write_chunk(&mut png, b"IHDR", &ihdr);
+    write_chunk(&mut png, b"IDAT", &zlib_stored(&raw));
+    write_chunk(&mut png, b"IEND", &[]);
+    png
+}
+
+fn write_chunk(out: &mut Vec<u8>, kind: &[u8; 4], data: &[u8]) {
+    out.extend_from_slice(&(data.len() as u32).to_be_bytes());
+    let start = out.len();
+    out.extend_from_slice(kind);
+    out.extend_from_slice(data);
+    let crc = crc32(&out[start..]);
+    out.extend_from_slice(&crc.to_be_bytes());
+}
+
+fn zlib_stored(data: &[u8]) -> Vec<u8> {
+    const MAX_BLOCK: usize = 65_535;
+    let mut out = vec![0x78, 0x01];
+    let mut blocks = data.chunks(MAX_BLOCK).peekable();
+    if blocks.peek().is_none() {
+        out.extend_from_slice(&[1, 0, 0, 0xFF, 0xFF]);
+    }
+    while let Some(block) = blocks.next() {
+        let is_final = blocks.peek().is_none();
+        let len = block.len() as u16;
+        out.push(u8::from(is_final));
+        out.extend_from_slice(&len.to_le_bytes());
+        out.extend_from_slice(&(!len).to_le_bytes());
+        out.extend_from_slice(block);
+    }
+    out.extend_from_slice(&adler32(data).to_be_bytes());
+    out
+}
+
+fn crc32(data: &[u8]) -> u32 {
+    let mut crc = 0xFFFF_FFFFu32;
+    for &byte in data {
+        crc ^= byte as u32;
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xEDB8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+fn adler32(data: &[u8]) -> u32 {
+    const MOD: u32 = 65_521;
+    let (mut a, mut b) = (1u32, 0u32);
+    for &byte in data {
+        a = (a + byte as u32) % MOD;
+        b = (b + a) % MOD;
+    }
+    (b << 16) | a
+}
+
+fn unix_seconds() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+    use ratatui::style::Style;
+
+    fn sample_buffer() -> Buffer {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 4, 2));
+        buffer.set_string(0, 0, "ab", Style::default().fg(Color::Rgb(1, 2, 3)));
+        buffer.set_string(0, 1, "█", Style::default().bg(Color::Red));
+        buffer
+    }
+
+    #[test]
+    fn ansi_export_keeps_text_and_colors() {
+        let ansi = buffer_to_ansi(&sample_buffer());
+        let lines: Vec<&str> = ansi.lines().collect();
+        assert_eq!(lines.len(), 2);
+        assert!(lines[0].starts_with("\x1b[0;38;2;1;2;3mab"));
+        assert!(lines[1].contains("\x1b[0;41m█"));
+        assert!(lines.iter().all(|line| line.ends_with("\x1b[0m")));
+    }
+
+    #[test]
+    fn crc32_matches_known_iend_checksum() {
+        assert_eq!(crc32(b"IEND"), 0xAE42_6082);
+    }
+
+    #[test]
+    fn adler32_matches_reference() {
+        assert_eq!(adler32(b"Wikipedia"), 0x11E6_0398);
+    }
+
+    #[test]
+    fn png_export_has_header_and_dimensions() {
+        let png = buffer_to_png(&sample_buffer());
+        assert_eq!(&png[..8], b"\x89PNG\r\n\x1a\n");
+        assert_eq!(&png[12..16], b"IHDR");
+        assert_eq!(
+            u32::from_be_bytes(png[16..20].try_into().unwrap()),
+            4 * CELL_PX_W
+        );
+        assert_eq!(
+            u32::from_be_bytes(png[20..24].try_into().unwrap()),
+            2 * CELL_PX_H
+        );
+        assert_eq!(&png[png.len() - 8..png.len() - 4], b"IEND");
+    }
+
+    #[test]
+    fn full_block_rasterizes_as_foreground() {
+        let mut buffer = Buffer::empty(Rect::new(0, 0, 1, 1));
+        buffer.set_string(
+            0,
+            0,
+            "█",
+            Style::default().fg(Color::Rgb(9, 8, 7)).bg(Color::Black),
+        );
+        let (_, _, pixels) = rasterize(&buffer);
+        assert!(pixels.chunks(3).all(|px| px == [9, 8, 7]));
+    }
+}
@@ -0,0 +1,159 @@
+//! Hotseat: two explorers share a floor and take turns at the keyboard.
+//!
+//! `state.player` is always the explorer whose turn it is. The other one waits
+//! in [`Hotseat::partner`] with their own lantern, pack and step count. A step
+//! or a wall search is a turn, and control passes after a fixed number of
+//! them. A lantern going dark hands over straight away, for good; the run
+//! only ends once both are out.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::state::{AppState, PlayerState};
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Hotseat {
+    /// The explorer waiting for their turn.
+    pub partner: PlayerState,
+    /// Which explorer `state.player` is: 0 for the first, 1 for the second.
+    pub active: usize,
+    /// Turns each explorer gets before handing over.
+    pub turns_per_shift: u32,
+    /// Turns the active explorer has left before handing over.
+    pub turns_left: u32,
+}
+
+impl Hotseat {
+    /// A second explorer kitted out like `player`, with a full lantern.
+    pub fn new(player: &PlayerState, turns_per_shift: u32) -> Self {
+        let turns_per_shift = turns_per_shift.max(1);
+        Self {
+            partner: PlayerState {
+                light_current: player.light_max,
+                ..player.clone()
+            },
+            active: 0,
+            turns_per_shift,
+            turns_left: turns_per_shift,
+        }
+    }
+
+    pub fn partner_index(&self) -> usize {
+        1 - self.active
+    }
+}
+
+/// How the HUD and log name explorer `index`.
+pub fn name(index: usize) -> String {
+    format!("Explorer {}", index + 1)
+}
+
+/// Sets the partner down on the new floor beside the active explorer, with a
+/// fresh lantern if theirs is still lit. `light` is the floor's budget.
+pub fn start_floor(state: &mut AppState, light: u16) {
+    let (x, y) = state.player_pos();
+    let Some(seat) = state.hotseat.as_mut() else {
+        return;
+    };
+    seat.partner.x = x;
+    seat.partner.y = y;
+    if seat.partner.light_current > 0 {
+        seat.partner.light_max = light;
+        seat.partner.light_current = light;
+    }
+}
+
+/// Counts off a turn, handing over once the shift is up. Returns the log
+/// line when control passed. A partner in the dark never gets a turn.
+pub fn end_turn(state: &mut AppState) -> Option<String> {
+    let seat = state.hotseat.as_mut()?;
+    seat.turns_left = seat.turns_left.saturating_sub(1);
+    if seat.turns_left > 0 {
+        return None;
+    }
+    seat.turns_left = seat.turns_per_shift;
+    if seat.partner.light_current == 0 {
+        return None;
+    }
+    hand_over(state);
+    let active = state.hotseat.as_ref()?.active;
+    Some(format!("{} takes the lead.", name(active)))
+}
+
+/// The active lantern has gone dark. Hands over for good when the partner's
+/// is still lit and returns the log line; `None` means both are out.
+pub fn lantern_out(state: &mut AppState) -> Option<String> {
+    let seat = state.hotseat.as_ref()?;
+    if seat.partner.light_current == 0 {
+        return None;
+    }
+    let dark = name(seat.active);
+    hand_over(state);
+    let active = state.hotseat.as_ref()?.active;
+    Some(format!(
+        "{dark}'s lantern goes dark. {} carries on alone.",
+        name(active)
+    ))
+}
+
+fn hand_over(state: &mut AppState) {
+    let Some(seat) = state.hotseat.as_mut() else {
+        return;
+    };
+    std::mem::swap(&mut state.player, &mut seat.partner);
+    seat.active = seat.partner_index();
+    seat.turns_left = seat.turns_per_shift;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pair(turns: u32) -> AppState {
+        let mut state = AppState::new(3);
+        state.player.x = 2;
+        state.player.light_current = 40;
+        state.hotseat = Some(Hotseat::new(&state.player, turns));
+        state.hotseat.as_mut().unwrap().partner.x = 5;
+        state
+    }
+
+    #[test]
+    fn control_passes_after_each_shift() {
+        let mut state = pair(2);
+        assert_eq!(state.hotseat.as_ref().unwrap().partner.light_current, 120);
+
+        assert_eq!(end_turn(&mut state), None);
+        assert_eq!(
+            end_turn(&mut state).as_deref(),
+            Some("Explorer 2 takes the lead.")
+        );
+        let seat = state.hotseat.as_ref().unwrap();
+        assert_eq!((seat.active, seat.turns_left), (1, 2));
+        assert_eq!(state.player.x, 5);
+        assert_eq!(seat.partner.x, 2);
+
+        end_turn(&mut state);
+        end_turn(&mut state);
+        assert_eq!(state.hotseat.as_ref().unwrap().active, 0);
+        assert_eq!(state.player.x, 2);
+    }
+
+    #[test]
+    fn a_dark_lantern_hands_over_for_good() {
+        let mut state = pair(1);
+        state.player.light_current = 0;
+        let line = lantern_out(&mut state).expect("partner still lit");
+        assert!(line.starts_with("Explorer 1's lantern goes dark"));
+        assert_eq!(state.hotseat.as_ref().unwrap().active, 1);
+
+        // The dark explorer is skipped from now on, even on a new floor.
+        assert_eq!(end_turn(&mut state), None);
+        start_floor(&mut state, 90);
+        assert_eq!(state.hotseat.as_ref().unwrap().partner.light_current, 0);
+        assert_eq!(state.hotseat.as_ref().unwrap().partner.x, state.player.x);
+
+        state.player.light_current = 0;
+        assert_eq!(lantern_out(&mut state), None);
+    }
+}
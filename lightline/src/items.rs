@@ -0,0 +1,276 @@
+//! Relic items: what each relic holds, how unidentified items are named, and
+//! what using one does.
+//!
+//! Every run gives each item kind its own gibberish label. Using an item, or
+//! reading the pack at a beacon, reveals the kind for the rest of the run.
+//! Cursed items do nothing useful and leave a curse behind instead.
+
+use crate::drain::DrainCause;
+use crate::narrative;
+use crate::procgen::mix64;
+use crate::secrets;
+use crate::state::{AppState, Curse, Item, ItemKind, RuntimeAnchorKind};
+
+// Item tuning:
+// - OIL_LIGHT: light restored by a flask of lamp oil.
+// - WICK_BONUS: extra max light (and light) from a spare wick, until the next floor.
+// - LEAK_STEPS: steps a leaking lantern burns one extra light for.
+// - DIM_AMOUNT: max light lost to a dimming curse, until the next floor.
+// - CURSE_ODDS: one relic in CURSE_ODDS is cursed.
+pub const PACK_SIZE: usize = 5;
+const OIL_LIGHT: u16 = 30;
+const WICK_BONUS: u16 = 15;
+pub const LEAK_STEPS: u16 = 20;
+const DIM_AMOUNT: u16 = 20;
+const CURSE_ODDS: u64 = 3;
+
+const ITEM_SALT: u64 = 0x243f_6a88_85a3_08d3;
+const LABEL_SALT: u64 = 0x1319_8a2e_0370_7344;
+const SYLLABLES: [&str; 16] = [
+    "ka", "zo", "vel", "mir", "tha", "rek", "ul", "sen", "dor", "ix", "ne", "gru", "po", "lim",
+    "ash", "tor",
+];
+
+impl ItemKind {
+    pub const ALL: [ItemKind; 4] = [
+        ItemKind::LampOil,
+        ItemKind::SpareWick,
+        ItemKind::Chalk,
+        ItemKind::Bomb,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            ItemKind::LampOil => "Lamp Oil",
+            ItemKind::SpareWick => "Spare Wick",
+            ItemKind::Chalk => "Pathfinder's Chalk",
+            ItemKind::Bomb => "Blasting Powder",
+        }
+    }
+}
+
+impl Curse {
+    pub fn label(self) -> &'static str {
+        match self {
+            Curse::Leak => "leaking",
+            Curse::Dim => "dimming",
+        }
+    }
+}
+
+/// The item sitting in this floor's relic. Same seed and floor, same item.
+pub fn relic_item(seed: u64, floor_index: u32) -> Item {
+    let roll = mix64(seed ^ ITEM_SALT ^ ((floor_index as u64) << 32));
+    let kind = ItemKind::ALL[(roll % ItemKind::ALL.len() as u64) as usize];
+    let curse_kind = if (roll >> 40) & 1 == 0 {
+        Curse::Leak
+    } else {
+        Curse::Dim
+    };
+    let curse = (roll >> 16)
+        .is_multiple_of(CURSE_ODDS)
+        .then_some(curse_kind);
+    Item {
+        kind,
+        curse,
+        curse_known: false,
+    }
+}
+
+/// Gibberish label an unidentified `kind` goes by for this run, e.g. `VELKA ZOTOR`.
+pub fn scrambled_name(seed: u64, kind: ItemKind) -> String {
+    let mut z = mix64(seed ^ LABEL_SALT ^ kind as u64);
+    let mut word = || {
+        let mut word = String::new();
+        for _ in 0..2 {
+            word.push_str(SYLLABLES[(z % SYLLABLES.len() as u64) as usize]);
+            z >>= 4;
+        }
+        word.to_uppercase()
+    };
+    let first = word();
+    let second = word();
+    format!("{first} {second}")
+}
+
+/// How `item` reads in the pack: its real name once identified, its scrambled
+/// label before that.
+pub fn display_name(state: &AppState, item: &Item) -> String {
+    if !state.identified.contains(&item.kind) {
+        return format!("'{}'", scrambled_name(state.seed, item.kind));
+    }
+    match item.curse {
+        Some(curse) if item.curse_known => format!("{} ({})", item.kind.name(), curse.label()),
+        _ => item.kind.name().to_string(),
+    }
+}
+
+/// Learns every item kind in the pack and exposes their curses. Returns the
+/// log line, or `None` when there is nothing left to learn.
+pub fn identify_pack(state: &mut AppState) -> Option<String> {
+    let mut learned = Vec::new();
+    let mut curses = 0;
+    for item in &mut state.player.pack {
+        if !state.identified.contains(&item.kind) {
+            state.identified.push(item.kind);
+            learned.push(item.kind.name());
+        }
+        if item.curse.is_some() && !item.curse_known {
+            item.curse_known = true;
+            curses += 1;
+        }
+    }
+    if learned.is_empty() && curses == 0 {
+        return None;
+    }
+    let mut line = "The beacon's glow reveals your pack".to_string();
+    if !learned.is_empty() {
+        line.push_str(&format!(": {}", learned.join(", ")));
+    }
+    line.push('.');
+    if curses > 0 {
+        line.push_str(" Something in it is cursed.");
+    }
+    Some(line)
+}
+
+/// Uses the pack item in `slot`, identifying its kind. Returns the log line.
+pub fn use_item(state: &mut AppState, slot: usize) -> Option<String> {
+    if slot >= state.player.pack.len() {
+        return None;
+    }
+    let item = state.player.pack.remove(slot);
+    let label = display_name(state, &item);
+    let first_use = !state.identified.contains(&item.kind);
+    if first_use {
+        state.identified.push(item.kind);
+    }
+    let revealed = if first_use {
+        format!("The {label} was {}. ", item.kind.name())
+    } else {
+        String::new()
+    };
+
+    let outcome = match item.curse {
+        Some(curse) => apply_curse(state, curse),
+        None => apply_effect(state, item.kind),
+    };
+    Some(format!("{revealed}{outcome}"))
+}
+
+fn apply_effect(state: &mut AppState, kind: ItemKind) -> String {
+    let player = &mut state.player;
+    match kind {
+        ItemKind::LampOil => {
+            player.light_current = player
+                .light_current
+                .saturating_add(OIL_LIGHT)
+                .min(player.light_max);
+            "Your lantern flares brighter.".to_string()
+        }
+        ItemKind::SpareWick => {
+            player.light_max = player.light_max.saturating_add(WICK_BONUS);
+            player.light_current = player.light_current.saturating_add(WICK_BONUS);
+            "The new wick holds more light.".to_string()
+        }
+        ItemKind::Chalk => match state.anchor_pos(RuntimeAnchorKind::Exit) {
+            Some((x, y)) => {
+                let (dx, dy) = (
+                    x as i32 - state.player.x as i32,
+                    y as i32 - state.player.y as i32,
+                );
+                format!("Chalk marks point {}.", narrative::direction_phrase(dx, dy))
+            }
+            None => "The chalk crumbles; there is no way out here.".to_string(),
+        },
+        ItemKind::Bomb => secrets::blast(state),
+    }
+}
+
+fn apply_curse(state: &mut AppState, curse: Curse) -> String {
+    let player = &mut state.player;
+    match curse {
+        Curse::Leak => {
+            player.leak_steps = LEAK_STEPS;
+            "It was cursed! Your lantern starts to leak.".to_string()
+        }
+        Curse::Dim => {
+            let before = player.light_current;
+            player.light_max = player.light_max.saturating_sub(DIM_AMOUNT).max(1);
+            player.light_current = player.light_current.min(player.light_max);
+            let lost = before - player.light_current;
+            state.drain.book(before, &[(DrainCause::Dimmed, lost)]);
+            "It was cursed! Your lantern's glass clouds over.".to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scrambled_names_are_stable_per_run_and_differ_by_kind() {
+        let names: Vec<String> = ItemKind::ALL
+            .iter()
+            .map(|&kind| scrambled_name(11, kind))
+            .collect();
+        assert_eq!(names[0], scrambled_name(11, ItemKind::LampOil));
+        assert_ne!(names[0], names[1]);
+        assert_ne!(names[1], names[2]);
+        assert_ne!(names[0], scrambled_name(12, ItemKind::LampOil));
+    }
+
+    #[test]
+    fn using_an_item_identifies_its_kind() {
+        let mut state = AppState::new(3);
+        state.player.light_current = 50;
+        let oil = Item {
+            kind: ItemKind::LampOil,
+            curse: None,
+            curse_known: false,
+        };
+        state.player.pack = vec![oil.clone(), oil.clone()];
+        assert!(display_name(&state, &oil).starts_with('\''));
+
+        let line = use_item(&mut state, 0).expect("used");
+        assert!(line.starts_with("The '"));
+        assert_eq!(state.player.light_current, 80);
+        assert_eq!(display_name(&state, &state.player.pack[0]), "Lamp Oil");
+        assert!(use_item(&mut state, 5).is_none());
+    }
+
+    #[test]
+    fn cursed_items_backfire() {
+        let mut state = AppState::new(3);
+        state.player.light_current = 100;
+        state.player.pack = vec![Item {
+            kind: ItemKind::SpareWick,
+            curse: Some(Curse::Dim),
+            curse_known: false,
+        }];
+
+        let _ = use_item(&mut state, 0);
+        assert_eq!(state.player.light_max, 100);
+        assert_eq!(state.player.light_current, 100);
+        assert!(state.player.pack.is_empty());
+    }
+
+    #[test]
+    fn beacon_reveals_kinds_and_curses_once() {
+        let mut state = AppState::new(3);
+        state.player.pack = vec![Item {
+            kind: ItemKind::Chalk,
+            curse: Some(Curse::Leak),
+            curse_known: false,
+        }];
+
+        let line = identify_pack(&mut state).expect("something to learn");
+        assert!(line.contains("Pathfinder's Chalk"));
+        assert_eq!(
+            display_name(&state, &state.player.pack[0]),
+            "Pathfinder's Chalk (leaking)"
+        );
+        assert!(identify_pack(&mut state).is_none());
+    }
+}
@@ -3,7 +3,7 @@ use std::{cmp::Ordering, collections::BinaryHeap};
 use ratatui::{buffer::Buffer, style::Color};
 use tui_map::render::MapRenderResult;
 
-use crate::state::MapState;
+use crate::state::{MapState, Tile};
 
 // Lighting tuneables (gameplay/visual knobs):
 // - FALL_OFF_EXPONENT: direct light drop-off steepness from each source.
@@ -12,6 +12,9 @@ use crate::state::MapState;
 // - GAMMA/MIN_VISIBLE: transfer curve from tile brightness to rendered darkness.
 // - DDA_TIE_EPS: corner-tie tolerance in ray traversal.
 // - FLOOR_PROPAGATE_*: post-pass diffusion strength and cutoff.
+// - WATER_AMPLIFY/WATER_REFLECT_FACTOR: lit water brightens itself and spills
+//   a reflection onto its open neighbors.
+// - WATER_GLINT_*: brightness above which lit water picks up a pale highlight.
 const FALL_OFF_EXPONENT: f32 = 1.35;
 const RAY_ANGLE_EPS: f32 = 0.0008;
 const WALL_BOUNCE_FACTOR: f32 = 0.05;
@@ -22,6 +25,20 @@ const FLOOR_PROPAGATE_CARDINAL_DECAY: f32 = 0.64;
 const FLOOR_PROPAGATE_DIAGONAL_DECAY: f32 = 0.46;
 const FLOOR_PROPAGATE_CUTOFF: f32 = 0.01;
 const FLOOR_PROPAGATE_EPS: f32 = 0.0001;
+const WATER_AMPLIFY: f32 = 1.25;
+const WATER_REFLECT_FACTOR: f32 = 0.14;
+const WATER_GLINT_MIN: f32 = 0.3;
+const WATER_GLINT_STRENGTH: f32 = 0.55;
+const WATER_GLINT: Color = Color::Rgb(200, 232, 255);
+
+// Player light range tuning:
+// range = (BASE + light_current / DIVISOR).clamp(MIN, MAX)
+// A hooded lantern throws range / HOOD_DIVISOR, still at least MIN.
+const PLAYER_LIGHT_RANGE_BASE: u16 = 1;
+const PLAYER_LIGHT_RANGE_DIVISOR: u16 = 30;
+const PLAYER_LIGHT_RANGE_MIN: u16 = 1;
+const PLAYER_LIGHT_RANGE_MAX: u16 = 6;
+const PLAYER_LIGHT_HOOD_DIVISOR: u16 = 2;
 
 #[derive(Clone, Copy, Debug, PartialEq)]
 pub struct LightSource {
@@ -138,6 +155,18 @@ impl FaceLight {
     }
 }
 
+/// How far the player's lantern reaches, in tiles. The renderer lights the
+/// map with it and the hunter spots the player by it.
+pub fn player_light_range(light_current: u16, hooded: bool) -> u16 {
+    let range = PLAYER_LIGHT_RANGE_BASE + light_current / PLAYER_LIGHT_RANGE_DIVISOR;
+    let range = if hooded {
+        range / PLAYER_LIGHT_HOOD_DIVISOR
+    } else {
+        range
+    };
+    range.clamp(PLAYER_LIGHT_RANGE_MIN, PLAYER_LIGHT_RANGE_MAX)
+}
+
 pub fn compute_light_field(
     map: &MapState,
     start_x: u16,
@@ -186,6 +215,7 @@ pub fn compute_light_field(
         &face_lights,
         &mut floor_light,
     );
+    apply_water_reflection(map, start_x, start_y, width, height, &mut floor_light);
     propagate_floor_light(map, start_x, start_y, width, height, &mut floor_light);
 
     for local_y in 0..height {
@@ -679,6 +709,42 @@ fn apply_directional_wall_bounce(
     }
 }
 
+fn apply_water_reflection(
+    map: &MapState,
+    start_x: u16,
+    start_y: u16,
+    width: u16,
+    height: u16,
+    floor_light: &mut [f32],
+) {
+    // Read from the direct light only so reflections don't feed each other.
+    let direct = floor_light.to_vec();
+    for (idx, &brightness) in direct.iter().enumerate() {
+        if brightness <= 0.0 {
+            continue;
+        }
+        let map_x = start_x as i32 + (idx % width as usize) as i32;
+        let map_y = start_y as i32 + (idx / width as usize) as i32;
+        if map.tile(map_x as u16, map_y as u16) != Tile::Water {
+            continue;
+        }
+
+        floor_light[idx] = floor_light[idx].max((brightness * WATER_AMPLIFY).min(1.0));
+        for (dx, dy) in [(-1, 0), (1, 0), (0, -1), (0, 1)] {
+            let (tx, ty) = (map_x + dx, map_y + dy);
+            if tx < 0 || ty < 0 || map.is_light_blocker(tx as u16, ty as u16) {
+                continue;
+            }
+            if let Some(target_idx) = local_index_in_view(start_x, start_y, width, height, tx, ty) {
+                add_clamped(
+                    &mut floor_light[target_idx],
+                    brightness * WATER_REFLECT_FACTOR,
+                );
+            }
+        }
+    }
+}
+
 fn sampled_angles(map: &MapState, source: &LightSource) -> Vec<f32> {
     let sx = source.x as i32;
     let sy = source.y as i32;
@@ -759,7 +825,7 @@ fn add_clamped(value: &mut f32, delta: f32) {
 pub fn apply_light_field_to_buffer(
     buf: &mut Buffer,
     render: MapRenderResult,
-    _map: &MapState,
+    map: &MapState,
     field: &LightField,
 ) {
     let black = Color::Rgb(0, 0, 0);
@@ -769,7 +835,8 @@ pub fn apply_light_field_to_buffer(
             let map_y = render.start_y + row;
             let brightness = field.brightness_at(map_x, map_y);
             let scale = brightness_to_scale(brightness);
-            if scale >= 1.0 {
+            let glint = water_glint(map.tile(map_x, map_y), brightness);
+            if scale >= 1.0 && glint <= 0.0 {
                 continue;
             }
 
@@ -779,10 +846,15 @@ pub fn apply_light_field_to_buffer(
                         if let Some(cell) = buf.cell_mut((tile_x + dx, tile_y + dy)) {
                             if scale <= 0.0 {
                                 cell.set_char(' ').set_fg(black).set_bg(black);
-                            } else {
+                                continue;
+                            }
+                            if scale < 1.0 {
                                 cell.set_fg(scale_and_floor_color(cell.fg, scale));
                                 cell.set_bg(scale_and_floor_color(cell.bg, scale));
                             }
+                            if glint > 0.0 {
+                                cell.set_fg(blend_color(cell.fg, WATER_GLINT, glint));
+                            }
                         }
                     }
                 }
@@ -791,6 +863,14 @@ pub fn apply_light_field_to_buffer(
     }
 }
 
+/// How far lit water's texture shifts towards the highlight color.
+fn water_glint(tile: Tile, brightness: f32) -> f32 {
+    if tile != Tile::Water || brightness <= WATER_GLINT_MIN {
+        return 0.0;
+    }
+    (brightness - WATER_GLINT_MIN) / (1.0 - WATER_GLINT_MIN) * WATER_GLINT_STRENGTH
+}
+
 fn brightness_to_scale(brightness: f32) -> f32 {
     let b = brightness.clamp(0.0, 1.0);
     if b <= 0.0 {
@@ -827,6 +907,18 @@ fn scale_and_floor_color(color: Color, scale: f32) -> Color {
     }
 }
 
+fn blend_color(color: Color, toward: Color, amount: f32) -> Color {
+    match (color, toward) {
+        (Color::Rgb(r, g, b), Color::Rgb(tr, tg, tb)) => {
+            let t = amount.clamp(0.0, 1.0);
+            let mix =
+                |from: u8, to: u8| (from as f32 + (to as f32 - from as f32) * t).round() as u8;
+            Color::Rgb(mix(r, tr), mix(g, tg), mix(b, tb))
+        }
+        (other, _) => other,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -992,4 +1084,31 @@ mod tests {
 
         assert!(field.brightness_at(7, 4) > 0.15);
     }
+
+    #[test]
+    fn lit_water_reflects_onto_neighbors() {
+        let source = LightSource {
+            x: 1,
+            y: 3,
+            intensity: 1.0,
+            range: 6,
+            core_radius: 1,
+        };
+        let dry = compute_light_field(&open_map(10, 7), 0, 0, 10, 7, &[source]);
+        let mut map = open_map(10, 7);
+        set_tile(&mut map, 5, 3, Tile::Water);
+        let wet = compute_light_field(&map, 0, 0, 10, 7, &[source]);
+
+        assert!(wet.brightness_at(5, 3) > dry.brightness_at(5, 3));
+        assert!(wet.brightness_at(5, 2) > dry.brightness_at(5, 2));
+        assert!(wet.brightness_at(6, 3) > dry.brightness_at(6, 3));
+        assert_eq!(wet.brightness_at(9, 0), dry.brightness_at(9, 0));
+    }
+
+    #[test]
+    fn only_lit_water_glints() {
+        assert_eq!(water_glint(Tile::Floor, 1.0), 0.0);
+        assert_eq!(water_glint(Tile::Water, WATER_GLINT_MIN), 0.0);
+        assert!(water_glint(Tile::Water, 0.9) > 0.0);
+    }
 }
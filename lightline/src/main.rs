@@ -1,14 +1,23 @@
 mod action;
+mod compass;
 mod danger;
+mod drain;
 mod effect;
+mod export;
+mod hotseat;
+mod items;
 mod lighting;
+mod modifiers;
+mod narrative;
 mod procgen;
 mod reducer;
+mod secrets;
 mod state;
 mod ui;
 
 use std::collections::VecDeque;
 use std::io;
+use std::path::PathBuf;
 use std::time::Duration;
 
 use clap::Parser;
@@ -19,9 +28,11 @@ use crossterm::{
 };
 use ratatui::{Terminal, backend::CrosstermBackend};
 use tui_dispatch::EffectStore;
+use tui_persist::SaveFile;
 
 use crate::action::Action;
 use crate::effect::Effect;
+use crate::hotseat::Hotseat;
 use crate::state::{AppState, Direction, GameMode};
 
 #[derive(Parser, Debug)]
@@ -30,8 +41,23 @@ use crate::state::{AppState, Direction, GameMode};
 struct Args {
     #[arg(long, default_value_t = 0xC0FF_EE_u64)]
     seed: u64,
+    /// Offer a stacking run modifier every 5 floors.
+    #[arg(long)]
+    endless: bool,
+    /// Pick up the run saved when you last quit with `q`.
+    #[arg(long)]
+    resume: bool,
+    /// Allow the procgen overlay (F3): room labels, danger scores, seed and timings.
+    #[arg(long)]
+    dev: bool,
+    /// Two explorers on one keyboard, passing control every TURNS turns.
+    #[arg(long, value_name = "TURNS")]
+    hotseat: Option<u32>,
 }
 
+/// Schema version of the run save this build writes.
+const RUN_VERSION: u32 = 1;
+
 fn main() -> io::Result<()> {
     let args = Args::parse();
 
@@ -41,7 +67,7 @@ fn main() -> io::Result<()> {
     let backend = CrosstermBackend::new(stdout);
     let mut terminal = Terminal::new(backend)?;
 
-    let result = run_app(&mut terminal, args.seed);
+    let result = run_app(&mut terminal, &args);
 
     disable_raw_mode()?;
     execute!(terminal.backend_mut(), LeaveAlternateScreen)?;
@@ -50,9 +76,33 @@ fn main() -> io::Result<()> {
     result
 }
 
-fn run_app<B: ratatui::backend::Backend>(terminal: &mut Terminal<B>, seed: u64) -> io::Result<()> {
-    let mut store = EffectStore::new(AppState::new(seed), reducer::reducer);
-    dispatch_action(&mut store, Action::Init);
+fn run_app<B: ratatui::backend::Backend>(
+    terminal: &mut Terminal<B>,
+    args: &Args,
+) -> io::Result<()> {
+    let resumed = if args.resume {
+        Some(run_file().load::<AppState>())
+    } else {
+        None
+    };
+    let mut store = match resumed {
+        Some(Ok(mut state)) => {
+            state.last_status = Some("Run resumed.".to_string());
+            EffectStore::new(state, reducer::reducer)
+        }
+        other => {
+            let mut state = AppState::new(args.seed);
+            state.endless = args.endless;
+            state.hotseat = args.hotseat.map(|turns| Hotseat::new(&state.player, turns));
+            let mut store = EffectStore::new(state, reducer::reducer);
+            dispatch_action(&mut store, Action::Init);
+            if let Some(Err(err)) = other {
+                store.state_mut().last_status = Some(format!("Couldn't resume: {err}"));
+            }
+            store
+        }
+    };
+    store.state_mut().dev = args.dev;
 
     loop {
         terminal.draw(|frame| ui::render(frame, frame.area(), store.state()))?;
@@ -71,7 +121,23 @@ fn run_app<B: ratatui::backend::Backend>(terminal: &mut Terminal<B>, seed: u64)
         dispatch_action(&mut store, Action::Tick);
     }
 
-    Ok(())
+    save_run(store.state())
+}
+
+fn run_file() -> SaveFile {
+    let base = dirs_next::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
+    SaveFile::new(base.join("lightline").join("run.json")).version(RUN_VERSION)
+}
+
+/// Keeps a run in progress for `--resume`. A finished run clears the save so
+/// it can't be resumed past its death.
+fn save_run(state: &AppState) -> io::Result<()> {
+    let file = run_file();
+    match state.mode {
+        GameMode::GameOver => file.delete(),
+        GameMode::Boot => Ok(()),
+        _ => file.save(state).map_err(io::Error::other),
+    }
 }
 
 fn handle_key(
@@ -122,6 +188,38 @@ fn handle_key(
             dispatch_action(store, Action::Interact);
             false
         }
+        KeyCode::Char('h') | KeyCode::Char('H') if mode == GameMode::Exploration => {
+            dispatch_action(store, Action::ToggleHood);
+            false
+        }
+        KeyCode::Char(digit @ '1'..='9') if mode == GameMode::Exploration => {
+            dispatch_action(store, Action::UseItem(digit as usize - '1' as usize));
+            false
+        }
+        KeyCode::Char(digit @ '1'..='9') if mode == GameMode::ChooseModifier => {
+            dispatch_action(store, Action::PickModifier(digit as usize - '1' as usize));
+            false
+        }
+        KeyCode::F(3) => {
+            dispatch_action(store, Action::DevOverlayToggle);
+            false
+        }
+        KeyCode::PageUp => {
+            dispatch_action(store, Action::LogScroll(1));
+            false
+        }
+        KeyCode::PageDown => {
+            dispatch_action(store, Action::LogScroll(-1));
+            false
+        }
+        KeyCode::Char('p') => {
+            dispatch_action(store, Action::ExportView { png: false });
+            false
+        }
+        KeyCode::Char('P') => {
+            dispatch_action(store, Action::ExportView { png: true });
+            false
+        }
         _ => false,
     }
 }
@@ -156,5 +254,20 @@ fn handle_effect(
                 state.last_status = Some(format!("Floor generation failed: {err}"));
             }
         },
+        Effect::ExportView { png } => {
+            let result = crossterm::terminal::size().and_then(|size| {
+                export::export_view(store.state(), size, std::path::Path::new("."), png)
+            });
+            queue.push_back(match result {
+                Ok(files) => {
+                    let mut saved = files.ansi.display().to_string();
+                    if let Some(png) = files.png {
+                        saved.push_str(&format!(" + {}", png.display()));
+                    }
+                    Action::ExportDidSave(saved)
+                }
+                Err(err) => Action::ExportDidError(err.to_string()),
+            });
+        }
     }
 }
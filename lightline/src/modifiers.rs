@@ -0,0 +1,203 @@
+//! Endless-mode run modifiers.
+//!
+//! With `--endless`, clearing every fifth floor stops at a choice of three
+//! modifiers before the next floor is generated. Each one trades a boon for a
+//! cost and lasts for the rest of the run. Picking the same modifier again
+//! stacks it.
+
+use crate::items;
+use crate::procgen::mix64;
+use crate::state::{
+    AppState, GeneratedFloor, Item, RunModifier, RuntimeAnchor, RuntimeAnchorKind, Tile,
+};
+
+// Modifier tuning, per stack:
+// - OFFER_EVERY: floors cleared between offers.
+// - OFFER_SIZE: modifiers to choose from at each offer.
+// - OVERCHARGE_LIGHT / OVERCHARGE_BURN: starting light gained, extra light per unlit step.
+// - SEAM_RELICS / SEAM_FLOOD: extra relics per floor, floor tiles turned to water.
+// - NARROW_LIGHT: starting light lost while floors stay at their first size.
+// - MIN_LIGHT: starting light never drops below this.
+pub const OFFER_EVERY: u32 = 5;
+pub const OFFER_SIZE: usize = 3;
+const OVERCHARGE_LIGHT: u16 = 40;
+const OVERCHARGE_BURN: u16 = 1;
+const SEAM_RELICS: usize = 2;
+const SEAM_FLOOD: usize = 10;
+const NARROW_LIGHT: u16 = 25;
+const MIN_LIGHT: u16 = 30;
+
+const OFFER_SALT: u64 = 0xa409_3822_299f_31d0;
+const SEAM_SALT: u64 = 0x082e_fa98_ec4e_6c89;
+/// Tag on relics added by Rich Seams; their items are rolled per tile.
+pub const SEAM_TAG: &str = "seam";
+
+impl RunModifier {
+    pub const ALL: [RunModifier; 4] = [
+        RunModifier::Overcharge,
+        RunModifier::RichSeams,
+        RunModifier::Siphon,
+        RunModifier::NarrowHalls,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            RunModifier::Overcharge => "Overcharged Wick",
+            RunModifier::RichSeams => "Rich Seams",
+            RunModifier::Siphon => "Siphon",
+            RunModifier::NarrowHalls => "Narrow Halls",
+        }
+    }
+
+    pub fn description(self) -> &'static str {
+        match self {
+            RunModifier::Overcharge => "+40 light each floor, but unlit steps burn one more.",
+            RunModifier::RichSeams => "Two more relics each floor, but the floors flood.",
+            RunModifier::Siphon => "Reclaimed trail gives back double, but wading burns double.",
+            RunModifier::NarrowHalls => "Floors stay small, but start with 25 less light.",
+        }
+    }
+}
+
+/// How many times `modifier` has been picked this run.
+pub fn stacks(state: &AppState, modifier: RunModifier) -> u16 {
+    state.modifiers.iter().filter(|&&m| m == modifier).count() as u16
+}
+
+/// Whether arriving at `state.floor_index` should stop for an offer first.
+pub fn offer_due(state: &AppState) -> bool {
+    state.endless && state.floor_index > 0 && state.floor_index.is_multiple_of(OFFER_EVERY)
+}
+
+/// `OFFER_SIZE` distinct modifiers for the offer before `floor_index`. Same
+/// seed and floor, same offer.
+pub fn roll_offer(seed: u64, floor_index: u32) -> Vec<RunModifier> {
+    let mut pool = RunModifier::ALL.to_vec();
+    let mut z = mix64(seed ^ OFFER_SALT ^ ((floor_index as u64) << 32));
+    for i in 0..OFFER_SIZE.min(pool.len()) {
+        let pick = i + (z % (pool.len() - i) as u64) as usize;
+        pool.swap(i, pick);
+        z = mix64(z);
+    }
+    pool.truncate(OFFER_SIZE);
+    pool
+}
+
+pub fn light_budget(state: &AppState, base: u16) -> u16 {
+    let gained = base.saturating_add(stacks(state, RunModifier::Overcharge) * OVERCHARGE_LIGHT);
+    let narrow = stacks(state, RunModifier::NarrowHalls);
+    if narrow == 0 {
+        return gained;
+    }
+    gained.saturating_sub(narrow * NARROW_LIGHT).max(MIN_LIGHT)
+}
+
+/// Extra light burned stepping onto an unlit tile.
+pub fn unlit_step_burn(state: &AppState) -> u16 {
+    stacks(state, RunModifier::Overcharge) * OVERCHARGE_BURN
+}
+
+/// Multiplier on both reclaimed trail and the extra cost of wading.
+pub fn siphon_factor(state: &AppState) -> u16 {
+    1 + stacks(state, RunModifier::Siphon)
+}
+
+pub fn floors_grow(state: &AppState) -> bool {
+    stacks(state, RunModifier::NarrowHalls) == 0
+}
+
+/// Adds Rich Seams' relics and flooding to a freshly generated floor. Only
+/// plain floor tiles off the anchors are touched, and water stays walkable, so
+/// the route to the exit is never cut.
+pub fn shape_floor(state: &AppState, floor: &mut GeneratedFloor) {
+    let seams = stacks(state, RunModifier::RichSeams) as usize;
+    if seams == 0 {
+        return;
+    }
+    let width = floor.map.width;
+    let mut open: Vec<(u16, u16)> = (0..floor.map.height)
+        .flat_map(|y| (0..width).map(move |x| (x, y)))
+        .filter(|&(x, y)| floor.map.tile(x, y) == Tile::Floor)
+        .filter(|&(x, y)| !floor.anchors.iter().any(|a| (a.x, a.y) == (x, y)))
+        .collect();
+
+    let mut z = mix64(state.seed ^ SEAM_SALT ^ ((state.floor_index as u64) << 32));
+    let mut take = |open: &mut Vec<(u16, u16)>| {
+        if open.is_empty() {
+            return None;
+        }
+        z = mix64(z);
+        Some(open.swap_remove((z % open.len() as u64) as usize))
+    };
+
+    for _ in 0..seams * SEAM_RELICS {
+        let Some((x, y)) = take(&mut open) else {
+            break;
+        };
+        floor.anchors.push(RuntimeAnchor {
+            kind: RuntimeAnchorKind::Relic,
+            x,
+            y,
+            tag: Some(SEAM_TAG.to_string()),
+        });
+    }
+    for _ in 0..seams * SEAM_FLOOD {
+        let Some((x, y)) = take(&mut open) else {
+            break;
+        };
+        floor.map.tiles[y as usize * width as usize + x as usize] = Tile::Water;
+    }
+}
+
+/// The item in a Rich Seams relic at (x, y).
+pub fn seam_item(seed: u64, floor_index: u32, x: u16, y: u16) -> Item {
+    items::relic_item(seed ^ mix64(((x as u64) << 16) | y as u64), floor_index)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::procgen::generate_floor;
+
+    #[test]
+    fn offers_are_distinct_and_seeded() {
+        let offer = roll_offer(7, 5);
+        assert_eq!(offer.len(), OFFER_SIZE);
+        for (i, modifier) in offer.iter().enumerate() {
+            assert!(!offer[i + 1..].contains(modifier));
+        }
+        assert_eq!(offer, roll_offer(7, 5));
+    }
+
+    #[test]
+    fn rich_seams_adds_relics_and_water_off_the_anchors() {
+        let mut state = AppState::new(21);
+        state.floor_index = 5;
+        let plain = generate_floor(21, 5, 36, 24).expect("floor");
+        let water = |floor: &GeneratedFloor| {
+            floor
+                .map
+                .tiles
+                .iter()
+                .filter(|&&t| t == Tile::Water)
+                .count()
+        };
+
+        let mut floor = plain.clone();
+        shape_floor(&state, &mut floor);
+        assert_eq!(floor, plain);
+
+        state.modifiers = vec![RunModifier::RichSeams, RunModifier::RichSeams];
+        shape_floor(&state, &mut floor);
+        let seams: Vec<_> = floor
+            .anchors
+            .iter()
+            .filter(|a| a.tag.as_deref() == Some(SEAM_TAG))
+            .collect();
+        assert_eq!(seams.len(), 2 * SEAM_RELICS);
+        assert_eq!(water(&floor), water(&plain) + 2 * SEAM_FLOOD);
+        for anchor in &floor.anchors {
+            assert_ne!(floor.map.tile(anchor.x, anchor.y), Tile::Water);
+        }
+    }
+}
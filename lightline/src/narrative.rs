@@ -0,0 +1,238 @@
+use crate::procgen::mix64;
+use crate::state::{AppState, DangerMode, RuntimeAnchorKind, Tile};
+
+// Flavor event tuning:
+// - HEARING_RADIUS: how far (in tiles) features can be "sensed" from.
+// - EVENT_ODDS: on average one event every EVENT_ODDS steps.
+// - QUIET_STEPS: minimum steps between two events.
+const HEARING_RADIUS: u16 = 7;
+const EVENT_ODDS: u64 = 5;
+const QUIET_STEPS: u32 = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Feature {
+    Water,
+    Grass,
+    Anchor(RuntimeAnchorKind),
+}
+
+impl Feature {
+    fn templates(self) -> &'static [&'static str] {
+        match self {
+            Feature::Water => &[
+                "You hear dripping water {where}.",
+                "A cold, damp draft drifts in {where}.",
+                "Something splashes {where}, then goes still.",
+            ],
+            Feature::Grass => &[
+                "Moss rustles {where}.",
+                "The air smells of wet roots {where}.",
+            ],
+            Feature::Anchor(RuntimeAnchorKind::Exit) => &[
+                "A faint updraft tugs at your lantern {where}.",
+                "Old stairs creak {where}.",
+            ],
+            Feature::Anchor(RuntimeAnchorKind::Beacon) => &[
+                "A dormant beacon hums {where}.",
+                "Warm light flickers for a moment {where}.",
+            ],
+            Feature::Anchor(RuntimeAnchorKind::Relic) => {
+                &["Something glints {where}.", "You feel an odd pull {where}."]
+            }
+            Feature::Anchor(RuntimeAnchorKind::Switch) => &[
+                "A mechanism ticks {where}.",
+                "Chains rattle softly {where}.",
+            ],
+            Feature::Anchor(RuntimeAnchorKind::SecretWall) => &[
+                "A cold draft slips through the stone {where}.",
+                "The walls sound hollow {where}.",
+            ],
+            Feature::Anchor(RuntimeAnchorKind::Cache) => {
+                &["Something rattles behind the rock {where}."]
+            }
+            Feature::Anchor(RuntimeAnchorKind::PlayerStart) => &[],
+        }
+    }
+}
+
+fn hazard_templates(danger_mode: DangerMode) -> &'static [&'static str] {
+    match danger_mode {
+        DangerMode::SoundHunter => &[
+            "Footsteps echo behind you, then stop.",
+            "Something breathes in the dark.",
+            "A claw scrapes stone somewhere far off.",
+        ],
+        DangerMode::ImminentCollapse => &[
+            "Dust sifts down from the ceiling.",
+            "The rock groans overhead.",
+            "A pebble clatters loose nearby.",
+        ],
+    }
+}
+
+/// Log line announcing a freshly generated floor.
+pub fn arrival_event(floor_index: u32, danger_mode: DangerMode) -> String {
+    let hint = match danger_mode {
+        DangerMode::SoundHunter => "Something down here is listening.",
+        DangerMode::ImminentCollapse => "The ceiling here does not look safe.",
+    };
+    format!("Floor {}. {hint}", floor_index + 1)
+}
+
+/// Flavor event for the player's current step, if one fires.
+///
+/// Deterministic for a given run seed, floor and step count, so replaying the
+/// same moves on the same seed produces the same log.
+pub fn step_event(state: &AppState) -> Option<String> {
+    let steps = state.player.steps;
+    let recent = state.log.entries.last().is_some_and(|entry| {
+        entry.floor == state.floor_index && steps.saturating_sub(entry.step) < QUIET_STEPS
+    });
+    if recent {
+        return None;
+    }
+
+    let roll = mix64(
+        state.seed ^ ((state.floor_index as u64) << 32) ^ (steps as u64).wrapping_mul(0xa24b_aed4),
+    );
+    if !roll.is_multiple_of(EVENT_ODDS) {
+        return None;
+    }
+
+    let nearby = nearby_features(state);
+    // Hazards count as one extra candidate so they show up even in empty corridors.
+    let pick = (roll >> 8) as usize % (nearby.len() + 1);
+    let variant = (roll >> 24) as usize;
+    match nearby.get(pick) {
+        Some(&(feature, dx, dy)) => {
+            let templates = feature.templates();
+            let template = templates[variant % templates.len()];
+            Some(template.replace("{where}", &direction_phrase(dx, dy)))
+        }
+        None => {
+            let templates = hazard_templates(state.danger_mode);
+            Some(templates[variant % templates.len()].to_string())
+        }
+    }
+}
+
+/// Nearest instance of each feature kind within hearing range, as offsets from the player.
+fn nearby_features(state: &AppState) -> Vec<(Feature, i32, i32)> {
+    let (px, py) = state.player_pos();
+    let mut found: Vec<(Feature, i32, i32)> = Vec::new();
+    let mut consider = |feature: Feature, x: u16, y: u16| {
+        let (dx, dy) = (x as i32 - px as i32, y as i32 - py as i32);
+        if dx.unsigned_abs().max(dy.unsigned_abs()) > HEARING_RADIUS as u32 {
+            return;
+        }
+        let dist = dx * dx + dy * dy;
+        match found
+            .iter_mut()
+            .find(|(existing, _, _)| *existing == feature)
+        {
+            Some(entry) if entry.1 * entry.1 + entry.2 * entry.2 > dist => {
+                *entry = (feature, dx, dy)
+            }
+            Some(_) => {}
+            None => found.push((feature, dx, dy)),
+        }
+    };
+
+    let x_range = px.saturating_sub(HEARING_RADIUS)..=px.saturating_add(HEARING_RADIUS);
+    for y in py.saturating_sub(HEARING_RADIUS)..=py.saturating_add(HEARING_RADIUS) {
+        for x in x_range.clone() {
+            match state.map.tile(x, y) {
+                Tile::Water => consider(Feature::Water, x, y),
+                Tile::Grass => consider(Feature::Grass, x, y),
+                _ => {}
+            }
+        }
+    }
+    for anchor in &state.anchors {
+        if anchor.kind != RuntimeAnchorKind::PlayerStart {
+            consider(Feature::Anchor(anchor.kind), anchor.x, anchor.y);
+        }
+    }
+    found
+}
+
+/// "to the north-east", "close by", ... for an offset in map space (+y is south).
+pub(crate) fn direction_phrase(dx: i32, dy: i32) -> String {
+    if dx.abs() <= 1 && dy.abs() <= 1 {
+        return "close by".to_string();
+    }
+    // Only call it diagonal when neither axis dominates by more than 2:1.
+    let vertical = if dy.abs() * 2 >= dx.abs() {
+        if dy < 0 { "north" } else { "south" }
+    } else {
+        ""
+    };
+    let horizontal = if dx.abs() * 2 >= dy.abs() {
+        if dx < 0 { "west" } else { "east" }
+    } else {
+        ""
+    };
+    match (vertical.is_empty(), horizontal.is_empty()) {
+        (false, false) => format!("to the {vertical}-{horizontal}"),
+        (false, true) => format!("to the {vertical}"),
+        _ => format!("to the {horizontal}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{MapState, RuntimeAnchor};
+    use tui_map::core::{MapSize, TileKind};
+
+    fn open_floor(seed: u64) -> AppState {
+        let mut state = AppState::new(seed);
+        state.map = MapState::filled("test", MapSize::new(20, 20), TileKind::Floor);
+        state.player.x = 10;
+        state.player.y = 10;
+        state
+    }
+
+    #[test]
+    fn direction_phrases() {
+        assert_eq!(direction_phrase(0, -5), "to the north");
+        assert_eq!(direction_phrase(4, 4), "to the south-east");
+        assert_eq!(direction_phrase(-6, 1), "to the west");
+        assert_eq!(direction_phrase(1, -1), "close by");
+    }
+
+    #[test]
+    fn step_events_are_deterministic_per_seed() {
+        let run = |seed| {
+            let mut state = open_floor(seed);
+            (0..60)
+                .map(|step| {
+                    state.player.steps = step;
+                    step_event(&state)
+                })
+                .collect::<Vec<_>>()
+        };
+        let events = run(42);
+        assert_eq!(events, run(42));
+        assert!(events.iter().any(Option::is_some));
+    }
+
+    #[test]
+    fn nearby_features_pick_the_closest_in_range() {
+        let mut state = open_floor(7);
+        let water = [(13, 10), (10, 6), (2, 2)];
+        for (x, y) in water {
+            let idx = y as usize * state.map.width as usize + x as usize;
+            state.map.tiles[idx] = Tile::Water;
+        }
+        state.anchors = vec![RuntimeAnchor {
+            kind: RuntimeAnchorKind::Exit,
+            x: 19,
+            y: 19,
+            tag: None,
+        }];
+
+        let found = nearby_features(&state);
+        assert_eq!(found, vec![(Feature::Water, 3, 0)]);
+    }
+}
@@ -1,11 +1,16 @@
 use std::collections::VecDeque;
+use std::time::Instant;
 
 use tui_map::core::{MapGrid, MapSize, TileKind};
 use tui_map::procgen::{
     AnchorKind, GenError, GenerateRequest, GeneratedMap, MapGenerator, SpawnAnchor,
 };
 
-use crate::state::{DangerMode, GeneratedFloor, MapState, RuntimeAnchor, RuntimeAnchorKind};
+use crate::secrets::{BREAKABLE_TAG, ILLUSORY_TAG};
+use crate::state::{
+    DangerMode, Direction, GeneratedFloor, MapState, PhaseTiming, RoomInfo, RuntimeAnchor,
+    RuntimeAnchorKind, Tile,
+};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub struct FloorGenParams {
@@ -39,6 +44,34 @@ impl SeededRng {
     }
 }
 
+// ---------------------------------------------------------------------------
+// Phase timings
+// ---------------------------------------------------------------------------
+
+struct PhaseClock {
+    last: Instant,
+    timings: Vec<PhaseTiming>,
+}
+
+impl PhaseClock {
+    fn start() -> Self {
+        Self {
+            last: Instant::now(),
+            timings: Vec::new(),
+        }
+    }
+
+    /// Records the time since the previous lap as `phase`.
+    fn lap(&mut self, phase: &str) {
+        let now = Instant::now();
+        self.timings.push(PhaseTiming {
+            phase: phase.to_string(),
+            micros: now.duration_since(self.last).as_micros() as u64,
+        });
+        self.last = now;
+    }
+}
+
 // ---------------------------------------------------------------------------
 // Maze grid
 // ---------------------------------------------------------------------------
@@ -291,6 +324,145 @@ fn add_water_patches(tiles: &mut [TileKind], width: u16, rooms: &[Room], rng: &m
     }
 }
 
+// ---------------------------------------------------------------------------
+// Ledges: one-way drops carved into straight corridor tiles
+// ---------------------------------------------------------------------------
+
+fn add_ledges(
+    tiles: &mut [TileKind],
+    width: u16,
+    height: u16,
+    rng: &mut SeededRng,
+    floor_index: u32,
+    start: (u16, u16),
+    exit: (u16, u16),
+) {
+    let mut candidates = Vec::new();
+    for y in 1..height - 1 {
+        for x in 1..width - 1 {
+            if let Some(directions) = corridor_axis(tiles, width, x, y) {
+                candidates.push((x, y, directions));
+            }
+        }
+    }
+
+    let target = 1 + (floor_index as usize / 2).min(3);
+    let mut placed = 0;
+    while placed < target && !candidates.is_empty() {
+        let pick = rng.next_bounded(candidates.len() as u64) as usize;
+        let (x, y, directions) = candidates.swap_remove(pick);
+        let direction = directions[rng.next_bounded(2) as usize];
+        let idx = y as usize * width as usize + x as usize;
+        tiles[idx] = Tile::Ledge(direction).to_tile_kind();
+        if exit_always_reachable(&runtime_map(tiles, width, height), start, exit) {
+            placed += 1;
+        } else {
+            tiles[idx] = TileKind::Floor;
+        }
+    }
+}
+
+/// The two ways along a straight, one-tile-wide stretch of plain floor at
+/// (x, y), or `None` anywhere else. (x, y) must not be on the border.
+fn corridor_axis(tiles: &[TileKind], width: u16, x: u16, y: u16) -> Option<[Direction; 2]> {
+    let floor_at =
+        |x: u16, y: u16| tiles[y as usize * width as usize + x as usize] == TileKind::Floor;
+    if !floor_at(x, y) {
+        return None;
+    }
+    let vertical =
+        floor_at(x, y - 1) && floor_at(x, y + 1) && !floor_at(x - 1, y) && !floor_at(x + 1, y);
+    let horizontal =
+        floor_at(x - 1, y) && floor_at(x + 1, y) && !floor_at(x, y - 1) && !floor_at(x, y + 1);
+    if vertical {
+        Some([Direction::Up, Direction::Down])
+    } else if horizontal {
+        Some([Direction::Left, Direction::Right])
+    } else {
+        None
+    }
+}
+
+// ---------------------------------------------------------------------------
+// Secret rooms: a short dead-end branch walled off at its mouth
+// ---------------------------------------------------------------------------
+
+// Secret room tuning:
+// - SECRET_MIN_TILES/SECRET_MAX_TILES: size of the branch a false wall may
+//   cut off; anything bigger would hide too much of the floor.
+// - SECRET_ATTEMPTS: corridor tiles tried before the floor goes without one.
+const SECRET_MIN_TILES: usize = 2;
+const SECRET_MAX_TILES: usize = 14;
+const SECRET_ATTEMPTS: usize = 24;
+
+struct SecretRoom {
+    wall: (u16, u16),
+    cache: (u16, u16),
+    illusory: bool,
+}
+
+/// Turns one corridor tile into a false wall, keeping it only where that
+/// cuts off a small branch with no anchors or ledges in it and the exit stays
+/// reachable. The cache goes at the far end of the branch.
+fn add_secret_room(
+    tiles: &mut [TileKind],
+    width: u16,
+    height: u16,
+    rng: &mut SeededRng,
+    start: (u16, u16),
+    exit: (u16, u16),
+) -> Option<SecretRoom> {
+    let mut candidates = Vec::new();
+    for y in 1..height - 1 {
+        for x in 1..width - 1 {
+            if corridor_axis(tiles, width, x, y).is_some() {
+                candidates.push((x, y));
+            }
+        }
+    }
+
+    for _ in 0..SECRET_ATTEMPTS {
+        if candidates.is_empty() {
+            return None;
+        }
+        let pick = rng.next_bounded(candidates.len() as u64) as usize;
+        let wall = candidates.swap_remove(pick);
+        let idx = wall.1 as usize * width as usize + wall.0 as usize;
+
+        let open = runtime_map(tiles, width, height);
+        let before = reachable(&open, start, false);
+        tiles[idx] = TileKind::Wall;
+        let sealed = runtime_map(tiles, width, height);
+        let after = reachable(&sealed, start, false);
+
+        // Anchors sit on trail tiles, so a plain-ground pocket holds none.
+        let pocket: Vec<usize> = (0..tiles.len())
+            .filter(|&i| before[i] && !after[i] && i != idx)
+            .collect();
+        let fits = (SECRET_MIN_TILES..=SECRET_MAX_TILES).contains(&pocket.len())
+            && pocket
+                .iter()
+                .all(|&i| matches!(sealed.tiles[i], Tile::Floor | Tile::Water | Tile::Grass))
+            && exit_always_reachable(&sealed, start, exit);
+        if !fits {
+            tiles[idx] = TileKind::Floor;
+            continue;
+        }
+
+        let distances = step_distances(&open, wall, false);
+        let far = pocket
+            .iter()
+            .copied()
+            .max_by_key(|&i| (distances[i], std::cmp::Reverse(i)))?;
+        return Some(SecretRoom {
+            wall,
+            cache: ((far % width as usize) as u16, (far / width as usize) as u16),
+            illusory: rng.next_bounded(2) == 0,
+        });
+    }
+    None
+}
+
 // ---------------------------------------------------------------------------
 // Anchor placement
 // ---------------------------------------------------------------------------
@@ -384,50 +556,81 @@ fn place_anchors(
 }
 
 // ---------------------------------------------------------------------------
-// Connectivity validation (BFS)
+// Connectivity validation (BFS over one-way steps)
 // ---------------------------------------------------------------------------
 
-fn validate_connectivity(
-    tiles: &[TileKind],
-    width: u16,
-    height: u16,
-    start: (u16, u16),
-    end: (u16, u16),
-) -> bool {
-    let idx = |x: u16, y: u16| y as usize * width as usize + x as usize;
-    let is_passable = |x: u16, y: u16| {
-        matches!(
-            tiles[idx(x, y)],
-            TileKind::Floor | TileKind::Trail | TileKind::Water
-        )
-    };
+fn runtime_map(tiles: &[TileKind], width: u16, height: u16) -> MapState {
+    MapState {
+        name: String::new(),
+        width,
+        height,
+        tiles: tiles.iter().copied().map(Tile::from_tile_kind).collect(),
+    }
+}
+
+/// Tiles reachable from `origin`, or with `reverse` set, tiles that can reach it.
+fn reachable(map: &MapState, origin: (u16, u16), reverse: bool) -> Vec<bool> {
+    step_distances(map, origin, reverse)
+        .iter()
+        .map(Option::is_some)
+        .collect()
+}
 
-    let mut visited = vec![false; tiles.len()];
+/// Fewest steps from `origin` to every tile (or from every tile to `origin`
+/// with `reverse` set), honoring ledges. `None` where there's no way through.
+pub(crate) fn step_distances(
+    map: &MapState,
+    origin: (u16, u16),
+    reverse: bool,
+) -> Vec<Option<u16>> {
+    let idx = |x: u16, y: u16| y as usize * map.width as usize + x as usize;
+    let mut distances = vec![None; map.tiles.len()];
     let mut queue = VecDeque::new();
 
-    visited[idx(start.0, start.1)] = true;
-    queue.push_back(start);
+    distances[idx(origin.0, origin.1)] = Some(0);
+    queue.push_back((origin, 0));
 
-    while let Some((x, y)) = queue.pop_front() {
-        if (x, y) == end {
-            return true;
-        }
-        for (dx, dy) in [(0i16, -1), (0, 1), (-1, 0), (1, 0)] {
-            let nx = x as i16 + dx;
-            let ny = y as i16 + dy;
-            if nx < 0 || ny < 0 || nx >= width as i16 || ny >= height as i16 {
+    while let Some(((x, y), distance)) = queue.pop_front() {
+        for heading in Direction::ALL {
+            let Some((nx, ny)) = heading.step(x, y) else {
+                continue;
+            };
+            if nx >= map.width || ny >= map.height || distances[idx(nx, ny)].is_some() {
                 continue;
             }
-            let (nx, ny) = (nx as u16, ny as u16);
-            let ni = idx(nx, ny);
-            if !visited[ni] && is_passable(nx, ny) {
-                visited[ni] = true;
-                queue.push_back((nx, ny));
+            let allowed = if reverse {
+                map.is_walkable(nx, ny) && map.can_step((nx, ny), opposite(heading))
+            } else {
+                map.can_step((x, y), heading)
+            };
+            if allowed {
+                distances[idx(nx, ny)] = Some(distance + 1);
+                queue.push_back(((nx, ny), distance + 1));
             }
         }
     }
 
-    false
+    distances
+}
+
+/// True when the exit can be reached from the start and no one-way drop can
+/// strand the player somewhere the exit can't be reached from.
+fn exit_always_reachable(map: &MapState, start: (u16, u16), exit: (u16, u16)) -> bool {
+    let from_start = reachable(map, start, false);
+    let to_exit = reachable(map, exit, true);
+    from_start
+        .iter()
+        .zip(&to_exit)
+        .all(|(&visited, &escapes)| !visited || escapes)
+}
+
+fn opposite(direction: Direction) -> Direction {
+    match direction {
+        Direction::Up => Direction::Down,
+        Direction::Down => Direction::Up,
+        Direction::Left => Direction::Right,
+        Direction::Right => Direction::Left,
+    }
 }
 
 // ---------------------------------------------------------------------------
@@ -442,10 +645,22 @@ impl MapGenerator<FloorGenParams> for LightlineGenerator {
     }
 
     fn version(&self) -> u32 {
-        2
+        4
     }
 
     fn generate(&self, req: &GenerateRequest<FloorGenParams>) -> Result<GeneratedMap, GenError> {
+        self.generate_with_report(req)
+            .map(|(generated, _)| generated)
+    }
+}
+
+impl LightlineGenerator {
+    /// [`MapGenerator::generate`], plus the labelled rooms and phase timings
+    /// the dev overlay shows.
+    fn generate_with_report(
+        &self,
+        req: &GenerateRequest<FloorGenParams>,
+    ) -> Result<(GeneratedMap, FloorExtras), GenError> {
         if req.width < 20 || req.height < 12 {
             return Err(GenError::InvalidSize);
         }
@@ -453,6 +668,7 @@ impl MapGenerator<FloorGenParams> for LightlineGenerator {
         let width = req.width;
         let height = req.height;
         let mut rng = SeededRng::new(req.seed ^ ((req.params.floor_index as u64) << 32));
+        let mut clock = PhaseClock::start();
 
         // Maze dimensions: each cell is 1 tile with 1-tile walls between
         let cell_w = (width - 1) / 2;
@@ -465,15 +681,19 @@ impl MapGenerator<FloorGenParams> for LightlineGenerator {
         // Convert to tile grid (starts all-wall)
         let mut tiles = vec![TileKind::Wall; width as usize * height as usize];
         maze_to_tiles(&maze, &mut tiles, width);
+        clock.lap("maze");
 
         // Carve rooms
         let rooms = carve_rooms(&mut tiles, width, height, &mut rng, req.params.floor_index);
+        clock.lap("rooms");
 
         // Widen some corridors
         widen_corridors(&mut tiles, width, height, &mut rng, req.params.floor_index);
+        clock.lap("corridors");
 
         // Water patches in rooms
         add_water_patches(&mut tiles, width, &rooms, &mut rng);
+        clock.lap("water");
 
         // Enforce border walls
         for x in 0..width {
@@ -493,11 +713,29 @@ impl MapGenerator<FloorGenParams> for LightlineGenerator {
             let idx = y as usize * width as usize + x as usize;
             tiles[idx] = TileKind::Trail;
         }
+        clock.lap("anchors");
+
+        // One-way ledges, kept only where they can't cut off the exit
+        add_ledges(
+            &mut tiles,
+            width,
+            height,
+            &mut rng,
+            req.params.floor_index,
+            player_start,
+            exit,
+        );
+        clock.lap("ledges");
+
+        // At most one secret room, sealed after the ledges so none end up inside
+        let secret = add_secret_room(&mut tiles, width, height, &mut rng, player_start, exit);
+        clock.lap("secrets");
 
         // Validate connectivity
-        if !validate_connectivity(&tiles, width, height, player_start, exit) {
+        if !exit_always_reachable(&runtime_map(&tiles, width, height), player_start, exit) {
             return Err(GenError::Internal("no path from start to exit".to_string()));
         }
+        clock.lap("validate");
 
         let map = MapGrid::new(
             format!("Lightline Floor {}", req.params.floor_index + 1),
@@ -506,7 +744,7 @@ impl MapGenerator<FloorGenParams> for LightlineGenerator {
         )
         .map_err(|err| GenError::Internal(err.to_string()))?;
 
-        let anchors = vec![
+        let mut anchors = vec![
             SpawnAnchor {
                 kind: AnchorKind::PlayerStart,
                 x: player_start.0,
@@ -538,17 +776,71 @@ impl MapGenerator<FloorGenParams> for LightlineGenerator {
                 tag: None,
             },
         ];
+        if let Some(secret) = secret {
+            anchors.push(SpawnAnchor {
+                kind: AnchorKind::Custom("secret_wall".to_string()),
+                x: secret.wall.0,
+                y: secret.wall.1,
+                tag: Some(
+                    if secret.illusory {
+                        ILLUSORY_TAG
+                    } else {
+                        BREAKABLE_TAG
+                    }
+                    .to_string(),
+                ),
+            });
+            anchors.push(SpawnAnchor {
+                kind: AnchorKind::Custom("cache".to_string()),
+                x: secret.cache.0,
+                y: secret.cache.1,
+                tag: None,
+            });
+        }
 
-        Ok(GeneratedMap::with_computed_fingerprint(
+        let generated = GeneratedMap::with_computed_fingerprint(
             self.id(),
             self.version(),
             req.seed,
             map,
             anchors,
-        ))
+        );
+        let extras = FloorExtras {
+            rooms: room_infos(&rooms),
+            timings: clock.timings,
+        };
+        Ok((generated, extras))
     }
 }
 
+/// What [`LightlineGenerator::generate_with_report`] knows beyond the map.
+struct FloorExtras {
+    rooms: Vec<RoomInfo>,
+    timings: Vec<PhaseTiming>,
+}
+
+/// The first three rooms hold the beacon, relic and switch (see `place_anchors`).
+fn room_infos(rooms: &[Room]) -> Vec<RoomInfo> {
+    rooms
+        .iter()
+        .enumerate()
+        .map(|(index, room)| {
+            let mut label = format!("R{}", index + 1);
+            if let Some(anchor) = ["beacon", "relic", "switch"].get(index) {
+                label.push(' ');
+                label.push_str(anchor);
+            }
+            RoomInfo {
+                label,
+                x: room.x,
+                y: room.y,
+                w: room.w,
+                h: room.h,
+            }
+        })
+        .collect()
+}
+
 // ---------------------------------------------------------------------------
 // Shared utilities
 // ---------------------------------------------------------------------------
@@ -583,11 +875,15 @@ pub fn generate_floor(
         },
     };
 
-    let generated = generator.generate(&req)?;
-    Ok(into_runtime_floor(generated, danger_mode))
+    let (generated, extras) = generator.generate_with_report(&req)?;
+    Ok(into_runtime_floor(generated, extras, danger_mode))
 }
 
-fn into_runtime_floor(generated: GeneratedMap, danger_mode: DangerMode) -> GeneratedFloor {
+fn into_runtime_floor(
+    generated: GeneratedMap,
+    extras: FloorExtras,
+    danger_mode: DangerMode,
+) -> GeneratedFloor {
     let map = MapState::from_grid(generated.map);
     let anchors = generated
         .anchors
@@ -603,6 +899,8 @@ fn into_runtime_floor(generated: GeneratedMap, danger_mode: DangerMode) -> Gener
         generator_version: generated.fingerprint.generator_version,
         seed: generated.fingerprint.seed,
         fingerprint: generated.fingerprint.output_hash_hex,
+        rooms: extras.rooms,
+        timings: extras.timings,
     }
 }
 
@@ -615,6 +913,8 @@ fn runtime_anchor(anchor: SpawnAnchor) -> Option<RuntimeAnchor> {
             "beacon" => RuntimeAnchorKind::Beacon,
             "relic" => RuntimeAnchorKind::Relic,
             "switch" => RuntimeAnchorKind::Switch,
+            "secret_wall" => RuntimeAnchorKind::SecretWall,
+            "cache" => RuntimeAnchorKind::Cache,
             _ => return None,
         },
         _ => return None,
@@ -623,7 +923,7 @@ fn runtime_anchor(anchor: SpawnAnchor) -> Option<RuntimeAnchor> {
     Some(RuntimeAnchor { kind, x, y, tag })
 }
 
-fn mix64(mut z: u64) -> u64 {
+pub(crate) fn mix64(mut z: u64) -> u64 {
     z = z.wrapping_add(0x9e37_79b9_7f4a_7c15);
     z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
     z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
@@ -710,6 +1010,87 @@ mod tests {
         assert_ne!(a.map.tiles, b.map.tiles);
     }
 
+    #[test]
+    fn ledges_never_strand_the_player() {
+        let mut ledges = 0;
+        for seed in [42u64, 123, 999, 7777] {
+            for floor_index in [0, 4] {
+                let floor = generate_floor(seed, floor_index, 36, 24).expect("floor");
+                let start = floor
+                    .anchors
+                    .iter()
+                    .find(|a| a.kind == RuntimeAnchorKind::PlayerStart)
+                    .unwrap();
+                let exit = floor
+                    .anchors
+                    .iter()
+                    .find(|a| a.kind == RuntimeAnchorKind::Exit)
+                    .unwrap();
+                assert!(
+                    exit_always_reachable(&floor.map, (start.x, start.y), (exit.x, exit.y)),
+                    "seed {seed} floor {floor_index}: a ledge strands the player"
+                );
+                ledges += floor
+                    .map
+                    .tiles
+                    .iter()
+                    .filter(|tile| matches!(tile, Tile::Ledge(_)))
+                    .count();
+            }
+        }
+        assert!(ledges > 0, "expected at least one ledge across seeds");
+    }
+
+    #[test]
+    fn one_way_pocket_is_not_an_escape() {
+        // Dropping off the ledge at (2, 0) leads away from an exit at (1, 0).
+        let mut map = runtime_map(&[TileKind::Floor; 4], 4, 1);
+        map.tiles[2] = Tile::Ledge(Direction::Right);
+        assert!(!exit_always_reachable(&map, (0, 0), (1, 0)));
+        assert!(exit_always_reachable(&map, (0, 0), (3, 0)));
+    }
+
+    #[test]
+    fn secret_rooms_hide_a_cache_behind_a_false_wall() {
+        let mut secrets = 0;
+        for seed in [42u64, 123, 999, 7777, 31337] {
+            let mut floor = generate_floor(seed, 1, 36, 24).expect("floor");
+            let find = |kind| floor.anchors.iter().find(|a| a.kind == kind).cloned();
+            let (Some(wall), Some(cache)) = (
+                find(RuntimeAnchorKind::SecretWall),
+                find(RuntimeAnchorKind::Cache),
+            ) else {
+                continue;
+            };
+            secrets += 1;
+            let start = find(RuntimeAnchorKind::PlayerStart).unwrap();
+            let exit = find(RuntimeAnchorKind::Exit).unwrap();
+            assert!(matches!(
+                wall.tag.as_deref(),
+                Some(BREAKABLE_TAG | ILLUSORY_TAG)
+            ));
+            assert_eq!(floor.map.tile(wall.x, wall.y), Tile::Wall);
+            assert!(!has_path(
+                &floor.map,
+                (start.x, start.y),
+                (cache.x, cache.y)
+            ));
+
+            let idx = wall.y as usize * floor.map.width as usize + wall.x as usize;
+            floor.map.tiles[idx] = Tile::Floor;
+            assert!(has_path(&floor.map, (start.x, start.y), (cache.x, cache.y)));
+            assert!(exit_always_reachable(
+                &floor.map,
+                (start.x, start.y),
+                (exit.x, exit.y)
+            ));
+        }
+        assert!(
+            secrets > 0,
+            "expected at least one secret room across seeds"
+        );
+    }
+
     fn has_path(map: &MapState, start: (u16, u16), end: (u16, u16)) -> bool {
         let mut visited = vec![false; map.width as usize * map.height as usize];
         let mut queue = VecDeque::new();
@@ -722,15 +1103,15 @@ mod tests {
             if (x, y) == end {
                 return true;
             }
-            for (dx, dy) in [(0i16, -1), (0, 1), (-1, 0), (1, 0)] {
-                let nx = x as i16 + dx;
-                let ny = y as i16 + dy;
-                if nx < 0 || ny < 0 || nx >= map.width as i16 || ny >= map.height as i16 {
+            for heading in Direction::ALL {
+                let Some((nx, ny)) = heading.step(x, y) else {
+                    continue;
+                };
+                if nx >= map.width || ny >= map.height {
                     continue;
                 }
-                let (nx, ny) = (nx as u16, ny as u16);
                 let ni = idx(nx, ny);
-                if !visited[ni] && map.is_walkable(nx, ny) {
+                if !visited[ni] && map.can_step((x, y), heading) {
                     visited[ni] = true;
                     queue.push_back((nx, ny));
                 }
@@ -3,8 +3,19 @@ use std::collections::VecDeque;
 use tui_dispatch::DispatchResult;
 
 use crate::action::Action;
+use crate::compass;
+use crate::danger::hunter::{self, Awareness};
+use crate::drain::{DrainCause, DrainLedger};
 use crate::effect::Effect;
-use crate::state::{AppState, Direction, GameMode, RuntimeAnchorKind, TrailState};
+use crate::hotseat::{self, Hotseat};
+use crate::items;
+use crate::lighting;
+use crate::modifiers;
+use crate::narrative;
+use crate::secrets;
+use crate::state::{
+    AppState, DangerMode, Direction, GameMode, RuntimeAnchorKind, Tile, TrailState,
+};
 
 const BASE_WIDTH: u16 = 36;
 const BASE_HEIGHT: u16 = 24;
@@ -14,9 +25,12 @@ const MAX_HEIGHT: u16 = 40;
 // - STEP_BURN: light spent moving onto an unlit tile.
 // - LIT_STEP_BURN: light spent moving onto an already lit tile.
 // - TRAIL_DEPOSIT_AMOUNT: charge laid when leaving an unlit tile.
+// - WADE_EXTRA_BURN/WADE_STEPS: water slows you down, costing extra light and time.
 const STEP_BURN: u16 = 1;
 const LIT_STEP_BURN: u16 = 0;
 const TRAIL_DEPOSIT_AMOUNT: u16 = 1;
+const WADE_EXTRA_BURN: u16 = 1;
+const WADE_STEPS: u32 = 2;
 const START_LIGHT: u16 = 120;
 const LIGHT_DECAY_EVERY: u32 = 3;
 const LIGHT_DECAY_AMOUNT: u16 = 3;
@@ -27,29 +41,90 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
         Action::Init => {
             state.floor_index = 0;
             state.player.steps = 0;
+            state.player.pack.clear();
+            state.player.leak_steps = 0;
+            state.player.hooded = false;
+            state.identified.clear();
+            state.modifiers.clear();
+            state.modifier_offer.clear();
+            state.log.clear();
+            state.drain = DrainLedger::default();
+            if let Some(seat) = &state.hotseat {
+                state.hotseat = Some(Hotseat::new(&state.player, seat.turns_per_shift));
+            }
             state.last_status = Some("New run started.".to_string());
             state.mode = GameMode::Boot;
-            DispatchResult::changed_with(generate_floor_effect(state.floor_index, state.seed))
+            DispatchResult::changed_with(generate_floor_effect(state))
         }
         Action::GenerateFloor => {
             state.mode = GameMode::Boot;
-            DispatchResult::changed_with(generate_floor_effect(state.floor_index, state.seed))
+            DispatchResult::changed_with(generate_floor_effect(state))
         }
-        Action::FloorGenerated(floor) => {
-            let starting_light = light_budget_for_floor(state.floor_index);
+        Action::FloorGenerated(mut floor) => {
+            modifiers::shape_floor(state, &mut floor);
+            let starting_light =
+                modifiers::light_budget(state, light_budget_for_floor(state.floor_index));
             state.player.light_max = starting_light;
             state.player.light_current = starting_light;
             state.apply_generated_floor(floor);
+            hotseat::start_floor(state, starting_light);
+            if state.danger_mode == DangerMode::SoundHunter {
+                state.hunter = hunter::spawn(&state.map, state.player_pos());
+            }
+            compass::reveal(state);
+            let arrival = narrative::arrival_event(state.floor_index, state.danger_mode);
+            state
+                .log
+                .push(state.floor_index, state.player.steps, arrival);
             DispatchResult::changed()
         }
         Action::Move(direction, collect) => handle_move(state, direction, collect),
-        Action::Descend => {
-            state.floor_index = state.floor_index.saturating_add(1);
+        Action::Descend => descend(state),
+        Action::PickModifier(choice) => {
+            if state.mode != GameMode::ChooseModifier {
+                return DispatchResult::unchanged();
+            }
+            let Some(&modifier) = state.modifier_offer.get(choice) else {
+                return DispatchResult::unchanged();
+            };
+            state.modifiers.push(modifier);
+            state.modifier_offer.clear();
+            let line = format!(
+                "You take on {}: {}",
+                modifier.name(),
+                modifier.description()
+            );
+            state
+                .log
+                .push(state.floor_index, state.player.steps, line.clone());
+            state.last_status = Some(line);
             state.mode = GameMode::Boot;
-            DispatchResult::changed_with(generate_floor_effect(state.floor_index, state.seed))
+            DispatchResult::changed_with(generate_floor_effect(state))
+        }
+        Action::Interact => handle_interact(state),
+        Action::UseItem(slot) => {
+            if state.mode != GameMode::Exploration {
+                return DispatchResult::unchanged();
+            }
+            let Some(line) = items::use_item(state, slot) else {
+                return DispatchResult::unchanged();
+            };
+            state
+                .log
+                .push(state.floor_index, state.player.steps, line.clone());
+            state.last_status = Some(line);
+            DispatchResult::changed()
         }
-        Action::Interact => {
-            state.last_status = Some("No interactive object on this tile yet.".to_string());
+        Action::ToggleHood => {
+            if state.mode != GameMode::Exploration {
+                return DispatchResult::unchanged();
+            }
+            state.player.hooded = !state.player.hooded;
+            state.last_status = Some(if state.player.hooded {
+                "You hood the lantern. Harder to spot, harder to see.".to_string()
+            } else {
+                "You lift the hood off the lantern.".to_string()
+            });
             DispatchResult::changed()
         }
         Action::Tick | Action::DangerAdvance => DispatchResult::unchanged(),
@@ -71,6 +146,33 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
             }
             DispatchResult::unchanged()
         }
+        Action::LogScroll(delta) => {
+            if state.mode == GameMode::Boot || !state.log.scroll_by(delta) {
+                return DispatchResult::unchanged();
+            }
+            DispatchResult::changed()
+        }
+        Action::DevOverlayToggle => {
+            if !state.dev {
+                return DispatchResult::unchanged();
+            }
+            state.dev_overlay = !state.dev_overlay;
+            DispatchResult::changed()
+        }
+        Action::ExportView { png } => {
+            if state.mode == GameMode::Boot {
+                return DispatchResult::unchanged();
+            }
+            DispatchResult::changed_with(Effect::ExportView { png })
+        }
+        Action::ExportDidSave(path) => {
+            state.last_status = Some(format!("Saved view to {path}"));
+            DispatchResult::changed()
+        }
+        Action::ExportDidError(err) => {
+            state.last_status = Some(format!("Export failed: {err}"));
+            DispatchResult::changed()
+        }
         Action::Quit => DispatchResult::unchanged(),
     }
 }
@@ -187,6 +289,10 @@ fn handle_move(
         state.last_status = Some("Blocked path.".to_string());
         return DispatchResult::unchanged();
     }
+    if !state.map.can_step((curr_x, curr_y), direction) {
+        state.last_status = Some("The ledge is too high to climb.".to_string());
+        return DispatchResult::unchanged();
+    }
 
     let curr_had_trail = state.trail.charge_at(curr_x, curr_y) > 0;
     let will_collect = collect && curr_had_trail;
@@ -205,7 +311,7 @@ fn handle_move(
 
     let reclaimed = if will_collect {
         // Shift+move: pick up current tile's trail charge.
-        state.trail.take(curr_x, curr_y)
+        state.trail.take(curr_x, curr_y) * modifiers::siphon_factor(state)
     } else {
         0
     };
@@ -215,11 +321,32 @@ fn handle_move(
         state.trail.deposit(curr_x, curr_y, TRAIL_DEPOSIT_AMOUNT);
     }
 
-    let burn_cost = if state.trail.charge_at(next_x, next_y) > 0 {
+    let wading = state.map.tile(next_x, next_y) == Tile::Water;
+    let walk_burn = if state.trail.charge_at(next_x, next_y) > 0 {
         LIT_STEP_BURN
     } else {
-        STEP_BURN
+        STEP_BURN + modifiers::unlit_step_burn(state)
+    };
+    let wade_burn = if wading {
+        WADE_EXTRA_BURN * modifiers::siphon_factor(state)
+    } else {
+        0
+    };
+    let leak_burn = if state.player.leak_steps > 0 {
+        state.player.leak_steps -= 1;
+        1
+    } else {
+        0
     };
+    let burn_cost = walk_burn + wade_burn + leak_burn;
+    state.drain.book(
+        state.player.light_current,
+        &[
+            (DrainCause::Walking, walk_burn),
+            (DrainCause::Wading, wade_burn),
+            (DrainCause::Leak, leak_burn),
+        ],
+    );
 
     state.player.x = next_x;
     state.player.y = next_y;
@@ -230,29 +357,225 @@ fn handle_move(
         .saturating_sub(burn_cost)
         .saturating_add(reclaimed)
         .min(state.player.light_max);
-    state.player.steps = state.player.steps.saturating_add(1);
+    state.player.steps = state
+        .player
+        .steps
+        .saturating_add(if wading { WADE_STEPS } else { 1 });
 
     if state.player.light_current == 0 {
-        set_game_over(state, "Your lantern goes dark.");
-        return DispatchResult::changed();
+        return lantern_dark(state);
     }
 
     if is_anchor(state, RuntimeAnchorKind::Exit, next_x, next_y) {
-        state.floor_index = state.floor_index.saturating_add(1);
-        state.mode = GameMode::Boot;
-        state.last_status = Some(format!("Descended to floor {}", state.floor_index + 1));
-        return DispatchResult::changed_with(generate_floor_effect(state.floor_index, state.seed));
+        state.last_status = Some(format!("Descended to floor {}", state.floor_index + 2));
+        return descend(state);
+    }
+
+    if stalk(state) {
+        set_game_over(state, "The hunter finds you in the dark.");
+        return DispatchResult::changed();
+    }
+
+    state.last_status = Some(if wading {
+        format!("Steps: {} (wading)", state.player.steps)
+    } else {
+        format!("Steps: {}", state.player.steps)
+    });
+    if let Some(event) = narrative::step_event(state) {
+        state.log.push(state.floor_index, state.player.steps, event);
+    }
+    let had_bearings = compass::unlocked(state);
+    compass::reveal(state);
+    if !had_bearings
+        && compass::unlocked(state)
+        && let Some(line) = compass::unlock_line(state)
+    {
+        state.log.push(state.floor_index, state.player.steps, line);
+    }
+    pass_turn(state);
+    DispatchResult::changed()
+}
+
+/// Hotseat runs hand the keyboard over once the active explorer's shift is up.
+fn pass_turn(state: &mut AppState) {
+    if let Some(line) = hotseat::end_turn(state) {
+        state
+            .log
+            .push(state.floor_index, state.player.steps, line.clone());
+        state.last_status = Some(line);
+    }
+}
+
+/// The active lantern has gone dark. In a hotseat run the partner carries on
+/// if theirs is still lit; otherwise the run is over.
+fn lantern_dark(state: &mut AppState) -> DispatchResult<Effect> {
+    match hotseat::lantern_out(state) {
+        Some(line) => {
+            state
+                .log
+                .push(state.floor_index, state.player.steps, line.clone());
+            state.last_status = Some(line);
+        }
+        None => set_game_over(state, "Your lantern goes dark."),
+    }
+    DispatchResult::changed()
+}
+
+/// Lets the hunter react to the step just taken: it spots the player by
+/// lantern light and closes in while hunting. Returns whether it caught them.
+fn stalk(state: &mut AppState) -> bool {
+    let player = state.player_pos();
+    let light_range = lighting::player_light_range(state.player.light_current, state.player.hooded);
+    let Some(stalker) = state.hunter.as_mut() else {
+        return false;
+    };
+    let before = stalker.awareness;
+    let radius = hunter::detection_radius(stalker, light_range);
+    let awareness = hunter::update_awareness(stalker, player, radius);
+    let mut caught = stalker.pos() == player;
+    if !caught && awareness == Awareness::Hunting && hunter::ready_to_advance(stalker) {
+        hunter::advance_toward(stalker, player, &state.map);
+        caught = stalker.pos() == player;
+    }
+    if caught {
+        state.drain.hunter_contacts += 1;
     }
 
-    state.last_status = Some(format!("Steps: {}", state.player.steps));
+    let line = match (before, awareness) {
+        (Awareness::Hunting, Awareness::Hunting) => None,
+        (_, Awareness::Hunting) => Some("Something has caught sight of your lantern."),
+        (Awareness::Hunting, _) => Some("The footsteps lose your trail."),
+        _ => None,
+    };
+    if let Some(line) = line {
+        state.log.push(state.floor_index, state.player.steps, line);
+    }
+    caught
+}
+
+/// Takes the relic's item, reads the pack at a beacon, empties a cache, or
+/// searches the walls around the player for a false one.
+fn handle_interact(state: &mut AppState) -> DispatchResult<Effect> {
+    if state.mode != GameMode::Exploration {
+        return DispatchResult::unchanged();
+    }
+    let (x, y) = state.player_pos();
+    let line = if is_anchor(state, RuntimeAnchorKind::Relic, x, y) {
+        if state.player.pack.len() >= items::PACK_SIZE {
+            state.last_status = Some("Your pack is full.".to_string());
+            return DispatchResult::changed();
+        }
+        let seam = state.anchors.iter().any(|anchor| {
+            anchor.kind == RuntimeAnchorKind::Relic
+                && (anchor.x, anchor.y) == (x, y)
+                && anchor.tag.as_deref() == Some(modifiers::SEAM_TAG)
+        });
+        let item = if seam {
+            modifiers::seam_item(state.seed, state.floor_index, x, y)
+        } else {
+            items::relic_item(state.seed, state.floor_index)
+        };
+        let line = format!(
+            "You pry loose a relic: {}.",
+            items::display_name(state, &item)
+        );
+        state.player.pack.push(item);
+        state.anchors.retain(|anchor| {
+            !(anchor.kind == RuntimeAnchorKind::Relic && anchor.x == x && anchor.y == y)
+        });
+        line
+    } else if is_anchor(state, RuntimeAnchorKind::Beacon, x, y) {
+        match items::identify_pack(state) {
+            Some(line) => line,
+            None => {
+                state.last_status = Some("The beacon has nothing new to show you.".to_string());
+                return DispatchResult::changed();
+            }
+        }
+    } else if is_anchor(state, RuntimeAnchorKind::Cache, x, y) {
+        if state.player.pack.len() + secrets::CACHE_ITEMS > items::PACK_SIZE {
+            state.last_status = Some("Your pack is too full to empty the cache.".to_string());
+            return DispatchResult::changed();
+        }
+        let line = secrets::loot_cache(state);
+        state.anchors.retain(|anchor| {
+            !(anchor.kind == RuntimeAnchorKind::Cache && anchor.x == x && anchor.y == y)
+        });
+        line
+    } else if secrets::beside_wall(state, x, y) {
+        return search_walls(state);
+    } else {
+        state.last_status = Some("Nothing to interact with here.".to_string());
+        return DispatchResult::changed();
+    };
+    state
+        .log
+        .push(state.floor_index, state.player.steps, line.clone());
+    state.last_status = Some(line);
+    DispatchResult::changed()
+}
+
+/// Spends a step and a little light feeling the walls around the player for
+/// a false one. The hunter gets its turn as if the player had moved.
+fn search_walls(state: &mut AppState) -> DispatchResult<Effect> {
+    state.player.steps = state.player.steps.saturating_add(1);
+    state.drain.book(
+        state.player.light_current,
+        &[(DrainCause::Searching, secrets::SEARCH_BURN)],
+    );
+    state.player.light_current = state
+        .player
+        .light_current
+        .saturating_sub(secrets::SEARCH_BURN);
+    let found = secrets::search(state);
+    if state.player.light_current == 0 {
+        return lantern_dark(state);
+    }
+    if stalk(state) {
+        set_game_over(state, "The hunter finds you in the dark.");
+        return DispatchResult::changed();
+    }
+    match found {
+        Some(line) => {
+            state
+                .log
+                .push(state.floor_index, state.player.steps, line.clone());
+            state.last_status = Some(line);
+        }
+        None => state.last_status = Some("You search the walls but find nothing.".to_string()),
+    }
+    pass_turn(state);
     DispatchResult::changed()
 }
 
-fn generate_floor_effect(floor_index: u32, seed: u64) -> Effect {
-    let (width, height) = floor_dimensions(floor_index);
+/// Moves on to the next floor, stopping for a modifier offer first when
+/// endless mode has one due.
+fn descend(state: &mut AppState) -> DispatchResult<Effect> {
+    state.floor_index = state.floor_index.saturating_add(1);
+    if modifiers::offer_due(state) {
+        state.modifier_offer = modifiers::roll_offer(state.seed, state.floor_index);
+        state.mode = GameMode::ChooseModifier;
+        state.last_status = Some(format!(
+            "Floor {} cleared. Choose a modifier.",
+            state.floor_index
+        ));
+        return DispatchResult::changed();
+    }
+    state.mode = GameMode::Boot;
+    DispatchResult::changed_with(generate_floor_effect(state))
+}
+
+fn generate_floor_effect(state: &AppState) -> Effect {
+    // Narrow Halls holds every floor at the first floor's size.
+    let growth_floor = if modifiers::floors_grow(state) {
+        state.floor_index
+    } else {
+        0
+    };
+    let (width, height) = floor_dimensions(growth_floor);
     Effect::GenerateFloor {
-        floor_index,
-        seed,
+        floor_index: state.floor_index,
+        seed: state.seed,
         width,
         height,
     }
@@ -552,4 +875,240 @@ mod tests {
         // Collecting did not happen because the move was rejected.
         assert_eq!(state.trail.charge_at(1, 1), 1);
     }
+
+    fn corridor(tiles: &[Tile]) -> AppState {
+        use crate::state::{MapState, RuntimeAnchor};
+        use tui_map::core::{MapSize, TileKind};
+
+        let mut state = AppState::new(1004);
+        state.mode = GameMode::Exploration;
+        state.map = MapState::filled("line", MapSize::new(tiles.len() as u16, 1), TileKind::Floor);
+        state.map.tiles = tiles.to_vec();
+        state.trail = TrailState::new(tiles.len() as u16, 1);
+        state.anchors = vec![RuntimeAnchor {
+            kind: RuntimeAnchorKind::PlayerStart,
+            x: 0,
+            y: 0,
+            tag: None,
+        }];
+        state.player.x = 0;
+        state.player.y = 0;
+        state.player.light_current = 10;
+        state.player.light_max = 10;
+        state
+    }
+
+    #[test]
+    fn wading_costs_extra_light_and_time() {
+        let mut state = corridor(&[Tile::Floor, Tile::Water, Tile::Floor]);
+
+        let _ = reducer(&mut state, Action::Move(Direction::Right, false));
+        assert_eq!(state.player_pos(), (1, 0));
+        assert_eq!(state.player.light_current, 8);
+        assert_eq!(state.player.steps, 2);
+
+        let _ = reducer(&mut state, Action::Move(Direction::Right, false));
+        assert_eq!(state.player.light_current, 7);
+        assert_eq!(state.player.steps, 3);
+    }
+
+    #[test]
+    fn relics_fill_the_pack_and_beacons_identify_it() {
+        use crate::state::{Curse, RuntimeAnchor};
+
+        let mut state = corridor(&[Tile::Floor, Tile::Floor, Tile::Floor]);
+        for (kind, x) in [
+            (RuntimeAnchorKind::Relic, 0),
+            (RuntimeAnchorKind::Beacon, 1),
+        ] {
+            state.anchors.push(RuntimeAnchor {
+                kind,
+                x,
+                y: 0,
+                tag: None,
+            });
+        }
+
+        let _ = reducer(&mut state, Action::Interact);
+        assert_eq!(state.player.pack.len(), 1);
+        assert!(!is_anchor(&state, RuntimeAnchorKind::Relic, 0, 0));
+        assert!(state.identified.is_empty());
+
+        let _ = reducer(&mut state, Action::Move(Direction::Right, false));
+        let _ = reducer(&mut state, Action::Interact);
+        assert_eq!(state.identified, vec![state.player.pack[0].kind]);
+
+        // A leaking lantern burns one extra light per step until it runs dry.
+        state.player.pack[0].curse = Some(Curse::Leak);
+        let _ = reducer(&mut state, Action::UseItem(0));
+        assert_eq!(state.player.leak_steps, items::LEAK_STEPS);
+        let light = state.player.light_current;
+        let _ = reducer(&mut state, Action::Move(Direction::Right, false));
+        assert_eq!(state.player.light_current, light - 2);
+        assert_eq!(state.player.leak_steps, items::LEAK_STEPS - 1);
+    }
+
+    #[test]
+    fn interacting_by_a_wall_searches_it_and_caches_fill_the_pack() {
+        use crate::state::RuntimeAnchor;
+
+        let mut state = corridor(&[Tile::Floor, Tile::Floor]);
+        let _ = reducer(&mut state, Action::Interact);
+        assert_eq!(state.player.steps, 1);
+        assert_eq!(state.player.light_current, 10 - secrets::SEARCH_BURN);
+
+        state.anchors.push(RuntimeAnchor {
+            kind: RuntimeAnchorKind::Cache,
+            x: 0,
+            y: 0,
+            tag: None,
+        });
+        state.player.pack = vec![items::relic_item(1, 0); items::PACK_SIZE - 1];
+        let _ = reducer(&mut state, Action::Interact);
+        assert!(is_anchor(&state, RuntimeAnchorKind::Cache, 0, 0));
+        assert_eq!(state.player.steps, 1);
+
+        state.player.pack.truncate(1);
+        let _ = reducer(&mut state, Action::Interact);
+        assert!(!is_anchor(&state, RuntimeAnchorKind::Cache, 0, 0));
+        assert_eq!(state.player.pack.len(), 1 + secrets::CACHE_ITEMS);
+    }
+
+    #[test]
+    fn endless_runs_stop_for_a_modifier_every_fifth_floor() {
+        use crate::state::RunModifier;
+
+        let mut state = corridor(&[Tile::Floor, Tile::Floor]);
+        state.endless = true;
+        state.floor_index = modifiers::OFFER_EVERY - 1;
+        state.anchors[0].kind = RuntimeAnchorKind::Exit;
+        state.anchors[0].x = 1;
+
+        let result = reducer(&mut state, Action::Move(Direction::Right, false));
+        assert!(result.effects.is_empty());
+        assert_eq!(state.mode, GameMode::ChooseModifier);
+        assert_eq!(state.modifier_offer.len(), modifiers::OFFER_SIZE);
+
+        let picked = state.modifier_offer[1];
+        let result = reducer(&mut state, Action::PickModifier(1));
+        assert_eq!(result.effects.len(), 1);
+        assert_eq!(state.mode, GameMode::Boot);
+        assert_eq!(state.modifiers, vec![picked]);
+        assert!(state.modifier_offer.is_empty());
+
+        // Overcharge trades a bigger budget for pricier unlit steps.
+        let mut state = corridor(&[Tile::Floor, Tile::Floor]);
+        state.modifiers = vec![RunModifier::Overcharge];
+        let _ = reducer(&mut state, Action::Move(Direction::Right, false));
+        assert_eq!(state.player.light_current, 8);
+        assert!(modifiers::light_budget(&state, START_LIGHT) > START_LIGHT);
+    }
+
+    #[test]
+    fn ledges_can_be_dropped_but_not_climbed() {
+        let mut state = corridor(&[Tile::Floor, Tile::Ledge(Direction::Right), Tile::Floor]);
+
+        let _ = reducer(&mut state, Action::Move(Direction::Right, false));
+        let _ = reducer(&mut state, Action::Move(Direction::Right, false));
+        assert_eq!(state.player_pos(), (2, 0));
+
+        let _ = reducer(&mut state, Action::Move(Direction::Left, false));
+        assert_eq!(state.player_pos(), (2, 0));
+        assert_eq!(
+            state.last_status.as_deref(),
+            Some("The ledge is too high to climb.")
+        );
+    }
+
+    #[test]
+    fn hooding_the_lantern_sneaks_past_the_hunter() {
+        use crate::danger::hunter::HunterState;
+
+        let lit_corridor = || {
+            let mut state = corridor(&[Tile::Floor; 10]);
+            state.player.light_current = 120;
+            state.player.light_max = 120;
+            state.hunter = Some(HunterState::new(8, 0));
+            state
+        };
+        // Seven tiles off after the step: inside a bright lantern's reach only.
+        let mut state = lit_corridor();
+        let _ = reducer(&mut state, Action::Move(Direction::Right, false));
+        let stalker = state.hunter.as_ref().expect("hunter");
+        assert_eq!(stalker.awareness, Awareness::Hunting);
+        assert_eq!(stalker.pos(), (7, 0));
+
+        let mut state = lit_corridor();
+        let _ = reducer(&mut state, Action::ToggleHood);
+        let _ = reducer(&mut state, Action::Move(Direction::Right, false));
+        let stalker = state.hunter.as_ref().expect("hunter");
+        assert_eq!(stalker.awareness, Awareness::Suspicious);
+        assert_eq!(stalker.pos(), (8, 0));
+    }
+
+    #[test]
+    fn walking_into_the_hunter_ends_the_run() {
+        use crate::danger::hunter::HunterState;
+
+        let mut state = corridor(&[Tile::Floor; 3]);
+        state.hunter = Some(HunterState::new(1, 0));
+        let _ = reducer(&mut state, Action::Move(Direction::Right, false));
+        assert_eq!(state.mode, GameMode::GameOver);
+        assert_eq!(state.drain.hunter_contacts, 1);
+    }
+
+    #[test]
+    fn light_burned_is_booked_by_cause() {
+        let mut state = corridor(&[Tile::Floor, Tile::Water, Tile::Floor]);
+        state.player.light_current = 4;
+        state.player.leak_steps = 5;
+
+        let _ = reducer(&mut state, Action::Move(Direction::Right, false));
+        let _ = reducer(&mut state, Action::Move(Direction::Right, false));
+        assert_eq!(state.mode, GameMode::GameOver);
+        let shares: Vec<(DrainCause, u32)> = state
+            .drain
+            .breakdown()
+            .iter()
+            .map(|share| (share.cause, share.light))
+            .collect();
+        assert_eq!(
+            shares,
+            vec![
+                (DrainCause::Walking, 2),
+                (DrainCause::Wading, 1),
+                (DrainCause::Leak, 1)
+            ]
+        );
+        assert_eq!(state.drain.total(), 4);
+    }
+
+    #[test]
+    fn hotseat_runs_end_once_both_lanterns_are_out() {
+        let mut state = corridor(&[Tile::Floor; 4]);
+        state.player.light_current = 1;
+        state.hotseat = Some(Hotseat::new(&state.player, 3));
+
+        let _ = reducer(&mut state, Action::Move(Direction::Right, false));
+        assert_eq!(state.mode, GameMode::Exploration);
+        assert_eq!(state.hotseat.as_ref().expect("hotseat").active, 1);
+        assert_eq!(state.player_pos(), (0, 0));
+
+        state.player.light_current = 1;
+        let _ = reducer(&mut state, Action::Move(Direction::Right, false));
+        assert_eq!(state.mode, GameMode::GameOver);
+    }
+
+    #[test]
+    fn dev_overlay_needs_the_dev_flag() {
+        let mut state = AppState::new(321);
+        let _ = reducer(&mut state, Action::DevOverlayToggle);
+        assert!(!state.dev_overlay);
+
+        state.dev = true;
+        let _ = reducer(&mut state, Action::DevOverlayToggle);
+        assert!(state.dev_overlay);
+        let _ = reducer(&mut state, Action::DevOverlayToggle);
+        assert!(!state.dev_overlay);
+    }
 }
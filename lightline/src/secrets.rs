@@ -0,0 +1,227 @@
+//! Secret rooms: a false wall sealing off a short dead-end branch, and the
+//! cache of loot waiting at the end of it.
+//!
+//! Pressing `E` beside a wall searches it, spending a step and a little light,
+//! with a small chance of finding a false wall next to the player. Blasting
+//! powder opens every false wall within reach at once. Breakable walls crumble
+//! and illusory ones fade, but either way the tile becomes floor for good.
+
+use crate::procgen::mix64;
+use crate::state::{AppState, Direction, Item, ItemKind, RuntimeAnchor, RuntimeAnchorKind, Tile};
+
+/// Anchor tags telling the two kinds of false wall apart.
+pub const BREAKABLE_TAG: &str = "breakable";
+pub const ILLUSORY_TAG: &str = "illusory";
+
+// Secret tuning:
+// - REVEAL_PERCENT: chance a search turns up a false wall beside the player.
+// - SEARCH_BURN: light a search costs, on top of the step.
+// - BLAST_RADIUS: reach of blasting powder, in tiles of Manhattan distance.
+// - CACHE_ITEMS: items in a cache; all of them have to fit in the pack.
+const REVEAL_PERCENT: u64 = 20;
+pub const SEARCH_BURN: u16 = 1;
+const BLAST_RADIUS: u16 = 2;
+pub const CACHE_ITEMS: usize = 2;
+
+const SEARCH_SALT: u64 = 0xa409_3822_299f_31d0;
+const CACHE_SALT: u64 = 0x082e_fa98_ec4e_6c89;
+
+/// Whether `anchor` is still hidden: a false wall nobody has opened, or a
+/// cache behind one.
+pub fn concealed(state: &AppState, anchor: &RuntimeAnchor) -> bool {
+    match anchor.kind {
+        RuntimeAnchorKind::SecretWall => state.map.tile(anchor.x, anchor.y) == Tile::Wall,
+        RuntimeAnchorKind::Cache => state
+            .anchors
+            .iter()
+            .any(|wall| wall.kind == RuntimeAnchorKind::SecretWall && concealed(state, wall)),
+        _ => false,
+    }
+}
+
+/// Whether there is a wall right next to (x, y) to search.
+pub fn beside_wall(state: &AppState, x: u16, y: u16) -> bool {
+    Direction::ALL.iter().any(|heading| {
+        heading
+            .step(x, y)
+            .is_some_and(|(nx, ny)| state.map.tile(nx, ny) == Tile::Wall)
+    })
+}
+
+/// Searches the walls around the player. Returns the log line when a false
+/// wall gives way, `None` when the search turns up nothing. Rolls are seeded
+/// by the run seed, floor and step count.
+pub fn search(state: &mut AppState) -> Option<String> {
+    let roll = mix64(
+        state.seed ^ SEARCH_SALT ^ ((state.floor_index as u64) << 32) ^ state.player.steps as u64,
+    );
+    if roll % 100 >= REVEAL_PERCENT {
+        return None;
+    }
+    let opened = open_walls(state, 1);
+    let illusory = *opened.first()?;
+    Some(if illusory {
+        "Your hand passes through the wall. It was never there.".to_string()
+    } else {
+        "The mortar here is loose. You shoulder the wall and it crumbles away.".to_string()
+    })
+}
+
+/// Sets off blasting powder at the player's feet. Returns the log line.
+pub fn blast(state: &mut AppState) -> String {
+    let opened = open_walls(state, BLAST_RADIUS);
+    if opened.is_empty() {
+        "The blast shakes dust from the ceiling, but the walls hold.".to_string()
+    } else if opened.iter().all(|&illusory| illusory) {
+        "In the flash, part of the wall flickers and is gone.".to_string()
+    } else {
+        "The blast tears open a hidden passage!".to_string()
+    }
+}
+
+/// Opens every hidden false wall within `radius` of the player. Returns
+/// whether each one was illusory.
+fn open_walls(state: &mut AppState, radius: u16) -> Vec<bool> {
+    let (px, py) = state.player_pos();
+    let walls: Vec<(u16, u16, bool)> = state
+        .anchors
+        .iter()
+        .filter(|anchor| anchor.kind == RuntimeAnchorKind::SecretWall)
+        .filter(|anchor| concealed(state, anchor))
+        .filter(|anchor| anchor.x.abs_diff(px) + anchor.y.abs_diff(py) <= radius)
+        .map(|anchor| {
+            let illusory = anchor.tag.as_deref() == Some(ILLUSORY_TAG);
+            (anchor.x, anchor.y, illusory)
+        })
+        .collect();
+    let width = state.map.width as usize;
+    for &(x, y, _) in &walls {
+        if let Some(tile) = state.map.tiles.get_mut(y as usize * width + x as usize) {
+            *tile = Tile::Floor;
+        }
+    }
+    walls.into_iter().map(|(_, _, illusory)| illusory).collect()
+}
+
+/// What this floor's cache holds. Cache items are never cursed, and their
+/// kinds are plain to see. Same seed and floor, same loot.
+pub fn cache_loot(seed: u64, floor_index: u32) -> Vec<Item> {
+    let mut z = mix64(seed ^ CACHE_SALT ^ ((floor_index as u64) << 32));
+    (0..CACHE_ITEMS)
+        .map(|_| {
+            z = mix64(z);
+            Item {
+                kind: ItemKind::ALL[(z % ItemKind::ALL.len() as u64) as usize],
+                curse: None,
+                curse_known: true,
+            }
+        })
+        .collect()
+}
+
+/// Empties the cache into the pack, identifying what was in it. The caller
+/// checks the pack has room. Returns the log line.
+pub fn loot_cache(state: &mut AppState) -> String {
+    let loot = cache_loot(state.seed, state.floor_index);
+    let mut names = Vec::new();
+    for item in loot {
+        if !state.identified.contains(&item.kind) {
+            state.identified.push(item.kind);
+        }
+        names.push(item.kind.name());
+        state.player.pack.push(item);
+    }
+    format!("You open the cache: {}.", names.join(" and "))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tui_map::core::{MapSize, TileKind};
+
+    use crate::state::MapState;
+
+    /// A 5x3 room with a false wall at (3, 1) and a cache behind it at (4, 1).
+    fn sealed_room(tag: &str) -> AppState {
+        let mut state = AppState::new(8);
+        state.map = MapState::filled("Test", MapSize::new(5, 3), TileKind::Floor);
+        state.map.tiles[8] = Tile::Wall;
+        state.player.x = 2;
+        state.player.y = 1;
+        state.anchors = vec![
+            RuntimeAnchor {
+                kind: RuntimeAnchorKind::SecretWall,
+                x: 3,
+                y: 1,
+                tag: Some(tag.to_string()),
+            },
+            RuntimeAnchor {
+                kind: RuntimeAnchorKind::Cache,
+                x: 4,
+                y: 1,
+                tag: None,
+            },
+        ];
+        state
+    }
+
+    #[test]
+    fn searching_eventually_finds_an_adjacent_false_wall() {
+        let mut state = sealed_room(ILLUSORY_TAG);
+        assert!(beside_wall(&state, 2, 1));
+        assert!(concealed(&state, &state.anchors[1]));
+
+        let mut tries = 0;
+        let line = loop {
+            tries += 1;
+            state.player.steps += 1;
+            if let Some(line) = search(&mut state) {
+                break line;
+            }
+            assert!(tries < 200, "search never succeeded");
+        };
+        assert!(line.contains("never there"));
+        assert_eq!(state.map.tile(3, 1), Tile::Floor);
+        assert!(!concealed(&state, &state.anchors[0]));
+        assert!(!concealed(&state, &state.anchors[1]));
+    }
+
+    #[test]
+    fn searching_far_from_a_false_wall_finds_nothing() {
+        let mut state = sealed_room(BREAKABLE_TAG);
+        state.player.x = 0;
+        for steps in 0..100 {
+            state.player.steps = steps;
+            assert_eq!(search(&mut state), None);
+        }
+        assert_eq!(state.map.tile(3, 1), Tile::Wall);
+    }
+
+    #[test]
+    fn blasting_powder_opens_walls_in_reach() {
+        let mut state = sealed_room(BREAKABLE_TAG);
+        state.player.x = 0;
+        assert!(blast(&mut state).contains("walls hold"));
+        assert_eq!(state.map.tile(3, 1), Tile::Wall);
+
+        state.player.x = 1;
+        assert!(blast(&mut state).contains("hidden passage"));
+        assert_eq!(state.map.tile(3, 1), Tile::Floor);
+    }
+
+    #[test]
+    fn cache_loot_is_stable_uncursed_and_identified() {
+        let loot = cache_loot(5, 2);
+        assert_eq!(loot, cache_loot(5, 2));
+        assert_eq!(loot.len(), CACHE_ITEMS);
+        assert!(loot.iter().all(|item| item.curse.is_none()));
+
+        let mut state = sealed_room(ILLUSORY_TAG);
+        let line = loot_cache(&mut state);
+        assert!(line.starts_with("You open the cache"));
+        assert_eq!(state.player.pack.len(), CACHE_ITEMS);
+        for item in &state.player.pack {
+            assert!(state.identified.contains(&item.kind));
+        }
+    }
+}
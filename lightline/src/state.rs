@@ -2,11 +2,17 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use tui_map::core::{MapGrid, MapRead, MapSize, TileKind};
 
+use crate::danger::hunter::HunterState;
+use crate::drain::DrainLedger;
+use crate::hotseat::Hotseat;
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum GameMode {
     Boot,
     Exploration,
     Pause,
+    /// Endless mode only: the floor is cleared and a run modifier must be picked.
+    ChooseModifier,
     GameOver,
 }
 
@@ -31,6 +37,11 @@ pub enum RuntimeAnchorKind {
     Beacon,
     Relic,
     Switch,
+    /// False wall sealing off a secret room; tagged breakable or illusory.
+    /// Drawn as plain wall until revealed, then left on the opened tile.
+    SecretWall,
+    /// Premium loot at the back of a secret room.
+    Cache,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
@@ -48,8 +59,16 @@ pub enum Tile {
     Water,
     Trail,
     Grass,
+    /// Raised step that can only be crossed heading in the given direction.
+    Ledge(Direction),
 }
 
+// `TileKind::Custom` ids that carry ledges through tui-map grids.
+const LEDGE_UP: u16 = 1;
+const LEDGE_DOWN: u16 = 2;
+const LEDGE_LEFT: u16 = 3;
+const LEDGE_RIGHT: u16 = 4;
+
 impl Tile {
     pub fn to_tile_kind(self) -> TileKind {
         match self {
@@ -58,6 +77,10 @@ impl Tile {
             Tile::Water => TileKind::Water,
             Tile::Trail => TileKind::Trail,
             Tile::Grass => TileKind::Grass,
+            Tile::Ledge(Direction::Up) => TileKind::Custom(LEDGE_UP),
+            Tile::Ledge(Direction::Down) => TileKind::Custom(LEDGE_DOWN),
+            Tile::Ledge(Direction::Left) => TileKind::Custom(LEDGE_LEFT),
+            Tile::Ledge(Direction::Right) => TileKind::Custom(LEDGE_RIGHT),
         }
     }
 
@@ -69,11 +92,34 @@ impl Tile {
             TileKind::Trail => Tile::Trail,
             TileKind::Grass => Tile::Grass,
             TileKind::Sand => Tile::Floor,
+            TileKind::Custom(LEDGE_UP) => Tile::Ledge(Direction::Up),
+            TileKind::Custom(LEDGE_DOWN) => Tile::Ledge(Direction::Down),
+            TileKind::Custom(LEDGE_LEFT) => Tile::Ledge(Direction::Left),
+            TileKind::Custom(LEDGE_RIGHT) => Tile::Ledge(Direction::Right),
             TileKind::Custom(_) => Tile::Floor,
         }
     }
 }
 
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Down,
+        Direction::Left,
+        Direction::Right,
+    ];
+
+    /// Neighbor of (x, y) in this direction, or `None` past the top/left edge.
+    pub fn step(self, x: u16, y: u16) -> Option<(u16, u16)> {
+        match self {
+            Direction::Up => Some((x, y.checked_sub(1)?)),
+            Direction::Down => Some((x, y.checked_add(1)?)),
+            Direction::Left => Some((x.checked_sub(1)?, y)),
+            Direction::Right => Some((x.checked_add(1)?, y)),
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct MapState {
     pub name: String,
@@ -105,7 +151,20 @@ impl MapState {
     }
 
     pub fn is_walkable(&self, x: u16, y: u16) -> bool {
-        matches!(self.tile(x, y), Tile::Floor | Tile::Trail | Tile::Grass)
+        !matches!(self.tile(x, y), Tile::Wall)
+    }
+
+    /// Whether a step from `from` heading `heading` is allowed. Walkability
+    /// alone ignores direction; ledges are one-way on the way in and out.
+    pub fn can_step(&self, from: (u16, u16), heading: Direction) -> bool {
+        let Some((x, y)) = heading.step(from.0, from.1) else {
+            return false;
+        };
+        let crosses = |tile| match tile {
+            Tile::Ledge(drop) => drop == heading,
+            _ => true,
+        };
+        self.is_walkable(x, y) && crosses(self.tile(from.0, from.1)) && crosses(self.tile(x, y))
     }
 
     pub fn is_light_blocker(&self, x: u16, y: u16) -> bool {
@@ -177,6 +236,43 @@ impl TrailState {
     }
 }
 
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum ItemKind {
+    LampOil,
+    SpareWick,
+    Chalk,
+    Bomb,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum Curse {
+    /// Every step burns an extra light for a while.
+    Leak,
+    /// Max light drops until the next floor.
+    Dim,
+}
+
+/// Endless-mode trade-offs that last for the rest of the run once picked.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum RunModifier {
+    /// More light per floor, but unlit steps burn faster.
+    Overcharge,
+    /// Extra relics on every floor, but more of it is flooded.
+    RichSeams,
+    /// Reclaimed trail gives back more, but wading burns more.
+    Siphon,
+    /// Floors stay small, but start with less light.
+    NarrowHalls,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct Item {
+    pub kind: ItemKind,
+    pub curse: Option<Curse>,
+    /// Set once a beacon has exposed the curse; using the item always does.
+    pub curse_known: bool,
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct PlayerState {
     pub x: u16,
@@ -184,6 +280,78 @@ pub struct PlayerState {
     pub light_current: u16,
     pub light_max: u16,
     pub steps: u32,
+    pub pack: Vec<Item>,
+    /// Steps left on a leaking-lantern curse.
+    pub leak_steps: u16,
+    /// A hooded lantern lights less of the floor but is harder to spot.
+    #[serde(default)]
+    pub hooded: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct LogEntry {
+    pub floor: u32,
+    pub step: u32,
+    pub text: String,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct NarrativeLog {
+    pub entries: Vec<LogEntry>,
+    /// Entries scrolled back from the newest one; 0 follows the tail.
+    pub scroll: usize,
+}
+
+impl NarrativeLog {
+    pub const CAPACITY: usize = 64;
+
+    pub fn push(&mut self, floor: u32, step: u32, text: impl Into<String>) {
+        self.entries.push(LogEntry {
+            floor,
+            step,
+            text: text.into(),
+        });
+        if self.entries.len() > Self::CAPACITY {
+            self.entries.remove(0);
+        }
+        // Keep the scrolled-back view on the same entries while new ones arrive.
+        if self.scroll > 0 {
+            self.scroll = (self.scroll + 1).min(self.max_scroll());
+        }
+    }
+
+    /// Scroll towards older (positive) or newer (negative) entries. Returns true if the view moved.
+    pub fn scroll_by(&mut self, delta: i32) -> bool {
+        let next = (self.scroll as i64 + delta as i64).clamp(0, self.max_scroll() as i64) as usize;
+        let moved = next != self.scroll;
+        self.scroll = next;
+        moved
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.scroll = 0;
+    }
+
+    fn max_scroll(&self) -> usize {
+        self.entries.len().saturating_sub(1)
+    }
+}
+
+/// A room carved by procgen, labelled with what was placed in it.
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct RoomInfo {
+    pub label: String,
+    pub x: u16,
+    pub y: u16,
+    pub w: u16,
+    pub h: u16,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct PhaseTiming {
+    pub phase: String,
+    pub micros: u64,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -195,6 +363,19 @@ pub struct GeneratedFloor {
     pub generator_version: u32,
     pub seed: u64,
     pub fingerprint: String,
+    pub rooms: Vec<RoomInfo>,
+    /// How long each generation phase took, in the order they ran.
+    pub timings: Vec<PhaseTiming>,
+}
+
+/// Where the current floor came from, kept for the `--dev` overlay.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct FloorReport {
+    pub generator: String,
+    pub seed: u64,
+    pub fingerprint: String,
+    pub rooms: Vec<RoomInfo>,
+    pub timings: Vec<PhaseTiming>,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -208,6 +389,34 @@ pub struct AppState {
     pub danger_mode: DangerMode,
     pub anchors: Vec<RuntimeAnchor>,
     pub last_status: Option<String>,
+    pub log: NarrativeLog,
+    /// Item kinds whose real names are known this run.
+    pub identified: Vec<ItemKind>,
+    /// Row-major flags for tiles seen on this floor; feeds the exit compass.
+    pub explored: Vec<bool>,
+    /// Offer a run modifier every few floors.
+    pub endless: bool,
+    /// Modifiers picked this run, in order; repeats stack.
+    pub modifiers: Vec<RunModifier>,
+    /// Choices on screen while in `GameMode::ChooseModifier`.
+    pub modifier_offer: Vec<RunModifier>,
+    #[serde(default)]
+    pub report: FloorReport,
+    /// Stalks the player on Sound Hunter floors.
+    #[serde(default)]
+    pub hunter: Option<HunterState>,
+    /// Set by `--hotseat`: a second explorer taking turns with the first.
+    #[serde(default)]
+    pub hotseat: Option<Hotseat>,
+    /// Light burned this run by cause, for the game-over breakdown.
+    #[serde(default)]
+    pub drain: DrainLedger,
+    /// Set by `--dev`, which allows the overlay; not saved with the run.
+    #[serde(skip)]
+    pub dev: bool,
+    /// Room labels, danger scores and procgen stats drawn over the map.
+    #[serde(skip)]
+    pub dev_overlay: bool,
 }
 
 impl AppState {
@@ -224,19 +433,43 @@ impl AppState {
                 light_current: 120,
                 light_max: 120,
                 steps: 0,
+                pack: Vec::new(),
+                leak_steps: 0,
+                hooded: false,
             },
             trail: TrailState::new(3, 3),
             danger_mode: DangerMode::SoundHunter,
             anchors: Vec::new(),
             last_status: None,
+            log: NarrativeLog::default(),
+            identified: Vec::new(),
+            explored: vec![false; 9],
+            endless: false,
+            modifiers: Vec::new(),
+            modifier_offer: Vec::new(),
+            report: FloorReport::default(),
+            hunter: None,
+            hotseat: None,
+            drain: DrainLedger::default(),
+            dev: false,
+            dev_overlay: false,
         }
     }
 
     pub fn apply_generated_floor(&mut self, floor: GeneratedFloor) {
         self.map = floor.map;
         self.trail = TrailState::new(self.map.width, self.map.height);
+        self.explored = vec![false; self.map.width as usize * self.map.height as usize];
         self.anchors = floor.anchors;
         self.danger_mode = floor.danger_mode;
+        self.hunter = None;
+        self.report = FloorReport {
+            generator: format!("{} v{}", floor.generator_id, floor.generator_version),
+            seed: floor.seed,
+            fingerprint: floor.fingerprint,
+            rooms: floor.rooms,
+            timings: floor.timings,
+        };
 
         if let Some((x, y)) = self.anchor_pos(RuntimeAnchorKind::PlayerStart) {
             self.player.x = x;
@@ -282,6 +515,45 @@ mod tests {
         assert_eq!(trail.take(2, 2), 0);
     }
 
+    #[test]
+    fn log_scrollback_is_clamped_and_sticks_to_entries() {
+        let mut log = NarrativeLog::default();
+        for step in 0..3 {
+            log.push(0, step, format!("event {step}"));
+        }
+        assert!(log.scroll_by(5));
+        assert_eq!(log.scroll, 2);
+        assert!(!log.scroll_by(1));
+
+        log.push(0, 3, "event 3");
+        assert_eq!(log.scroll, 3);
+        assert!(log.scroll_by(-10));
+        assert_eq!(log.scroll, 0);
+    }
+
+    #[test]
+    fn ledges_round_trip_through_tile_kind() {
+        for direction in Direction::ALL {
+            let ledge = Tile::Ledge(direction);
+            assert_eq!(Tile::from_tile_kind(ledge.to_tile_kind()), ledge);
+        }
+        assert_eq!(Tile::from_tile_kind(TileKind::Custom(99)), Tile::Floor);
+    }
+
+    #[test]
+    fn ledge_is_one_way() {
+        let mut map = MapState::filled("test", MapSize::new(3, 3), TileKind::Floor);
+        map.tiles[4] = Tile::Ledge(Direction::Down);
+
+        assert!(map.can_step((1, 0), Direction::Down));
+        assert!(map.can_step((1, 1), Direction::Down));
+        assert!(!map.can_step((1, 2), Direction::Up));
+        assert!(!map.can_step((0, 1), Direction::Right));
+        assert!(!map.can_step((1, 1), Direction::Left));
+        assert!(map.can_step((0, 0), Direction::Right));
+        assert!(!map.can_step((0, 0), Direction::Left));
+    }
+
     #[test]
     fn out_of_bounds_tile_is_wall() {
         let map = MapState::filled("test", MapSize::new(4, 4), TileKind::Floor);
@@ -6,7 +6,7 @@ use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
     text::{Line, Span},
-    widgets::{Block, Borders, Paragraph},
+    widgets::{Block, Borders, Clear, Paragraph, Wrap},
 };
 use tui_map::core::TileKind;
 use tui_map::render::{
@@ -14,8 +14,20 @@ use tui_map::render::{
     adjust_color,
 };
 
-use crate::lighting::{LightSource, apply_light_field_to_buffer, compute_light_field};
-use crate::state::{AppState, DangerMode, GameMode, RuntimeAnchorKind};
+use crate::compass;
+use crate::danger::hunter::{Awareness, HunterState, detection_radius};
+use crate::danger::score::{MAX_SCORE, tile_scores};
+use crate::drain;
+use crate::hotseat;
+use crate::items;
+use crate::lighting::{
+    LightField, LightSource, apply_light_field_to_buffer, compute_light_field, player_light_range,
+};
+use crate::modifiers;
+use crate::secrets;
+use crate::state::{
+    AppState, DangerMode, Direction as Heading, GameMode, PlayerState, RuntimeAnchorKind, Tile,
+};
 
 const BG: Color = Color::Rgb(16, 18, 24);
 const FG: Color = Color::Rgb(230, 228, 218);
@@ -23,21 +35,19 @@ const MUTED: Color = Color::Rgb(146, 148, 154);
 const ACCENT: Color = Color::Rgb(233, 199, 104);
 const DANGER_HUNTER: Color = Color::Rgb(210, 88, 78);
 const DANGER_COLLAPSE: Color = Color::Rgb(222, 158, 78);
+const CURSED: Color = Color::Rgb(176, 110, 214);
+// The hunter never fades below this share of its color, even unlit.
+const HUNTER_MIN_VISIBLE: f32 = 0.45;
 const TRAIL_GLOW: Color = Color::Rgb(228, 186, 88);
 const TRAIL_EDGE: Color = Color::Rgb(186, 138, 62);
 const PLAYER_CORE: Color = Color::Rgb(255, 252, 244);
 const PLAYER_EDGE: Color = Color::Rgb(218, 228, 248);
 const PLAYER_DIM: Color = Color::Rgb(128, 148, 184);
 
-// Player light range tuning:
-// range = (BASE + light_current / DIVISOR).clamp(MIN, MAX)
-const PLAYER_LIGHT_RANGE_BASE: u16 = 1;
-const PLAYER_LIGHT_RANGE_DIVISOR: u16 = 30;
-const PLAYER_LIGHT_RANGE_MIN: u16 = 1;
-const PLAYER_LIGHT_RANGE_MAX: u16 = 6;
-
 const CELL_ASPECT: f32 = 2.0;
 const MAP_TILES_V: u16 = 10;
+const LOG_HEIGHT: u16 = 5;
+const DEV_PANEL_WIDTH: u16 = 36;
 
 static MAP_RENDERER: OnceLock<MapRenderer> = OnceLock::new();
 
@@ -60,6 +70,7 @@ fn lightline_map_theme() -> TileTheme {
     let grass_base = Color::Rgb(82, 148, 92);
     let wall_base = Color::Rgb(18, 20, 24);
     let water_base = Color::Rgb(14, 58, 142);
+    let ledge_base = Color::Rgb(128, 122, 112);
 
     let floor = TilePalette::new(
         floor_base,
@@ -107,27 +118,58 @@ fn lightline_map_theme() -> TileTheme {
         ],
     );
 
-    TileTheme::builder()
+    // Ledges sit a shade above the floor, textured with the way they drop.
+    let ledge = |glyph: char| {
+        TilePalette::new(
+            ledge_base,
+            adjust_color(ledge_base, 10),
+            [
+                TextureVariant::new(glyph, adjust_color(ledge_base, 90), 10),
+                TextureVariant::new(glyph, adjust_color(ledge_base, 64), 8),
+                TextureVariant::new('_', adjust_color(ledge_base, 40), 6),
+            ],
+        )
+    };
+
+    let mut theme = TileTheme::builder()
         .fallback(floor)
         .tile(TileKind::Floor, floor)
         .tile(TileKind::Trail, trail)
         .tile(TileKind::Grass, grass)
         .tile(TileKind::Wall, wall)
-        .tile(TileKind::Water, water)
-        .build()
+        .tile(TileKind::Water, water);
+    for (heading, glyph) in [
+        (Heading::Up, '^'),
+        (Heading::Down, 'v'),
+        (Heading::Left, '<'),
+        (Heading::Right, '>'),
+    ] {
+        theme = theme.tile(Tile::Ledge(heading).to_tile_kind(), ledge(glyph));
+    }
+    theme.build()
 }
 
 pub fn render(frame: &mut Frame, area: Rect, state: &AppState) {
     let chunks = Layout::default()
         .direction(Direction::Vertical)
-        .constraints([Constraint::Min(8), Constraint::Length(4)])
+        .constraints([
+            Constraint::Min(8),
+            Constraint::Length(LOG_HEIGHT),
+            Constraint::Length(5),
+        ])
         .split(area);
 
-    let title = format!(
+    let mut title = format!(
         "Lightline  Floor {}  [{:?}]",
         state.floor_index + 1,
         state.danger_mode
     );
+    if state.endless {
+        title.push_str("  Endless");
+    }
+    if state.hotseat.is_some() {
+        title.push_str("  Hotseat");
+    }
     let block = Block::default()
         .title(title)
         .borders(Borders::ALL)
@@ -135,6 +177,16 @@ pub fn render(frame: &mut Frame, area: Rect, state: &AppState) {
     let map_inner = block.inner(chunks[0]);
     frame.render_widget(block, chunks[0]);
     render_map(frame, map_inner, state);
+    if state.dev_overlay {
+        render_dev_panel(frame, map_inner, state);
+    }
+    if state.mode == GameMode::ChooseModifier {
+        render_modifier_offer(frame, map_inner, state);
+    }
+    if state.mode == GameMode::GameOver {
+        render_drain_report(frame, map_inner, state);
+    }
+    render_log(frame, chunks[1], state);
 
     let danger_color = match state.danger_mode {
         DangerMode::SoundHunter => DANGER_HUNTER,
@@ -144,24 +196,83 @@ pub fn render(frame: &mut Frame, area: Rect, state: &AppState) {
         .last_status
         .clone()
         .unwrap_or_else(|| "Find the exit (>) and descend.".to_string());
-    let lines = vec![
-        Line::from(vec![
-            Span::styled(
-                format!(
-                    "Light {}/{}  ",
-                    state.player.light_current, state.player.light_max
-                ),
-                Style::default().fg(ACCENT).add_modifier(Modifier::BOLD),
-            ),
-            Span::styled(
-                format!("Danger {:?}  ", state.danger_mode),
-                Style::default().fg(danger_color),
+    let mut stats = Vec::new();
+    if let Some(seat) = &state.hotseat {
+        stats.push(Span::styled(
+            format!(
+                "{} ({} left)  ",
+                hotseat::name(seat.active),
+                seat.turns_left
             ),
-            Span::styled(
-                format!("Pos ({}, {})", state.player.x, state.player.y),
-                Style::default().fg(MUTED),
+            Style::default().fg(FG).add_modifier(Modifier::BOLD),
+        ));
+    }
+    stats.extend([
+        Span::styled(
+            format!(
+                "Light {}/{}  ",
+                state.player.light_current, state.player.light_max
             ),
-        ]),
+            Style::default().fg(ACCENT).add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("Danger {:?}  ", state.danger_mode),
+            Style::default().fg(danger_color),
+        ),
+        Span::styled(
+            format!("Pos ({}, {})", state.player.x, state.player.y),
+            Style::default().fg(MUTED),
+        ),
+        Span::styled(
+            format!("  Explored {}%", compass::explored_percent(state)),
+            Style::default().fg(MUTED),
+        ),
+    ]);
+    if let Some(seat) = &state.hotseat {
+        let partner = &seat.partner;
+        let text = if partner.light_current == 0 {
+            format!("  {} dark", hotseat::name(seat.partner_index()))
+        } else {
+            format!(
+                "  {} {}/{}",
+                hotseat::name(seat.partner_index()),
+                partner.light_current,
+                partner.light_max
+            )
+        };
+        stats.push(Span::styled(text, Style::default().fg(MUTED)));
+    }
+    if let Some(arrow) = compass::exit_arrow(state) {
+        stats.push(Span::styled(
+            format!("  Exit {arrow}"),
+            Style::default().fg(ACCENT),
+        ));
+    }
+    if let Some(stalker) = &state.hunter {
+        let range = player_light_range(state.player.light_current, state.player.hooded);
+        stats.push(Span::styled(
+            format!("  Seen within {}", detection_radius(stalker, range)),
+            Style::default().fg(DANGER_HUNTER),
+        ));
+    }
+    if state.player.hooded {
+        stats.push(Span::styled("  Hooded", Style::default().fg(MUTED)));
+    }
+    if state.player.leak_steps > 0 {
+        stats.push(Span::styled(
+            format!("  Leaking {}", state.player.leak_steps),
+            Style::default().fg(CURSED),
+        ));
+    }
+    if !state.modifiers.is_empty() {
+        stats.push(Span::styled(
+            format!("  Modifiers {}", modifier_summary(state)),
+            Style::default().fg(MUTED),
+        ));
+    }
+    let lines = vec![
+        Line::from(stats),
+        pack_line(state),
         Line::from(Span::styled(status, Style::default().fg(FG))),
         Line::from(Span::styled(
             controls_line(state.mode),
@@ -170,15 +281,216 @@ pub fn render(frame: &mut Frame, area: Rect, state: &AppState) {
     ];
     let footer = Paragraph::new(lines).alignment(Alignment::Left);
 
-    frame.render_widget(footer, chunks[1]);
+    frame.render_widget(footer, chunks[2]);
+}
+
+/// Picked modifiers in order, repeats folded into a count, e.g. `Siphon x2, Rich Seams`.
+fn modifier_summary(state: &AppState) -> String {
+    let mut seen = Vec::new();
+    for &modifier in &state.modifiers {
+        if !seen.contains(&modifier) {
+            seen.push(modifier);
+        }
+    }
+    seen.iter()
+        .map(|&modifier| match modifiers::stacks(state, modifier) {
+            1 => modifier.name().to_string(),
+            n => format!("{} x{n}", modifier.name()),
+        })
+        .collect::<Vec<_>>()
+        .join(", ")
+}
+
+fn render_modifier_offer(frame: &mut Frame, area: Rect, state: &AppState) {
+    let width = 64.min(area.width);
+    let height = (state.modifier_offer.len() as u16 * 3 + 4).min(area.height);
+    let popup = Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    );
+    let block = Block::default()
+        .title(format!(" Floor {} cleared ", state.floor_index))
+        .borders(Borders::ALL)
+        .style(Style::default().bg(BG).fg(ACCENT));
+    let inner = block.inner(popup);
+    frame.render_widget(Clear, popup);
+    frame.render_widget(block, popup);
+
+    let mut lines = vec![Line::from(Span::styled(
+        "Take one for the rest of the run:",
+        Style::default().fg(MUTED),
+    ))];
+    for (slot, modifier) in state.modifier_offer.iter().enumerate() {
+        let stacked = modifiers::stacks(state, *modifier);
+        let mut title = vec![
+            Span::styled(format!("{} ", slot + 1), Style::default().fg(ACCENT)),
+            Span::styled(
+                modifier.name(),
+                Style::default().fg(FG).add_modifier(Modifier::BOLD),
+            ),
+        ];
+        if stacked > 0 {
+            title.push(Span::styled(
+                format!("  (have x{stacked})"),
+                Style::default().fg(MUTED),
+            ));
+        }
+        lines.push(Line::default());
+        lines.push(Line::from(title));
+        lines.push(Line::from(Span::styled(
+            format!("  {}", modifier.description()),
+            Style::default().fg(MUTED),
+        )));
+    }
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+/// The game-over breakdown: the run's biggest light drains with their share
+/// of the total, and what to try next time.
+fn render_drain_report(frame: &mut Frame, area: Rect, state: &AppState) {
+    const TOP_CAUSES: usize = 3;
+    const BAR_WIDTH: u32 = 12;
+
+    let shares = state.drain.breakdown();
+    let caught = state.drain.hunter_contacts > 0;
+    let rows = shares.len().clamp(1, TOP_CAUSES) as u16 + u16::from(caught);
+    let width = 64.min(area.width);
+    let height = (rows + 8).min(area.height);
+    let popup = Rect::new(
+        area.x + (area.width - width) / 2,
+        area.y + (area.height - height) / 2,
+        width,
+        height,
+    );
+    let block = Block::default()
+        .title(format!(" Run over on floor {} ", state.floor_index + 1))
+        .borders(Borders::ALL)
+        .style(Style::default().bg(BG).fg(ACCENT));
+    let inner = block.inner(popup);
+    frame.render_widget(Clear, popup);
+    frame.render_widget(block, popup);
+
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!("{} light burned. Where it went:", state.drain.total()),
+            Style::default().fg(MUTED),
+        )),
+        Line::default(),
+    ];
+    if shares.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "  Nothing; the lantern was still full.",
+            Style::default().fg(MUTED),
+        )));
+    }
+    for share in shares.iter().take(TOP_CAUSES) {
+        let filled = (share.percent * BAR_WIDTH).div_ceil(100) as usize;
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {:<16}", share.cause.label()),
+                Style::default().fg(FG),
+            ),
+            Span::styled("█".repeat(filled), Style::default().fg(ACCENT)),
+            Span::styled(
+                "░".repeat(BAR_WIDTH as usize - filled),
+                Style::default().fg(MUTED),
+            ),
+            Span::styled(
+                format!(" {:>3}%  ({})", share.percent, share.light),
+                Style::default().fg(FG),
+            ),
+        ]));
+    }
+    if caught {
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("  {:<16}", "Hunter contact"),
+                Style::default().fg(FG),
+            ),
+            Span::styled("caught you in the dark", Style::default().fg(DANGER_HUNTER)),
+        ]));
+    }
+    let hint = if caught {
+        Some(drain::HUNTER_HINT)
+    } else {
+        shares.first().map(|share| share.cause.hint())
+    };
+    if let Some(hint) = hint {
+        lines.push(Line::default());
+        lines.push(Line::from(Span::styled(hint, Style::default().fg(FG))));
+    }
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: false }), inner);
+}
+
+fn render_log(frame: &mut Frame, area: Rect, state: &AppState) {
+    let log = &state.log;
+    let title = if log.scroll > 0 {
+        format!(" Log  -{} ", log.scroll)
+    } else {
+        " Log ".to_string()
+    };
+    let block = Block::default()
+        .title(title)
+        .borders(Borders::TOP)
+        .style(Style::default().bg(BG).fg(MUTED));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    let end = log.entries.len().saturating_sub(log.scroll);
+    let start = end.saturating_sub(inner.height as usize);
+    let lines: Vec<Line> = log.entries[start..end]
+        .iter()
+        .enumerate()
+        .map(|(i, entry)| {
+            let newest = log.scroll == 0 && start + i + 1 == end;
+            let text_style = if newest {
+                Style::default().fg(FG)
+            } else {
+                Style::default().fg(MUTED)
+            };
+            Line::from(vec![
+                Span::styled(format!("{:>5} ", entry.step), Style::default().fg(MUTED)),
+                Span::styled(entry.text.as_str(), text_style),
+            ])
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+fn pack_line(state: &AppState) -> Line<'_> {
+    let mut spans = vec![Span::styled("Pack ", Style::default().fg(MUTED))];
+    if state.player.pack.is_empty() {
+        spans.push(Span::styled("empty", Style::default().fg(MUTED)));
+    }
+    for (slot, item) in state.player.pack.iter().enumerate() {
+        let color = if item.curse_known && item.curse.is_some() {
+            CURSED
+        } else if state.identified.contains(&item.kind) {
+            FG
+        } else {
+            MUTED
+        };
+        spans.push(Span::styled(
+            format!("{} ", slot + 1),
+            Style::default().fg(ACCENT),
+        ));
+        spans.push(Span::styled(
+            format!("{}  ", items::display_name(state, item)),
+            Style::default().fg(color),
+        ));
+    }
+    Line::from(spans)
 }
 
 fn controls_line(mode: GameMode) -> &'static str {
     match mode {
         GameMode::Exploration => {
-            "Move: WASD/arrows  Reclaim trail: Shift+move  Interact: E  Pause: Esc  Quit: Q"
+            "Move: WASD/arrows  Reclaim trail: Shift+move  Interact/search: E  Use item: 1-5  Hood: H  Log: PgUp/PgDn  Snapshot: P  Pause: Esc  Quit: Q"
         }
         GameMode::Pause => "Paused: Esc to resume  Quit: Q",
+        GameMode::ChooseModifier => "Choose a modifier: 1-3  Quit: Q",
         GameMode::GameOver => "Game Over: R restart  Quit: Q",
         GameMode::Boot => "Generating floor...",
     }
@@ -221,17 +533,126 @@ fn render_map(frame: &mut Frame, area: Rect, state: &AppState) {
     let buf = frame.buffer_mut();
     apply_light_field_to_buffer(buf, render, &state.map, &light_field);
     render_trail_overlay(buf, render, state);
+    if state.dev_overlay {
+        draw_danger_scores(buf, render, state);
+    }
 
     for anchor in &state.anchors {
         if anchor.x == state.player.x && anchor.y == state.player.y {
             continue;
         }
+        // Secrets stay hidden; the dev overlay shows them as `?`.
+        let concealed = secrets::concealed(state, anchor);
+        if concealed && !state.dev_overlay {
+            continue;
+        }
         if let Some((ch, fg)) = anchor_marker(anchor.kind) {
+            let ch = if concealed { '?' } else { ch };
             draw_marker(buf, anchor.x, anchor.y, render, ch, fg);
         }
     }
 
-    draw_player_bulb(buf, render, state);
+    if let Some(stalker) = &state.hunter {
+        draw_hunter(buf, render, stalker, &light_field);
+    }
+
+    if let Some(seat) = &state.hotseat {
+        draw_player_bulb(buf, render, &seat.partner, '○');
+    }
+    draw_player_bulb(buf, render, &state.player, '●');
+    if state.dev_overlay {
+        draw_room_labels(buf, area, render, state);
+    }
+}
+
+/// Each open tile's danger score, green when safe through to red.
+fn draw_danger_scores(buf: &mut Buffer, render: MapRenderResult, state: &AppState) {
+    let scores = tile_scores(&state.map, state.exit_pos());
+    for row in 0..render.view_tiles_v {
+        for col in 0..render.view_tiles_h {
+            let map_x = render.start_x + col;
+            let map_y = render.start_y + row;
+            if map_x >= state.map.width || map_y >= state.map.height {
+                continue;
+            }
+            let idx = map_y as usize * state.map.width as usize + map_x as usize;
+            let Some(score) = scores[idx] else {
+                continue;
+            };
+            let heat = score as f32 / MAX_SCORE as f32;
+            let color = Color::Rgb(
+                (110.0 + heat * 140.0) as u8,
+                (210.0 - heat * 150.0) as u8,
+                96,
+            );
+            draw_marker(buf, map_x, map_y, render, char::from(b'0' + score), color);
+        }
+    }
+}
+
+/// Room labels from procgen, written from each room's top-left tile.
+fn draw_room_labels(buf: &mut Buffer, area: Rect, render: MapRenderResult, state: &AppState) {
+    for room in &state.report.rooms {
+        let Some((x, y)) = render.tile_cell_origin(room.x, room.y) else {
+            continue;
+        };
+        for (offset, ch) in room.label.chars().enumerate() {
+            let cell_x = x + offset as u16;
+            if cell_x >= area.right() {
+                break;
+            }
+            if let Some(cell) = buf.cell_mut((cell_x, y)) {
+                cell.set_char(ch).set_fg(ACCENT).set_bg(BG);
+            }
+        }
+    }
+}
+
+/// Seeds, fingerprint and generation timings in the map's top-right corner.
+fn render_dev_panel(frame: &mut Frame, area: Rect, state: &AppState) {
+    let report = &state.report;
+    let fingerprint: String = report.fingerprint.chars().take(16).collect();
+    let mut lines = vec![
+        Line::from(Span::styled(
+            format!("Run seed   {:#x}", state.seed),
+            Style::default().fg(FG),
+        )),
+        Line::from(Span::styled(
+            format!("Floor seed {:#x}", report.seed),
+            Style::default().fg(FG),
+        )),
+        Line::from(Span::styled(
+            format!("{}  {fingerprint}", report.generator),
+            Style::default().fg(MUTED),
+        )),
+        Line::from(Span::styled(
+            format!("{} rooms", report.rooms.len()),
+            Style::default().fg(MUTED),
+        )),
+    ];
+    for timing in &report.timings {
+        lines.push(Line::from(Span::styled(
+            format!("{:<12}{:>8} us", timing.phase, timing.micros),
+            Style::default().fg(MUTED),
+        )));
+    }
+    let total: u64 = report.timings.iter().map(|timing| timing.micros).sum();
+    lines.push(Line::from(Span::styled(
+        format!("{:<12}{total:>8} us", "total"),
+        Style::default().fg(ACCENT),
+    )));
+
+    let width = DEV_PANEL_WIDTH.min(area.width);
+    let height = (lines.len() as u16 + 2).min(area.height);
+    let panel = Rect::new(area.right() - width, area.y, width, height);
+    let block = Block::default()
+        .title(" Dev ")
+        .borders(Borders::ALL)
+        .style(Style::default().bg(BG).fg(MUTED));
+    let inner = block.inner(panel);
+    frame.render_widget(Clear, panel);
+    frame.render_widget(block, panel);
+    frame.render_widget(Paragraph::new(lines), inner);
 }
 
 fn build_light_sources(render: MapRenderResult, state: &AppState) -> Vec<LightSource> {
@@ -240,9 +661,23 @@ fn build_light_sources(render: MapRenderResult, state: &AppState) -> Vec<LightSo
         x: state.player.x,
         y: state.player.y,
         intensity: 1.0,
-        range: player_light_range(state.player.light_current),
+        range: player_light_range(state.player.light_current, state.player.hooded),
         core_radius: 1,
     });
+    if let Some(partner) = state
+        .hotseat
+        .as_ref()
+        .map(|seat| &seat.partner)
+        .filter(|partner| partner.light_current > 0)
+    {
+        sources.push(LightSource {
+            x: partner.x,
+            y: partner.y,
+            intensity: 1.0,
+            range: player_light_range(partner.light_current, partner.hooded),
+            core_radius: 1,
+        });
+    }
 
     for row in 0..render.view_tiles_v {
         for col in 0..render.view_tiles_h {
@@ -268,11 +703,6 @@ fn build_light_sources(render: MapRenderResult, state: &AppState) -> Vec<LightSo
     sources
 }
 
-fn player_light_range(light_current: u16) -> u16 {
-    (PLAYER_LIGHT_RANGE_BASE + light_current / PLAYER_LIGHT_RANGE_DIVISOR)
-        .clamp(PLAYER_LIGHT_RANGE_MIN, PLAYER_LIGHT_RANGE_MAX)
-}
-
 fn render_trail_overlay(buf: &mut Buffer, render: MapRenderResult, state: &AppState) {
     for row in 0..render.view_tiles_v {
         for col in 0..render.view_tiles_h {
@@ -314,11 +744,18 @@ fn render_trail_overlay(buf: &mut Buffer, render: MapRenderResult, state: &AppSt
     }
 }
 
-fn draw_player_bulb(buf: &mut Buffer, render: MapRenderResult, state: &AppState) {
-    let ratio = if state.player.light_max == 0 {
+/// An explorer's lantern, sized and tinted by how much light it has left.
+/// `filament` marks whose turn it is in a hotseat run.
+fn draw_player_bulb(
+    buf: &mut Buffer,
+    render: MapRenderResult,
+    player: &PlayerState,
+    filament: char,
+) {
+    let ratio = if player.light_max == 0 {
         0.0
     } else {
-        (state.player.light_current as f32 / state.player.light_max as f32).clamp(0.0, 1.0)
+        (player.light_current as f32 / player.light_max as f32).clamp(0.0, 1.0)
     };
     let Some(radius) = player_bulb_radius(ratio) else {
         return;
@@ -331,8 +768,8 @@ fn draw_player_bulb(buf: &mut Buffer, render: MapRenderResult, state: &AppState)
 
     draw_blob_in_tile(
         buf,
-        state.player.x,
-        state.player.y,
+        player.x,
+        player.y,
         render,
         radius,
         core_radius,
@@ -345,10 +782,10 @@ fn draw_player_bulb(buf: &mut Buffer, render: MapRenderResult, state: &AppState)
     // Small center filament to keep player orientation/visibility crisp at low light.
     draw_marker(
         buf,
-        state.player.x,
-        state.player.y,
+        player.x,
+        player.y,
         render,
-        '●',
+        filament,
         if ratio > 0.12 { core_color } else { dim_color },
     );
 }
@@ -438,6 +875,41 @@ fn draw_blob_in_tile(
     }
 }
 
+/// The hunter, faded by how much light falls on it, with its awareness shown
+/// in the cell above: `?` when suspicious, `!` when hunting.
+fn draw_hunter(
+    buf: &mut Buffer,
+    render: MapRenderResult,
+    stalker: &HunterState,
+    light: &LightField,
+) {
+    let Some((cell_x, cell_y)) = render.marker_cell(stalker.x, stalker.y) else {
+        return;
+    };
+    let lit = light
+        .brightness_at(stalker.x, stalker.y)
+        .max(HUNTER_MIN_VISIBLE);
+    if let Some(cell) = buf.cell_mut((cell_x, cell_y)) {
+        cell.set_char('H').set_fg(scale_color(DANGER_HUNTER, lit));
+    }
+
+    let indicator = match stalker.awareness {
+        Awareness::Unaware => return,
+        Awareness::Suspicious => ('?', ACCENT),
+        Awareness::Hunting => ('!', DANGER_HUNTER),
+    };
+    if cell_y <= render.origin_y {
+        return;
+    }
+    if let Some(cell) = buf.cell_mut((cell_x, cell_y - 1)) {
+        cell.set_char(indicator.0).set_style(
+            Style::default()
+                .fg(indicator.1)
+                .add_modifier(Modifier::BOLD),
+        );
+    }
+}
+
 fn anchor_marker(kind: RuntimeAnchorKind) -> Option<(char, Color)> {
     Some(match kind {
         RuntimeAnchorKind::PlayerStart => ('S', Color::Rgb(150, 180, 220)),
@@ -445,6 +917,8 @@ fn anchor_marker(kind: RuntimeAnchorKind) -> Option<(char, Color)> {
         RuntimeAnchorKind::Beacon => ('B', Color::Rgb(240, 188, 126)),
         RuntimeAnchorKind::Relic => ('*', Color::Rgb(198, 142, 224)),
         RuntimeAnchorKind::Switch => ('=', Color::Rgb(138, 188, 154)),
+        RuntimeAnchorKind::SecretWall => (':', Color::Rgb(168, 150, 128)),
+        RuntimeAnchorKind::Cache => ('$', Color::Rgb(236, 204, 96)),
     })
 }
 
@@ -1,9 +1,11 @@
+use std::collections::{HashMap, HashSet};
+
 use serde::{Deserialize, Serialize};
+use sprite_cache::SpriteData;
 
-use crate::sprite::SpriteData;
 use crate::state::{
-    AbilityDetail, EncounterLocation, EvolutionChain, FocusArea, MoveDetail, PokemonDetail,
-    PokemonSpecies, PokedexEntry, RegionInfo, TypeMatchup,
+    AbilityDetail, EncounterLocation, EvolutionChain, FocusArea, ItemDetail, LocationEncounter,
+    MoveDetail, PokemonDetail, PokemonSpecies, PokemonStat, PokedexEntry, RegionInfo, TypeMatchup,
 };
 
 #[derive(tui_dispatch::Action, Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -12,14 +14,25 @@ pub enum Action {
     Init,
     PokedexDidLoad(Vec<PokedexEntry>),
     PokedexDidError(String),
+    PokedexPageDidLoad { offset: usize, entries: Vec<PokedexEntry>, total: usize },
+    PokedexPageDidError { offset: usize, error: String },
 
     SpeciesIndexDidLoad(Vec<PokemonSpecies>),
     SpeciesIndexDidError(String),
+    BaseStatsDidLoad(HashMap<String, Vec<PokemonStat>>),
+    BaseStatsDidError(String),
 
     RegionsDidLoad(Vec<RegionInfo>),
     RegionsDidError(String),
-    RegionNext,
-    RegionPrev,
+    RegionPickerOpen,
+    RegionPickerClose,
+    RegionPickerInput(char),
+    RegionPickerBackspace,
+    RegionPickerMove(i16),
+    RegionPickerSelect(usize),
+    RegionPickerConfirm,
+    RegionDexDidLoad { name: String, species: Vec<String> },
+    RegionDexDidError { name: String, error: String },
 
     FocusNext,
     FocusPrev,
@@ -30,6 +43,22 @@ pub enum Action {
     TypeFilterNext,
     TypeFilterPrev,
     TypeFilterClear,
+    GenerationFilterNext,
+    GenerationFilterPrev,
+    SortOpen,
+    SortClose,
+    SortMove(i16),
+    SortConfirm,
+    HelpOpen,
+    HelpClose,
+    HelpScroll(i16),
+    CalcOpen,
+    CalcClose,
+    CalcPaneToggle,
+    CalcInput(char),
+    CalcBackspace,
+    CalcMove(i16),
+    CalcConfirm,
     TypeFilterDidLoad { name: String, pokemon: Vec<String> },
     TypeFilterDidError { name: String, error: String },
 
@@ -37,6 +66,8 @@ pub enum Action {
     SelectionPage(i16),
     SelectionJumpTop,
     SelectionJumpBottom,
+    FamilyViewToggle,
+    FamilyCollapseToggle,
     DexSelect(usize),
 
     SearchStart,
@@ -54,8 +85,20 @@ pub enum Action {
     EvolutionSelect(usize),
     SpriteDidLoad { name: String, sprite: SpriteData },
     SpriteDidError { name: String, error: String },
+    PrefetchDidLoad { detail: PokemonDetail, sprite: Option<SpriteData> },
+    PrefetchDidError { name: String, error: String },
+    FeaturedDidLoad {
+        day: u64,
+        species: PokemonSpecies,
+        detail: PokemonDetail,
+        sprite: Option<SpriteData>,
+    },
+    FeaturedDidError(String),
+    FeaturedClose,
+    FeaturedJump,
     MoveDetailDidLoad(MoveDetail),
     MoveDetailDidError { name: String, error: String },
+    MovePrefetchDidError { name: String, error: String },
     AbilityDetailDidLoad(AbilityDetail),
     AbilityDetailDidError { name: String, error: String },
     EncounterDidLoad { name: String, encounters: Vec<EncounterLocation> },
@@ -73,12 +116,97 @@ pub enum Action {
     EncounterSelect(usize),
     EncounterFilterNext,
     EncounterFilterPrev,
+    MoveSortNext,
+    MoveTypeFilterNext,
+    MoveTypeFilterPrev,
+    MatchupViewToggle,
+    StatsRadarToggle,
+    /// Flavor text and genus switch to this language code.
+    LanguageChanged(String),
 
     ToggleFavorite,
     ToggleTeam,
+    TeamOpen,
+    TeamClose,
+    TeamSelect(usize),
+    TeamReorder(i16),
+    TeamRemove,
+    BookmarkToggle,
+    BookmarksOpen,
+    BookmarksClose,
+    BookmarksMove(i16),
+    BookmarkShow(String),
+    CompareWith(String),
+    CompareClose,
+    NotesDidLoad(HashMap<String, String>),
+    NotesDidSave,
+    NotesDidError(String),
+    NotesLoadDidError(String),
+    ExportOpen,
+    ExportClose,
+    ExportMove(i16),
+    ExportConfirm,
+    ExportDidWrite(String),
+    ExportDidError(String),
+    ReportOpen,
+    ReportClose,
+    ReportInput(char),
+    ReportBackspace,
+    ReportConfirm,
+    ReportDidWrite(String),
+    ReportDidError(String),
+    ClipboardCopy,
+    ClipboardDidCopy(String),
+    ClipboardDidError(String),
+    UserDataDidLoad {
+        favorites: HashSet<String>,
+        team: Vec<String>,
+        bookmarks: Vec<String>,
+    },
+    UserDataDidSave,
+    UserDataDidError(String),
+    NoteEditOpen,
+    NoteEditCancel,
+    NoteEditSave,
+    NoteEditInput(char),
+    NoteEditNewline,
+    NoteEditBackspace,
+    NoteEditDelete,
+    NoteEditMove { rows: i16, cols: i16 },
+    NoteEditHome,
+    NoteEditEnd,
     PlayCry,
+    CryLevel(f32),
+    CryDidFinish,
     CryDidError(String),
 
+    ItemsOpen,
+    ItemsClose,
+    ItemListDidLoad(Vec<String>),
+    ItemListDidError(String),
+    ItemDetailDidLoad(ItemDetail),
+    ItemDetailDidError { name: String, error: String },
+    ItemSelect(usize),
+    ItemMove(i16),
+    ItemSearchStart,
+    ItemSearchInput(char),
+    ItemSearchBackspace,
+    ItemSearchSubmit,
+    ItemSearchCancel,
+
+    LocationsOpen,
+    LocationsClose,
+    LocationsDidLoad { region: String, names: Vec<String> },
+    LocationsDidError(String),
+    LocationEncountersDidLoad { name: String, encounters: Vec<LocationEncounter> },
+    LocationEncountersDidError { name: String, error: String },
+    LocationSelect(usize),
+    LocationMove(i16),
+    LocationPaneToggle,
+    LocationPokemonSelect(usize),
+    LocationPokemonMove(i16),
+    LocationShowPokemon,
+
     UiTerminalResize(u16, u16),
     Tick,
     Quit,
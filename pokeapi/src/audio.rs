@@ -1,11 +1,101 @@
 use std::io::Cursor;
+use std::time::Duration;
 
-pub fn play_ogg(bytes: Vec<u8>) -> Result<(), String> {
+use rodio::Source;
+
+/// Amplitude readings reported per second of audio.
+const LEVELS_PER_SECOND: u32 = 40;
+
+/// Plays an OGG clip to the end, calling `on_level` with the RMS amplitude
+/// (0.0 to 1.0) of each short window of samples as the mixer pulls it.
+///
+/// Blocks until playback finishes; the callback runs on rodio's audio thread,
+/// so it should only hand the level off.
+pub fn play_ogg(bytes: Vec<u8>, on_level: impl FnMut(f32) + Send + 'static) -> Result<(), String> {
     let cursor = Cursor::new(bytes);
     let (_stream, handle) = rodio::OutputStream::try_default().map_err(|err| err.to_string())?;
     let sink = rodio::Sink::try_new(&handle).map_err(|err| err.to_string())?;
     let source = rodio::Decoder::new(cursor).map_err(|err| err.to_string())?;
-    sink.append(source);
+    sink.append(LevelMeter::new(source, on_level));
     sink.sleep_until_end();
     Ok(())
 }
+
+/// Pass-through source that reports the loudness of what flows through it.
+struct LevelMeter<S, F> {
+    source: S,
+    on_level: F,
+    window: usize,
+    sum_squares: f32,
+    count: usize,
+}
+
+impl<S, F> LevelMeter<S, F>
+where
+    S: Source<Item = i16>,
+    F: FnMut(f32),
+{
+    fn new(source: S, on_level: F) -> Self {
+        let samples_per_second = source.sample_rate() * u32::from(source.channels());
+        Self {
+            window: (samples_per_second / LEVELS_PER_SECOND).max(1) as usize,
+            source,
+            on_level,
+            sum_squares: 0.0,
+            count: 0,
+        }
+    }
+
+    fn flush(&mut self) {
+        let level = (self.sum_squares / self.count as f32).sqrt().min(1.0);
+        (self.on_level)(level);
+        self.sum_squares = 0.0;
+        self.count = 0;
+    }
+}
+
+impl<S, F> Iterator for LevelMeter<S, F>
+where
+    S: Source<Item = i16>,
+    F: FnMut(f32),
+{
+    type Item = i16;
+
+    fn next(&mut self) -> Option<i16> {
+        let Some(sample) = self.source.next() else {
+            if self.count > 0 {
+                self.flush();
+            }
+            return None;
+        };
+        let value = f32::from(sample) / f32::from(i16::MAX);
+        self.sum_squares += value * value;
+        self.count += 1;
+        if self.count >= self.window {
+            self.flush();
+        }
+        Some(sample)
+    }
+}
+
+impl<S, F> Source for LevelMeter<S, F>
+where
+    S: Source<Item = i16>,
+    F: FnMut(f32),
+{
+    fn current_frame_len(&self) -> Option<usize> {
+        self.source.current_frame_len()
+    }
+
+    fn channels(&self) -> u16 {
+        self.source.channels()
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.source.sample_rate()
+    }
+
+    fn total_duration(&self) -> Option<Duration> {
+        self.source.total_duration()
+    }
+}
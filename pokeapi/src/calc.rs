@@ -0,0 +1,264 @@
+//! Damage calculator.
+//!
+//! Both sides are level 50 with 31 IVs, no EVs and a neutral nature, so the
+//! numbers come from base stats alone. Damage follows the formula used since
+//! generation V: base damage, a random roll from 85% to 100%, then STAB and
+//! type effectiveness, each step rounded down. Critical hits, abilities, items
+//! and weather are left out.
+
+use std::cmp::Reverse;
+
+use crate::fuzzy::fuzzy_match;
+use crate::state::{AppState, MoveSummary, PokemonDetail, TypeMatchup};
+
+pub const LEVEL: u16 = 50;
+const IV: u32 = 31;
+const ROLL_MIN: u32 = 85;
+const ROLL_MAX: u32 = 100;
+/// Defender candidates listed for a query, best matches first.
+pub const DEFENDER_LIMIT: usize = 50;
+
+/// Damage from one hit at the lowest and highest random roll.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct DamageRange {
+    pub min: u32,
+    pub max: u32,
+    pub defender_hp: u16,
+    pub effectiveness: f32,
+    pub stab: bool,
+}
+
+impl DamageRange {
+    /// `damage` as a share of the defender's HP, in percent.
+    pub fn percent(&self, damage: u32) -> f32 {
+        damage as f32 * 100.0 / f32::from(self.defender_hp.max(1))
+    }
+
+    /// Hits to knock out the defender at the highest and lowest roll, or `None`
+    /// when the move can't hurt it.
+    pub fn hits_to_ko(&self) -> Option<(u32, u32)> {
+        if self.min == 0 {
+            return None;
+        }
+        let hp = u32::from(self.defender_hp);
+        Some((hp.div_ceil(self.max), hp.div_ceil(self.min)))
+    }
+}
+
+/// A non-HP stat at `level`.
+pub fn stat(base: u16, level: u16) -> u16 {
+    ((2 * u32::from(base) + IV) * u32::from(level) / 100 + 5) as u16
+}
+
+pub fn hp(base: u16, level: u16) -> u16 {
+    ((2 * u32::from(base) + IV) * u32::from(level) / 100 + u32::from(level) + 10) as u16
+}
+
+/// Damage before the random roll and modifiers.
+pub fn base_damage(level: u16, power: u16, attack: u16, defense: u16) -> u32 {
+    let level_factor = 2 * u32::from(level) / 5 + 2;
+    level_factor * u32::from(power) * u32::from(attack) / u32::from(defense.max(1)) / 50 + 2
+}
+
+fn modified(base: u32, roll: u32, stab: bool, effectiveness: f32) -> u32 {
+    let mut damage = base * roll / 100;
+    if stab {
+        damage = damage * 3 / 2;
+    }
+    if effectiveness == 0.0 {
+        return 0;
+    }
+    ((damage as f32 * effectiveness).floor() as u32).max(1)
+}
+
+pub fn damage_range(
+    level: u16,
+    power: u16,
+    attack: u16,
+    defense: u16,
+    defender_hp: u16,
+    stab: bool,
+    effectiveness: f32,
+) -> DamageRange {
+    let base = base_damage(level, power, attack, defense);
+    DamageRange {
+        min: modified(base, ROLL_MIN, stab, effectiveness),
+        max: modified(base, ROLL_MAX, stab, effectiveness),
+        defender_hp,
+        effectiveness,
+        stab,
+    }
+}
+
+/// Multiplier of an attacking type's matchup against a defender's types.
+pub fn effectiveness(matchup: &TypeMatchup, defender_types: &[String]) -> f32 {
+    defender_types
+        .iter()
+        .map(|type_name| {
+            if matchup.no_to.contains(type_name) {
+                0.0
+            } else if matchup.double_to.contains(type_name) {
+                2.0
+            } else if matchup.half_to.contains(type_name) {
+                0.5
+            } else {
+                1.0
+            }
+        })
+        .product()
+}
+
+/// Moves the calculator offers for `detail`: anything with base power that
+/// isn't a status move, strongest first.
+pub fn damaging_moves(detail: &PokemonDetail) -> Vec<&MoveSummary> {
+    let mut moves: Vec<&MoveSummary> = detail
+        .moves
+        .iter()
+        .filter(|summary| {
+            summary.power.is_some_and(|power| power > 0)
+                && summary.damage_class.as_deref() != Some("status")
+        })
+        .collect();
+    moves.sort_by(|a, b| {
+        Reverse(a.power)
+            .cmp(&Reverse(b.power))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    moves
+}
+
+/// Pokedex names matching the defender query, best first.
+pub fn defender_matches(state: &AppState) -> Vec<&str> {
+    let query = state.calc.query.trim().to_lowercase();
+    let mut ranked: Vec<(&str, i32)> = state
+        .pokedex
+        .iter()
+        .filter_map(|entry| {
+            if query.is_empty() {
+                return Some((entry.name.as_str(), 0));
+            }
+            fuzzy_match(&query, &entry.name).map(|(score, _)| (entry.name.as_str(), score))
+        })
+        .collect();
+    ranked.sort_by_key(|(_, score)| Reverse(*score));
+    ranked
+        .into_iter()
+        .take(DEFENDER_LIMIT)
+        .map(|(name, _)| name)
+        .collect()
+}
+
+/// The move picked in the calculator, from the current Pokemon's learnset.
+pub fn selected_move(state: &AppState) -> Option<&MoveSummary> {
+    let detail = state.current_detail()?;
+    damaging_moves(detail)
+        .get(state.calc.move_selected)
+        .copied()
+}
+
+/// Damage for the current Pokemon's selected move against the chosen
+/// defender, or what's still missing.
+pub fn calculate(state: &AppState) -> Result<DamageRange, String> {
+    let attacker = state
+        .current_detail()
+        .ok_or_else(|| "Loading attacker...".to_string())?;
+    let summary = selected_move(state).ok_or_else(|| "No damaging moves.".to_string())?;
+    let defender_name = state
+        .calc
+        .defender
+        .as_deref()
+        .ok_or_else(|| "Pick a defender.".to_string())?;
+    let defender = state
+        .details
+        .get(defender_name)
+        .ok_or_else(|| format!("Loading {defender_name}..."))?;
+    let move_type = summary
+        .move_type
+        .as_deref()
+        .ok_or_else(|| format!("No type known for {}.", summary.name))?;
+    let matchup = state
+        .type_matchup_cache
+        .get(move_type)
+        .ok_or_else(|| format!("Loading {move_type} matchups..."))?;
+
+    let special = summary.damage_class.as_deref() == Some("special");
+    let (attack_stat, defense_stat) = if special {
+        ("special-attack", "special-defense")
+    } else {
+        ("attack", "defense")
+    };
+    let base = |detail: &PokemonDetail, name: &str| {
+        detail
+            .stats
+            .iter()
+            .find(|stat| stat.name == name)
+            .map_or(0, |stat| stat.value)
+    };
+    Ok(damage_range(
+        LEVEL,
+        summary.power.unwrap_or(0),
+        stat(base(attacker, attack_stat), LEVEL),
+        stat(base(defender, defense_stat), LEVEL),
+        hp(base(defender, "hp"), LEVEL),
+        attacker
+            .types
+            .iter()
+            .any(|type_name| type_name == move_type),
+        effectiveness(matchup, &defender.types),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn types(names: &[&str]) -> Vec<String> {
+        names.iter().map(|name| name.to_string()).collect()
+    }
+
+    #[test]
+    fn level_50_stats_from_base() {
+        // Pikachu: 35 HP, 55 Attack.
+        assert_eq!(hp(35, LEVEL), 110);
+        assert_eq!(stat(55, LEVEL), 75);
+        assert_eq!(stat(100, 100), 236);
+    }
+
+    #[test]
+    fn damage_follows_the_modern_formula() {
+        assert_eq!(base_damage(50, 90, 120, 100), 49);
+
+        let neutral = damage_range(50, 90, 120, 100, 150, false, 1.0);
+        assert_eq!((neutral.min, neutral.max), (41, 49));
+
+        // STAB is applied before effectiveness, each rounded down.
+        let boosted = damage_range(50, 90, 120, 100, 150, true, 2.0);
+        assert_eq!((boosted.min, boosted.max), (122, 146));
+        assert_eq!(boosted.hits_to_ko(), Some((2, 2)));
+
+        let immune = damage_range(50, 90, 120, 100, 150, true, 0.0);
+        assert_eq!((immune.min, immune.max), (0, 0));
+        assert_eq!(immune.hits_to_ko(), None);
+
+        // Anything that connects does at least 1.
+        let feeble = damage_range(50, 10, 10, 250, 300, false, 0.25);
+        assert_eq!(feeble.min, 1);
+    }
+
+    #[test]
+    fn effectiveness_multiplies_across_types() {
+        let electric = TypeMatchup {
+            name: "electric".to_string(),
+            double_from: Vec::new(),
+            half_from: Vec::new(),
+            no_from: Vec::new(),
+            double_to: types(&["water", "flying"]),
+            half_to: types(&["grass", "electric", "dragon"]),
+            no_to: types(&["ground"]),
+        };
+        assert_eq!(effectiveness(&electric, &types(&["water", "flying"])), 4.0);
+        assert_eq!(effectiveness(&electric, &types(&["grass", "dragon"])), 0.25);
+        assert_eq!(effectiveness(&electric, &types(&["water", "ground"])), 0.0);
+        assert_eq!(effectiveness(&electric, &types(&["normal"])), 1.0);
+    }
+}
@@ -0,0 +1,184 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::state::AppState;
+
+/// How many team members an attacking type hits for more, or less, than neutral.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct TypeExposure {
+    pub attacker: String,
+    pub weak: usize,
+    pub resist: usize,
+    pub immune: usize,
+}
+
+impl TypeExposure {
+    /// Weak members minus members that take reduced or no damage.
+    pub fn net(&self) -> i32 {
+        self.weak as i32 - (self.resist + self.immune) as i32
+    }
+}
+
+/// Offensive and defensive type coverage for the loaded members of `state.team`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct TeamCoverage {
+    pub members: Vec<String>,
+    /// Best multiplier any member's own types reach against each defending type.
+    pub offense: Vec<(String, f32)>,
+    pub defense: Vec<TypeExposure>,
+}
+
+impl TeamCoverage {
+    /// Defending types no member hits super effectively.
+    pub fn gaps(&self) -> Vec<&str> {
+        self.offense
+            .iter()
+            .filter(|(_, best)| *best < 2.0)
+            .map(|(name, _)| name.as_str())
+            .collect()
+    }
+
+    pub fn covered(&self) -> usize {
+        self.offense.len() - self.gaps().len()
+    }
+
+    /// Attacking types the team is weak to overall, worst first.
+    pub fn weaknesses(&self) -> Vec<&TypeExposure> {
+        let mut exposed: Vec<&TypeExposure> = self
+            .defense
+            .iter()
+            .filter(|exposure| exposure.weak > 0 && exposure.net() > 0)
+            .collect();
+        exposed.sort_by(|a, b| {
+            b.net()
+                .cmp(&a.net())
+                .then_with(|| b.weak.cmp(&a.weak))
+                .then_with(|| a.attacker.cmp(&b.attacker))
+        });
+        exposed
+    }
+
+    /// Weaknesses shared by at least two members that the rest of the team can't cover.
+    pub fn shared_weaknesses(&self) -> Vec<&TypeExposure> {
+        self.weaknesses()
+            .into_iter()
+            .filter(|exposure| exposure.weak >= 2)
+            .collect()
+    }
+}
+
+/// Coverage for every team member whose detail has loaded.
+///
+/// `None` until the type list and the matchups for all of those members' types are cached.
+pub fn team_coverage(state: &AppState) -> Option<TeamCoverage> {
+    let mut coverage = TeamCoverage::default();
+    let mut best_offense: HashMap<String, f32> = HashMap::new();
+    let mut exposure: HashMap<String, TypeExposure> = HashMap::new();
+    for name in &state.team {
+        let Some(detail) = state.details.get(name) else {
+            continue;
+        };
+        let defense = defense_multipliers(state, &detail.types)?;
+        let offense = offense_multipliers(state, &detail.types)?;
+        for (type_name, value) in offense {
+            let entry = best_offense.entry(type_name).or_insert(0.0);
+            *entry = entry.max(value);
+        }
+        for (type_name, value) in defense {
+            let entry = exposure
+                .entry(type_name.clone())
+                .or_insert_with(|| TypeExposure {
+                    attacker: type_name,
+                    weak: 0,
+                    resist: 0,
+                    immune: 0,
+                });
+            if value == 0.0 {
+                entry.immune += 1;
+            } else if value > 1.0 {
+                entry.weak += 1;
+            } else if value < 1.0 {
+                entry.resist += 1;
+            }
+        }
+        coverage.members.push(name.clone());
+    }
+    if coverage.members.is_empty() {
+        return None;
+    }
+
+    for type_name in &state.type_list {
+        let best = best_offense.get(type_name).copied().unwrap_or(1.0);
+        coverage.offense.push((type_name.clone(), best));
+        if let Some(entry) = exposure.remove(type_name) {
+            coverage.defense.push(entry);
+        }
+    }
+    Some(coverage)
+}
+
+pub fn defense_multipliers(state: &AppState, types: &[String]) -> Option<HashMap<String, f32>> {
+    if state.type_list.is_empty() {
+        return None;
+    }
+    let mut multipliers: HashMap<String, f32> = state
+        .type_list
+        .iter()
+        .map(|name| (name.clone(), 1.0))
+        .collect();
+    for type_name in types {
+        let matchup = state.type_matchup_cache.get(type_name)?;
+        apply_multiplier(&mut multipliers, &matchup.double_from, 2.0);
+        apply_multiplier(&mut multipliers, &matchup.half_from, 0.5);
+        apply_immunity(&mut multipliers, &matchup.no_from);
+    }
+    Some(multipliers)
+}
+
+pub fn offense_multipliers(state: &AppState, types: &[String]) -> Option<HashMap<String, f32>> {
+    if state.type_list.is_empty() {
+        return None;
+    }
+    let mut multipliers: HashMap<String, f32> = state
+        .type_list
+        .iter()
+        .map(|name| (name.clone(), 1.0))
+        .collect();
+    for type_name in types {
+        let matchup = state.type_matchup_cache.get(type_name)?;
+        let mut type_map: HashMap<String, f32> = state
+            .type_list
+            .iter()
+            .map(|name| (name.clone(), 1.0))
+            .collect();
+        apply_multiplier(&mut type_map, &matchup.double_to, 2.0);
+        apply_multiplier(&mut type_map, &matchup.half_to, 0.5);
+        apply_immunity(&mut type_map, &matchup.no_to);
+        for (name, value) in type_map {
+            let entry = multipliers.entry(name).or_insert(1.0);
+            if value > *entry {
+                *entry = value;
+            }
+        }
+    }
+    Some(multipliers)
+}
+
+fn apply_multiplier(multipliers: &mut HashMap<String, f32>, types: &[String], factor: f32) {
+    for type_name in types {
+        if let Some(value) = multipliers.get_mut(type_name) {
+            if *value != 0.0 {
+                *value *= factor;
+            }
+        }
+    }
+}
+
+fn apply_immunity(multipliers: &mut HashMap<String, f32>, types: &[String]) {
+    for type_name in types {
+        if let Some(value) = multipliers.get_mut(type_name) {
+            *value = 0.0;
+        }
+    }
+}
@@ -1,17 +1,69 @@
+use std::collections::{HashMap, HashSet};
+
 #[derive(Clone, Debug, PartialEq)]
 pub enum Effect {
     LoadPokedex { name: String },
+    LoadPokedexPage { offset: usize, limit: usize },
     LoadRegions,
-    LoadSpeciesIndex { names: Vec<String> },
+    LoadRegionDex {
+        name: String,
+    },
+    LoadSpeciesIndex {
+        names: Vec<String>,
+        language: String,
+    },
+    LoadBaseStats {
+        names: Vec<String>,
+    },
     LoadTypes,
-    LoadTypeDetail { name: String },
-    LoadPokemonDetail { name: String },
-    LoadPokemonSpecies { name: String },
-    LoadEncounters { name: String },
-    LoadTypeMatchup { name: String },
-    LoadEvolutionChain { id: String, url: String },
-    LoadSprite { name: String, url: String },
+    LoadTypeDetail {
+        name: String,
+    },
+    LoadPokemonDetail {
+        name: String,
+    },
+    LoadPokemonSpecies {
+        name: String,
+        language: String,
+    },
+    LoadEncounters {
+        name: String,
+    },
+    LoadTypeMatchup {
+        name: String,
+    },
+    LoadEvolutionChain {
+        id: String,
+        url: String,
+    },
+    LoadSprite {
+        name: String,
+        url: String,
+    },
+    /// Detail and sprite for each of `names`, fetched a few at a time.
+    PrefetchPokemon {
+        names: Vec<String>,
+    },
+    /// Today's featured Pokemon: species, detail and sprite in one go.
+    LoadFeatured,
     PlayCry { name: String, url: String },
     LoadMoveDetail { name: String },
+    /// Details for a page of the Moves table, fetched a few at a time.
+    PrefetchMoves { names: Vec<String> },
     LoadAbilityDetail { name: String },
+    LoadItemList,
+    LoadItemDetail { name: String },
+    LoadLocations { region: String },
+    LoadLocationEncounters { name: String },
+    LoadNotes,
+    SaveNotes { notes: HashMap<String, String> },
+    WriteExport { file_name: String, contents: String },
+    WriteReport { path: String, contents: String },
+    CopyToClipboard { name: String, text: String },
+    LoadUserData,
+    SaveUserData {
+        favorites: HashSet<String>,
+        team: Vec<String>,
+        bookmarks: Vec<String>,
+    },
 }
@@ -0,0 +1,358 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+
+use crate::state::{MoveSummary, PokemonDetail, PokemonSpecies};
+
+/// Showdown sets carry at most this many moves.
+const SHOWDOWN_MOVES: usize = 4;
+
+/// Suffixes PokeAPI gives the default form of some species (`deoxys-normal`,
+/// `landorus-incarnate`). Showdown knows those forms by the species alone.
+const DEFAULT_FORMS: &[&str] = &[
+    "normal",
+    "incarnate",
+    "altered",
+    "land",
+    "standard",
+    "ordinary",
+    "aria",
+    "shield",
+    "average",
+    "50",
+    "baile",
+    "midday",
+    "solo",
+    "red-meteor",
+    "disguised",
+    "amped",
+    "single-strike",
+    "full-belly",
+    "male",
+    "plant",
+    "red-striped",
+    "ice",
+    "zero",
+    "family-of-four",
+    "green-plumage",
+    "curly",
+    "two-segment",
+];
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ExportFormat {
+    Markdown,
+    Showdown,
+}
+
+impl ExportFormat {
+    pub const ALL: [ExportFormat; 2] = [ExportFormat::Markdown, ExportFormat::Showdown];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ExportFormat::Markdown => "Markdown",
+            ExportFormat::Showdown => "Showdown set",
+        }
+    }
+
+    pub fn extension(self) -> &'static str {
+        match self {
+            ExportFormat::Markdown => "md",
+            ExportFormat::Showdown => "txt",
+        }
+    }
+}
+
+/// File name the export of `name` is written to, in the working directory.
+pub fn file_name(name: &str, format: ExportFormat) -> String {
+    format!("{name}.{}", format.extension())
+}
+
+pub fn render(
+    format: ExportFormat,
+    detail: &PokemonDetail,
+    species: Option<&PokemonSpecies>,
+) -> String {
+    match format {
+        ExportFormat::Markdown => markdown(detail, species),
+        ExportFormat::Showdown => showdown(detail, species),
+    }
+}
+
+fn markdown(detail: &PokemonDetail, species: Option<&PokemonSpecies>) -> String {
+    let mut out = format!("# {} (#{:03})\n\n", title(&detail.name), detail.id);
+    if let Some(genus) = species.and_then(|species| species.genus.as_ref()) {
+        out.push_str(&format!("*{genus}*\n\n"));
+    }
+    let types: Vec<String> = detail.types.iter().map(|name| title(name)).collect();
+    out.push_str(&format!("- **Type:** {}\n", types.join(" / ")));
+    out.push_str(&format!(
+        "- **Height:** {:.1} m\n- **Weight:** {:.1} kg\n",
+        detail.height as f32 / 10.0,
+        detail.weight as f32 / 10.0
+    ));
+    if let Some(text) = species.and_then(|species| species.flavor_text.as_ref()) {
+        let text: Vec<&str> = text.split_whitespace().collect();
+        out.push_str(&format!("\n> {}\n", text.join(" ")));
+    }
+
+    out.push_str("\n## Base stats\n\n| Stat | Value |\n| --- | ---: |\n");
+    for stat in &detail.stats {
+        out.push_str(&format!("| {} | {} |\n", title(&stat.name), stat.value));
+    }
+    let total: u16 = detail.stats.iter().map(|stat| stat.value).sum();
+    out.push_str(&format!("| **Total** | **{total}** |\n"));
+
+    out.push_str("\n## Abilities\n\n");
+    for ability in &detail.abilities {
+        out.push_str(&format!("- {}\n", title(ability)));
+    }
+
+    out.push_str("\n## Moves\n\n| Move | Type | Power | Accuracy | Learned |\n");
+    out.push_str("| --- | --- | ---: | ---: | --- |\n");
+    let number = |value: Option<u16>| value.map_or("-".to_string(), |value| value.to_string());
+    for summary in learnset(detail) {
+        out.push_str(&format!(
+            "| {} | {} | {} | {} | {} |\n",
+            title(&summary.name),
+            summary.move_type.as_deref().map(title).unwrap_or_default(),
+            number(summary.power),
+            number(summary.accuracy),
+            learned(summary)
+        ));
+    }
+    out
+}
+
+/// A few plain lines for pasting into chat: name, dex number, types and base
+/// stats.
+pub fn summary(detail: &PokemonDetail) -> String {
+    let types: Vec<String> = detail.types.iter().map(|name| title(name)).collect();
+    let mut stats: Vec<String> = detail
+        .stats
+        .iter()
+        .map(|stat| format!("{} {}", title(&stat.name), stat.value))
+        .collect();
+    let total: u16 = detail.stats.iter().map(|stat| stat.value).sum();
+    stats.push(format!("Total {total}"));
+    format!(
+        "{} (#{:03})\nType: {}\n{}\n",
+        title(&detail.name),
+        detail.id,
+        types.join(" / "),
+        stats.join(" | ")
+    )
+}
+
+/// Showdown team syntax: the species, its first ability and the last moves it
+/// learns by level, which is what a freshly caught one would know.
+fn showdown(detail: &PokemonDetail, species: Option<&PokemonSpecies>) -> String {
+    let mut out = format!("{}\n", showdown_species(detail, species));
+    if let Some(ability) = detail.abilities.first() {
+        out.push_str(&format!("Ability: {}\n", title(ability)));
+    }
+    let level_up: Vec<&MoveSummary> = learnset(detail)
+        .into_iter()
+        .filter(|summary| summary.is_level_up())
+        .collect();
+    let start = level_up.len().saturating_sub(SHOWDOWN_MOVES);
+    for summary in &level_up[start..] {
+        out.push_str(&format!("- {}\n", title(&summary.name)));
+    }
+    out
+}
+
+/// The species as Showdown spells it: the English name with its punctuation
+/// (`Mr. Mime`), and a `-Form` for anything but the default form
+/// (`Deoxys-Attack`). Without the species loaded the slug is the best guess.
+fn showdown_species(detail: &PokemonDetail, species: Option<&PokemonSpecies>) -> String {
+    let form_of = |species: &&PokemonSpecies| {
+        detail
+            .name
+            .strip_prefix(species.name.as_str())
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with('-'))
+    };
+    let Some(species) = species.filter(form_of) else {
+        let base = DEFAULT_FORMS
+            .iter()
+            .find_map(|form| detail.name.strip_suffix(form)?.strip_suffix('-'))
+            .unwrap_or(&detail.name);
+        return title(base);
+    };
+    let name = species
+        .english_name
+        .clone()
+        .unwrap_or_else(|| title(&species.name));
+    match detail.name[species.name.len()..].strip_prefix('-') {
+        Some(form) if !DEFAULT_FORMS.contains(&form) => {
+            format!("{name}-{}", title(form).replace(' ', "-"))
+        }
+        _ => name,
+    }
+}
+
+/// Level-up moves by level, then everything else grouped by how it's learned.
+fn learnset(detail: &PokemonDetail) -> Vec<&MoveSummary> {
+    let mut moves: Vec<&MoveSummary> = detail.moves.iter().collect();
+    moves.sort_by(|a, b| {
+        b.is_level_up()
+            .cmp(&a.is_level_up())
+            .then_with(|| a.level.cmp(&b.level))
+            .then_with(|| a.learn_method.cmp(&b.learn_method))
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    moves
+}
+
+fn learned(summary: &MoveSummary) -> String {
+    match summary.learn_method.as_str() {
+        "level-up" => format!("Lv {}", summary.level),
+        "machine" => "TM".to_string(),
+        other => title(other),
+    }
+}
+
+/// `thunder-punch` -> `Thunder Punch`.
+pub fn title(name: &str) -> String {
+    name.split('-')
+        .filter(|part| !part.is_empty())
+        .map(|part| {
+            let mut chars = part.chars();
+            match chars.next() {
+                Some(first) => format!("{}{}", first.to_ascii_uppercase(), chars.as_str()),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Puts `text` on the system clipboard. The clipboard is opened on a blocking
+/// thread since X11 waits for a clipboard manager to take the contents.
+pub async fn copy_to_clipboard(text: String) -> Result<(), String> {
+    tokio::task::spawn_blocking(move || {
+        arboard::Clipboard::new()
+            .and_then(|mut clipboard| clipboard.set_text(text))
+            .map_err(|err| err.to_string())
+    })
+    .await
+    .map_err(|err| err.to_string())?
+}
+
+/// Writes `contents` next to where the app was started and returns the path.
+pub async fn write_export(file_name: String, contents: String) -> Result<String, String> {
+    let path = PathBuf::from(file_name);
+    fs::write(&path, contents)
+        .await
+        .map_err(|err| err.to_string())?;
+    Ok(path.display().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn detail(name: &str) -> PokemonDetail {
+        PokemonDetail {
+            id: 122,
+            name: name.to_string(),
+            types: vec!["psychic".to_string(), "fairy".to_string()],
+            stats: Vec::new(),
+            abilities: vec!["soundproof".to_string(), "filter".to_string()],
+            moves: Vec::new(),
+            height: 13,
+            weight: 545,
+            sprite_front_default: None,
+            sprite_front_shiny: None,
+            sprite_animated: None,
+            cries_latest: None,
+            cries_legacy: None,
+        }
+    }
+
+    fn species(name: &str, english_name: &str) -> PokemonSpecies {
+        PokemonSpecies {
+            name: name.to_string(),
+            language: "en".to_string(),
+            flavor_text: None,
+            genus: None,
+            evolution_chain_url: None,
+            evolves_from: None,
+            generation: None,
+            english_name: Some(english_name.to_string()),
+        }
+    }
+
+    fn learned_move(name: &str, learn_method: &str, level: u8) -> MoveSummary {
+        MoveSummary {
+            name: name.to_string(),
+            move_type: None,
+            power: None,
+            accuracy: None,
+            damage_class: None,
+            learn_method: learn_method.to_string(),
+            level,
+        }
+    }
+
+    fn species_line(name: &str, species: Option<&PokemonSpecies>) -> String {
+        showdown_species(&detail(name), species)
+    }
+
+    #[test]
+    fn showdown_set_lists_the_last_four_level_up_moves() {
+        let mut mime = detail("mr-mime");
+        mime.moves = vec![
+            learned_move("psychic", "level-up", 48),
+            learned_move("barrier", "level-up", 1),
+            learned_move("thunder-punch", "machine", 0),
+            learned_move("confusion", "level-up", 12),
+            learned_move("light-screen", "level-up", 24),
+            learned_move("reflect", "level-up", 24),
+        ];
+        let species = species("mr-mime", "Mr. Mime");
+        assert_eq!(
+            render(ExportFormat::Showdown, &mime, Some(&species)),
+            "Mr. Mime\nAbility: Soundproof\n- Confusion\n- Light Screen\n- Reflect\n- Psychic\n"
+        );
+    }
+
+    #[test]
+    fn default_forms_export_as_the_bare_species() {
+        let deoxys = species("deoxys", "Deoxys");
+        assert_eq!(species_line("deoxys-normal", Some(&deoxys)), "Deoxys");
+        assert_eq!(species_line("deoxys-normal", None), "Deoxys");
+        assert_eq!(species_line("landorus-incarnate", None), "Landorus");
+        assert_eq!(species_line("pikachu", None), "Pikachu");
+    }
+
+    #[test]
+    fn alternate_forms_keep_a_showdown_suffix() {
+        let deoxys = species("deoxys", "Deoxys");
+        assert_eq!(
+            species_line("deoxys-attack", Some(&deoxys)),
+            "Deoxys-Attack"
+        );
+        let mime = species("mr-mime", "Mr. Mime");
+        assert_eq!(species_line("mr-mime-galar", Some(&mime)), "Mr. Mime-Galar");
+        let urshifu = species("urshifu", "Urshifu");
+        assert_eq!(
+            species_line("urshifu-rapid-strike", Some(&urshifu)),
+            "Urshifu-Rapid-Strike"
+        );
+        // A species that was loaded for some other pokemon is ignored.
+        assert_eq!(
+            species_line("porygon2", Some(&species("porygon", "Porygon"))),
+            "Porygon2"
+        );
+    }
+
+    #[test]
+    fn slugs_become_titles_and_file_names() {
+        assert_eq!(title("thunder-punch"), "Thunder Punch");
+        assert_eq!(title("-odd--slug-"), "Odd Slug");
+        assert_eq!(file_name("pikachu", ExportFormat::Showdown), "pikachu.txt");
+        assert_eq!(file_name("pikachu", ExportFormat::Markdown), "pikachu.md");
+    }
+}
@@ -0,0 +1,130 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::state::PokemonSpecies;
+
+/// National dex numbers the daily pick is drawn from (Bulbasaur through Pecharunt).
+pub const FEATURED_POOL: u16 = 1025;
+
+const SECONDS_PER_DAY: u64 = 24 * 60 * 60;
+
+/// Days since the Unix epoch, in UTC, so everyone sees the same pick on the same day.
+pub fn day_index(now: SystemTime) -> u64 {
+    now.duration_since(UNIX_EPOCH)
+        .map(|elapsed| elapsed.as_secs() / SECONDS_PER_DAY)
+        .unwrap_or(0)
+}
+
+pub fn today() -> u64 {
+    day_index(SystemTime::now())
+}
+
+/// National dex number featured on `day`. Hashed rather than stepped so
+/// consecutive days don't walk through the dex in order.
+pub fn featured_id(day: u64) -> u16 {
+    (splitmix64(day) % u64::from(FEATURED_POOL)) as u16 + 1
+}
+
+/// One fact from the species entry. Which fact rotates with the day, so a
+/// Pokemon that comes round again doesn't repeat itself.
+pub fn trivia(species: &PokemonSpecies, day: u64) -> String {
+    let mut facts = Vec::new();
+    if let Some(genus) = species.genus.as_deref() {
+        facts.push(format!("Known as the {genus}."));
+    }
+    if let Some(from) = species.evolves_from.as_deref() {
+        facts.push(format!("Evolves from {}.", display_name(from)));
+    }
+    if let Some(generation) = species.generation {
+        facts.push(format!("First appeared in generation {generation}."));
+    }
+    if let Some(text) = species.flavor_text.as_deref().and_then(first_sentence) {
+        facts.push(text);
+    }
+    if facts.is_empty() {
+        return "The dex has nothing to say about this one yet.".to_string();
+    }
+    let index = (day % facts.len() as u64) as usize;
+    facts.swap_remove(index)
+}
+
+fn first_sentence(text: &str) -> Option<String> {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    let end = text.find(". ").map(|idx| idx + 1).unwrap_or(text.len());
+    let sentence = text[..end].trim();
+    (!sentence.is_empty()).then(|| sentence.to_string())
+}
+
+fn display_name(name: &str) -> String {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(first) => format!("{}{}", first.to_ascii_uppercase(), chars.as_str()),
+        None => String::new(),
+    }
+}
+
+fn splitmix64(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::time::Duration;
+
+    fn species() -> PokemonSpecies {
+        PokemonSpecies {
+            name: "raichu".to_string(),
+            language: "en".to_string(),
+            flavor_text: Some(
+                "Its long tail serves as a ground.\nIt protects itself. More text.".to_string(),
+            ),
+            genus: Some("Mouse Pokémon".to_string()),
+            evolution_chain_url: None,
+            evolves_from: Some("pikachu".to_string()),
+            generation: Some(1),
+            english_name: Some("Raichu".to_string()),
+        }
+    }
+
+    #[test]
+    fn same_day_same_pick() {
+        let morning = UNIX_EPOCH + Duration::from_secs(20_000 * SECONDS_PER_DAY + 60);
+        let night = morning + Duration::from_secs(SECONDS_PER_DAY - 120);
+        assert_eq!(day_index(morning), day_index(night));
+        assert_eq!(
+            featured_id(day_index(morning)),
+            featured_id(day_index(night))
+        );
+        assert_ne!(featured_id(20_000), featured_id(20_001));
+        for day in 0..2_000 {
+            assert!((1..=FEATURED_POOL).contains(&featured_id(day)));
+        }
+    }
+
+    #[test]
+    fn trivia_rotates_through_species_facts() {
+        let species = species();
+        let lines: Vec<String> = (0..4).map(|day| trivia(&species, day)).collect();
+        assert_eq!(
+            lines,
+            vec![
+                "Known as the Mouse Pokémon.",
+                "Evolves from Pikachu.",
+                "First appeared in generation 1.",
+                "Its long tail serves as a ground.",
+            ]
+        );
+
+        let bare = PokemonSpecies {
+            flavor_text: None,
+            genus: None,
+            evolves_from: None,
+            generation: None,
+            ..species
+        };
+        assert!(trivia(&bare, 3).contains("nothing to say"));
+    }
+}
@@ -0,0 +1,152 @@
+//! Skim-style fuzzy scoring: query characters must appear in order, with
+//! bonuses for word starts and runs of consecutive matches and penalties for
+//! the gaps between them.
+
+const SCORE_MATCH: i32 = 16;
+const BONUS_BOUNDARY: i32 = 10;
+const BONUS_FIRST_CHAR: i32 = 8;
+const BONUS_CONSECUTIVE: i32 = 12;
+const PENALTY_GAP_START: i32 = 4;
+const PENALTY_GAP_EXTEND: i32 = 1;
+const PENALTY_LEADING_MAX: i32 = 6;
+
+/// Dex number scores sit above any name score a short query can reach.
+const SCORE_NUMBER_EXACT: i32 = 1000;
+const SCORE_NUMBER_PREFIX: i32 = 500;
+const SCORE_NUMBER_CONTAINS: i32 = 100;
+
+/// Score `query` against `candidate`, case-insensitively.
+///
+/// Returns the best score and the char positions in `candidate` it matched,
+/// or `None` when the query isn't a subsequence of the candidate.
+pub fn fuzzy_match(query: &str, candidate: &str) -> Option<(i32, Vec<usize>)> {
+    let query: Vec<char> = query.chars().flat_map(char::to_lowercase).collect();
+    let chars: Vec<char> = candidate.chars().flat_map(char::to_lowercase).collect();
+    let (n, m) = (query.len(), chars.len());
+    if n == 0 {
+        return Some((0, Vec::new()));
+    }
+    if n > m {
+        return None;
+    }
+
+    let bonus = |j: usize| {
+        if j == 0 {
+            BONUS_BOUNDARY + BONUS_FIRST_CHAR
+        } else if matches!(chars[j - 1], '-' | ' ' | '_' | '.') {
+            BONUS_BOUNDARY
+        } else {
+            0
+        }
+    };
+
+    // scores[i][j]: best score with query[i] matched at chars[j].
+    let mut scores: Vec<Vec<Option<i32>>> = vec![vec![None; m]; n];
+    let mut back: Vec<Vec<usize>> = vec![vec![0; m]; n];
+    for j in 0..m {
+        if chars[j] == query[0] {
+            let leading = (j as i32).min(PENALTY_LEADING_MAX);
+            scores[0][j] = Some(SCORE_MATCH + bonus(j) - leading);
+        }
+    }
+    for i in 1..n {
+        for j in i..m {
+            if chars[j] != query[i] {
+                continue;
+            }
+            let mut best: Option<(i32, usize)> = None;
+            for (k, prev) in scores[i - 1].iter().enumerate().take(j).skip(i - 1) {
+                let Some(prev) = *prev else {
+                    continue;
+                };
+                let link = if k + 1 == j {
+                    BONUS_CONSECUTIVE
+                } else {
+                    -(PENALTY_GAP_START + PENALTY_GAP_EXTEND * (j - k - 2) as i32)
+                };
+                let score = prev + link;
+                if best.is_none_or(|(top, _)| score > top) {
+                    best = Some((score, k));
+                }
+            }
+            if let Some((score, k)) = best {
+                scores[i][j] = Some(score + SCORE_MATCH + bonus(j));
+                back[i][j] = k;
+            }
+        }
+    }
+
+    let (mut j, score) = scores[n - 1]
+        .iter()
+        .enumerate()
+        .filter_map(|(j, score)| score.map(|score| (j, score)))
+        .max_by(|a, b| a.1.cmp(&b.1).then_with(|| b.0.cmp(&a.0)))?;
+    let mut positions = vec![0; n];
+    for i in (0..n).rev() {
+        positions[i] = j;
+        j = back[i][j];
+    }
+    Some((score, positions))
+}
+
+/// Score a dex number query such as `25` (from `#25` or a bare `25`).
+pub fn number_match(digits: &str, number: u16) -> Option<i32> {
+    let plain = number.to_string();
+    let padded = format!("{number:03}");
+    if digits.trim_start_matches('0') == plain {
+        Some(SCORE_NUMBER_EXACT)
+    } else if plain.starts_with(digits) || padded.starts_with(digits) {
+        Some(SCORE_NUMBER_PREFIX - plain.len() as i32)
+    } else if plain.contains(digits) {
+        Some(SCORE_NUMBER_CONTAINS)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn score(query: &str, candidate: &str) -> i32 {
+        fuzzy_match(query, candidate).unwrap().0
+    }
+
+    #[test]
+    fn consecutive_runs_beat_gapped_matches() {
+        assert!(score("char", "charmander") > score("char", "chandelure"));
+    }
+
+    #[test]
+    fn word_starts_score_a_bonus() {
+        assert_eq!(score("e", "zz-e") - score("e", "zzze"), BONUS_BOUNDARY);
+        assert!(score("m", "mew") > score("m", "amew"));
+    }
+
+    #[test]
+    fn non_subsequences_do_not_match() {
+        assert_eq!(fuzzy_match("xyz", "pikachu"), None);
+        assert_eq!(fuzzy_match("ahcip", "pikachu"), None);
+        assert_eq!(fuzzy_match("pikachu", "pika"), None);
+        assert_eq!(fuzzy_match("", "pikachu"), Some((0, Vec::new())));
+    }
+
+    #[test]
+    fn positions_point_at_the_best_alignment() {
+        let (_, positions) = fuzzy_match("MIME", "mr-mime").unwrap();
+        assert_eq!(positions, vec![3, 4, 5, 6]);
+        let (_, positions) = fuzzy_match("pc", "pikachu").unwrap();
+        assert_eq!(positions, vec![0, 4]);
+    }
+
+    #[test]
+    fn numbers_match_exactly_then_by_prefix_then_anywhere() {
+        assert_eq!(number_match("25", 25), Some(SCORE_NUMBER_EXACT));
+        assert_eq!(number_match("025", 25), Some(SCORE_NUMBER_EXACT));
+        assert_eq!(number_match("2", 25), Some(SCORE_NUMBER_PREFIX - 2));
+        assert_eq!(number_match("00", 7), Some(SCORE_NUMBER_PREFIX - 1));
+        assert!(number_match("2", 25) > number_match("2", 251));
+        assert_eq!(number_match("5", 25), Some(SCORE_NUMBER_CONTAINS));
+        assert_eq!(number_match("3", 25), None);
+    }
+}
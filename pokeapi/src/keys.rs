@@ -0,0 +1,410 @@
+//! Global key bindings.
+//!
+//! The defaults come from [`COMMANDS`]. `~/.config/pokeapi-tui/keys.toml`
+//! (or `$XDG_CONFIG_HOME`) can rebind any command, either everywhere or only
+//! while one part of the UI has focus:
+//!
+//! ```toml
+//! [global]
+//! quit = ["q", "ctrl+c"]
+//!
+//! [dex_list]
+//! cry = ["space"]
+//! ```
+//!
+//! Keys listed for a command replace its defaults in that section.
+
+use std::collections::BTreeMap;
+use std::path::PathBuf;
+
+use tui_dispatch::{format_key_for_display, parse_key_string, BindingContext, Keybindings};
+
+use crate::action::Action;
+use crate::state::{next_language, AppState, DetailMode, FocusArea};
+use crate::PokeContext;
+
+/// A command the global key handler can run.
+pub struct Command {
+    /// Name used in `keys.toml`.
+    pub name: &'static str,
+    pub label: &'static str,
+    pub default_keys: &'static [&'static str],
+}
+
+pub const COMMANDS: &[Command] = &[
+    Command {
+        name: "focus_next",
+        label: "Focus",
+        default_keys: &["tab"],
+    },
+    Command {
+        name: "focus_prev",
+        label: "Focus back",
+        default_keys: &["backtab"],
+    },
+    Command {
+        name: "search",
+        label: "Search",
+        default_keys: &["/"],
+    },
+    Command {
+        name: "filter_prev",
+        label: "Previous type",
+        default_keys: &["["],
+    },
+    Command {
+        name: "filter_next",
+        label: "Next type",
+        default_keys: &["]"],
+    },
+    Command {
+        name: "generation_next",
+        label: "Next generation",
+        default_keys: &["g"],
+    },
+    Command {
+        name: "generation_prev",
+        label: "Previous generation",
+        default_keys: &["G"],
+    },
+    Command {
+        name: "regions",
+        label: "Regions",
+        default_keys: &["r"],
+    },
+    Command {
+        name: "sort",
+        label: "Sort",
+        default_keys: &["o"],
+    },
+    Command {
+        name: "team",
+        label: "Team",
+        default_keys: &["T"],
+    },
+    Command {
+        name: "bookmark",
+        label: "Bookmark",
+        default_keys: &["m"],
+    },
+    Command {
+        name: "bookmarks",
+        label: "Jump list",
+        default_keys: &["'"],
+    },
+    Command {
+        name: "items",
+        label: "Items",
+        default_keys: &["I"],
+    },
+    Command {
+        name: "locations",
+        label: "Locations",
+        default_keys: &["L"],
+    },
+    Command {
+        name: "notes",
+        label: "Note",
+        default_keys: &["n"],
+    },
+    Command {
+        name: "export",
+        label: "Export",
+        default_keys: &["x"],
+    },
+    Command {
+        name: "report",
+        label: "Region report",
+        default_keys: &["X"],
+    },
+    Command {
+        name: "copy",
+        label: "Copy",
+        default_keys: &["y"],
+    },
+    Command {
+        name: "calc",
+        label: "Damage calc",
+        default_keys: &["D"],
+    },
+    Command {
+        name: "radar",
+        label: "Stats radar",
+        default_keys: &["R"],
+    },
+    Command {
+        name: "language",
+        label: "Flavor language",
+        default_keys: &["i"],
+    },
+    Command {
+        name: "cry",
+        label: "Cry",
+        default_keys: &["p"],
+    },
+    Command {
+        name: "help",
+        label: "Help",
+        default_keys: &["?"],
+    },
+    Command {
+        name: "quit",
+        label: "Quit",
+        default_keys: &["q"],
+    },
+];
+
+/// Section of `keys.toml` that applies regardless of focus.
+const GLOBAL_SECTION: &str = "global";
+
+/// Context name to command name to keys, as written in `keys.toml`.
+type KeysFile = BTreeMap<String, BTreeMap<String, Vec<String>>>;
+
+pub fn default_keybindings() -> Keybindings<PokeContext> {
+    let mut bindings = Keybindings::new();
+    for command in COMMANDS {
+        let keys = command.default_keys.iter().map(|key| key.to_string());
+        bindings.add_global(command.name, keys.collect());
+    }
+    bindings
+}
+
+/// Defaults with the user's `keys.toml` applied on top, plus a note on
+/// anything in the file that was skipped. A missing file is not a problem.
+pub fn load_keybindings() -> (Keybindings<PokeContext>, Option<String>) {
+    let mut bindings = default_keybindings();
+    let path = keys_path();
+    let text = match std::fs::read_to_string(&path) {
+        Ok(text) => text,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return (bindings, None),
+        Err(err) => return (bindings, Some(format!("keys.toml: {err}"))),
+    };
+    let problems = match toml::from_str::<KeysFile>(&text) {
+        Ok(file) => apply_keys_file(&mut bindings, file),
+        Err(err) => vec![err.message().to_string()],
+    };
+    let warning = (!problems.is_empty()).then(|| format!("keys.toml: {}", problems.join("; ")));
+    (bindings, warning)
+}
+
+/// Apply every valid entry and describe the ones that weren't.
+fn apply_keys_file(bindings: &mut Keybindings<PokeContext>, file: KeysFile) -> Vec<String> {
+    let mut problems = Vec::new();
+    for (section, commands) in file {
+        let context = if section == GLOBAL_SECTION {
+            None
+        } else if let Some(context) = PokeContext::from_name(&section) {
+            Some(context)
+        } else {
+            problems.push(format!("unknown section [{section}]"));
+            continue;
+        };
+        for (name, keys) in commands {
+            if !COMMANDS.iter().any(|command| command.name == name) {
+                problems.push(format!("unknown command `{name}`"));
+                continue;
+            }
+            let (valid, invalid): (Vec<String>, Vec<String>) = keys
+                .into_iter()
+                .partition(|key| parse_key_string(key).is_some());
+            for key in invalid {
+                problems.push(format!("bad key `{key}` for `{name}`"));
+            }
+            match context {
+                Some(context) => bindings.add(context, name, valid),
+                None => bindings.add_global(name, valid),
+            }
+        }
+    }
+    problems
+}
+
+/// The action a command stands for right now. Some depend on focus: the
+/// filter keys follow the open detail tab, and `team` closes the team view
+/// when it's already open.
+pub fn command_action(command: &str, state: &AppState) -> Option<Action> {
+    let on_tab = |mode| state.focus == FocusArea::DetailTabs && state.detail_mode == mode;
+    let action = match command {
+        "focus_next" => Action::FocusNext,
+        "focus_prev" => Action::FocusPrev,
+        "quit" => Action::Quit,
+        // Everything else would steal keys typed into the search box.
+        _ if state.search.active => return None,
+        "search" => Action::SearchStart,
+        "filter_prev" if on_tab(DetailMode::Encounter) => Action::EncounterFilterPrev,
+        "filter_prev" if on_tab(DetailMode::Move) => Action::MoveTypeFilterPrev,
+        "filter_prev" => Action::TypeFilterPrev,
+        "filter_next" if on_tab(DetailMode::Encounter) => Action::EncounterFilterNext,
+        "filter_next" if on_tab(DetailMode::Move) => Action::MoveTypeFilterNext,
+        "filter_next" => Action::TypeFilterNext,
+        "generation_next" => Action::GenerationFilterNext,
+        "generation_prev" => Action::GenerationFilterPrev,
+        "regions" => Action::RegionPickerOpen,
+        "sort" => Action::SortOpen,
+        "team" if state.focus == FocusArea::Team => Action::TeamClose,
+        "team" => Action::TeamOpen,
+        "bookmark" => Action::BookmarkToggle,
+        "bookmarks" => Action::BookmarksOpen,
+        "items" => Action::ItemsOpen,
+        // The team view uses `L` to move a member right.
+        "locations" if state.focus == FocusArea::Team => return None,
+        "locations" => Action::LocationsOpen,
+        "notes" => Action::NoteEditOpen,
+        // The team view uses its own `x` to remove a member.
+        "export" if state.focus == FocusArea::Team => return None,
+        "export" => Action::ExportOpen,
+        "report" => Action::ReportOpen,
+        "copy" => Action::ClipboardCopy,
+        "calc" => Action::CalcOpen,
+        "radar" => Action::StatsRadarToggle,
+        "language" => Action::LanguageChanged(next_language(&state.language).to_string()),
+        "cry" => Action::PlayCry,
+        "help" => Action::HelpOpen,
+        _ => return None,
+    };
+    Some(action)
+}
+
+pub fn label(command: &str) -> &'static str {
+    COMMANDS
+        .iter()
+        .find(|candidate| candidate.name == command)
+        .map_or("", |candidate| candidate.label)
+}
+
+/// Binding context for the part of the main view that has focus.
+pub fn focus_context(focus: FocusArea) -> PokeContext {
+    match focus {
+        FocusArea::Header => PokeContext::Header,
+        FocusArea::DexList => PokeContext::DexList,
+        FocusArea::DetailTabs => PokeContext::DetailTabs,
+        FocusArea::Evolution => PokeContext::Evolution,
+        FocusArea::Team => PokeContext::Team,
+        FocusArea::Items => PokeContext::Items,
+        FocusArea::Locations => PokeContext::Locations,
+    }
+}
+
+/// First key bound to `command` in `context`, formatted for the footer.
+pub fn key_hint(
+    bindings: &Keybindings<PokeContext>,
+    command: &str,
+    context: PokeContext,
+) -> Option<String> {
+    bindings
+        .get_first_keybinding(command, context)
+        .map(|key| format_key_for_display(&key))
+}
+
+/// Every key bound to `command` in `context`, formatted for display.
+pub fn bound_keys(
+    bindings: &Keybindings<PokeContext>,
+    command: &str,
+    context: PokeContext,
+) -> Vec<String> {
+    bindings
+        .get_context_bindings(context)
+        .and_then(|commands| commands.get(command))
+        .or_else(|| bindings.global_bindings().get(command))
+        .map(|keys| keys.iter().map(|key| format_key_for_display(key)).collect())
+        .unwrap_or_default()
+}
+
+fn keys_path() -> PathBuf {
+    let base = std::env::var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|_| std::env::var("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .unwrap_or_else(|_| PathBuf::from("."));
+    base.join("pokeapi-tui").join("keys.toml")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
+
+    fn key(code: KeyCode) -> KeyEvent {
+        KeyEvent::new(code, KeyModifiers::NONE)
+    }
+
+    #[test]
+    fn keys_file_rebinds_and_reports_problems() {
+        let mut bindings = default_keybindings();
+        let file: KeysFile = toml::from_str(
+            r#"
+            [global]
+            quit = ["Q", "not a key"]
+            bogus = ["b"]
+
+            [dex_list]
+            cry = ["space"]
+
+            [nowhere]
+            quit = ["z"]
+            "#,
+        )
+        .unwrap();
+
+        let problems = apply_keys_file(&mut bindings, file);
+
+        assert_eq!(
+            problems,
+            vec![
+                "unknown command `bogus`",
+                "bad key `not a key` for `quit`",
+                "unknown section [nowhere]",
+            ]
+        );
+        let quit = bindings.get_command(key(KeyCode::Char('Q')), PokeContext::Team);
+        assert_eq!(quit.as_deref(), Some("quit"));
+        assert_eq!(
+            bindings.get_command(key(KeyCode::Char('q')), PokeContext::Team),
+            None
+        );
+        let cry = bindings.get_command(key(KeyCode::Char(' ')), PokeContext::DexList);
+        assert_eq!(cry.as_deref(), Some("cry"));
+        assert_eq!(
+            bindings.get_command(key(KeyCode::Char(' ')), PokeContext::Team),
+            None
+        );
+    }
+
+    #[test]
+    fn bound_keys_prefer_the_focus_section() {
+        let mut bindings = default_keybindings();
+        bindings.add(PokeContext::Team, "help", vec!["h".to_string()]);
+
+        assert_eq!(bound_keys(&bindings, "help", PokeContext::Team), vec!["h"]);
+        assert_eq!(
+            bound_keys(&bindings, "help", PokeContext::DexList),
+            vec!["?"]
+        );
+        assert!(bound_keys(&bindings, "bogus", PokeContext::DexList).is_empty());
+
+        let team = AppState {
+            focus: FocusArea::Team,
+            ..AppState::default()
+        };
+        let command = bindings.get_command(key(KeyCode::Char('L')), PokeContext::Team);
+        assert_eq!(command.as_deref(), Some("locations"));
+        assert_eq!(command_action("locations", &team), None);
+    }
+
+    #[test]
+    fn filter_commands_follow_the_detail_tab() {
+        let mut state = AppState::default();
+        assert_eq!(
+            command_action("filter_next", &state),
+            Some(Action::TypeFilterNext)
+        );
+        state.focus = FocusArea::DetailTabs;
+        state.detail_mode = DetailMode::Move;
+        assert_eq!(
+            command_action("filter_next", &state),
+            Some(Action::MoveTypeFilterNext)
+        );
+        state.search.active = true;
+        assert_eq!(command_action("filter_next", &state), None);
+        assert_eq!(command_action("quit", &state), Some(Action::Quit));
+    }
+}
@@ -0,0 +1,40 @@
+use std::collections::{BTreeMap, HashMap};
+use std::path::PathBuf;
+
+use tokio::fs;
+
+pub async fn load_notes() -> Result<HashMap<String, String>, String> {
+    let bytes = match fs::read(notes_path()).await {
+        Ok(bytes) => bytes,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(HashMap::new()),
+        Err(err) => return Err(err.to_string()),
+    };
+    serde_json::from_slice(&bytes).map_err(|err| err.to_string())
+}
+
+pub async fn save_notes(notes: HashMap<String, String>) -> Result<(), String> {
+    // Sorted keys keep the file diff-friendly for people who sync it.
+    let sorted: BTreeMap<String, String> = notes.into_iter().collect();
+    let bytes = serde_json::to_vec_pretty(&sorted).map_err(|err| err.to_string())?;
+    let path = notes_path();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .await
+            .map_err(|err| err.to_string())?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    fs::write(&tmp, bytes)
+        .await
+        .map_err(|err| err.to_string())?;
+    fs::rename(&tmp, &path).await.map_err(|err| err.to_string())
+}
+
+fn notes_path() -> PathBuf {
+    let base = std::env::var("HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(|_| PathBuf::from("."));
+    base.join(".local")
+        .join("share")
+        .join("pokeapi-tui")
+        .join("notes.json")
+}
@@ -3,8 +3,18 @@ use tui_dispatch::DispatchResult;
 use std::collections::HashSet;
 
 use crate::action::Action;
+use crate::calc;
+use crate::coverage;
 use crate::effect::Effect;
-use crate::state::AppState;
+use crate::export::{self, ExportFormat};
+use crate::featured;
+use crate::report;
+use crate::state::{
+    item_sprite_key, AppState, CalcPane, CryState, DexPaging, DexRow, DexSort, FocusArea,
+    HelpState, LocationPane, MatchupView, NoteEditorState, DETAIL_PREFETCH, DEX_PAGE_PREFETCH,
+    DEX_PAGE_SIZE, FAMILY_PREFETCH, LANGUAGES, MOVE_PAGE_SIZE, NATIONAL_DEX,
+};
+use crate::suggest;
 
 pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
     match action {
@@ -13,38 +23,98 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
             state.type_loading = true;
             state.region_loading = true;
             state.species_index_loading = true;
-            state.message = None;
-            DispatchResult::changed_with_many(vec![
-                Effect::LoadRegions,
-                Effect::LoadPokedex {
+            state.message = state.keys_warning.take();
+            // A deep link goes straight to its target, past the featured card.
+            state.featured.active = state.deep_link.is_empty();
+            let mut effects = Vec::new();
+            if state.featured.active {
+                effects.push(Effect::LoadFeatured);
+            }
+            effects.push(Effect::LoadRegions);
+            // `--region` has to wait for the region list to find its pokedex.
+            if state.deep_link.region.is_none() {
+                effects.push(Effect::LoadPokedex {
                     name: "kanto".to_string(),
-                },
-                Effect::LoadTypes,
-            ])
+                });
+            }
+            effects.extend([Effect::LoadTypes, Effect::LoadNotes, Effect::LoadUserData]);
+            DispatchResult::changed_with_many(effects)
         }
 
         Action::PokedexDidLoad(entries) => {
             state.pokedex_all = entries;
             state.pokedex.clear();
             state.filtered_indices.clear();
+            state.dex_rows.clear();
             state.selected_index = 0;
             state.detail_name = None;
             state.list_loading = false;
             state.species_index_loading = true;
             state.reset_sprite_animation();
             state.reset_detail_selection();
-            let names = state
+            let names: Vec<String> = state
                 .pokedex_all
                 .iter()
                 .map(|entry| entry.name.clone())
                 .collect();
-            DispatchResult::changed_with(Effect::LoadSpeciesIndex { names })
+            if let Some(region) = state.current_region() {
+                let key = region.name.clone();
+                state.region_dex_members.insert(key, names.clone());
+            }
+            let mut effects = vec![Effect::LoadSpeciesIndex {
+                names,
+                language: state.language.clone(),
+            }];
+            effects.extend(base_stats_effect(state));
+            DispatchResult::changed_with_many(effects)
+        }
+
+        Action::PokedexPageDidLoad {
+            offset,
+            entries,
+            total,
+        } => {
+            let Some(paging) = state.dex_paging.as_mut() else {
+                return DispatchResult::unchanged();
+            };
+            if paging.loading != Some(offset) || state.pokedex_all.len() != offset {
+                return DispatchResult::unchanged();
+            }
+            paging.total = Some(total);
+            state.list_loading = false;
+            state.species_index_loading = true;
+            let names: Vec<String> = entries.iter().map(|entry| entry.name.clone()).collect();
+            state.pokedex_all.extend(entries);
+            let mut effects = vec![Effect::LoadSpeciesIndex {
+                names,
+                language: state.language.clone(),
+            }];
+            effects.extend(base_stats_effect(state));
+            DispatchResult::changed_with_many(effects)
+        }
+
+        Action::PokedexPageDidError { offset, error } => {
+            let Some(paging) = state.dex_paging.as_mut() else {
+                return DispatchResult::unchanged();
+            };
+            if paging.loading != Some(offset) {
+                return DispatchResult::unchanged();
+            }
+            // Clearing the in-flight page lets the next scroll retry it.
+            paging.loading = None;
+            state.list_loading = false;
+            state.message = Some(format!("Pokedex error: {error}"));
+            DispatchResult::changed()
         }
 
         Action::PokedexDidError(error) => {
             state.list_loading = false;
             state.species_index_loading = false;
             state.message = Some(format!("Pokedex error: {error}"));
+            // The deep-linked pokemon can still be shown without its dex.
+            if let Some(name) = state.deep_link.pokemon.take() {
+                return DispatchResult::changed_with_many(jump_to(state, &name));
+            }
             DispatchResult::changed()
         }
 
@@ -53,6 +123,7 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
             for species in species_list {
                 state.species.insert(species.name.clone(), species);
             }
+            let previous = state.selected_name();
             let region_species: HashSet<String> = state
                 .pokedex_all
                 .iter()
@@ -77,27 +148,16 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
                 .cloned()
                 .collect();
             state.rebuild_filtered();
-            state.selected_index = 0;
-            let effects = select_current(state);
-            if effects.is_empty() {
-                DispatchResult::changed()
-            } else {
-                DispatchResult::changed_with_many(effects)
-            }
+            reselect_after_rebuild(state, previous)
         }
 
         Action::SpeciesIndexDidError(error) => {
             state.species_index_loading = false;
             state.message = Some(format!("Species index error: {error}"));
+            let previous = state.selected_name();
             state.pokedex = state.pokedex_all.clone();
             state.rebuild_filtered();
-            state.selected_index = 0;
-            let effects = select_current(state);
-            if effects.is_empty() {
-                DispatchResult::changed()
-            } else {
-                DispatchResult::changed_with_many(effects)
-            }
+            reselect_after_rebuild(state, previous)
         }
 
         Action::RegionsDidLoad(regions) => {
@@ -112,20 +172,110 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
             } else if state.region_index >= state.regions.len() {
                 state.region_index = 0;
             }
+            if let Some(name) = state.deep_link.region.take() {
+                return open_deep_link_region(state, &name);
+            }
+            if state.region_picker.active {
+                state.region_picker.selected = state.region_index;
+                return DispatchResult::changed_with_many(region_dex_effects(state));
+            }
             DispatchResult::changed()
         }
 
         Action::RegionsDidError(error) => {
             state.region_loading = false;
             state.message = Some(format!("Region error: {error}"));
+            if state.deep_link.region.take().is_some() {
+                return DispatchResult::changed_with(Effect::LoadPokedex {
+                    name: "kanto".to_string(),
+                });
+            }
+            DispatchResult::changed()
+        }
+
+        Action::RegionPickerOpen => {
+            if state.region_picker.active {
+                return DispatchResult::unchanged();
+            }
+            state.region_picker.active = true;
+            state.region_picker.query.clear();
+            state.region_picker.selected = state.region_index;
+            if state.regions.is_empty() && !state.region_loading {
+                state.region_loading = true;
+                return DispatchResult::changed_with(Effect::LoadRegions);
+            }
+            DispatchResult::changed_with_many(region_dex_effects(state))
+        }
+
+        Action::RegionPickerClose => {
+            if !state.region_picker.active {
+                return DispatchResult::unchanged();
+            }
+            state.region_picker.active = false;
+            state.region_picker.query.clear();
+            DispatchResult::changed()
+        }
+
+        Action::RegionPickerInput(ch) => {
+            state.region_picker.query.push(ch);
+            state.region_picker.selected = 0;
+            DispatchResult::changed()
+        }
+
+        Action::RegionPickerBackspace => {
+            if state.region_picker.query.pop().is_none() {
+                return DispatchResult::unchanged();
+            }
+            state.region_picker.selected = 0;
+            DispatchResult::changed()
+        }
+
+        Action::RegionPickerMove(delta) => {
+            let len = state.region_picker_matches().len();
+            let next = clamp_index(state.region_picker.selected, len, delta);
+            if next == state.region_picker.selected {
+                return DispatchResult::unchanged();
+            }
+            state.region_picker.selected = next;
+            DispatchResult::changed()
+        }
+
+        Action::RegionPickerSelect(index) => {
+            let len = state.region_picker_matches().len();
+            if len == 0 {
+                return DispatchResult::unchanged();
+            }
+            let bounded = index.min(len - 1);
+            if bounded == state.region_picker.selected {
+                return DispatchResult::unchanged();
+            }
+            state.region_picker.selected = bounded;
+            DispatchResult::changed()
+        }
+
+        Action::RegionPickerConfirm => {
+            let Some(index) = state.region_picker_selected() else {
+                return DispatchResult::unchanged();
+            };
+            state.region_picker.active = false;
+            state.region_picker.query.clear();
+            select_region(state, index)
+        }
+
+        Action::RegionDexDidLoad { name, species } => {
+            state.region_dex_members.insert(name, species);
             DispatchResult::changed()
         }
 
-        Action::RegionNext => cycle_region(state, 1),
-        Action::RegionPrev => cycle_region(state, -1),
+        Action::RegionDexDidError { name, error } => {
+            state.message = Some(format!("Pokedex {name} error: {error}"));
+            DispatchResult::changed()
+        }
 
         Action::FocusNext => {
-            if state.search.active {
+            if state.search.active || state.region_picker.active
+                || matches!(state.focus, FocusArea::Team | FocusArea::Items | FocusArea::Locations)
+            {
                 return DispatchResult::unchanged();
             }
             state.focus_next();
@@ -133,7 +283,9 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
         }
 
         Action::FocusPrev => {
-            if state.search.active {
+            if state.search.active || state.region_picker.active
+                || matches!(state.focus, FocusArea::Team | FocusArea::Items | FocusArea::Locations)
+            {
                 return DispatchResult::unchanged();
             }
             state.focus_prev();
@@ -141,7 +293,7 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
         }
 
         Action::FocusSet(area) => {
-            if state.search.active {
+            if state.search.active || state.region_picker.active {
                 return DispatchResult::unchanged();
             }
             if state.focus == area {
@@ -154,6 +306,7 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
         Action::TypesDidLoad(types) => {
             state.type_loading = false;
             state.type_list = types;
+            refresh_team_coverage(state);
             DispatchResult::changed()
         }
 
@@ -177,6 +330,199 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
             DispatchResult::changed_with_many(effects)
         }
 
+        Action::SortOpen => {
+            if state.sort_picker.active
+                || state.export_picker.active
+                || state.report_prompt.active
+                || state.note_editor.active
+                || state.region_picker.active
+            {
+                return DispatchResult::unchanged();
+            }
+            state.sort_picker.active = true;
+            state.sort_picker.selected = DexSort::ALL
+                .iter()
+                .position(|sort| *sort == state.dex_sort)
+                .unwrap_or(0);
+            DispatchResult::changed()
+        }
+
+        Action::HelpOpen => {
+            if state.help.active
+                || state.sort_picker.active
+                || state.export_picker.active
+                || state.report_prompt.active
+                || state.note_editor.active
+                || state.region_picker.active
+                || state.featured.active
+            {
+                return DispatchResult::unchanged();
+            }
+            state.help = HelpState {
+                active: true,
+                scroll: 0,
+            };
+            DispatchResult::changed()
+        }
+
+        Action::HelpClose => {
+            if !state.help.active {
+                return DispatchResult::unchanged();
+            }
+            state.help.active = false;
+            DispatchResult::changed()
+        }
+
+        Action::HelpScroll(delta) => {
+            let next = state.help.scroll.saturating_add_signed(delta);
+            if next == state.help.scroll {
+                return DispatchResult::unchanged();
+            }
+            state.help.scroll = next;
+            DispatchResult::changed()
+        }
+
+        Action::CalcOpen => {
+            if state.calc.active {
+                return DispatchResult::unchanged();
+            }
+            let Some(detail) = state.current_detail() else {
+                return DispatchResult::unchanged();
+            };
+            if calc::damaging_moves(detail).is_empty() {
+                state.message = Some(format!("{} has no damaging moves.", detail.name));
+                return DispatchResult::changed();
+            }
+            state.calc.active = true;
+            state.calc.pane = CalcPane::Defender;
+            state.calc.query.clear();
+            state.calc.selected = 0;
+            state.calc.move_selected = 0;
+            calc_follow_up(state)
+        }
+
+        Action::CalcClose => {
+            if !state.calc.active {
+                return DispatchResult::unchanged();
+            }
+            state.calc.active = false;
+            DispatchResult::changed()
+        }
+
+        Action::CalcPaneToggle => {
+            state.calc.pane = match state.calc.pane {
+                CalcPane::Defender => CalcPane::Move,
+                CalcPane::Move => CalcPane::Defender,
+            };
+            DispatchResult::changed()
+        }
+
+        Action::CalcInput(ch) => {
+            state.calc.pane = CalcPane::Defender;
+            state.calc.query.push(ch);
+            state.calc.selected = 0;
+            DispatchResult::changed()
+        }
+
+        Action::CalcBackspace => {
+            if state.calc.query.pop().is_none() {
+                return DispatchResult::unchanged();
+            }
+            state.calc.selected = 0;
+            DispatchResult::changed()
+        }
+
+        Action::CalcMove(delta) => match state.calc.pane {
+            CalcPane::Defender => {
+                let len = calc::defender_matches(state).len();
+                let next = clamp_index(state.calc.selected, len, delta);
+                if next == state.calc.selected {
+                    return DispatchResult::unchanged();
+                }
+                state.calc.selected = next;
+                DispatchResult::changed()
+            }
+            CalcPane::Move => {
+                let len = state
+                    .current_detail()
+                    .map_or(0, |detail| calc::damaging_moves(detail).len());
+                let next = clamp_index(state.calc.move_selected, len, delta);
+                if next == state.calc.move_selected {
+                    return DispatchResult::unchanged();
+                }
+                state.calc.move_selected = next;
+                calc_follow_up(state)
+            }
+        },
+
+        Action::CalcConfirm => {
+            if state.calc.pane != CalcPane::Defender {
+                return DispatchResult::unchanged();
+            }
+            let Some(name) = calc::defender_matches(state)
+                .get(state.calc.selected)
+                .map(|name| name.to_string())
+            else {
+                return DispatchResult::unchanged();
+            };
+            state.calc.defender = Some(name);
+            state.calc.pane = CalcPane::Move;
+            calc_follow_up(state)
+        }
+
+        Action::SortClose => {
+            if !state.sort_picker.active {
+                return DispatchResult::unchanged();
+            }
+            state.sort_picker.active = false;
+            DispatchResult::changed()
+        }
+
+        Action::SortMove(delta) => {
+            let next = clamp_index(state.sort_picker.selected, DexSort::ALL.len(), delta);
+            if next == state.sort_picker.selected {
+                return DispatchResult::unchanged();
+            }
+            state.sort_picker.selected = next;
+            DispatchResult::changed()
+        }
+
+        Action::SortConfirm => {
+            if !state.sort_picker.active {
+                return DispatchResult::unchanged();
+            }
+            state.sort_picker.active = false;
+            let selected = state.sort_picker.selected.min(DexSort::ALL.len() - 1);
+            if DexSort::ALL[selected] == state.dex_sort {
+                return DispatchResult::changed();
+            }
+            state.dex_sort = DexSort::ALL[selected];
+            resort_dex(state);
+            let mut effects: Vec<Effect> = base_stats_effect(state).into_iter().collect();
+            effects.extend(next_dex_page(state));
+            effects.extend(family_chain_effects(state));
+            DispatchResult::changed_with_many(effects)
+        }
+
+        Action::BaseStatsDidLoad(stats) => {
+            state.base_stats_loading = false;
+            state.base_stats.extend(stats);
+            if state.dex_sort == DexSort::Number {
+                return DispatchResult::changed();
+            }
+            resort_dex(state);
+            DispatchResult::changed_with_many(family_chain_effects(state))
+        }
+
+        Action::BaseStatsDidError(error) => {
+            state.base_stats_loading = false;
+            state.message = Some(format!("Base stats error: {error}"));
+            DispatchResult::changed()
+        }
+
+        Action::GenerationFilterNext => cycle_generation_filter(state, 1),
+        Action::GenerationFilterPrev => cycle_generation_filter(state, -1),
+
         Action::TypeFilterDidLoad { name, pokemon } => {
             let set = pokemon.into_iter().collect();
             state.update_type_members(&name, set);
@@ -186,7 +532,16 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
                 let effects = select_current(state);
                 return DispatchResult::changed_with_many(effects);
             }
-            DispatchResult::changed()
+            if !state.search.query.trim().is_empty() {
+                // Newly known members can match a type-name search; keep the selection put.
+                let selected = state.selected_name();
+                state.rebuild_filtered();
+                if let Some(index) = selected.and_then(|name| state.row_position(&name)) {
+                    state.selected_index = index;
+                }
+            }
+            refresh_team_coverage(state);
+            team_suggestion_follow_up(state)
         }
 
         Action::TypeFilterDidError { name, error } => {
@@ -205,14 +560,14 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
             if !state.set_selected_index(index as usize) {
                 return DispatchResult::unchanged();
             }
-            DispatchResult::changed_with_many(select_current(state))
+            DispatchResult::changed_with_many(select_dex_row(state))
         }
 
         Action::DexSelect(index) => {
             if !state.set_selected_index(index) {
                 return DispatchResult::unchanged();
             }
-            DispatchResult::changed_with_many(select_current(state))
+            DispatchResult::changed_with_many(select_dex_row(state))
         }
 
         Action::SelectionPage(delta) => {
@@ -224,7 +579,32 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
             if !state.set_selected_index(index as usize) {
                 return DispatchResult::unchanged();
             }
-            DispatchResult::changed_with_many(select_current(state))
+            DispatchResult::changed_with_many(select_dex_row(state))
+        }
+
+        Action::FamilyViewToggle => {
+            state.family_view = !state.family_view;
+            relayout_rows(state);
+            let mut effects = family_chain_effects(state);
+            effects.extend(next_dex_page(state));
+            DispatchResult::changed_with_many(effects)
+        }
+
+        Action::FamilyCollapseToggle => {
+            if !state.family_view {
+                return DispatchResult::unchanged();
+            }
+            // On a member row this folds the family it belongs to.
+            let Some(head) = selected_family_head(state) else {
+                return DispatchResult::unchanged();
+            };
+            if !state.collapsed_families.remove(&head) {
+                state.collapsed_families.insert(head);
+            }
+            relayout_rows(state);
+            let mut effects = select_current(state);
+            effects.extend(family_chain_effects(state));
+            DispatchResult::changed_with_many(effects)
         }
 
         Action::SelectionJumpTop => {
@@ -235,11 +615,11 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
         }
 
         Action::SelectionJumpBottom => {
-            let last = state.filtered_indices.len().saturating_sub(1);
+            let last = state.dex_rows.len().saturating_sub(1);
             if !state.set_selected_index(last) {
                 return DispatchResult::unchanged();
             }
-            DispatchResult::changed_with_many(select_current(state))
+            DispatchResult::changed_with_many(select_dex_row(state))
         }
 
         Action::SearchStart => {
@@ -267,14 +647,21 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
 
         Action::SearchInput(ch) => {
             state.search.query.push(ch);
+            // Ranked results change order, so follow the best match.
+            state.selected_index = 0;
             state.rebuild_filtered();
-            DispatchResult::changed_with_many(select_current(state))
+            let mut effects = select_current(state);
+            effects.extend(search_type_effects(state));
+            DispatchResult::changed_with_many(effects)
         }
 
         Action::SearchBackspace => {
             state.search.query.pop();
+            state.selected_index = 0;
             state.rebuild_filtered();
-            DispatchResult::changed_with_many(select_current(state))
+            let mut effects = select_current(state);
+            effects.extend(search_type_effects(state));
+            DispatchResult::changed_with_many(effects)
         }
 
         Action::PokemonDidLoad(detail) => {
@@ -282,7 +669,51 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
             state.details.insert(name.clone(), detail);
             state.detail_loading = false;
             state.message = None;
-            let effects = detail_follow_up(state, &name);
+            // Team members and the compare pin load in the background; only the
+            // current detail gets the full follow-up.
+            let team_member = state.team.contains(&name);
+            let compared = state.compare.as_deref() == Some(&name);
+            let calc_defender = state.calc.defender.as_deref() == Some(&name);
+            let suggested = suggestion_request_key(&Effect::LoadPokemonDetail {
+                name: name.clone(),
+            })
+            .is_some_and(|key| state.suggestion_requests.contains(&key));
+            let mut effects = if (team_member || compared || suggested || calc_defender)
+                && state.detail_name.as_deref() != Some(&name)
+            {
+                Vec::new()
+            } else {
+                detail_follow_up(state, &name)
+            };
+            if compared {
+                for effect in compare_effects(state) {
+                    if !effects.contains(&effect) {
+                        effects.push(effect);
+                    }
+                }
+            }
+            if team_member {
+                let member_effects = if state.focus == FocusArea::Team {
+                    team_member_effects(state, &name)
+                } else {
+                    coverage_effects(state, &name)
+                };
+                for effect in member_effects {
+                    if !effects.contains(&effect) {
+                        effects.push(effect);
+                    }
+                }
+                refresh_team_coverage(state);
+            } else if suggested {
+                refresh_team_coverage(state);
+            }
+            if state.focus == FocusArea::Team {
+                for effect in suggestion_effects(state) {
+                    if !effects.contains(&effect) {
+                        effects.push(effect);
+                    }
+                }
+            }
             if effects.is_empty() {
                 DispatchResult::changed()
             } else {
@@ -314,16 +745,21 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
 
         Action::EvolutionDidLoad { id, chain } => {
             if let Some(name) = state.detail_name.as_ref() {
-                if let Some(index) = chain.stages.iter().position(|stage| stage == name) {
+                if let Some(index) = chain.position(name) {
                     state.evolution_selected_index = index;
                 }
             }
+            state.family_requests.remove(&id);
             state.evolution.insert(id, chain);
             state.evolution_loading = false;
+            if state.family_view {
+                relayout_rows(state);
+            }
             DispatchResult::changed()
         }
 
-        Action::EvolutionDidError { id: _, error } => {
+        Action::EvolutionDidError { id, error } => {
+            state.family_requests.remove(&id);
             state.evolution_loading = false;
             state.message = Some(format!("Evolution error: {error}"));
             DispatchResult::changed()
@@ -348,9 +784,12 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
         }
 
         Action::SpriteDidLoad { name, sprite } => {
+            // Team view loads sprites for members other than the one in the detail panel.
+            if state.detail_name.as_deref() == Some(&name) {
+                state.sprite_loading = false;
+                state.reset_sprite_animation();
+            }
             state.sprite_cache.insert(name, sprite);
-            state.sprite_loading = false;
-            state.reset_sprite_animation();
             DispatchResult::changed()
         }
 
@@ -360,46 +799,132 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
             DispatchResult::changed()
         }
 
-        Action::MoveDetailDidLoad(detail) => {
-            state.move_cache.insert(detail.name.clone(), detail);
-            DispatchResult::changed()
+        Action::PrefetchDidLoad { detail, sprite } => {
+            let name = detail.name.clone();
+            state.prefetching.remove(&name);
+            state.details.entry(name.clone()).or_insert(detail);
+            if let Some(sprite) = sprite {
+                state.sprite_cache.entry(name.clone()).or_insert(sprite);
+            }
+            if state.detail_name.as_deref() != Some(&name) {
+                return DispatchResult::changed();
+            }
+            // Selected while still in flight: finish what `select_detail` deferred.
+            state.detail_loading = false;
+            state.reset_sprite_animation();
+            let effects = detail_follow_up(state, &name);
+            if effects.is_empty() {
+                DispatchResult::changed()
+            } else {
+                DispatchResult::changed_with_many(effects)
+            }
         }
 
-        Action::MoveDetailDidError { name, error } => {
-            state.message = Some(format!("Move {name} error: {error}"));
+        Action::FeaturedDidLoad {
+            day,
+            species,
+            detail,
+            sprite,
+        } => {
+            let name = species.name.clone();
+            state.featured.trivia = Some(featured::trivia(&species, day));
+            state.featured.name = Some(name.clone());
+            // Primed like a prefetch, so jumping to it needs no further loads.
+            state.species.entry(name.clone()).or_insert(species);
+            state.details.entry(detail.name.clone()).or_insert(detail);
+            if let Some(sprite) = sprite {
+                state.sprite_cache.entry(name).or_insert(sprite);
+            }
             DispatchResult::changed()
         }
 
-        Action::AbilityDetailDidLoad(detail) => {
-            state.ability_cache.insert(detail.name.clone(), detail);
+        Action::FeaturedDidError(error) => {
+            state.featured.error = Some(error);
             DispatchResult::changed()
         }
 
-        Action::AbilityDetailDidError { name, error } => {
-            state.message = Some(format!("Ability {name} error: {error}"));
+        Action::FeaturedClose => {
+            if !state.featured.active {
+                return DispatchResult::unchanged();
+            }
+            state.featured.active = false;
             DispatchResult::changed()
         }
 
-        Action::EncounterDidLoad { name, encounters } => {
-            state.encounter_cache.insert(name.clone(), encounters);
-            state.encounter_loading = false;
-            if state.detail_name.as_deref() == Some(&name) {
-                normalize_encounter_filter(state, &name);
-                state.selected_encounter_index = 0;
+        Action::FeaturedJump => {
+            if !state.featured.active {
+                return DispatchResult::unchanged();
             }
-            DispatchResult::changed()
+            state.featured.active = false;
+            let Some(name) = state.featured.name.clone() else {
+                return DispatchResult::changed();
+            };
+            DispatchResult::changed_with_many(jump_to(state, &name))
         }
 
-        Action::EncounterDidError { name, error } => {
-            state.encounter_loading = false;
-            state.message = Some(format!("Encounters {name} error: {error}"));
-            DispatchResult::changed()
+        Action::PrefetchDidError { name, .. } => {
+            state.prefetching.remove(&name);
+            // Only worth reporting if someone is waiting on it; the regular load
+            // retries and surfaces its own error.
+            if state.detail_name.as_deref() != Some(&name) || state.details.contains_key(&name) {
+                return DispatchResult::unchanged();
+            }
+            DispatchResult::changed_with_many(detail_follow_up(state, &name))
         }
 
-        Action::TypeMatchupDidLoad { name, matchup } => {
+        Action::MoveDetailDidLoad(detail) => {
+            state.move_prefetching.remove(&detail.name);
+            state.move_cache.insert(detail.name.clone(), detail);
+            DispatchResult::changed()
+        }
+
+        Action::MovePrefetchDidError { name, error } => {
+            state.move_prefetching.remove(&name);
+            // The rest of the page just shows no PP; only the selected move
+            // is worth an error.
+            if state.current_move_name().as_deref() != Some(&name) {
+                return DispatchResult::changed();
+            }
+            state.message = Some(format!("Move {name} error: {error}"));
+            DispatchResult::changed()
+        }
+
+        Action::MoveDetailDidError { name, error } => {
+            state.message = Some(format!("Move {name} error: {error}"));
+            DispatchResult::changed()
+        }
+
+        Action::AbilityDetailDidLoad(detail) => {
+            state.ability_cache.insert(detail.name.clone(), detail);
+            DispatchResult::changed()
+        }
+
+        Action::AbilityDetailDidError { name, error } => {
+            state.message = Some(format!("Ability {name} error: {error}"));
+            DispatchResult::changed()
+        }
+
+        Action::EncounterDidLoad { name, encounters } => {
+            state.encounter_cache.insert(name.clone(), encounters);
+            state.encounter_loading = false;
+            if state.detail_name.as_deref() == Some(&name) {
+                normalize_encounter_filter(state, &name);
+                state.selected_encounter_index = 0;
+            }
+            DispatchResult::changed()
+        }
+
+        Action::EncounterDidError { name, error } => {
+            state.encounter_loading = false;
+            state.message = Some(format!("Encounters {name} error: {error}"));
+            DispatchResult::changed()
+        }
+
+        Action::TypeMatchupDidLoad { name, matchup } => {
             state.type_matchup_cache.insert(name, matchup);
             state.type_matchup_loading = current_matchup_loading(state);
-            DispatchResult::changed()
+            refresh_team_coverage(state);
+            team_suggestion_follow_up(state)
         }
 
         Action::TypeMatchupDidError { name, error } => {
@@ -455,6 +980,56 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
 
         Action::EncounterFilterPrev => cycle_encounter_filter(state, -1),
 
+        Action::MoveSortNext => {
+            state.move_sort = state.move_sort.next();
+            state.selected_move_index = 0;
+            detail_selection_effects(state)
+        }
+
+        Action::MoveTypeFilterNext => cycle_move_type_filter(state, 1),
+
+        Action::MoveTypeFilterPrev => cycle_move_type_filter(state, -1),
+
+        Action::StatsRadarToggle => {
+            state.stats_radar = !state.stats_radar;
+            DispatchResult::changed()
+        }
+
+        Action::LanguageChanged(language) => {
+            if state.language == language {
+                return DispatchResult::unchanged();
+            }
+            let label = LANGUAGES
+                .iter()
+                .find(|(code, _)| *code == language)
+                .map_or(language.as_str(), |(_, label)| label);
+            state.message = Some(format!("Flavor text in {label}."));
+            state.language = language;
+            // Cached species keep their old text until they are shown again;
+            // only the one on screen is refetched now.
+            match state.detail_name.clone() {
+                Some(name) if !species_is_current(state, &name) => {
+                    DispatchResult::changed_with(Effect::LoadPokemonSpecies {
+                        name,
+                        language: state.language.clone(),
+                    })
+                }
+                _ => DispatchResult::changed(),
+            }
+        }
+
+        Action::MatchupViewToggle => {
+            state.matchup_view = match state.matchup_view {
+                MatchupView::Pokemon => MatchupView::Team,
+                MatchupView::Team => MatchupView::Pokemon,
+            };
+            if state.matchup_view == MatchupView::Pokemon {
+                return DispatchResult::changed();
+            }
+            let effects = team_load_effects(state, coverage_effects);
+            DispatchResult::changed_with_many(effects)
+        }
+
         Action::ToggleFavorite => {
             let Some(name) = state.selected_name() else {
                 return DispatchResult::unchanged();
@@ -464,7 +1039,7 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
             } else {
                 state.favorites.insert(name);
             }
-            DispatchResult::changed()
+            DispatchResult::changed_with(save_user_data(state))
         }
 
         Action::ToggleTeam => {
@@ -473,35 +1048,729 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
             };
             if let Some(pos) = state.team.iter().position(|member| member == &name) {
                 state.team.remove(pos);
-                return DispatchResult::changed();
+                clamp_team_selection(state);
+                refresh_team_coverage(state);
+                return DispatchResult::changed_with(save_user_data(state));
             }
             if state.team.len() >= 6 {
                 state.message = Some("Team is full (6).".to_string());
                 return DispatchResult::changed();
             }
+            let mut effects = coverage_effects(state, &name);
             state.team.push(name);
+            refresh_team_coverage(state);
+            effects.push(save_user_data(state));
+            DispatchResult::changed_with_many(effects)
+        }
+
+        Action::TeamOpen => {
+            if state.search.active || state.region_picker.active || state.focus == FocusArea::Team {
+                return DispatchResult::unchanged();
+            }
+            state.focus = FocusArea::Team;
+            clamp_team_selection(state);
+            let mut effects = team_effects(state);
+            effects.extend(suggestion_effects(state));
+            if effects.is_empty() {
+                DispatchResult::changed()
+            } else {
+                DispatchResult::changed_with_many(effects)
+            }
+        }
+
+        Action::TeamClose => {
+            if state.focus != FocusArea::Team {
+                return DispatchResult::unchanged();
+            }
+            state.focus = FocusArea::DexList;
+            DispatchResult::changed()
+        }
+
+        Action::TeamSelect(index) => {
+            let index = index.min(state.team.len().saturating_sub(1));
+            if index == state.team_selected {
+                return DispatchResult::unchanged();
+            }
+            state.team_selected = index;
+            DispatchResult::changed()
+        }
+
+        Action::TeamReorder(delta) => {
+            let from = state.team_selected;
+            let to = clamp_index(from, state.team.len(), delta);
+            if from >= state.team.len() || to == from {
+                return DispatchResult::unchanged();
+            }
+            state.team.swap(from, to);
+            state.team_selected = to;
+            DispatchResult::changed_with(save_user_data(state))
+        }
+
+        Action::TeamRemove => {
+            if state.team_selected >= state.team.len() {
+                return DispatchResult::unchanged();
+            }
+            let removed = state.team.remove(state.team_selected);
+            clamp_team_selection(state);
+            refresh_team_coverage(state);
+            state.message = Some(format!("Removed {removed} from the team."));
+            DispatchResult::changed_with(save_user_data(state))
+        }
+
+        Action::BookmarkToggle => {
+            let Some(name) = state.detail_name.clone().or_else(|| state.selected_name()) else {
+                return DispatchResult::unchanged();
+            };
+            if let Some(pos) = state.bookmarks.iter().position(|mark| *mark == name) {
+                state.bookmarks.remove(pos);
+                state.message = Some(format!("Removed bookmark for {name}."));
+            } else {
+                state.message = Some(format!("Bookmarked {name}."));
+                state.bookmarks.push(name);
+            }
+            clamp_bookmark_selection(state);
+            DispatchResult::changed_with(save_user_data(state))
+        }
+
+        Action::BookmarksOpen => {
+            if state.bookmark_list.active
+                || state.sort_picker.active
+                || state.export_picker.active
+                || state.report_prompt.active
+                || state.note_editor.active
+                || state.region_picker.active
+            {
+                return DispatchResult::unchanged();
+            }
+            state.bookmark_list.active = true;
+            // Start on the Pokemon being shown when it is bookmarked.
+            state.bookmark_list.selected = state
+                .detail_name
+                .as_ref()
+                .and_then(|name| state.bookmarks.iter().position(|mark| mark == name))
+                .unwrap_or(0);
+            let effects = bookmark_sprite_effects(state);
+            if effects.is_empty() {
+                DispatchResult::changed()
+            } else {
+                DispatchResult::changed_with_many(effects)
+            }
+        }
+
+        Action::BookmarksClose => {
+            if !state.bookmark_list.active {
+                return DispatchResult::unchanged();
+            }
+            state.bookmark_list.active = false;
+            DispatchResult::changed()
+        }
+
+        Action::BookmarksMove(delta) => {
+            let next = clamp_index(state.bookmark_list.selected, state.bookmarks.len(), delta);
+            if next == state.bookmark_list.selected {
+                return DispatchResult::unchanged();
+            }
+            state.bookmark_list.selected = next;
+            DispatchResult::changed()
+        }
+
+        Action::BookmarkShow(name) => {
+            // Bookmarks off the current dex (another region, or filtered out)
+            // still open in the detail panel.
+            state.focus = FocusArea::DexList;
+            DispatchResult::changed_with_many(select_detail(state, &name))
+        }
+
+        Action::CompareWith(name) => {
+            if state.compare.as_deref() == Some(&name) {
+                return DispatchResult::unchanged();
+            }
+            state.compare = Some(name);
+            // Both sides load in parallel: the pin here, the current detail as usual.
+            let mut effects = compare_effects(state);
+            if let Some(current) = state.detail_name.clone() {
+                if !state.details.contains_key(&current) {
+                    for effect in detail_follow_up(state, &current) {
+                        if !effects.contains(&effect) {
+                            effects.push(effect);
+                        }
+                    }
+                }
+            }
+            if effects.is_empty() {
+                DispatchResult::changed()
+            } else {
+                DispatchResult::changed_with_many(effects)
+            }
+        }
+
+        Action::CompareClose => {
+            if state.compare.take().is_none() {
+                return DispatchResult::unchanged();
+            }
+            DispatchResult::changed()
+        }
+
+        Action::NotesDidLoad(notes) => {
+            // Keep anything edited while the file was still loading.
+            for (name, note) in notes {
+                state.notes.entry(name).or_insert(note);
+            }
+            DispatchResult::changed_with_many(refresh_note_search(state))
+        }
+
+        Action::NotesDidSave => DispatchResult::unchanged(),
+
+        Action::NotesDidError(error) => {
+            state.message = Some(format!("Notes error: {error}"));
+            DispatchResult::changed()
+        }
+
+        Action::NotesLoadDidError(error) => {
+            state.notes_load_failed = true;
+            state.message = Some(format!("Notes error: {error}; notes won't be saved"));
+            DispatchResult::changed()
+        }
+
+        Action::UserDataDidLoad {
+            favorites,
+            team,
+            bookmarks,
+        } => {
+            // Toggles made while the file was still loading win over it.
+            state.favorites.extend(favorites);
+            for name in team {
+                if state.team.len() < 6 && !state.team.contains(&name) {
+                    state.team.push(name);
+                }
+            }
+            let marked_early = std::mem::replace(&mut state.bookmarks, bookmarks);
+            for name in marked_early {
+                if !state.bookmarks.contains(&name) {
+                    state.bookmarks.push(name);
+                }
+            }
+            refresh_team_coverage(state);
+            DispatchResult::changed()
+        }
+
+        Action::UserDataDidSave => DispatchResult::unchanged(),
+
+        Action::UserDataDidError(error) => {
+            state.message = Some(format!("Favorites/team error: {error}"));
+            DispatchResult::changed()
+        }
+
+        Action::ExportOpen => {
+            if state.export_picker.active
+                || state.report_prompt.active
+                || state.note_editor.active
+                || state.region_picker.active
+            {
+                return DispatchResult::unchanged();
+            }
+            if state.current_detail().is_none() {
+                state.message = Some("Nothing to export until a Pokemon has loaded.".to_string());
+                return DispatchResult::changed();
+            }
+            state.export_picker.active = true;
+            DispatchResult::changed()
+        }
+
+        Action::ExportClose => {
+            if !state.export_picker.active {
+                return DispatchResult::unchanged();
+            }
+            state.export_picker.active = false;
+            DispatchResult::changed()
+        }
+
+        Action::ExportMove(delta) => {
+            let next = clamp_index(state.export_picker.selected, ExportFormat::ALL.len(), delta);
+            if next == state.export_picker.selected {
+                return DispatchResult::unchanged();
+            }
+            state.export_picker.selected = next;
+            DispatchResult::changed()
+        }
+
+        Action::ExportConfirm => {
+            if !state.export_picker.active {
+                return DispatchResult::unchanged();
+            }
+            state.export_picker.active = false;
+            let selected = state.export_picker.selected.min(ExportFormat::ALL.len() - 1);
+            let format = ExportFormat::ALL[selected];
+            let Some(detail) = state.current_detail() else {
+                return DispatchResult::changed();
+            };
+            let contents = export::render(format, detail, state.current_species());
+            let file_name = export::file_name(&detail.name, format);
+            DispatchResult::changed_with(Effect::WriteExport {
+                file_name,
+                contents,
+            })
+        }
+
+        Action::ExportDidWrite(path) => {
+            state.message = Some(format!("Exported to {path}"));
+            DispatchResult::changed()
+        }
+
+        Action::ExportDidError(error) => {
+            state.message = Some(format!("Export error: {error}"));
+            DispatchResult::changed()
+        }
+
+        Action::ReportOpen => {
+            if state.report_prompt.active
+                || state.export_picker.active
+                || state.note_editor.active
+                || state.region_picker.active
+            {
+                return DispatchResult::unchanged();
+            }
+            if state.pokedex_all.is_empty() {
+                state.message = Some("Nothing to report until the dex has loaded.".to_string());
+                return DispatchResult::changed();
+            }
+            state.report_prompt.active = true;
+            state.report_prompt.path = report::default_path(state);
+            DispatchResult::changed()
+        }
+
+        Action::ReportClose => {
+            if !state.report_prompt.active {
+                return DispatchResult::unchanged();
+            }
+            state.report_prompt.active = false;
+            DispatchResult::changed()
+        }
+
+        Action::ReportInput(ch) => {
+            state.report_prompt.path.push(ch);
+            DispatchResult::changed()
+        }
+
+        Action::ReportBackspace => {
+            if state.report_prompt.path.pop().is_none() {
+                return DispatchResult::unchanged();
+            }
+            DispatchResult::changed()
+        }
+
+        Action::ReportConfirm => {
+            let path = state.report_prompt.path.trim().to_string();
+            if !state.report_prompt.active || path.is_empty() {
+                return DispatchResult::unchanged();
+            }
+            state.report_prompt.active = false;
+            DispatchResult::changed_with(Effect::WriteReport {
+                path,
+                contents: report::render(state),
+            })
+        }
+
+        Action::ReportDidWrite(path) => {
+            state.message = Some(format!("Report saved to {path}"));
+            DispatchResult::changed()
+        }
+
+        Action::ReportDidError(error) => {
+            state.message = Some(format!("Report error: {error}"));
+            DispatchResult::changed()
+        }
+
+        Action::ClipboardCopy => {
+            let Some(detail) = state.current_detail() else {
+                state.message = Some("Nothing to copy until a Pokemon has loaded.".to_string());
+                return DispatchResult::changed();
+            };
+            DispatchResult::changed_with(Effect::CopyToClipboard {
+                name: detail.name.clone(),
+                text: export::summary(detail),
+            })
+        }
+
+        Action::ClipboardDidCopy(name) => {
+            state.message = Some(format!("Copied {name} to the clipboard"));
+            DispatchResult::changed()
+        }
+
+        Action::ClipboardDidError(error) => {
+            state.message = Some(format!("Clipboard error: {error}"));
+            DispatchResult::changed()
+        }
+
+        Action::NoteEditOpen => {
+            if state.note_editor.active || state.search.active || state.region_picker.active {
+                return DispatchResult::unchanged();
+            }
+            let name = if state.focus == FocusArea::Team {
+                state.team.get(state.team_selected).cloned()
+            } else {
+                state.detail_name.clone().or_else(|| state.selected_name())
+            };
+            let Some(name) = name else {
+                return DispatchResult::unchanged();
+            };
+            let text = state.notes.get(&name).cloned().unwrap_or_default();
+            state.note_editor.open(&name, &text);
+            DispatchResult::changed()
+        }
+
+        Action::NoteEditCancel => {
+            if !state.note_editor.active {
+                return DispatchResult::unchanged();
+            }
+            state.note_editor = NoteEditorState::default();
+            DispatchResult::changed()
+        }
+
+        Action::NoteEditSave => {
+            if !state.note_editor.active {
+                return DispatchResult::unchanged();
+            }
+            if state.notes_load_failed {
+                // Keep the editor open so the text isn't lost.
+                state.message =
+                    Some("Notes file couldn't be read; not saving over it.".to_string());
+                return DispatchResult::changed();
+            }
+            let editor = std::mem::take(&mut state.note_editor);
+            let text = editor.text();
+            if text.is_empty() {
+                state.notes.remove(&editor.name);
+                state.message = Some(format!("Cleared note for {}.", editor.name));
+            } else {
+                state.notes.insert(editor.name.clone(), text);
+                state.message = Some(format!("Saved note for {}.", editor.name));
+            }
+            let mut effects = vec![Effect::SaveNotes {
+                notes: state.notes.clone(),
+            }];
+            effects.extend(refresh_note_search(state));
+            DispatchResult::changed_with_many(effects)
+        }
+
+        Action::NoteEditInput(ch) => {
+            state.note_editor.insert(ch);
+            DispatchResult::changed()
+        }
+
+        Action::NoteEditNewline => {
+            state.note_editor.newline();
+            DispatchResult::changed()
+        }
+
+        Action::NoteEditBackspace => note_edit_result(state.note_editor.backspace()),
+
+        Action::NoteEditDelete => note_edit_result(state.note_editor.delete()),
+
+        Action::NoteEditMove { rows, cols } => {
+            note_edit_result(state.note_editor.move_cursor(rows, cols))
+        }
+
+        Action::NoteEditHome => note_edit_result(state.note_editor.home()),
+
+        Action::NoteEditEnd => note_edit_result(state.note_editor.end()),
+
+        Action::PlayCry => {
+            let Some(detail) = state.current_detail() else {
+                return DispatchResult::unchanged();
+            };
+            let Some(url) = detail.cries_latest.clone().or(detail.cries_legacy.clone()) else {
+                state.message = Some("No cry available.".to_string());
+                return DispatchResult::changed();
+            };
+            let name = detail.name.clone();
+            state.cry = CryState {
+                name: Some(name.clone()),
+                ..CryState::default()
+            };
+            DispatchResult::changed_with(Effect::PlayCry { name, url })
+        }
+
+        Action::CryLevel(level) => {
+            if state.cry.name.is_none() {
+                return DispatchResult::unchanged();
+            }
+            state.cry.push_level(level);
+            DispatchResult::changed()
+        }
+
+        Action::CryDidFinish => {
+            state.cry = CryState::default();
+            DispatchResult::changed()
+        }
+
+        Action::CryDidError(error) => {
+            state.cry = CryState::default();
+            state.message = Some(format!("Cry error: {error}"));
+            DispatchResult::changed()
+        }
+
+        Action::ItemsOpen => {
+            if state.search.active || state.region_picker.active || state.focus == FocusArea::Items {
+                return DispatchResult::unchanged();
+            }
+            state.focus = FocusArea::Items;
+            if state.items.names.is_empty() {
+                if state.items.list_loading {
+                    return DispatchResult::changed();
+                }
+                state.items.list_loading = true;
+                return DispatchResult::changed_with(Effect::LoadItemList);
+            }
+            item_selection_result(state)
+        }
+
+        Action::ItemsClose => {
+            if state.focus != FocusArea::Items {
+                return DispatchResult::unchanged();
+            }
+            state.focus = FocusArea::DexList;
+            state.items.searching = false;
+            DispatchResult::changed()
+        }
+
+        Action::ItemListDidLoad(names) => {
+            state.items.list_loading = false;
+            state.items.names = names;
+            state.items.selected = 0;
+            state.items.rebuild_filtered();
+            if state.focus != FocusArea::Items {
+                return DispatchResult::changed();
+            }
+            item_selection_result(state)
+        }
+
+        Action::ItemListDidError(error) => {
+            state.items.list_loading = false;
+            state.message = Some(format!("Item list error: {error}"));
+            DispatchResult::changed()
+        }
+
+        Action::ItemDetailDidLoad(detail) => {
+            if state.items.detail_loading.as_deref() == Some(&detail.name) {
+                state.items.detail_loading = None;
+            }
+            let name = detail.name.clone();
+            state.items.details.insert(name.clone(), detail);
+            if state.items.selected_name() != Some(&name) {
+                return DispatchResult::changed();
+            }
+            item_selection_result(state)
+        }
+
+        Action::ItemDetailDidError { name, error } => {
+            if state.items.detail_loading.as_deref() == Some(&name) {
+                state.items.detail_loading = None;
+            }
+            state.message = Some(format!("Item {name} error: {error}"));
+            DispatchResult::changed()
+        }
+
+        Action::ItemSelect(index) => {
+            let index = index.min(state.items.filtered.len().saturating_sub(1));
+            if index == state.items.selected {
+                return DispatchResult::unchanged();
+            }
+            state.items.selected = index;
+            item_selection_result(state)
+        }
+
+        Action::ItemMove(delta) => {
+            let next = clamp_index(state.items.selected, state.items.filtered.len(), delta);
+            if next == state.items.selected {
+                return DispatchResult::unchanged();
+            }
+            state.items.selected = next;
+            item_selection_result(state)
+        }
+
+        Action::ItemSearchStart => {
+            if state.items.searching {
+                return DispatchResult::unchanged();
+            }
+            state.items.searching = true;
+            DispatchResult::changed()
+        }
+
+        Action::ItemSearchInput(ch) => {
+            if !state.items.searching {
+                return DispatchResult::unchanged();
+            }
+            state.items.query.push(ch);
+            state.items.selected = 0;
+            state.items.rebuild_filtered();
+            item_selection_result(state)
+        }
+
+        Action::ItemSearchBackspace => {
+            if !state.items.searching || state.items.query.pop().is_none() {
+                return DispatchResult::unchanged();
+            }
+            state.items.selected = 0;
+            state.items.rebuild_filtered();
+            item_selection_result(state)
+        }
+
+        Action::ItemSearchSubmit => {
+            if !state.items.searching {
+                return DispatchResult::unchanged();
+            }
+            state.items.searching = false;
+            DispatchResult::changed()
+        }
+
+        Action::ItemSearchCancel => {
+            if !state.items.searching {
+                return DispatchResult::unchanged();
+            }
+            state.items.searching = false;
+            state.items.query.clear();
+            state.items.rebuild_filtered();
+            item_selection_result(state)
+        }
+
+        Action::LocationsOpen => {
+            if state.search.active
+                || state.region_picker.active
+                || state.focus == FocusArea::Locations
+            {
+                return DispatchResult::unchanged();
+            }
+            let region = match state.current_region() {
+                Some(region) if region.region != NATIONAL_DEX => region.region.clone(),
+                _ => {
+                    state.message = Some("Locations need a regional dex (press r)".to_string());
+                    return DispatchResult::changed();
+                }
+            };
+            state.focus = FocusArea::Locations;
+            if state.locations.region.as_deref() == Some(&region) {
+                return location_selection_result(state);
+            }
+            state.locations.region = Some(region.clone());
+            state.locations.names.clear();
+            state.locations.selected = 0;
+            state.locations.pokemon_selected = 0;
+            state.locations.pane = LocationPane::Locations;
+            state.locations.list_loading = true;
+            DispatchResult::changed_with(Effect::LoadLocations { region })
+        }
+
+        Action::LocationsClose => {
+            if state.focus != FocusArea::Locations {
+                return DispatchResult::unchanged();
+            }
+            state.focus = FocusArea::DexList;
+            DispatchResult::changed()
+        }
+
+        Action::LocationsDidLoad { region, names } => {
+            if state.locations.region.as_deref() != Some(&region) {
+                return DispatchResult::unchanged();
+            }
+            state.locations.list_loading = false;
+            state.locations.names = names;
+            state.locations.selected = 0;
+            state.locations.pokemon_selected = 0;
+            if state.focus != FocusArea::Locations {
+                return DispatchResult::changed();
+            }
+            location_selection_result(state)
+        }
+
+        Action::LocationsDidError(error) => {
+            state.locations.list_loading = false;
+            state.locations.region = None;
+            state.message = Some(format!("Locations error: {error}"));
+            DispatchResult::changed()
+        }
+
+        Action::LocationEncountersDidLoad { name, encounters } => {
+            if state.locations.encounters_loading.as_deref() == Some(&name) {
+                state.locations.encounters_loading = None;
+            }
+            state.locations.encounters.insert(name, encounters);
+            DispatchResult::changed()
+        }
+
+        Action::LocationEncountersDidError { name, error } => {
+            if state.locations.encounters_loading.as_deref() == Some(&name) {
+                state.locations.encounters_loading = None;
+            }
+            state.message = Some(format!("Location {name} error: {error}"));
+            DispatchResult::changed()
+        }
+
+        Action::LocationSelect(index) => {
+            let index = index.min(state.locations.names.len().saturating_sub(1));
+            if index == state.locations.selected {
+                return DispatchResult::unchanged();
+            }
+            state.locations.selected = index;
+            state.locations.pokemon_selected = 0;
+            location_selection_result(state)
+        }
+
+        Action::LocationMove(delta) => {
+            let next = clamp_index(state.locations.selected, state.locations.names.len(), delta);
+            if next == state.locations.selected {
+                return DispatchResult::unchanged();
+            }
+            state.locations.selected = next;
+            state.locations.pokemon_selected = 0;
+            location_selection_result(state)
+        }
+
+        Action::LocationPaneToggle => {
+            state.locations.pane = match state.locations.pane {
+                LocationPane::Locations => LocationPane::Pokemon,
+                LocationPane::Pokemon => LocationPane::Locations,
+            };
             DispatchResult::changed()
         }
 
-        Action::PlayCry => {
-            let Some(detail) = state.current_detail() else {
+        Action::LocationPokemonSelect(index) => {
+            let len = state.locations.selected_encounters().map_or(0, <[_]>::len);
+            let index = index.min(len.saturating_sub(1));
+            if index == state.locations.pokemon_selected {
                 return DispatchResult::unchanged();
-            };
-            let Some(url) = detail.cries_latest.clone().or(detail.cries_legacy.clone()) else {
-                state.message = Some("No cry available.".to_string());
-                return DispatchResult::changed();
-            };
-            DispatchResult::changed_with(Effect::PlayCry {
-                name: detail.name.clone(),
-                url,
-            })
+            }
+            state.locations.pokemon_selected = index;
+            DispatchResult::changed()
         }
 
-        Action::CryDidError(error) => {
-            state.message = Some(format!("Cry error: {error}"));
+        Action::LocationPokemonMove(delta) => {
+            let len = state.locations.selected_encounters().map_or(0, <[_]>::len);
+            let next = clamp_index(state.locations.pokemon_selected, len, delta);
+            if next == state.locations.pokemon_selected {
+                return DispatchResult::unchanged();
+            }
+            state.locations.pokemon_selected = next;
             DispatchResult::changed()
         }
 
+        Action::LocationShowPokemon => {
+            let Some(name) = state.locations.selected_pokemon().map(|entry| entry.pokemon.clone())
+            else {
+                return DispatchResult::unchanged();
+            };
+            state.focus = FocusArea::DetailTabs;
+            // Selecting resets the detail tab, so switch to encounters afterwards.
+            let mut effects = select_detail(state, &name);
+            state.detail_mode = crate::state::DetailMode::Encounter;
+            effects.extend(detail_encounter_effects(state, &name));
+            if effects.is_empty() {
+                DispatchResult::changed()
+            } else {
+                DispatchResult::changed_with_many(effects)
+            }
+        }
+
         Action::UiTerminalResize(width, height) => {
             if state.terminal_size != (width, height) {
                 state.terminal_size = (width, height);
@@ -517,6 +1786,18 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
     }
 }
 
+/// Fetch a type's members when the search query names it, so the search
+/// can match pokemon whose details haven't loaded yet.
+fn search_type_effects(state: &AppState) -> Vec<Effect> {
+    let query = state.search.query.trim().to_lowercase();
+    match state.type_list.iter().find(|name| **name == query) {
+        Some(name) if !state.type_cache.contains_key(name) => {
+            vec![Effect::LoadTypeDetail { name: name.clone() }]
+        }
+        _ => Vec::new(),
+    }
+}
+
 fn cycle_filter(state: &mut AppState, step: i16) -> DispatchResult<Effect> {
     if state.type_list.is_empty() {
         return DispatchResult::unchanged();
@@ -562,22 +1843,73 @@ fn cycle_filter(state: &mut AppState, step: i16) -> DispatchResult<Effect> {
     DispatchResult::changed_with_many(vec![Effect::LoadTypeDetail { name: next_type }])
 }
 
-fn cycle_region(state: &mut AppState, step: i16) -> DispatchResult<Effect> {
-    if state.regions.is_empty() {
+/// Bulk load of the base stats the current sort is still missing. Replaces any
+/// load in flight, since the new batch covers its names too.
+fn base_stats_effect(state: &mut AppState) -> Option<Effect> {
+    let names = state.missing_base_stats();
+    if names.is_empty() {
+        return None;
+    }
+    state.base_stats_loading = true;
+    Some(Effect::LoadBaseStats { names })
+}
+
+/// Re-sorts the dex list, keeping the cursor on the same species.
+fn resort_dex(state: &mut AppState) {
+    let selected = state.selected_name();
+    state.rebuild_filtered();
+    if let Some(index) = selected.and_then(|name| state.row_position(&name)) {
+        state.selected_index = index;
+    }
+}
+
+/// Steps through "all" and each generation present in the loaded dex.
+fn cycle_generation_filter(state: &mut AppState, step: i16) -> DispatchResult<Effect> {
+    let generations = state.dex_generations();
+    if generations.is_empty() {
+        if state.species_index_loading {
+            state.message = Some("Generations load with the species index...".to_string());
+            return DispatchResult::changed();
+        }
         return DispatchResult::unchanged();
     }
-    let len = state.regions.len() as i16;
-    let mut next = state.region_index as i16 + step;
-    if next < 0 {
-        next = len - 1;
-    } else if next >= len {
-        next = 0;
+
+    let slots = generations.len() as i16 + 1;
+    let current = state
+        .generation_filter
+        .and_then(|generation| generations.iter().position(|&known| known == generation))
+        .map(|idx| idx as i16 + 1)
+        .unwrap_or(0);
+    let next = (current + step).rem_euclid(slots);
+    state.generation_filter = (next > 0).then(|| generations[(next - 1) as usize]);
+    state.rebuild_filtered();
+    let effects = select_current(state);
+    DispatchResult::changed_with_many(effects)
+}
+
+/// Loads the pokedex `--region` named, or the default one with a note when
+/// no pokedex goes by that name.
+fn open_deep_link_region(state: &mut AppState, name: &str) -> DispatchResult<Effect> {
+    let found = state.find_region(name);
+    let result = select_region(state, found.unwrap_or(state.region_index));
+    if found.is_none() {
+        let showing = state
+            .current_region()
+            .map(|region| region.label.clone())
+            .unwrap_or_default();
+        state.message = Some(format!("No pokedex called `{name}`; showing {showing}."));
     }
-    let next_index = next as usize;
-    if next_index == state.region_index {
+    result
+}
+
+fn select_region(state: &mut AppState, index: usize) -> DispatchResult<Effect> {
+    if index >= state.regions.len() {
         return DispatchResult::unchanged();
     }
-    state.region_index = next_index;
+    if index == state.region_index && !state.pokedex_all.is_empty() {
+        return DispatchResult::changed();
+    }
+    state.region_index = index;
     state.search.active = false;
     state.search.query.clear();
     state.detail_name = None;
@@ -586,17 +1918,159 @@ fn cycle_region(state: &mut AppState, step: i16) -> DispatchResult<Effect> {
     state.pokedex.clear();
     state.pokedex_all.clear();
     state.filtered_indices.clear();
+    state.dex_rows.clear();
     state.species_index_loading = true;
     state.evolution_selected_index = 0;
     state.reset_sprite_animation();
     state.reset_detail_selection();
     state.message = None;
-    if let Some(region) = state.current_region() {
-        return DispatchResult::changed_with(Effect::LoadPokedex {
-            name: region.name.clone(),
+    state.dex_paging = None;
+    let Some(name) = state.current_region().map(|region| region.name.clone()) else {
+        return DispatchResult::changed();
+    };
+    if name == NATIONAL_DEX {
+        state.dex_paging = Some(DexPaging {
+            total: None,
+            loading: Some(0),
+        });
+        return DispatchResult::changed_with(Effect::LoadPokedexPage {
+            offset: 0,
+            limit: DEX_PAGE_SIZE,
         });
     }
-    DispatchResult::changed()
+    DispatchResult::changed_with(Effect::LoadPokedex { name })
+}
+
+/// Select the dex row under the cursor and fetch more of a paged dex if it is near the end.
+fn select_dex_row(state: &mut AppState) -> Vec<Effect> {
+    let mut effects = select_current(state);
+    effects.extend(next_dex_page(state));
+    effects.extend(family_chain_effects(state));
+    effects
+}
+
+/// Chains for family heads near the selection, each requested once. Only the
+/// family view needs them, so nothing is fetched while it is off.
+fn family_chain_effects(state: &mut AppState) -> Vec<Effect> {
+    if !state.family_view {
+        return Vec::new();
+    }
+    let start = state.selected_index.saturating_sub(FAMILY_PREFETCH);
+    let end = (state.selected_index + FAMILY_PREFETCH + 1).min(state.dex_rows.len());
+    let mut effects = Vec::new();
+    for row in &state.dex_rows[start..end] {
+        let DexRow::Head(idx) = row else {
+            continue;
+        };
+        let Some(species) = state.pokedex.get(*idx).and_then(|entry| state.species.get(&entry.name))
+        else {
+            continue;
+        };
+        let Some(url) = species.evolution_chain_url.clone() else {
+            continue;
+        };
+        let id = evolution_id_from_url(&url);
+        if state.evolution.contains_key(&id) || !state.family_requests.insert(id.clone()) {
+            continue;
+        }
+        effects.push(Effect::LoadEvolutionChain { id, url });
+    }
+    effects
+}
+
+/// The selected head, or the head above a selected family member.
+fn selected_family_head(state: &AppState) -> Option<String> {
+    let end = (state.selected_index + 1).min(state.dex_rows.len());
+    state.dex_rows[..end].iter().rev().find_map(|row| match row {
+        DexRow::Head(idx) => state.pokedex.get(*idx).map(|entry| entry.name.clone()),
+        DexRow::Member { .. } => None,
+    })
+}
+
+/// Re-lays the dex rows, keeping the cursor on the same species when it is
+/// still listed and on its family head when its family was folded.
+fn relayout_rows(state: &mut AppState) {
+    let selected = state.selected_name();
+    let head = selected_family_head(state);
+    state.rebuild_rows();
+    let index = selected
+        .and_then(|name| state.row_position(&name))
+        .or_else(|| head.and_then(|name| state.row_position(&name)))
+        .unwrap_or(0);
+    state.selected_index = index.min(state.dex_rows.len().saturating_sub(1));
+}
+
+fn next_dex_page(state: &mut AppState) -> Option<Effect> {
+    let below = state.dex_rows.len().saturating_sub(state.selected_index + 1);
+    if below > DEX_PAGE_PREFETCH {
+        return None;
+    }
+    let offset = state.next_dex_page()?;
+    state.dex_paging.as_mut()?.loading = Some(offset);
+    Some(Effect::LoadPokedexPage {
+        offset,
+        limit: DEX_PAGE_SIZE,
+    })
+}
+
+/// After the visible dex is rebuilt, keep the previous pick if it is still listed.
+/// A paged dex may have grown underneath the cursor, so its page is finished here.
+fn reselect_after_rebuild(
+    state: &mut AppState,
+    previous: Option<String>,
+) -> DispatchResult<Effect> {
+    if let Some(paging) = state.dex_paging.as_mut() {
+        paging.loading = None;
+    }
+    if let Some(name) = state.deep_link.pokemon.take() {
+        let effects = jump_to(state, &name);
+        if effects.is_empty() {
+            return DispatchResult::changed();
+        }
+        return DispatchResult::changed_with_many(effects);
+    }
+    let kept = previous.and_then(|name| state.row_position(&name));
+    let mut effects = match kept {
+        Some(index) => {
+            state.selected_index = index;
+            Vec::new()
+        }
+        None => {
+            state.selected_index = 0;
+            select_current(state)
+        }
+    };
+    effects.extend(next_dex_page(state));
+    effects.extend(family_chain_effects(state));
+    if effects.is_empty() {
+        DispatchResult::changed()
+    } else {
+        DispatchResult::changed_with_many(effects)
+    }
+}
+
+/// Selects `name` in the dex list. Off the current dex (another region, or
+/// filtered out) it still opens in the detail panel.
+fn jump_to(state: &mut AppState, name: &str) -> Vec<Effect> {
+    state.focus = FocusArea::DexList;
+    match state.row_position(name) {
+        Some(index) => {
+            state.set_selected_index(index);
+            select_dex_row(state)
+        }
+        None => select_detail(state, name),
+    }
+}
+
+fn region_dex_effects(state: &AppState) -> Vec<Effect> {
+    state
+        .regions
+        .iter()
+        .filter(|region| !state.region_dex_members.contains_key(&region.name))
+        .map(|region| Effect::LoadRegionDex {
+            name: region.name.clone(),
+        })
+        .collect()
 }
 
 fn select_current(state: &mut AppState) -> Vec<Effect> {
@@ -604,7 +2078,33 @@ fn select_current(state: &mut AppState) -> Vec<Effect> {
         state.detail_name = None;
         return Vec::new();
     };
-    select_detail(state, &name)
+    let mut effects = select_detail(state, &name);
+    effects.extend(prefetch_effect(state));
+    effects
+}
+
+/// Background loads for uncached rows around the selection, nearest first, so
+/// stepping through the dex finds them ready. Each name is requested once.
+fn prefetch_effect(state: &mut AppState) -> Option<Effect> {
+    let mut names = Vec::new();
+    for distance in 1..=DETAIL_PREFETCH {
+        let below = Some(state.selected_index + distance);
+        let above = state.selected_index.checked_sub(distance);
+        for index in [below, above].into_iter().flatten() {
+            let Some(name) = state
+                .dex_rows
+                .get(index)
+                .and_then(|row| state.row_entry(row))
+                .map(|entry| entry.name.clone())
+            else {
+                continue;
+            };
+            if !state.details.contains_key(&name) && state.prefetching.insert(name.clone()) {
+                names.push(name);
+            }
+        }
+    }
+    (!names.is_empty()).then_some(Effect::PrefetchPokemon { names })
 }
 
 fn select_detail(state: &mut AppState, name: &str) -> Vec<Effect> {
@@ -629,13 +2129,12 @@ fn tick_animation(state: &mut AppState) -> DispatchResult<Effect> {
     let Some(sprite) = state.sprite_cache.get(name) else {
         return DispatchResult::unchanged();
     };
-    if sprite.frames.len() <= 1 {
-        return DispatchResult::unchanged();
-    }
     const FRAME_STEP: u64 = 1;
-    state.sprite_frame_tick = state.sprite_frame_tick.wrapping_add(1);
-    if state.sprite_frame_tick % FRAME_STEP == 0 {
-        state.sprite_frame_index = (state.sprite_frame_index + 1) % sprite.frames.len();
+    if sprite.advance(
+        &mut state.sprite_frame_index,
+        &mut state.sprite_frame_tick,
+        FRAME_STEP,
+    ) {
         return DispatchResult::changed();
     }
     DispatchResult::unchanged()
@@ -645,15 +2144,20 @@ fn detail_follow_up(state: &mut AppState, name: &str) -> Vec<Effect> {
     let mut effects = Vec::new();
     if !state.details.contains_key(name) {
         state.detail_loading = true;
+        // A prefetch already on its way finishes the job when it lands.
+        if state.prefetching.contains(name) {
+            return effects;
+        }
         effects.push(Effect::LoadPokemonDetail {
             name: name.to_string(),
         });
         return effects;
     }
 
-    if !state.species.contains_key(name) {
+    if !species_is_current(state, name) {
         effects.push(Effect::LoadPokemonSpecies {
             name: name.to_string(),
+            language: state.language.clone(),
         });
     }
 
@@ -684,26 +2188,292 @@ fn detail_follow_up(state: &mut AppState, name: &str) -> Vec<Effect> {
     effects
 }
 
-fn evolution_stage_name(state: &AppState, index: usize) -> Option<String> {
-    let chain = current_evolution_chain(state)?;
-    chain.stages.get(index).cloned()
+fn note_edit_result(changed: bool) -> DispatchResult<Effect> {
+    if changed {
+        DispatchResult::changed()
+    } else {
+        DispatchResult::unchanged()
+    }
+}
+
+/// Re-runs a `note:` search so results follow note edits.
+fn refresh_note_search(state: &mut AppState) -> Vec<Effect> {
+    if !state
+        .search
+        .query
+        .trim()
+        .to_lowercase()
+        .starts_with("note:")
+    {
+        return Vec::new();
+    }
+    state.rebuild_filtered();
+    select_current(state)
+}
+
+/// Whether `name`'s species is cached in the chosen language.
+fn species_is_current(state: &AppState, name: &str) -> bool {
+    state
+        .species
+        .get(name)
+        .is_some_and(|species| species.language == state.language)
 }
 
-fn current_evolution_chain(state: &AppState) -> Option<&crate::state::EvolutionChain> {
-    let species = state.current_species()?;
-    let url = species.evolution_chain_url.as_ref()?;
-    let id = evolution_id_from_url(url);
-    state.evolution.get(&id)
+fn save_user_data(state: &AppState) -> Effect {
+    Effect::SaveUserData {
+        favorites: state.favorites.clone(),
+        team: state.team.clone(),
+        bookmarks: state.bookmarks.clone(),
+    }
+}
+
+fn clamp_bookmark_selection(state: &mut AppState) {
+    state.bookmark_list.selected = state
+        .bookmark_list
+        .selected
+        .min(state.bookmarks.len().saturating_sub(1));
+}
+
+/// Sprites the jump list can't draw yet: fetched directly where the detail
+/// is known, prefetched along with it where it isn't.
+fn bookmark_sprite_effects(state: &mut AppState) -> Vec<Effect> {
+    let mut effects = Vec::new();
+    let mut names = Vec::new();
+    for name in state.bookmarks.clone() {
+        if state.details.contains_key(&name) {
+            effects.extend(sprite_effect(state, &name));
+        } else if state.prefetching.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+    if !names.is_empty() {
+        effects.push(Effect::PrefetchPokemon { names });
+    }
+    effects
+}
+
+fn clamp_team_selection(state: &mut AppState) {
+    state.team_selected = state.team_selected.min(state.team.len().saturating_sub(1));
+}
+
+/// Detail, then sprite, for the selected item; only the latest detail request is tracked.
+fn item_selection_result(state: &mut AppState) -> DispatchResult<Effect> {
+    let Some(name) = state.items.selected_name().map(str::to_string) else {
+        return DispatchResult::changed();
+    };
+    let Some(detail) = state.items.details.get(&name) else {
+        if state.items.detail_loading.as_deref() == Some(&name) {
+            return DispatchResult::changed();
+        }
+        state.items.detail_loading = Some(name.clone());
+        return DispatchResult::changed_with(Effect::LoadItemDetail { name });
+    };
+    let key = item_sprite_key(&name);
+    match &detail.sprite_url {
+        Some(url) if !state.sprite_cache.contains_key(&key) => {
+            DispatchResult::changed_with(Effect::LoadSprite {
+                name: key,
+                url: url.clone(),
+            })
+        }
+        _ => DispatchResult::changed(),
+    }
+}
+
+/// Encounters for the selected location, unless cached or already in flight.
+fn location_selection_result(state: &mut AppState) -> DispatchResult<Effect> {
+    let Some(name) = state.locations.selected_name().map(str::to_string) else {
+        return DispatchResult::changed();
+    };
+    if state.locations.encounters.contains_key(&name)
+        || state.locations.encounters_loading.as_deref() == Some(&name)
+    {
+        return DispatchResult::changed();
+    }
+    state.locations.encounters_loading = Some(name.clone());
+    DispatchResult::changed_with(Effect::LoadLocationEncounters { name })
+}
+
+/// Everything the team view needs that isn't cached yet.
+fn team_effects(state: &AppState) -> Vec<Effect> {
+    team_load_effects(state, team_member_effects)
+}
+
+/// Details for members not loaded yet, plus `member_effects` for the rest.
+fn team_load_effects(
+    state: &AppState,
+    member_effects: fn(&AppState, &str) -> Vec<Effect>,
+) -> Vec<Effect> {
+    let mut effects = Vec::new();
+    for name in state.team.clone() {
+        if !state.details.contains_key(&name) {
+            effects.push(Effect::LoadPokemonDetail { name });
+            continue;
+        }
+        for effect in member_effects(state, &name) {
+            if !effects.contains(&effect) {
+                effects.push(effect);
+            }
+        }
+    }
+    effects
+}
+
+/// Sprite and type matchups for a team member whose detail is loaded.
+fn team_member_effects(state: &AppState, name: &str) -> Vec<Effect> {
+    if !state.details.contains_key(name) {
+        return Vec::new();
+    }
+    let mut effects: Vec<Effect> = sprite_effect(state, name).into_iter().collect();
+    effects.extend(coverage_effects(state, name));
+    effects
+}
+
+/// Sprite fetch for a loaded Pokemon that isn't in the sprite cache yet.
+fn sprite_effect(state: &AppState, name: &str) -> Option<Effect> {
+    if state.sprite_cache.contains_key(name) {
+        return None;
+    }
+    let detail = state.details.get(name)?;
+    let url = detail
+        .sprite_animated
+        .clone()
+        .or(detail.sprite_front_default.clone())?;
+    Some(Effect::LoadSprite {
+        name: name.to_string(),
+        url,
+    })
+}
+
+/// Detail, then sprite, for the pinned compare Pokemon.
+fn compare_effects(state: &AppState) -> Vec<Effect> {
+    let Some(name) = state.compare.as_deref() else {
+        return Vec::new();
+    };
+    if !state.details.contains_key(name) {
+        return vec![Effect::LoadPokemonDetail {
+            name: name.to_string(),
+        }];
+    }
+    sprite_effect(state, name).into_iter().collect()
+}
+
+/// Loads the damage calculator is waiting on: the defender's detail and the
+/// selected move's type matchups.
+fn calc_follow_up(state: &AppState) -> DispatchResult<Effect> {
+    let mut effects = Vec::new();
+    if let Some(name) = state.calc.defender.as_ref() {
+        if !state.details.contains_key(name) {
+            effects.push(Effect::LoadPokemonDetail { name: name.clone() });
+        }
+    }
+    if let Some(move_type) =
+        calc::selected_move(state).and_then(|summary| summary.move_type.clone())
+    {
+        if !state.type_matchup_cache.contains_key(&move_type) {
+            effects.push(Effect::LoadTypeMatchup { name: move_type });
+        }
+    }
+    if effects.is_empty() {
+        DispatchResult::changed()
+    } else {
+        DispatchResult::changed_with_many(effects)
+    }
+}
+
+/// Type matchups still missing for a team member's coverage.
+fn coverage_effects(state: &AppState, name: &str) -> Vec<Effect> {
+    let Some(detail) = state.details.get(name) else {
+        return Vec::new();
+    };
+    detail
+        .types
+        .iter()
+        .filter(|type_name| !state.type_matchup_cache.contains_key(*type_name))
+        .map(|type_name| Effect::LoadTypeMatchup {
+            name: type_name.clone(),
+        })
+        .collect()
+}
+
+fn refresh_team_coverage(state: &mut AppState) {
+    state.team_coverage = coverage::team_coverage(state);
+    state.team_suggestions = state
+        .team_coverage
+        .as_ref()
+        .map(|coverage| suggest::suggestions(state, coverage))
+        .unwrap_or_default();
+}
+
+/// Loads suggestions still need while the team view is open: matchups for the
+/// team's weaknesses, members of the types resisting them, then the shortlist's
+/// details and matchups. Each load is only ever requested once.
+fn suggestion_effects(state: &mut AppState) -> Vec<Effect> {
+    let Some(coverage) = state.team_coverage.clone() else {
+        return Vec::new();
+    };
+    let mut effects = Vec::new();
+    for exposure in coverage.weaknesses() {
+        match suggest::resisting_types(state, &exposure.attacker) {
+            Some(types) => effects.extend(
+                types
+                    .into_iter()
+                    .filter(|type_name| !state.type_cache.contains_key(type_name))
+                    .map(|name| Effect::LoadTypeDetail { name }),
+            ),
+            None => effects.push(Effect::LoadTypeMatchup {
+                name: exposure.attacker.clone(),
+            }),
+        }
+    }
+    for name in suggest::shortlist(state, &coverage) {
+        if state.details.contains_key(&name) {
+            effects.extend(coverage_effects(state, &name));
+        } else {
+            effects.push(Effect::LoadPokemonDetail { name });
+        }
+    }
+    effects.retain(|effect| {
+        suggestion_request_key(effect)
+            .is_some_and(|key| state.suggestion_requests.insert(key))
+    });
+    effects
+}
+
+fn suggestion_request_key(effect: &Effect) -> Option<String> {
+    match effect {
+        Effect::LoadTypeMatchup { name } => Some(format!("matchup:{name}")),
+        Effect::LoadTypeDetail { name } => Some(format!("type:{name}")),
+        Effect::LoadPokemonDetail { name } => Some(format!("pokemon:{name}")),
+        _ => None,
+    }
+}
+
+fn team_suggestion_follow_up(state: &mut AppState) -> DispatchResult<Effect> {
+    if state.focus != FocusArea::Team {
+        return DispatchResult::changed();
+    }
+    let effects = suggestion_effects(state);
+    if effects.is_empty() {
+        DispatchResult::changed()
+    } else {
+        DispatchResult::changed_with_many(effects)
+    }
+}
+
+fn evolution_stage_name(state: &AppState, index: usize) -> Option<String> {
+    let chain = state.current_evolution()?;
+    chain.stages.get(index).map(|stage| stage.name.clone())
 }
 
 fn sync_evolution_selection(state: &mut AppState) {
     let Some(name) = state.detail_name.as_ref() else {
         return;
     };
-    let Some(chain) = current_evolution_chain(state) else {
+    let Some(chain) = state.current_evolution() else {
         return;
     };
-    if let Some(index) = chain.stages.iter().position(|stage| stage == name) {
+    if let Some(index) = chain.position(name) {
         state.evolution_selected_index = index;
     }
 }
@@ -719,6 +2489,7 @@ fn detail_selection_effects(state: &mut AppState) -> DispatchResult<Effect> {
             detail_move_effects(state, detail),
         )
     };
+    effects.extend(move_page_effect(state));
     effects.extend(detail_encounter_effects(state, &detail_name));
     effects.extend(detail_matchup_effects(state, &detail_types));
     if effects.is_empty() {
@@ -734,10 +2505,11 @@ fn advance_detail(state: &mut AppState, delta: i16) -> bool {
     };
     match state.detail_mode {
         crate::state::DetailMode::Move => {
-            if detail.moves.is_empty() {
+            let count = state.visible_moves().len();
+            if count == 0 {
                 return false;
             }
-            let new_index = clamp_index(state.selected_move_index, detail.moves.len(), delta);
+            let new_index = clamp_index(state.selected_move_index, count, delta);
             if new_index == state.selected_move_index {
                 return false;
             }
@@ -781,13 +2553,11 @@ fn advance_detail(state: &mut AppState, delta: i16) -> bool {
 }
 
 fn select_move_index(state: &mut AppState, index: usize) -> bool {
-    let Some(detail) = state.current_detail() else {
-        return false;
-    };
-    if detail.moves.is_empty() {
+    let count = state.visible_moves().len();
+    if count == 0 {
         return false;
     }
-    let bounded = index.min(detail.moves.len().saturating_sub(1));
+    let bounded = index.min(count - 1);
     if bounded == state.selected_move_index {
         return false;
     }
@@ -872,11 +2642,11 @@ fn detail_move_effects(state: &AppState, detail: &crate::state::PokemonDetail) -
     let mut effects = Vec::new();
     match state.detail_mode {
         crate::state::DetailMode::Move => {
-            if let Some(move_name) = detail.moves.get(state.selected_move_index) {
-                if !state.move_cache.contains_key(move_name) {
-                    effects.push(Effect::LoadMoveDetail {
-                        name: move_name.clone(),
-                    });
+            if let Some(move_name) = state.current_move_name() {
+                if !state.move_cache.contains_key(&move_name)
+                    && !state.move_prefetching.contains(&move_name)
+                {
+                    effects.push(Effect::LoadMoveDetail { name: move_name });
                 }
             }
         }
@@ -896,6 +2666,31 @@ fn detail_move_effects(state: &AppState, detail: &crate::state::PokemonDetail) -
     effects
 }
 
+/// Details for the rest of the selected move's page, so the table's PP column
+/// fills in a page at a time. The selected move has its own request.
+fn move_page_effect(state: &mut AppState) -> Option<Effect> {
+    if state.detail_mode != crate::state::DetailMode::Move {
+        return None;
+    }
+    let page = state.selected_move_index / MOVE_PAGE_SIZE;
+    let selected = state.current_move_name();
+    let page_names: Vec<String> = state
+        .visible_moves()
+        .into_iter()
+        .skip(page * MOVE_PAGE_SIZE)
+        .take(MOVE_PAGE_SIZE)
+        .map(|summary| summary.name.clone())
+        .filter(|name| Some(name) != selected.as_ref())
+        .collect();
+    let mut names = Vec::new();
+    for name in page_names {
+        if !state.move_cache.contains_key(&name) && state.move_prefetching.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+    (!names.is_empty()).then_some(Effect::PrefetchMoves { names })
+}
+
 fn detail_encounter_effects(state: &mut AppState, detail_name: &str) -> Vec<Effect> {
     if state.detail_mode != crate::state::DetailMode::Encounter {
         return Vec::new();
@@ -980,6 +2775,33 @@ fn cycle_encounter_filter(state: &mut AppState, step: i16) -> DispatchResult<Eff
     DispatchResult::changed()
 }
 
+fn cycle_move_type_filter(state: &mut AppState, step: i16) -> DispatchResult<Effect> {
+    let types = state.move_types();
+    if types.is_empty() {
+        return DispatchResult::unchanged();
+    }
+    let current_index = state
+        .move_type_filter
+        .as_ref()
+        .and_then(|move_type| types.iter().position(|item| item == move_type))
+        .map(|idx| idx as i16 + 1)
+        .unwrap_or(0);
+    let max_index = types.len() as i16;
+    let mut next = current_index + step;
+    if next < 0 {
+        next = max_index;
+    } else if next > max_index {
+        next = 0;
+    }
+    state.move_type_filter = if next == 0 {
+        None
+    } else {
+        Some(types[(next - 1) as usize].clone())
+    };
+    state.selected_move_index = 0;
+    detail_selection_effects(state)
+}
+
 fn normalize_encounter_filter(state: &mut AppState, name: &str) {
     let Some(filter) = state.encounter_version_filter.as_ref() else {
         return;
@@ -0,0 +1,119 @@
+//! Hold-to-accelerate for the dex list.
+//!
+//! Terminals report a held key as a stream of presses: one, a pause while
+//! the OS repeat delay runs out, then a press every few dozen milliseconds.
+//! [`KeyRepeat`] watches those gaps to tell a hold from taps, and once a
+//! move key has been held long enough the list steps several rows per press.
+
+use std::time::{Duration, Instant};
+
+use crossterm::event::KeyCode;
+
+/// Longest pause between the first press and the first repeat; covers the
+/// usual OS repeat delays of 250-600ms.
+const FIRST_REPEAT_GAP: Duration = Duration::from_millis(650);
+/// Longest pause between repeats once they're flowing. Taps are slower.
+const REPEAT_GAP: Duration = Duration::from_millis(150);
+/// Held this long, each press skips [`FAST_STEP`] rows.
+const FAST_AFTER: Duration = Duration::from_millis(500);
+const FAST_STEP: u16 = 3;
+/// Held this long, each press skips [`FASTER_STEP`] rows.
+const FASTER_AFTER: Duration = Duration::from_millis(1500);
+const FASTER_STEP: u16 = 10;
+
+#[derive(Clone, Copy, Debug)]
+struct Hold {
+    code: KeyCode,
+    started: Instant,
+    last: Instant,
+    presses: u32,
+}
+
+/// Tracks the key being held, if any.
+#[derive(Debug, Default)]
+pub struct KeyRepeat {
+    hold: Option<Hold>,
+}
+
+impl KeyRepeat {
+    /// Records a press of `code` at `now` and returns how many rows it moves.
+    pub fn press(&mut self, code: KeyCode, now: Instant) -> u16 {
+        let continues = self.hold.is_some_and(|hold| {
+            let gap = now.saturating_duration_since(hold.last);
+            let limit = if hold.presses == 1 {
+                FIRST_REPEAT_GAP
+            } else {
+                REPEAT_GAP
+            };
+            hold.code == code && gap <= limit
+        });
+        let hold = match self.hold {
+            Some(hold) if continues => Hold {
+                last: now,
+                presses: hold.presses + 1,
+                ..hold
+            },
+            _ => Hold {
+                code,
+                started: now,
+                last: now,
+                presses: 1,
+            },
+        };
+        self.hold = Some(hold);
+
+        let held = now.saturating_duration_since(hold.started);
+        if held >= FASTER_AFTER {
+            FASTER_STEP
+        } else if held >= FAST_AFTER {
+            FAST_STEP
+        } else {
+            1
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Presses `code` as a held key would: one press, the OS delay, then a
+    /// repeat every 30ms until `until` has passed. Returns each press's step.
+    fn hold(repeat: &mut KeyRepeat, code: KeyCode, start: Instant, until: u64) -> Vec<u16> {
+        let mut steps = vec![repeat.press(code, start)];
+        let mut at = 400;
+        while at <= until {
+            steps.push(repeat.press(code, start + Duration::from_millis(at)));
+            at += 30;
+        }
+        steps
+    }
+
+    #[test]
+    fn holding_speeds_up_to_three_then_ten_rows() {
+        let mut repeat = KeyRepeat::default();
+        let steps = hold(&mut repeat, KeyCode::Char('j'), Instant::now(), 2000);
+        assert_eq!(steps[0], 1);
+        assert_eq!(steps[1], 1);
+        assert!(steps.contains(&FAST_STEP));
+        assert_eq!(steps.last(), Some(&FASTER_STEP));
+        let first_fast = steps.iter().position(|step| *step == FAST_STEP).unwrap();
+        let first_faster = steps.iter().position(|step| *step == FASTER_STEP).unwrap();
+        assert!(first_fast < first_faster);
+    }
+
+    #[test]
+    fn taps_and_a_different_key_start_over() {
+        let mut repeat = KeyRepeat::default();
+        let start = Instant::now();
+        for tap in 0..10 {
+            let at = start + Duration::from_millis(tap * 250);
+            assert_eq!(repeat.press(KeyCode::Down, at), 1);
+        }
+
+        let start = start + Duration::from_secs(10);
+        hold(&mut repeat, KeyCode::Down, start, 1000);
+        let switched = start + Duration::from_millis(1020);
+        assert_eq!(repeat.press(KeyCode::Up, switched), 1);
+    }
+}
@@ -0,0 +1,189 @@
+//! Region completion report: the loaded dex with what has been seen and
+//! caught, the favorites among it, and the team, as Markdown to share.
+
+use crate::export::title;
+use crate::state::AppState;
+
+/// Where the report goes unless the prompt is edited, in the working directory.
+pub fn default_path(state: &AppState) -> String {
+    let region = state
+        .current_region()
+        .map(|region| region.name.as_str())
+        .unwrap_or("pokedex");
+    format!("{region}-report.md")
+}
+
+pub fn render(state: &AppState) -> String {
+    let region = state
+        .current_region()
+        .map(|region| region.label.clone())
+        .unwrap_or_else(|| "Pokedex".to_string());
+    let entries = &state.pokedex_all;
+    let total = state
+        .dex_paging
+        .as_ref()
+        .and_then(|paging| paging.total)
+        .unwrap_or(entries.len());
+    let seen = entries
+        .iter()
+        .filter(|entry| state.seen.contains(&entry.name))
+        .count();
+    let caught: Vec<&str> = entries
+        .iter()
+        .filter(|entry| state.favorites.contains(&entry.name))
+        .map(|entry| entry.name.as_str())
+        .collect();
+
+    let mut out = format!("# {region} completion report\n\n");
+    out.push_str(&format!("- **Seen:** {}\n", progress(seen, total)));
+    out.push_str(&format!(
+        "- **Caught:** {}\n",
+        progress(caught.len(), total)
+    ));
+
+    out.push_str("\n## Pokedex\n\n");
+    if entries.len() < total {
+        out.push_str(&format!(
+            "*First {} entries, as far as the list has loaded.*\n\n",
+            entries.len()
+        ));
+    }
+    out.push_str("| # | Pokemon | Seen | Caught |\n| ---: | --- | :---: | :---: |\n");
+    for entry in entries {
+        out.push_str(&format!(
+            "| {:03} | {} | {} | {} |\n",
+            entry.entry_number,
+            title(&entry.name),
+            check(state.seen.contains(&entry.name)),
+            check(state.favorites.contains(&entry.name))
+        ));
+    }
+
+    out.push_str("\n## Favorites\n\n");
+    if caught.is_empty() {
+        out.push_str("None yet.\n");
+    }
+    for name in &caught {
+        out.push_str(&format!("- {}\n", title(name)));
+    }
+
+    out.push_str("\n## Team\n\n");
+    if state.team.is_empty() {
+        out.push_str("Empty.\n");
+    }
+    for name in &state.team {
+        // Sprites are only known for members whose details have loaded.
+        let sprite = state
+            .details
+            .get(name)
+            .and_then(|detail| detail.sprite_front_default.as_ref());
+        match sprite {
+            Some(url) => out.push_str(&format!("- [{}]({url})\n", title(name))),
+            None => out.push_str(&format!("- {}\n", title(name))),
+        }
+    }
+    out
+}
+
+/// `12 / 151 (7%)`.
+fn progress(count: usize, total: usize) -> String {
+    let percent = count * 100 / total.max(1);
+    format!("{count} / {total} ({percent}%)")
+}
+
+fn check(yes: bool) -> &'static str {
+    if yes {
+        "✓"
+    } else {
+        ""
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{PokedexEntry, PokemonDetail, RegionInfo};
+
+    fn entry(number: u16, name: &str) -> PokedexEntry {
+        PokedexEntry {
+            entry_number: number,
+            name: name.to_string(),
+            url: format!("https://pokeapi.co/api/v2/pokemon-species/{number}/"),
+        }
+    }
+
+    fn state() -> AppState {
+        AppState {
+            regions: vec![RegionInfo {
+                name: "kanto".to_string(),
+                label: "Kanto".to_string(),
+                region: "kanto".to_string(),
+            }],
+            pokedex_all: vec![
+                entry(1, "bulbasaur"),
+                entry(4, "charmander"),
+                entry(122, "mr-mime"),
+            ],
+            seen: ["bulbasaur", "mr-mime"].map(String::from).into(),
+            favorites: ["mr-mime".to_string()].into(),
+            ..AppState::default()
+        }
+    }
+
+    #[test]
+    fn counts_and_table_follow_seen_and_favorites() {
+        let report = render(&state());
+        assert!(report.starts_with("# Kanto completion report\n\n"));
+        assert!(report.contains("- **Seen:** 2 / 3 (66%)\n- **Caught:** 1 / 3 (33%)\n"));
+        assert!(report.contains("| 001 | Bulbasaur | ✓ |  |\n"));
+        assert!(report.contains("| 004 | Charmander |  |  |\n"));
+        assert!(report.contains("| 122 | Mr Mime | ✓ | ✓ |\n"));
+        assert!(report.contains("## Favorites\n\n- Mr Mime\n"));
+        assert!(report.contains("## Team\n\nEmpty.\n"));
+        assert!(!report.contains("as far as the list has loaded"));
+        assert_eq!(default_path(&state()), "kanto-report.md");
+    }
+
+    #[test]
+    fn team_links_loaded_sprites() {
+        let mut state = state();
+        state.team = vec!["bulbasaur".to_string(), "charmander".to_string()];
+        state.details.insert(
+            "bulbasaur".to_string(),
+            PokemonDetail {
+                id: 1,
+                name: "bulbasaur".to_string(),
+                types: vec!["grass".to_string()],
+                stats: Vec::new(),
+                abilities: Vec::new(),
+                moves: Vec::new(),
+                height: 7,
+                weight: 69,
+                sprite_front_default: Some("https://example.test/1.png".to_string()),
+                sprite_front_shiny: None,
+                sprite_animated: None,
+                cries_latest: None,
+                cries_legacy: None,
+            },
+        );
+        let report = render(&state);
+        assert!(
+            report.contains("## Team\n\n- [Bulbasaur](https://example.test/1.png)\n- Charmander\n")
+        );
+    }
+
+    #[test]
+    fn a_partly_loaded_dex_says_so() {
+        let mut state = state();
+        state.regions.clear();
+        state.dex_paging = Some(crate::state::DexPaging {
+            total: Some(1025),
+            ..Default::default()
+        });
+        let report = render(&state);
+        assert!(report.starts_with("# Pokedex completion report"));
+        assert!(report.contains("- **Seen:** 2 / 1025 (0%)\n"));
+        assert!(report.contains("*First 3 entries, as far as the list has loaded.*"));
+        assert_eq!(default_path(&state), "pokedex-report.md");
+    }
+}
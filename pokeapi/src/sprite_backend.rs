@@ -1,4 +1,4 @@
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::io::{self, Write};
 use std::sync::{Arc, Mutex, OnceLock};
 
@@ -6,22 +6,153 @@ use crossterm::{cursor::MoveTo, queue, style::Print};
 use ratatui::backend::{Backend, ClearType, CrosstermBackend, WindowSize};
 use ratatui::buffer::Cell;
 use ratatui::layout::{Position, Size};
+use ratatui::style::Color;
+
+use sprite_cache::{HalfBlock, SpriteFrame};
+
+/// Override for the detected sprite renderer: `kitty`, `sixel` or `halfblock`.
+const PROTOCOL_ENV: &str = "POKEAPI_SPRITES";
+/// Cell size assumed for sixel scaling when the terminal won't report pixels.
+const FALLBACK_CELL_PIXELS: (u16, u16) = (10, 20);
+
+/// How sprites reach the terminal, best first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GraphicsProtocol {
+    Kitty,
+    Sixel,
+    /// `▀` cells colored with the top and bottom pixel; works in any truecolor terminal.
+    HalfBlock,
+}
+
+impl GraphicsProtocol {
+    fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "kitty" => Some(Self::Kitty),
+            "sixel" => Some(Self::Sixel),
+            "halfblock" | "blocks" => Some(Self::HalfBlock),
+            _ => None,
+        }
+    }
+}
+
+/// The renderer for this terminal, detected once from the environment.
+pub fn protocol() -> GraphicsProtocol {
+    static PROTOCOL: OnceLock<GraphicsProtocol> = OnceLock::new();
+    *PROTOCOL.get_or_init(|| {
+        let var = |name: &str| std::env::var(name).unwrap_or_default().to_ascii_lowercase();
+        GraphicsProtocol::from_name(&var(PROTOCOL_ENV)).unwrap_or_else(|| {
+            protocol_for(
+                &var("TERM"),
+                &var("TERM_PROGRAM"),
+                std::env::var_os("KITTY_WINDOW_ID").is_some(),
+            )
+        })
+    })
+}
+
+/// Terminals are matched by name: asking the terminal itself would mean reading
+/// a reply off stdin before the event loop owns it.
+fn protocol_for(term: &str, term_program: &str, kitty_window: bool) -> GraphicsProtocol {
+    const KITTY_TERMS: [&str; 2] = ["kitty", "ghostty"];
+    const KITTY_PROGRAMS: [&str; 2] = ["ghostty", "wezterm"];
+    const SIXEL_TERMS: [&str; 4] = ["foot", "mlterm", "contour", "yaft"];
+    const SIXEL_PROGRAMS: [&str; 2] = ["iterm.app", "mintty"];
+
+    if kitty_window
+        || KITTY_TERMS.iter().any(|name| term.contains(name))
+        || KITTY_PROGRAMS.contains(&term_program)
+    {
+        GraphicsProtocol::Kitty
+    } else if SIXEL_TERMS.iter().any(|name| term.contains(name))
+        || SIXEL_PROGRAMS.contains(&term_program)
+    {
+        GraphicsProtocol::Sixel
+    } else {
+        GraphicsProtocol::HalfBlock
+    }
+}
+
+/// A sprite ready to hand to the backend, in whatever form the protocol needs.
+#[derive(Debug, Clone, PartialEq)]
+pub enum SpriteImage {
+    /// Encoded by the backend when it's placed, so the placement gets an id
+    /// it can be deleted by on its own.
+    Kitty {
+        frame: SpriteFrame,
+        cols: u16,
+        rows: u16,
+    },
+    /// Printed as-is at the sprite's top-left cell.
+    Escape { data: String, cols: u16, rows: u16 },
+    /// Drawn as ordinary cells, row-major, `cols` wide.
+    HalfBlocks { cells: Vec<HalfBlock>, cols: u16 },
+}
+
+impl SpriteImage {
+    fn size(&self) -> (u16, u16) {
+        match self {
+            SpriteImage::Kitty { cols, rows, .. } | SpriteImage::Escape { cols, rows, .. } => {
+                (*cols, *rows)
+            }
+            SpriteImage::HalfBlocks { cells, cols } => {
+                let rows = cells.len() / usize::from((*cols).max(1));
+                (*cols, rows as u16)
+            }
+        }
+    }
+}
+
+/// Encodes `frame` to fill `cols` x `rows` cells with the detected protocol.
+pub fn encode_frame(frame: &SpriteFrame, cols: u16, rows: u16) -> Result<SpriteImage, String> {
+    match protocol() {
+        GraphicsProtocol::Kitty => Ok(SpriteImage::Kitty {
+            frame: frame.clone(),
+            cols,
+            rows,
+        }),
+        GraphicsProtocol::Sixel => {
+            let (cell_width, cell_height) = cell_pixels();
+            let data = sprite_cache::sixel_sequence(
+                frame,
+                u32::from(cols) * u32::from(cell_width),
+                u32::from(rows) * u32::from(cell_height),
+            )?;
+            Ok(SpriteImage::Escape { data, cols, rows })
+        }
+        GraphicsProtocol::HalfBlock => Ok(SpriteImage::HalfBlocks {
+            cells: sprite_cache::halfblock_cells(frame, cols, rows)?,
+            cols,
+        }),
+    }
+}
+
+fn cell_pixels() -> (u16, u16) {
+    static CELL: OnceLock<(u16, u16)> = OnceLock::new();
+    *CELL.get_or_init(|| {
+        crossterm::terminal::window_size()
+            .ok()
+            .filter(|size| size.width > 0 && size.columns > 0 && size.rows > 0)
+            .map(|size| (size.width / size.columns, size.height / size.rows))
+            .filter(|(width, height)| *width > 0 && *height > 0)
+            .unwrap_or(FALLBACK_CELL_PIXELS)
+    })
+}
 
 #[derive(Default, Debug, Clone)]
 pub struct SpriteRegistry {
-    sprites: HashMap<(u16, u16), String>,
+    sprites: HashMap<(u16, u16), SpriteImage>,
 }
 
 impl SpriteRegistry {
-    pub fn set(&mut self, x: u16, y: u16, data: String) {
-        self.sprites.insert((x, y), data);
+    pub fn set(&mut self, x: u16, y: u16, image: SpriteImage) {
+        self.sprites.insert((x, y), image);
     }
 
     pub fn clear(&mut self) {
         self.sprites.clear();
     }
 
-    pub fn entries(&self) -> Vec<((u16, u16), String)> {
+    pub fn entries(&self) -> Vec<((u16, u16), SpriteImage)> {
         self.sprites
             .iter()
             .map(|(pos, data)| (*pos, data.clone()))
@@ -37,11 +168,20 @@ pub fn sprite_registry() -> Arc<Mutex<SpriteRegistry>> {
         .clone()
 }
 
-pub fn update_sprite(x: u16, y: u16, data: String) {
+pub fn update_sprite(x: u16, y: u16, image: SpriteImage) {
+    let registry = sprite_registry();
+    let mut registry = registry.lock().expect("sprite registry lock");
+    registry.clear();
+    registry.set(x, y, image);
+}
+
+pub fn update_sprites(sprites: Vec<((u16, u16), SpriteImage)>) {
     let registry = sprite_registry();
     let mut registry = registry.lock().expect("sprite registry lock");
     registry.clear();
-    registry.set(x, y, data);
+    for ((x, y), image) in sprites {
+        registry.set(x, y, image);
+    }
 }
 
 pub fn clear_sprites() {
@@ -50,44 +190,178 @@ pub fn clear_sprites() {
     registry.clear();
 }
 
+/// A sprite as last sent to the terminal.
+#[derive(Debug, Clone)]
+struct Placement {
+    image: SpriteImage,
+    /// Kitty image id, 0 for the other protocols.
+    id: u32,
+}
+
+/// Draws ratatui's cells, then brings the sprites on screen in line with the
+/// registry. Only sprites that changed are sent again: a new kitty image is
+/// placed before the one it replaces is deleted, so nothing blinks out in
+/// between, and unchanged ones cost nothing per frame.
 #[derive(Debug, Clone)]
 pub struct SpriteBackend<W: Write> {
     inner: CrosstermBackend<W>,
     registry: Arc<Mutex<SpriteRegistry>>,
-    had_sprite: bool,
+    protocol: GraphicsProtocol,
+    /// What the terminal is showing, by top-left cell.
+    placed: HashMap<(u16, u16), Placement>,
+    next_id: u32,
+    /// Every cell ratatui has drawn. Sixel and half-block sprites paint over
+    /// real cells that ratatui doesn't know changed, so when a sprite moves or
+    /// goes away the backend restores them from here.
+    shadow: HashMap<(u16, u16), Cell>,
+    /// Cells under the sprites drawn last frame, for non-kitty protocols.
+    covered: HashSet<(u16, u16)>,
 }
 
 impl<W: Write> SpriteBackend<W> {
     pub fn new(writer: W, registry: Arc<Mutex<SpriteRegistry>>) -> Self {
+        Self::with_protocol(writer, registry, protocol())
+    }
+
+    pub fn with_protocol(
+        writer: W,
+        registry: Arc<Mutex<SpriteRegistry>>,
+        protocol: GraphicsProtocol,
+    ) -> Self {
         Self {
             inner: CrosstermBackend::new(writer),
             registry,
-            had_sprite: false,
+            protocol,
+            placed: HashMap::new(),
+            next_id: 1,
+            shadow: HashMap::new(),
+            covered: HashSet::new(),
+        }
+    }
+
+    /// Half-block cells at `(x, y)`. Fully transparent cells keep whatever
+    /// ratatui put there; half-transparent ones borrow its background.
+    fn halfblock_cells(
+        &self,
+        x: u16,
+        y: u16,
+        cells: &[HalfBlock],
+        cols: u16,
+    ) -> Vec<(u16, u16, Cell)> {
+        let mut drawn = Vec::with_capacity(cells.len());
+        for (index, [top, bottom]) in cells.iter().enumerate() {
+            let pos = (
+                x + (index % usize::from(cols.max(1))) as u16,
+                y + (index / usize::from(cols.max(1))) as u16,
+            );
+            let under = self.shadow.get(&pos).cloned().unwrap_or_default();
+            let rgb = |(r, g, b): (u8, u8, u8)| Color::Rgb(r, g, b);
+            let mut cell = under.clone();
+            match (top, bottom) {
+                (None, None) => {}
+                (Some(top), Some(bottom)) => {
+                    cell.set_symbol("▀").set_fg(rgb(*top)).set_bg(rgb(*bottom));
+                }
+                (Some(top), None) => {
+                    cell.set_symbol("▀").set_fg(rgb(*top)).set_bg(under.bg);
+                }
+                (None, Some(bottom)) => {
+                    cell.set_symbol("▄").set_fg(rgb(*bottom)).set_bg(under.bg);
+                }
+            }
+            drawn.push((pos.0, pos.1, cell));
+        }
+        drawn
+    }
+
+    fn place(&mut self, (x, y): (u16, u16), image: &SpriteImage) -> io::Result<u32> {
+        match image {
+            SpriteImage::Kitty { frame, cols, rows } => {
+                let id = self.next_id;
+                self.next_id = self.next_id.checked_add(1).unwrap_or(1);
+                // Base64 payloads always chunk cleanly, so this doesn't fail in practice.
+                if let Ok(data) = sprite_cache::kitty_sequence(frame, *cols, *rows, Some(id)) {
+                    queue!(self.inner, MoveTo(x, y), Print(data))?;
+                }
+                Ok(id)
+            }
+            SpriteImage::Escape { data, .. } => {
+                queue!(self.inner, MoveTo(x, y), Print(data))?;
+                Ok(0)
+            }
+            SpriteImage::HalfBlocks { cells, cols } => {
+                let drawn = self.halfblock_cells(x, y, cells, *cols);
+                self.inner
+                    .draw(drawn.iter().map(|(x, y, cell)| (*x, *y, cell)))?;
+                Ok(0)
+            }
         }
     }
 }
 
+/// Cells a sprite at `(x, y)` covers.
+fn footprint((x, y): (u16, u16), image: &SpriteImage) -> impl Iterator<Item = (u16, u16)> {
+    let (cols, rows) = image.size();
+    (0..rows).flat_map(move |row| (0..cols).map(move |col| (x + col, y + row)))
+}
+
 impl<W: Write> Backend for SpriteBackend<W> {
     fn draw<'a, I>(&mut self, content: I) -> io::Result<()>
     where
         I: Iterator<Item = (u16, u16, &'a Cell)>,
     {
-        self.inner.draw(content)?;
-        let sprites = {
+        let content: Vec<(u16, u16, &Cell)> = content.collect();
+        let drawn: HashSet<(u16, u16)> = content.iter().map(|(x, y, _)| (*x, *y)).collect();
+        for (x, y, cell) in &content {
+            self.shadow.insert((*x, *y), (*cell).clone());
+        }
+        self.inner.draw(content.into_iter())?;
+        let sprites: HashMap<(u16, u16), SpriteImage> = {
             let registry = self.registry.lock().expect("sprite registry lock");
-            registry.entries()
+            registry.entries().into_iter().collect()
         };
-        if self.had_sprite {
-            queue!(self.inner, Print("\x1b_Ga=d,d=a\x1b\\"))?;
+        let kitty = self.protocol == GraphicsProtocol::Kitty;
+
+        // Put back what last frame's sprites covered, minus what this frame's cover again.
+        let mut covered = HashSet::new();
+        if !kitty {
+            for (pos, image) in &sprites {
+                covered.extend(footprint(*pos, image));
+            }
         }
-        if sprites.is_empty() {
-            self.had_sprite = false;
-            return Ok(());
+        let stale: Vec<(u16, u16, &Cell)> = self
+            .covered
+            .difference(&covered)
+            .filter_map(|pos| self.shadow.get(pos).map(|cell| (pos.0, pos.1, cell)))
+            .collect();
+        self.inner.draw(stale.into_iter())?;
+        self.covered = covered;
+
+        let mut previous = std::mem::take(&mut self.placed);
+        for (pos, image) in sprites {
+            let unchanged = previous
+                .get(&pos)
+                .is_some_and(|placement| placement.image == image);
+            // Kitty images float above the text; the others are the text, so
+            // ratatui drawing under one means it has to be painted again.
+            let damaged = !kitty && footprint(pos, &image).any(|cell| drawn.contains(&cell));
+            if unchanged && !damaged {
+                let placement = previous.remove(&pos).expect("unchanged placement");
+                self.placed.insert(pos, placement);
+                continue;
+            }
+            let id = self.place(pos, &image)?;
+            self.placed.insert(pos, Placement { image, id });
         }
-        for ((x, y), data) in sprites {
-            queue!(self.inner, MoveTo(x, y), Print(data))?;
+        // Whatever is left was replaced or removed; the replacements are
+        // already up, so deleting now never leaves a gap.
+        if kitty {
+            let mut gone: Vec<u32> = previous.values().map(|placement| placement.id).collect();
+            gone.sort_unstable();
+            for id in gone {
+                queue!(self.inner, Print(sprite_cache::kitty::delete_image(id)))?;
+            }
         }
-        self.had_sprite = true;
         Ok(())
     }
 
@@ -112,6 +386,12 @@ impl<W: Write> Backend for SpriteBackend<W> {
     }
 
     fn clear(&mut self) -> io::Result<()> {
+        self.shadow.clear();
+        self.covered.clear();
+        if self.protocol == GraphicsProtocol::Kitty && !self.placed.is_empty() {
+            queue!(self.inner, Print(sprite_cache::kitty::delete_all()))?;
+        }
+        self.placed.clear();
         self.inner.clear()
     }
 
@@ -141,3 +421,154 @@ impl<W: Write> Write for SpriteBackend<W> {
         Write::flush(&mut self.inner)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ratatui::layout::Rect;
+    use ratatui::widgets::Paragraph;
+    use ratatui::{Terminal, TerminalOptions, Viewport};
+    use sprite_cache::frame::FORMAT_RGBA;
+
+    /// Everything the backend writes, kept for the test to read back.
+    #[derive(Clone, Default)]
+    struct Capture(Arc<Mutex<Vec<u8>>>);
+
+    impl Write for Capture {
+        fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+            self.0.lock().unwrap().extend_from_slice(buf);
+            Ok(buf.len())
+        }
+
+        fn flush(&mut self) -> io::Result<()> {
+            Ok(())
+        }
+    }
+
+    struct Screen {
+        terminal: Terminal<SpriteBackend<Capture>>,
+        output: Capture,
+        registry: Arc<Mutex<SpriteRegistry>>,
+    }
+
+    impl Screen {
+        fn new(protocol: GraphicsProtocol) -> Self {
+            let output = Capture::default();
+            let registry = Arc::new(Mutex::new(SpriteRegistry::default()));
+            let backend = SpriteBackend::with_protocol(output.clone(), registry.clone(), protocol);
+            let options = TerminalOptions {
+                viewport: Viewport::Fixed(Rect::new(0, 0, 20, 4)),
+            };
+            let terminal = Terminal::with_options(backend, options).unwrap();
+            Self {
+                terminal,
+                output,
+                registry,
+            }
+        }
+
+        fn sprites(&self) -> std::sync::MutexGuard<'_, SpriteRegistry> {
+            self.registry.lock().unwrap()
+        }
+
+        /// Draws `text` with the registry as it stands and returns what reached the terminal.
+        fn frame(&mut self, text: &str) -> String {
+            self.terminal
+                .draw(|frame| frame.render_widget(Paragraph::new(text), frame.area()))
+                .unwrap();
+            let written = std::mem::take(&mut *self.output.0.lock().unwrap());
+            String::from_utf8(written).unwrap()
+        }
+    }
+
+    fn kitty_image(payload: &str) -> SpriteImage {
+        let frame = SpriteFrame {
+            payload: payload.to_string(),
+            width: 1,
+            height: 1,
+            format: FORMAT_RGBA,
+        };
+        SpriteImage::Kitty {
+            frame,
+            cols: 2,
+            rows: 1,
+        }
+    }
+
+    #[test]
+    fn kitty_sprites_are_only_sent_when_they_change() {
+        let mut screen = Screen::new(GraphicsProtocol::Kitty);
+        screen.sprites().set(1, 1, kitty_image("AAAA"));
+        let first = screen.frame("bulbasaur");
+        assert!(first.contains("a=T,t=d,i=1,"));
+        assert!(!first.contains("a=d"));
+
+        let idle = screen.frame("bulbasaur");
+        assert!(!idle.contains("\x1b_G"), "nothing to resend: {idle:?}");
+        let text_only = screen.frame("ivysaur");
+        assert!(!text_only.contains("\x1b_G"));
+
+        screen.sprites().set(1, 1, kitty_image("BBBB"));
+        let swapped = screen.frame("ivysaur");
+        let placed = swapped.find("i=2,").expect("new placement");
+        let deleted = swapped
+            .find(&sprite_cache::kitty::delete_image(1))
+            .expect("old deleted");
+        assert!(placed < deleted, "the replacement goes up first");
+
+        screen.sprites().clear();
+        let gone = screen.frame("ivysaur");
+        assert_eq!(gone.matches("\x1b_G").count(), 1);
+        assert!(gone.contains(&sprite_cache::kitty::delete_image(2)));
+    }
+
+    #[test]
+    fn halfblocks_are_repainted_when_text_is_drawn_under_them() {
+        let mut screen = Screen::new(GraphicsProtocol::HalfBlock);
+        let cells = vec![[Some((255, 0, 0)), Some((0, 0, 255))]; 2];
+        screen
+            .sprites()
+            .set(0, 0, SpriteImage::HalfBlocks { cells, cols: 2 });
+        assert!(screen.frame("").contains('▀'));
+        assert!(!screen.frame("").contains('▀'));
+        // Text under the sprite overwrites it, so the sprite goes back on top.
+        let redrawn = screen.frame("pikachu");
+        assert!(redrawn.contains('▀'));
+        let shadow = &screen.terminal.backend().shadow;
+        assert_eq!(shadow.get(&(0, 0)).map(Cell::symbol), Some("p"));
+
+        screen.sprites().clear();
+        let restored = screen.frame("pikachu");
+        assert!(!restored.contains('▀'));
+        assert!(restored.contains('p'));
+    }
+
+    #[test]
+    fn protocol_follows_the_terminal() {
+        assert_eq!(
+            protocol_for("xterm-kitty", "", false),
+            GraphicsProtocol::Kitty
+        );
+        assert_eq!(
+            protocol_for("xterm-256color", "ghostty", false),
+            GraphicsProtocol::Kitty
+        );
+        assert_eq!(
+            protocol_for("xterm-256color", "", true),
+            GraphicsProtocol::Kitty
+        );
+        assert_eq!(protocol_for("foot", "", false), GraphicsProtocol::Sixel);
+        assert_eq!(
+            protocol_for("xterm-256color", "iterm.app", false),
+            GraphicsProtocol::Sixel
+        );
+        assert_eq!(
+            protocol_for("xterm-256color", "apple_terminal", false),
+            GraphicsProtocol::HalfBlock
+        );
+        assert_eq!(
+            GraphicsProtocol::from_name("blocks"),
+            Some(GraphicsProtocol::HalfBlock)
+        );
+    }
+}
@@ -1,13 +1,369 @@
 use serde::{Deserialize, Serialize};
+use sprite_cache::SpriteData;
 use tui_dispatch_debug::debug::{DebugSection, DebugState, ron_string};
 
-use crate::sprite::SpriteData;
-use std::collections::{HashMap, HashSet};
+use crate::coverage::TeamCoverage;
+use crate::fuzzy::{fuzzy_match, number_match};
+use crate::suggest::Suggestion;
+use std::cmp::Reverse;
+use std::collections::{HashMap, HashSet, VecDeque};
 
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
 pub struct SearchState {
     pub active: bool,
     pub query: String,
+    /// Why each listed pokedex index matched, for highlighting.
+    pub matches: HashMap<usize, SearchMatch>,
+}
+
+/// What part of a dex entry a search query hit.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum SearchMatch {
+    /// Char positions in the name.
+    Name(Vec<usize>),
+    Number,
+    /// A type the entry is known to have, with char positions in the type name.
+    Type(String, Vec<usize>),
+}
+
+/// Type matches rank below name matches of similar quality.
+const TYPE_MATCH_PENALTY: i32 = 24;
+
+/// Pokedex that lists every species and is loaded a page at a time.
+pub const NATIONAL_DEX: &str = "national";
+pub const DEX_PAGE_SIZE: usize = 50;
+/// Rows left below the selection before the next national dex page is requested.
+pub const DEX_PAGE_PREFETCH: usize = 10;
+/// Rows either side of the selection whose family chains the family view fetches.
+pub const FAMILY_PREFETCH: usize = 20;
+/// Rows either side of the selection whose details and sprites load in the background.
+pub const DETAIL_PREFETCH: usize = 3;
+/// Rows per page of the Moves tab. PP for the selection's page is fetched as a batch.
+pub const MOVE_PAGE_SIZE: usize = 12;
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct RegionPickerState {
+    pub active: bool,
+    pub query: String,
+    pub selected: usize,
+}
+
+/// Format picker for exporting the current Pokemon; `selected` indexes `ExportFormat::ALL`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ExportPickerState {
+    pub active: bool,
+    pub selected: usize,
+}
+
+/// Path prompt for the region completion report; `path` is edited in place.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ReportPromptState {
+    pub active: bool,
+    pub path: String,
+}
+
+/// Sort menu over the dex list; `selected` indexes `DexSort::ALL`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct SortPickerState {
+    pub active: bool,
+    pub selected: usize,
+}
+
+/// Jump list over the bookmarks; `selected` indexes `AppState::bookmarks`.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct BookmarkListState {
+    pub active: bool,
+    pub selected: usize,
+}
+
+/// Key reference for the focused part of the UI; `scroll` is in rows.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct HelpState {
+    pub active: bool,
+    pub scroll: u16,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum CalcPane {
+    #[default]
+    Defender,
+    Move,
+}
+
+/// Damage calculator over the current Pokemon. `selected` indexes the defender
+/// matches for `query`; `move_selected` indexes its damaging moves.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CalcState {
+    pub active: bool,
+    pub pane: CalcPane,
+    pub query: String,
+    pub selected: usize,
+    /// Kept between openings, so one target can be checked against several attackers.
+    pub defender: Option<String>,
+    pub move_selected: usize,
+}
+
+/// `--region` and `--pokemon` from the command line, waiting for the data
+/// they point into. Each is taken once it has been acted on.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DeepLink {
+    pub region: Option<String>,
+    pub pokemon: Option<String>,
+}
+
+impl DeepLink {
+    pub fn is_empty(&self) -> bool {
+        self.region.is_none() && self.pokemon.is_none()
+    }
+}
+
+/// Startup card for the pokemon of the day. `name` and `trivia` arrive with its
+/// species; until then the card shows a loading line.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct FeaturedState {
+    pub active: bool,
+    pub name: Option<String>,
+    pub trivia: Option<String>,
+    pub error: Option<String>,
+}
+
+/// Cry currently playing and its latest amplitude readings, oldest first.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct CryState {
+    pub name: Option<String>,
+    pub levels: VecDeque<f32>,
+}
+
+impl CryState {
+    /// Readings kept for the oscilloscope; older ones scroll off the left.
+    pub const HISTORY: usize = 48;
+
+    pub fn push_level(&mut self, level: f32) {
+        if self.levels.len() == Self::HISTORY {
+            self.levels.pop_front();
+        }
+        self.levels.push_back(level.clamp(0.0, 1.0));
+    }
+}
+
+/// Multi-line buffer for the note editor overlay; `col` counts chars, not bytes.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct NoteEditorState {
+    pub active: bool,
+    pub name: String,
+    pub lines: Vec<String>,
+    pub row: usize,
+    pub col: usize,
+}
+
+impl Default for NoteEditorState {
+    fn default() -> Self {
+        Self {
+            active: false,
+            name: String::new(),
+            lines: vec![String::new()],
+            row: 0,
+            col: 0,
+        }
+    }
+}
+
+impl NoteEditorState {
+    pub fn open(&mut self, name: &str, text: &str) {
+        self.active = true;
+        self.name = name.to_string();
+        self.lines = text.lines().map(str::to_string).collect();
+        if self.lines.is_empty() {
+            self.lines.push(String::new());
+        }
+        self.row = self.lines.len() - 1;
+        self.col = self.lines[self.row].chars().count();
+    }
+
+    pub fn text(&self) -> String {
+        let text = self.lines.join("\n");
+        text.trim_start_matches('\n').trim_end().to_string()
+    }
+
+    pub fn insert(&mut self, ch: char) {
+        let at = self.byte_offset();
+        self.lines[self.row].insert(at, ch);
+        self.col += 1;
+    }
+
+    pub fn newline(&mut self) {
+        let at = self.byte_offset();
+        let rest = self.lines[self.row].split_off(at);
+        self.row += 1;
+        self.lines.insert(self.row, rest);
+        self.col = 0;
+    }
+
+    /// Deletes before the cursor, joining with the previous line at column 0.
+    pub fn backspace(&mut self) -> bool {
+        if self.col > 0 {
+            self.col -= 1;
+            let at = self.byte_offset();
+            self.lines[self.row].remove(at);
+            return true;
+        }
+        if self.row == 0 {
+            return false;
+        }
+        let line = self.lines.remove(self.row);
+        self.row -= 1;
+        self.col = self.lines[self.row].chars().count();
+        self.lines[self.row].push_str(&line);
+        true
+    }
+
+    /// Deletes under the cursor, pulling the next line up at end of line.
+    pub fn delete(&mut self) -> bool {
+        let at = self.byte_offset();
+        if at < self.lines[self.row].len() {
+            self.lines[self.row].remove(at);
+            return true;
+        }
+        if self.row + 1 >= self.lines.len() {
+            return false;
+        }
+        let next = self.lines.remove(self.row + 1);
+        self.lines[self.row].push_str(&next);
+        true
+    }
+
+    pub fn move_cursor(&mut self, rows: i16, cols: i16) -> bool {
+        let before = (self.row, self.col);
+        let last_row = self.lines.len() - 1;
+        self.row = (self.row as i64 + rows as i64).clamp(0, last_row as i64) as usize;
+        let len = self.lines[self.row].chars().count();
+        self.col = (self.col.min(len) as i64 + cols as i64).clamp(0, len as i64) as usize;
+        before != (self.row, self.col)
+    }
+
+    pub fn home(&mut self) -> bool {
+        std::mem::replace(&mut self.col, 0) != 0
+    }
+
+    pub fn end(&mut self) -> bool {
+        let len = self.lines[self.row].chars().count();
+        std::mem::replace(&mut self.col, len) != len
+    }
+
+    fn byte_offset(&self) -> usize {
+        let line = &self.lines[self.row];
+        line.char_indices()
+            .nth(self.col)
+            .map(|(idx, _)| idx)
+            .unwrap_or(line.len())
+    }
+}
+
+/// Item as shown in the Items mode detail panel.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ItemDetail {
+    pub name: String,
+    pub category: String,
+    pub cost: u32,
+    pub effect: Option<String>,
+    pub sprite_url: Option<String>,
+}
+
+/// Items mode: the full item list with its own search, plus fetched details.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct ItemBrowserState {
+    pub names: Vec<String>,
+    pub list_loading: bool,
+    pub query: String,
+    pub searching: bool,
+    /// Indices into `names` matching `query`, best match first.
+    pub filtered: Vec<usize>,
+    pub selected: usize,
+    pub details: HashMap<String, ItemDetail>,
+    /// Item whose detail request is in flight; a newer selection replaces it.
+    pub detail_loading: Option<String>,
+}
+
+impl ItemBrowserState {
+    pub fn rebuild_filtered(&mut self) {
+        let query = self.query.trim().to_lowercase();
+        let mut ranked: Vec<(usize, i32)> = self
+            .names
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, name)| {
+                if query.is_empty() {
+                    return Some((idx, 0));
+                }
+                fuzzy_match(&query, name).map(|(score, _)| (idx, score))
+            })
+            .collect();
+        ranked.sort_by_key(|(_, score)| Reverse(*score));
+        self.filtered = ranked.into_iter().map(|(idx, _)| idx).collect();
+        if self.selected >= self.filtered.len() {
+            self.selected = 0;
+        }
+    }
+
+    pub fn selected_name(&self) -> Option<&str> {
+        let idx = *self.filtered.get(self.selected)?;
+        self.names.get(idx).map(String::as_str)
+    }
+
+    pub fn selected_detail(&self) -> Option<&ItemDetail> {
+        self.details.get(self.selected_name()?)
+    }
+}
+
+/// A pokemon that can be met in one area of a location.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct LocationEncounter {
+    pub pokemon: String,
+    pub area: String,
+    pub version_details: Vec<EncounterVersion>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum LocationPane {
+    #[default]
+    Locations,
+    Pokemon,
+}
+
+/// Locations mode: the current region's locations and who lives there.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct LocationBrowserState {
+    /// Region `names` belongs to; reopening in another region reloads.
+    pub region: Option<String>,
+    pub names: Vec<String>,
+    pub list_loading: bool,
+    pub selected: usize,
+    pub pane: LocationPane,
+    pub pokemon_selected: usize,
+    pub encounters: HashMap<String, Vec<LocationEncounter>>,
+    /// Location whose encounters are in flight; a newer selection replaces it.
+    pub encounters_loading: Option<String>,
+}
+
+impl LocationBrowserState {
+    pub fn selected_name(&self) -> Option<&str> {
+        self.names.get(self.selected).map(String::as_str)
+    }
+
+    pub fn selected_encounters(&self) -> Option<&[LocationEncounter]> {
+        self.encounters
+            .get(self.selected_name()?)
+            .map(Vec::as_slice)
+    }
+
+    pub fn selected_pokemon(&self) -> Option<&LocationEncounter> {
+        self.selected_encounters()?.get(self.pokemon_selected)
+    }
+}
+
+/// Item sprites share `sprite_cache` with pokemon, under a prefixed key.
+pub fn item_sprite_key(name: &str) -> String {
+    format!("item:{name}")
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -24,7 +380,7 @@ pub struct PokemonDetail {
     pub types: Vec<String>,
     pub stats: Vec<PokemonStat>,
     pub abilities: Vec<String>,
-    pub moves: Vec<String>,
+    pub moves: Vec<MoveSummary>,
     pub height: u16,
     pub weight: u16,
     pub sprite_front_default: Option<String>,
@@ -43,16 +399,218 @@ pub struct PokemonStat {
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct PokemonSpecies {
     pub name: String,
+    /// Language `flavor_text` and `genus` were picked for; either may still be
+    /// English if PokeAPI has nothing in it.
+    #[serde(default)]
+    pub language: String,
     pub flavor_text: Option<String>,
     pub genus: Option<String>,
     pub evolution_chain_url: Option<String>,
     pub evolves_from: Option<String>,
+    /// Generation the species debuted in, 1 for Red/Blue.
+    pub generation: Option<u8>,
+    /// Name as the English games print it, punctuation and all ("Mr. Mime").
+    #[serde(default)]
+    pub english_name: Option<String>,
+}
+
+/// Languages with flavor text in PokeAPI, as (code, name), in the order the
+/// `language` command cycles through them.
+pub const LANGUAGES: [(&str, &str); 9] = [
+    ("en", "English"),
+    ("fr", "Français"),
+    ("de", "Deutsch"),
+    ("es", "Español"),
+    ("it", "Italiano"),
+    ("ja", "日本語"),
+    ("ko", "한국어"),
+    ("zh-Hans", "简体中文"),
+    ("zh-Hant", "繁體中文"),
+];
+
+/// The language after `code` in [`LANGUAGES`], wrapping around.
+pub fn next_language(code: &str) -> &'static str {
+    let index = LANGUAGES
+        .iter()
+        .position(|(lang, _)| *lang == code)
+        .map_or(0, |index| (index + 1) % LANGUAGES.len());
+    LANGUAGES[index].0
+}
+
+/// Roman numerals PokeAPI uses in generation names (`generation-iv`).
+pub const GENERATION_NUMERALS: [&str; 9] = ["i", "ii", "iii", "iv", "v", "vi", "vii", "viii", "ix"];
+
+/// `generation-iv` -> 4.
+pub fn parse_generation(name: &str) -> Option<u8> {
+    let numeral = name.strip_prefix("generation-")?;
+    let index = GENERATION_NUMERALS
+        .iter()
+        .position(|candidate| *candidate == numeral)?;
+    Some(index as u8 + 1)
+}
+
+/// 4 -> `GEN IV`.
+pub fn generation_label(generation: u8) -> String {
+    let numeral = GENERATION_NUMERALS
+        .get(usize::from(generation).wrapping_sub(1))
+        .map(|numeral| numeral.to_ascii_uppercase())
+        .unwrap_or_else(|| generation.to_string());
+    format!("GEN {numeral}")
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct EvolutionChain {
     pub id: String,
-    pub stages: Vec<String>,
+    /// Every species in the chain, depth-first: each stage is followed by the
+    /// stages that evolve from it.
+    pub stages: Vec<EvolutionStage>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct EvolutionStage {
+    pub name: String,
+    /// Index of the stage this one evolves from; `None` for the base form.
+    pub parent: Option<usize>,
+    pub depth: usize,
+    /// How it evolves from `parent`, e.g. `Lv. 16` or `Water Stone`.
+    pub trigger: Option<String>,
+}
+
+impl EvolutionChain {
+    pub fn position(&self, name: &str) -> Option<usize> {
+        self.stages.iter().position(|stage| stage.name == name)
+    }
+
+    /// Whether a later stage evolves from the same parent as `index`, i.e.
+    /// the tree needs a `├` rather than a `└` in front of it.
+    pub fn has_later_sibling(&self, index: usize) -> bool {
+        let Some(stage) = self.stages.get(index) else {
+            return false;
+        };
+        self.stages[index + 1..]
+            .iter()
+            .any(|other| other.parent == stage.parent)
+    }
+}
+
+/// A move in a Pokemon's learnset, with the metadata the Moves tab sorts and filters on.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct MoveSummary {
+    pub name: String,
+    pub move_type: Option<String>,
+    pub power: Option<u16>,
+    pub accuracy: Option<u16>,
+    /// `physical`, `special` or `status`.
+    #[serde(default)]
+    pub damage_class: Option<String>,
+    /// How the move is learned in the most recent version group, e.g. `level-up` or `machine`.
+    pub learn_method: String,
+    /// Level it's learned at; 0 unless learned by leveling up.
+    pub level: u8,
+}
+
+impl MoveSummary {
+    pub fn is_level_up(&self) -> bool {
+        self.learn_method == "level-up"
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum MoveSort {
+    /// Level-up moves by level, then everything else by learn method.
+    Level,
+    Power,
+    Accuracy,
+}
+
+impl MoveSort {
+    pub fn next(self) -> Self {
+        match self {
+            MoveSort::Level => MoveSort::Power,
+            MoveSort::Power => MoveSort::Accuracy,
+            MoveSort::Accuracy => MoveSort::Level,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            MoveSort::Level => "Level",
+            MoveSort::Power => "Power",
+            MoveSort::Accuracy => "Accuracy",
+        }
+    }
+}
+
+/// Order of the dex list. `Number` keeps dex (or search) order; the rest put
+/// the highest base stat first.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DexSort {
+    Number,
+    Total,
+    Hp,
+    Attack,
+    Defense,
+    SpecialAttack,
+    SpecialDefense,
+    Speed,
+}
+
+impl DexSort {
+    pub const ALL: [DexSort; 8] = [
+        DexSort::Number,
+        DexSort::Total,
+        DexSort::Hp,
+        DexSort::Attack,
+        DexSort::Defense,
+        DexSort::SpecialAttack,
+        DexSort::SpecialDefense,
+        DexSort::Speed,
+    ];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            DexSort::Number => "Dex number",
+            DexSort::Total => "Base stat total",
+            DexSort::Hp => "HP",
+            DexSort::Attack => "Attack",
+            DexSort::Defense => "Defense",
+            DexSort::SpecialAttack => "Sp. Attack",
+            DexSort::SpecialDefense => "Sp. Defense",
+            DexSort::Speed => "Speed",
+        }
+    }
+
+    /// Short tag for the DEX panel title.
+    pub fn short(self) -> &'static str {
+        match self {
+            DexSort::Number => "NO.",
+            DexSort::Total => "BST",
+            DexSort::Hp => "HP",
+            DexSort::Attack => "ATK",
+            DexSort::Defense => "DEF",
+            DexSort::SpecialAttack => "SAT",
+            DexSort::SpecialDefense => "SDF",
+            DexSort::Speed => "SPD",
+        }
+    }
+
+    /// The value this order ranks by, or `None` for dex order.
+    pub fn value(self, stats: &[PokemonStat]) -> Option<u16> {
+        let stat = match self {
+            DexSort::Number => return None,
+            DexSort::Total => return Some(stats.iter().map(|stat| stat.value).sum()),
+            DexSort::Hp => "hp",
+            DexSort::Attack => "attack",
+            DexSort::Defense => "defense",
+            DexSort::SpecialAttack => "special-attack",
+            DexSort::SpecialDefense => "special-defense",
+            DexSort::Speed => "speed",
+        };
+        stats
+            .iter()
+            .find(|candidate| candidate.name == stat)
+            .map(|stat| stat.value)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
@@ -92,6 +650,57 @@ pub struct EncounterDetail {
     pub conditions: Vec<String>,
 }
 
+/// One version's encounters as method rows by level-range columns.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EncounterGrid {
+    /// Most likely method first.
+    pub methods: Vec<String>,
+    /// Lowest range first.
+    pub levels: Vec<(u8, u8)>,
+    /// `cells[method][level]`: the best chance of that method in that range,
+    /// if it covers it at all.
+    pub cells: Vec<Vec<Option<u8>>>,
+}
+
+impl EncounterVersion {
+    /// Slots under different conditions (time of day, season...) share a cell
+    /// and show the best of their chances.
+    pub fn grid(&self) -> EncounterGrid {
+        let mut levels: Vec<(u8, u8)> = self
+            .encounters
+            .iter()
+            .map(|detail| (detail.min_level, detail.max_level))
+            .collect();
+        levels.sort();
+        levels.dedup();
+
+        let mut rows: Vec<(String, Vec<Option<u8>>)> = Vec::new();
+        for detail in &self.encounters {
+            let row = match rows.iter().position(|(method, _)| *method == detail.method) {
+                Some(row) => row,
+                None => {
+                    rows.push((detail.method.clone(), vec![None; levels.len()]));
+                    rows.len() - 1
+                }
+            };
+            let Ok(column) = levels.binary_search(&(detail.min_level, detail.max_level)) else {
+                continue;
+            };
+            let cell = &mut rows[row].1[column];
+            *cell = Some(cell.unwrap_or(0).max(detail.chance));
+        }
+        let total = |cells: &[Option<u8>]| cells.iter().flatten().map(|&c| c as u32).sum::<u32>();
+        rows.sort_by(|a, b| total(&b.1).cmp(&total(&a.1)).then_with(|| a.0.cmp(&b.0)));
+
+        let (methods, cells) = rows.into_iter().unzip();
+        EncounterGrid {
+            methods,
+            levels,
+            cells,
+        }
+    }
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct TypeMatchup {
     pub name: String,
@@ -112,10 +721,36 @@ pub enum DetailMode {
     Matchup,
 }
 
+/// What the Matchup tab shows: the selected Pokemon or the whole team.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
+pub enum MatchupView {
+    Pokemon,
+    Team,
+}
+
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
 pub struct RegionInfo {
     pub name: String,
     pub label: String,
+    pub region: String,
+}
+
+/// A row of the dex list. Outside the family view every row is a `Head`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub enum DexRow {
+    /// Index into `pokedex`; in the family view, the header of that species' family.
+    Head(usize),
+    /// An evolved family member: index into `pokedex_all`, `depth` stages below the head.
+    Member { entry: usize, depth: usize },
+}
+
+/// Progress through the national dex, which is fetched in pages as the list scrolls.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct DexPaging {
+    /// Species count reported by the API, known once the first page lands.
+    pub total: Option<usize>,
+    /// Offset of the page in flight, held until its species index arrives.
+    pub loading: Option<usize>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize)]
@@ -124,6 +759,9 @@ pub enum FocusArea {
     DexList,
     DetailTabs,
     Evolution,
+    Team,
+    Items,
+    Locations,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -132,38 +770,91 @@ pub struct AppState {
     pub focus: FocusArea,
     pub pokedex: Vec<PokedexEntry>,
     pub pokedex_all: Vec<PokedexEntry>,
+    pub dex_paging: Option<DexPaging>,
     pub filtered_indices: Vec<usize>,
+    /// What the dex list shows, built from `filtered_indices`; `selected_index` points here.
+    pub dex_rows: Vec<DexRow>,
     pub selected_index: usize,
+    /// Dex list grouped by evolutionary family, with members under each head.
+    pub family_view: bool,
+    /// Heads whose family members are folded away.
+    pub collapsed_families: HashSet<String>,
+    /// Evolution chains requested for the family view and not back yet.
+    pub family_requests: HashSet<String>,
     pub detail_name: Option<String>,
 
     pub details: HashMap<String, PokemonDetail>,
     pub species: HashMap<String, PokemonSpecies>,
     pub evolution: HashMap<String, EvolutionChain>,
     pub sprite_cache: HashMap<String, SpriteData>,
+    /// Neighbours being prefetched; selecting one waits for it instead of asking again.
+    pub prefetching: HashSet<String>,
     pub sprite_frame_index: usize,
     pub sprite_frame_tick: u64,
     pub move_cache: HashMap<String, MoveDetail>,
+    /// Moves on the current page whose details are being fetched for the table.
+    pub move_prefetching: HashSet<String>,
     pub ability_cache: HashMap<String, AbilityDetail>,
     pub encounter_cache: HashMap<String, Vec<EncounterLocation>>,
     pub type_matchup_cache: HashMap<String, TypeMatchup>,
     pub detail_mode: DetailMode,
+    pub matchup_view: MatchupView,
+    /// The STATS panel draws a radar chart instead of bars.
+    pub stats_radar: bool,
+    /// Language code for species flavor text and genus. Species cached in
+    /// another language are fetched again when next shown.
+    pub language: String,
     pub selected_move_index: usize,
     pub selected_ability_index: usize,
     pub selected_encounter_index: usize,
     pub evolution_selected_index: usize,
+    pub move_sort: MoveSort,
+    pub move_type_filter: Option<String>,
 
     pub search: SearchState,
     pub type_list: Vec<String>,
+    pub dex_sort: DexSort,
+    pub sort_picker: SortPickerState,
+    pub featured: FeaturedState,
+    pub help: HelpState,
+    pub calc: CalcState,
+    pub cry: CryState,
+    /// Base stats for sorting, filled in bulk for the loaded dex.
+    pub base_stats: HashMap<String, Vec<PokemonStat>>,
     pub type_filter: Option<String>,
+    /// Only list species that debuted in this generation.
+    pub generation_filter: Option<u8>,
     pub type_members: HashSet<String>,
     pub type_cache: HashMap<String, HashSet<String>>,
 
     pub regions: Vec<RegionInfo>,
     pub region_index: usize,
+    pub region_picker: RegionPickerState,
+    pub region_dex_members: HashMap<String, Vec<String>>,
     pub seen: HashSet<String>,
 
     pub favorites: HashSet<String>,
     pub team: Vec<String>,
+    pub team_selected: usize,
+    /// Pokemon marked with `m`, in the order they were marked.
+    pub bookmarks: Vec<String>,
+    pub bookmark_list: BookmarkListState,
+    pub team_coverage: Option<TeamCoverage>,
+    /// Species that would patch the team's weaknesses, best first.
+    pub team_suggestions: Vec<Suggestion>,
+    /// Loads already sent for suggestions, so updates don't re-request them.
+    pub suggestion_requests: HashSet<String>,
+    /// Pokemon pinned next to the current detail in compare mode.
+    pub compare: Option<String>,
+    pub notes: HashMap<String, String>,
+    /// The notes file is there but couldn't be read. Saving would replace it
+    /// with just this session's notes, so saving is refused until a restart.
+    pub notes_load_failed: bool,
+    pub note_editor: NoteEditorState,
+    pub export_picker: ExportPickerState,
+    pub report_prompt: ReportPromptState,
+    pub items: ItemBrowserState,
+    pub locations: LocationBrowserState,
 
     pub list_loading: bool,
     pub detail_loading: bool,
@@ -171,10 +862,14 @@ pub struct AppState {
     pub evolution_loading: bool,
     pub sprite_loading: bool,
     pub species_index_loading: bool,
+    pub base_stats_loading: bool,
     pub region_loading: bool,
     pub encounter_loading: bool,
     pub type_matchup_loading: bool,
     pub message: Option<String>,
+    /// Problems with `keys.toml` or `theme.toml`, shown in place of a message on startup.
+    pub keys_warning: Option<String>,
+    pub deep_link: DeepLink,
     pub tick: u64,
     pub encounter_version_filter: Option<String>,
 }
@@ -186,44 +881,83 @@ impl Default for AppState {
             focus: FocusArea::DexList,
             pokedex: Vec::new(),
             pokedex_all: Vec::new(),
+            dex_paging: None,
             filtered_indices: Vec::new(),
+            dex_rows: Vec::new(),
             selected_index: 0,
+            family_view: false,
+            collapsed_families: HashSet::new(),
+            family_requests: HashSet::new(),
             detail_name: None,
             details: HashMap::new(),
             species: HashMap::new(),
             evolution: HashMap::new(),
             sprite_cache: HashMap::new(),
+            prefetching: HashSet::new(),
             sprite_frame_index: 0,
             sprite_frame_tick: 0,
             move_cache: HashMap::new(),
+            move_prefetching: HashSet::new(),
             ability_cache: HashMap::new(),
             encounter_cache: HashMap::new(),
             type_matchup_cache: HashMap::new(),
             detail_mode: DetailMode::General,
+            matchup_view: MatchupView::Pokemon,
+            stats_radar: false,
+            language: LANGUAGES[0].0.to_string(),
             selected_move_index: 0,
             selected_ability_index: 0,
             selected_encounter_index: 0,
             evolution_selected_index: 0,
+            move_sort: MoveSort::Level,
+            move_type_filter: None,
             search: SearchState::default(),
             type_list: Vec::new(),
+            dex_sort: DexSort::Number,
+            sort_picker: SortPickerState::default(),
+            featured: FeaturedState::default(),
+            help: HelpState::default(),
+            calc: CalcState::default(),
+            cry: CryState::default(),
+            base_stats: HashMap::new(),
             type_filter: None,
+            generation_filter: None,
             type_members: HashSet::new(),
             type_cache: HashMap::new(),
             regions: Vec::new(),
             region_index: 0,
+            region_picker: RegionPickerState::default(),
+            region_dex_members: HashMap::new(),
             seen: HashSet::new(),
             favorites: HashSet::new(),
             team: Vec::new(),
+            team_selected: 0,
+            bookmarks: Vec::new(),
+            bookmark_list: BookmarkListState::default(),
+            team_coverage: None,
+            team_suggestions: Vec::new(),
+            suggestion_requests: HashSet::new(),
+            compare: None,
+            notes: HashMap::new(),
+            notes_load_failed: false,
+            note_editor: NoteEditorState::default(),
+            export_picker: ExportPickerState::default(),
+            report_prompt: ReportPromptState::default(),
+            items: ItemBrowserState::default(),
+            locations: LocationBrowserState::default(),
             list_loading: false,
             detail_loading: false,
             type_loading: false,
             evolution_loading: false,
             sprite_loading: false,
             species_index_loading: false,
+            base_stats_loading: false,
             region_loading: false,
             encounter_loading: false,
             type_matchup_loading: false,
             message: None,
+            keys_warning: None,
+            deep_link: DeepLink::default(),
             tick: 0,
             encounter_version_filter: None,
         }
@@ -232,9 +966,22 @@ impl Default for AppState {
 
 impl AppState {
     pub fn selected_entry(&self) -> Option<&PokedexEntry> {
-        self.filtered_indices
-            .get(self.selected_index)
-            .and_then(|idx| self.pokedex.get(*idx))
+        self.row_entry(self.dex_rows.get(self.selected_index)?)
+    }
+
+    pub fn row_entry(&self, row: &DexRow) -> Option<&PokedexEntry> {
+        match row {
+            DexRow::Head(idx) => self.pokedex.get(*idx),
+            DexRow::Member { entry, .. } => self.pokedex_all.get(*entry),
+        }
+    }
+
+    /// Row listing `name`, if it is on the dex list right now.
+    pub fn row_position(&self, name: &str) -> Option<usize> {
+        self.dex_rows.iter().position(|row| {
+            self.row_entry(row)
+                .is_some_and(|entry| entry.name == name)
+        })
     }
 
     pub fn selected_name(&self) -> Option<String> {
@@ -242,11 +989,11 @@ impl AppState {
     }
 
     pub fn set_selected_index(&mut self, index: usize) -> bool {
-        if self.filtered_indices.is_empty() {
+        if self.dex_rows.is_empty() {
             self.selected_index = 0;
             return false;
         }
-        let bounded = index.min(self.filtered_indices.len() - 1);
+        let bounded = index.min(self.dex_rows.len() - 1);
         if bounded != self.selected_index {
             self.selected_index = bounded;
             return true;
@@ -256,29 +1003,152 @@ impl AppState {
 
     pub fn rebuild_filtered(&mut self) {
         let query = self.search.query.trim().to_lowercase();
-        let type_filter = self.type_filter.clone();
-        self.filtered_indices = self
-            .pokedex
-            .iter()
-            .enumerate()
-            .filter(|(_, entry)| {
-                let matches_query = query.is_empty()
-                    || entry.name.to_lowercase().contains(&query)
-                    || entry.entry_number.to_string().contains(&query);
-                let matches_type = match &type_filter {
-                    Some(_) => self.type_members.contains(&entry.name),
-                    None => true,
+        // `note:<text>` searches note bodies instead of names.
+        let note_query = query.strip_prefix("note:").map(str::trim);
+        // `#123` (or bare digits) searches dex numbers.
+        let number_query = query
+            .strip_prefix('#')
+            .or_else(|| query.chars().all(|ch| ch.is_ascii_digit()).then_some(query.as_str()))
+            .filter(|digits| digits.chars().all(|ch| ch.is_ascii_digit()));
+        let mut known_types: HashMap<&str, Vec<&str>> = HashMap::new();
+        if note_query.is_none() && number_query.is_none() && !query.is_empty() {
+            for (type_name, members) in &self.type_cache {
+                for member in members {
+                    known_types.entry(member.as_str()).or_default().push(type_name);
+                }
+            }
+            for (name, detail) in &self.details {
+                let types = known_types.entry(name.as_str()).or_default();
+                types.clear();
+                types.extend(detail.types.iter().map(String::as_str));
+            }
+        }
+
+        let mut matches = HashMap::new();
+        let mut ranked: Vec<(usize, i32)> = Vec::new();
+        for (idx, entry) in self.pokedex.iter().enumerate() {
+            if self.type_filter.is_some() && !self.type_members.contains(&entry.name) {
+                continue;
+            }
+            if self.generation_filter.is_some()
+                && self.generation_filter != self.species_generation(&entry.name)
+            {
+                continue;
+            }
+            let hit = if query.is_empty() {
+                Some((0, None))
+            } else if let Some(needle) = note_query {
+                self.notes
+                    .get(&entry.name)
+                    .filter(|note| note.to_lowercase().contains(needle))
+                    .map(|_| (0, None))
+            } else if let Some(digits) = number_query {
+                if digits.is_empty() {
+                    Some((0, None))
+                } else {
+                    number_match(digits, entry.entry_number)
+                        .map(|score| (score, Some(SearchMatch::Number)))
+                }
+            } else {
+                let by_name = fuzzy_match(&query, &entry.name)
+                    .map(|(score, chars)| (score, SearchMatch::Name(chars)));
+                let by_type = known_types
+                    .get(entry.name.as_str())
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|type_name| {
+                        let (score, chars) = fuzzy_match(&query, type_name)?;
+                        Some((
+                            score - TYPE_MATCH_PENALTY,
+                            SearchMatch::Type(type_name.to_string(), chars),
+                        ))
+                    })
+                    .max_by_key(|(score, _)| *score);
+                let best = match (by_name, by_type) {
+                    (Some(name), Some(by_type)) if by_type.0 > name.0 => Some(by_type),
+                    (Some(name), _) => Some(name),
+                    (None, by_type) => by_type,
                 };
-                matches_query && matches_type
-            })
-            .map(|(idx, _)| idx)
-            .collect();
+                best.map(|(score, hit)| (score, Some(hit)))
+            };
+            if let Some((score, hit)) = hit {
+                ranked.push((idx, score));
+                if let Some(hit) = hit {
+                    matches.insert(idx, hit);
+                }
+            }
+        }
+        // Stable sort keeps dex order among equal scores.
+        ranked.sort_by_key(|(_, score)| std::cmp::Reverse(*score));
+        if self.dex_sort != DexSort::Number {
+            // Highest stat first; entries whose stats haven't loaded go last.
+            ranked.sort_by_key(|(idx, _)| {
+                std::cmp::Reverse(
+                    self.pokedex
+                        .get(*idx)
+                        .and_then(|entry| self.sort_value(&entry.name)),
+                )
+            });
+        }
+        self.filtered_indices = ranked.into_iter().map(|(idx, _)| idx).collect();
+        self.search.matches = matches;
+        self.rebuild_rows();
 
-        if self.selected_index >= self.filtered_indices.len() {
+        if self.selected_index >= self.dex_rows.len() {
             self.selected_index = 0;
         }
     }
 
+    /// Lays out `dex_rows` from `filtered_indices`. The family view lists each
+    /// head's evolved members under it, once its chain has loaded.
+    pub fn rebuild_rows(&mut self) {
+        let mut rows = Vec::with_capacity(self.filtered_indices.len());
+        for &idx in &self.filtered_indices {
+            rows.push(DexRow::Head(idx));
+            if !self.family_view {
+                continue;
+            }
+            let Some(head) = self.pokedex.get(idx) else {
+                continue;
+            };
+            if self.collapsed_families.contains(&head.name) {
+                continue;
+            }
+            for (entry, depth) in self.family_members(&head.name).unwrap_or_default() {
+                rows.push(DexRow::Member { entry, depth });
+            }
+        }
+        self.dex_rows = rows;
+    }
+
+    /// Evolution chain id for `name`'s family, from its species.
+    pub fn family_chain_id(&self, name: &str) -> Option<&str> {
+        let url = self.species.get(name)?.evolution_chain_url.as_ref()?;
+        url.trim_end_matches('/').rsplit('/').next()
+    }
+
+    /// `head`'s evolved relatives in this dex as (`pokedex_all` index, depth),
+    /// in chain order. `None` until the chain has loaded.
+    pub fn family_members(&self, head: &str) -> Option<Vec<(usize, usize)>> {
+        let chain = self.evolution.get(self.family_chain_id(head)?)?;
+        let head_depth = chain
+            .position(head)
+            .map_or(0, |index| chain.stages[index].depth);
+        let members = chain
+            .stages
+            .iter()
+            .filter(|stage| stage.name != head)
+            .filter_map(|stage| {
+                let entry = self
+                    .pokedex_all
+                    .iter()
+                    .position(|entry| entry.name == stage.name)?;
+                Some((entry, stage.depth.saturating_sub(head_depth).max(1)))
+            })
+            .collect();
+        Some(members)
+    }
+
     pub fn update_type_members(&mut self, type_name: &str, pokemon: HashSet<String>) {
         self.type_cache.insert(type_name.to_string(), pokemon.clone());
         if self.type_filter.as_deref() == Some(type_name) {
@@ -296,16 +1166,163 @@ impl AppState {
         self.species.get(name)
     }
 
+    /// Evolution chain of the Pokemon in the detail panel, once loaded.
+    pub fn current_evolution(&self) -> Option<&EvolutionChain> {
+        let name = self.detail_name.as_deref()?;
+        self.evolution.get(self.family_chain_id(name)?)
+    }
+
+    /// `name`'s value under the current dex sort, once its stats are known.
+    pub fn sort_value(&self, name: &str) -> Option<u16> {
+        let stats = match self.details.get(name) {
+            Some(detail) => &detail.stats,
+            None => self.base_stats.get(name)?,
+        };
+        self.dex_sort.value(stats)
+    }
+
+    /// Dex entries the current sort still needs base stats for.
+    pub fn missing_base_stats(&self) -> Vec<String> {
+        if self.dex_sort == DexSort::Number {
+            return Vec::new();
+        }
+        self.pokedex_all
+            .iter()
+            .filter(|entry| {
+                !self.details.contains_key(&entry.name)
+                    && !self.base_stats.contains_key(&entry.name)
+            })
+            .map(|entry| entry.name.clone())
+            .collect()
+    }
+
+    pub fn species_generation(&self, name: &str) -> Option<u8> {
+        self.species.get(name)?.generation
+    }
+
+    /// Generations with at least one species in the loaded dex, oldest first.
+    pub fn dex_generations(&self) -> Vec<u8> {
+        let mut generations: Vec<u8> = self
+            .pokedex_all
+            .iter()
+            .filter_map(|entry| self.species_generation(&entry.name))
+            .collect();
+        generations.sort_unstable();
+        generations.dedup();
+        generations
+    }
+
     pub fn current_region(&self) -> Option<&RegionInfo> {
         self.regions.get(self.region_index)
     }
 
+    /// Index of the pokedex a `--region` names: a pokedex by its own name
+    /// (`original-johto`), else the first pokedex of a region (`johto`).
+    pub fn find_region(&self, name: &str) -> Option<usize> {
+        self.regions
+            .iter()
+            .position(|region| region.name == name)
+            .or_else(|| self.regions.iter().position(|region| region.region == name))
+    }
+
+    /// Offset of the next national dex page, when one remains and none is in flight.
+    pub fn next_dex_page(&self) -> Option<usize> {
+        let paging = self.dex_paging.as_ref()?;
+        if paging.loading.is_some() {
+            return None;
+        }
+        let loaded = self.pokedex_all.len();
+        match paging.total {
+            Some(total) if loaded >= total => None,
+            _ => Some(loaded),
+        }
+    }
+
+    /// Species of the national dex not fetched yet, if the dex is paged.
+    pub fn dex_remaining(&self) -> Option<usize> {
+        let paging = self.dex_paging.as_ref()?;
+        let total = paging.total?;
+        Some(total.saturating_sub(self.pokedex_all.len()))
+    }
+
+    /// Indices into `regions` matching the picker query, by region or pokedex name.
+    pub fn region_picker_matches(&self) -> Vec<usize> {
+        let query = self.region_picker.query.trim().to_lowercase();
+        self.regions
+            .iter()
+            .enumerate()
+            .filter(|(_, info)| {
+                query.is_empty()
+                    || info.region.contains(&query)
+                    || info.name.contains(&query)
+                    || info.label.to_lowercase().contains(&query)
+            })
+            .map(|(idx, _)| idx)
+            .collect()
+    }
+
+    pub fn region_picker_selected(&self) -> Option<usize> {
+        self.region_picker_matches()
+            .get(self.region_picker.selected)
+            .copied()
+    }
+
+    /// Seen and total species for a pokedex, once its members are known.
+    pub fn region_completion(&self, name: &str) -> Option<(usize, usize)> {
+        let members = self.region_dex_members.get(name)?;
+        let seen = members
+            .iter()
+            .filter(|member| self.seen.contains(*member))
+            .count();
+        Some((seen, members.len()))
+    }
+
     pub fn current_move_name(&self) -> Option<String> {
-        let detail = self.current_detail()?;
-        detail
-            .moves
+        self.visible_moves()
             .get(self.selected_move_index)
-            .cloned()
+            .map(|summary| summary.name.clone())
+    }
+
+    /// The current Pokemon's moves as listed on the Moves tab: type filter, then sort.
+    pub fn visible_moves(&self) -> Vec<&MoveSummary> {
+        let Some(detail) = self.current_detail() else {
+            return Vec::new();
+        };
+        let mut moves: Vec<&MoveSummary> = detail
+            .moves
+            .iter()
+            .filter(|summary| {
+                self.move_type_filter
+                    .as_ref()
+                    .is_none_or(|filter| summary.move_type.as_ref() == Some(filter))
+            })
+            .collect();
+        match self.move_sort {
+            MoveSort::Level => moves.sort_by(|a, b| {
+                b.is_level_up()
+                    .cmp(&a.is_level_up())
+                    .then_with(|| a.level.cmp(&b.level))
+                    .then_with(|| a.learn_method.cmp(&b.learn_method))
+            }),
+            MoveSort::Power => moves.sort_by_key(|summary| Reverse(summary.power)),
+            MoveSort::Accuracy => moves.sort_by_key(|summary| Reverse(summary.accuracy)),
+        }
+        moves
+    }
+
+    /// Distinct move types in the current Pokemon's learnset, for the type filter.
+    pub fn move_types(&self) -> Vec<String> {
+        let Some(detail) = self.current_detail() else {
+            return Vec::new();
+        };
+        let mut types: Vec<String> = detail
+            .moves
+            .iter()
+            .filter_map(|summary| summary.move_type.clone())
+            .collect();
+        types.sort();
+        types.dedup();
+        types
     }
 
     pub fn current_ability_name(&self) -> Option<String> {
@@ -316,6 +1333,12 @@ impl AppState {
             .cloned()
     }
 
+    /// Sum of base stats, once the member's detail has loaded.
+    pub fn base_stat_total(&self, name: &str) -> Option<u16> {
+        let detail = self.details.get(name)?;
+        Some(detail.stats.iter().map(|stat| stat.value).sum())
+    }
+
     pub fn reset_sprite_animation(&mut self) {
         self.sprite_frame_index = 0;
         self.sprite_frame_tick = 0;
@@ -324,6 +1347,7 @@ impl AppState {
     pub fn reset_detail_selection(&mut self) {
         self.detail_mode = DetailMode::General;
         self.selected_move_index = 0;
+        self.move_type_filter = None;
         self.selected_ability_index = 0;
         self.selected_encounter_index = 0;
     }
@@ -334,6 +1358,9 @@ impl AppState {
             FocusArea::DexList => FocusArea::DetailTabs,
             FocusArea::DetailTabs => FocusArea::Evolution,
             FocusArea::Evolution => FocusArea::DexList,
+            FocusArea::Team => FocusArea::Team,
+            FocusArea::Items => FocusArea::Items,
+            FocusArea::Locations => FocusArea::Locations,
         };
     }
 
@@ -343,6 +1370,9 @@ impl AppState {
             FocusArea::DexList => FocusArea::Evolution,
             FocusArea::DetailTabs => FocusArea::DexList,
             FocusArea::Evolution => FocusArea::DetailTabs,
+            FocusArea::Team => FocusArea::Team,
+            FocusArea::Items => FocusArea::Items,
+            FocusArea::Locations => FocusArea::Locations,
         };
     }
 }
@@ -353,16 +1383,51 @@ impl DebugState for AppState {
             DebugSection::new("Dex")
                 .entry("total", ron_string(&self.pokedex.len()))
                 .entry("filtered", ron_string(&self.filtered_indices.len()))
+                .entry("rows", ron_string(&self.dex_rows.len()))
+                .entry("family_view", ron_string(&self.family_view))
                 .entry("selected", ron_string(&self.selected_index))
                 .entry("detail", ron_string(&self.detail_name))
-                .entry("region", ron_string(&self.current_region().map(|region| region.label.clone()))),
+                .entry("region", ron_string(&self.current_region().map(|region| region.label.clone())))
+                .entry("region_picker", ron_string(&self.region_picker.active))
+                .entry("region_query", ron_string(&self.region_picker.query))
+                .entry("team", ron_string(&self.team))
+                .entry("team_selected", ron_string(&self.team_selected))
+                .entry("team_suggestions", ron_string(&self.team_suggestions))
+                .entry("compare", ron_string(&self.compare))
+                .entry("notes", ron_string(&self.notes.len()))
+                .entry("notes_load_failed", ron_string(&self.notes_load_failed))
+                .entry("note_editor", ron_string(&self.note_editor.active)),
+            DebugSection::new("Items")
+                .entry("total", ron_string(&self.items.names.len()))
+                .entry("filtered", ron_string(&self.items.filtered.len()))
+                .entry("selected", ron_string(&self.items.selected_name()))
+                .entry("query", ron_string(&self.items.query))
+                .entry("searching", ron_string(&self.items.searching))
+                .entry("details", ron_string(&self.items.details.len()))
+                .entry("list_loading", ron_string(&self.items.list_loading))
+                .entry("detail_loading", ron_string(&self.items.detail_loading)),
+            DebugSection::new("Locations")
+                .entry("region", ron_string(&self.locations.region))
+                .entry("total", ron_string(&self.locations.names.len()))
+                .entry("selected", ron_string(&self.locations.selected_name()))
+                .entry("pane", ron_string(&self.locations.pane))
+                .entry("pokemon_selected", ron_string(&self.locations.pokemon_selected))
+                .entry("cached", ron_string(&self.locations.encounters.len()))
+                .entry("list_loading", ron_string(&self.locations.list_loading))
+                .entry("loading", ron_string(&self.locations.encounters_loading)),
             DebugSection::new("Filters")
                 .entry("search", ron_string(&self.search.query))
                 .entry("search_active", ron_string(&self.search.active))
                 .entry("type", ron_string(&self.type_filter))
+                .entry("generation", ron_string(&self.generation_filter))
+                .entry("dex_sort", ron_string(&self.dex_sort))
                 .entry("detail_mode", ron_string(&self.detail_mode))
+                .entry("matchup_view", ron_string(&self.matchup_view))
                 .entry("focus", ron_string(&self.focus))
                 .entry("evolution_index", ron_string(&self.evolution_selected_index))
+                .entry("move_sort", ron_string(&self.move_sort))
+                .entry("move_type", ron_string(&self.move_type_filter))
+                .entry("language", ron_string(&self.language))
                 .entry(
                     "encounter_index",
                     ron_string(&self.selected_encounter_index),
@@ -375,7 +1440,12 @@ impl DebugState for AppState {
                 .entry("list_loading", ron_string(&self.list_loading))
                 .entry("detail_loading", ron_string(&self.detail_loading))
                 .entry("sprite_loading", ron_string(&self.sprite_loading))
+                .entry("prefetching", ron_string(&self.prefetching.len()))
+                .entry("featured", ron_string(&self.featured.name))
+                .entry("cry", ron_string(&self.cry.name))
                 .entry("species_index_loading", ron_string(&self.species_index_loading))
+                .entry("base_stats_loading", ron_string(&self.base_stats_loading))
+                .entry("dex_paging", ron_string(&self.dex_paging))
                 .entry("encounter_loading", ron_string(&self.encounter_loading))
                 .entry("matchup_loading", ron_string(&self.type_matchup_loading))
                 .entry("region_loading", ron_string(&self.region_loading))
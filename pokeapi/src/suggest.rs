@@ -0,0 +1,254 @@
+use std::collections::{HashMap, HashSet};
+
+use serde::{Deserialize, Serialize};
+
+use crate::coverage::{self, TeamCoverage, TypeExposure};
+use crate::state::AppState;
+
+pub const SUGGESTION_COUNT: usize = 3;
+/// Candidates whose details get fetched so they can be scored on full typing and BST.
+pub const SHORTLIST_SIZE: usize = 6;
+
+// Typing points per team member a weakness hits; one point is worth
+// BST_PER_POINT base stat total when ranking.
+const IMMUNE_POINTS: i32 = 3;
+const RESIST_POINTS: i32 = 2;
+const WEAK_PENALTY: i32 = 2;
+const BST_PER_POINT: i32 = 40;
+
+/// A species from the loaded dex that covers some of the team's weaknesses.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct Suggestion {
+    pub name: String,
+    /// Team weaknesses this species resists or is immune to.
+    pub patches: Vec<String>,
+    pub bst: u16,
+    pub score: i32,
+}
+
+/// Types that resist or ignore `attacker`, once its matchup is cached.
+pub fn resisting_types(state: &AppState, attacker: &str) -> Option<Vec<String>> {
+    let matchup = state.type_matchup_cache.get(attacker)?;
+    Some(
+        matchup
+            .half_to
+            .iter()
+            .chain(&matchup.no_to)
+            .cloned()
+            .collect(),
+    )
+}
+
+/// Typing points for a defender against the team's weaknesses, and the ones it patches.
+pub fn typing_score(
+    weaknesses: &[&TypeExposure],
+    defense: &HashMap<String, f32>,
+) -> (i32, Vec<String>) {
+    let mut points = 0;
+    let mut patches = Vec::new();
+    for exposure in weaknesses {
+        let weight = exposure.weak as i32;
+        let multiplier = defense.get(&exposure.attacker).copied().unwrap_or(1.0);
+        if multiplier == 0.0 {
+            points += IMMUNE_POINTS * weight;
+            patches.push(exposure.attacker.clone());
+        } else if multiplier < 1.0 {
+            points += RESIST_POINTS * weight;
+            patches.push(exposure.attacker.clone());
+        } else if multiplier > 1.0 {
+            points -= WEAK_PENALTY * weight;
+        }
+    }
+    (points, patches)
+}
+
+pub fn score(typing: i32, bst: u16) -> i32 {
+    typing * BST_PER_POINT + bst as i32
+}
+
+/// Dex species not on the team, ranked by how much of the team's weakness their
+/// known types resist. Only types whose member lists are cached are known.
+pub fn shortlist(state: &AppState, coverage: &TeamCoverage) -> Vec<String> {
+    let patch_sets: Vec<(usize, Vec<&HashSet<String>>)> = coverage
+        .weaknesses()
+        .into_iter()
+        .map(|exposure| {
+            let members = resisting_types(state, &exposure.attacker)
+                .unwrap_or_default()
+                .iter()
+                .filter_map(|type_name| state.type_cache.get(type_name))
+                .collect();
+            (exposure.weak, members)
+        })
+        .collect();
+
+    let mut ranked: Vec<(usize, u16, &str)> = state
+        .pokedex
+        .iter()
+        .filter(|entry| !state.team.contains(&entry.name))
+        .filter_map(|entry| {
+            let points: usize = patch_sets
+                .iter()
+                .filter(|(_, members)| members.iter().any(|set| set.contains(&entry.name)))
+                .map(|(weak, _)| weak)
+                .sum();
+            (points > 0).then_some((points, entry.entry_number, entry.name.as_str()))
+        })
+        .collect();
+    ranked.sort_by(|a, b| b.0.cmp(&a.0).then_with(|| a.1.cmp(&b.1)));
+    ranked
+        .into_iter()
+        .take(SHORTLIST_SIZE)
+        .map(|(_, _, name)| name.to_string())
+        .collect()
+}
+
+/// Best shortlisted species, once their details and type matchups have loaded.
+pub fn suggestions(state: &AppState, coverage: &TeamCoverage) -> Vec<Suggestion> {
+    let weaknesses = coverage.weaknesses();
+    if weaknesses.is_empty() {
+        return Vec::new();
+    }
+    let mut picks: Vec<Suggestion> = shortlist(state, coverage)
+        .into_iter()
+        .filter_map(|name| {
+            let detail = state.details.get(&name)?;
+            let defense = coverage::defense_multipliers(state, &detail.types)?;
+            let (typing, patches) = typing_score(&weaknesses, &defense);
+            if patches.is_empty() {
+                return None;
+            }
+            let bst = state.base_stat_total(&name)?;
+            Some(Suggestion {
+                name,
+                patches,
+                bst,
+                score: score(typing, bst),
+            })
+        })
+        .collect();
+    picks.sort_by(|a, b| b.score.cmp(&a.score).then_with(|| a.name.cmp(&b.name)));
+    picks.truncate(SUGGESTION_COUNT);
+    picks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::{PokedexEntry, PokemonDetail, PokemonStat, TypeMatchup};
+
+    fn matchup(name: &str, double_from: &[&str], half_from: &[&str], half_to: &[&str]) -> TypeMatchup {
+        let names = |list: &[&str]| list.iter().map(|name| name.to_string()).collect();
+        TypeMatchup {
+            name: name.to_string(),
+            double_from: names(double_from),
+            half_from: names(half_from),
+            no_from: Vec::new(),
+            double_to: Vec::new(),
+            half_to: names(half_to),
+            no_to: Vec::new(),
+        }
+    }
+
+    fn detail(name: &str, types: &[&str], bst: u16) -> PokemonDetail {
+        PokemonDetail {
+            id: 0,
+            name: name.to_string(),
+            types: types.iter().map(|name| name.to_string()).collect(),
+            stats: vec![PokemonStat {
+                name: "hp".to_string(),
+                value: bst,
+            }],
+            abilities: Vec::new(),
+            moves: Vec::new(),
+            height: 0,
+            weight: 0,
+            sprite_front_default: None,
+            sprite_front_shiny: None,
+            sprite_animated: None,
+            cries_latest: None,
+            cries_legacy: None,
+        }
+    }
+
+    /// A grass team weak to fire, with a water and a rock species to choose from.
+    fn state() -> AppState {
+        let mut state = AppState {
+            type_list: ["fire", "water", "grass", "rock"].map(String::from).to_vec(),
+            team: vec!["bulbasaur".to_string()],
+            ..Default::default()
+        };
+        for (name, double_from, half_from, half_to) in [
+            ("fire", &["water", "rock"][..], &["fire", "grass"][..], &["water", "rock", "fire"][..]),
+            ("water", &["grass"][..], &["fire", "water"][..], &["grass", "water"][..]),
+            ("grass", &["fire"][..], &["water", "grass"][..], &["fire", "grass"][..]),
+            ("rock", &["water", "grass"][..], &["fire"][..], &[][..]),
+        ] {
+            state
+                .type_matchup_cache
+                .insert(name.to_string(), matchup(name, double_from, half_from, half_to));
+        }
+        for (number, name) in [(1, "bulbasaur"), (7, "squirtle"), (74, "geodude"), (60, "poliwag")] {
+            state.pokedex.push(PokedexEntry {
+                entry_number: number,
+                name: name.to_string(),
+                url: String::new(),
+            });
+        }
+        state.type_cache.insert(
+            "water".to_string(),
+            HashSet::from(["squirtle".to_string(), "poliwag".to_string()]),
+        );
+        state
+            .type_cache
+            .insert("rock".to_string(), HashSet::from(["geodude".to_string()]));
+        state
+            .details
+            .insert("bulbasaur".to_string(), detail("bulbasaur", &["grass"], 318));
+        state
+            .details
+            .insert("squirtle".to_string(), detail("squirtle", &["water"], 314));
+        state
+            .details
+            .insert("poliwag".to_string(), detail("poliwag", &["water"], 300));
+        state
+            .details
+            .insert("geodude".to_string(), detail("geodude", &["rock"], 300));
+        state
+    }
+
+    #[test]
+    fn typing_score_rewards_resists_and_penalizes_new_weakness() {
+        let exposure = TypeExposure {
+            attacker: "fire".to_string(),
+            weak: 2,
+            resist: 0,
+            immune: 0,
+        };
+        let weaknesses = [&exposure];
+        let resists = HashMap::from([("fire".to_string(), 0.5)]);
+        let immune = HashMap::from([("fire".to_string(), 0.0)]);
+        let weak = HashMap::from([("fire".to_string(), 2.0)]);
+        assert_eq!(typing_score(&weaknesses, &resists), (4, vec!["fire".to_string()]));
+        assert_eq!(typing_score(&weaknesses, &immune), (6, vec!["fire".to_string()]));
+        assert_eq!(typing_score(&weaknesses, &weak), (-4, Vec::new()));
+    }
+
+    #[test]
+    fn shortlist_skips_team_and_orders_by_dex_number_on_ties() {
+        let state = state();
+        let coverage = coverage::team_coverage(&state).expect("coverage");
+        assert_eq!(shortlist(&state, &coverage), ["squirtle", "poliwag", "geodude"]);
+    }
+
+    #[test]
+    fn suggestions_rank_patches_by_score_then_bst() {
+        let state = state();
+        let coverage = coverage::team_coverage(&state).expect("coverage");
+        let picks = suggestions(&state, &coverage);
+        let names: Vec<&str> = picks.iter().map(|pick| pick.name.as_str()).collect();
+        assert_eq!(names, ["squirtle", "geodude", "poliwag"]);
+        assert_eq!(picks[0].patches, ["fire"]);
+        assert_eq!(picks[0].bst, 314);
+    }
+}
@@ -1,34 +1,84 @@
 use std::collections::HashMap;
+use std::time::Instant;
 
 use ratatui::{
     layout::{Alignment, Constraint, Direction, Layout, Rect},
     style::{Color, Modifier, Style},
+    symbols::Marker,
     text::{Line, Span, Text},
-    widgets::{Block, Borders, Paragraph, Tabs, Wrap},
+    widgets::{
+        canvas::{Canvas, Line as CanvasLine},
+        Block, Borders, Clear, Paragraph, Tabs, Wrap,
+    },
     Frame,
 };
-use tui_dispatch::{Component, EventContext, EventKind, HandlerResponse, RenderContext};
+use sprite_cache::SpriteData;
+use tui_dispatch::{
+    BindingContext, Component, EventContext, EventKind, HandlerResponse, Keybindings, RenderContext,
+};
 use tui_dispatch_components::style::BorderStyle;
 use tui_dispatch_components::{
     BaseStyle, Padding, SelectList, SelectListBehavior, SelectListProps, SelectListStyle,
     SelectionStyle, StatusBar, StatusBarHint, StatusBarItem, StatusBarProps, StatusBarSection,
     StatusBarStyle,
 };
+use tui_theme::theme;
 
 use crate::action::Action;
-use crate::sprite;
+use crate::calc;
+use crate::coverage::{self, TeamCoverage};
+use crate::export::{self, ExportFormat};
+use crate::fuzzy::fuzzy_match;
+use crate::keys;
+use crate::repeat::KeyRepeat;
 use crate::sprite_backend;
-use crate::state::{AppState, PokemonStat};
-
-const BG_BASE: Color = Color::Rgb(12, 18, 28);
-const BG_PANEL: Color = Color::Rgb(20, 32, 46);
-const BG_PANEL_ALT: Color = Color::Rgb(26, 40, 58);
-const BG_HIGHLIGHT: Color = Color::Rgb(28, 92, 110);
-const TEXT_MAIN: Color = Color::Rgb(232, 242, 244);
-const TEXT_DIM: Color = Color::Rgb(176, 195, 207);
-const ACCENT_TEAL: Color = Color::Rgb(72, 204, 184);
-const ACCENT_GOLD: Color = Color::Rgb(228, 176, 88);
+use crate::state::{
+    generation_label, item_sprite_key, AppState, CalcPane, CryState, DexRow, DexSort,
+    EncounterGrid, EncounterVersion, EvolutionChain, ItemDetail, LocationPane, MoveSummary,
+    PokedexEntry, PokemonDetail, PokemonStat, SearchMatch, MOVE_PAGE_SIZE,
+};
+
+const COMPARE_BAR_WIDTH: usize = 12;
 const CELL_ASPECT: f32 = 2.0;
+/// Base stat at the rim of the radar chart; the few above it are pinned there.
+const RADAR_STAT_MAX: f64 = 200.0;
+/// Radar half-extent along the shorter axis, leaving room for the labels.
+const RADAR_EXTENT: f64 = 1.6;
+const CRY_SCOPE_HEIGHT: u16 = 3;
+const TEAM_SIZE: usize = 6;
+const TEAM_COLUMNS: usize = 3;
+const ITEM_PAGE: i16 = 10;
+const LOCATION_PAGE: i16 = 10;
+const HELP_PAGE: i16 = 10;
+/// Rows per jump-list entry, enough for a small sprite.
+const BOOKMARK_ROW_HEIGHT: u16 = 3;
+const BOOKMARK_SPRITE_WIDTH: u16 = 8;
+/// Global commands in the footer, in order. `filter_next` stands for the
+/// `[`/`]` pair.
+const FOOTER_COMMANDS: [&str; 14] = [
+    "focus_next",
+    "search",
+    "filter_next",
+    "regions",
+    "sort",
+    "team",
+    "bookmarks",
+    "items",
+    "locations",
+    "notes",
+    "export",
+    "cry",
+    "help",
+    "quit",
+];
+const STAT_ORDER: [&str; 6] = [
+    "hp",
+    "attack",
+    "defense",
+    "special-attack",
+    "special-defense",
+    "speed",
+];
 
 pub struct PokeUi {
     dex_list: SelectList,
@@ -36,18 +86,34 @@ pub struct PokeUi {
     move_list: SelectList,
     ability_list: SelectList,
     encounter_list: SelectList,
+    region_list: SelectList,
+    item_list: SelectList,
+    location_list: SelectList,
+    location_pokemon_list: SelectList,
+    /// Held j/k or arrows in the dex list, to speed scrolling up.
+    dex_repeat: KeyRepeat,
     status_bar: StatusBar,
+    keybindings: Keybindings<crate::PokeContext>,
+    /// Furthest the help overlay can scroll at its last rendered size.
+    help_max_scroll: u16,
 }
 
 impl PokeUi {
-    pub fn new() -> Self {
+    pub fn new(keybindings: Keybindings<crate::PokeContext>) -> Self {
         Self {
             dex_list: SelectList::new(),
             evolution_list: SelectList::new(),
             move_list: SelectList::new(),
             ability_list: SelectList::new(),
             encounter_list: SelectList::new(),
+            region_list: SelectList::new(),
+            item_list: SelectList::new(),
+            location_list: SelectList::new(),
+            location_pokemon_list: SelectList::new(),
+            dex_repeat: KeyRepeat::default(),
             status_bar: StatusBar::new(),
+            keybindings,
+            help_max_scroll: 0,
         }
     }
 
@@ -70,8 +136,40 @@ impl PokeUi {
             &mut self.move_list,
             &mut self.ability_list,
             &mut self.encounter_list,
+            &mut self.item_list,
+            &mut self.location_list,
+            &mut self.location_pokemon_list,
             &mut self.status_bar,
+            &self.keybindings,
         );
+        if state.region_picker.active {
+            render_region_picker(frame, area, state, event_ctx, &mut self.region_list);
+        }
+        if state.note_editor.active {
+            render_note_editor(frame, area, state, event_ctx);
+        }
+        if state.export_picker.active {
+            render_export_picker(frame, area, state, event_ctx);
+        }
+        if state.report_prompt.active {
+            render_report_prompt(frame, area, state, event_ctx);
+        }
+        if state.sort_picker.active {
+            render_sort_picker(frame, area, state, event_ctx);
+        }
+        if state.bookmark_list.active {
+            render_bookmark_list(frame, area, state, event_ctx);
+        }
+        if state.calc.active {
+            render_damage_calc(frame, area, state, event_ctx);
+        }
+        if state.featured.active {
+            render_featured_card(frame, area, state, event_ctx);
+        }
+        if state.help.active {
+            self.help_max_scroll =
+                render_help_overlay(frame, area, state, event_ctx, &self.keybindings);
+        }
     }
 
     pub fn handle_evolution_event(
@@ -95,7 +193,7 @@ impl PokeUi {
         event: &EventKind,
         state: &AppState,
     ) -> HandlerResponse<Action> {
-        handle_list_event(event, state, &mut self.dex_list)
+        handle_list_event(event, state, &mut self.dex_list, &mut self.dex_repeat)
     }
 
     pub fn handle_detail_tabs_event(
@@ -112,6 +210,35 @@ impl PokeUi {
         )
     }
 
+    pub fn handle_team_event(
+        &mut self,
+        event: &EventKind,
+        state: &AppState,
+    ) -> HandlerResponse<Action> {
+        handle_team_event(event, state)
+    }
+
+    pub fn handle_items_event(
+        &mut self,
+        event: &EventKind,
+        state: &AppState,
+    ) -> HandlerResponse<Action> {
+        handle_items_event(event, state, &mut self.item_list)
+    }
+
+    pub fn handle_locations_event(
+        &mut self,
+        event: &EventKind,
+        state: &AppState,
+    ) -> HandlerResponse<Action> {
+        handle_locations_event(
+            event,
+            state,
+            &mut self.location_list,
+            &mut self.location_pokemon_list,
+        )
+    }
+
     pub fn handle_search_event(
         &mut self,
         event: &EventKind,
@@ -119,6 +246,78 @@ impl PokeUi {
     ) -> HandlerResponse<Action> {
         handle_search_event(event, state)
     }
+
+    pub fn handle_note_editor_event(
+        &mut self,
+        event: &EventKind,
+        state: &AppState,
+    ) -> HandlerResponse<Action> {
+        handle_note_editor_event(event, state)
+    }
+
+    pub fn handle_export_picker_event(
+        &mut self,
+        event: &EventKind,
+        state: &AppState,
+    ) -> HandlerResponse<Action> {
+        handle_export_picker_event(event, state)
+    }
+
+    pub fn handle_report_prompt_event(
+        &mut self,
+        event: &EventKind,
+        state: &AppState,
+    ) -> HandlerResponse<Action> {
+        handle_report_prompt_event(event, state)
+    }
+
+    pub fn handle_sort_picker_event(
+        &mut self,
+        event: &EventKind,
+        state: &AppState,
+    ) -> HandlerResponse<Action> {
+        handle_sort_picker_event(event, state)
+    }
+
+    pub fn handle_bookmark_list_event(
+        &mut self,
+        event: &EventKind,
+        state: &AppState,
+    ) -> HandlerResponse<Action> {
+        handle_bookmark_list_event(event, state)
+    }
+
+    pub fn handle_help_event(
+        &mut self,
+        event: &EventKind,
+        state: &AppState,
+    ) -> HandlerResponse<Action> {
+        handle_help_event(event, state, self.help_max_scroll)
+    }
+
+    pub fn handle_calc_event(
+        &mut self,
+        event: &EventKind,
+        state: &AppState,
+    ) -> HandlerResponse<Action> {
+        handle_calc_event(event, state)
+    }
+
+    pub fn handle_featured_event(
+        &mut self,
+        event: &EventKind,
+        state: &AppState,
+    ) -> HandlerResponse<Action> {
+        handle_featured_event(event, state)
+    }
+
+    pub fn handle_region_picker_event(
+        &mut self,
+        event: &EventKind,
+        state: &AppState,
+    ) -> HandlerResponse<Action> {
+        handle_region_picker_event(event, state, &mut self.region_list)
+    }
 }
 
 pub fn render_app(
@@ -132,9 +331,13 @@ pub fn render_app(
     move_list: &mut SelectList,
     ability_list: &mut SelectList,
     encounter_list: &mut SelectList,
+    item_list: &mut SelectList,
+    location_list: &mut SelectList,
+    location_pokemon_list: &mut SelectList,
     status_bar: &mut StatusBar,
+    keybindings: &Keybindings<crate::PokeContext>,
 ) {
-    let base = Block::default().style(Style::default().bg(BG_BASE));
+    let base = Block::default().style(Style::default().bg(theme().bg));
     frame.render_widget(base, area);
     let layout = Layout::default()
         .direction(Direction::Vertical)
@@ -146,18 +349,33 @@ pub fn render_app(
         .split(area);
 
     render_header(frame, layout[0], state, event_ctx);
-    render_body(
-        frame,
-        layout[1],
-        state,
-        event_ctx,
-        dex_list,
-        evolution_list,
-        move_list,
-        ability_list,
-        encounter_list,
-    );
-    render_footer(frame, layout[2], state, status_bar);
+    if state.focus == crate::state::FocusArea::Team {
+        render_team(frame, layout[1], state, event_ctx);
+    } else if state.focus == crate::state::FocusArea::Items {
+        render_items(frame, layout[1], state, event_ctx, item_list);
+    } else if state.focus == crate::state::FocusArea::Locations {
+        render_locations(
+            frame,
+            layout[1],
+            state,
+            event_ctx,
+            location_list,
+            location_pokemon_list,
+        );
+    } else {
+        render_body(
+            frame,
+            layout[1],
+            state,
+            event_ctx,
+            dex_list,
+            evolution_list,
+            move_list,
+            ability_list,
+            encounter_list,
+        );
+    }
+    render_footer(frame, layout[2], state, status_bar, keybindings);
 }
 
 pub fn handle_header_event(event: &EventKind, _state: &AppState) -> HandlerResponse<Action> {
@@ -175,6 +393,7 @@ pub fn handle_list_event(
     event: &EventKind,
     state: &AppState,
     dex_list: &mut SelectList,
+    repeat: &mut KeyRepeat,
 ) -> HandlerResponse<Action> {
     let actions = match event {
         EventKind::Key(key) => {
@@ -200,11 +419,34 @@ pub fn handle_list_event(
                 }
                 return handler_response(Vec::new());
             }
+            let down = match key.code {
+                crossterm::event::KeyCode::Down | crossterm::event::KeyCode::Char('j') => {
+                    Some(true)
+                }
+                crossterm::event::KeyCode::Up | crossterm::event::KeyCode::Char('k') => Some(false),
+                _ => None,
+            };
+            if let Some(down) = down {
+                let step = repeat.press(key.code, Instant::now()) as i16;
+                if step > 1 {
+                    let delta = if down { step } else { -step };
+                    return handler_response(vec![Action::SelectionMove(delta)]);
+                }
+            }
             match key.code {
                 crossterm::event::KeyCode::PageDown => vec![Action::SelectionPage(1)],
                 crossterm::event::KeyCode::PageUp => vec![Action::SelectionPage(-1)],
                 crossterm::event::KeyCode::Char('f') => vec![Action::ToggleFavorite],
                 crossterm::event::KeyCode::Char('t') => vec![Action::ToggleTeam],
+                crossterm::event::KeyCode::Char('F') => vec![Action::FamilyViewToggle],
+                crossterm::event::KeyCode::Char(' ') if state.family_view => {
+                    vec![Action::FamilyCollapseToggle]
+                }
+                crossterm::event::KeyCode::Char('C') => match (&state.compare, state.selected_name()) {
+                    (Some(_), _) => vec![Action::CompareClose],
+                    (None, Some(name)) => vec![Action::CompareWith(name)],
+                    (None, None) => vec![],
+                },
                 _ => {
                     let items = dex_items(state);
                     let props = SelectListProps {
@@ -247,6 +489,16 @@ pub fn handle_detail_tabs_event(
             crossterm::event::KeyCode::Right | crossterm::event::KeyCode::Char('l') => {
                 vec![Action::DetailTabNext]
             }
+            crossterm::event::KeyCode::Char('v')
+                if state.detail_mode == crate::state::DetailMode::Matchup =>
+            {
+                vec![Action::MatchupViewToggle]
+            }
+            crossterm::event::KeyCode::Char('s')
+                if state.detail_mode == crate::state::DetailMode::Move =>
+            {
+                vec![Action::MoveSortNext]
+            }
             _ => vec![],
         },
         _ => vec![],
@@ -256,7 +508,8 @@ pub fn handle_detail_tabs_event(
     }
     match state.detail_mode {
         crate::state::DetailMode::Move => {
-            let items = move_items(state);
+            // Only the row count matters for navigation, not the column layout.
+            let items = move_items(state, move_columns(state.terminal_size.0));
             let props = SelectListProps {
                 items: &items,
                 count: items.len(),
@@ -356,6 +609,176 @@ fn handle_evolution_event(
     handler_response(actions)
 }
 
+pub fn handle_items_event(
+    event: &EventKind,
+    state: &AppState,
+    item_list: &mut SelectList,
+) -> HandlerResponse<Action> {
+    let actions = match event {
+        EventKind::Key(key) if state.items.searching => match key.code {
+            crossterm::event::KeyCode::Esc => vec![Action::ItemSearchCancel],
+            crossterm::event::KeyCode::Enter => vec![Action::ItemSearchSubmit],
+            crossterm::event::KeyCode::Backspace => vec![Action::ItemSearchBackspace],
+            crossterm::event::KeyCode::Up => vec![Action::ItemMove(-1)],
+            crossterm::event::KeyCode::Down => vec![Action::ItemMove(1)],
+            crossterm::event::KeyCode::Char(ch) => vec![Action::ItemSearchInput(ch)],
+            _ => vec![],
+        },
+        EventKind::Key(key) => match key.code {
+            crossterm::event::KeyCode::Esc | crossterm::event::KeyCode::Char('I') => {
+                vec![Action::ItemsClose]
+            }
+            crossterm::event::KeyCode::Char('q') => vec![Action::Quit],
+            crossterm::event::KeyCode::Char('?') => vec![Action::HelpOpen],
+            crossterm::event::KeyCode::Char('/') => vec![Action::ItemSearchStart],
+            crossterm::event::KeyCode::Up | crossterm::event::KeyCode::Char('k') => {
+                vec![Action::ItemMove(-1)]
+            }
+            crossterm::event::KeyCode::Down | crossterm::event::KeyCode::Char('j') => {
+                vec![Action::ItemMove(1)]
+            }
+            crossterm::event::KeyCode::PageUp => vec![Action::ItemMove(-ITEM_PAGE)],
+            crossterm::event::KeyCode::PageDown => vec![Action::ItemMove(ITEM_PAGE)],
+            _ => vec![],
+        },
+        EventKind::Scroll { delta, .. } => vec![Action::ItemMove((*delta * 3) as i16)],
+        _ => {
+            let items = item_list_items(state);
+            let props = SelectListProps {
+                items: &items,
+                count: items.len(),
+                selected: state.items.selected.min(items.len().saturating_sub(1)),
+                is_focused: true,
+                style: dex_list_style(),
+                behavior: SelectListBehavior {
+                    show_scrollbar: true,
+                    wrap_navigation: false,
+                },
+                on_select: Action::ItemSelect,
+                render_item: &|item| item.clone(),
+            };
+            item_list.handle_event(event, props).into_iter().collect()
+        }
+    };
+    handler_response(actions)
+}
+
+pub fn handle_locations_event(
+    event: &EventKind,
+    state: &AppState,
+    location_list: &mut SelectList,
+    location_pokemon_list: &mut SelectList,
+) -> HandlerResponse<Action> {
+    let on_pokemon = state.locations.pane == LocationPane::Pokemon;
+    let step = |delta: i16| {
+        if on_pokemon {
+            Action::LocationPokemonMove(delta)
+        } else {
+            Action::LocationMove(delta)
+        }
+    };
+    let actions = match event {
+        EventKind::Key(key) => match key.code {
+            crossterm::event::KeyCode::Esc | crossterm::event::KeyCode::Char('L') => {
+                vec![Action::LocationsClose]
+            }
+            crossterm::event::KeyCode::Char('q') => vec![Action::Quit],
+            crossterm::event::KeyCode::Char('?') => vec![Action::HelpOpen],
+            crossterm::event::KeyCode::Tab
+            | crossterm::event::KeyCode::BackTab
+            | crossterm::event::KeyCode::Left
+            | crossterm::event::KeyCode::Right
+            | crossterm::event::KeyCode::Char('h')
+            | crossterm::event::KeyCode::Char('l') => vec![Action::LocationPaneToggle],
+            crossterm::event::KeyCode::Enter if on_pokemon => vec![Action::LocationShowPokemon],
+            crossterm::event::KeyCode::Enter => vec![Action::LocationPaneToggle],
+            crossterm::event::KeyCode::Up | crossterm::event::KeyCode::Char('k') => vec![step(-1)],
+            crossterm::event::KeyCode::Down | crossterm::event::KeyCode::Char('j') => vec![step(1)],
+            crossterm::event::KeyCode::PageUp => vec![step(-LOCATION_PAGE)],
+            crossterm::event::KeyCode::PageDown => vec![step(LOCATION_PAGE)],
+            _ => vec![],
+        },
+        EventKind::Scroll { delta, .. } => vec![step((*delta * 3) as i16)],
+        _ if on_pokemon => {
+            let items = location_pokemon_items(state);
+            let props = SelectListProps {
+                items: &items,
+                count: items.len(),
+                selected: state
+                    .locations
+                    .pokemon_selected
+                    .min(items.len().saturating_sub(1)),
+                is_focused: true,
+                style: dex_list_style(),
+                behavior: SelectListBehavior {
+                    show_scrollbar: true,
+                    wrap_navigation: false,
+                },
+                on_select: Action::LocationPokemonSelect,
+                render_item: &|item| item.clone(),
+            };
+            location_pokemon_list
+                .handle_event(event, props)
+                .into_iter()
+                .collect()
+        }
+        _ => {
+            let items = location_items(state);
+            let props = SelectListProps {
+                items: &items,
+                count: items.len(),
+                selected: state.locations.selected.min(items.len().saturating_sub(1)),
+                is_focused: true,
+                style: dex_list_style(),
+                behavior: SelectListBehavior {
+                    show_scrollbar: true,
+                    wrap_navigation: false,
+                },
+                on_select: Action::LocationSelect,
+                render_item: &|item| item.clone(),
+            };
+            location_list.handle_event(event, props).into_iter().collect()
+        }
+    };
+    handler_response(actions)
+}
+
+pub fn handle_team_event(event: &EventKind, state: &AppState) -> HandlerResponse<Action> {
+    let selected = state.team_selected;
+    let actions = match event {
+        EventKind::Key(key) => {
+            let shift = key
+                .modifiers
+                .contains(crossterm::event::KeyModifiers::SHIFT);
+            match key.code {
+                crossterm::event::KeyCode::Esc => vec![Action::TeamClose],
+                crossterm::event::KeyCode::Char('H') => vec![Action::TeamReorder(-1)],
+                crossterm::event::KeyCode::Char('L') => vec![Action::TeamReorder(1)],
+                crossterm::event::KeyCode::Left if shift => vec![Action::TeamReorder(-1)],
+                crossterm::event::KeyCode::Right if shift => vec![Action::TeamReorder(1)],
+                crossterm::event::KeyCode::Left | crossterm::event::KeyCode::Char('h') => {
+                    vec![Action::TeamSelect(selected.saturating_sub(1))]
+                }
+                crossterm::event::KeyCode::Right | crossterm::event::KeyCode::Char('l') => {
+                    vec![Action::TeamSelect(selected + 1)]
+                }
+                crossterm::event::KeyCode::Up | crossterm::event::KeyCode::Char('k') => {
+                    vec![Action::TeamSelect(selected.saturating_sub(TEAM_COLUMNS))]
+                }
+                crossterm::event::KeyCode::Down | crossterm::event::KeyCode::Char('j') => {
+                    vec![Action::TeamSelect(selected + TEAM_COLUMNS)]
+                }
+                crossterm::event::KeyCode::Char('x') | crossterm::event::KeyCode::Delete => {
+                    vec![Action::TeamRemove]
+                }
+                _ => vec![],
+            }
+        }
+        _ => vec![],
+    };
+    handler_response(actions)
+}
+
 pub fn handle_search_event(event: &EventKind, _state: &AppState) -> HandlerResponse<Action> {
     let actions = match event {
         EventKind::Key(key) => match key.code {
@@ -370,36 +793,303 @@ pub fn handle_search_event(event: &EventKind, _state: &AppState) -> HandlerRespo
     handler_response(actions)
 }
 
-fn handler_response(actions: Vec<Action>) -> HandlerResponse<Action> {
-    if actions.is_empty() {
-        HandlerResponse::ignored()
-    } else {
-        HandlerResponse {
-            actions,
-            consumed: true,
-            needs_render: false,
+pub fn handle_region_picker_event(
+    event: &EventKind,
+    state: &AppState,
+    region_list: &mut SelectList,
+) -> HandlerResponse<Action> {
+    let actions = match event {
+        EventKind::Key(key) => match key.code {
+            crossterm::event::KeyCode::Esc => vec![Action::RegionPickerClose],
+            crossterm::event::KeyCode::Enter => vec![Action::RegionPickerConfirm],
+            crossterm::event::KeyCode::Backspace => vec![Action::RegionPickerBackspace],
+            crossterm::event::KeyCode::Up => vec![Action::RegionPickerMove(-1)],
+            crossterm::event::KeyCode::Down => vec![Action::RegionPickerMove(1)],
+            crossterm::event::KeyCode::PageUp => vec![Action::RegionPickerMove(-10)],
+            crossterm::event::KeyCode::PageDown => vec![Action::RegionPickerMove(10)],
+            crossterm::event::KeyCode::Char(ch) => vec![Action::RegionPickerInput(ch)],
+            _ => vec![],
+        },
+        _ => {
+            let items = region_picker_items(state);
+            let props = SelectListProps {
+                items: &items,
+                count: items.len(),
+                selected: state
+                    .region_picker
+                    .selected
+                    .min(items.len().saturating_sub(1)),
+                is_focused: true,
+                style: detail_list_style(),
+                behavior: SelectListBehavior {
+                    show_scrollbar: true,
+                    wrap_navigation: false,
+                },
+                on_select: Action::RegionPickerSelect,
+                render_item: &|item| item.clone(),
+            };
+            region_list.handle_event(event, props).into_iter().collect()
         }
+    };
+    handler_response(actions)
+}
+
+pub fn handle_note_editor_event(event: &EventKind, _state: &AppState) -> HandlerResponse<Action> {
+    let EventKind::Key(key) = event else {
+        return HandlerResponse::ignored();
+    };
+    let ctrl = key
+        .modifiers
+        .contains(crossterm::event::KeyModifiers::CONTROL);
+    let actions = match key.code {
+        crossterm::event::KeyCode::Char('s') if ctrl => vec![Action::NoteEditSave],
+        crossterm::event::KeyCode::Char(_) if ctrl => vec![],
+        crossterm::event::KeyCode::Esc => vec![Action::NoteEditCancel],
+        crossterm::event::KeyCode::Enter => vec![Action::NoteEditNewline],
+        crossterm::event::KeyCode::Backspace => vec![Action::NoteEditBackspace],
+        crossterm::event::KeyCode::Delete => vec![Action::NoteEditDelete],
+        crossterm::event::KeyCode::Home => vec![Action::NoteEditHome],
+        crossterm::event::KeyCode::End => vec![Action::NoteEditEnd],
+        crossterm::event::KeyCode::Up => vec![Action::NoteEditMove { rows: -1, cols: 0 }],
+        crossterm::event::KeyCode::Down => vec![Action::NoteEditMove { rows: 1, cols: 0 }],
+        crossterm::event::KeyCode::Left => vec![Action::NoteEditMove { rows: 0, cols: -1 }],
+        crossterm::event::KeyCode::Right => vec![Action::NoteEditMove { rows: 0, cols: 1 }],
+        crossterm::event::KeyCode::Char(ch) => vec![Action::NoteEditInput(ch)],
+        _ => vec![],
+    };
+    // Consume every key so typing never leaks into the screens underneath.
+    HandlerResponse {
+        actions,
+        consumed: true,
+        needs_render: false,
     }
 }
 
-fn render_header(
-    frame: &mut Frame,
-    area: Rect,
-    state: &AppState,
-    event_ctx: &mut EventContext<crate::PokeComponentId>,
-) {
-    event_ctx.set_component_area(crate::PokeComponentId::Header, area);
-    if state.search.active {
-        event_ctx.set_component_area(crate::PokeComponentId::Search, area);
+pub fn handle_export_picker_event(event: &EventKind, _state: &AppState) -> HandlerResponse<Action> {
+    let EventKind::Key(key) = event else {
+        return HandlerResponse::ignored();
+    };
+    let actions = match key.code {
+        crossterm::event::KeyCode::Esc | crossterm::event::KeyCode::Char('x') => {
+            vec![Action::ExportClose]
+        }
+        crossterm::event::KeyCode::Up | crossterm::event::KeyCode::Char('k') => {
+            vec![Action::ExportMove(-1)]
+        }
+        crossterm::event::KeyCode::Down | crossterm::event::KeyCode::Char('j') => {
+            vec![Action::ExportMove(1)]
+        }
+        crossterm::event::KeyCode::Enter => vec![Action::ExportConfirm],
+        _ => vec![],
+    };
+    HandlerResponse {
+        actions,
+        consumed: true,
+        needs_render: false,
     }
-    let title_style = Style::default()
-        .fg(ACCENT_TEAL)
-        .add_modifier(Modifier::BOLD);
-    let filter = state
-        .type_filter
+}
+
+pub fn handle_report_prompt_event(event: &EventKind, _state: &AppState) -> HandlerResponse<Action> {
+    let EventKind::Key(key) = event else {
+        return HandlerResponse::ignored();
+    };
+    let actions = match key.code {
+        crossterm::event::KeyCode::Esc => vec![Action::ReportClose],
+        crossterm::event::KeyCode::Enter => vec![Action::ReportConfirm],
+        crossterm::event::KeyCode::Backspace => vec![Action::ReportBackspace],
+        crossterm::event::KeyCode::Char(ch) => vec![Action::ReportInput(ch)],
+        _ => vec![],
+    };
+    HandlerResponse {
+        actions,
+        consumed: true,
+        needs_render: false,
+    }
+}
+
+pub fn handle_featured_event(event: &EventKind, _state: &AppState) -> HandlerResponse<Action> {
+    let EventKind::Key(key) = event else {
+        return HandlerResponse::ignored();
+    };
+    let actions = match key.code {
+        crossterm::event::KeyCode::Enter => vec![Action::FeaturedJump],
+        crossterm::event::KeyCode::Char('q') => vec![Action::Quit],
+        // Any other key just gets the card out of the way.
+        _ => vec![Action::FeaturedClose],
+    };
+    HandlerResponse {
+        actions,
+        consumed: true,
+        needs_render: false,
+    }
+}
+
+pub fn handle_help_event(
+    event: &EventKind,
+    state: &AppState,
+    max_scroll: u16,
+) -> HandlerResponse<Action> {
+    let scroll_by = |delta: i16| {
+        let next = state
+            .help
+            .scroll
+            .saturating_add_signed(delta)
+            .min(max_scroll);
+        let delta = i32::from(next) - i32::from(state.help.scroll);
+        if delta == 0 {
+            vec![]
+        } else {
+            vec![Action::HelpScroll(delta as i16)]
+        }
+    };
+    let actions = match event {
+        EventKind::Key(key) => match key.code {
+            crossterm::event::KeyCode::Esc
+            | crossterm::event::KeyCode::Char('?')
+            | crossterm::event::KeyCode::Char('q') => vec![Action::HelpClose],
+            crossterm::event::KeyCode::Up | crossterm::event::KeyCode::Char('k') => scroll_by(-1),
+            crossterm::event::KeyCode::Down | crossterm::event::KeyCode::Char('j') => scroll_by(1),
+            crossterm::event::KeyCode::PageUp => scroll_by(-HELP_PAGE),
+            crossterm::event::KeyCode::PageDown => scroll_by(HELP_PAGE),
+            _ => vec![],
+        },
+        EventKind::Scroll { delta, .. } => scroll_by(*delta as i16),
+        _ => vec![],
+    };
+    HandlerResponse {
+        actions,
+        consumed: true,
+        needs_render: false,
+    }
+}
+
+pub fn handle_sort_picker_event(event: &EventKind, _state: &AppState) -> HandlerResponse<Action> {
+    let EventKind::Key(key) = event else {
+        return HandlerResponse::ignored();
+    };
+    let actions = match key.code {
+        crossterm::event::KeyCode::Esc | crossterm::event::KeyCode::Char('o') => {
+            vec![Action::SortClose]
+        }
+        crossterm::event::KeyCode::Up | crossterm::event::KeyCode::Char('k') => {
+            vec![Action::SortMove(-1)]
+        }
+        crossterm::event::KeyCode::Down | crossterm::event::KeyCode::Char('j') => {
+            vec![Action::SortMove(1)]
+        }
+        crossterm::event::KeyCode::Enter => vec![Action::SortConfirm],
+        _ => vec![],
+    };
+    HandlerResponse {
+        actions,
+        consumed: true,
+        needs_render: false,
+    }
+}
+
+pub fn handle_bookmark_list_event(event: &EventKind, state: &AppState) -> HandlerResponse<Action> {
+    let EventKind::Key(key) = event else {
+        return HandlerResponse::ignored();
+    };
+    let actions = match key.code {
+        crossterm::event::KeyCode::Esc | crossterm::event::KeyCode::Char('\'') => {
+            vec![Action::BookmarksClose]
+        }
+        crossterm::event::KeyCode::Up | crossterm::event::KeyCode::Char('k') => {
+            vec![Action::BookmarksMove(-1)]
+        }
+        crossterm::event::KeyCode::Down | crossterm::event::KeyCode::Char('j') => {
+            vec![Action::BookmarksMove(1)]
+        }
+        crossterm::event::KeyCode::Enter => {
+            match state.bookmarks.get(state.bookmark_list.selected) {
+                Some(name) => {
+                    let jump = match state.row_position(name) {
+                        Some(index) => vec![
+                            Action::FocusSet(crate::state::FocusArea::DexList),
+                            Action::DexSelect(index),
+                        ],
+                        None => vec![Action::BookmarkShow(name.clone())],
+                    };
+                    std::iter::once(Action::BookmarksClose)
+                        .chain(jump)
+                        .collect()
+                }
+                None => vec![Action::BookmarksClose],
+            }
+        }
+        _ => vec![],
+    };
+    HandlerResponse {
+        actions,
+        consumed: true,
+        needs_render: false,
+    }
+}
+
+pub fn handle_calc_event(event: &EventKind, state: &AppState) -> HandlerResponse<Action> {
+    let EventKind::Key(key) = event else {
+        return HandlerResponse::ignored();
+    };
+    let on_moves = state.calc.pane == CalcPane::Move;
+    let actions = match key.code {
+        crossterm::event::KeyCode::Esc => vec![Action::CalcClose],
+        crossterm::event::KeyCode::Tab | crossterm::event::KeyCode::BackTab => {
+            vec![Action::CalcPaneToggle]
+        }
+        crossterm::event::KeyCode::Enter => vec![Action::CalcConfirm],
+        crossterm::event::KeyCode::Backspace => vec![Action::CalcBackspace],
+        crossterm::event::KeyCode::Up => vec![Action::CalcMove(-1)],
+        crossterm::event::KeyCode::Down => vec![Action::CalcMove(1)],
+        crossterm::event::KeyCode::PageUp => vec![Action::CalcMove(-10)],
+        crossterm::event::KeyCode::PageDown => vec![Action::CalcMove(10)],
+        crossterm::event::KeyCode::Char('k') if on_moves => vec![Action::CalcMove(-1)],
+        crossterm::event::KeyCode::Char('j') if on_moves => vec![Action::CalcMove(1)],
+        crossterm::event::KeyCode::Char(ch) => vec![Action::CalcInput(ch)],
+        _ => vec![],
+    };
+    // The defender search takes any character, so nothing leaks underneath.
+    HandlerResponse {
+        actions,
+        consumed: true,
+        needs_render: false,
+    }
+}
+
+fn handler_response(actions: Vec<Action>) -> HandlerResponse<Action> {
+    if actions.is_empty() {
+        HandlerResponse::ignored()
+    } else {
+        HandlerResponse {
+            actions,
+            consumed: true,
+            needs_render: false,
+        }
+    }
+}
+
+fn render_header(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    event_ctx: &mut EventContext<crate::PokeComponentId>,
+) {
+    event_ctx.set_component_area(crate::PokeComponentId::Header, area);
+    if state.search.active {
+        event_ctx.set_component_area(crate::PokeComponentId::Search, area);
+    }
+    let title_style = Style::default()
+        .fg(theme().accent)
+        .add_modifier(Modifier::BOLD);
+    let filter = state
+        .type_filter
         .as_deref()
         .map(|name| name.to_ascii_uppercase())
         .unwrap_or_else(|| "ALL".to_string());
+    let generation = state
+        .generation_filter
+        .map(generation_label)
+        .unwrap_or_else(|| "ALL".to_string());
     let search = if state.search.active {
         format!("/{}_", state.search.query)
     } else if state.search.query.is_empty() {
@@ -419,35 +1109,40 @@ fn render_header(
             Span::raw("  "),
             Span::styled(
                 format!("ROUTE {:02}/{:02}", route_index, route_total),
-                Style::default().fg(ACCENT_GOLD),
+                Style::default().fg(theme().gold),
             ),
             Span::raw("  |  Type: "),
-            Span::styled(filter, Style::default().fg(ACCENT_GOLD)),
+            Span::styled(filter, Style::default().fg(theme().gold)),
+            Span::raw("  |  Gen: "),
+            Span::styled(generation, Style::default().fg(theme().gold)),
             Span::raw("  |  Search: "),
-            Span::styled(search, Style::default().fg(ACCENT_TEAL)),
+            Span::styled(search, Style::default().fg(theme().accent)),
         ]),
         Line::from(vec![
             Span::raw("Seen: "),
-            Span::styled(format!("{seen}/{total}"), Style::default().fg(ACCENT_TEAL)),
+            Span::styled(
+                format!("{seen}/{total}"),
+                Style::default().fg(theme().accent),
+            ),
             Span::raw("  Caught: "),
             Span::styled(
                 format!("{caught}/{total}"),
-                Style::default().fg(ACCENT_GOLD),
+                Style::default().fg(theme().gold),
             ),
             Span::raw("  |  Team: "),
-            Span::styled(state.team.join(", "), Style::default().fg(ACCENT_TEAL)),
+            Span::styled(state.team.join(", "), Style::default().fg(theme().accent)),
         ]),
     ]);
 
     let block = Block::default()
         .borders(Borders::ALL)
-        .style(Style::default().bg(BG_PANEL).fg(TEXT_MAIN))
+        .style(Style::default().bg(theme().panel).fg(theme().text))
         .border_style(focus_border(state, crate::state::FocusArea::Header))
         .title("POKEDEX");
     let paragraph = Paragraph::new(header_text)
         .block(block)
         .wrap(Wrap { trim: true })
-        .style(Style::default().fg(TEXT_MAIN));
+        .style(Style::default().fg(theme().text));
     frame.render_widget(paragraph, area);
 }
 
@@ -480,6 +1175,22 @@ fn render_body(
     );
 }
 
+/// `DEX`, plus the family view and the sort order when either is on, e.g.
+/// `DEX · FAMILIES · ▼ATK`. The sort tag trails an ellipsis while stats load.
+fn dex_title(state: &AppState) -> String {
+    let mut title = "DEX".to_string();
+    if state.family_view {
+        title.push_str(" \u{00b7} FAMILIES");
+    }
+    if state.dex_sort != DexSort::Number {
+        title.push_str(&format!(" \u{00b7} \u{25bc}{}", state.dex_sort.short()));
+        if state.base_stats_loading {
+            title.push('\u{2026}');
+        }
+    }
+    title
+}
+
 fn render_list(
     frame: &mut Frame,
     area: Rect,
@@ -490,8 +1201,8 @@ fn render_list(
     event_ctx.set_component_area(crate::PokeComponentId::DexList, area);
     let block = Block::default()
         .borders(Borders::ALL)
-        .title("DEX")
-        .style(Style::default().bg(BG_PANEL).fg(TEXT_MAIN))
+        .title(dex_title(state))
+        .style(Style::default().bg(theme().panel).fg(theme().text))
         .border_style(focus_border(state, crate::state::FocusArea::DexList));
     let inner = block.inner(area);
     frame.render_widget(block, area);
@@ -523,10 +1234,14 @@ fn render_detail(
     ability_list: &mut SelectList,
     encounter_list: &mut SelectList,
 ) {
+    if let Some(pinned) = state.compare.as_deref() {
+        render_compare(frame, area, state, pinned);
+        return;
+    }
     let block = Block::default()
         .borders(Borders::ALL)
         .title("DATA")
-        .style(Style::default().bg(BG_PANEL).fg(TEXT_MAIN));
+        .style(Style::default().bg(theme().panel).fg(theme().text));
     let inner = block.inner(area);
     frame.render_widget(block, area);
     let layout = Layout::default()
@@ -539,12 +1254,20 @@ fn render_detail(
         .constraints([Constraint::Percentage(60), Constraint::Percentage(40)])
         .split(layout[0]);
 
-    let sprite_area = Rect {
+    let mut sprite_area = Rect {
         x: top[0].x,
         y: top[0].y.saturating_add(1),
         width: top[0].width,
         height: top[0].height.saturating_sub(2),
     };
+    if state.cry.name.is_some() && state.cry.name == state.detail_name {
+        let parts = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(CRY_SCOPE_HEIGHT)])
+            .split(sprite_area);
+        sprite_area = parts[0];
+        render_cry_scope(frame, parts[1], state);
+    }
     render_sprite(frame, sprite_area, state);
     render_stats_panel(frame, top[1], state);
     render_secondary(
@@ -564,10 +1287,10 @@ fn render_sprite(frame: &mut Frame, area: Rect, state: &AppState) {
         if let Some(sprite) = state.sprite_cache.get(name) {
             let (cols, rows) = sprite_fit(sprite, area.width, area.height);
             let sprite_frame = sprite.frame(state.sprite_frame_index);
-            if let Ok(sequence) = sprite::kitty_sequence(sprite_frame, cols, rows) {
+            if let Ok(image) = sprite_backend::encode_frame(sprite_frame, cols, rows) {
                 let offset_x = area.x.saturating_add(area.width.saturating_sub(cols) / 2);
                 let offset_y = area.y.saturating_add(area.height.saturating_sub(rows) / 2);
-                sprite_backend::update_sprite(offset_x, offset_y, sequence);
+                sprite_backend::update_sprite(offset_x, offset_y, image);
             } else {
                 sprite_backend::clear_sprites();
             }
@@ -587,16 +1310,53 @@ fn render_sprite(frame: &mut Frame, area: Rect, state: &AppState) {
     let paragraph = Paragraph::new(content)
         .alignment(Alignment::Center)
         .wrap(Wrap { trim: false })
-        .style(Style::default().fg(TEXT_DIM));
+        .style(Style::default().fg(theme().text_dim));
     frame.render_widget(paragraph, area);
 }
 
+/// Oscilloscope trace of the playing cry: each reading swings the line above
+/// or below the centre in turn, newest at the right edge.
+fn render_cry_scope(frame: &mut Frame, area: Rect, state: &AppState) {
+    let width = f64::from(area.width.saturating_sub(1).max(1));
+    let start = CryState::HISTORY.saturating_sub(state.cry.levels.len());
+    let points: Vec<(f64, f64)> = state
+        .cry
+        .levels
+        .iter()
+        .enumerate()
+        .map(|(idx, level)| {
+            let x = (start + idx) as f64 / (CryState::HISTORY - 1) as f64 * width;
+            let sign = if idx % 2 == 0 { 1.0 } else { -1.0 };
+            (x, sign * f64::from(*level))
+        })
+        .collect();
+    let scope = Canvas::default()
+        .marker(Marker::Braille)
+        .x_bounds([0.0, width])
+        .y_bounds([-1.0, 1.0])
+        .background_color(theme().panel)
+        .paint(move |ctx| {
+            ctx.draw(&CanvasLine::new(0.0, 0.0, width, 0.0, theme().highlight));
+            for pair in points.windows(2) {
+                let ((x1, y1), (x2, y2)) = (pair[0], pair[1]);
+                ctx.draw(&CanvasLine::new(x1, y1, x2, y2, theme().accent));
+            }
+        });
+    frame.render_widget(scope, area);
+}
+
 fn render_stats_panel(frame: &mut Frame, area: Rect, state: &AppState) {
     let stats = detail_stats(state.current_detail());
     let stats_block = Block::default()
         .borders(Borders::ALL)
         .title("STATS")
-        .style(Style::default().fg(TEXT_MAIN));
+        .style(Style::default().fg(theme().text));
+    if let Some(detail) = state.current_detail().filter(|_| state.stats_radar) {
+        let inner = stats_block.inner(area);
+        frame.render_widget(stats_block, area);
+        render_stats_radar(frame, inner, detail);
+        return;
+    }
     frame.render_widget(
         Paragraph::new(stats)
             .block(stats_block)
@@ -605,6 +1365,82 @@ fn render_stats_panel(frame: &mut Frame, area: Rect, state: &AppState) {
     );
 }
 
+/// Base stats as a hexagon, HP at the top and the rest clockwise in
+/// `STAT_ORDER`, over guide rings at half and full scale.
+fn render_stats_radar(frame: &mut Frame, area: Rect, detail: &PokemonDetail) {
+    if area.width < 8 || area.height < 4 {
+        return;
+    }
+    // Cells are about twice as tall as wide; stretch the longer axis so the
+    // hexagon stays regular.
+    let ratio = f64::from(area.width) / (f64::from(area.height) * f64::from(CELL_ASPECT));
+    let (x_half, y_half) = if ratio >= 1.0 {
+        (RADAR_EXTENT * ratio, RADAR_EXTENT)
+    } else {
+        (RADAR_EXTENT, RADAR_EXTENT / ratio)
+    };
+    let cell_width = 2.0 * x_half / f64::from(area.width);
+    let vertex = |index: usize, radius: f64| {
+        let angle = std::f64::consts::FRAC_PI_2 - index as f64 * std::f64::consts::PI / 3.0;
+        (radius * angle.cos(), radius * angle.sin())
+    };
+    let values = ordered_stats(detail);
+    let points: Vec<(f64, f64)> = values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| vertex(index, (f64::from(*value) / RADAR_STAT_MAX).min(1.0)))
+        .collect();
+    let labels: Vec<(f64, f64, String)> = values
+        .iter()
+        .enumerate()
+        .map(|(index, value)| {
+            let text = format!("{} {value}", shorten_stat(STAT_ORDER[index]).trim());
+            let (x, y) = vertex(index, 1.3);
+            // Labels on the left end at the vertex instead of starting there.
+            let width = text.len() as f64 * cell_width;
+            let x = if x < -0.1 {
+                x - width
+            } else if x.abs() <= 0.1 {
+                x - width / 2.0
+            } else {
+                x
+            };
+            (x.clamp(-x_half, x_half - width), y, text)
+        })
+        .collect();
+
+    let radar = Canvas::default()
+        .marker(Marker::Braille)
+        .x_bounds([-x_half, x_half])
+        .y_bounds([-y_half, y_half])
+        .paint(move |ctx| {
+            for index in 0..STAT_ORDER.len() {
+                let (x, y) = vertex(index, 1.0);
+                ctx.draw(&CanvasLine::new(0.0, 0.0, x, y, theme().highlight));
+            }
+            for radius in [0.5, 1.0] {
+                for index in 0..STAT_ORDER.len() {
+                    let (x1, y1) = vertex(index, radius);
+                    let (x2, y2) = vertex((index + 1) % STAT_ORDER.len(), radius);
+                    ctx.draw(&CanvasLine::new(x1, y1, x2, y2, theme().border));
+                }
+            }
+            ctx.layer();
+            for (index, (x1, y1)) in points.iter().enumerate() {
+                let (x2, y2) = points[(index + 1) % points.len()];
+                ctx.draw(&CanvasLine::new(*x1, *y1, x2, y2, theme().accent));
+            }
+            for (x, y, text) in &labels {
+                ctx.print(
+                    *x,
+                    *y,
+                    Span::styled(text.clone(), Style::default().fg(theme().text_dim)),
+                );
+            }
+        });
+    frame.render_widget(radar, area);
+}
+
 fn detail_stats(detail: Option<&crate::state::PokemonDetail>) -> Text<'static> {
     let Some(detail) = detail else {
         return Text::from("No stats loaded.");
@@ -617,6 +1453,148 @@ fn detail_stats(detail: Option<&crate::state::PokemonDetail>) -> Text<'static> {
     Text::from(lines)
 }
 
+/// Pinned Pokemon on the left, current selection on the right, stats diffed.
+fn render_compare(frame: &mut Frame, area: Rect, state: &AppState, pinned: &str) {
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("COMPARE")
+        .style(Style::default().bg(theme().panel).fg(theme().text));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
+        .split(inner);
+
+    let current = state.detail_name.as_deref();
+    let sides = [
+        (Some(pinned), current, 0),
+        (current, Some(pinned), state.sprite_frame_index),
+    ];
+    let mut sprites = Vec::new();
+    for (index, (name, other, frame_index)) in sides.into_iter().enumerate() {
+        let border_style = if index == 0 {
+            Style::default().fg(theme().gold)
+        } else {
+            Style::default().fg(theme().text_dim)
+        };
+        let title = match name {
+            Some(name) if index == 0 => format!("{} (pinned)", format_name(name)),
+            Some(name) => format_name(name),
+            None => "--".to_string(),
+        };
+        let panel = Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .border_style(border_style);
+        let panel_inner = panel.inner(columns[index]);
+        frame.render_widget(panel, columns[index]);
+
+        let parts = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([
+                Constraint::Min(3),
+                Constraint::Length(STAT_ORDER.len() as u16 + 2),
+            ])
+            .split(panel_inner);
+        let Some(name) = name else {
+            continue;
+        };
+        let sprite_area = parts[0];
+        match state.sprite_cache.get(name) {
+            Some(sprite) => {
+                let (cols, rows) = sprite_fit(sprite, sprite_area.width, sprite_area.height);
+                if let Ok(image) =
+                    sprite_backend::encode_frame(sprite.frame(frame_index), cols, rows)
+                {
+                    let x = sprite_area.x + sprite_area.width.saturating_sub(cols) / 2;
+                    let y = sprite_area.y + sprite_area.height.saturating_sub(rows) / 2;
+                    sprites.push(((x, y), image));
+                }
+            }
+            None => {
+                let message = if state.details.contains_key(name) {
+                    "[loading sprite]"
+                } else {
+                    "[loading]"
+                };
+                frame.render_widget(
+                    Paragraph::new(message)
+                        .alignment(Alignment::Center)
+                        .style(Style::default().fg(theme().text_dim)),
+                    sprite_area,
+                );
+            }
+        }
+        let detail = state.details.get(name);
+        let other = other.and_then(|other| state.details.get(other));
+        frame.render_widget(Paragraph::new(compare_stats_text(detail, other)), parts[1]);
+    }
+    sprite_backend::update_sprites(sprites);
+}
+
+/// Stat bars for `detail`, colored and annotated by how they differ from `other`.
+fn compare_stats_text(detail: Option<&PokemonDetail>, other: Option<&PokemonDetail>) -> Text<'static> {
+    let Some(detail) = detail else {
+        return Text::from(Span::styled(
+            "loading...",
+            Style::default().fg(theme().text_dim),
+        ));
+    };
+    let values = ordered_stats(detail);
+    let others = other.map(ordered_stats);
+    let mut lines = vec![Line::from(Span::styled(
+        detail.types.join(" / "),
+        Style::default().fg(theme().text_dim),
+    ))];
+    for (index, stat) in STAT_ORDER.iter().enumerate() {
+        let value = values[index];
+        let bar_len = (value as usize * COMPARE_BAR_WIDTH / 255).clamp(1, COMPARE_BAR_WIDTH);
+        let delta = others.map(|others| i32::from(value) - i32::from(others[index]));
+        lines.push(Line::from(vec![
+            Span::raw(format!("{:>4} {value:>3} ", shorten_stat(stat))),
+            Span::styled(
+                format!("{:<COMPARE_BAR_WIDTH$}", "#".repeat(bar_len)),
+                Style::default().fg(delta_color(delta, theme().accent)),
+            ),
+            delta_span(delta),
+        ]));
+    }
+    let total: i32 = values.iter().map(|value| i32::from(*value)).sum();
+    let delta = others.map(|others| total - others.iter().map(|value| i32::from(*value)).sum::<i32>());
+    lines.push(Line::from(vec![
+        Span::styled(
+            format!("{:>4} {total:>3} ", "BST"),
+            Style::default()
+                .fg(theme().gold)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(" ".repeat(COMPARE_BAR_WIDTH)),
+        delta_span(delta),
+    ]));
+    Text::from(lines)
+}
+
+fn delta_color(delta: Option<i32>, even: Color) -> Color {
+    match delta {
+        Some(delta) if delta > 0 => theme().success,
+        Some(delta) if delta < 0 => theme().danger,
+        _ => even,
+    }
+}
+
+fn delta_span(delta: Option<i32>) -> Span<'static> {
+    let text = match delta {
+        Some(0) => " =".to_string(),
+        Some(delta) => format!(" {delta:+}"),
+        None => String::new(),
+    };
+    Span::styled(
+        text,
+        Style::default().fg(delta_color(delta, theme().text_dim)),
+    )
+}
+
 // profile block removed; details live in General tab
 
 fn render_secondary(
@@ -658,7 +1636,7 @@ fn render_evolution_list(
     let block = Block::default()
         .borders(Borders::ALL)
         .title("EVOLUTION")
-        .style(Style::default().bg(BG_PANEL).fg(TEXT_MAIN))
+        .style(Style::default().bg(theme().panel).fg(theme().text))
         .border_style(focus_border(state, crate::state::FocusArea::Evolution));
     let inner = block.inner(area);
     frame.render_widget(block, area);
@@ -672,7 +1650,7 @@ fn render_evolution_list(
         };
         frame.render_widget(
             Paragraph::new(message)
-                .style(Style::default().fg(TEXT_DIM))
+                .style(Style::default().fg(theme().text_dim))
                 .wrap(Wrap { trim: true }),
             inner,
         );
@@ -697,203 +1675,2463 @@ fn render_evolution_list(
     evolution_list.render(frame, inner, props);
 }
 
-fn render_footer(frame: &mut Frame, area: Rect, state: &AppState, status_bar: &mut StatusBar) {
-    let status = state.message.clone().unwrap_or_else(|| {
-        if state.list_loading {
-            "Loading pokedex...".to_string()
-        } else if state.species_index_loading {
-            "Loading species index...".to_string()
-        } else if state.detail_loading {
-            "Loading pokemon...".to_string()
-        } else if state.encounter_loading {
-            "Loading encounters...".to_string()
-        } else if state.type_matchup_loading {
-            "Loading type matchup...".to_string()
-        } else if state.type_loading {
-            "Loading types...".to_string()
-        } else if state.region_loading {
-            "Loading regions...".to_string()
-        } else {
-            "".to_string()
-        }
-    });
-    let (left_hints, center_hints) = status_hints(state);
-    let status_span = Span::styled(status.as_str(), Style::default().fg(ACCENT_GOLD));
-    let status_items = [StatusBarItem::span(status_span)];
+fn render_team(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    event_ctx: &mut EventContext<crate::PokeComponentId>,
+) {
+    event_ctx.set_component_area(crate::PokeComponentId::Team, area);
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!("TEAM {}/{TEAM_SIZE}", state.team.len()))
+        .style(Style::default().bg(theme().panel).fg(theme().text))
+        .border_style(focus_border(state, crate::state::FocusArea::Team));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
 
-    let style = StatusBarStyle {
-        base: BaseStyle {
-            border: Some(BorderStyle {
-                borders: Borders::ALL,
-                style: Style::default().fg(TEXT_DIM),
-                focused_style: Some(Style::default().fg(ACCENT_TEAL)),
-            }),
-            padding: Padding::xy(1, 0),
-            bg: Some(BG_PANEL),
-            fg: Some(TEXT_MAIN),
-        },
-        text: Style::default().fg(TEXT_DIM),
-        hint_key: Style::default()
-            .fg(ACCENT_TEAL)
-            .add_modifier(Modifier::BOLD),
-        hint_label: Style::default().fg(TEXT_DIM),
-        separator: Style::default().fg(TEXT_DIM),
-    };
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(8), Constraint::Length(10)])
+        .split(inner);
+    render_team_cards(frame, layout[0], state);
 
-    let props = StatusBarProps {
-        left: StatusBarSection::hints(&left_hints).with_separator("  "),
-        center: StatusBarSection::hints(&center_hints).with_separator("  "),
-        right: StatusBarSection::items(&status_items).with_separator("  "),
-        style,
-        is_focused: false,
-    };
-    Component::<Action>::render(status_bar, frame, area, props);
+    let bottom = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([
+            Constraint::Percentage(40),
+            Constraint::Percentage(30),
+            Constraint::Percentage(30),
+        ])
+        .split(layout[1]);
+    let stats_block = Block::default()
+        .borders(Borders::ALL)
+        .title("BASE STATS")
+        .style(Style::default().bg(theme().panel_alt).fg(theme().text));
+    frame.render_widget(
+        Paragraph::new(team_stats_text(state)).block(stats_block),
+        bottom[0],
+    );
+    let coverage_block = Block::default()
+        .borders(Borders::ALL)
+        .title("COVERAGE")
+        .style(Style::default().bg(theme().panel_alt).fg(theme().text));
+    frame.render_widget(
+        Paragraph::new(team_coverage_text(state))
+            .block(coverage_block)
+            .wrap(Wrap { trim: true }),
+        bottom[1],
+    );
+    let suggestion_block = Block::default()
+        .borders(Borders::ALL)
+        .title("SUGGESTIONS")
+        .style(Style::default().bg(theme().panel_alt).fg(theme().text));
+    frame.render_widget(
+        Paragraph::new(team_suggestions_text(state))
+            .block(suggestion_block)
+            .wrap(Wrap { trim: true }),
+        bottom[2],
+    );
 }
 
-fn status_hints(state: &AppState) -> (Vec<StatusBarHint<'static>>, Vec<StatusBarHint<'static>>) {
-    if state.search.active {
-        let left = vec![
-            StatusBarHint::new("Enter", "Apply"),
-            StatusBarHint::new("Esc", "Cancel"),
-            StatusBarHint::new("Bksp", "Delete"),
-        ];
-        let center = vec![StatusBarHint::new("q", "Quit")];
-        return (left, center);
-    }
+fn render_items(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    event_ctx: &mut EventContext<crate::PokeComponentId>,
+    item_list: &mut SelectList,
+) {
+    event_ctx.set_component_area(crate::PokeComponentId::Items, area);
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(36), Constraint::Percentage(64)])
+        .split(area);
 
-    let mut left = Vec::new();
-    match state.focus {
-        crate::state::FocusArea::Header => {
+    let items = &state.items;
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!("ITEMS {}/{}", items.filtered.len(), items.names.len()))
+        .style(Style::default().bg(theme().panel).fg(theme().text))
+        .border_style(focus_border(state, crate::state::FocusArea::Items));
+    let inner = block.inner(columns[0]);
+    frame.render_widget(block, columns[0]);
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(1)])
+        .split(inner);
+    let query_style = if items.searching {
+        Style::default().fg(theme().accent)
+    } else {
+        Style::default().fg(theme().text_dim)
+    };
+    let cursor = if items.searching { "_" } else { "" };
+    let query = Line::from(vec![
+        Span::styled(" Find: ", Style::default().fg(theme().text_dim)),
+        Span::styled(format!("{}{cursor}", items.query), query_style),
+    ]);
+    frame.render_widget(Paragraph::new(query), layout[0]);
+
+    let lines = item_list_items(state);
+    if lines.is_empty() {
+        let message = if items.list_loading {
+            "Loading items..."
+        } else {
+            "No matching items."
+        };
+        frame.render_widget(
+            Paragraph::new(format!(" {message}")).style(Style::default().fg(theme().text_dim)),
+            layout[1],
+        );
+    } else {
+        let props = SelectListProps {
+            items: &lines,
+            count: lines.len(),
+            selected: items.selected.min(lines.len().saturating_sub(1)),
+            is_focused: true,
+            style: dex_list_style(),
+            behavior: SelectListBehavior {
+                show_scrollbar: true,
+                wrap_navigation: false,
+            },
+            on_select: Action::ItemSelect,
+            render_item: &|item| item.clone(),
+        };
+        item_list.render(frame, layout[1], props);
+    }
+
+    let detail_block = Block::default()
+        .borders(Borders::ALL)
+        .title("ITEM")
+        .style(Style::default().bg(theme().panel).fg(theme().text));
+    let detail_inner = detail_block.inner(columns[1]);
+    frame.render_widget(detail_block, columns[1]);
+    let detail_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(8), Constraint::Min(4)])
+        .split(detail_inner);
+    render_item_sprite(frame, detail_layout[0], state);
+    frame.render_widget(
+        Paragraph::new(item_detail_text(state)).wrap(Wrap { trim: true }),
+        detail_layout[1],
+    );
+}
+
+fn render_locations(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    event_ctx: &mut EventContext<crate::PokeComponentId>,
+    location_list: &mut SelectList,
+    location_pokemon_list: &mut SelectList,
+) {
+    event_ctx.set_component_area(crate::PokeComponentId::Locations, area);
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(36), Constraint::Percentage(64)])
+        .split(area);
+
+    let locations = &state.locations;
+    let region = state
+        .current_region()
+        .map(|region| region.label.to_uppercase())
+        .unwrap_or_default();
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!("{region} LOCATIONS {}", locations.names.len()))
+        .style(Style::default().bg(theme().panel).fg(theme().text))
+        .border_style(pane_border(locations.pane == LocationPane::Locations));
+    let inner = block.inner(columns[0]);
+    frame.render_widget(block, columns[0]);
+
+    let lines = location_items(state);
+    if lines.is_empty() {
+        let message = if locations.list_loading {
+            "Loading locations..."
+        } else {
+            "No locations in this region."
+        };
+        frame.render_widget(
+            Paragraph::new(format!(" {message}")).style(Style::default().fg(theme().text_dim)),
+            inner,
+        );
+    } else {
+        let props = SelectListProps {
+            items: &lines,
+            count: lines.len(),
+            selected: locations.selected.min(lines.len().saturating_sub(1)),
+            is_focused: locations.pane == LocationPane::Locations,
+            style: dex_list_style(),
+            behavior: SelectListBehavior {
+                show_scrollbar: true,
+                wrap_navigation: false,
+            },
+            on_select: Action::LocationSelect,
+            render_item: &|item| item.clone(),
+        };
+        location_list.render(frame, inner, props);
+    }
+
+    let title = locations
+        .selected_name()
+        .map(|name| format_name(name).to_uppercase())
+        .unwrap_or_else(|| "POKEMON".to_string());
+    let pokemon_block = Block::default()
+        .borders(Borders::ALL)
+        .title(title)
+        .style(Style::default().bg(theme().panel).fg(theme().text))
+        .border_style(pane_border(locations.pane == LocationPane::Pokemon));
+    let pokemon_inner = pokemon_block.inner(columns[1]);
+    frame.render_widget(pokemon_block, columns[1]);
+    let pokemon_layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(6)])
+        .split(pokemon_inner);
+
+    let pokemon_lines = location_pokemon_items(state);
+    if pokemon_lines.is_empty() {
+        let message = match locations.selected_encounters() {
+            Some(_) => "No wild pokemon here.",
+            None if locations.encounters_loading.is_some() => "Loading encounters...",
+            None => "",
+        };
+        frame.render_widget(
+            Paragraph::new(format!(" {message}")).style(Style::default().fg(theme().text_dim)),
+            pokemon_layout[0],
+        );
+    } else {
+        let props = SelectListProps {
+            items: &pokemon_lines,
+            count: pokemon_lines.len(),
+            selected: locations
+                .pokemon_selected
+                .min(pokemon_lines.len().saturating_sub(1)),
+            is_focused: locations.pane == LocationPane::Pokemon,
+            style: dex_list_style(),
+            behavior: SelectListBehavior {
+                show_scrollbar: true,
+                wrap_navigation: false,
+            },
+            on_select: Action::LocationPokemonSelect,
+            render_item: &|item| item.clone(),
+        };
+        location_pokemon_list.render(frame, pokemon_layout[0], props);
+    }
+    frame.render_widget(
+        Paragraph::new(location_pokemon_text(state))
+            .style(Style::default().bg(theme().panel_alt))
+            .wrap(Wrap { trim: true }),
+        pokemon_layout[1],
+    );
+}
+
+fn render_item_sprite(frame: &mut Frame, area: Rect, state: &AppState) {
+    let sprite = state
+        .items
+        .selected_name()
+        .and_then(|name| state.sprite_cache.get(&item_sprite_key(name)));
+    let Some(sprite) = sprite else {
+        sprite_backend::clear_sprites();
+        let content = match state.items.selected_detail() {
+            Some(detail) if detail.sprite_url.is_none() => "[no sprite]",
+            Some(_) => "[loading sprite]",
+            None => "",
+        };
+        frame.render_widget(
+            Paragraph::new(content)
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(theme().text_dim)),
+            area,
+        );
+        return;
+    };
+    let (cols, rows) = sprite_fit(sprite, area.width, area.height);
+    match sprite_backend::encode_frame(sprite.frame(0), cols, rows) {
+        Ok(image) => {
+            let offset_x = area.x.saturating_add(area.width.saturating_sub(cols) / 2);
+            let offset_y = area.y.saturating_add(area.height.saturating_sub(rows) / 2);
+            sprite_backend::update_sprite(offset_x, offset_y, image);
+        }
+        Err(_) => sprite_backend::clear_sprites(),
+    }
+}
+
+fn item_detail_text(state: &AppState) -> Text<'static> {
+    let Some(name) = state.items.selected_name() else {
+        return Text::from(Line::styled("[select an item]", Style::default().fg(theme().text_dim)));
+    };
+    let Some(detail) = state.items.selected_detail() else {
+        let status = if state.items.detail_loading.as_deref() == Some(name) {
+            "Loading item..."
+        } else {
+            "No details."
+        };
+        return Text::from(vec![
+            Line::styled(
+                format_name(name),
+                Style::default().fg(theme().gold).add_modifier(Modifier::BOLD),
+            ),
+            Line::styled(status, Style::default().fg(theme().text_dim)),
+        ]);
+    };
+    item_text(detail)
+}
+
+fn item_text(detail: &ItemDetail) -> Text<'static> {
+    let label = |label: &str| {
+        Span::styled(
+            format!("{label:<10}"),
+            Style::default().fg(theme().text_dim),
+        )
+    };
+    let cost = if detail.cost == 0 {
+        "--".to_string()
+    } else {
+        format!("{}", detail.cost)
+    };
+    let mut lines = vec![
+        Line::styled(
+            format_name(&detail.name),
+            Style::default().fg(theme().gold).add_modifier(Modifier::BOLD),
+        ),
+        Line::from(vec![label("Category"), Span::raw(format_name(&detail.category))]),
+        Line::from(vec![label("Cost"), Span::raw(cost)]),
+        Line::from(""),
+    ];
+    match &detail.effect {
+        Some(effect) => lines.push(Line::from(effect.clone())),
+        None => lines.push(Line::styled("No effect text.", Style::default().fg(theme().text_dim))),
+    }
+    Text::from(lines)
+}
+
+fn render_team_cards(frame: &mut Frame, area: Rect, state: &AppState) {
+    if state.team.is_empty() {
+        sprite_backend::clear_sprites();
+        frame.render_widget(
+            Paragraph::new("No team members yet. Press t on a Pokemon in the dex to add it.")
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(theme().text_dim))
+                .wrap(Wrap { trim: true }),
+            area,
+        );
+        return;
+    }
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Ratio(1, 2), Constraint::Ratio(1, 2)])
+        .split(area);
+    let mut sprites = Vec::new();
+    for slot in 0..TEAM_SIZE {
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([Constraint::Ratio(1, TEAM_COLUMNS as u32); TEAM_COLUMNS])
+            .split(rows[slot / TEAM_COLUMNS]);
+        let card = columns[slot % TEAM_COLUMNS];
+        let member = state.team.get(slot);
+        let border_style = if member.is_some() && slot == state.team_selected {
+            Style::default()
+                .fg(theme().gold)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme().text_dim)
+        };
+        let title = match member {
+            Some(name) => format!("{} {}", slot + 1, format_name(name)),
+            None => format!("{} --", slot + 1),
+        };
+        let block = Block::default()
+            .borders(Borders::ALL)
+            .title(title)
+            .border_style(border_style);
+        let inner = block.inner(card);
+        frame.render_widget(block, card);
+        let Some(name) = member else {
+            continue;
+        };
+
+        let parts = Layout::default()
+            .direction(Direction::Vertical)
+            .constraints([Constraint::Min(1), Constraint::Length(2)])
+            .split(inner);
+        let sprite_area = parts[0];
+        match state.sprite_cache.get(name) {
+            Some(sprite) => {
+                let (cols, rows) = sprite_fit(sprite, sprite_area.width, sprite_area.height);
+                if let Ok(image) = sprite_backend::encode_frame(sprite.frame(0), cols, rows) {
+                    let x = sprite_area.x + sprite_area.width.saturating_sub(cols) / 2;
+                    let y = sprite_area.y + sprite_area.height.saturating_sub(rows) / 2;
+                    sprites.push(((x, y), image));
+                }
+            }
+            None => {
+                frame.render_widget(
+                    Paragraph::new("[loading sprite]")
+                        .alignment(Alignment::Center)
+                        .style(Style::default().fg(theme().text_dim)),
+                    sprite_area,
+                );
+            }
+        }
+
+        let info = match state.details.get(name) {
+            Some(detail) => vec![
+                Line::from(detail.types.join(" / ")),
+                Line::from(Span::styled(
+                    format!("BST {}", state.base_stat_total(name).unwrap_or(0)),
+                    Style::default().fg(theme().accent),
+                )),
+            ],
+            None => vec![Line::from("loading...")],
+        };
+        frame.render_widget(
+            Paragraph::new(info)
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(theme().text_dim)),
+            parts[1],
+        );
+    }
+    sprite_backend::update_sprites(sprites);
+}
+
+fn team_stats_text(state: &AppState) -> Text<'static> {
+    let mut header = format!("{:<12}", "");
+    for stat in STAT_ORDER {
+        header.push_str(&format!("{:>4}", shorten_stat(stat)));
+    }
+    header.push_str(&format!("{:>5}", "BST"));
+    let mut lines = vec![Line::from(Span::styled(
+        header,
+        Style::default()
+            .fg(theme().accent)
+            .add_modifier(Modifier::BOLD),
+    ))];
+
+    let mut loaded = Vec::new();
+    for name in &state.team {
+        let label: String = format_name(name).chars().take(11).collect();
+        let Some(detail) = state.details.get(name) else {
+            lines.push(Line::from(format!("{label:<12}loading...")));
+            continue;
+        };
+        let values = ordered_stats(detail).map(u32::from);
+        lines.push(Line::from(stat_row(&label, values)));
+        loaded.push(values);
+    }
+
+    if !loaded.is_empty() {
+        let mut average = [0u32; STAT_ORDER.len()];
+        for values in &loaded {
+            for (sum, value) in average.iter_mut().zip(values) {
+                *sum += value;
+            }
+        }
+        let average = average.map(|sum| sum / loaded.len() as u32);
+        lines.push(Line::from(Span::styled(
+            stat_row("Average", average),
+            Style::default().fg(theme().gold),
+        )));
+    }
+    Text::from(lines)
+}
+
+fn stat_row(label: &str, values: [u32; STAT_ORDER.len()]) -> String {
+    let mut row = format!("{label:<12}");
+    for value in values {
+        row.push_str(&format!("{value:>4}"));
+    }
+    row.push_str(&format!("{:>5}", values.iter().sum::<u32>()));
+    row
+}
+
+fn ordered_stats(detail: &PokemonDetail) -> [u16; STAT_ORDER.len()] {
+    STAT_ORDER.map(|name| {
+        detail
+            .stats
+            .iter()
+            .find(|stat| stat.name == name)
+            .map(|stat| stat.value)
+            .unwrap_or(0)
+    })
+}
+
+/// Offensive coverage from the team's own types, plus weaknesses several members share.
+fn team_coverage_text(state: &AppState) -> Text<'static> {
+    let Some(coverage) = &state.team_coverage else {
+        return Text::from(team_coverage_pending(state));
+    };
+    let gaps = coverage
+        .gaps()
+        .into_iter()
+        .map(format_name)
+        .collect::<Vec<_>>();
+    let shared = coverage
+        .shared_weaknesses()
+        .into_iter()
+        .map(|exposure| format!("{} x{}", format_name(&exposure.attacker), exposure.weak))
+        .collect::<Vec<_>>();
+
+    let heading = Style::default()
+        .fg(theme().accent)
+        .add_modifier(Modifier::BOLD);
+    Text::from(vec![
+        Line::from(vec![
+            Span::styled("Offense ", heading),
+            Span::styled(
+                format!(
+                    "{}/{} types hit super effectively",
+                    coverage.covered(),
+                    coverage.offense.len()
+                ),
+                Style::default().fg(theme().gold),
+            ),
+        ]),
+        Line::from(format!("Gaps: {}", or_none(gaps))),
+        Line::from(" "),
+        Line::from(Span::styled("Shared weaknesses", heading)),
+        Line::from(or_none(shared)),
+    ])
+}
+
+/// Every type the team is weak to overall, then species from the dex that patch them.
+fn team_suggestions_text(state: &AppState) -> Text<'static> {
+    let Some(coverage) = &state.team_coverage else {
+        return Text::from(team_coverage_pending(state));
+    };
+    let weaknesses = coverage.weaknesses();
+    if weaknesses.is_empty() {
+        return Text::from("No weaknesses to patch.");
+    }
+    let weak = weaknesses
+        .iter()
+        .map(|exposure| format!("{} x{}", format_name(&exposure.attacker), exposure.weak))
+        .collect::<Vec<_>>();
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled(
+                "Weak to ",
+                Style::default()
+                    .fg(theme().accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(weak.join(", ")),
+        ]),
+        Line::from(" "),
+    ];
+    if state.team_suggestions.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "Scouting the dex...",
+            Style::default().fg(theme().text_dim),
+        )));
+    }
+    for (index, suggestion) in state.team_suggestions.iter().enumerate() {
+        let patches = suggestion
+            .patches
+            .iter()
+            .map(|name| format_name(name))
+            .collect::<Vec<_>>();
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{}. {}", index + 1, format_name(&suggestion.name)),
+                Style::default().fg(theme().gold),
+            ),
+            Span::styled(
+                format!("  BST {}", suggestion.bst),
+                Style::default().fg(theme().text_dim),
+            ),
+        ]));
+        lines.push(Line::from(Span::styled(
+            format!("   resists {}", patches.join(", ")),
+            Style::default().fg(theme().text_dim),
+        )));
+    }
+    Text::from(lines)
+}
+
+fn team_coverage_pending(state: &AppState) -> &'static str {
+    if state.team.is_empty() {
+        "Add Pokemon to see coverage."
+    } else {
+        "Loading type matchups..."
+    }
+}
+
+fn or_none(items: Vec<String>) -> String {
+    if items.is_empty() {
+        "none".to_string()
+    } else {
+        items.join(", ")
+    }
+}
+
+fn render_footer(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    status_bar: &mut StatusBar,
+    keybindings: &Keybindings<crate::PokeContext>,
+) {
+    let status = state.message.clone().unwrap_or_else(|| {
+        if state.list_loading {
+            "Loading pokedex...".to_string()
+        } else if state.species_index_loading {
+            "Loading species index...".to_string()
+        } else if state.detail_loading {
+            "Loading pokemon...".to_string()
+        } else if state.encounter_loading {
+            "Loading encounters...".to_string()
+        } else if state.type_matchup_loading {
+            "Loading type matchup...".to_string()
+        } else if state.type_loading {
+            "Loading types...".to_string()
+        } else if state.region_loading {
+            "Loading regions...".to_string()
+        } else if state.items.list_loading {
+            "Loading items...".to_string()
+        } else if state.locations.list_loading {
+            "Loading locations...".to_string()
+        } else {
+            "".to_string()
+        }
+    });
+    let (left_hints, center_hints) = status_hints(state);
+    let global_hints = global_key_hints(state, keybindings);
+    let center_hints = center_hints.unwrap_or_else(|| {
+        global_hints
+            .iter()
+            .map(|(key, label)| StatusBarHint::new(key, label))
+            .collect()
+    });
+    let status_span = Span::styled(status.as_str(), Style::default().fg(theme().gold));
+    let status_items = [StatusBarItem::span(status_span)];
+
+    let style = StatusBarStyle {
+        base: BaseStyle {
+            border: Some(BorderStyle {
+                borders: Borders::ALL,
+                style: Style::default().fg(theme().text_dim),
+                focused_style: Some(Style::default().fg(theme().accent)),
+            }),
+            padding: Padding::xy(1, 0),
+            bg: Some(theme().panel),
+            fg: Some(theme().text),
+        },
+        text: Style::default().fg(theme().text_dim),
+        hint_key: Style::default()
+            .fg(theme().accent)
+            .add_modifier(Modifier::BOLD),
+        hint_label: Style::default().fg(theme().text_dim),
+        separator: Style::default().fg(theme().text_dim),
+    };
+
+    let props = StatusBarProps {
+        left: StatusBarSection::hints(&left_hints).with_separator("  "),
+        center: StatusBarSection::hints(&center_hints).with_separator("  "),
+        right: StatusBarSection::items(&status_items).with_separator("  "),
+        style,
+        is_focused: false,
+    };
+    Component::<Action>::render(status_bar, frame, area, props);
+}
+
+/// Footer hints for the current focus. The center is `None` where the
+/// global keys apply, so they can be listed as bound.
+fn status_hints(
+    state: &AppState,
+) -> (
+    Vec<StatusBarHint<'static>>,
+    Option<Vec<StatusBarHint<'static>>>,
+) {
+    if state.featured.active {
+        let left = vec![
+            StatusBarHint::new("Enter", "View"),
+            StatusBarHint::new("any key", "Dismiss"),
+        ];
+        return (left, Some(Vec::new()));
+    }
+    if state.help.active {
+        let left = vec![
+            StatusBarHint::new("j/k", "Scroll"),
+            StatusBarHint::new("Esc", "Close"),
+        ];
+        return (left, Some(Vec::new()));
+    }
+    if state.sort_picker.active {
+        let left = vec![
+            StatusBarHint::new("j/k", "Order"),
+            StatusBarHint::new("Enter", "Sort"),
+            StatusBarHint::new("Esc", "Cancel"),
+        ];
+        return (left, Some(Vec::new()));
+    }
+    if state.bookmark_list.active {
+        let left = vec![
+            StatusBarHint::new("j/k", "Move"),
+            StatusBarHint::new("Enter", "Jump"),
+            StatusBarHint::new("Esc", "Close"),
+        ];
+        return (left, Some(Vec::new()));
+    }
+    if state.calc.active {
+        let left = vec![
+            StatusBarHint::new("Tab", "Defender/Move"),
+            StatusBarHint::new("Up/Down", "Move"),
+            StatusBarHint::new("Esc", "Close"),
+        ];
+        let center = if state.calc.pane == CalcPane::Defender {
+            vec![
+                StatusBarHint::new("type", "Find"),
+                StatusBarHint::new("Enter", "Pick"),
+            ]
+        } else {
+            vec![StatusBarHint::new("j/k", "Move")]
+        };
+        return (left, Some(center));
+    }
+    if state.export_picker.active {
+        let left = vec![
+            StatusBarHint::new("j/k", "Format"),
+            StatusBarHint::new("Enter", "Export"),
+            StatusBarHint::new("Esc", "Cancel"),
+        ];
+        return (left, Some(Vec::new()));
+    }
+    if state.report_prompt.active {
+        let left = vec![
+            StatusBarHint::new("type", "Path"),
+            StatusBarHint::new("Enter", "Write"),
+            StatusBarHint::new("Esc", "Cancel"),
+        ];
+        return (left, Some(Vec::new()));
+    }
+    if state.note_editor.active {
+        let left = vec![
+            StatusBarHint::new("Ctrl+S", "Save"),
+            StatusBarHint::new("Esc", "Discard"),
+            StatusBarHint::new("Enter", "Newline"),
+        ];
+        let center = vec![
+            StatusBarHint::new("#", "Heading"),
+            StatusBarHint::new("-", "Bullet"),
+            StatusBarHint::new("**", "Bold"),
+        ];
+        return (left, Some(center));
+    }
+    if state.region_picker.active {
+        let left = vec![
+            StatusBarHint::new("Up/Down", "Move"),
+            StatusBarHint::new("Enter", "Load"),
+            StatusBarHint::new("Esc", "Close"),
+        ];
+        let center = vec![StatusBarHint::new("type", "Filter")];
+        return (left, Some(center));
+    }
+    if state.focus == crate::state::FocusArea::Items {
+        if state.items.searching {
+            let left = vec![
+                StatusBarHint::new("Enter", "Apply"),
+                StatusBarHint::new("Esc", "Clear"),
+                StatusBarHint::new("Bksp", "Delete"),
+            ];
+            return (left, Some(vec![StatusBarHint::new("Up/Down", "Move")]));
+        }
+        let left = vec![
+            StatusBarHint::new("j/k", "Move"),
+            StatusBarHint::new("PgUp/PgDn", "Page"),
+            StatusBarHint::new("/", "Search"),
+            StatusBarHint::new("Esc", "Close"),
+        ];
+        let center = vec![
+            StatusBarHint::new("?", "Help"),
+            StatusBarHint::new("q", "Quit"),
+        ];
+        return (left, Some(center));
+    }
+    if state.focus == crate::state::FocusArea::Locations {
+        let enter = match state.locations.pane {
+            LocationPane::Locations => "Pokemon",
+            LocationPane::Pokemon => "Open in dex",
+        };
+        let left = vec![
+            StatusBarHint::new("j/k", "Move"),
+            StatusBarHint::new("h/l", "Pane"),
+            StatusBarHint::new("Enter", enter),
+            StatusBarHint::new("Esc", "Close"),
+        ];
+        let center = vec![
+            StatusBarHint::new("?", "Help"),
+            StatusBarHint::new("q", "Quit"),
+        ];
+        return (left, Some(center));
+    }
+    if state.search.active {
+        let left = vec![
+            StatusBarHint::new("Enter", "Apply"),
+            StatusBarHint::new("Esc", "Cancel"),
+            StatusBarHint::new("Bksp", "Delete"),
+        ];
+        let center = vec![StatusBarHint::new("q", "Quit")];
+        return (left, Some(center));
+    }
+
+    let mut left = Vec::new();
+    match state.focus {
+        crate::state::FocusArea::Header => {
             left.push(StatusBarHint::new("c", "Clear"));
         }
-        crate::state::FocusArea::DexList => {
-            left.extend([
-                StatusBarHint::new("j/k", "Move"),
-                StatusBarHint::new("PgUp/PgDn", "Page"),
-                StatusBarHint::new("Shift+Up/Down", "Evo"),
-                StatusBarHint::new("f", "Favorite"),
-                StatusBarHint::new("t", "Team"),
-            ]);
+        crate::state::FocusArea::DexList => {
+            left.extend([
+                StatusBarHint::new("j/k", "Move"),
+                StatusBarHint::new("PgUp/PgDn", "Page"),
+                StatusBarHint::new("Shift+Up/Down", "Evo"),
+                StatusBarHint::new("f", "Favorite"),
+                StatusBarHint::new("t", "Team"),
+                StatusBarHint::new("F", if state.family_view { "List" } else { "Families" }),
+            ]);
+            if state.family_view {
+                left.push(StatusBarHint::new("Space", "Fold"));
+            }
+            left.push(StatusBarHint::new(
+                "C",
+                if state.compare.is_some() {
+                    "End compare"
+                } else {
+                    "Compare"
+                },
+            ));
+        }
+        crate::state::FocusArea::DetailTabs => {
+            left.push(StatusBarHint::new("h/l", "Tabs"));
+            match state.detail_mode {
+                crate::state::DetailMode::Move => {
+                    left.extend([
+                        StatusBarHint::new("j/k", "Select"),
+                        StatusBarHint::new("s", "Sort"),
+                    ]);
+                }
+                crate::state::DetailMode::Ability | crate::state::DetailMode::Encounter => {
+                    left.push(StatusBarHint::new("j/k", "Select"));
+                }
+                crate::state::DetailMode::Matchup => {
+                    let label = match state.matchup_view {
+                        crate::state::MatchupView::Pokemon => "Team",
+                        crate::state::MatchupView::Team => "Pokemon",
+                    };
+                    left.push(StatusBarHint::new("v", label));
+                }
+                crate::state::DetailMode::General => {}
+            }
+        }
+        crate::state::FocusArea::Evolution => {
+            left.push(StatusBarHint::new("j/k", "Select"));
+        }
+        crate::state::FocusArea::Team => {
+            left.extend([
+                StatusBarHint::new("hjkl", "Move"),
+                StatusBarHint::new("H/L", "Reorder"),
+                StatusBarHint::new("x", "Remove"),
+                StatusBarHint::new("Esc", "Close"),
+            ]);
+        }
+        crate::state::FocusArea::Items | crate::state::FocusArea::Locations => {}
+    }
+
+    (left, None)
+}
+
+fn global_key_hints(
+    state: &AppState,
+    keybindings: &Keybindings<crate::PokeContext>,
+) -> Vec<(String, &'static str)> {
+    let context = keys::focus_context(state.focus);
+    let hint = |command| keys::key_hint(keybindings, command, context);
+    FOOTER_COMMANDS
+        .iter()
+        .filter_map(|&command| {
+            if command != "filter_next" {
+                return Some((hint(command)?, keys::label(command)));
+            }
+            let type_label = match (state.focus, state.detail_mode) {
+                (crate::state::FocusArea::DetailTabs, crate::state::DetailMode::Encounter) => {
+                    "Version"
+                }
+                (crate::state::FocusArea::DetailTabs, crate::state::DetailMode::Move) => {
+                    "Move type"
+                }
+                _ => "Type",
+            };
+            Some((
+                format!("{} {}", hint("filter_prev")?, hint("filter_next")?),
+                type_label,
+            ))
+        })
+        .collect()
+}
+
+fn render_region_picker(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    event_ctx: &mut EventContext<crate::PokeComponentId>,
+    region_list: &mut SelectList,
+) {
+    let items = region_picker_items(state);
+    let width = area.width.saturating_sub(4).min(60);
+    let height = area
+        .height
+        .saturating_sub(4)
+        .min(items.len().max(1) as u16 + 4);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+    event_ctx.set_component_area(crate::PokeComponentId::RegionPicker, popup);
+    frame.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("REGIONS")
+        .style(Style::default().bg(theme().panel_alt).fg(theme().text))
+        .border_style(
+            Style::default()
+                .fg(theme().accent)
+                .add_modifier(Modifier::BOLD),
+        );
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(2), Constraint::Min(1)])
+        .split(inner);
+    let query = Line::from(vec![
+        Span::styled(" Find: ", Style::default().fg(theme().text_dim)),
+        Span::styled(
+            format!("{}_", state.region_picker.query),
+            Style::default().fg(theme().accent),
+        ),
+    ]);
+    frame.render_widget(Paragraph::new(query), layout[0]);
+
+    if items.is_empty() {
+        let message = if state.region_loading {
+            "Loading regions..."
+        } else {
+            "No matching pokedex."
+        };
+        frame.render_widget(
+            Paragraph::new(format!(" {message}")).style(Style::default().fg(theme().text_dim)),
+            layout[1],
+        );
+        return;
+    }
+
+    let props = SelectListProps {
+        items: &items,
+        count: items.len(),
+        selected: state
+            .region_picker
+            .selected
+            .min(items.len().saturating_sub(1)),
+        is_focused: true,
+        style: detail_list_style(),
+        behavior: SelectListBehavior {
+            show_scrollbar: true,
+            wrap_navigation: false,
+        },
+        on_select: Action::RegionPickerSelect,
+        render_item: &|item| item.clone(),
+    };
+    region_list.render(frame, layout[1], props);
+}
+
+fn region_picker_items(state: &AppState) -> Vec<Line<'static>> {
+    state
+        .region_picker_matches()
+        .into_iter()
+        .filter_map(|idx| state.regions.get(idx).map(|info| (idx, info)))
+        .map(|(idx, info)| {
+            let marker = if idx == state.region_index { "*" } else { " " };
+            let completion = match state.region_completion(&info.name) {
+                Some((seen, total)) if total > 0 => {
+                    format!("{:>3}% {seen}/{total}", seen * 100 / total)
+                }
+                Some(_) => "  0%".to_string(),
+                None => "  --".to_string(),
+            };
+            Line::from(vec![
+                Span::raw(format!("{marker} ")),
+                Span::styled(
+                    format!("{:<8} ", format_name(&info.region)),
+                    Style::default().fg(theme().gold),
+                ),
+                Span::raw(format!("{:<18} ", info.label)),
+                Span::styled(completion, Style::default().fg(theme().accent)),
+            ])
+        })
+        .collect()
+}
+
+fn render_damage_calc(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    event_ctx: &mut EventContext<crate::PokeComponentId>,
+) {
+    let Some(attacker) = state.current_detail() else {
+        return;
+    };
+    let width = area.width.saturating_sub(4).min(76);
+    let height = area.height.saturating_sub(4).min(22);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+    event_ctx.set_component_area(crate::PokeComponentId::Calc, popup);
+    frame.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!("DAMAGE CALC: {}", format_name(&attacker.name)))
+        .style(Style::default().bg(theme().panel_alt).fg(theme().text))
+        .border_style(
+            Style::default()
+                .fg(theme().accent)
+                .add_modifier(Modifier::BOLD),
+        );
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+
+    let rows = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(5)])
+        .split(inner);
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
+        .split(rows[0]);
+
+    let pane_block = |title: &'static str, pane: CalcPane| {
+        let color = if state.calc.pane == pane {
+            theme().accent
+        } else {
+            theme().text_dim
+        };
+        Block::default()
+            .borders(Borders::TOP)
+            .title(title)
+            .border_style(Style::default().fg(color))
+    };
+
+    let defender_block = pane_block("DEFENDER", CalcPane::Defender);
+    let defender_area = defender_block.inner(columns[0]);
+    frame.render_widget(defender_block, columns[0]);
+    let mut defender_lines = vec![Line::from(vec![
+        Span::styled(" Find: ", Style::default().fg(theme().text_dim)),
+        Span::styled(
+            format!("{}_", state.calc.query),
+            Style::default().fg(theme().accent),
+        ),
+    ])];
+    let matches = calc::defender_matches(state);
+    let names: Vec<String> = matches
+        .iter()
+        .map(|name| {
+            let marker = if state.calc.defender.as_deref() == Some(*name) {
+                "*"
+            } else {
+                " "
+            };
+            format!("{marker}{}", format_name(name))
+        })
+        .collect();
+    let visible = defender_area.height.saturating_sub(1) as usize;
+    defender_lines.extend(calc_list_lines(
+        &names,
+        state.calc.selected,
+        visible,
+        state.calc.pane == CalcPane::Defender,
+    ));
+    frame.render_widget(Paragraph::new(defender_lines), defender_area);
+
+    let move_block = pane_block("MOVE", CalcPane::Move);
+    let move_area = move_block.inner(columns[1]);
+    frame.render_widget(move_block, columns[1]);
+    let moves: Vec<String> = calc::damaging_moves(attacker)
+        .into_iter()
+        .map(|summary| {
+            format!(
+                " {:<16}{:<9}{:>4}",
+                format_name(&summary.name),
+                summary
+                    .move_type
+                    .as_deref()
+                    .map(format_name)
+                    .unwrap_or_default(),
+                summary.power.unwrap_or(0)
+            )
+        })
+        .collect();
+    let move_lines = calc_list_lines(
+        &moves,
+        state.calc.move_selected,
+        move_area.height as usize,
+        state.calc.pane == CalcPane::Move,
+    );
+    frame.render_widget(Paragraph::new(move_lines), move_area);
+
+    let result_block = Block::default()
+        .borders(Borders::TOP)
+        .title("RESULT")
+        .border_style(Style::default().fg(theme().text_dim));
+    let result_area = result_block.inner(rows[1]);
+    frame.render_widget(result_block, rows[1]);
+    frame.render_widget(
+        Paragraph::new(calc_result_lines(state)).wrap(Wrap { trim: false }),
+        result_area,
+    );
+}
+
+/// A window of `items` that keeps `selected` in view, highlighted when the
+/// list has focus.
+fn calc_list_lines(
+    items: &[String],
+    selected: usize,
+    visible: usize,
+    focused: bool,
+) -> Vec<Line<'static>> {
+    let start = selected.saturating_sub(visible.saturating_sub(1));
+    items
+        .iter()
+        .enumerate()
+        .skip(start)
+        .take(visible)
+        .map(|(idx, text)| {
+            let style = if idx != selected {
+                Style::default()
+            } else if focused {
+                Style::default()
+                    .bg(theme().highlight)
+                    .fg(theme().text)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().add_modifier(Modifier::BOLD)
+            };
+            Line::from(Span::styled(text.clone(), style))
+        })
+        .collect()
+}
+
+fn calc_result_lines(state: &AppState) -> Vec<Line<'static>> {
+    let footnote = Line::from(Span::styled(
+        format!(
+            " Level {}, 31 IVs, no EVs, neutral nature. No crits, items or abilities.",
+            calc::LEVEL
+        ),
+        Style::default().fg(theme().text_dim),
+    ));
+    let range = match calc::calculate(state) {
+        Ok(range) => range,
+        Err(waiting) => {
+            return vec![
+                Line::from(Span::styled(
+                    format!(" {waiting}"),
+                    Style::default().fg(theme().text_dim),
+                )),
+                Line::default(),
+                footnote,
+            ];
+        }
+    };
+    let (Some(summary), Some(defender)) = (
+        calc::selected_move(state),
+        state
+            .calc
+            .defender
+            .as_ref()
+            .and_then(|name| state.details.get(name)),
+    ) else {
+        return vec![footnote];
+    };
+    let types = defender
+        .types
+        .iter()
+        .map(|name| format_name(name))
+        .collect::<Vec<_>>()
+        .join("/");
+    let matchup = Line::from(vec![
+        Span::raw(format!(" {} ", format_name(&summary.name))),
+        Span::styled(
+            format!(
+                "({})",
+                summary.damage_class.as_deref().unwrap_or("physical")
+            ),
+            Style::default().fg(theme().text_dim),
+        ),
+        Span::raw(format!(" vs {} ", format_name(&defender.name))),
+        Span::styled(format!("({types})"), Style::default().fg(theme().text_dim)),
+    ]);
+
+    let damage = Line::from(vec![
+        Span::styled(
+            format!(" {}-{} damage", range.min, range.max),
+            Style::default()
+                .fg(theme().gold)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::raw(format!(
+            "  {:.1}%-{:.1}% of {} HP",
+            range.percent(range.min),
+            range.percent(range.max),
+            range.defender_hp
+        )),
+    ]);
+
+    let mut notes = vec![Span::raw(" ")];
+    let e = range.effectiveness;
+    let (label, color) = if e == 0.0 {
+        ("No effect".to_string(), theme().danger)
+    } else if e > 1.0 {
+        (format!("Super effective x{e}"), theme().success)
+    } else if e < 1.0 {
+        (format!("Not very effective x{e}"), theme().danger)
+    } else {
+        ("Neutral".to_string(), theme().text_dim)
+    };
+    notes.push(Span::styled(label, Style::default().fg(color)));
+    if range.stab {
+        notes.push(Span::styled("  STAB", Style::default().fg(theme().accent)));
+    }
+    if let Some((best, worst)) = range.hits_to_ko() {
+        let ko = match (best, worst) {
+            (1, 1) => "Guaranteed OHKO".to_string(),
+            (1, _) => "Possible OHKO".to_string(),
+            (best, worst) if best == worst => format!("{best}HKO"),
+            (best, worst) => format!("{best}-{worst}HKO"),
+        };
+        notes.push(Span::styled(
+            format!("  {ko}"),
+            Style::default()
+                .fg(theme().text)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    vec![matchup, damage, Line::from(notes), footnote]
+}
+
+fn render_export_picker(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    event_ctx: &mut EventContext<crate::PokeComponentId>,
+) {
+    let Some(detail) = state.current_detail() else {
+        return;
+    };
+    let width = area.width.saturating_sub(4).min(40);
+    let height = area
+        .height
+        .saturating_sub(4)
+        .min(ExportFormat::ALL.len() as u16 + 4);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+    event_ctx.set_component_area(crate::PokeComponentId::ExportPicker, popup);
+    frame.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!("EXPORT: {}", format_name(&detail.name)))
+        .style(Style::default().bg(theme().panel_alt).fg(theme().text))
+        .border_style(
+            Style::default()
+                .fg(theme().accent)
+                .add_modifier(Modifier::BOLD),
+        );
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+
+    let mut lines: Vec<Line> = ExportFormat::ALL
+        .iter()
+        .enumerate()
+        .map(|(idx, format)| {
+            let text = format!(
+                " {:<14}{}",
+                format.label(),
+                export::file_name(&detail.name, *format)
+            );
+            if idx == state.export_picker.selected {
+                Line::from(Span::styled(
+                    text,
+                    Style::default()
+                        .bg(theme().highlight)
+                        .fg(theme().text)
+                        .add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(Span::raw(text))
+            }
+        })
+        .collect();
+    lines.push(Line::default());
+    lines.push(Line::from(Span::styled(
+        " Written to the working directory",
+        Style::default().fg(theme().text_dim),
+    )));
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+fn render_report_prompt(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    event_ctx: &mut EventContext<crate::PokeComponentId>,
+) {
+    let width = area.width.saturating_sub(4).min(56);
+    let height = area.height.saturating_sub(4).min(6);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+    event_ctx.set_component_area(crate::PokeComponentId::ReportPrompt, popup);
+    frame.render_widget(Clear, popup);
+
+    let region = state
+        .current_region()
+        .map(|region| region.label.to_ascii_uppercase())
+        .unwrap_or_else(|| "POKEDEX".to_string());
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!("REPORT: {region}"))
+        .style(Style::default().bg(theme().panel_alt).fg(theme().text))
+        .border_style(
+            Style::default()
+                .fg(theme().accent)
+                .add_modifier(Modifier::BOLD),
+        );
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+
+    let (seen, caught, total) = region_counts(state);
+    let lines = vec![
+        Line::from(vec![
+            Span::styled(" Path ", Style::default().fg(theme().text_dim)),
+            Span::styled(
+                format!("{}_", state.report_prompt.path),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+        ]),
+        Line::default(),
+        Line::from(Span::styled(
+            format!(
+                " Seen {seen}/{total}, caught {caught}/{total}, team of {}",
+                state.team.len()
+            ),
+            Style::default().fg(theme().text_dim),
+        )),
+    ];
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+fn render_sort_picker(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    event_ctx: &mut EventContext<crate::PokeComponentId>,
+) {
+    let width = area.width.saturating_sub(4).min(32);
+    let height = area
+        .height
+        .saturating_sub(4)
+        .min(DexSort::ALL.len() as u16 + 2);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+    event_ctx.set_component_area(crate::PokeComponentId::SortPicker, popup);
+    frame.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("SORT DEX BY")
+        .style(Style::default().bg(theme().panel_alt).fg(theme().text))
+        .border_style(
+            Style::default()
+                .fg(theme().accent)
+                .add_modifier(Modifier::BOLD),
+        );
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+
+    let lines: Vec<Line> = DexSort::ALL
+        .iter()
+        .enumerate()
+        .map(|(idx, sort)| {
+            let marker = if *sort == state.dex_sort { "*" } else { " " };
+            let text = format!("{marker}{:<18}{}", sort.label(), sort.short());
+            if idx == state.sort_picker.selected {
+                Line::from(Span::styled(
+                    text,
+                    Style::default()
+                        .bg(theme().highlight)
+                        .fg(theme().text)
+                        .add_modifier(Modifier::BOLD),
+                ))
+            } else {
+                Line::from(Span::raw(text))
+            }
+        })
+        .collect();
+    frame.render_widget(Paragraph::new(lines), inner);
+}
+
+/// Bookmarks with their sprites, scrolled to keep the selection in view.
+fn render_bookmark_list(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    event_ctx: &mut EventContext<crate::PokeComponentId>,
+) {
+    let rows = state.bookmarks.len().max(1) as u16 * BOOKMARK_ROW_HEIGHT;
+    let width = area.width.saturating_sub(4).min(44);
+    let height = area.height.saturating_sub(4).min(rows + 2);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+    event_ctx.set_component_area(crate::PokeComponentId::BookmarkList, popup);
+    frame.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!("BOOKMARKS ({})", state.bookmarks.len()))
+        .style(Style::default().bg(theme().panel_alt).fg(theme().text))
+        .border_style(
+            Style::default()
+                .fg(theme().accent)
+                .add_modifier(Modifier::BOLD),
+        );
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+
+    if state.bookmarks.is_empty() {
+        sprite_backend::clear_sprites();
+        frame.render_widget(
+            Paragraph::new("No bookmarks yet. Press m on a Pokemon to mark it.")
+                .alignment(Alignment::Center)
+                .style(Style::default().fg(theme().text_dim))
+                .wrap(Wrap { trim: true }),
+            inner,
+        );
+        return;
+    }
+
+    let visible = (inner.height / BOOKMARK_ROW_HEIGHT).max(1) as usize;
+    let first = state
+        .bookmark_list
+        .selected
+        .saturating_sub(visible - 1)
+        .min(state.bookmarks.len().saturating_sub(visible));
+    let mut sprites = Vec::new();
+    for (slot, (idx, name)) in state
+        .bookmarks
+        .iter()
+        .enumerate()
+        .skip(first)
+        .take(visible)
+        .enumerate()
+    {
+        let row = Rect {
+            x: inner.x,
+            y: inner.y + slot as u16 * BOOKMARK_ROW_HEIGHT,
+            width: inner.width,
+            height: BOOKMARK_ROW_HEIGHT.min(inner.height - slot as u16 * BOOKMARK_ROW_HEIGHT),
+        };
+        let selected = idx == state.bookmark_list.selected;
+        if selected {
+            frame.render_widget(
+                Block::default().style(Style::default().bg(theme().highlight)),
+                row,
+            );
+        }
+        let columns = Layout::default()
+            .direction(Direction::Horizontal)
+            .constraints([
+                Constraint::Length(BOOKMARK_SPRITE_WIDTH),
+                Constraint::Min(10),
+            ])
+            .split(row);
+        match state.sprite_cache.get(name) {
+            Some(sprite) => {
+                let (cols, rows) = sprite_fit(sprite, columns[0].width, columns[0].height);
+                if let Ok(image) = sprite_backend::encode_frame(sprite.frame(0), cols, rows) {
+                    let x = columns[0].x + columns[0].width.saturating_sub(cols) / 2;
+                    sprites.push(((x, columns[0].y), image));
+                }
+            }
+            None => frame.render_widget(
+                Paragraph::new("...")
+                    .alignment(Alignment::Center)
+                    .style(Style::default().fg(theme().text_dim)),
+                columns[0],
+            ),
         }
-        crate::state::FocusArea::DetailTabs => {
-            left.push(StatusBarHint::new("h/l", "Tabs"));
-            match state.detail_mode {
-                crate::state::DetailMode::Move
-                | crate::state::DetailMode::Ability
-                | crate::state::DetailMode::Encounter => {
-                    left.push(StatusBarHint::new("j/k", "Select"));
+
+        let name_style = if selected {
+            Style::default()
+                .fg(theme().text)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme().text)
+        };
+        let heading = match state.details.get(name) {
+            Some(detail) => format!("#{:03} {}", detail.id, format_name(name)),
+            None => format_name(name),
+        };
+        let mut lines = vec![Line::from(Span::styled(heading, name_style))];
+        if let Some(detail) = state.details.get(name) {
+            lines.push(Line::from(Span::styled(
+                detail.types.join(" / "),
+                Style::default().fg(theme().text_dim),
+            )));
+        }
+        if state.row_position(name).is_none() {
+            lines.push(Line::from(Span::styled(
+                "not in this dex",
+                Style::default().fg(theme().text_dim),
+            )));
+        }
+        frame.render_widget(Paragraph::new(lines), columns[1]);
+    }
+    sprite_backend::update_sprites(sprites);
+}
+
+/// Keys the focused panel handles itself, ahead of the global keys.
+fn panel_keys(focus: crate::state::FocusArea) -> &'static [(&'static str, &'static str)] {
+    match focus {
+        crate::state::FocusArea::Header => &[("c", "Clear type filter")],
+        crate::state::FocusArea::DexList => &[
+            ("j/k", "Move"),
+            ("PgUp/PgDn", "Page"),
+            ("Shift+Up/Dn", "Evolution stage"),
+            ("f", "Toggle favorite"),
+            ("t", "Add/remove team member"),
+            ("F", "Family view"),
+            ("Space", "Fold family (family view)"),
+            ("C", "Compare"),
+        ],
+        crate::state::FocusArea::DetailTabs => &[
+            ("h/l", "Switch tab"),
+            ("j/k", "Move in list"),
+            ("s", "Cycle move sort (Moves)"),
+            ("v", "Pokemon/team (Matchup)"),
+        ],
+        crate::state::FocusArea::Evolution => &[("j/k", "Select stage")],
+        crate::state::FocusArea::Team => &[
+            ("h/j/k/l", "Move"),
+            ("H/L", "Reorder"),
+            ("x", "Remove"),
+            ("Esc", "Close"),
+        ],
+        crate::state::FocusArea::Items => &[
+            ("j/k", "Move"),
+            ("PgUp/PgDn", "Page"),
+            ("/", "Search"),
+            ("Esc/I", "Close"),
+            ("?", "Help"),
+            ("q", "Quit"),
+        ],
+        crate::state::FocusArea::Locations => &[
+            ("j/k", "Move"),
+            ("h/l", "Switch pane"),
+            ("Enter", "Open Pokemon in dex"),
+            ("PgUp/PgDn", "Page"),
+            ("Esc/L", "Close"),
+            ("?", "Help"),
+            ("q", "Quit"),
+        ],
+    }
+}
+
+/// Every key that does something where focus is: the panel's own keys, then
+/// the global commands as bound for its context, with the ones that do
+/// nothing right now dimmed. Returns how far the list can scroll.
+fn render_help_overlay(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    event_ctx: &mut EventContext<crate::PokeComponentId>,
+    keybindings: &Keybindings<crate::PokeContext>,
+) -> u16 {
+    let context = keys::focus_context(state.focus);
+    let heading = |text: &'static str| {
+        Line::from(Span::styled(
+            text,
+            Style::default()
+                .fg(theme().gold)
+                .add_modifier(Modifier::BOLD),
+        ))
+    };
+    let row = |key: String, label: &str, style: Style| {
+        Line::from(vec![
+            Span::styled(format!(" {key:<14}"), style.fg(theme().accent)),
+            Span::styled(label.to_string(), style),
+        ])
+    };
+
+    let mut lines = vec![heading("THIS PANEL")];
+    for (key, label) in panel_keys(state.focus) {
+        lines.push(row(
+            key.to_string(),
+            label,
+            Style::default().fg(theme().text),
+        ));
+    }
+    // The item and location browsers take every key themselves.
+    if !matches!(
+        state.focus,
+        crate::state::FocusArea::Items | crate::state::FocusArea::Locations
+    ) {
+        lines.push(Line::default());
+        lines.push(heading("EVERYWHERE"));
+        for command in keys::COMMANDS {
+            let bound = keys::bound_keys(keybindings, command.name, context);
+            if bound.is_empty() {
+                continue;
+            }
+            let style = if keys::command_action(command.name, state).is_some() {
+                Style::default().fg(theme().text)
+            } else {
+                Style::default()
+                    .fg(theme().text_dim)
+                    .add_modifier(Modifier::DIM)
+            };
+            lines.push(row(bound.join(" "), command.label, style));
+        }
+    }
+
+    let width = area.width.saturating_sub(4).min(48);
+    let height = area.height.saturating_sub(4).min(lines.len() as u16 + 2);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+    event_ctx.set_component_area(crate::PokeComponentId::Help, popup);
+    frame.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!("KEYS [{}]", context.name()))
+        .style(Style::default().bg(theme().panel_alt).fg(theme().text))
+        .border_style(
+            Style::default()
+                .fg(theme().accent)
+                .add_modifier(Modifier::BOLD),
+        );
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+
+    let max_scroll = (lines.len() as u16).saturating_sub(inner.height);
+    let scroll = state.help.scroll.min(max_scroll);
+    frame.render_widget(Paragraph::new(lines).scroll((scroll, 0)), inner);
+    max_scroll
+}
+
+/// Pokemon of the day: sprite on the left, name, typing and a trivia line on the right.
+fn render_featured_card(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    event_ctx: &mut EventContext<crate::PokeComponentId>,
+) {
+    let width = area.width.saturating_sub(4).min(60);
+    let height = area.height.saturating_sub(4).min(12);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+    event_ctx.set_component_area(crate::PokeComponentId::FeaturedCard, popup);
+    frame.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title("POKEMON OF THE DAY")
+        .style(Style::default().bg(theme().panel_alt).fg(theme().text))
+        .border_style(
+            Style::default()
+                .fg(theme().gold)
+                .add_modifier(Modifier::BOLD),
+        );
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+
+    let featured = &state.featured;
+    let Some(name) = featured.name.as_deref() else {
+        sprite_backend::clear_sprites();
+        let message = match &featured.error {
+            Some(error) => format!("Couldn't load today's pick: {error}"),
+            None => "Picking today's Pokemon...".to_string(),
+        };
+        frame.render_widget(
+            Paragraph::new(message)
+                .alignment(Alignment::Center)
+                .wrap(Wrap { trim: true })
+                .style(Style::default().fg(theme().text_dim)),
+            inner,
+        );
+        return;
+    };
+
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Length(20), Constraint::Min(10)])
+        .split(inner);
+    // The card covers the detail panel, so its sprite replaces that one.
+    match state.sprite_cache.get(name) {
+        Some(sprite) => {
+            let (cols, rows) = sprite_fit(sprite, columns[0].width, columns[0].height);
+            match sprite_backend::encode_frame(sprite.frame(state.sprite_frame_index), cols, rows) {
+                Ok(image) => {
+                    let x = columns[0].x + columns[0].width.saturating_sub(cols) / 2;
+                    let y = columns[0].y + columns[0].height.saturating_sub(rows) / 2;
+                    sprite_backend::update_sprite(x, y, image);
                 }
-                crate::state::DetailMode::General | crate::state::DetailMode::Matchup => {}
+                Err(_) => sprite_backend::clear_sprites(),
             }
         }
-        crate::state::FocusArea::Evolution => {
-            left.push(StatusBarHint::new("j/k", "Select"));
+        None => {
+            sprite_backend::clear_sprites();
+            frame.render_widget(
+                Paragraph::new("[no sprite]")
+                    .alignment(Alignment::Center)
+                    .style(Style::default().fg(theme().text_dim)),
+                columns[0],
+            );
+        }
+    }
+
+    let detail = state.details.get(name);
+    let heading = match detail {
+        Some(detail) => format!("{}  #{:03}", format_name(name), detail.id),
+        None => format_name(name),
+    };
+    let mut lines = vec![
+        Line::from(Span::styled(
+            heading,
+            Style::default()
+                .fg(theme().accent)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(Span::styled(
+            detail
+                .map(|detail| detail.types.join(" / "))
+                .unwrap_or_default(),
+            Style::default().fg(theme().text_dim),
+        )),
+        Line::from(" "),
+    ];
+    if let Some(trivia) = &featured.trivia {
+        lines.push(Line::from(trivia.clone()));
+    }
+    lines.push(Line::from(" "));
+    lines.push(Line::from(Span::styled(
+        "Enter to view, any other key to close",
+        Style::default().fg(theme().text_dim),
+    )));
+    frame.render_widget(Paragraph::new(lines).wrap(Wrap { trim: true }), columns[1]);
+}
+
+fn render_note_editor(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    event_ctx: &mut EventContext<crate::PokeComponentId>,
+) {
+    let editor = &state.note_editor;
+    let width = area.width.saturating_sub(4).min(72);
+    let height = area.height.saturating_sub(4).min(18);
+    let popup = Rect {
+        x: area.x + (area.width - width) / 2,
+        y: area.y + (area.height - height) / 2,
+        width,
+        height,
+    };
+    event_ctx.set_component_area(crate::PokeComponentId::NoteEditor, popup);
+    frame.render_widget(Clear, popup);
+
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .title(format!("NOTES: {}", format_name(&editor.name)))
+        .style(Style::default().bg(theme().panel_alt).fg(theme().text))
+        .border_style(
+            Style::default()
+                .fg(theme().gold)
+                .add_modifier(Modifier::BOLD),
+        );
+    let inner = block.inner(popup);
+    frame.render_widget(block, popup);
+    if inner.width == 0 || inner.height == 0 {
+        return;
+    }
+
+    // Keep the cursor in view; lines are not wrapped so columns stay aligned.
+    let scroll_y = editor.row.saturating_sub(inner.height as usize - 1);
+    let scroll_x = editor.col.saturating_sub(inner.width as usize - 1);
+    let lines: Vec<Line> = editor
+        .lines
+        .iter()
+        .enumerate()
+        .map(|(row, line)| {
+            let cursor = (row == editor.row).then_some(editor.col);
+            note_editor_line(line, cursor)
+        })
+        .collect();
+    frame.render_widget(
+        Paragraph::new(lines).scroll((scroll_y as u16, scroll_x as u16)),
+        inner,
+    );
+}
+
+/// Raw line with markdown-lite styling and a block cursor at `cursor` (in chars).
+fn note_editor_line(line: &str, cursor: Option<usize>) -> Line<'static> {
+    let style = note_line_style(line);
+    let Some(col) = cursor else {
+        return Line::from(Span::styled(line.to_string(), style));
+    };
+    let mut chars = line.chars();
+    let before: String = chars.by_ref().take(col).collect();
+    let at = chars
+        .next()
+        .map(String::from)
+        .unwrap_or_else(|| " ".to_string());
+    let after: String = chars.collect();
+    Line::from(vec![
+        Span::styled(before, style),
+        Span::styled(at, style.bg(theme().accent).fg(theme().bg)),
+        Span::styled(after, style),
+    ])
+}
+
+/// Rendered note line for the detail panel: markers are replaced and `**bold**` applied.
+fn note_preview_line(line: &str) -> Line<'static> {
+    let style = note_line_style(line);
+    if let Some(heading) = line.strip_prefix('#') {
+        return Line::from(Span::styled(
+            heading.trim_start_matches('#').trim().to_string(),
+            style,
+        ));
+    }
+    let (prefix, body) = match line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
+        Some(body) => (
+            Some(Span::styled("  o ", Style::default().fg(theme().accent))),
+            body,
+        ),
+        None => (None, line.strip_prefix("> ").unwrap_or(line)),
+    };
+    let spans = prefix
+        .into_iter()
+        .chain(body.split("**").enumerate().map(|(idx, part)| {
+            // Odd segments sit between a pair of `**` markers.
+            let part_style = if idx % 2 == 1 {
+                style.add_modifier(Modifier::BOLD)
+            } else {
+                style
+            };
+            Span::styled(part.to_string(), part_style)
+        }))
+        .collect::<Vec<_>>();
+    Line::from(spans)
+}
+
+fn note_line_style(line: &str) -> Style {
+    if line.starts_with('#') {
+        Style::default()
+            .fg(theme().gold)
+            .add_modifier(Modifier::BOLD)
+    } else if line.starts_with("> ") {
+        Style::default()
+            .fg(theme().text_dim)
+            .add_modifier(Modifier::ITALIC)
+    } else {
+        Style::default().fg(theme().text)
+    }
+}
+
+/// The current chain as a tree, one row per stage: connectors, how the stage
+/// is reached from its parent, then its name.
+fn evolution_items(state: &AppState) -> Vec<Line<'static>> {
+    let Some(chain) = state.current_evolution() else {
+        return Vec::new();
+    };
+    chain
+        .stages
+        .iter()
+        .enumerate()
+        .map(|(index, stage)| {
+            let mut spans = vec![Span::styled(
+                evolution_tree_prefix(chain, index),
+                Style::default().fg(theme().text_dim),
+            )];
+            if let Some(trigger) = &stage.trigger {
+                spans.push(Span::styled(
+                    format!("{trigger} "),
+                    Style::default().fg(theme().gold),
+                ));
+                spans.push(Span::styled("▸ ", Style::default().fg(theme().text_dim)));
+            }
+            let style = if state.detail_name.as_deref() == Some(stage.name.as_str()) {
+                Style::default()
+                    .fg(theme().accent)
+                    .add_modifier(Modifier::BOLD)
+            } else {
+                Style::default().fg(theme().text)
+            };
+            spans.push(Span::styled(format_name(&stage.name), style));
+            Line::from(spans)
+        })
+        .collect()
+}
+
+/// Box-drawing in front of a stage: a bar for every ancestor that still has
+/// siblings below, then `├─`/`└─` for the stage itself. The base form gets none.
+fn evolution_tree_prefix(chain: &EvolutionChain, index: usize) -> String {
+    let mut columns = Vec::new();
+    let mut ancestor = chain.stages[index].parent;
+    while let Some(current) = ancestor {
+        let parent = chain.stages[current].parent;
+        if parent.is_some() {
+            columns.push(if chain.has_later_sibling(current) {
+                "│  "
+            } else {
+                "   "
+            });
+        }
+        ancestor = parent;
+    }
+    if chain.stages[index].parent.is_none() {
+        return String::new();
+    }
+    columns.reverse();
+    let mut prefix = columns.concat();
+    prefix.push_str(if chain.has_later_sibling(index) {
+        "├─ "
+    } else {
+        "└─ "
+    });
+    prefix
+}
+
+fn evolution_stage_count(state: &AppState) -> Option<usize> {
+    Some(state.current_evolution()?.stages.len())
+}
+
+fn evolution_list_style() -> SelectListStyle {
+    SelectListStyle {
+        base: BaseStyle {
+            border: None,
+            padding: Padding::xy(1, 0),
+            bg: Some(theme().panel),
+            fg: Some(theme().text),
+        },
+        selection: SelectionStyle {
+            style: Some(
+                Style::default()
+                    .bg(theme().highlight)
+                    .fg(theme().text)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            marker: None,
+            disabled: false,
+        },
+        ..SelectListStyle::default()
+    }
+}
+
+fn dex_items(state: &AppState) -> Vec<Line<'static>> {
+    let mut items: Vec<Line<'static>> = state
+        .dex_rows
+        .iter()
+        .filter_map(|row| {
+            let entry = state.row_entry(row)?;
+            let line = match row {
+                DexRow::Head(idx) => {
+                    let mut spans = Vec::new();
+                    if state.family_view {
+                        spans.push(family_marker(state, &entry.name));
+                    }
+                    spans.extend(dex_entry_spans(state, entry, state.search.matches.get(idx)));
+                    Line::from(spans)
+                }
+                DexRow::Member { depth, .. } => {
+                    let indent = "  ".repeat(*depth);
+                    let mut spans = vec![Span::styled(
+                        format!("{indent}\u{2514} "),
+                        Style::default().fg(theme().text_dim),
+                    )];
+                    spans.extend(dex_entry_spans(state, entry, None));
+                    Line::from(spans)
+                }
+            };
+            Some(line)
+        })
+        .collect();
+    if let Some(sentinel) = dex_page_sentinel(state) {
+        items.push(sentinel);
+    }
+    items
+}
+
+fn dex_entry_spans(
+    state: &AppState,
+    entry: &PokedexEntry,
+    hit: Option<&SearchMatch>,
+) -> Vec<Span<'static>> {
+    let fav = if state.favorites.contains(&entry.name) {
+        "*"
+    } else {
+        " "
+    };
+    let note = if state.notes.contains_key(&entry.name) {
+        "+"
+    } else {
+        " "
+    };
+    let number = format!("#{:03}", entry.entry_number);
+    let mut spans = vec![Span::raw(format!("{fav}{note} "))];
+    match hit {
+        Some(SearchMatch::Number) => {
+            spans.push(Span::styled(number, search_hit_style()));
+            spans.push(Span::raw(format!(" {}", entry.name)));
+        }
+        Some(SearchMatch::Name(chars)) => {
+            spans.push(Span::raw(format!("{number} ")));
+            spans.extend(highlight_chars(&entry.name, chars, Style::default()));
+        }
+        Some(SearchMatch::Type(type_name, chars)) => {
+            spans.push(Span::raw(format!("{number} {}  ", entry.name)));
+            let dim = Style::default().fg(theme().text_dim);
+            spans.extend(highlight_chars(type_name, chars, dim));
         }
+        None => spans.push(Span::raw(format!("{number} {}", entry.name))),
     }
+    spans
+}
 
-    let type_label = if state.focus == crate::state::FocusArea::DetailTabs
-        && state.detail_mode == crate::state::DetailMode::Encounter
-    {
-        "Version"
+/// Fold marker in front of a family head: open, folded (with its size), still
+/// loading, or blank for species that never evolve here.
+fn family_marker(state: &AppState, head: &str) -> Span<'static> {
+    let dim = Style::default().fg(theme().text_dim);
+    let Some(members) = state.family_members(head) else {
+        let text = if state.family_chain_id(head).is_some() {
+            "\u{2026} "
+        } else {
+            "  "
+        };
+        return Span::styled(text, dim);
+    };
+    if members.is_empty() {
+        Span::styled("  ", dim)
+    } else if state.collapsed_families.contains(head) {
+        Span::styled(
+            format!("\u{25b8}{}", members.len()),
+            Style::default().fg(theme().gold),
+        )
     } else {
-        "Type"
-    };
-    let center = vec![
-        StatusBarHint::new("Tab", "Focus"),
-        StatusBarHint::new("/", "Search"),
-        StatusBarHint::new("[ ]", type_label),
-        StatusBarHint::new("r/R", "Region"),
-        StatusBarHint::new("p", "Cry"),
-        StatusBarHint::new("q", "Quit"),
-    ];
-    (left, center)
+        Span::styled("\u{25be} ", Style::default().fg(theme().gold))
+    }
 }
 
-fn evolution_items(state: &AppState) -> Vec<Line<'static>> {
-    let Some(species) = state.current_species() else {
-        return Vec::new();
-    };
-    let Some(url) = species.evolution_chain_url.as_ref() else {
-        return Vec::new();
-    };
-    let id = url
-        .trim_end_matches('/')
-        .split('/')
-        .last()
-        .unwrap_or("unknown");
-    let Some(chain) = state.evolution.get(id) else {
-        return Vec::new();
+/// Trailing row for a paged dex while more species remain to be fetched.
+fn dex_page_sentinel(state: &AppState) -> Option<Line<'static>> {
+    let paging = state.dex_paging.as_ref()?;
+    let text = if paging.loading.is_some() {
+        "   loading more...".to_string()
+    } else {
+        match state.dex_remaining()? {
+            0 => return None,
+            remaining => format!("   {remaining} more below"),
+        }
     };
-    chain
-        .stages
-        .iter()
-        .enumerate()
-        .map(|(idx, name)| Line::from(format!("{:02} {}", idx + 1, name)))
-        .collect()
+    Some(Line::from(Span::styled(
+        text,
+        Style::default()
+            .fg(theme().text_dim)
+            .add_modifier(Modifier::ITALIC),
+    )))
 }
 
-fn evolution_stage_count(state: &AppState) -> Option<usize> {
-    let species = state.current_species()?;
-    let url = species.evolution_chain_url.as_ref()?;
-    let id = url
-        .trim_end_matches('/')
-        .split('/')
-        .last()
-        .unwrap_or("unknown");
-    let chain = state.evolution.get(id)?;
-    Some(chain.stages.len())
+fn search_hit_style() -> Style {
+    Style::default()
+        .fg(theme().gold)
+        .add_modifier(Modifier::BOLD)
 }
 
-fn evolution_list_style() -> SelectListStyle {
-    SelectListStyle {
-        base: BaseStyle {
-            border: None,
-            padding: Padding::xy(1, 0),
-            bg: Some(BG_PANEL),
-            fg: Some(TEXT_MAIN),
-        },
-        selection: SelectionStyle {
-            style: Some(
-                Style::default()
-                    .bg(BG_HIGHLIGHT)
-                    .fg(TEXT_MAIN)
-                    .add_modifier(Modifier::BOLD),
-            ),
-            marker: None,
-            disabled: false,
-        },
-        ..SelectListStyle::default()
+/// Split `text` into runs, styling the chars at `positions` as search hits.
+fn highlight_chars(text: &str, positions: &[usize], base: Style) -> Vec<Span<'static>> {
+    let mut spans: Vec<Span<'static>> = Vec::new();
+    let mut run = String::new();
+    let mut run_hit = false;
+    for (idx, ch) in text.chars().enumerate() {
+        let hit = positions.contains(&idx);
+        if hit != run_hit && !run.is_empty() {
+            let style = if run_hit { search_hit_style() } else { base };
+            spans.push(Span::styled(std::mem::take(&mut run), style));
+        }
+        run_hit = hit;
+        run.push(ch);
+    }
+    if !run.is_empty() {
+        let style = if run_hit { search_hit_style() } else { base };
+        spans.push(Span::styled(run, style));
     }
+    spans
 }
 
-fn dex_items(state: &AppState) -> Vec<Line<'static>> {
+fn item_list_items(state: &AppState) -> Vec<Line<'static>> {
+    let query = state.items.query.trim().to_lowercase();
     state
-        .filtered_indices
+        .items
+        .filtered
         .iter()
-        .filter_map(|idx| state.pokedex.get(*idx))
-        .map(|entry| {
-            let fav = if state.favorites.contains(&entry.name) {
-                "*"
+        .filter_map(|&idx| state.items.names.get(idx))
+        .map(|name| {
+            let positions = if query.is_empty() {
+                Vec::new()
             } else {
-                " "
+                fuzzy_match(&query, name)
+                    .map(|(_, chars)| chars)
+                    .unwrap_or_default()
             };
-            Line::from(format!("{} #{:03} {}", fav, entry.entry_number, entry.name))
+            let label = format_name(name);
+            Line::from(highlight_chars(&label, &positions, Style::default()))
         })
         .collect()
 }
 
-fn move_items(state: &AppState) -> Vec<Line<'static>> {
-    let Some(detail) = state.current_detail() else {
+fn location_items(state: &AppState) -> Vec<Line<'static>> {
+    state
+        .locations
+        .names
+        .iter()
+        .map(|name| Line::from(format_name(name)))
+        .collect()
+}
+
+fn location_pokemon_items(state: &AppState) -> Vec<Line<'static>> {
+    let Some(location) = state.locations.selected_name() else {
         return Vec::new();
     };
-    detail
-        .moves
+    state
+        .locations
+        .selected_encounters()
+        .unwrap_or_default()
         .iter()
+        .map(|encounter| {
+            let name_color = if state.seen.contains(&encounter.pokemon) {
+                theme().accent
+            } else {
+                theme().text
+            };
+            let mut spans = vec![Span::styled(
+                format!("{:<14}", format_name(&encounter.pokemon)),
+                Style::default().fg(name_color),
+            )];
+            let chance = encounter
+                .version_details
+                .iter()
+                .map(|version| version.max_chance)
+                .max()
+                .unwrap_or(0);
+            spans.push(Span::styled(
+                format!("{chance:>3}%  "),
+                Style::default().fg(theme().text_dim),
+            ));
+            if let Some(area) = area_label(location, &encounter.area) {
+                spans.push(Span::styled(
+                    format!("{area}  "),
+                    Style::default().fg(theme().gold),
+                ));
+            }
+            if let Some(summary) = version_summary(&encounter.version_details, None) {
+                spans.push(Span::styled(summary, Style::default().fg(theme().text_dim)));
+            }
+            Line::from(spans)
+        })
+        .collect()
+}
+
+/// Per-version breakdown for the pokemon selected in the Locations pane.
+fn location_pokemon_text(state: &AppState) -> Text<'static> {
+    let Some(encounter) = state.locations.selected_pokemon() else {
+        return Text::default();
+    };
+    let mut lines = vec![Line::from(vec![
+        Span::styled(
+            format_name(&encounter.pokemon),
+            Style::default()
+                .fg(theme().accent)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!("  {}", format_name(&encounter.area)),
+            Style::default().fg(theme().text_dim),
+        ),
+    ])];
+    for version in filtered_versions(&encounter.version_details, None) {
+        let summary = version_summary(std::slice::from_ref(version), None).unwrap_or_default();
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{:<12}", format_name(&version.version)),
+                Style::default().fg(theme().gold),
+            ),
+            Span::styled(
+                format!("{:>3}%  ", version.max_chance),
+                Style::default().fg(theme().text_dim),
+            ),
+            Span::raw(summary),
+        ]));
+    }
+    Text::from(lines)
+}
+
+/// The part of an area name that sets it apart from its location, if any.
+fn area_label(location: &str, area: &str) -> Option<String> {
+    let rest = area.strip_prefix(location).unwrap_or(area).trim_matches('-');
+    if rest.is_empty() || rest == "area" {
+        None
+    } else {
+        Some(format_name(rest))
+    }
+}
+
+const MOVE_NUMBER_WIDTH: usize = 3;
+const MOVE_NAME_MIN: usize = 8;
+const MOVE_NAME_MAX: usize = 18;
+const MOVE_TYPE_WIDTH: usize = 10;
+const MOVE_TYPE_SHORT_WIDTH: usize = 5;
+const MOVE_STAT_WIDTH: usize = 4;
+const MOVE_LEARNED_WIDTH: usize = 6;
+
+/// Which Moves table columns fit, and how wide the name gets.
+#[derive(Clone, Copy)]
+struct MoveColumns {
+    name: usize,
+    type_chip: usize,
+    accuracy: bool,
+    learned: bool,
+}
+
+impl MoveColumns {
+    fn fixed(self) -> usize {
+        let stats = if self.accuracy { 3 } else { 2 };
+        let learned = if self.learned { MOVE_LEARNED_WIDTH } else { 0 };
+        MOVE_NUMBER_WIDTH + self.type_chip + stats * MOVE_STAT_WIDTH + learned
+    }
+}
+
+/// Lay the Moves table out in `width` cells. When the name would drop below
+/// its minimum, the learn method goes first, then the type chip shrinks to
+/// three letters, then accuracy goes. Names longer than their column end in `…`.
+fn move_columns(width: u16) -> MoveColumns {
+    let width = width as usize;
+    let mut columns = MoveColumns {
+        name: MOVE_NAME_MAX,
+        type_chip: MOVE_TYPE_WIDTH,
+        accuracy: true,
+        learned: true,
+    };
+    if width < columns.fixed() + MOVE_NAME_MIN {
+        columns.learned = false;
+    }
+    if width < columns.fixed() + MOVE_NAME_MIN {
+        columns.type_chip = MOVE_TYPE_SHORT_WIDTH;
+    }
+    if width < columns.fixed() + MOVE_NAME_MIN {
+        columns.accuracy = false;
+    }
+    columns.name = width
+        .saturating_sub(columns.fixed())
+        .clamp(MOVE_NAME_MIN, MOVE_NAME_MAX);
+    columns
+}
+
+fn move_header(columns: MoveColumns) -> Line<'static> {
+    let mut header = format!(
+        "{:<number$}{:<name$}{:<chip$}{:>stat$}",
+        "#",
+        "NAME",
+        "TYPE",
+        "POW",
+        number = MOVE_NUMBER_WIDTH,
+        name = columns.name,
+        chip = columns.type_chip,
+        stat = MOVE_STAT_WIDTH,
+    );
+    if columns.accuracy {
+        header.push_str(&format!("{:>MOVE_STAT_WIDTH$}", "ACC"));
+    }
+    header.push_str(&format!("{:>MOVE_STAT_WIDTH$}", "PP"));
+    if columns.learned {
+        header.push_str(&format!(
+            "  {:<width$}",
+            "HOW",
+            width = MOVE_LEARNED_WIDTH - 2
+        ));
+    }
+    Line::from(Span::styled(
+        header,
+        Style::default()
+            .fg(theme().accent)
+            .add_modifier(Modifier::BOLD),
+    ))
+}
+
+fn move_items(state: &AppState, columns: MoveColumns) -> Vec<Line<'static>> {
+    state
+        .visible_moves()
+        .into_iter()
         .enumerate()
-        .map(|(idx, name)| Line::from(format!("{:02} {name}", idx + 1)))
+        .map(|(idx, summary)| move_item(state, idx, summary, columns))
         .collect()
 }
 
+fn move_item(
+    state: &AppState,
+    idx: usize,
+    summary: &MoveSummary,
+    columns: MoveColumns,
+) -> Line<'static> {
+    let number = |value: Option<u16>| value.map_or("--".to_string(), |value| value.to_string());
+    let dim = Style::default().fg(theme().text_dim);
+    // PP only comes with the move's details, which load a page at a time.
+    let pp = match state.move_cache.get(&summary.name) {
+        Some(detail) => number(detail.pp),
+        None => "…".to_string(),
+    };
+
+    let mut spans = vec![
+        Span::styled(format!("{:<MOVE_NUMBER_WIDTH$}", idx + 1), dim),
+        Span::raw(fit_cell(&format_name(&summary.name), columns.name)),
+        type_chip(summary.move_type.as_deref(), columns.type_chip),
+        Span::raw(" "),
+        Span::styled(
+            format!("{:>MOVE_STAT_WIDTH$}", number(summary.power)),
+            Style::default().fg(theme().text),
+        ),
+    ];
+    if columns.accuracy {
+        spans.push(Span::styled(
+            format!("{:>MOVE_STAT_WIDTH$}", number(summary.accuracy)),
+            dim,
+        ));
+    }
+    spans.push(Span::styled(format!("{pp:>MOVE_STAT_WIDTH$}"), dim));
+    if columns.learned {
+        let learned = match summary.learn_method.as_str() {
+            "level-up" => format!("Lv{}", summary.level),
+            "machine" => "TM".to_string(),
+            "egg" => "Egg".to_string(),
+            "tutor" => "Tutor".to_string(),
+            other => format_name(other),
+        };
+        spans.push(Span::styled(
+            format!("  {}", fit_cell(&learned, MOVE_LEARNED_WIDTH - 2)),
+            dim,
+        ));
+    }
+    Line::from(spans)
+}
+
+/// `text` padded or cut to exactly `width` chars, ending in `…` when cut.
+fn fit_cell(text: &str, width: usize) -> String {
+    if text.chars().count() <= width {
+        return format!("{text:<width$}");
+    }
+    let mut cut: String = text.chars().take(width.saturating_sub(1)).collect();
+    cut.push('…');
+    cut
+}
+
+/// A move type as a colored block, three letters when `width` is short. The
+/// block is one column narrower than `width`, leaving room for a gap after it.
+fn type_chip(move_type: Option<&str>, width: usize) -> Span<'static> {
+    let block = width.saturating_sub(1);
+    let Some(move_type) = move_type else {
+        return Span::styled(
+            format!("{:<block$}", " ?"),
+            Style::default().fg(theme().text_dim),
+        );
+    };
+    let label = if width < MOVE_TYPE_WIDTH {
+        move_type.chars().take(3).collect::<String>()
+    } else {
+        move_type.to_string()
+    };
+    let chip = format!(
+        " {:<inner$}",
+        label.to_uppercase(),
+        inner = block.saturating_sub(1)
+    );
+    let (r, g, b) = type_rgb(move_type);
+    // Light chips get dark text; dark ones light text.
+    let luma = (r as u32 * 299 + g as u32 * 587 + b as u32 * 114) / 1000;
+    let text = if luma > 140 {
+        Color::Rgb(24, 24, 24)
+    } else {
+        Color::Rgb(245, 245, 245)
+    };
+    Span::styled(
+        chip,
+        Style::default()
+            .fg(text)
+            .bg(Color::Rgb(r, g, b))
+            .add_modifier(Modifier::BOLD),
+    )
+}
+
+/// The type colors the games use; unknown types are grey.
+fn type_rgb(move_type: &str) -> (u8, u8, u8) {
+    match move_type {
+        "normal" => (0xA8, 0xA8, 0x78),
+        "fire" => (0xF0, 0x80, 0x30),
+        "water" => (0x68, 0x90, 0xF0),
+        "electric" => (0xF8, 0xD0, 0x30),
+        "grass" => (0x78, 0xC8, 0x50),
+        "ice" => (0x98, 0xD8, 0xD8),
+        "fighting" => (0xC0, 0x30, 0x28),
+        "poison" => (0xA0, 0x40, 0xA0),
+        "ground" => (0xE0, 0xC0, 0x68),
+        "flying" => (0xA8, 0x90, 0xF0),
+        "psychic" => (0xF8, 0x58, 0x88),
+        "bug" => (0xA8, 0xB8, 0x20),
+        "rock" => (0xB8, 0xA0, 0x38),
+        "ghost" => (0x70, 0x58, 0x98),
+        "dragon" => (0x70, 0x38, 0xF8),
+        "dark" => (0x70, 0x58, 0x48),
+        "steel" => (0xB8, 0xB8, 0xD0),
+        "fairy" => (0xEE, 0x99, 0xAC),
+        _ => (0x68, 0x68, 0x68),
+    }
+}
+
 fn ability_items(state: &AppState) -> Vec<Line<'static>> {
     let Some(detail) = state.current_detail() else {
         return Vec::new();
@@ -949,7 +4187,12 @@ fn encounter_summary(
     encounter: &crate::state::EncounterLocation,
     filter: Option<&str>,
 ) -> Option<String> {
-    let versions = filtered_encounter_versions(encounter, filter);
+    version_summary(&encounter.version_details, filter)
+}
+
+/// Up to three encounter methods with their level ranges, most likely first.
+fn version_summary(version_details: &[EncounterVersion], filter: Option<&str>) -> Option<String> {
+    let versions = filtered_versions(version_details, filter);
     if versions.is_empty() {
         return None;
     }
@@ -993,8 +4236,14 @@ fn filtered_encounter_versions<'a>(
     encounter: &'a crate::state::EncounterLocation,
     filter: Option<&str>,
 ) -> Vec<&'a crate::state::EncounterVersion> {
-    let mut versions: Vec<_> = encounter
-        .version_details
+    filtered_versions(&encounter.version_details, filter)
+}
+
+fn filtered_versions<'a>(
+    version_details: &'a [EncounterVersion],
+    filter: Option<&str>,
+) -> Vec<&'a EncounterVersion> {
+    let mut versions: Vec<_> = version_details
         .iter()
         .filter(|version| filter.map_or(true, |value| version.version == value))
         .collect();
@@ -1008,13 +4257,13 @@ fn dex_list_style() -> SelectListStyle {
             border: None,
             padding: Padding::xy(1, 0),
             bg: None,
-            fg: Some(TEXT_MAIN),
+            fg: Some(theme().text),
         },
         selection: SelectionStyle {
             style: Some(
                 Style::default()
-                    .bg(BG_HIGHLIGHT)
-                    .fg(TEXT_MAIN)
+                    .bg(theme().highlight)
+                    .fg(theme().text)
                     .add_modifier(Modifier::BOLD),
             ),
             marker: None,
@@ -1029,14 +4278,14 @@ fn detail_list_style() -> SelectListStyle {
         base: BaseStyle {
             border: None,
             padding: Padding::xy(1, 0),
-            bg: Some(BG_PANEL_ALT),
-            fg: Some(TEXT_MAIN),
+            bg: Some(theme().panel_alt),
+            fg: Some(theme().text),
         },
         selection: SelectionStyle {
             style: Some(
                 Style::default()
-                    .bg(BG_HIGHLIGHT)
-                    .fg(TEXT_MAIN)
+                    .bg(theme().highlight)
+                    .fg(theme().text)
                     .add_modifier(Modifier::BOLD),
             ),
             marker: None,
@@ -1061,7 +4310,7 @@ fn render_detail_tabs(
     let block = Block::default()
         .borders(Borders::ALL)
         .title("DETAIL")
-        .style(Style::default().bg(BG_PANEL).fg(TEXT_MAIN))
+        .style(Style::default().bg(theme().panel).fg(theme().text))
         .border_style(focus_border(state, crate::state::FocusArea::DetailTabs));
     let inner = block.inner(area);
     frame.render_widget(block, area);
@@ -1071,10 +4320,10 @@ fn render_detail_tabs(
         .split(inner);
     let tabs = Tabs::new(vec!["General", "Moves", "Abilities", "Encounters", "Matchup"])
         .select(detail_mode_index(state))
-        .style(Style::default().fg(TEXT_DIM))
+        .style(Style::default().fg(theme().text_dim))
         .highlight_style(
             Style::default()
-                .fg(ACCENT_TEAL)
+                .fg(theme().accent)
                 .add_modifier(Modifier::BOLD),
         );
     frame.render_widget(tabs, layout[0]);
@@ -1083,7 +4332,7 @@ fn render_detail_tabs(
             let content = general_detail_text(state);
             frame.render_widget(
                 Paragraph::new(content)
-                    .style(Style::default().fg(TEXT_MAIN))
+                    .style(Style::default().fg(theme().text))
                     .wrap(Wrap { trim: true }),
                 layout[1],
             );
@@ -1125,7 +4374,7 @@ fn general_detail_text(state: &AppState) -> Text<'static> {
         Line::from(Span::styled(
             format!("{}  #{:03}", detail.name.to_ascii_uppercase(), detail.id),
             Style::default()
-                .fg(ACCENT_TEAL)
+                .fg(theme().accent)
                 .add_modifier(Modifier::BOLD),
         )),
         Line::from(format!("Type: {types}")),
@@ -1140,6 +4389,16 @@ fn general_detail_text(state: &AppState) -> Text<'static> {
         lines.push(Line::from(" "));
         lines.push(Line::from(flavor));
     }
+    if let Some(note) = state.notes.get(&detail.name) {
+        lines.push(Line::from(" "));
+        lines.push(Line::from(Span::styled(
+            "NOTES",
+            Style::default()
+                .fg(theme().gold)
+                .add_modifier(Modifier::BOLD),
+        )));
+        lines.extend(note.lines().map(note_preview_line));
+    }
     Text::from(lines)
 }
 
@@ -1148,18 +4407,39 @@ fn render_moves_tab(frame: &mut Frame, area: Rect, state: &AppState, move_list:
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(45), Constraint::Percentage(55)])
         .split(area);
+    let filter_label = state
+        .move_type_filter
+        .as_deref()
+        .map(format_name)
+        .unwrap_or_else(|| "All".to_string());
+    let move_count = state.visible_moves().len();
+    let pages = move_count.div_ceil(MOVE_PAGE_SIZE).max(1);
+    let page = (state.selected_move_index / MOVE_PAGE_SIZE + 1).min(pages);
     let list_block = Block::default()
         .borders(Borders::ALL)
-        .title("MOVES")
-        .style(Style::default().bg(BG_PANEL_ALT).fg(TEXT_MAIN));
+        .title(format!(
+            "MOVES ({filter_label}, by {}) {page}/{pages}",
+            state.move_sort.label().to_lowercase()
+        ))
+        .style(Style::default().bg(theme().panel_alt).fg(theme().text));
     let list_inner = list_block.inner(layout[0]);
     frame.render_widget(list_block, layout[0]);
 
-    let items = move_items(state);
+    // Leave the scrollbar its column.
+    let columns = move_columns(list_inner.width.saturating_sub(1));
+    let items = move_items(state, columns);
+    let table = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Length(1), Constraint::Min(1)])
+        .split(list_inner);
+    if !items.is_empty() {
+        frame.render_widget(Paragraph::new(move_header(columns)), table[0]);
+    }
+    let list_inner = table[1];
     if items.is_empty() {
         frame.render_widget(
             Paragraph::new("No moves.")
-                .style(Style::default().fg(TEXT_DIM))
+                .style(Style::default().fg(theme().text_dim))
                 .wrap(Wrap { trim: true }),
             list_inner,
         );
@@ -1183,13 +4463,13 @@ fn render_moves_tab(frame: &mut Frame, area: Rect, state: &AppState, move_list:
     let detail_block = Block::default()
         .borders(Borders::ALL)
         .title("MOVE DETAIL")
-        .style(Style::default().bg(BG_PANEL_ALT).fg(TEXT_MAIN));
+        .style(Style::default().bg(theme().panel_alt).fg(theme().text));
     let detail_inner = detail_block.inner(layout[1]);
     frame.render_widget(detail_block, layout[1]);
     let detail_text = move_detail_text(state);
     frame.render_widget(
         Paragraph::new(detail_text)
-            .style(Style::default().fg(TEXT_MAIN))
+            .style(Style::default().fg(theme().text))
             .wrap(Wrap { trim: true }),
         detail_inner,
     );
@@ -1208,7 +4488,7 @@ fn render_abilities_tab(
     let list_block = Block::default()
         .borders(Borders::ALL)
         .title("ABILITIES")
-        .style(Style::default().bg(BG_PANEL_ALT).fg(TEXT_MAIN));
+        .style(Style::default().bg(theme().panel_alt).fg(theme().text));
     let list_inner = list_block.inner(layout[0]);
     frame.render_widget(list_block, layout[0]);
 
@@ -1216,7 +4496,7 @@ fn render_abilities_tab(
     if items.is_empty() {
         frame.render_widget(
             Paragraph::new("No abilities.")
-                .style(Style::default().fg(TEXT_DIM))
+                .style(Style::default().fg(theme().text_dim))
                 .wrap(Wrap { trim: true }),
             list_inner,
         );
@@ -1242,13 +4522,13 @@ fn render_abilities_tab(
     let detail_block = Block::default()
         .borders(Borders::ALL)
         .title("ABILITY DETAIL")
-        .style(Style::default().bg(BG_PANEL_ALT).fg(TEXT_MAIN));
+        .style(Style::default().bg(theme().panel_alt).fg(theme().text));
     let detail_inner = detail_block.inner(layout[1]);
     frame.render_widget(detail_block, layout[1]);
     let detail_text = ability_detail_only_text(state);
     frame.render_widget(
         Paragraph::new(detail_text)
-            .style(Style::default().fg(TEXT_MAIN))
+            .style(Style::default().fg(theme().text))
             .wrap(Wrap { trim: true }),
         detail_inner,
     );
@@ -1272,7 +4552,7 @@ fn render_encounters_tab(
     let list_block = Block::default()
         .borders(Borders::ALL)
         .title(format!("LOCATIONS ({filter_label})"))
-        .style(Style::default().bg(BG_PANEL_ALT).fg(TEXT_MAIN));
+        .style(Style::default().bg(theme().panel_alt).fg(theme().text));
     let list_inner = list_block.inner(layout[0]);
     frame.render_widget(list_block, layout[0]);
 
@@ -1285,7 +4565,7 @@ fn render_encounters_tab(
         };
         frame.render_widget(
             Paragraph::new(message)
-                .style(Style::default().fg(TEXT_DIM))
+                .style(Style::default().fg(theme().text_dim))
                 .wrap(Wrap { trim: true }),
             list_inner,
         );
@@ -1311,7 +4591,7 @@ fn render_encounters_tab(
     let detail_block = Block::default()
         .borders(Borders::ALL)
         .title("ENCOUNTERS")
-        .style(Style::default().bg(BG_PANEL_ALT).fg(TEXT_MAIN));
+        .style(Style::default().bg(theme().panel_alt).fg(theme().text));
     let detail_inner = detail_block.inner(layout[1]);
     frame.render_widget(detail_block, layout[1]);
     let detail_layout = Layout::default()
@@ -1321,20 +4601,24 @@ fn render_encounters_tab(
     let version_text = encounter_version_text(state);
     frame.render_widget(
         Paragraph::new(version_text)
-            .style(Style::default().fg(TEXT_MAIN))
+            .style(Style::default().fg(theme().text))
             .wrap(Wrap { trim: true }),
         detail_layout[0],
     );
-    let detail_text = encounter_detail_text(state);
+    let detail_text = encounter_detail_text(state, detail_layout[1].width);
     frame.render_widget(
         Paragraph::new(detail_text)
-            .style(Style::default().fg(TEXT_MAIN))
-            .wrap(Wrap { trim: true }),
+            .style(Style::default().fg(theme().text))
+            .wrap(Wrap { trim: false }),
         detail_layout[1],
     );
 }
 
 fn render_matchup_tab(frame: &mut Frame, area: Rect, state: &AppState) {
+    if state.matchup_view == crate::state::MatchupView::Team {
+        render_team_matchup(frame, area, state);
+        return;
+    }
     let layout = Layout::default()
         .direction(Direction::Horizontal)
         .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
@@ -1342,11 +4626,11 @@ fn render_matchup_tab(frame: &mut Frame, area: Rect, state: &AppState) {
     let defense_block = Block::default()
         .borders(Borders::ALL)
         .title("DEFENSE")
-        .style(Style::default().bg(BG_PANEL_ALT).fg(TEXT_MAIN));
+        .style(Style::default().bg(theme().panel_alt).fg(theme().text));
     let offense_block = Block::default()
         .borders(Borders::ALL)
         .title("OFFENSE")
-        .style(Style::default().bg(BG_PANEL_ALT).fg(TEXT_MAIN));
+        .style(Style::default().bg(theme().panel_alt).fg(theme().text));
     let defense_inner = defense_block.inner(layout[0]);
     let offense_inner = offense_block.inner(layout[1]);
     frame.render_widget(defense_block, layout[0]);
@@ -1355,18 +4639,109 @@ fn render_matchup_tab(frame: &mut Frame, area: Rect, state: &AppState) {
     let (defense_text, offense_text) = matchup_texts(state);
     frame.render_widget(
         Paragraph::new(defense_text)
-            .style(Style::default().fg(TEXT_MAIN))
+            .style(Style::default().fg(theme().text))
             .wrap(Wrap { trim: true }),
         defense_inner,
     );
     frame.render_widget(
         Paragraph::new(offense_text)
-            .style(Style::default().fg(TEXT_MAIN))
+            .style(Style::default().fg(theme().text))
+            .wrap(Wrap { trim: true }),
+        offense_inner,
+    );
+}
+
+fn render_team_matchup(frame: &mut Frame, area: Rect, state: &AppState) {
+    let layout = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(area);
+    let weak_block = Block::default()
+        .borders(Borders::ALL)
+        .title("TEAM WEAKNESSES")
+        .style(Style::default().bg(theme().panel_alt).fg(theme().text));
+    let offense_block = Block::default()
+        .borders(Borders::ALL)
+        .title("TEAM OFFENSE")
+        .style(Style::default().bg(theme().panel_alt).fg(theme().text));
+    let weak_inner = weak_block.inner(layout[0]);
+    let offense_inner = offense_block.inner(layout[1]);
+    frame.render_widget(weak_block, layout[0]);
+    frame.render_widget(offense_block, layout[1]);
+
+    let (weak_text, offense_text) = match &state.team_coverage {
+        Some(coverage) => team_matchup_texts(coverage),
+        None => {
+            let message = team_coverage_pending(state);
+            (Text::from(message), Text::from(message))
+        }
+    };
+    frame.render_widget(
+        Paragraph::new(weak_text)
+            .style(Style::default().fg(theme().text))
+            .wrap(Wrap { trim: true }),
+        weak_inner,
+    );
+    frame.render_widget(
+        Paragraph::new(offense_text)
+            .style(Style::default().fg(theme().text))
             .wrap(Wrap { trim: true }),
         offense_inner,
     );
 }
 
+fn team_matchup_texts(coverage: &TeamCoverage) -> (Text<'static>, Text<'static>) {
+    let heading = Style::default()
+        .fg(theme().accent)
+        .add_modifier(Modifier::BOLD);
+    let members = coverage.members.len();
+
+    let weaknesses = coverage.weaknesses();
+    let mut weak_lines = vec![Line::from(Span::styled(
+        format!("Weak / resist of {members}"),
+        heading,
+    ))];
+    if weaknesses.is_empty() {
+        weak_lines.push(Line::from("No net weaknesses."));
+    }
+    for exposure in weaknesses {
+        let color = if exposure.weak >= 2 {
+            theme().gold
+        } else {
+            theme().text
+        };
+        weak_lines.push(Line::from(vec![
+            Span::styled(
+                format!("{:<10}", format_name(&exposure.attacker)),
+                Style::default().fg(color),
+            ),
+            Span::raw(format!(
+                " {} / {}",
+                exposure.weak,
+                exposure.resist + exposure.immune
+            )),
+        ]));
+    }
+
+    let gaps = coverage
+        .gaps()
+        .into_iter()
+        .map(format_name)
+        .collect::<Vec<_>>();
+    let offense_lines = vec![
+        Line::from(Span::styled("Super effective", heading)),
+        Line::from(format!(
+            "{}/{} types",
+            coverage.covered(),
+            coverage.offense.len()
+        )),
+        Line::from(" "),
+        Line::from(Span::styled("Gaps", heading)),
+        Line::from(or_none(gaps)),
+    ];
+    (Text::from(weak_lines), Text::from(offense_lines))
+}
+
 fn move_detail_text(state: &AppState) -> Text<'static> {
     let Some(name) = state.current_move_name() else {
         return Text::from("No move selected.");
@@ -1414,7 +4789,7 @@ fn ability_detail_only_text(state: &AppState) -> Text<'static> {
     ])
 }
 
-fn encounter_detail_text(state: &AppState) -> Text<'static> {
+fn encounter_detail_text(state: &AppState, width: u16) -> Text<'static> {
     let Some(name) = state.detail_name.as_ref() else {
         return Text::from("Select a Pokemon.");
     };
@@ -1443,38 +4818,119 @@ fn encounter_detail_text(state: &AppState) -> Text<'static> {
             format_name(&active_version.version),
             active_version.max_chance
         ),
-        Style::default().fg(ACCENT_TEAL).add_modifier(Modifier::BOLD),
+        Style::default().fg(theme().accent).add_modifier(Modifier::BOLD),
     ))];
     if active_version.encounters.is_empty() {
         lines.push(Line::from("No encounters."));
         return Text::from(lines);
     }
-    for detail in &active_version.encounters {
-        let level = if detail.min_level == detail.max_level {
-            format!("Lv{}", detail.min_level)
-        } else {
-            format!("Lv{}-{}", detail.min_level, detail.max_level)
-        };
-        let mut line = format!(
-            "{} {}% {}",
-            level,
-            detail.chance,
-            format_name(&detail.method)
-        );
-        if !detail.conditions.is_empty() {
+    lines.push(Line::from(" "));
+    lines.extend(encounter_grid_lines(&active_version.grid(), width));
+
+    let conditional: Vec<_> = active_version
+        .encounters
+        .iter()
+        .filter(|detail| !detail.conditions.is_empty())
+        .collect();
+    if !conditional.is_empty() {
+        lines.push(Line::from(" "));
+        for detail in conditional {
             let conditions = detail
                 .conditions
                 .iter()
                 .map(|condition| format_name(condition))
                 .collect::<Vec<_>>()
                 .join(", ");
-            line.push_str(&format!(" [{}]", conditions));
+            lines.push(Line::from(Span::styled(
+                format!(
+                    "{} {} {}%: {}",
+                    format_name(&detail.method),
+                    encounter_level_label(detail.min_level, detail.max_level),
+                    detail.chance,
+                    conditions
+                ),
+                Style::default().fg(theme().text_dim),
+            )));
         }
-        lines.push(Line::from(line));
     }
     Text::from(lines)
 }
 
+const ENCOUNTER_METHOD_WIDTH: usize = 10;
+const ENCOUNTER_CELL_WIDTH: usize = 7;
+
+/// Method rows by level columns, each cell shaded by its chance. Columns that
+/// don't fit in `width` are counted below the grid.
+fn encounter_grid_lines(grid: &EncounterGrid, width: u16) -> Vec<Line<'static>> {
+    let fit = (width as usize).saturating_sub(ENCOUNTER_METHOD_WIDTH) / ENCOUNTER_CELL_WIDTH;
+    let shown = grid.levels.len().min(fit.max(1));
+
+    let mut header = vec![Span::styled(
+        format!("{:<ENCOUNTER_METHOD_WIDTH$}", "Lv"),
+        Style::default().fg(theme().text_dim),
+    )];
+    for &(min, max) in &grid.levels[..shown] {
+        let label = encounter_level_label(min, max);
+        let label = label.trim_start_matches("Lv");
+        header.push(Span::styled(
+            format!("{label:^ENCOUNTER_CELL_WIDTH$}"),
+            Style::default()
+                .fg(theme().accent)
+                .add_modifier(Modifier::BOLD),
+        ));
+    }
+    let mut lines = vec![Line::from(header)];
+
+    for (method, cells) in grid.methods.iter().zip(&grid.cells) {
+        let mut name = format_name(method);
+        name.truncate(ENCOUNTER_METHOD_WIDTH - 1);
+        let mut spans = vec![Span::raw(format!("{name:<ENCOUNTER_METHOD_WIDTH$}"))];
+        for cell in &cells[..shown] {
+            spans.push(match cell {
+                Some(chance) => {
+                    let label = format!("{chance}%");
+                    Span::styled(
+                        format!("{label:^ENCOUNTER_CELL_WIDTH$}"),
+                        chance_heat(*chance),
+                    )
+                }
+                None => Span::styled(
+                    format!("{:^ENCOUNTER_CELL_WIDTH$}", "·"),
+                    Style::default().fg(theme().text_dim),
+                ),
+            });
+        }
+        lines.push(Line::from(spans));
+    }
+
+    if shown < grid.levels.len() {
+        lines.push(Line::from(Span::styled(
+            format!("+{} more level ranges", grid.levels.len() - shown),
+            Style::default().fg(theme().text_dim),
+        )));
+    }
+    lines
+}
+
+/// Cool for rare slots, hot for common ones.
+fn chance_heat(chance: u8) -> Style {
+    let (bg, fg) = match chance {
+        0..=10 => (theme().highlight, theme().text),
+        11..=25 => (theme().accent, theme().bg),
+        26..=50 => (theme().gold, theme().bg),
+        _ => (theme().danger, theme().bg),
+    };
+    Style::default().bg(bg).fg(fg).add_modifier(Modifier::BOLD)
+}
+
+fn encounter_level_label(min: u8, max: u8) -> String {
+    if min == max {
+        format!("Lv{min}")
+    } else {
+        format!("Lv{min}-{max}")
+    }
+}
+
 fn encounter_version_text(state: &AppState) -> Text<'static> {
     let Some(name) = state.detail_name.as_ref() else {
         return Text::from("Select a Pokemon.");
@@ -1513,9 +4969,9 @@ fn encounter_version_text(state: &AppState) -> Text<'static> {
             version.max_chance
         );
         let style = if active_version == Some(version.version.as_str()) {
-            Style::default().fg(ACCENT_TEAL).add_modifier(Modifier::BOLD)
+            Style::default().fg(theme().accent).add_modifier(Modifier::BOLD)
         } else {
-            Style::default().fg(TEXT_MAIN)
+            Style::default().fg(theme().text)
         };
         lines.push(Line::from(Span::styled(label, style)));
     }
@@ -1547,8 +5003,8 @@ fn matchup_texts(state: &AppState) -> (Text<'static>, Text<'static>) {
     if detail.types.is_empty() {
         return (Text::from("No type data."), Text::from("No type data."));
     }
-    let defense = defense_multipliers(state, &detail.types);
-    let offense = offense_multipliers(state, &detail.types);
+    let defense = coverage::defense_multipliers(state, &detail.types);
+    let offense = coverage::offense_multipliers(state, &detail.types);
     let Some(defense) = defense else {
         return (
             Text::from("Type data unavailable."),
@@ -1583,81 +5039,6 @@ fn matchup_texts(state: &AppState) -> (Text<'static>, Text<'static>) {
     )
 }
 
-fn defense_multipliers(
-    state: &AppState,
-    types: &[String],
-) -> Option<HashMap<String, f32>> {
-    if state.type_list.is_empty() {
-        return None;
-    }
-    let mut multipliers: HashMap<String, f32> = state
-        .type_list
-        .iter()
-        .map(|name| (name.clone(), 1.0))
-        .collect();
-    for type_name in types {
-        let matchup = state.type_matchup_cache.get(type_name)?;
-        apply_multiplier(&mut multipliers, &matchup.double_from, 2.0);
-        apply_multiplier(&mut multipliers, &matchup.half_from, 0.5);
-        apply_immunity(&mut multipliers, &matchup.no_from);
-    }
-    Some(multipliers)
-}
-
-fn offense_multipliers(
-    state: &AppState,
-    types: &[String],
-) -> Option<HashMap<String, f32>> {
-    if state.type_list.is_empty() {
-        return None;
-    }
-    let mut multipliers: HashMap<String, f32> = state
-        .type_list
-        .iter()
-        .map(|name| (name.clone(), 1.0))
-        .collect();
-    for type_name in types {
-        let matchup = state.type_matchup_cache.get(type_name)?;
-        let mut type_map: HashMap<String, f32> = state
-            .type_list
-            .iter()
-            .map(|name| (name.clone(), 1.0))
-            .collect();
-        apply_multiplier(&mut type_map, &matchup.double_to, 2.0);
-        apply_multiplier(&mut type_map, &matchup.half_to, 0.5);
-        apply_immunity(&mut type_map, &matchup.no_to);
-        for (name, value) in type_map {
-            let entry = multipliers.entry(name).or_insert(1.0);
-            if value > *entry {
-                *entry = value;
-            }
-        }
-    }
-    Some(multipliers)
-}
-
-fn apply_multiplier(
-    multipliers: &mut HashMap<String, f32>,
-    types: &[String],
-    factor: f32,
-) {
-    for type_name in types {
-        if let Some(value) = multipliers.get_mut(type_name) {
-            if *value != 0.0 {
-                *value *= factor;
-            }
-        }
-    }
-}
-
-fn apply_immunity(multipliers: &mut HashMap<String, f32>, types: &[String]) {
-    for type_name in types {
-        if let Some(value) = multipliers.get_mut(type_name) {
-            *value = 0.0;
-        }
-    }
-}
-
 fn matchup_section_text(
     multipliers: &HashMap<String, f32>,
     sections: &[(f32, &'static str)],
@@ -1675,7 +5056,7 @@ fn matchup_section_text(
         names.sort();
         lines.push(Line::from(Span::styled(
             *label,
-            Style::default().fg(ACCENT_TEAL).add_modifier(Modifier::BOLD),
+            Style::default().fg(theme().accent).add_modifier(Modifier::BOLD),
         )));
         lines.push(Line::from(names.join(", ")));
         lines.push(Line::from(" "));
@@ -1731,7 +5112,7 @@ fn shorten_stat(name: &str) -> String {
 fn route_label(state: &AppState) -> (usize, usize) {
     let page_size = list_page_size(state).max(1);
     let route_index = state.selected_index / page_size + 1;
-    let total_pages = (state.filtered_indices.len() + page_size - 1) / page_size;
+    let total_pages = state.dex_rows.len().div_ceil(page_size);
     (route_index, total_pages.max(1))
 }
 
@@ -1746,7 +5127,11 @@ fn region_counts(state: &AppState) -> (usize, usize, usize) {
         .iter()
         .filter(|entry| state.favorites.contains(&entry.name))
         .count();
-    let total = state.pokedex_all.len();
+    let total = state
+        .dex_paging
+        .as_ref()
+        .and_then(|paging| paging.total)
+        .unwrap_or(state.pokedex_all.len());
     (seen, caught, total)
 }
 
@@ -1754,17 +5139,27 @@ fn list_page_size(state: &AppState) -> usize {
     state.terminal_size.1.saturating_sub(8) as usize
 }
 
-fn focus_border(state: &AppState, area: crate::state::FocusArea) -> Style {
-    if state.focus == area {
+fn pane_border(active: bool) -> Style {
+    if active {
         Style::default()
-            .fg(ACCENT_TEAL)
+            .fg(theme().accent)
             .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(TEXT_DIM)
+        Style::default().fg(theme().text_dim)
+    }
+}
+
+fn focus_border(state: &AppState, area: crate::state::FocusArea) -> Style {
+    let focused = state.focus == area;
+    let style = Style::default().fg(theme().focus_border(focused));
+    if focused {
+        style.add_modifier(Modifier::BOLD)
+    } else {
+        style
     }
 }
 
-fn sprite_fit(sprite: &sprite::SpriteData, max_cols: u16, max_rows: u16) -> (u16, u16) {
+fn sprite_fit(sprite: &SpriteData, max_cols: u16, max_rows: u16) -> (u16, u16) {
     if max_cols == 0 || max_rows == 0 || sprite.height == 0 {
         return (max_cols, max_rows);
     }
@@ -0,0 +1,64 @@
+use std::collections::{BTreeSet, HashSet};
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+use tokio::fs;
+
+/// Favorites, team and bookmarks as stored on disk.
+#[derive(Clone, Debug, Default, Deserialize, Serialize)]
+struct UserData {
+    #[serde(default)]
+    favorites: BTreeSet<String>,
+    #[serde(default)]
+    team: Vec<String>,
+    #[serde(default)]
+    bookmarks: Vec<String>,
+}
+
+pub async fn load_user_data() -> Result<(HashSet<String>, Vec<String>, Vec<String>), String> {
+    let bytes = match fs::read(user_data_path()).await {
+        Ok(bytes) => bytes,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => {
+            return Ok((HashSet::new(), Vec::new(), Vec::new()))
+        }
+        Err(err) => return Err(err.to_string()),
+    };
+    let data: UserData = serde_json::from_slice(&bytes).map_err(|err| err.to_string())?;
+    Ok((
+        data.favorites.into_iter().collect(),
+        data.team,
+        data.bookmarks,
+    ))
+}
+
+pub async fn save_user_data(
+    favorites: HashSet<String>,
+    team: Vec<String>,
+    bookmarks: Vec<String>,
+) -> Result<(), String> {
+    let data = UserData {
+        favorites: favorites.into_iter().collect(),
+        team,
+        bookmarks,
+    };
+    let bytes = serde_json::to_vec_pretty(&data).map_err(|err| err.to_string())?;
+    let path = user_data_path();
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)
+            .await
+            .map_err(|err| err.to_string())?;
+    }
+    let tmp = path.with_extension("json.tmp");
+    fs::write(&tmp, bytes)
+        .await
+        .map_err(|err| err.to_string())?;
+    fs::rename(&tmp, &path).await.map_err(|err| err.to_string())
+}
+
+fn user_data_path() -> PathBuf {
+    let base = std::env::var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|_| std::env::var("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .unwrap_or_else(|_| PathBuf::from("."));
+    base.join("pokeapi-tui").join("user_data.json")
+}
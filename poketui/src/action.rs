@@ -1,8 +1,9 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
+use sprite_cache::SpriteData;
 
+use crate::gamepad::PadButton;
 use crate::scenario::ScenarioRuntime;
-use crate::sprite::SpriteData;
 use crate::state::{AppState, Direction, PokemonInfo, SpriteTarget};
 
 #[derive(tui_dispatch::Action, Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -18,16 +19,21 @@ pub enum Action {
     BattleMenuPrev,
     BattleConfirm,
     BattleItemCancel,
+    BattleMoveCancel,
     MessageNext,
 
     // Main menu actions
     MenuSelect(usize),
     MenuConfirm,
-    SaveExists(bool),
+    SaveExists {
+        exists: bool,
+        hardcore: bool,
+    },
 
     // Pokemon selection actions
     StarterSelect(usize),
     StarterConfirm,
+    StarterToggleHardcore,
     StarterPreviewLoaded {
         info: PokemonInfo,
     },
@@ -44,6 +50,71 @@ pub enum Action {
     PauseSelect(usize),
     PauseConfirm,
 
+    // Options screen
+    OptionsOpen,
+    OptionsClose,
+    OptionsMove(i16),
+    OptionsAdjust(i16),
+
+    // Gamepad
+    /// A pad button press (or d-pad repeat), routed like the matching key.
+    GamepadButton(PadButton),
+
+    // Battle replay
+    ReplayStart,
+    ReplayStop,
+
+    // Party screen
+    PartyOpen,
+    PartyClose,
+    PartyMove(i16),
+    PartySetLead,
+    PartyToggleMoving,
+    PartyRename,
+    PartyNameInput(char),
+    PartyNameBackspace,
+    PartyNameConfirm,
+    PartyNameCancel,
+
+    // PC box actions
+    BoxOpen,
+    BoxClose,
+    BoxMove(i16),
+    BoxSwitchPane,
+    BoxTransfer,
+    BoxRelease,
+
+    // Shiny hunting stats page
+    StatsOpen,
+    StatsClose,
+
+    // NPC dialogue and quests
+    Talk,
+    DialogueSelect(usize),
+    DialogueConfirm,
+    DialogueClose,
+    QuestLogOpen,
+    QuestLogClose,
+
+    // Fishing
+    FishReel,
+    FishCancel,
+
+    // Shop
+    ShopMove(i16),
+    ShopSwitchTab,
+    ShopConfirm,
+    ShopClose,
+
+    // Evolution prompt
+    EvolutionConfirm,
+    EvolutionCancel,
+    EvolutionDidLoad {
+        member_index: usize,
+        info: PokemonInfo,
+    },
+    EvolutionDidError(String),
+
     // Save/Load actions
     SaveGame,
     SaveComplete,
@@ -51,10 +122,18 @@ pub enum Action {
     LoadGame,
     LoadComplete(Box<AppState>),
     LoadError(String),
+    SaveDeleted,
+    SaveDeleteError(String),
+    SettingsSaved,
+    SettingsSaveError(String),
+
+    // Speedrun
+    SplitsExported(String),
+    SplitsExportError(String),
 
     // Scenario loading
     ScenarioLoaded {
-        scenario: ScenarioRuntime,
+        scenario: Box<ScenarioRuntime>,
     },
     ScenarioLoadError {
         error: String,
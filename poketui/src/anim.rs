@@ -0,0 +1,177 @@
+//! Battle hit animations. Every hit queues a [`HitAnim`] on the battle, and
+//! `Tick` plays them one at a time: the HP bar drains from the old value to
+//! the new one, the Pokemon hit shakes, and heavy hits flash the screen. The
+//! battle's HP fields are always the real values; the ui draws [`shown_hp`].
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::moves::Effectiveness;
+use crate::state::BattleState;
+
+// Animation tuning, in ticks of `TICK_MS`:
+// - DRAIN_TICKS: how long a bar takes to reach its new value.
+// - SHAKE_TICKS: how long the Pokemon hit shakes.
+// - FLASH_TICKS: how long a heavy hit flashes the screen.
+// - HEAVY_HIT_PERCENT: share of max HP one hit has to take to flash.
+pub const DRAIN_TICKS: u8 = 5;
+const SHAKE_TICKS: u8 = 3;
+const FLASH_TICKS: u8 = 2;
+const HEAVY_HIT_PERCENT: u32 = 50;
+
+/// How hit animations play, picked on the options screen.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum AnimSpeed {
+    #[default]
+    Normal,
+    /// Two animation ticks per game tick.
+    Fast,
+    /// The queue is dropped on the next tick, so bars jump to their new value.
+    Off,
+}
+
+impl AnimSpeed {
+    const ALL: [AnimSpeed; 3] = [AnimSpeed::Normal, AnimSpeed::Fast, AnimSpeed::Off];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            AnimSpeed::Normal => "Normal",
+            AnimSpeed::Fast => "Fast",
+            AnimSpeed::Off => "Off",
+        }
+    }
+
+    /// The speed `step` places along `ALL`, wrapping around.
+    pub fn cycle(self, step: i16) -> Self {
+        let len = Self::ALL.len() as i16;
+        let index = Self::ALL
+            .iter()
+            .position(|speed| *speed == self)
+            .unwrap_or(0) as i16;
+        Self::ALL[(index + step).rem_euclid(len) as usize]
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum Side {
+    Player,
+    Enemy,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct HitAnim {
+    pub side: Side,
+    pub from_hp: u16,
+    pub to_hp: u16,
+    /// Max HP of the Pokemon hit, which a faint-and-switch changes before
+    /// the drain has played.
+    pub hp_max: u16,
+    /// Super effective, or at least half the Pokemon's max HP in one go.
+    pub heavy: bool,
+    /// Ticks played so far.
+    pub elapsed: u8,
+}
+
+impl HitAnim {
+    /// A hit, or a heal when `to_hp` is above `from_hp`.
+    pub fn new(
+        side: Side,
+        from_hp: u16,
+        to_hp: u16,
+        hp_max: u16,
+        effectiveness: Effectiveness,
+    ) -> Self {
+        let lost = from_hp.saturating_sub(to_hp) as u32;
+        let heavy = lost > 0
+            && (effectiveness == Effectiveness::Super
+                || lost * 100 >= hp_max.max(1) as u32 * HEAVY_HIT_PERCENT);
+        Self {
+            side,
+            from_hp,
+            to_hp,
+            hp_max,
+            heavy,
+            elapsed: 0,
+        }
+    }
+
+    fn shaking(&self) -> bool {
+        self.to_hp < self.from_hp && self.elapsed < SHAKE_TICKS
+    }
+}
+
+/// Queues `anim` behind whatever is still playing. Misses and other hits that
+/// leave HP where it was have nothing to show.
+pub fn push(battle: &mut BattleState, anim: HitAnim) {
+    if anim.from_hp != anim.to_hp {
+        battle.anims.push_back(anim);
+    }
+}
+
+/// Plays a game tick's worth of animation at `speed`. Returns whether
+/// anything moved.
+pub fn advance(battle: &mut BattleState, speed: AnimSpeed) -> bool {
+    match speed {
+        AnimSpeed::Normal => step(battle),
+        AnimSpeed::Fast => step(battle) | step(battle),
+        AnimSpeed::Off => {
+            let playing = is_playing(battle);
+            battle.anims.clear();
+            playing
+        }
+    }
+}
+
+/// One tick of the front animation.
+fn step(battle: &mut BattleState) -> bool {
+    let Some(front) = battle.anims.front_mut() else {
+        return false;
+    };
+    front.elapsed += 1;
+    if front.elapsed >= DRAIN_TICKS {
+        battle.anims.pop_front();
+    }
+    true
+}
+
+pub fn is_playing(battle: &BattleState) -> bool {
+    !battle.anims.is_empty()
+}
+
+/// HP and max HP the bar for `side` shows: partway through the drain while
+/// its animation plays, where the next one starts while it waits its turn,
+/// and the real values otherwise.
+pub fn shown_hp(battle: &BattleState, side: Side) -> (u16, u16) {
+    let mut queued = battle.anims.iter().filter(|anim| anim.side == side);
+    let Some(anim) = queued.next() else {
+        return match side {
+            Side::Player => (battle.player_hp, battle.player_hp_max),
+            Side::Enemy => (battle.enemy_hp, battle.enemy_hp_max),
+        };
+    };
+    let (from, to) = (anim.from_hp as i32, anim.to_hp as i32);
+    let hp = from + (to - from) * anim.elapsed as i32 / DRAIN_TICKS as i32;
+    (hp.max(0) as u16, anim.hp_max)
+}
+
+/// Columns to nudge `side`'s sprite by: alternately right and left while it
+/// shakes, otherwise nothing.
+pub fn shake_offset(battle: &BattleState, side: Side) -> i16 {
+    match battle.anims.front() {
+        Some(anim) if anim.side == side && anim.shaking() => {
+            if anim.elapsed.is_multiple_of(2) {
+                1
+            } else {
+                -1
+            }
+        }
+        _ => 0,
+    }
+}
+
+pub fn flashing(battle: &BattleState) -> bool {
+    battle
+        .anims
+        .front()
+        .is_some_and(|anim| anim.heavy && anim.elapsed < FLASH_TICKS)
+}
@@ -1,11 +1,10 @@
-use std::path::{Path, PathBuf};
 use std::sync::OnceLock;
 
 use serde::Deserialize;
-use sha2::{Digest, Sha256};
-use tokio::fs;
+use sprite_cache::DiskCache;
 
-use crate::state::PokemonInfo;
+use crate::moves::MoveInfo;
+use crate::state::{Evolution, PokemonInfo, MAX_LEVEL};
 
 const API_BASE: &str = "https://pokeapi.co/api/v2";
 
@@ -20,6 +19,11 @@ struct PokemonResponse {
     base_experience: Option<u16>,
     stats: Vec<PokemonStatSlot>,
     sprites: serde_json::Value,
+    #[serde(default)]
+    types: Vec<PokemonTypeSlot>,
+    #[serde(default)]
+    moves: Vec<PokemonMoveSlot>,
+    species: NamedResource,
 }
 
 #[derive(Clone, Debug, Deserialize)]
@@ -28,6 +32,64 @@ struct PokemonStatSlot {
     stat: NamedResource,
 }
 
+#[derive(Clone, Debug, Deserialize)]
+struct PokemonTypeSlot {
+    #[serde(rename = "type")]
+    kind: NamedResource,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct PokemonMoveSlot {
+    #[serde(rename = "move")]
+    move_ref: NamedResource,
+    version_group_details: Vec<MoveLearnDetail>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct MoveLearnDetail {
+    level_learned_at: u8,
+    move_learn_method: NamedResource,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct MoveResponse {
+    name: String,
+    power: Option<u16>,
+    pp: Option<u8>,
+    #[serde(rename = "type")]
+    kind: NamedResource,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct SpeciesResponse {
+    evolution_chain: Option<ResourceUrl>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct ResourceUrl {
+    url: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct EvolutionChainResponse {
+    chain: ChainLink,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct ChainLink {
+    species: NamedResource,
+    #[serde(default)]
+    evolution_details: Vec<EvolutionDetail>,
+    #[serde(default)]
+    evolves_to: Vec<ChainLink>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+struct EvolutionDetail {
+    min_level: Option<u8>,
+    trigger: NamedResource,
+}
+
 pub async fn fetch_pokemon(name: &str) -> Result<PokemonInfo, String> {
     let url = format!("{API_BASE}/pokemon/{name}");
     let response: PokemonResponse = fetch_json_cached(&url).await?;
@@ -41,6 +103,9 @@ pub async fn fetch_pokemon(name: &str) -> Result<PokemonInfo, String> {
             .unwrap_or(35)
     };
 
+    let learnset = fetch_learnset(&response.moves).await;
+    let evolution = fetch_evolution(&response.species.name).await;
+
     Ok(PokemonInfo {
         name: response.name,
         base_experience: response.base_experience.unwrap_or(60),
@@ -60,9 +125,86 @@ pub async fn fetch_pokemon(name: &str) -> Result<PokemonInfo, String> {
             &response.sprites,
             "/versions/generation-v/black-white/animated/back_default",
         ),
+        types: response
+            .types
+            .into_iter()
+            .map(|slot| slot.kind.name)
+            .collect(),
+        learnset,
+        evolution,
     })
 }
 
+/// The level-up evolution of `species`, if it has one. Evolutions by stone,
+/// trade or friendship are skipped, and so is a species whose chain fails to
+/// load.
+async fn fetch_evolution(species: &str) -> Option<Evolution> {
+    let url = format!("{API_BASE}/pokemon-species/{species}");
+    let response: SpeciesResponse = fetch_json_cached(&url).await.ok()?;
+    let chain: EvolutionChainResponse = fetch_json_cached(&response.evolution_chain?.url)
+        .await
+        .ok()?;
+    level_up_evolution(&chain.chain, species)
+}
+
+fn level_up_evolution(link: &ChainLink, species: &str) -> Option<Evolution> {
+    if link.species.name != species {
+        return link
+            .evolves_to
+            .iter()
+            .find_map(|next| level_up_evolution(next, species));
+    }
+    link.evolves_to.iter().find_map(|next| {
+        let level = next
+            .evolution_details
+            .iter()
+            .filter(|detail| detail.trigger.name == "level-up")
+            .find_map(|detail| detail.min_level)?;
+        Some(Evolution {
+            into: next.species.name.clone(),
+            level: level.min(MAX_LEVEL),
+        })
+    })
+}
+
+/// Damaging moves the species learns by levelling up, lowest level first.
+/// Moves whose details fail to load are left out rather than failing the
+/// whole Pokemon.
+async fn fetch_learnset(slots: &[PokemonMoveSlot]) -> Vec<MoveInfo> {
+    let mut tasks = tokio::task::JoinSet::new();
+    for slot in slots {
+        let level = slot
+            .version_group_details
+            .iter()
+            .filter(|detail| detail.move_learn_method.name == "level-up")
+            .map(|detail| detail.level_learned_at.max(1))
+            .min();
+        let Some(level) = level.filter(|&level| level <= MAX_LEVEL) else {
+            continue;
+        };
+        let url = format!("{API_BASE}/move/{}", slot.move_ref.name);
+        tasks.spawn(async move {
+            let response: MoveResponse = fetch_json_cached(&url).await.ok()?;
+            let power = response.power.filter(|&power| power > 0)?;
+            Some(MoveInfo {
+                name: response.name,
+                move_type: response.kind.name,
+                power,
+                pp: response.pp.unwrap_or(10).max(1),
+                level,
+            })
+        });
+    }
+    let mut learnset = Vec::new();
+    while let Some(result) = tasks.join_next().await {
+        if let Ok(Some(info)) = result {
+            learnset.push(info);
+        }
+    }
+    learnset.sort_by(|a, b| a.level.cmp(&b.level).then_with(|| a.name.cmp(&b.name)));
+    learnset
+}
+
 pub async fn fetch_bytes(url: &str) -> Result<Vec<u8>, String> {
     fetch_bytes_cached(url).await
 }
@@ -79,16 +221,15 @@ async fn fetch_json_cached<T: serde::de::DeserializeOwned>(url: &str) -> Result<
     match serde_json::from_slice(&bytes) {
         Ok(value) => Ok(value),
         Err(err) => {
-            let cache_path = cache_path("http", url);
-            let _ = fs::remove_file(&cache_path).await;
+            disk_cache().remove("http", url).await;
             Err(err.to_string())
         }
     }
 }
 
 async fn fetch_bytes_cached(url: &str) -> Result<Vec<u8>, String> {
-    let cache_path = cache_path("http", url);
-    if let Some(bytes) = read_cache(&cache_path).await {
+    let cache = disk_cache();
+    if let Some(bytes) = cache.read("http", url).await {
         return Ok(bytes);
     }
 
@@ -104,7 +245,7 @@ async fn fetch_bytes_cached(url: &str) -> Result<Vec<u8>, String> {
         .await
         .map_err(|err| err.to_string())?
         .to_vec();
-    write_cache(&cache_path, &bytes).await;
+    cache.write("http", url, &bytes).await;
     Ok(bytes)
 }
 
@@ -113,30 +254,7 @@ fn http_client() -> &'static reqwest::Client {
     CLIENT.get_or_init(reqwest::Client::new)
 }
 
-fn cache_root() -> PathBuf {
-    let base = std::env::var("HOME")
-        .map(PathBuf::from)
-        .unwrap_or_else(|_| PathBuf::from("."));
-    base.join(".cache").join("poketui")
-}
-
-fn cache_path(kind: &str, url: &str) -> PathBuf {
-    let mut hasher = Sha256::new();
-    hasher.update(url.as_bytes());
-    let digest = hex::encode(hasher.finalize());
-    cache_root().join(kind).join(digest)
-}
-
-async fn read_cache(path: &Path) -> Option<Vec<u8>> {
-    if let Ok(bytes) = fs::read(path).await {
-        return Some(bytes);
-    }
-    None
-}
-
-async fn write_cache(path: &Path, bytes: &[u8]) {
-    if let Some(parent) = path.parent() {
-        let _ = fs::create_dir_all(parent).await;
-    }
-    let _ = fs::write(path, bytes).await;
+fn disk_cache() -> &'static DiskCache {
+    static CACHE: OnceLock<DiskCache> = OnceLock::new();
+    CACHE.get_or_init(|| DiskCache::for_app("poketui"))
 }
@@ -0,0 +1,149 @@
+//! Control presets. Every key press goes through [`input`], which looks the
+//! key up in the active preset's binding table; the screens only ever match
+//! on the [`Input`] that comes back.
+
+use crossterm::event::KeyCode;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum ControlPreset {
+    /// Arrows or WASD to move, Z or Enter to confirm, X or Esc to back out.
+    #[default]
+    Wasd,
+    /// Arrows or HJKL to move, Enter to confirm, Q or Esc to back out.
+    Vim,
+    /// Arrows to move, Enter to confirm, Esc to back out.
+    Arrows,
+}
+
+/// What a key press means, whatever key it was.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Input {
+    Up,
+    Down,
+    Left,
+    Right,
+    Confirm,
+    Back,
+    /// Flips between the two sides of the shop and the PC box.
+    Switch,
+    /// Hardcore on the starter screen.
+    Toggle,
+    /// Releases a Pokemon from the PC box.
+    Release,
+    /// Nicknames the selected member on the party screen.
+    Rename,
+}
+
+/// Bound in every preset.
+const SHARED: &[(KeyCode, Input)] = &[
+    (KeyCode::Up, Input::Up),
+    (KeyCode::Down, Input::Down),
+    (KeyCode::Left, Input::Left),
+    (KeyCode::Right, Input::Right),
+    (KeyCode::Enter, Input::Confirm),
+    (KeyCode::Char(' '), Input::Confirm),
+    (KeyCode::Char('e'), Input::Confirm),
+    (KeyCode::Char('E'), Input::Confirm),
+    (KeyCode::Esc, Input::Back),
+    (KeyCode::Tab, Input::Switch),
+    (KeyCode::Char('r'), Input::Release),
+    (KeyCode::Char('R'), Input::Release),
+    (KeyCode::Char('n'), Input::Rename),
+    (KeyCode::Char('N'), Input::Rename),
+];
+
+const WASD: &[(KeyCode, Input)] = &[
+    (KeyCode::Char('w'), Input::Up),
+    (KeyCode::Char('s'), Input::Down),
+    (KeyCode::Char('a'), Input::Left),
+    (KeyCode::Char('d'), Input::Right),
+    (KeyCode::Char('z'), Input::Confirm),
+    (KeyCode::Char('Z'), Input::Confirm),
+    (KeyCode::Char('x'), Input::Back),
+    (KeyCode::Char('X'), Input::Back),
+    (KeyCode::Char('h'), Input::Toggle),
+    (KeyCode::Char('H'), Input::Toggle),
+];
+
+const VIM: &[(KeyCode, Input)] = &[
+    (KeyCode::Char('k'), Input::Up),
+    (KeyCode::Char('j'), Input::Down),
+    (KeyCode::Char('h'), Input::Left),
+    (KeyCode::Char('l'), Input::Right),
+    (KeyCode::Char('q'), Input::Back),
+    (KeyCode::Char('t'), Input::Toggle),
+    (KeyCode::Char('T'), Input::Toggle),
+];
+
+const ARROWS: &[(KeyCode, Input)] = &[
+    (KeyCode::Char('h'), Input::Toggle),
+    (KeyCode::Char('H'), Input::Toggle),
+];
+
+impl ControlPreset {
+    pub const ALL: [ControlPreset; 3] = [
+        ControlPreset::Wasd,
+        ControlPreset::Vim,
+        ControlPreset::Arrows,
+    ];
+
+    fn bindings(self) -> &'static [(KeyCode, Input)] {
+        match self {
+            ControlPreset::Wasd => WASD,
+            ControlPreset::Vim => VIM,
+            ControlPreset::Arrows => ARROWS,
+        }
+    }
+
+    pub fn label(self) -> &'static str {
+        match self {
+            ControlPreset::Wasd => "WASD",
+            ControlPreset::Vim => "Vim",
+            ControlPreset::Arrows => "Arrows",
+        }
+    }
+
+    /// The preset `step` places along `ALL`, wrapping around.
+    pub fn cycle(self, step: i16) -> Self {
+        let len = Self::ALL.len() as i16;
+        let index = Self::ALL
+            .iter()
+            .position(|preset| *preset == self)
+            .unwrap_or(0) as i16;
+        Self::ALL[(index + step).rem_euclid(len) as usize]
+    }
+
+    /// Movement keys as the on-screen hints name them.
+    pub fn move_keys(self) -> &'static str {
+        match self {
+            ControlPreset::Wasd => "Arrows/WASD",
+            ControlPreset::Vim => "Arrows/HJKL",
+            ControlPreset::Arrows => "Arrows",
+        }
+    }
+
+    pub fn confirm_key(self) -> &'static str {
+        match self {
+            ControlPreset::Wasd => "Z/Enter",
+            ControlPreset::Vim | ControlPreset::Arrows => "Enter",
+        }
+    }
+
+    pub fn toggle_key(self) -> &'static str {
+        match self {
+            ControlPreset::Vim => "T",
+            ControlPreset::Wasd | ControlPreset::Arrows => "H",
+        }
+    }
+}
+
+/// What `key` means under `preset`, if anything.
+pub fn input(preset: ControlPreset, key: KeyCode) -> Option<Input> {
+    SHARED
+        .iter()
+        .chain(preset.bindings())
+        .find(|(bound, _)| *bound == key)
+        .map(|(_, input)| *input)
+}
@@ -0,0 +1,103 @@
+use std::collections::VecDeque;
+use std::path::Path;
+
+use serde::Deserialize;
+
+use crate::action::Action;
+
+/// A scripted action, dispatched once `tick` ticks have passed since startup.
+#[derive(Clone, Debug, Deserialize)]
+pub struct DemoStep {
+    pub tick: u64,
+    pub action: Action,
+}
+
+/// A recorded demo: the RNG seed it was captured with and its inputs.
+#[derive(Clone, Debug, Deserialize)]
+pub struct DemoScript {
+    /// `--seed` takes precedence when both are given.
+    #[serde(default)]
+    pub seed: Option<u64>,
+    pub steps: Vec<DemoStep>,
+}
+
+pub fn load_script(path: &Path) -> Result<DemoScript, String> {
+    let json = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read demo script {}: {}", path.display(), e))?;
+    serde_json::from_str(&json)
+        .map_err(|e| format!("Demo script {} is invalid: {}", path.display(), e))
+}
+
+/// Feeds a demo script through the tick timer.
+///
+/// Each timer firing yields either the next due step or a plain `Tick`, and only
+/// `Tick`s advance the clock, so the store sees the same sequence on every run
+/// no matter how the timer drifts. Steps sharing an offset go out back to back.
+#[derive(Debug)]
+pub struct DemoDriver {
+    steps: VecDeque<DemoStep>,
+    tick: u64,
+}
+
+impl DemoDriver {
+    pub fn new(script: DemoScript) -> Self {
+        let mut steps = script.steps;
+        steps.sort_by_key(|step| step.tick);
+        Self {
+            steps: steps.into(),
+            tick: 0,
+        }
+    }
+
+    pub fn next_action(&mut self) -> Action {
+        if self.steps.front().is_some_and(|step| step.tick <= self.tick) {
+            if let Some(step) = self.steps.pop_front() {
+                return step.action;
+            }
+        }
+        self.tick += 1;
+        Action::Tick
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Steps out of order, two sharing tick 2.
+    const SCRIPT: &str = r#"{
+        "seed": 7,
+        "steps": [
+            { "tick": 2, "action": "StarterConfirm" },
+            { "tick": 0, "action": "MenuConfirm" },
+            { "tick": 2, "action": "MessageNext" }
+        ]
+    }"#;
+
+    fn play(ticks: usize) -> Vec<Action> {
+        let script: DemoScript = serde_json::from_str(SCRIPT).unwrap();
+        let mut driver = DemoDriver::new(script);
+        (0..ticks).map(|_| driver.next_action()).collect()
+    }
+
+    #[test]
+    fn steps_play_in_tick_order_with_ticks_between() {
+        assert_eq!(
+            play(7),
+            vec![
+                Action::MenuConfirm,
+                Action::Tick,
+                Action::Tick,
+                Action::StarterConfirm,
+                Action::MessageNext,
+                Action::Tick,
+                Action::Tick,
+            ]
+        );
+    }
+
+    #[test]
+    fn the_same_script_plays_the_same_every_time() {
+        assert_eq!(play(12), play(12));
+    }
+}
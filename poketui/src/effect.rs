@@ -1,19 +1,30 @@
+use crate::action::Action;
+use crate::settings::Settings;
 use crate::state::{AppState, SpriteTarget};
 
 #[derive(Clone, Debug)]
 pub enum Effect {
     LoadPokemon { target: SpriteTarget, name: String },
     LoadSprite { target: SpriteTarget, url: String },
-    PlayAttackSound,
+    PlayAttackSound { volume: u8 },
     LoadScenario { path: String },
     LoadPartySprite { index: usize, url: String },
+    LoadEvolution { member_index: usize, name: String },
 
     // Save/Load
     CheckSaveExists,
     SaveGame { state: Box<AppState> },
     LoadGame,
+    DeleteSave,
+    SaveSettings { settings: Settings },
+
+    // Speedrun
+    ExportSplits { contents: String },
 
     // Starter preview
     LoadStarterPreview { name: String },
     LoadStarterSprite { url: String },
+
+    // Gamepad: an action the reducer routed like a key, sent back to the store
+    Dispatch { action: Action },
 }
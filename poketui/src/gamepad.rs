@@ -0,0 +1,179 @@
+//! Gamepad input. With the opt-in `gamepad` feature a background
+//! thread polls gilrs and sends each button press as
+//! `Action::GamepadButton`; the reducer turns it into the [`Input`] the
+//! layout picked on the options screen gives it and routes that exactly
+//! like a key press. Holding the d-pad repeats, so walking works like a
+//! held arrow key.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::controls::Input;
+
+/// Which face button confirms. gilrs names buttons by position, so a
+/// Nintendo pad's A is the right one and an Xbox pad's A the bottom one.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum PadLayout {
+    /// Bottom button confirms, right button backs out.
+    #[default]
+    Xbox,
+    /// Right button confirms, bottom button backs out.
+    Nintendo,
+    /// Pads are ignored.
+    Off,
+}
+
+impl PadLayout {
+    pub const ALL: [PadLayout; 3] = [PadLayout::Xbox, PadLayout::Nintendo, PadLayout::Off];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            PadLayout::Xbox => "Xbox",
+            PadLayout::Nintendo => "Nintendo",
+            PadLayout::Off => "Off",
+        }
+    }
+
+    /// The layout `step` places along `ALL`, wrapping around.
+    pub fn cycle(self, step: i16) -> Self {
+        let len = Self::ALL.len() as i16;
+        let index = Self::ALL
+            .iter()
+            .position(|layout| *layout == self)
+            .unwrap_or(0) as i16;
+        Self::ALL[(index + step).rem_euclid(len) as usize]
+    }
+
+    /// What the face buttons do, for the options screen.
+    pub fn note(self) -> &'static str {
+        match self {
+            PadLayout::Xbox => "A (bottom) confirms, B (right) backs out",
+            PadLayout::Nintendo => "A (right) confirms, B (bottom) backs out",
+            PadLayout::Off => "Gamepad buttons are ignored",
+        }
+    }
+}
+
+/// A pad button, by position.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum PadButton {
+    DPadUp,
+    DPadDown,
+    DPadLeft,
+    DPadRight,
+    South,
+    East,
+    West,
+    North,
+    LeftShoulder,
+    RightShoulder,
+    Start,
+}
+
+/// What `button` means under `layout`, if anything. Start backs out too,
+/// which opens the pause menu on the map. The other two face buttons and
+/// the shoulders stand in for Tab and the starter screen's toggle.
+pub fn input(layout: PadLayout, button: PadButton) -> Option<Input> {
+    use PadButton::*;
+    let input = match (layout, button) {
+        (PadLayout::Off, _) => return None,
+        (_, DPadUp) => Input::Up,
+        (_, DPadDown) => Input::Down,
+        (_, DPadLeft) => Input::Left,
+        (_, DPadRight) => Input::Right,
+        (PadLayout::Xbox, South) | (PadLayout::Nintendo, East) => Input::Confirm,
+        (PadLayout::Xbox, East) | (PadLayout::Nintendo, South) | (_, Start) => Input::Back,
+        (PadLayout::Xbox, West) | (PadLayout::Nintendo, North) => Input::Switch,
+        (PadLayout::Xbox, North) | (PadLayout::Nintendo, West) => Input::Toggle,
+        (_, LeftShoulder | RightShoulder) => Input::Switch,
+    };
+    Some(input)
+}
+
+#[cfg(feature = "gamepad")]
+pub use listener::listen;
+
+#[cfg(feature = "gamepad")]
+mod listener {
+    use std::time::{Duration, Instant};
+
+    use gilrs::{Button, EventType, Gilrs};
+    use tokio::sync::mpsc::UnboundedSender;
+
+    use super::PadButton;
+    use crate::action::Action;
+
+    /// A held d-pad starts repeating after this long...
+    const REPEAT_DELAY: Duration = Duration::from_millis(300);
+    /// ...and then sends a press this often.
+    const REPEAT_EVERY: Duration = Duration::from_millis(150);
+
+    fn pad_button(button: Button) -> Option<PadButton> {
+        Some(match button {
+            Button::DPadUp => PadButton::DPadUp,
+            Button::DPadDown => PadButton::DPadDown,
+            Button::DPadLeft => PadButton::DPadLeft,
+            Button::DPadRight => PadButton::DPadRight,
+            Button::South => PadButton::South,
+            Button::East => PadButton::East,
+            Button::West => PadButton::West,
+            Button::North => PadButton::North,
+            Button::LeftTrigger => PadButton::LeftShoulder,
+            Button::RightTrigger => PadButton::RightShoulder,
+            Button::Start => PadButton::Start,
+            _ => return None,
+        })
+    }
+
+    fn is_dpad(button: PadButton) -> bool {
+        matches!(
+            button,
+            PadButton::DPadUp | PadButton::DPadDown | PadButton::DPadLeft | PadButton::DPadRight
+        )
+    }
+
+    /// Polls every connected pad on a thread of its own until `sender`'s
+    /// receiver goes away. Without a gamepad backend (no udev, say) the
+    /// thread just ends and the game plays on the keyboard.
+    pub fn listen(sender: UnboundedSender<Action>) {
+        std::thread::spawn(move || {
+            let Ok(mut gilrs) = Gilrs::new() else {
+                return;
+            };
+            // The held d-pad direction and when it next repeats.
+            let mut held: Option<(PadButton, Instant)> = None;
+            loop {
+                let event = gilrs.next_event_blocking(Some(REPEAT_EVERY));
+                let mut pressed = None;
+                match event.map(|event| event.event) {
+                    Some(EventType::ButtonPressed(button, _)) => {
+                        pressed = pad_button(button);
+                        if let Some(button) = pressed.filter(|button| is_dpad(*button)) {
+                            held = Some((button, Instant::now() + REPEAT_DELAY));
+                        }
+                    }
+                    Some(EventType::ButtonReleased(button, _))
+                        if held.is_some_and(|(down, _)| Some(down) == pad_button(button)) =>
+                    {
+                        held = None;
+                    }
+                    Some(EventType::Disconnected) => held = None,
+                    _ => {}
+                }
+                if let Some((button, next)) = held.as_mut() {
+                    if pressed.is_none() && Instant::now() >= *next {
+                        pressed = Some(*button);
+                        *next += REPEAT_EVERY;
+                    }
+                }
+                if let Some(button) = pressed {
+                    if sender.send(Action::GamepadButton(button)).is_err() {
+                        return;
+                    }
+                } else if sender.is_closed() {
+                    return;
+                }
+            }
+        });
+    }
+}
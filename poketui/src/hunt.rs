@@ -0,0 +1,65 @@
+//! Shiny hunting statistics: wild encounters per species, shinies seen, and
+//! the chain of back-to-back encounters with one species.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+
+/// Base shiny odds, one in this many wild encounters.
+pub const SHINY_ODDS: u32 = 512;
+
+/// Chain encounters per step of better odds.
+const CHAIN_STEP: u32 = 10;
+
+/// Odds stop improving past this chain length.
+const CHAIN_CAP: u32 = 40;
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct HuntStats {
+    /// Wild encounters by species.
+    pub encounters: BTreeMap<String, u32>,
+    /// Shiny wild encounters by species.
+    pub shinies: BTreeMap<String, u32>,
+    /// Species the current chain is on.
+    pub chain_species: Option<String>,
+    pub chain: u32,
+    pub best_chain: u32,
+}
+
+impl HuntStats {
+    /// Chain length an encounter with `species` would reach.
+    pub fn chain_with(&self, species: &str) -> u32 {
+        if self.chain_species.as_deref() == Some(species) {
+            self.chain + 1
+        } else {
+            1
+        }
+    }
+
+    /// One-in-N shiny odds for the next encounter with `species`; every
+    /// `CHAIN_STEP` links of an unbroken chain shave a share off the base.
+    pub fn odds_for(&self, species: &str) -> u32 {
+        let steps = self.chain_with(species).min(CHAIN_CAP) / CHAIN_STEP;
+        SHINY_ODDS / (1 + steps)
+    }
+
+    /// Count a wild encounter and extend or restart the chain.
+    pub fn record(&mut self, species: &str, shiny: bool) {
+        self.chain = self.chain_with(species);
+        self.chain_species = Some(species.to_string());
+        self.best_chain = self.best_chain.max(self.chain);
+        *self.encounters.entry(species.to_string()).or_insert(0) += 1;
+        if shiny {
+            *self.shinies.entry(species.to_string()).or_insert(0) += 1;
+        }
+    }
+
+    pub fn total_encounters(&self) -> u32 {
+        self.encounters.values().sum()
+    }
+
+    pub fn total_shinies(&self) -> u32 {
+        self.shinies.values().sum()
+    }
+}
@@ -0,0 +1,194 @@
+//! The item catalog: what each item is called, what a mart charges for it
+//! and what it does when used. Potions, Ethers and Poke Balls are built in;
+//! a scenario's `items` list adds its own, or redefines a built-in by reusing
+//! its id.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::state::{AppState, ItemKind};
+
+/// X items can't raise a stat past this many stages.
+pub const MAX_BOOST_STAGES: u8 = 6;
+
+const BUILTIN: [(ItemKind, &str, u32, ItemEffect); 4] = [
+    (ItemKind::POTION, "Potion", 300, ItemEffect::Heal { hp: 20 }),
+    (
+        ItemKind::SUPER_POTION,
+        "Super Potion",
+        700,
+        ItemEffect::Heal { hp: 50 },
+    ),
+    (
+        ItemKind::POKE_BALL,
+        "Poke Ball",
+        200,
+        ItemEffect::Ball { catch_rate: 100 },
+    ),
+    (
+        ItemKind::ETHER,
+        "Ether",
+        1200,
+        ItemEffect::RestorePp { pp: 10 },
+    ),
+];
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ItemDef {
+    pub id: ItemKind,
+    pub name: String,
+    /// Mart price when a shop doesn't set its own.
+    pub price: u32,
+    pub effect: ItemEffect,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ItemEffect {
+    /// Restores up to `hp` to the Pokemon out.
+    Heal { hp: u16 },
+    /// Restores `pp` to each of the Pokemon out's moves.
+    RestorePp { pp: u8 },
+    /// Brings a fainted party member back with `percent` of its max HP.
+    Revive { percent: u8 },
+    /// Raises a stat of the Pokemon out for the rest of the battle.
+    Boost { stat: BoostStat, stages: u8 },
+    /// Thrown at wild Pokemon. `catch_rate` scales a Poke Ball's odds, in
+    /// percent: 150 catches half again as often.
+    Ball { catch_rate: u16 },
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum BoostStat {
+    Attack,
+    Defense,
+}
+
+impl BoostStat {
+    pub fn label(self) -> &'static str {
+        match self {
+            BoostStat::Attack => "Attack",
+            BoostStat::Defense => "Defense",
+        }
+    }
+}
+
+fn builtin(kind: &ItemKind) -> Option<ItemDef> {
+    BUILTIN
+        .into_iter()
+        .find(|(id, ..)| id == kind)
+        .map(|(id, name, price, effect)| ItemDef {
+            id,
+            name: name.to_string(),
+            price,
+            effect,
+        })
+}
+
+/// What `kind` is: the scenario's definition if it has one, else the
+/// built-in. `None` for an id neither knows, such as an item carried over in
+/// a save from another scenario.
+pub fn def(state: &AppState, kind: &ItemKind) -> Option<ItemDef> {
+    state
+        .scenario
+        .as_ref()
+        .and_then(|scenario| scenario.manifest.items.iter().find(|item| item.id == *kind))
+        .cloned()
+        .or_else(|| builtin(kind))
+}
+
+/// Display name; unknown items show their raw id.
+pub fn name(state: &AppState, kind: &ItemKind) -> String {
+    def(state, kind)
+        .map(|item| item.name)
+        .unwrap_or_else(|| kind.id().to_string())
+}
+
+/// Mart price; unknown items are worth nothing.
+pub fn price(state: &AppState, kind: &ItemKind) -> u32 {
+    def(state, kind).map(|item| item.price).unwrap_or(0)
+}
+
+pub fn effect(state: &AppState, kind: &ItemKind) -> Option<ItemEffect> {
+    def(state, kind).map(|item| item.effect)
+}
+
+pub fn is_ball(state: &AppState, kind: &ItemKind) -> bool {
+    matches!(effect(state, kind), Some(ItemEffect::Ball { .. }))
+}
+
+/// Stacks the battle Bag lists: everything held that the catalog knows, in
+/// bag order.
+pub fn usable(state: &AppState) -> Vec<(ItemKind, u16)> {
+    state
+        .inventory
+        .iter()
+        .filter(|stack| stack.qty > 0 && def(state, &stack.kind).is_some())
+        .map(|stack| (stack.kind.clone(), stack.qty))
+        .collect()
+}
+
+/// Balls of every kind in the bag.
+pub fn ball_count(state: &AppState) -> u16 {
+    state
+        .inventory
+        .iter()
+        .filter(|stack| is_ball(state, &stack.kind))
+        .map(|stack| stack.qty)
+        .sum()
+}
+
+/// The ball Catch throws: the first kind in the bag, with its catch rate.
+pub fn first_ball(state: &AppState) -> Option<(ItemKind, u16)> {
+    state
+        .inventory
+        .iter()
+        .filter(|stack| stack.qty > 0)
+        .find_map(|stack| match effect(state, &stack.kind) {
+            Some(ItemEffect::Ball { catch_rate }) => Some((stack.kind.clone(), catch_rate)),
+            _ => None,
+        })
+}
+
+/// `stat` raised by `stages`, each worth half the base stat again.
+pub fn boosted(stat: u16, stages: u8) -> u16 {
+    (stat as u32 * (2 + stages as u32) / 2).min(u16::MAX as u32) as u16
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::ItemStack;
+
+    #[test]
+    fn boost_stages_add_half_the_stat_each() {
+        assert_eq!(boosted(40, 0), 40);
+        assert_eq!(boosted(40, 1), 60);
+        assert_eq!(boosted(40, MAX_BOOST_STAGES), 160);
+        assert_eq!(boosted(u16::MAX, MAX_BOOST_STAGES), u16::MAX);
+    }
+
+    #[test]
+    fn catch_throws_the_first_ball_held() {
+        let mut state = AppState::new();
+        state.inventory = vec![
+            ItemStack {
+                kind: ItemKind::POTION,
+                qty: 2,
+            },
+            ItemStack {
+                kind: ItemKind::POKE_BALL,
+                qty: 0,
+            },
+            ItemStack {
+                kind: ItemKind::new("MysteryBall"),
+                qty: 1,
+            },
+        ];
+        assert_eq!(first_ball(&state), None);
+
+        state.inventory[1].qty = 3;
+        assert_eq!(first_ball(&state), Some((ItemKind::POKE_BALL, 100)));
+    }
+}
@@ -1,16 +1,29 @@
 mod action;
+mod anim;
 mod api;
+mod controls;
+mod demo;
 mod effect;
+mod gamepad;
+mod hunt;
+mod items;
+mod moves;
+mod quest;
 mod reducer;
+mod replay;
 mod scenario;
-mod sprite;
+mod settings;
+mod shop;
+mod speedrun;
 mod sprite_backend;
 mod state;
 mod ui;
+mod water;
+mod weather;
 
 use std::io;
-use std::io::ErrorKind;
 use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use clap::Parser;
@@ -21,6 +34,8 @@ use crossterm::{
 };
 use ratatui::Terminal;
 use rodio::{source::SineWave, OutputStream, Sink, Source};
+#[cfg(feature = "gamepad")]
+use tokio_stream::wrappers::UnboundedReceiverStream;
 use tui_dispatch::{
     EffectContext, EffectStoreLike, EffectStoreWithMiddleware, EventOutcome, RenderContext, TaskKey,
 };
@@ -28,12 +43,14 @@ use tui_dispatch_debug::debug::DebugLayer;
 use tui_dispatch_debug::{
     DebugCliArgs, DebugRunOutput, DebugSession, DebugSessionError, ReplayItem,
 };
+use tui_persist::SaveFile;
 
 use crate::action::Action;
+use crate::demo::DemoDriver;
 use crate::effect::Effect;
 use crate::reducer::reducer;
 use crate::sprite_backend::SpriteBackend;
-use crate::state::AppState;
+use crate::state::{AppState, MessagePacing, TICK_MS};
 
 #[derive(Parser, Debug)]
 #[command(name = "poketui")]
@@ -44,11 +61,28 @@ struct Args {
     /// Scenario directory (manifest.ron + map.txt)
     #[arg(long, default_value = "assets/scenarios/lakeside")]
     scenario: String,
+    /// Show a speedrun timer with automatic milestone splits
+    #[arg(long)]
+    speedrun: bool,
+    /// Seed for encounters, damage rolls and pickups (defaults to the clock)
+    #[arg(long)]
+    seed: Option<u64>,
+    /// Play a demo script (JSON list of actions at tick offsets) on top of normal input
+    #[arg(long, value_name = "SCRIPT")]
+    demo: Option<PathBuf>,
+    /// Advance messages on their own after this many milliseconds (500-5000)
+    #[arg(long, value_name = "MS", value_parser = clap::value_parser!(u64).range(
+        MessagePacing::MIN_DELAY_MS as i64..=MessagePacing::MAX_DELAY_MS as i64
+    ))]
+    auto_advance: Option<u64>,
 }
 
 #[tokio::main]
 async fn main() -> io::Result<()> {
     let args = Args::parse();
+    let settings = settings::load(&settings_file());
+    let (palette, theme_warning) = tui_theme::load("poketui", &settings.palette);
+    tui_theme::install(palette);
     let debug = DebugSession::new(args.debug);
     debug.save_state_schema::<AppState>().map_err(debug_error)?;
     debug.save_actions_schema::<Action>().map_err(debug_error)?;
@@ -58,6 +92,20 @@ async fn main() -> io::Result<()> {
         .await
         .map_err(debug_error)?;
     state.scenario_dir = args.scenario.clone();
+    state.speedrun.enabled = args.speedrun;
+    state.settings = settings;
+    if let Some(delay_ms) = args.auto_advance {
+        state.settings.pacing.auto_advance = true;
+        state.settings.pacing.delay_ms = delay_ms;
+    }
+    let demo = match &args.demo {
+        Some(path) => Some(demo::load_script(path).map_err(io::Error::other)?),
+        None => None,
+    };
+    if let Some(seed) = args.seed.or(demo.as_ref().and_then(|script| script.seed)) {
+        state.rng_seed = seed;
+    }
+    let demo = demo.map(|script| Arc::new(Mutex::new(DemoDriver::new(script))));
     let replay_actions = debug.load_replay_items().map_err(debug_error)?;
     let (middleware, recorder) = debug.middleware_with_recorder();
     let store = EffectStoreWithMiddleware::new(state, reducer, middleware);
@@ -71,7 +119,7 @@ async fn main() -> io::Result<()> {
     let backend = SpriteBackend::new(stdout, sprite_backend::sprite_registry());
     let mut terminal = Terminal::new(backend)?;
 
-    let result = run_app(&mut terminal, &debug, store, replay_actions).await;
+    let result = run_app(&mut terminal, &debug, store, replay_actions, demo).await;
 
     if use_alt_screen {
         disable_raw_mode()?;
@@ -82,6 +130,9 @@ async fn main() -> io::Result<()> {
         )?;
         terminal.show_cursor()?;
     }
+    if let Some(warning) = theme_warning {
+        eprintln!("{warning}");
+    }
 
     let run_output = result?;
     run_output.write_render_output()?;
@@ -98,6 +149,7 @@ async fn run_app<B: ratatui::backend::Backend>(
     debug: &DebugSession,
     store: impl EffectStoreLike<AppState, Action, Effect>,
     replay_actions: Vec<ReplayItem<Action>>,
+    demo: Option<Arc<Mutex<DemoDriver>>>,
 ) -> io::Result<DebugRunOutput<AppState>> {
     debug
         .run_effect_app(
@@ -111,9 +163,27 @@ async fn run_app<B: ratatui::backend::Backend>(
                 if debug.render_once() {
                     return;
                 }
+                #[cfg(feature = "gamepad")]
+                {
+                    let (pad_tx, pad_rx) = tokio::sync::mpsc::unbounded_channel();
+                    gamepad::listen(pad_tx);
+                    runtime
+                        .subscriptions()
+                        .stream("gamepad", UnboundedReceiverStream::new(pad_rx));
+                }
+                // A demo script rides on the tick timer so its offsets are counted in ticks.
+                let demo = demo.clone();
                 runtime
                     .subscriptions()
-                    .interval("tick", Duration::from_millis(120), || Action::Tick);
+                    .interval("tick", Duration::from_millis(TICK_MS), move || {
+                        match &demo {
+                            Some(driver) => driver
+                                .lock()
+                                .unwrap_or_else(|poisoned| poisoned.into_inner())
+                                .next_action(),
+                            None => Action::Tick,
+                        }
+                    });
             },
             |frame, area, state, render_ctx: RenderContext| {
                 ui::render(frame, area, state, render_ctx);
@@ -144,7 +214,7 @@ fn handle_effect(effect: Effect, ctx: &mut EffectContext<Action>) {
             let key = format!("sprite_{}", target.label());
             ctx.tasks().spawn(TaskKey::new(key), async move {
                 match api::fetch_bytes(&url).await {
-                    Ok(bytes) => match sprite::decode_sprite(&bytes, &url) {
+                    Ok(bytes) => match sprite_cache::decode_sprite(&bytes, &url) {
                         Ok(sprite) => Action::SpriteDidLoad { target, sprite },
                         Err(error) => Action::SpriteDidError { target, error },
                     },
@@ -152,13 +222,16 @@ fn handle_effect(effect: Effect, ctx: &mut EffectContext<Action>) {
                 }
             });
         }
-        Effect::PlayAttackSound => {
-            play_attack_sound();
+        Effect::PlayAttackSound { volume } => {
+            play_attack_sound(volume);
         }
         Effect::CheckSaveExists => {
             ctx.tasks().spawn(TaskKey::new("check_save"), async move {
                 let path = save_file_path();
-                Action::SaveExists(path.exists())
+                Action::SaveExists {
+                    exists: path.exists(),
+                    hardcore: save_is_hardcore().await,
+                }
             });
         }
         Effect::SaveGame { state } => {
@@ -177,6 +250,30 @@ fn handle_effect(effect: Effect, ctx: &mut EffectContext<Action>) {
                 }
             });
         }
+        Effect::DeleteSave => {
+            ctx.tasks().spawn(TaskKey::new("delete_save"), async move {
+                match delete_save().await {
+                    Ok(()) => Action::SaveDeleted,
+                    Err(e) => Action::SaveDeleteError(e),
+                }
+            });
+        }
+        Effect::SaveSettings { settings } => {
+            ctx.tasks().spawn(TaskKey::new("save_settings"), async move {
+                match settings_file().save(&settings) {
+                    Ok(()) => Action::SettingsSaved,
+                    Err(e) => Action::SettingsSaveError(e.to_string()),
+                }
+            });
+        }
+        Effect::ExportSplits { contents } => {
+            ctx.tasks().spawn(TaskKey::new("export_splits"), async move {
+                match export_splits(&contents).await {
+                    Ok(path) => Action::SplitsExported(path),
+                    Err(e) => Action::SplitsExportError(e),
+                }
+            });
+        }
         Effect::LoadStarterPreview { name } => {
             ctx.tasks()
                 .spawn(TaskKey::new("starter_preview"), async move {
@@ -190,7 +287,7 @@ fn handle_effect(effect: Effect, ctx: &mut EffectContext<Action>) {
             ctx.tasks()
                 .spawn(TaskKey::new("starter_sprite"), async move {
                     match api::fetch_bytes(&url).await {
-                        Ok(bytes) => match sprite::decode_sprite(&bytes, &url) {
+                        Ok(bytes) => match sprite_cache::decode_sprite(&bytes, &url) {
                             Ok(sprite) => Action::StarterPreviewSpriteLoaded { sprite },
                             Err(error) => Action::StarterPreviewError { error },
                         },
@@ -203,7 +300,9 @@ fn handle_effect(effect: Effect, ctx: &mut EffectContext<Action>) {
                 .spawn(TaskKey::new("scenario_load"), async move {
                     let path = std::path::PathBuf::from(path);
                     match scenario::load_scenario(&path).await {
-                        Ok(scenario) => Action::ScenarioLoaded { scenario },
+                        Ok(scenario) => Action::ScenarioLoaded {
+                            scenario: Box::new(scenario),
+                        },
                         Err(error) => Action::ScenarioLoadError { error },
                     }
                 });
@@ -212,7 +311,7 @@ fn handle_effect(effect: Effect, ctx: &mut EffectContext<Action>) {
             let key = format!("party_sprite_{}", index);
             ctx.tasks().spawn(TaskKey::new(key), async move {
                 match api::fetch_bytes(&url).await {
-                    Ok(bytes) => match sprite::decode_sprite(&bytes, &url) {
+                    Ok(bytes) => match sprite_cache::decode_sprite(&bytes, &url) {
                         Ok(sprite) => Action::PartySpriteLoaded { index, sprite },
                         Err(error) => Action::PartySpriteError { index, error },
                     },
@@ -220,11 +319,24 @@ fn handle_effect(effect: Effect, ctx: &mut EffectContext<Action>) {
                 }
             });
         }
+        Effect::Dispatch { action } => {
+            ctx.tasks()
+                .spawn(TaskKey::new("gamepad_input"), async move { action });
+        }
+        Effect::LoadEvolution { member_index, name } => {
+            ctx.tasks().spawn(TaskKey::new("evolution"), async move {
+                match api::fetch_pokemon(&name).await {
+                    Ok(info) => Action::EvolutionDidLoad { member_index, info },
+                    Err(error) => Action::EvolutionDidError(error),
+                }
+            });
+        }
     }
 }
 
-fn play_attack_sound() {
-    std::thread::spawn(|| {
+/// Short beep; `volume` is the options screen's 1 to `MAX_VOLUME`.
+fn play_attack_sound(volume: u8) {
+    std::thread::spawn(move || {
         let Ok((stream, handle)) = OutputStream::try_default() else {
             return;
         };
@@ -233,45 +345,76 @@ fn play_attack_sound() {
         };
         let source = SineWave::new(640.0)
             .take_duration(Duration::from_millis(140))
-            .amplify(0.18);
+            .amplify(0.036 * volume as f32);
         sink.append(source);
         sink.sleep_until_end();
         drop(stream);
     });
 }
 
+/// Current save schema version. Bump this alongside a `.migrate(..)` in
+/// `save_file` when `AppState` changes shape.
+const SAVE_VERSION: u32 = 1;
+
 fn save_file_path() -> PathBuf {
     let base = dirs_next::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
     base.join("poketui").join("save.json")
 }
 
+fn save_file() -> SaveFile {
+    SaveFile::new(save_file_path()).version(SAVE_VERSION)
+}
+
+fn settings_file() -> SaveFile {
+    let base = dirs_next::data_local_dir().unwrap_or_else(|| PathBuf::from("."));
+    settings::file(&base.join("poketui"))
+}
+
 async fn save_game(state: &AppState) -> Result<(), String> {
-    let path = save_file_path();
-    if let Some(parent) = path.parent() {
-        tokio::fs::create_dir_all(parent)
-            .await
-            .map_err(|e| format!("Failed to create save directory: {}", e))?;
-    }
-    let json =
-        serde_json::to_string_pretty(state).map_err(|e| format!("Failed to serialize: {}", e))?;
-    tokio::fs::write(&path, json)
+    save_file().save(state).map_err(|e| e.to_string())
+}
+
+async fn export_splits(contents: &str) -> Result<String, String> {
+    use std::time::{SystemTime, UNIX_EPOCH};
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let dir = save_file_path()
+        .parent()
+        .map(|dir| dir.join("splits"))
+        .unwrap_or_else(|| PathBuf::from("splits"));
+    tokio::fs::create_dir_all(&dir)
         .await
-        .map_err(|e| format!("Failed to write save file: {}", e))?;
-    Ok(())
+        .map_err(|e| format!("Failed to create splits directory: {}", e))?;
+    let path = dir.join(format!("run-{}.txt", stamp));
+    tokio::fs::write(&path, contents)
+        .await
+        .map_err(|e| format!("Failed to write splits file: {}", e))?;
+    Ok(path.display().to_string())
 }
 
 async fn load_game() -> Result<AppState, String> {
-    let path = save_file_path();
-    let json = match tokio::fs::read_to_string(&path).await {
-        Ok(contents) => contents,
-        Err(e) => {
-            if e.kind() == ErrorKind::NotFound {
-                return Err("Save file not found.".to_string());
-            }
-            return Err(format!("Failed to read save file: {}", e));
-        }
-    };
-    let state: AppState =
-        serde_json::from_str(&json).map_err(|e| format!("Save file corrupted: {}", e))?;
-    Ok(state)
+    save_file().load().map_err(|e| e.to_string())
+}
+
+/// Just the run mode from a save, so the menu can badge it without
+/// deserializing the whole state.
+#[derive(serde::Deserialize)]
+struct SaveTag {
+    #[serde(default)]
+    hardcore: bool,
+}
+
+async fn save_is_hardcore() -> bool {
+    save_file()
+        .load::<SaveTag>()
+        .map(|tag| tag.hardcore)
+        .unwrap_or(false)
+}
+
+async fn delete_save() -> Result<(), String> {
+    save_file()
+        .delete()
+        .map_err(|e| format!("Failed to delete save file: {}", e))
 }
@@ -0,0 +1,215 @@
+//! Moves party members learn from PokeAPI, and the type chart that scales
+//! their damage.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Moves a party member can know at once; learning a fifth forgets the oldest.
+pub const MAX_MOVES: usize = 4;
+
+/// A damaging move a species learns by levelling up.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct MoveInfo {
+    pub name: String,
+    pub move_type: String,
+    pub power: u16,
+    pub pp: u8,
+    /// Lowest level it's learned at across the version groups PokeAPI lists.
+    pub level: u8,
+}
+
+/// A move a party member knows, with the PP it has left.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct KnownMove {
+    pub name: String,
+    pub move_type: String,
+    pub power: u16,
+    pub pp: u8,
+    pub max_pp: u8,
+}
+
+impl KnownMove {
+    /// What a Pokemon falls back to once every move is out of PP. It has no
+    /// type, so it's never boosted or resisted.
+    pub fn struggle(power: u16) -> Self {
+        Self {
+            name: "struggle".to_string(),
+            move_type: String::new(),
+            power,
+            pp: 0,
+            max_pp: 0,
+        }
+    }
+}
+
+impl From<&MoveInfo> for KnownMove {
+    fn from(info: &MoveInfo) -> Self {
+        Self {
+            name: info.name.clone(),
+            move_type: info.move_type.clone(),
+            power: info.power,
+            pp: info.pp,
+            max_pp: info.pp,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum Effectiveness {
+    #[default]
+    Normal,
+    Super,
+    NotVery,
+    Immune,
+}
+
+impl Effectiveness {
+    /// Buckets a damage multiplier given in percent.
+    pub fn from_percent(percent: u32) -> Self {
+        match percent {
+            0 => Effectiveness::Immune,
+            1..=99 => Effectiveness::NotVery,
+            100 => Effectiveness::Normal,
+            _ => Effectiveness::Super,
+        }
+    }
+
+    pub fn message(self) -> Option<&'static str> {
+        match self {
+            Effectiveness::Normal => None,
+            Effectiveness::Super => Some("It's super effective!"),
+            Effectiveness::NotVery => Some("It's not very effective..."),
+            Effectiveness::Immune => Some("It had no effect..."),
+        }
+    }
+}
+
+/// Attacking type: (double damage against, half damage against, no effect on).
+type Matchup = (
+    &'static str,
+    &'static [&'static str],
+    &'static [&'static str],
+    &'static [&'static str],
+);
+
+const TYPE_CHART: [Matchup; 18] = [
+    ("normal", &[], &["rock", "steel"], &["ghost"]),
+    (
+        "fire",
+        &["grass", "ice", "bug", "steel"],
+        &["fire", "water", "rock", "dragon"],
+        &[],
+    ),
+    (
+        "water",
+        &["fire", "ground", "rock"],
+        &["water", "grass", "dragon"],
+        &[],
+    ),
+    (
+        "electric",
+        &["water", "flying"],
+        &["electric", "grass", "dragon"],
+        &["ground"],
+    ),
+    (
+        "grass",
+        &["water", "ground", "rock"],
+        &[
+            "fire", "grass", "poison", "flying", "bug", "dragon", "steel",
+        ],
+        &[],
+    ),
+    (
+        "ice",
+        &["grass", "ground", "flying", "dragon"],
+        &["fire", "water", "ice", "steel"],
+        &[],
+    ),
+    (
+        "fighting",
+        &["normal", "ice", "rock", "dark", "steel"],
+        &["poison", "flying", "psychic", "bug", "fairy"],
+        &["ghost"],
+    ),
+    (
+        "poison",
+        &["grass", "fairy"],
+        &["poison", "ground", "rock", "ghost"],
+        &["steel"],
+    ),
+    (
+        "ground",
+        &["fire", "electric", "poison", "rock", "steel"],
+        &["grass", "bug"],
+        &["flying"],
+    ),
+    (
+        "flying",
+        &["grass", "fighting", "bug"],
+        &["electric", "rock", "steel"],
+        &[],
+    ),
+    (
+        "psychic",
+        &["fighting", "poison"],
+        &["psychic", "steel"],
+        &["dark"],
+    ),
+    (
+        "bug",
+        &["grass", "psychic", "dark"],
+        &[
+            "fire", "fighting", "poison", "flying", "ghost", "steel", "fairy",
+        ],
+        &[],
+    ),
+    (
+        "rock",
+        &["fire", "ice", "flying", "bug"],
+        &["fighting", "ground", "steel"],
+        &[],
+    ),
+    ("ghost", &["psychic", "ghost"], &["dark"], &["normal"]),
+    ("dragon", &["dragon"], &["steel"], &["fairy"]),
+    (
+        "dark",
+        &["psychic", "ghost"],
+        &["fighting", "dark", "fairy"],
+        &[],
+    ),
+    (
+        "steel",
+        &["ice", "rock", "fairy"],
+        &["fire", "water", "electric", "steel"],
+        &[],
+    ),
+    (
+        "fairy",
+        &["fighting", "dragon", "dark"],
+        &["fire", "poison", "steel"],
+        &[],
+    ),
+];
+
+/// Damage multiplier in percent for a move of `move_type` hitting a Pokemon
+/// with `defender_types`. Unknown types on either side count as neutral.
+pub fn type_multiplier(move_type: &str, defender_types: &[String]) -> u32 {
+    let Some((_, strong, weak, immune)) =
+        TYPE_CHART.iter().find(|(attack, ..)| *attack == move_type)
+    else {
+        return 100;
+    };
+    defender_types.iter().fold(100, |percent, defender| {
+        let defender = defender.as_str();
+        if immune.contains(&defender) {
+            0
+        } else if strong.contains(&defender) {
+            percent * 2
+        } else if weak.contains(&defender) {
+            percent / 2
+        } else {
+            percent
+        }
+    })
+}
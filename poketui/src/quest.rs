@@ -0,0 +1,120 @@
+//! Quests handed out by scenario NPCs: which ones the player has taken on,
+//! and how far along each one is.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use crate::scenario::{QuestObjective, QuestSpec};
+use crate::state::{AppState, ItemStack};
+
+/// A quest the player has accepted.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct QuestEntry {
+    pub id: String,
+    /// Defeats of the target species before the quest was accepted; only the
+    /// ones after count.
+    #[serde(default)]
+    pub baseline: u16,
+    /// Turned in and rewarded.
+    #[serde(default)]
+    pub done: bool,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct QuestLog {
+    /// Accepted quests, oldest first.
+    pub entries: Vec<QuestEntry>,
+}
+
+impl QuestLog {
+    pub fn entry(&self, id: &str) -> Option<&QuestEntry> {
+        self.entries.iter().find(|entry| entry.id == id)
+    }
+
+    pub fn entry_mut(&mut self, id: &str) -> Option<&mut QuestEntry> {
+        self.entries.iter_mut().find(|entry| entry.id == id)
+    }
+
+    /// Takes on `quest`, counting defeats from `defeat_counts` as they stand.
+    /// Returns false if it was already accepted.
+    pub fn accept(&mut self, quest: &QuestSpec, defeat_counts: &HashMap<String, u16>) -> bool {
+        if self.entry(&quest.id).is_some() {
+            return false;
+        }
+        let baseline = match &quest.objective {
+            QuestObjective::Defeat { species, .. } => species_defeats(defeat_counts, species),
+            QuestObjective::Fetch { .. } => 0,
+        };
+        self.entries.push(QuestEntry {
+            id: quest.id.clone(),
+            baseline,
+            done: false,
+        });
+        true
+    }
+}
+
+/// Where the player stands with a quest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum QuestStatus {
+    NotTaken,
+    InProgress {
+        progress: u16,
+        goal: u16,
+    },
+    /// Objective met; the NPC is waiting for the player to come back.
+    Ready,
+    Done,
+}
+
+pub fn status(state: &AppState, quest: &QuestSpec) -> QuestStatus {
+    let Some(entry) = state.quests.entry(&quest.id) else {
+        return QuestStatus::NotTaken;
+    };
+    if entry.done {
+        return QuestStatus::Done;
+    }
+    let (progress, goal) = progress(quest, entry, &state.defeat_counts, &state.inventory);
+    if progress >= goal {
+        QuestStatus::Ready
+    } else {
+        QuestStatus::InProgress { progress, goal }
+    }
+}
+
+/// How far along `entry` is, as (progress, goal). Progress never passes the goal.
+pub fn progress(
+    quest: &QuestSpec,
+    entry: &QuestEntry,
+    defeat_counts: &HashMap<String, u16>,
+    inventory: &[ItemStack],
+) -> (u16, u16) {
+    let (current, goal) = match &quest.objective {
+        QuestObjective::Defeat { species, count } => (
+            species_defeats(defeat_counts, species).saturating_sub(entry.baseline),
+            *count,
+        ),
+        QuestObjective::Fetch { item, qty } => (
+            inventory
+                .iter()
+                .filter(|stack| stack.kind == *item)
+                .map(|stack| stack.qty)
+                .sum(),
+            *qty,
+        ),
+    };
+    if entry.done {
+        return (goal, goal);
+    }
+    (current.min(goal), goal)
+}
+
+/// Defeat counts are keyed by lowercase species name.
+fn species_defeats(defeat_counts: &HashMap<String, u16>, species: &str) -> u16 {
+    defeat_counts
+        .get(&species.trim().to_ascii_lowercase())
+        .copied()
+        .unwrap_or(0)
+}
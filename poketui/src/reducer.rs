@@ -1,13 +1,30 @@
 use tui_dispatch::DispatchResult;
 
 use crate::action::Action;
+use crate::anim::{self, HitAnim, Side};
 use crate::effect::Effect;
-use crate::scenario::{AbilityEffect, AbilitySpec, ScenarioRuntime, ScenarioTrigger};
+use crate::gamepad::{self, PadButton};
+use crate::items::{self, BoostStat, ItemEffect, MAX_BOOST_STAGES};
+use crate::moves::{type_multiplier, Effectiveness, KnownMove};
+use crate::quest::{self, QuestStatus};
+use crate::replay::{self, BattleLog, ReplayState};
+use crate::scenario::{
+    AbilityEffect, AbilitySpec, GauntletSpec, HealPolicy, NpcSpec, QuestObjective, QuestSpec,
+    ScenarioRuntime, ScenarioTrigger, TrainerSpec,
+};
+use crate::settings::{self, OptionRow};
+use crate::shop::{self, ShopState, ShopTab, STARTING_MONEY};
+use crate::speedrun::{self, Milestone};
 use crate::state::{
-    calc_hp, calc_stat, exp_for_level, AppState, BattleKind, BattleStage, ComboHit, Direction,
-    GameMode, ItemKind, MenuState, PartyMember, Pickup, PokemonSelectState, SpriteState,
-    SpriteTarget, Tile, TurnActor, MAX_LEVEL,
+    calc_hp, calc_stat, exp_for_level, AppState, BattleKind, BattleStage, BattleState, BoxPane,
+    BoxScreenState, ComboHit, DialogueState, Direction, EvolutionPrompt, GameMode, GauntletRun,
+    ItemKind, MenuState, OptionsScreenState, PartyMember, PartyScreenState, Pickup,
+    PokemonSelectState, SpriteState, SpriteTarget, Tile, TrainerState, TurnActor, BOX_CAPACITY,
+    MAX_LEVEL, NICKNAME_LIMIT, PARTY_LIMIT, TICK_MS,
 };
+use crate::ui;
+use crate::water::{self, FishingPhase, FishingState, DEFAULT_WATER_POOL};
+use crate::weather;
 
 const DEFAULT_STARTERS: [&str; 4] = ["pikachu", "charmander", "bulbasaur", "squirtle"];
 const DEFAULT_WILD_POOL: [&str; 6] = ["pidgey", "rattata", "caterpie", "weedle", "oddish", "zubat"];
@@ -15,6 +32,10 @@ const BOSS_NAME: &str = "onix";
 const RELIC_WINS: u16 = 3;
 const BOSS_WINS: u16 = 5;
 const MOVE_POWER: u32 = 40;
+/// Trainer AI moves. Take Down hits half again as hard as a plain attack but
+/// misses one time in four; Recover heals half of max HP, once per Pokemon.
+const TAKE_DOWN: &str = "Take Down";
+const RECOVER: &str = "Recover";
 
 pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
     match action {
@@ -24,6 +45,7 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
             state.menu = Some(MenuState {
                 selected: 0,
                 has_save: false,
+                save_hardcore: false,
             });
             state.message = None;
             state.message_queue.clear();
@@ -44,23 +66,13 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
         Action::Move(direction) => move_player(state, direction),
         Action::BattleMenuNext => battle_menu_change(state, 1),
         Action::BattleMenuPrev => battle_menu_change(state, -1),
-        Action::BattleConfirm => battle_confirm(state),
-        Action::MessageNext => message_next(state),
-        Action::BattleItemCancel => {
-            let is_item_menu = state
-                .battle
-                .as_ref()
-                .map(|battle| battle.stage == BattleStage::ItemMenu)
-                .unwrap_or(false);
-            if is_item_menu {
-                if let Some(battle) = state.battle.as_mut() {
-                    battle.stage = BattleStage::Menu;
-                }
-                set_battle_menu_prompt(state);
-                return DispatchResult::changed();
-            }
-            DispatchResult::unchanged()
+        Action::BattleConfirm => {
+            state.message_timer = 0;
+            battle_confirm(state)
         }
+        Action::MessageNext => message_next(state),
+        Action::BattleItemCancel => close_battle_item_menu(state),
+        Action::BattleMoveCancel => close_battle_submenu(state, BattleStage::MoveMenu),
         Action::PokemonDidLoad { target, info } => pokemon_loaded(state, target, info),
         Action::PokemonDidError {
             target,
@@ -78,14 +90,15 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
             DispatchResult::changed()
         }
         Action::MenuConfirm => menu_confirm(state),
-        Action::SaveExists(exists) => {
+        Action::SaveExists { exists, hardcore } => {
             if let Some(menu) = state.menu.as_mut() {
                 menu.has_save = exists;
+                menu.save_hardcore = exists && hardcore;
             }
             DispatchResult::changed()
         }
         Action::ScenarioLoaded { scenario } => {
-            apply_scenario(state, scenario);
+            apply_scenario(state, *scenario);
             DispatchResult::changed_with(Effect::CheckSaveExists)
         }
         Action::ScenarioLoadError { error } => {
@@ -115,6 +128,13 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
         // Pokemon selection actions
         Action::StarterSelect(index) => starter_select(state, index),
         Action::StarterConfirm => starter_confirm(state),
+        Action::StarterToggleHardcore => {
+            let Some(select) = state.pokemon_select.as_mut() else {
+                return DispatchResult::unchanged();
+            };
+            select.hardcore = !select.hardcore;
+            DispatchResult::changed()
+        }
         Action::StarterPreviewLoaded { info } => {
             if let Some(select) = state.pokemon_select.as_mut() {
                 select.preview_info = Some(info.clone());
@@ -160,6 +180,178 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
             DispatchResult::changed()
         }
         Action::PauseConfirm => pause_confirm(state),
+        Action::PartyOpen => party_open(state),
+        Action::PartyClose => {
+            if !state.party_screen.is_open {
+                return DispatchResult::unchanged();
+            }
+            state.party_screen = PartyScreenState::default();
+            DispatchResult::changed()
+        }
+        Action::PartyMove(delta) => party_move(state, delta),
+        Action::PartySetLead => party_set_lead(state),
+        Action::PartyToggleMoving => {
+            if state.party.len() < 2 {
+                return DispatchResult::unchanged();
+            }
+            state.party_screen.moving = !state.party_screen.moving;
+            DispatchResult::changed()
+        }
+        Action::PartyRename => {
+            let Some(member) = state.party.get(state.party_screen.selected) else {
+                return DispatchResult::unchanged();
+            };
+            state.party_screen.moving = false;
+            state.party_screen.naming = Some(member.nickname.clone().unwrap_or_default());
+            DispatchResult::changed()
+        }
+        Action::PartyNameInput(ch) => {
+            let Some(name) = state.party_screen.naming.as_mut() else {
+                return DispatchResult::unchanged();
+            };
+            if !(ch.is_ascii_alphanumeric() || ch == ' ') || name.len() >= NICKNAME_LIMIT {
+                return DispatchResult::unchanged();
+            }
+            name.push(ch);
+            DispatchResult::changed()
+        }
+        Action::PartyNameBackspace => {
+            match state.party_screen.naming.as_mut().and_then(String::pop) {
+                Some(_) => DispatchResult::changed(),
+                None => DispatchResult::unchanged(),
+            }
+        }
+        Action::PartyNameConfirm => party_name_confirm(state),
+        Action::PartyNameCancel => {
+            if state.party_screen.naming.take().is_none() {
+                return DispatchResult::unchanged();
+            }
+            DispatchResult::changed()
+        }
+        Action::BoxOpen => box_open(state),
+        Action::BoxClose => {
+            if !state.box_screen.is_open {
+                return DispatchResult::unchanged();
+            }
+            state.box_screen.is_open = false;
+            state.box_screen.release_armed = false;
+            DispatchResult::changed()
+        }
+        Action::BoxMove(delta) => box_move(state, delta),
+        Action::BoxSwitchPane => {
+            state.box_screen.pane = match state.box_screen.pane {
+                BoxPane::Party => BoxPane::Box,
+                BoxPane::Box => BoxPane::Party,
+            };
+            state.box_screen.release_armed = false;
+            DispatchResult::changed()
+        }
+        Action::BoxTransfer => box_transfer(state),
+        Action::BoxRelease => box_release(state),
+        Action::StatsOpen => {
+            state.stats_open = true;
+            DispatchResult::changed()
+        }
+        Action::StatsClose => {
+            if !state.stats_open {
+                return DispatchResult::unchanged();
+            }
+            state.stats_open = false;
+            DispatchResult::changed()
+        }
+        Action::Talk => talk(state),
+        Action::DialogueSelect(index) => {
+            let Some(dialogue) = state.dialogue.as_mut() else {
+                return DispatchResult::unchanged();
+            };
+            dialogue.selected = index;
+            DispatchResult::changed()
+        }
+        Action::DialogueConfirm => dialogue_confirm(state),
+        Action::DialogueClose => {
+            if state.dialogue.take().is_none() {
+                return DispatchResult::unchanged();
+            }
+            DispatchResult::changed()
+        }
+        Action::QuestLogOpen => {
+            state.quest_log_open = true;
+            DispatchResult::changed()
+        }
+        Action::QuestLogClose => {
+            if !state.quest_log_open {
+                return DispatchResult::unchanged();
+            }
+            state.quest_log_open = false;
+            DispatchResult::changed()
+        }
+        Action::FishReel => reel_in(state),
+        Action::FishCancel => {
+            if state.fishing.take().is_none() {
+                return DispatchResult::unchanged();
+            }
+            DispatchResult::changed()
+        }
+        Action::ShopMove(delta) => shop_move(state, delta),
+        Action::ShopSwitchTab => {
+            let Some(shop) = state.shop.as_mut() else {
+                return DispatchResult::unchanged();
+            };
+            shop.tab = match shop.tab {
+                ShopTab::Buy => ShopTab::Sell,
+                ShopTab::Sell => ShopTab::Buy,
+            };
+            shop.selected = 0;
+            shop.notice = None;
+            DispatchResult::changed()
+        }
+        Action::ShopConfirm => shop_confirm(state),
+        Action::ShopClose => {
+            if state.shop.take().is_none() {
+                return DispatchResult::unchanged();
+            }
+            state.mode = GameMode::Overworld;
+            DispatchResult::changed()
+        }
+        Action::EvolutionConfirm => evolution_confirm(state),
+        Action::EvolutionCancel => evolution_cancel(state),
+        Action::EvolutionDidLoad { member_index, info } => {
+            evolution_loaded(state, member_index, info)
+        }
+        Action::EvolutionDidError(error) => {
+            state.evolution = None;
+            push_message(state, format!("Evolution failed: {error}"));
+            DispatchResult::changed()
+        }
+        Action::OptionsOpen => options_open(state),
+        Action::OptionsClose => {
+            if !state.options.is_open {
+                return DispatchResult::unchanged();
+            }
+            state.options.is_open = false;
+            DispatchResult::changed()
+        }
+        Action::OptionsMove(delta) => {
+            let len = OptionRow::ALL.len() as i64;
+            state.options.selected =
+                (state.options.selected as i64 + delta as i64).rem_euclid(len) as usize;
+            DispatchResult::changed()
+        }
+        Action::ReplayStart => replay_start(state),
+        Action::ReplayStop => replay_stop(state),
+        Action::GamepadButton(button) => gamepad_button(state, button),
+        Action::OptionsAdjust(step) => {
+            let Some(row) = OptionRow::ALL.get(state.options.selected) else {
+                return DispatchResult::unchanged();
+            };
+            if !settings::adjust(&mut state.settings, *row, step) {
+                return DispatchResult::unchanged();
+            }
+            state.message_timer = 0;
+            DispatchResult::changed_with(Effect::SaveSettings {
+                settings: state.settings.clone(),
+            })
+        }
 
         // Save/Load actions
         Action::SaveGame => DispatchResult::changed_with(Effect::SaveGame {
@@ -178,7 +370,14 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
         Action::LoadComplete(loaded_state) => {
             // Replace entire state with loaded state
             let scenario_dir = state.scenario_dir.clone();
+            let speedrun_enabled = state.speedrun.enabled;
+            let settings = state.settings.clone();
             *state = *loaded_state;
+            state.speedrun.enabled = speedrun_enabled;
+            state.settings = settings;
+            if !speedrun_enabled {
+                state.speedrun.running = false;
+            }
             if state.scenario_dir.is_empty() {
                 state.scenario_dir = scenario_dir;
             } else if state.scenario_dir != scenario_dir {
@@ -198,6 +397,25 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
             push_message(state, format!("Load failed: {}", error));
             DispatchResult::changed()
         }
+        Action::SaveDeleted => DispatchResult::changed_with(Effect::CheckSaveExists),
+        Action::SaveDeleteError(error) => {
+            push_message(state, format!("Could not delete save: {}", error));
+            DispatchResult::changed_with(Effect::CheckSaveExists)
+        }
+
+        Action::SplitsExported(path) => {
+            push_message(state, format!("Splits saved to {}", path));
+            DispatchResult::changed()
+        }
+        Action::SplitsExportError(error) => {
+            push_message(state, format!("Splits export failed: {}", error));
+            DispatchResult::changed()
+        }
+        Action::SettingsSaved => DispatchResult::unchanged(),
+        Action::SettingsSaveError(error) => {
+            push_message(state, format!("Couldn't save settings: {}", error));
+            DispatchResult::changed()
+        }
 
         Action::Quit => DispatchResult::unchanged(),
     }
@@ -224,7 +442,15 @@ fn move_player(state: &mut AppState, direction: Direction) -> DispatchResult<Eff
     if next_x >= state.map.width || next_y >= state.map.height {
         return DispatchResult::changed();
     }
-    if !state.map.is_walkable(next_x, next_y) {
+    let surf = state.map.is_water(next_x, next_y) && water::can_surf(state);
+    if !state.map.is_walkable(next_x, next_y) && !surf {
+        return DispatchResult::changed();
+    }
+    if state.scenario.as_ref().is_some_and(|scenario| {
+        scenario.trainer_at(next_x, next_y).is_some()
+            || scenario.npc_at(next_x, next_y).is_some()
+            || scenario.gauntlet_at(next_x, next_y).is_some()
+    }) {
         return DispatchResult::changed();
     }
 
@@ -232,10 +458,38 @@ fn move_player(state: &mut AppState, direction: Direction) -> DispatchResult<Eff
     state.player.y = next_y;
     state.player.steps = state.player.steps.wrapping_add(1);
     state.steps_since_encounter = state.steps_since_encounter.saturating_add(1);
+    if state
+        .player
+        .steps
+        .is_multiple_of(weather::WEATHER_STEPS as u64)
+    {
+        change_weather(state);
+    }
 
     collect_pickup(state, next_x, next_y);
     trigger_tile_events(state, next_x, next_y);
 
+    if let Some(shop) = state
+        .scenario
+        .as_ref()
+        .and_then(|scenario| scenario.shop_at(next_x, next_y))
+        .cloned()
+    {
+        state.mode = GameMode::Shop;
+        state.shop = Some(ShopState {
+            shop_id: shop.id,
+            tab: ShopTab::Buy,
+            selected: 0,
+            notice: shop.greeting,
+        });
+        return DispatchResult::changed();
+    }
+
+    if let Some(trainer) = trainer_in_reach(state) {
+        state.steps_since_encounter = 0;
+        return start_trainer_battle(state, trainer);
+    }
+
     if state.map.is_grass(next_x, next_y) && state.steps_since_encounter >= 3 {
         let roll = next_rand(state) % 100;
         if roll < 18 {
@@ -246,28 +500,51 @@ fn move_player(state: &mut AppState, direction: Direction) -> DispatchResult<Eff
             if state.has_relic && state.wild_wins >= BOSS_WINS {
                 return start_boss_battle(state);
             }
-            return start_wild_battle(state);
+            let pool = encounter_pool(state, false);
+            return start_wild_battle(state, pool);
         }
     }
 
     DispatchResult::changed()
 }
 
-fn start_wild_battle(state: &mut AppState) -> DispatchResult<Effect> {
-    let scenario_pool = state
+/// Species a wild encounter is drawn from: the scenario's grass or water
+/// pool, else the built-in one.
+fn encounter_pool(state: &AppState, water: bool) -> Vec<String> {
+    state
         .scenario
         .as_ref()
-        .map(|scenario| scenario.manifest.wild_pool.clone())
-        .filter(|pool| !pool.is_empty());
-    let enemy_name = if let Some(pool) = scenario_pool {
-        let index = (next_rand(state) as usize) % pool.len();
-        pool[index].clone()
-    } else {
-        let index = (next_rand(state) as usize) % DEFAULT_WILD_POOL.len();
-        DEFAULT_WILD_POOL[index].to_string()
-    };
+        .map(|scenario| {
+            if water {
+                scenario.manifest.water_pool.clone()
+            } else {
+                scenario.manifest.wild_pool.clone()
+            }
+        })
+        .filter(|pool| !pool.is_empty())
+        .unwrap_or_else(|| {
+            let fallback: &[&str] = if water {
+                &DEFAULT_WATER_POOL
+            } else {
+                &DEFAULT_WILD_POOL
+            };
+            fallback.iter().map(|name| name.to_string()).collect()
+        })
+}
+
+fn start_wild_battle(state: &mut AppState, pool: Vec<String>) -> DispatchResult<Effect> {
+    let index = (next_rand(state) as usize) % pool.len();
+    let enemy_name = pool[index].clone();
     let enemy_level = roll_enemy_level(state);
-    start_battle(state, enemy_name, enemy_level, BattleKind::Wild)
+    let odds = state.hunt.odds_for(&enemy_name);
+    let shiny = next_rand(state).is_multiple_of(odds);
+    state.hunt.record(&enemy_name, shiny);
+    let result = start_battle(state, enemy_name, enemy_level, BattleKind::Wild);
+    if let Some(battle) = state.battle.as_mut().filter(|_| shiny) {
+        battle.shiny = true;
+        battle.message = format!("A shiny {} appeared!", format_name(&battle.enemy_name));
+    }
+    result
 }
 
 fn start_boss_battle(state: &mut AppState) -> DispatchResult<Effect> {
@@ -276,6 +553,387 @@ fn start_boss_battle(state: &mut AppState) -> DispatchResult<Effect> {
     start_battle(state, enemy_name, enemy_level, BattleKind::Boss)
 }
 
+/// An undefeated trainer standing right next to the player.
+fn trainer_in_reach(state: &AppState) -> Option<TrainerSpec> {
+    let scenario = state.scenario.as_ref()?;
+    let (x, y) = (state.player.x, state.player.y);
+    scenario
+        .manifest
+        .trainers
+        .iter()
+        .find(|trainer| {
+            x.abs_diff(trainer.x) + y.abs_diff(trainer.y) == 1
+                && !trainer.party.is_empty()
+                && !state.defeated_trainers.contains(&trainer.id)
+        })
+        .cloned()
+}
+
+/// The tile the player is facing.
+fn facing_tile(state: &AppState) -> Option<(u16, u16)> {
+    let (x, y) = (state.player.x, state.player.y);
+    Some(match state.player.facing {
+        Direction::Up => (x, y.checked_sub(1)?),
+        Direction::Down => (x, y.checked_add(1)?),
+        Direction::Left => (x.checked_sub(1)?, y),
+        Direction::Right => (x.checked_add(1)?, y),
+    })
+}
+
+fn facing_npc(state: &AppState) -> Option<NpcSpec> {
+    let (x, y) = facing_tile(state)?;
+    state.scenario.as_ref()?.npc_at(x, y).cloned()
+}
+
+fn facing_gauntlet(state: &AppState) -> Option<GauntletSpec> {
+    let (x, y) = facing_tile(state)?;
+    state.scenario.as_ref()?.gauntlet_at(x, y).cloned()
+}
+
+fn npc_quest(state: &AppState, npc: &NpcSpec) -> Option<QuestSpec> {
+    let scenario = state.scenario.as_ref()?;
+    npc.quest
+        .as_deref()
+        .and_then(|id| scenario.quest(id))
+        .cloned()
+}
+
+/// Talks to the NPC in front of the player. Someone waiting on a quest checks
+/// in on it instead of repeating their whole dialogue.
+fn talk(state: &mut AppState) -> DispatchResult<Effect> {
+    if state.mode != GameMode::Overworld || state.dialogue.is_some() || state.fishing.is_some() {
+        return DispatchResult::unchanged();
+    }
+    if let Some(gauntlet) = facing_gauntlet(state) {
+        return enter_gauntlet(state, gauntlet);
+    }
+    let Some(npc) = facing_npc(state) else {
+        return cast_line(state);
+    };
+    if let Some(quest) = npc_quest(state, &npc) {
+        match quest::status(state, &quest) {
+            QuestStatus::NotTaken => {}
+            QuestStatus::InProgress { progress, goal } => {
+                let line = quest.reminder.as_ref().unwrap_or(&quest.description);
+                push_message(
+                    state,
+                    format!("{}: \"{}\" ({}/{})", npc.name, line, progress, goal),
+                );
+                return DispatchResult::changed();
+            }
+            QuestStatus::Ready => {
+                complete_quest(state, &npc, &quest);
+                return DispatchResult::changed();
+            }
+            QuestStatus::Done => {
+                let line = quest.thanks.as_deref().unwrap_or("Thanks again!");
+                push_message(state, format!("{}: \"{}\"", npc.name, line));
+                return DispatchResult::changed();
+            }
+        }
+    }
+    let Some(first) = npc.dialogue.first() else {
+        return DispatchResult::unchanged();
+    };
+    state.dialogue = Some(DialogueState {
+        npc_id: npc.id.clone(),
+        node: first.id.clone(),
+        selected: 0,
+    });
+    DispatchResult::changed()
+}
+
+/// Follows the highlighted choice; a line without choices just ends the talk.
+fn dialogue_confirm(state: &mut AppState) -> DispatchResult<Effect> {
+    let Some(dialogue) = state.dialogue.take() else {
+        return DispatchResult::unchanged();
+    };
+    let Some(npc) = state
+        .scenario
+        .as_ref()
+        .and_then(|scenario| scenario.npc(&dialogue.npc_id))
+        .cloned()
+    else {
+        return DispatchResult::changed();
+    };
+    let Some(choice) = npc
+        .node(&dialogue.node)
+        .and_then(|node| node.choices.get(dialogue.selected))
+        .cloned()
+    else {
+        return DispatchResult::changed();
+    };
+    if choice.accept_quest {
+        if let Some(quest) = npc_quest(state, &npc) {
+            if state.quests.accept(&quest, &state.defeat_counts) {
+                push_message(state, format!("Quest accepted: {}", quest.name));
+            }
+        }
+    }
+    state.dialogue = choice
+        .next
+        .filter(|next| npc.node(next).is_some())
+        .map(|node| DialogueState {
+            npc_id: npc.id.clone(),
+            node,
+            selected: 0,
+        });
+    DispatchResult::changed()
+}
+
+/// Hands over fetched items and pays out the reward.
+fn complete_quest(state: &mut AppState, npc: &NpcSpec, quest: &QuestSpec) {
+    if let QuestObjective::Fetch { item, qty } = &quest.objective {
+        for _ in 0..*qty {
+            take_item(state, item);
+        }
+        let name = items::name(state, item);
+        push_message(state, format!("Handed over {} x{}.", name, qty));
+    }
+    if let Some(entry) = state.quests.entry_mut(&quest.id) {
+        entry.done = true;
+    }
+    push_message(state, format!("Quest complete: {}!", quest.name));
+    for stack in &quest.reward {
+        add_item_to_inventory(state, &stack.kind, stack.qty);
+        let name = items::name(state, &stack.kind);
+        push_message(
+            state,
+            format!("{} gave you {} x{}!", npc.name, name, stack.qty),
+        );
+    }
+    if let Some(thanks) = &quest.thanks {
+        push_message(state, format!("{}: \"{}\"", npc.name, thanks));
+    }
+    let teaches_surf = state
+        .scenario
+        .as_ref()
+        .and_then(|scenario| scenario.manifest.surf_quest.as_deref())
+        == Some(quest.id.as_str());
+    if teaches_surf {
+        push_message(state, "You learned Surf! Walk onto water to cross it.");
+    }
+}
+
+/// Casts a line into the water in front of the player.
+fn cast_line(state: &mut AppState) -> DispatchResult<Effect> {
+    let facing_water = facing_tile(state).is_some_and(|(x, y)| state.map.is_water(x, y));
+    if !facing_water {
+        return DispatchResult::unchanged();
+    }
+    let roll = next_rand(state);
+    state.fishing = Some(FishingState::cast(roll));
+    DispatchResult::changed()
+}
+
+/// Reels the line in. With something biting that's a battle against a catch
+/// from the water pool; any earlier and the hook comes up empty.
+fn reel_in(state: &mut AppState) -> DispatchResult<Effect> {
+    let Some(fishing) = state.fishing.take() else {
+        return DispatchResult::unchanged();
+    };
+    if fishing.phase != FishingPhase::Bite {
+        push_message(state, "Not even a nibble...");
+        return DispatchResult::changed();
+    }
+    let pool = encounter_pool(state, true);
+    let result = start_wild_battle(state, pool);
+    if let Some(battle) = state.battle.as_mut().filter(|battle| !battle.shiny) {
+        battle.message = format!("You hooked a wild {}!", format_name(&battle.enemy_name));
+    }
+    result
+}
+
+/// Runs the line's clock. Returns whether the bite came or went.
+fn tick_fishing(state: &mut AppState) -> bool {
+    let Some(fishing) = state.fishing.as_mut() else {
+        return false;
+    };
+    let phase = fishing.phase;
+    if fishing.tick() {
+        state.fishing = None;
+        push_message(state, "It got away...");
+        return true;
+    }
+    fishing.phase != phase
+}
+
+fn enter_gauntlet(state: &mut AppState, spec: GauntletSpec) -> DispatchResult<Effect> {
+    if state.badges.contains(&spec.id) {
+        push_message(
+            state,
+            format!(
+                "You already hold the {} from the {}.",
+                spec.badge, spec.name
+            ),
+        );
+        return DispatchResult::changed();
+    }
+    let Some(first) = spec.trainer(0) else {
+        return DispatchResult::unchanged();
+    };
+    if let Some(intro) = &spec.intro {
+        push_message(state, intro.clone());
+    }
+    state.gauntlet = Some(GauntletRun {
+        id: spec.id,
+        stage: 0,
+        leveled: false,
+    });
+    start_trainer_battle(state, first)
+}
+
+/// Moves a gauntlet on after one of its battles: straight into the next
+/// battle after a win, out with the badge after the last one, and out empty
+/// handed after a loss. Returns the next battle when there is one.
+fn gauntlet_advance(
+    state: &mut AppState,
+    won: bool,
+    leveled: bool,
+) -> Option<DispatchResult<Effect>> {
+    let mut run = state.gauntlet.take()?;
+    run.leveled |= leveled;
+    let spec = state
+        .scenario
+        .as_ref()
+        .and_then(|scenario| scenario.gauntlet(&run.id))
+        .cloned()?;
+    if won {
+        if let Some(next) = spec.trainer(run.stage + 1) {
+            run.stage += 1;
+            apply_heal_policy(state, spec.heal);
+            state.gauntlet = Some(run);
+            return Some(start_trainer_battle(state, next));
+        }
+        if !state.badges.contains(&spec.id) {
+            state.badges.push(spec.id.clone());
+        }
+        push_message(
+            state,
+            format!(
+                "You cleared the {} and earned the {}!",
+                spec.name, spec.badge
+            ),
+        );
+    } else {
+        push_message(state, format!("Your run at the {} is over.", spec.name));
+    }
+    if run.leveled {
+        queue_evolution(state);
+    }
+    None
+}
+
+fn apply_heal_policy(state: &mut AppState, policy: HealPolicy) {
+    let percent = match policy {
+        HealPolicy::None => return,
+        HealPolicy::Full => 100,
+        HealPolicy::Percent { percent } => percent.min(100),
+    };
+    for member in &mut state.party {
+        let max_hp = calc_hp(member.info.hp, member.level).max(1);
+        let amount = (max_hp as u32 * percent as u32).div_ceil(100) as u16;
+        member.hp = member.hp.saturating_add(amount).min(max_hp);
+        if policy == HealPolicy::Full {
+            member.restore_pp(u8::MAX);
+        }
+    }
+    sync_legacy_from_active(state);
+    push_message(
+        state,
+        match policy {
+            HealPolicy::Full => "Your party was fully healed.".to_string(),
+            _ => format!("Your party recovered {percent}% HP."),
+        },
+    );
+}
+
+fn shop_move(state: &mut AppState, delta: i16) -> DispatchResult<Effect> {
+    let Some((spec, tab)) = state.shop.as_ref().and_then(|shop| {
+        let spec = state.scenario.as_ref()?.shop(&shop.shop_id)?;
+        Some((spec, shop.tab))
+    }) else {
+        return DispatchResult::unchanged();
+    };
+    let len = shop::rows(state, spec, tab).len() as i16;
+    let Some(shop) = state.shop.as_mut().filter(|_| len > 1) else {
+        return DispatchResult::unchanged();
+    };
+    shop.selected = (shop.selected as i16 + delta).rem_euclid(len) as usize;
+    DispatchResult::changed()
+}
+
+/// Buys or sells one of the selected item.
+fn shop_confirm(state: &mut AppState) -> DispatchResult<Effect> {
+    let Some((row, tab)) = state.shop.as_ref().and_then(|shop| {
+        let spec = state.scenario.as_ref()?.shop(&shop.shop_id)?;
+        let row = shop::rows(state, spec, shop.tab)
+            .get(shop.selected)?
+            .clone();
+        Some((row, shop.tab))
+    }) else {
+        return DispatchResult::unchanged();
+    };
+    let notice = match tab {
+        ShopTab::Buy if state.money < row.price => "Not enough money.".to_string(),
+        ShopTab::Buy => {
+            state.money -= row.price;
+            add_item_to_inventory(state, &row.kind, 1);
+            format!(
+                "Bought a {} for {}.",
+                items::name(state, &row.kind),
+                shop::format_money(row.price)
+            )
+        }
+        ShopTab::Sell => {
+            take_item(state, &row.kind);
+            state.money = state.money.saturating_add(row.price);
+            format!(
+                "Sold a {} for {}.",
+                items::name(state, &row.kind),
+                shop::format_money(row.price)
+            )
+        }
+    };
+    let remaining = match tab {
+        ShopTab::Buy => usize::MAX,
+        ShopTab::Sell => state.inventory.iter().filter(|stack| stack.qty > 0).count(),
+    };
+    if let Some(shop) = state.shop.as_mut() {
+        // Selling the last of a stack drops its row
+        shop.selected = shop.selected.min(remaining.saturating_sub(1));
+        shop.notice = Some(notice);
+    }
+    DispatchResult::changed()
+}
+
+fn start_trainer_battle(state: &mut AppState, spec: TrainerSpec) -> DispatchResult<Effect> {
+    let Some(first) = spec.party.first().cloned() else {
+        return DispatchResult::changed();
+    };
+    let result = start_battle(
+        state,
+        first.species.clone(),
+        first.level.clamp(1, MAX_LEVEL),
+        BattleKind::Trainer,
+    );
+    if let Some(battle) = state.battle.as_mut() {
+        let sent_out = format!("{} sent out {}!", spec.name, format_name(&first.species));
+        battle.message = match &spec.intro {
+            Some(intro) => format!("{}: \"{}\" {}", spec.name, intro, sent_out),
+            None => format!("{} wants to battle! {}", spec.name, sent_out),
+        };
+        battle.trainer = Some(TrainerState {
+            id: spec.id,
+            name: spec.name,
+            party: spec.party,
+            active: 0,
+            recovered: false,
+        });
+    }
+    result
+}
+
 fn start_battle(
     state: &mut AppState,
     enemy_name: String,
@@ -303,10 +961,17 @@ fn start_battle(
         player_hp,
         kind,
     ));
+    state.battle_log = BattleLog::new(state.tick);
+    let (x, y) = (state.player.x, state.player.y);
+    if let (Some(battle), Some(scenario)) = (state.battle.as_mut(), state.scenario.as_ref()) {
+        battle.zone = scenario.zone_at(x, y).map(|zone| zone.id.clone());
+        battle.backdrop = scenario.backdrop_at(x, y);
+    }
     if let Some(battle) = state.battle.as_mut() {
         battle.message = match battle.kind {
             BattleKind::Boss => format!("Boss {} appears!", format_name(&enemy_name)),
             BattleKind::Wild => format!("A wild {} appeared!", format_name(&enemy_name)),
+            BattleKind::Trainer => format!("{} was sent out!", format_name(&enemy_name)),
         };
     }
     DispatchResult::changed_with(Effect::LoadPokemon {
@@ -321,8 +986,25 @@ fn battle_menu_change(state: &mut AppState, delta: i16) -> DispatchResult<Effect
     };
     let menu_len = match stage {
         BattleStage::Menu => 5i16,
+        BattleStage::MoveMenu => {
+            let count = state
+                .active_member()
+                .map(|member| member.moves.len())
+                .unwrap_or(0) as i16;
+            if count == 0 {
+                return DispatchResult::unchanged();
+            }
+            count
+        }
         BattleStage::ItemMenu => {
-            let count = available_items(state).len() as i16;
+            let count = items::usable(state).len() as i16;
+            if count == 0 {
+                return DispatchResult::unchanged();
+            }
+            count
+        }
+        BattleStage::ReviveMenu => {
+            let count = fainted_members(state).len() as i16;
             if count == 0 {
                 return DispatchResult::unchanged();
             }
@@ -336,11 +1018,21 @@ fn battle_menu_change(state: &mut AppState, delta: i16) -> DispatchResult<Effect
             .as_ref()
             .map(|battle| battle.menu_index)
             .unwrap_or(0),
+        BattleStage::MoveMenu => state
+            .battle
+            .as_ref()
+            .map(|battle| battle.move_index)
+            .unwrap_or(0),
         BattleStage::ItemMenu => state
             .battle
             .as_ref()
             .map(|battle| battle.item_index)
             .unwrap_or(0),
+        BattleStage::ReviveMenu => state
+            .battle
+            .as_ref()
+            .map(|battle| battle.revive_index)
+            .unwrap_or(0),
         _ => 0,
     } as i16;
     let mut next = current_index + delta;
@@ -356,7 +1048,9 @@ fn battle_menu_change(state: &mut AppState, delta: i16) -> DispatchResult<Effect
     if let Some(battle) = state.battle.as_mut() {
         match stage {
             BattleStage::Menu => battle.menu_index = next as usize,
+            BattleStage::MoveMenu => battle.move_index = next as usize,
             BattleStage::ItemMenu => battle.item_index = next as usize,
+            BattleStage::ReviveMenu => battle.revive_index = next as usize,
             _ => {}
         }
     }
@@ -376,10 +1070,48 @@ fn set_battle_item_prompt(state: &mut AppState) {
     }
 }
 
+fn set_battle_move_prompt(state: &mut AppState) {
+    if let Some(battle) = state.battle.as_mut() {
+        battle.message = "Choose a move.".to_string();
+    }
+}
+
+/// Backs out of the move or item list to the main battle menu.
+fn close_battle_submenu(state: &mut AppState, submenu: BattleStage) -> DispatchResult<Effect> {
+    let Some(battle) = state
+        .battle
+        .as_mut()
+        .filter(|battle| battle.stage == submenu)
+    else {
+        return DispatchResult::unchanged();
+    };
+    battle.stage = BattleStage::Menu;
+    set_battle_menu_prompt(state);
+    DispatchResult::changed()
+}
+
+/// Backs out of the bag, or from picking a revive's target back into it.
+fn close_battle_item_menu(state: &mut AppState) -> DispatchResult<Effect> {
+    let Some(battle) = state
+        .battle
+        .as_mut()
+        .filter(|battle| battle.stage == BattleStage::ReviveMenu)
+    else {
+        return close_battle_submenu(state, BattleStage::ItemMenu);
+    };
+    battle.stage = BattleStage::ItemMenu;
+    set_battle_item_prompt(state);
+    DispatchResult::changed()
+}
+
 fn battle_confirm(state: &mut AppState) -> DispatchResult<Effect> {
     let Some(stage) = state.battle.as_ref().map(|battle| battle.stage) else {
         return DispatchResult::unchanged();
     };
+    // Pressing on skips whatever is left of the last hit's animation.
+    if let Some(battle) = state.battle.as_mut() {
+        battle.anims.clear();
+    }
 
     match stage {
         BattleStage::Intro => {
@@ -399,12 +1131,27 @@ fn battle_confirm(state: &mut AppState) -> DispatchResult<Effect> {
             let mut combo_effect: Option<Effect> = None;
             match menu_index {
                 0 => {
+                    let moves = state
+                        .active_member()
+                        .map(|member| member.moves.clone())
+                        .unwrap_or_default();
+                    if moves.iter().any(|mv| mv.pp > 0) {
+                        if let Some(battle) = state.battle.as_mut() {
+                            battle.stage = BattleStage::MoveMenu;
+                            battle.move_index = battle.move_index.min(moves.len() - 1);
+                        }
+                        set_battle_move_prompt(state);
+                        return DispatchResult::changed();
+                    }
+                    // Out of PP it has to struggle; with no moves at all
+                    // (an old save) it falls back to a plain attack
+                    let struggle =
+                        (!moves.is_empty()).then(|| KnownMove::struggle(MOVE_POWER as u16));
                     play_sound = true;
-                    combo_effect = start_combo_attack(state, None, None);
+                    combo_effect = start_combo_attack(state, None, None, struggle.as_ref());
                 }
                 1 => {
-                    let items = available_items(state);
-                    if items.is_empty() {
+                    if items::usable(state).is_empty() {
                         push_message(state, "Your bag is empty.");
                         set_battle_menu_prompt(state);
                     } else {
@@ -416,98 +1163,17 @@ fn battle_confirm(state: &mut AppState) -> DispatchResult<Effect> {
                     }
                 }
                 2 => {
-                    let kind = state
-                        .battle
-                        .as_ref()
-                        .map(|battle| battle.kind)
-                        .unwrap_or(BattleKind::Wild);
-                    if kind == BattleKind::Boss {
-                        push_message(state, "You can't catch this Pokemon!");
+                    if let Some(refusal) = catch_refusal(state) {
+                        push_message(state, refusal);
                         set_battle_menu_prompt(state);
                         return DispatchResult::changed();
                     }
-                    if pokeball_count(state) == 0 {
+                    let Some((ball, catch_rate)) = items::first_ball(state) else {
                         push_message(state, "No Poke Balls left.");
                         set_battle_menu_prompt(state);
                         return DispatchResult::changed();
-                    }
-                    take_pokeball(state);
-                    let (enemy_hp, enemy_hp_max, enemy_level, enemy_name) =
-                        match state.battle.as_ref() {
-                            Some(battle) => (
-                                battle.enemy_hp,
-                                battle.enemy_hp_max,
-                                battle.enemy_level,
-                                battle.enemy_name.clone(),
-                            ),
-                            None => return DispatchResult::unchanged(),
-                        };
-                    let hp_ratio = if enemy_hp_max == 0 {
-                        1.0
-                    } else {
-                        enemy_hp as f32 / enemy_hp_max as f32
                     };
-                    let mut chance = 0.2 + 0.6 * (1.0 - hp_ratio);
-                    chance = chance.clamp(0.2, 0.8);
-                    let roll = (next_rand(state) % 100) as f32 / 100.0;
-                    if roll <= chance {
-                        if state.party.len() >= 3 {
-                            let pending_damage = calc_damage(
-                                state,
-                                enemy_level,
-                                enemy_attack(state, enemy_level),
-                                player_defense(state),
-                            );
-                            if let Some(battle) = state.battle.as_mut() {
-                                battle.stage = BattleStage::EnemyTurn;
-                                battle.message = "Party full!".to_string();
-                                battle.pending_enemy_damage = Some(pending_damage);
-                            }
-                            return DispatchResult::changed();
-                        }
-                        if let Some(info) = state.enemy_info.clone() {
-                            let level = enemy_level.max(1);
-                            let max_hp = calc_hp(info.hp, level);
-                            let ability_id = ability_id_for_species(state, &info.name);
-                            state.party.push(PartyMember {
-                                info,
-                                level,
-                                exp: exp_for_level(level),
-                                hp: max_hp,
-                                ability_id,
-                                ability_cd: 0,
-                            });
-                            if let Some(battle) = state.battle.as_mut() {
-                                battle.captured = true;
-                                battle.stage = BattleStage::Victory;
-                                battle.message = format!("Caught {}!", format_name(&enemy_name));
-                            }
-                        } else {
-                            let pending_damage = calc_damage(
-                                state,
-                                enemy_level,
-                                enemy_attack(state, enemy_level),
-                                player_defense(state),
-                            );
-                            if let Some(battle) = state.battle.as_mut() {
-                                battle.stage = BattleStage::EnemyTurn;
-                                battle.message = "It slipped away!".to_string();
-                                battle.pending_enemy_damage = Some(pending_damage);
-                            }
-                        }
-                    } else {
-                        let pending_damage = calc_damage(
-                            state,
-                            enemy_level,
-                            enemy_attack(state, enemy_level),
-                            player_defense(state),
-                        );
-                        if let Some(battle) = state.battle.as_mut() {
-                            battle.stage = BattleStage::EnemyTurn;
-                            battle.message = "It broke free!".to_string();
-                            battle.pending_enemy_damage = Some(pending_damage);
-                        }
-                    }
+                    return throw_ball(state, &ball, catch_rate);
                 }
                 3 => {
                     let ability = active_ability_spec(state);
@@ -554,7 +1220,7 @@ fn battle_confirm(state: &mut AppState) -> DispatchResult<Effect> {
                             }
                         }
                     }
-                    combo_effect = start_combo_attack(state, ability_damage, ability_name);
+                    combo_effect = start_combo_attack(state, ability_damage, ability_name, None);
                 }
                 _ => {
                     let kind = state
@@ -562,8 +1228,12 @@ fn battle_confirm(state: &mut AppState) -> DispatchResult<Effect> {
                         .as_ref()
                         .map(|battle| battle.kind)
                         .unwrap_or(BattleKind::Wild);
-                    if kind == BattleKind::Boss {
-                        push_message(state, "Can't run from a boss!");
+                    if kind != BattleKind::Wild {
+                        let refusal = match kind {
+                            BattleKind::Trainer => "No running from a trainer battle!",
+                            _ => "Can't run from a boss!",
+                        };
+                        push_message(state, refusal);
                         set_battle_menu_prompt(state);
                         return DispatchResult::changed();
                     }
@@ -575,7 +1245,7 @@ fn battle_confirm(state: &mut AppState) -> DispatchResult<Effect> {
             }
             let mut effects = Vec::new();
             if play_sound {
-                effects.push(Effect::PlayAttackSound);
+                effects.extend(attack_sound(state));
             }
             if let Some(effect) = combo_effect {
                 effects.push(effect);
@@ -586,9 +1256,44 @@ fn battle_confirm(state: &mut AppState) -> DispatchResult<Effect> {
                 _ => DispatchResult::changed_with_many(effects),
             }
         }
+        BattleStage::MoveMenu => {
+            let move_index = state
+                .battle
+                .as_ref()
+                .map(|battle| battle.move_index)
+                .unwrap_or(0);
+            let Some(chosen) = state
+                .active_member()
+                .and_then(|member| member.moves.get(move_index))
+                .cloned()
+            else {
+                if let Some(battle) = state.battle.as_mut() {
+                    battle.stage = BattleStage::Menu;
+                }
+                set_battle_menu_prompt(state);
+                return DispatchResult::changed();
+            };
+            if chosen.pp == 0 {
+                push_message(
+                    state,
+                    format!("No PP left for {}!", format_name(&chosen.name)),
+                );
+                set_battle_move_prompt(state);
+                return DispatchResult::changed();
+            }
+            if let Some(known) = state
+                .active_member_mut()
+                .and_then(|member| member.moves.get_mut(move_index))
+            {
+                known.pp -= 1;
+            }
+            let mut effects: Vec<Effect> = attack_sound(state).into_iter().collect();
+            effects.extend(start_combo_attack(state, None, None, Some(&chosen)));
+            DispatchResult::changed_with_many(effects)
+        }
         BattleStage::ItemMenu => {
-            let items = available_items(state);
-            if items.is_empty() {
+            let bag = items::usable(state);
+            if bag.is_empty() {
                 if let Some(battle) = state.battle.as_mut() {
                     battle.stage = BattleStage::Menu;
                 }
@@ -607,7 +1312,29 @@ fn battle_confirm(state: &mut AppState) -> DispatchResult<Effect> {
                 None => return DispatchResult::unchanged(),
             };
 
-            let (kind, _) = items.get(item_index).copied().unwrap_or_else(|| items[0]);
+            let (kind, _) = bag.get(item_index).unwrap_or(&bag[0]).clone();
+            let heal = match items::effect(state, &kind) {
+                Some(ItemEffect::Heal { hp }) => hp,
+                Some(ItemEffect::RestorePp { pp }) => {
+                    return use_pp_item(state, &kind, pp, enemy_level);
+                }
+                Some(ItemEffect::Revive { .. }) => return open_revive_menu(state),
+                Some(ItemEffect::Boost { stat, stages }) => {
+                    return use_boost_item(state, &kind, stat, stages, enemy_level);
+                }
+                Some(ItemEffect::Ball { catch_rate }) => {
+                    if let Some(refusal) = catch_refusal(state) {
+                        if let Some(battle) = state.battle.as_mut() {
+                            battle.stage = BattleStage::Menu;
+                        }
+                        push_message(state, refusal);
+                        set_battle_menu_prompt(state);
+                        return DispatchResult::changed();
+                    }
+                    return throw_ball(state, &kind, catch_rate);
+                }
+                None => return DispatchResult::unchanged(),
+            };
 
             if player_hp >= player_hp_max {
                 if let Some(battle) = state.battle.as_mut() {
@@ -618,7 +1345,7 @@ fn battle_confirm(state: &mut AppState) -> DispatchResult<Effect> {
                 return DispatchResult::changed();
             }
 
-            if !take_item(state, kind) {
+            if !take_item(state, &kind) {
                 if let Some(battle) = state.battle.as_mut() {
                     battle.stage = BattleStage::Menu;
                 }
@@ -627,9 +1354,7 @@ fn battle_confirm(state: &mut AppState) -> DispatchResult<Effect> {
                 return DispatchResult::changed();
             }
 
-            let heal = kind
-                .heal_amount()
-                .min(player_hp_max.saturating_sub(player_hp));
+            let heal = heal.min(player_hp_max.saturating_sub(player_hp));
             let new_hp = player_hp.saturating_add(heal);
             let pending_damage = calc_damage(
                 state,
@@ -638,16 +1363,64 @@ fn battle_confirm(state: &mut AppState) -> DispatchResult<Effect> {
                 player_defense(state),
             );
 
+            let name = items::name(state, &kind);
             if let Some(battle) = state.battle.as_mut() {
                 battle.player_hp = new_hp;
                 battle.stage = BattleStage::EnemyTurn;
-                battle.message = format!("Used {}! Restored {} HP.", kind.label(), heal);
+                battle.message = format!("Used {}! Restored {} HP.", name, heal);
                 battle.pending_enemy_damage = Some(pending_damage);
             }
             sync_active_hp_from_battle(state);
 
             DispatchResult::changed()
         }
+        BattleStage::ReviveMenu => {
+            let (revive_index, item_index, enemy_level) = match state.battle.as_ref() {
+                Some(battle) => (battle.revive_index, battle.item_index, battle.enemy_level),
+                None => return DispatchResult::unchanged(),
+            };
+            let fainted = fainted_members(state);
+            let item = items::usable(state)
+                .into_iter()
+                .nth(item_index)
+                .and_then(|(kind, _)| match items::effect(state, &kind) {
+                    Some(ItemEffect::Revive { percent }) => Some((kind, percent)),
+                    _ => None,
+                });
+            let (Some(&member_index), Some((kind, percent))) = (fainted.get(revive_index), item)
+            else {
+                if let Some(battle) = state.battle.as_mut() {
+                    battle.stage = BattleStage::Menu;
+                }
+                set_battle_menu_prompt(state);
+                return DispatchResult::changed();
+            };
+            take_item(state, &kind);
+            let name = items::name(state, &kind);
+            let Some(member) = state.party.get_mut(member_index) else {
+                return DispatchResult::unchanged();
+            };
+            let max_hp = calc_hp(member.info.hp, member.level).max(1);
+            member.hp = (max_hp as u32 * percent as u32 / 100).clamp(1, max_hp as u32) as u16;
+            let message = format!(
+                "Used {}! {} is back on its feet with {} HP.",
+                name,
+                format_name(member.name()),
+                member.hp
+            );
+            let pending_damage = calc_damage(
+                state,
+                enemy_level,
+                enemy_attack(state, enemy_level),
+                player_defense(state),
+            );
+            if let Some(battle) = state.battle.as_mut() {
+                battle.stage = BattleStage::EnemyTurn;
+                battle.message = message;
+                battle.pending_enemy_damage = Some(pending_damage);
+            }
+            DispatchResult::changed()
+        }
         BattleStage::PlayerCombo => {
             let combo_empty = state
                 .battle
@@ -659,6 +1432,7 @@ fn battle_confirm(state: &mut AppState) -> DispatchResult<Effect> {
                     battle.stage = BattleStage::Menu;
                 }
                 set_battle_menu_prompt(state);
+                weather_end_of_turn(state);
                 return DispatchResult::changed();
             }
 
@@ -692,7 +1466,7 @@ fn battle_confirm(state: &mut AppState) -> DispatchResult<Effect> {
                 .as_ref()
                 .map(|battle| battle.enemy_level)
                 .unwrap_or(5);
-            let mut damage = pending_damage.unwrap_or_else(|| {
+            let damage = pending_damage.unwrap_or_else(|| {
                 calc_damage(
                     state,
                     enemy_level,
@@ -700,6 +1474,12 @@ fn battle_confirm(state: &mut AppState) -> DispatchResult<Effect> {
                     player_defense(state),
                 )
             });
+            let enemy_type = enemy_types(state).into_iter().next();
+            let mut damage = weather_scaled(state, damage, enemy_type.as_deref());
+            let missed = weather_miss(state);
+            if missed {
+                damage = 0;
+            }
             let mut fainted = false;
             if let Some(battle) = state.battle.as_mut() {
                 if battle.guard_turns > 0 && battle.guard_pct > 0 {
@@ -711,8 +1491,17 @@ fn battle_confirm(state: &mut AppState) -> DispatchResult<Effect> {
                     }
                 }
                 battle.pending_enemy_damage = None;
+                let from_hp = battle.player_hp;
                 battle.player_hp = battle.player_hp.saturating_sub(damage);
                 fainted = battle.player_hp == 0;
+                let anim = HitAnim::new(
+                    Side::Player,
+                    from_hp,
+                    battle.player_hp,
+                    battle.player_hp_max,
+                    Effectiveness::Normal,
+                );
+                replay::push_hit(&mut state.battle_log, state.tick, battle, anim);
             }
             sync_active_hp_from_battle(state);
             tick_ability_cooldowns(state);
@@ -735,16 +1524,18 @@ fn battle_confirm(state: &mut AppState) -> DispatchResult<Effect> {
                 battle.stage = BattleStage::Menu;
             }
             if !fainted {
-                let enemy_name = state
+                let enemy = state
                     .battle
                     .as_ref()
-                    .map(|battle| battle.enemy_name.clone())
+                    .map(enemy_label)
                     .unwrap_or_else(|| "Enemy".to_string());
-                push_message(
-                    state,
-                    format!("Wild {} hit you for {}!", format_name(&enemy_name), damage),
-                );
+                if missed {
+                    push_message(state, format!("{enemy}'s attack missed!"));
+                } else {
+                    push_message(state, format!("{} hit you for {}!", enemy, damage));
+                }
                 set_battle_menu_prompt(state);
+                weather_end_of_turn(state);
             }
             DispatchResult::changed()
         }
@@ -753,6 +1544,8 @@ fn battle_confirm(state: &mut AppState) -> DispatchResult<Effect> {
 }
 
 fn end_battle(state: &mut AppState) -> DispatchResult<Effect> {
+    let mut effect = None;
+    let mut leveled = false;
     if let Some(battle) = state.battle.take() {
         let mut message = match battle.stage {
             BattleStage::Victory => battle.message.clone(),
@@ -773,21 +1566,25 @@ fn end_battle(state: &mut AppState) -> DispatchResult<Effect> {
                     if state.wild_wins == RELIC_WINS && !state.has_relic {
                         state.has_relic = true;
                         relic_triggered = true;
+                        state.speedrun.split(Milestone::Relic);
                     }
                 }
 
                 if battle.kind == BattleKind::Boss {
                     state.boss_defeated = true;
                     message = "Demo complete! You beat the boss!".to_string();
-                } else if !battle.captured {
-                    let old_max = state.player_max_hp();
-                    let (gained, levels) = award_exp(state, battle.enemy_level);
-                    let new_max = state.player_max_hp();
-                    if let Some(member) = state.active_member_mut() {
-                        let hp_bonus = new_max.saturating_sub(old_max);
-                        member.hp = member.hp.saturating_add(hp_bonus).min(new_max);
+                    if state.speedrun.split(Milestone::Boss) {
+                        message = format!(
+                            "{message} Final time {}.",
+                            speedrun::format_time(state.speedrun.elapsed_ms)
+                        );
+                        effect = Some(Effect::ExportSplits {
+                            contents: state.speedrun.splits_report(&state.map.name),
+                        });
                     }
-                    sync_legacy_from_active(state);
+                } else if !battle.captured {
+                    let (gained, levels) = gain_battle_exp(state, battle.enemy_level);
+                    leveled = levels > 0;
                     if levels > 0 {
                         message = format!(
                             "{} won! Gained {} XP. Leveled up to {}!",
@@ -807,28 +1604,219 @@ fn end_battle(state: &mut AppState) -> DispatchResult<Effect> {
                 if relic_triggered && battle.kind != BattleKind::Boss {
                     message = format!("{message} You found a relic!");
                 }
+                if let Some(trainer) = &battle.trainer {
+                    let defeat = match state.gauntlet.as_ref() {
+                        Some(run) => state
+                            .scenario
+                            .as_ref()
+                            .and_then(|scenario| scenario.gauntlet(&run.id))
+                            .and_then(|spec| spec.battles.get(run.stage))
+                            .and_then(|battle| battle.defeat.clone()),
+                        None => {
+                            state.defeated_trainers.insert(trainer.id.clone());
+                            state
+                                .scenario
+                                .as_ref()
+                                .and_then(|scenario| scenario.trainer(&trainer.id))
+                                .and_then(|spec| spec.defeat.clone())
+                        }
+                    };
+                    message = match defeat {
+                        Some(line) => format!("{}: \"{}\" {message}", trainer.name, line),
+                        None => format!("{} was defeated! {message}", trainer.name),
+                    };
+                }
+                if !battle.captured {
+                    let prize = shop::prize_money(&battle);
+                    state.money = state.money.saturating_add(prize);
+                    message = format!("{message} Got {}.", shop::format_money(prize));
+                }
             }
             BattleStage::Escape => {
                 sync_active_hp_from_battle(state);
             }
+            BattleStage::Defeat if state.hardcore => return hardcore_game_over(state),
             BattleStage::Defeat => {
                 for member in &mut state.party {
                     let max_hp = calc_hp(member.info.hp, member.level).max(1);
                     member.hp = max_hp;
+                    member.restore_pp(u8::MAX);
                 }
                 sync_legacy_from_active(state);
             }
-            _ => {}
+            _ => {}
+        }
+        push_message(state, message);
+        if state.hardcore {
+            bury_fainted(state);
+        }
+        if state.gauntlet.is_some() {
+            let won = battle.stage == BattleStage::Victory;
+            if let Some(next) = gauntlet_advance(state, won, leveled) {
+                return next;
+            }
+        } else if leveled {
+            queue_evolution(state);
         }
-        push_message(state, message);
     }
     state.mode = GameMode::Overworld;
     state.enemy_info = None;
     state.enemy_sprite.reset();
     state.steps_since_encounter = 0;
+    match effect {
+        Some(effect) => DispatchResult::changed_with(effect),
+        None => DispatchResult::changed(),
+    }
+}
+
+/// Ask to evolve the active member once it has reached its species' evolve
+/// level. Declining just waits for the next level-up to ask again.
+fn queue_evolution(state: &mut AppState) {
+    let member_index = state.active_party_index;
+    let Some(member) = state.party.get(member_index) else {
+        return;
+    };
+    let Some(evolution) = member
+        .info
+        .evolution
+        .as_ref()
+        .filter(|evolution| member.level >= evolution.level)
+    else {
+        return;
+    };
+    state.evolution = Some(EvolutionPrompt {
+        member_index,
+        into: evolution.into.clone(),
+        loading: false,
+    });
+}
+
+fn evolution_confirm(state: &mut AppState) -> DispatchResult<Effect> {
+    let Some(prompt) = state.evolution.as_mut().filter(|prompt| !prompt.loading) else {
+        return DispatchResult::unchanged();
+    };
+    prompt.loading = true;
+    DispatchResult::changed_with(Effect::LoadEvolution {
+        member_index: prompt.member_index,
+        name: prompt.into.clone(),
+    })
+}
+
+fn evolution_cancel(state: &mut AppState) -> DispatchResult<Effect> {
+    if state.evolution.as_ref().is_none_or(|prompt| prompt.loading) {
+        return DispatchResult::unchanged();
+    }
+    let name = state
+        .evolution
+        .take()
+        .and_then(|prompt| state.party.get(prompt.member_index))
+        .map(|member| format_name(member.name()));
+    if let Some(name) = name {
+        push_message(state, format!("{name} stopped evolving."));
+    }
     DispatchResult::changed()
 }
 
+/// Swap the evolved species in, keeping level, moves and damage taken, and
+/// reload the sprites that show it.
+fn evolution_loaded(
+    state: &mut AppState,
+    member_index: usize,
+    info: crate::state::PokemonInfo,
+) -> DispatchResult<Effect> {
+    state.evolution = None;
+    let Some(member) = state.party.get_mut(member_index) else {
+        return DispatchResult::changed();
+    };
+    let old_name = format_name(member.name());
+    let old_max = calc_hp(member.info.hp, member.level).max(1);
+    let damage = old_max.saturating_sub(member.hp);
+    member.info = info;
+    let new_max = calc_hp(member.info.hp, member.level).max(1);
+    if member.hp > 0 {
+        member.hp = new_max.saturating_sub(damage).max(1);
+    }
+    let new_name = format_name(&member.info.name);
+    let learned = member.learn_moves_since(member.level.saturating_sub(1));
+    push_message(
+        state,
+        format!("Congratulations! {old_name} evolved into {new_name}!"),
+    );
+    for move_name in learned {
+        push_message(
+            state,
+            format!("{new_name} learned {}!", format_name(&move_name)),
+        );
+    }
+
+    let mut effects = Vec::new();
+    ensure_party_sprites(state);
+    if let Some(slot) = state.party_sprites.get_mut(member_index) {
+        *slot = SpriteState::default();
+    }
+    effects.extend(maybe_request_party_sprite(state));
+    if member_index == state.active_party_index {
+        sync_legacy_from_active(state);
+        state.player_sprite.reset();
+        let url = state
+            .active_member()
+            .and_then(|member| sprite_url_for(&member.info, SpriteTarget::Player));
+        if let Some(url) = url {
+            state.player_sprite.loading = true;
+            effects.push(Effect::LoadSprite {
+                target: SpriteTarget::Player,
+                url,
+            });
+        }
+    }
+    DispatchResult::changed_with_many(effects)
+}
+
+/// Hardcore: drop party members left at 0 HP after a battle, keeping sprites
+/// and the active index lined up with the survivors.
+fn bury_fainted(state: &mut AppState) {
+    ensure_party_sprites(state);
+    let mut lost = Vec::new();
+    let mut index = 0;
+    while index < state.party.len() {
+        if state.party[index].hp > 0 {
+            index += 1;
+            continue;
+        }
+        lost.push(state.party.remove(index).name().to_string());
+        state.party_sprites.remove(index);
+        if index < state.active_party_index {
+            state.active_party_index -= 1;
+        }
+    }
+    if state.active_party_index >= state.party.len() {
+        state.active_party_index = 0;
+    }
+    sync_legacy_from_active(state);
+    for name in lost {
+        push_message(state, format!("{} was lost.", format_name(&name)));
+    }
+}
+
+/// Hardcore defeat: the run is over, so back to the menu and delete the save.
+fn hardcore_game_over(state: &mut AppState) -> DispatchResult<Effect> {
+    state.mode = GameMode::MainMenu;
+    state.menu = Some(MenuState {
+        selected: 0,
+        has_save: false,
+        save_hardcore: false,
+    });
+    state.pause_menu.is_open = false;
+    state.speedrun.running = false;
+    state.enemy_info = None;
+    state.enemy_sprite.reset();
+    state.steps_since_encounter = 0;
+    state.message = None;
+    state.message_queue.clear();
+    push_message(state, "Your whole party fell. The hardcore run is over.");
+    DispatchResult::changed_with(Effect::DeleteSave)
+}
+
 fn pokemon_loaded(
     state: &mut AppState,
     target: SpriteTarget,
@@ -842,14 +1830,18 @@ fn pokemon_loaded(
                 let level = state.player_level.max(1);
                 let max_hp = calc_hp(info.hp, level).max(1);
                 let exp = state.player_exp.max(exp_for_level(level));
-                state.party.push(PartyMember {
+                let mut member = PartyMember {
                     info: info.clone(),
                     level,
                     exp,
                     hp: max_hp,
                     ability_id,
                     ability_cd: 0,
-                });
+                    moves: Vec::new(),
+                    nickname: None,
+                };
+                member.learn_starting_moves();
+                state.party.push(member);
                 state.active_party_index = 0;
             } else if let Some(member) = state.active_member_mut() {
                 member.info = info.clone();
@@ -860,6 +1852,10 @@ fn pokemon_loaded(
                 if member.ability_id.is_none() {
                     member.ability_id = ability_id;
                 }
+                // Saves from before moves came with an empty move list
+                if member.moves.is_empty() {
+                    member.learn_starting_moves();
+                }
             }
             sync_legacy_from_active(state);
             if let Some(sprite_url) = sprite_url_for(&info, target) {
@@ -873,7 +1869,7 @@ fn pokemon_loaded(
         }
         SpriteTarget::Enemy => {
             state.enemy_info = Some(info.clone());
-            if let Some(battle) = state.battle.as_mut() {
+            if let Some(battle) = live_battle(state) {
                 let enemy_hp = calc_hp(info.hp, battle.enemy_level).max(1);
                 battle.enemy_hp_max = enemy_hp;
                 battle.enemy_hp = enemy_hp;
@@ -903,7 +1899,7 @@ fn pokemon_error(
             state.player_sprite.loading = false;
         }
         SpriteTarget::Enemy => {
-            if let Some(battle) = state.battle.as_mut() {
+            if let Some(battle) = live_battle(state) {
                 battle.stage = BattleStage::Escape;
                 battle.message = format!("{name} fled.");
             }
@@ -916,7 +1912,7 @@ fn pokemon_error(
 fn sprite_loaded(
     state: &mut AppState,
     target: SpriteTarget,
-    sprite: crate::sprite::SpriteData,
+    sprite: sprite_cache::SpriteData,
 ) -> DispatchResult<Effect> {
     match target {
         SpriteTarget::Player => {
@@ -944,7 +1940,7 @@ fn sprite_error(state: &mut AppState, target: SpriteTarget, error: &str) -> Disp
             state.player_sprite.loading = false;
         }
         SpriteTarget::Enemy => {
-            if let Some(battle) = state.battle.as_mut() {
+            if let Some(battle) = live_battle(state) {
                 battle.message = format!("Sprite error: {error}");
             }
             state.enemy_sprite.loading = false;
@@ -962,7 +1958,23 @@ fn sprite_url_for(info: &crate::state::PokemonInfo, _target: SpriteTarget) -> Op
 
 fn tick_animation(state: &mut AppState) -> DispatchResult<Effect> {
     state.tick = state.tick.wrapping_add(1);
-    let mut changed = tick_messages(state);
+    let DispatchResult {
+        mut changed,
+        mut effects,
+    } = if state.replay.is_some() {
+        replay_tick(state)
+    } else {
+        tick_messages(state)
+    };
+    changed = state.speedrun.advance(TICK_MS) || changed;
+    changed = tick_fishing(state) || changed;
+    let speed = state.settings.anim_speed;
+    if let Some(battle) = state.battle.as_mut() {
+        changed = anim::advance(battle, speed) || changed;
+        if state.replay.is_none() {
+            replay::capture(&mut state.battle_log, state.tick, battle);
+        }
+    }
     let mut sprite_changed = advance_sprite(&mut state.enemy_sprite);
 
     if state.mode != GameMode::Overworld {
@@ -987,10 +1999,10 @@ fn tick_animation(state: &mut AppState) -> DispatchResult<Effect> {
         changed = true;
     }
 
-    if let Some(effect) = maybe_request_party_sprite(state) {
-        return DispatchResult::changed_with(effect);
-    }
-    if changed {
+    effects.extend(maybe_request_party_sprite(state));
+    if !effects.is_empty() {
+        DispatchResult::changed_with_many(effects)
+    } else if changed {
         DispatchResult::changed()
     } else {
         DispatchResult::unchanged()
@@ -1001,16 +2013,8 @@ fn advance_sprite(sprite: &mut crate::state::SpriteState) -> bool {
     let Some(data) = sprite.sprite.as_ref() else {
         return false;
     };
-    if data.frames.len() <= 1 {
-        return false;
-    }
     const FRAME_STEP: u64 = 1;
-    sprite.frame_tick = sprite.frame_tick.wrapping_add(1);
-    if sprite.frame_tick % FRAME_STEP == 0 {
-        sprite.frame_index = (sprite.frame_index + 1) % data.frames.len();
-        return true;
-    }
-    false
+    data.advance(&mut sprite.frame_index, &mut sprite.frame_tick, FRAME_STEP)
 }
 
 fn next_rand(state: &mut AppState) -> u32 {
@@ -1018,7 +2022,13 @@ fn next_rand(state: &mut AppState) -> u32 {
         .rng_seed
         .wrapping_mul(6364136223846793005)
         .wrapping_add(1);
-    (state.rng_seed >> 32) as u32
+    let roll = (state.rng_seed >> 32) as u32;
+    if state.battle.is_some() && state.replay.is_none() {
+        state
+            .battle_log
+            .record(state.tick, replay::LogEvent::Roll(roll));
+    }
+    roll
 }
 
 fn push_message(state: &mut AppState, message: impl Into<String>) {
@@ -1033,14 +2043,147 @@ fn push_message(state: &mut AppState, message: impl Into<String>) {
     }
 }
 
-fn tick_messages(_state: &mut AppState) -> bool {
-    false
+/// With auto-advance on, moves past the message on screen (the overlay first,
+/// then the battle box) once it has been up for the configured delay. The
+/// delay only starts once the battle's hit animations have played.
+fn tick_messages(state: &mut AppState) -> DispatchResult<Effect> {
+    let battle_waiting = state.mode == GameMode::Battle
+        && state.battle.as_ref().is_some_and(|battle| {
+            !anim::is_playing(battle)
+                && !matches!(
+                    battle.stage,
+                    BattleStage::Menu
+                        | BattleStage::MoveMenu
+                        | BattleStage::ItemMenu
+                        | BattleStage::ReviveMenu
+                )
+        });
+    let waiting = state.message.is_some() || battle_waiting;
+    if !state.settings.pacing.auto_advance || state.pause_menu.is_open || !waiting {
+        state.message_timer = 0;
+        return DispatchResult::unchanged();
+    }
+
+    state.message_timer = state.message_timer.saturating_add(1);
+    if state.message_timer < state.settings.pacing.delay_ticks() {
+        return DispatchResult::unchanged();
+    }
+    state.message_timer = 0;
+    if state.message.is_some() {
+        message_next(state)
+    } else {
+        battle_confirm(state)
+    }
+}
+
+/// The battle being fought; a replay's frames come from the log, so loads
+/// finishing mid-replay leave them alone.
+fn live_battle(state: &mut AppState) -> Option<&mut BattleState> {
+    if state.replay.is_some() {
+        return None;
+    }
+    state.battle.as_mut()
+}
+
+/// Plays the last battle back from its log, on the battle screen.
+fn replay_start(state: &mut AppState) -> DispatchResult<Effect> {
+    if state.mode != GameMode::Overworld {
+        push_message(state, "Finish this battle before replaying one.");
+        return DispatchResult::changed();
+    }
+    let Some((replay, battle)) = ReplayState::start(&state.battle_log) else {
+        push_message(state, "No battle to replay yet.");
+        return DispatchResult::changed();
+    };
+    state.pause_menu.is_open = false;
+    let name = battle.enemy_name.clone();
+    state.replay = Some(replay);
+    state.battle = Some(battle);
+    state.mode = GameMode::Battle;
+    state.enemy_info = None;
+    state.enemy_sprite.reset();
+    state.enemy_sprite.loading = true;
+    DispatchResult::changed_with(Effect::LoadPokemon {
+        target: SpriteTarget::Enemy,
+        name,
+    })
+}
+
+fn replay_stop(state: &mut AppState) -> DispatchResult<Effect> {
+    if state.replay.take().is_none() {
+        return DispatchResult::unchanged();
+    }
+    state.battle = None;
+    state.mode = GameMode::Overworld;
+    state.enemy_info = None;
+    state.enemy_sprite.reset();
+    DispatchResult::changed()
+}
+
+/// A tick of the replay: plays the entries that came due, fetches the next
+/// trainer Pokemon when a frame sends one out, and ends once it's all shown.
+fn replay_tick(state: &mut AppState) -> DispatchResult<Effect> {
+    let (Some(replay), Some(battle)) = (state.replay.as_mut(), state.battle.as_mut()) else {
+        return DispatchResult::unchanged();
+    };
+    let enemy = battle.enemy_name.clone();
+    if !replay.advance(&state.battle_log, battle) {
+        if replay.finished(&state.battle_log, battle) {
+            return replay_stop(state);
+        }
+        return DispatchResult::unchanged();
+    }
+    if battle.enemy_name == enemy {
+        return DispatchResult::changed();
+    }
+    let name = battle.enemy_name.clone();
+    state.enemy_info = None;
+    state.enemy_sprite.reset();
+    state.enemy_sprite.loading = true;
+    DispatchResult::changed_with(Effect::LoadPokemon {
+        target: SpriteTarget::Enemy,
+        name,
+    })
+}
+
+/// A pad button goes through the same screen routing as a key press. What
+/// that comes up with is dispatched like any other action, so a Quit from
+/// the main menu still ends the app.
+fn gamepad_button(state: &mut AppState, button: PadButton) -> DispatchResult<Effect> {
+    let Some(input) = gamepad::input(state.settings.gamepad, button) else {
+        return DispatchResult::unchanged();
+    };
+    let effects: Vec<Effect> = ui::handle_input(input, state)
+        .actions
+        .into_iter()
+        .map(|action| Effect::Dispatch { action })
+        .collect();
+    if effects.is_empty() {
+        return DispatchResult::unchanged();
+    }
+    DispatchResult::changed_with_many(effects)
+}
+
+/// Opens the options screen over the pause menu, on its first row.
+fn options_open(state: &mut AppState) -> DispatchResult<Effect> {
+    state.options = OptionsScreenState {
+        is_open: true,
+        selected: 0,
+    };
+    DispatchResult::changed()
+}
+
+/// The attack sound at the chosen volume, or nothing when it's muted.
+fn attack_sound(state: &AppState) -> Option<Effect> {
+    let volume = state.settings.volume;
+    (volume > 0).then_some(Effect::PlayAttackSound { volume })
 }
 
 fn message_next(state: &mut AppState) -> DispatchResult<Effect> {
     if state.message.is_none() {
         return DispatchResult::unchanged();
     }
+    state.message_timer = 0;
     if let Some(next) = state.message_queue.pop_front() {
         state.message = Some(next);
     } else {
@@ -1140,15 +2283,54 @@ fn boss_level(state: &AppState) -> u8 {
 }
 
 fn calc_damage(state: &mut AppState, level: u8, attack: u16, defense: u16) -> u16 {
+    calc_damage_with_power(state, level, attack, defense, MOVE_POWER)
+}
+
+fn calc_damage_with_power(
+    state: &mut AppState,
+    level: u8,
+    attack: u16,
+    defense: u16,
+    power: u32,
+) -> u16 {
     let level = level.max(1) as u32;
     let attack = attack.max(1) as u32;
     let defense = defense.max(1) as u32;
-    let base = (((2 * level / 5 + 2) * MOVE_POWER * attack) / defense) / 50 + 2;
+    let base = (((2 * level / 5 + 2) * power.max(1) * attack) / defense) / 50 + 2;
     let variance = 85 + (next_rand(state) % 16); // 85..=100
     let damage = base * variance / 100;
     damage.max(1) as u16
 }
 
+/// Damage of a chosen move against the enemy out: half again with a type
+/// match (STAB), then scaled by the type chart against the enemy's types.
+fn move_damage(
+    state: &mut AppState,
+    level: u8,
+    attack: u16,
+    defense: u16,
+    chosen: &KnownMove,
+    attacker_types: &[String],
+) -> (u16, Effectiveness) {
+    let defender_types = state
+        .enemy_info
+        .as_ref()
+        .map(|info| info.types.clone())
+        .unwrap_or_default();
+    let percent = type_multiplier(&chosen.move_type, &defender_types);
+    let effectiveness = Effectiveness::from_percent(percent);
+    if percent == 0 {
+        return (0, effectiveness);
+    }
+    let mut damage =
+        calc_damage_with_power(state, level, attack, defense, chosen.power as u32) as u32;
+    if attacker_types.contains(&chosen.move_type) {
+        damage = damage * 3 / 2;
+    }
+    damage = damage * percent / 100 * state.weather.damage_percent(&chosen.move_type) / 100;
+    (damage.clamp(1, u16::MAX as u32) as u16, effectiveness)
+}
+
 fn player_defense(state: &AppState) -> u16 {
     let (base, level) = state
         .active_member()
@@ -1160,7 +2342,11 @@ fn player_defense(state: &AppState) -> u16 {
                 .map(|info| (info.defense, state.player_level))
         })
         .unwrap_or((10, state.player_level));
-    calc_stat(base, level)
+    let stages = state
+        .battle
+        .as_ref()
+        .map_or(0, |battle| battle.defense_stage);
+    items::boosted(calc_stat(base, level), stages)
 }
 
 fn enemy_attack(state: &AppState, enemy_level: u8) -> u16 {
@@ -1181,12 +2367,93 @@ fn enemy_defense(state: &AppState, enemy_level: u8) -> u16 {
     calc_stat(base, enemy_level)
 }
 
+fn enemy_types(state: &AppState) -> Vec<String> {
+    state
+        .enemy_info
+        .as_ref()
+        .map(|info| info.types.clone())
+        .unwrap_or_default()
+}
+
+/// `damage` scaled by the weather for an attack of `move_type`. Plain attacks
+/// count as the attacker's first type.
+fn weather_scaled(state: &AppState, damage: u16, move_type: Option<&str>) -> u16 {
+    let percent = move_type.map_or(100, |kind| state.weather.damage_percent(kind));
+    if damage == 0 || percent == 100 {
+        return damage;
+    }
+    (damage as u32 * percent / 100).clamp(1, u16::MAX as u32) as u16
+}
+
+/// Whether the weather throws an attack off.
+fn weather_miss(state: &mut AppState) -> bool {
+    let accuracy = state.weather.accuracy_percent();
+    accuracy < 100 && next_rand(state) % 100 >= accuracy
+}
+
+/// End of a round: a sandstorm wears down both Pokemon out. It leaves them
+/// at 1 HP at worst, so a round never ends in a faint nobody saw coming.
+fn weather_end_of_turn(state: &mut AppState) {
+    let weather = state.weather;
+    let player_types = state
+        .active_member()
+        .map(|member| member.info.types.clone())
+        .or_else(|| state.player_info.as_ref().map(|info| info.types.clone()))
+        .unwrap_or_default();
+    let enemy_types = enemy_types(state);
+    let player_name = format_name(&state.player_name());
+    let Some(battle) = state.battle.as_mut() else {
+        return;
+    };
+    let mut notes = Vec::new();
+    let chip = weather
+        .chip_damage(&player_types, battle.player_hp_max)
+        .min(battle.player_hp.saturating_sub(1));
+    if chip > 0 {
+        let from_hp = battle.player_hp;
+        battle.player_hp -= chip;
+        let anim = HitAnim::new(
+            Side::Player,
+            from_hp,
+            battle.player_hp,
+            battle.player_hp_max,
+            Effectiveness::Normal,
+        );
+        replay::push_hit(&mut state.battle_log, state.tick, battle, anim);
+        notes.push(format!("{player_name} is buffeted by the sandstorm!"));
+    }
+    let chip = weather
+        .chip_damage(&enemy_types, battle.enemy_hp_max)
+        .min(battle.enemy_hp.saturating_sub(1));
+    if chip > 0 {
+        let from_hp = battle.enemy_hp;
+        battle.enemy_hp -= chip;
+        let anim = HitAnim::new(
+            Side::Enemy,
+            from_hp,
+            battle.enemy_hp,
+            battle.enemy_hp_max,
+            Effectiveness::Normal,
+        );
+        replay::push_hit(&mut state.battle_log, state.tick, battle, anim);
+        notes.push(format!(
+            "{} is buffeted by the sandstorm!",
+            enemy_label(battle)
+        ));
+    }
+    sync_active_hp_from_battle(state);
+    for note in notes {
+        push_message(state, note);
+    }
+}
+
 fn start_combo_attack(
     state: &mut AppState,
     ability_damage: Option<u16>,
     ability_name: Option<String>,
+    chosen: Option<&KnownMove>,
 ) -> Option<Effect> {
-    let mut hits = build_combo_hits(state, ability_damage, ability_name.as_deref());
+    let mut hits = build_combo_hits(state, ability_damage, ability_name.as_deref(), chosen);
     if hits.is_empty() {
         if let Some(battle) = state.battle.as_mut() {
             battle.stage = BattleStage::Defeat;
@@ -1211,10 +2478,13 @@ fn start_combo_attack(
     effect
 }
 
+/// The round's hits in speed order. The active member uses the ability or
+/// the `chosen` move; the rest of the party chips in with plain attacks.
 fn build_combo_hits(
     state: &mut AppState,
     ability_damage: Option<u16>,
     ability_name: Option<&str>,
+    chosen: Option<&KnownMove>,
 ) -> Vec<ComboHit> {
     let enemy_level = match state.battle.as_ref() {
         Some(battle) => battle.enemy_level,
@@ -1270,11 +2540,12 @@ fn build_combo_hits(
     for (actor, _) in ordered {
         match actor {
             TurnActor::Player { member_index } => {
-                let (level, attack, name) = match state.party.get(member_index) {
+                let (level, attack, name, types) = match state.party.get(member_index) {
                     Some(member) => (
                         member.level.max(1),
                         member.info.attack,
-                        member.info.name.clone(),
+                        member.name().to_string(),
+                        member.info.types.clone(),
                     ),
                     None => {
                         let info = state.player_info.as_ref();
@@ -1283,27 +2554,48 @@ fn build_combo_hits(
                         let name = info
                             .map(|info| info.name.clone())
                             .unwrap_or_else(|| state.player_name());
-                        (level, attack, name)
+                        let types = info.map(|info| info.types.clone()).unwrap_or_default();
+                        (level, attack, name, types)
                     }
                 };
                 let is_active = member_index == active_idx;
+                let chosen = chosen.filter(|_| is_active);
                 let ability_label = if is_active {
-                    ability_name.map(|name| name.to_string())
+                    ability_name
+                        .map(|name| name.to_string())
+                        .or_else(|| chosen.map(|mv| format_name(&mv.name)))
                 } else {
                     None
                 };
                 let ability_damage_used = is_active && ability_damage.is_some();
-                let damage = if ability_damage_used {
-                    ability_damage.unwrap_or(1).max(1)
-                } else {
-                    calc_damage(state, level, calc_stat(attack, level), enemy_def)
+                let stages = match state.battle.as_ref() {
+                    Some(battle) if is_active => battle.attack_stage,
+                    _ => 0,
+                };
+                let attack = items::boosted(calc_stat(attack, level), stages);
+                let (damage, effectiveness) = match chosen {
+                    _ if ability_damage_used => {
+                        (ability_damage.unwrap_or(1).max(1), Effectiveness::Normal)
+                    }
+                    Some(mv) => move_damage(state, level, attack, enemy_def, mv, &types),
+                    None => {
+                        let damage = calc_damage(state, level, attack, enemy_def);
+                        let damage =
+                            weather_scaled(state, damage, types.first().map(String::as_str));
+                        (damage, Effectiveness::Normal)
+                    }
                 };
+                let missed = damage > 0 && weather_miss(state);
+                let damage = if missed { 0 } else { damage };
                 hits.push(ComboHit {
                     actor: TurnActor::Player { member_index },
                     name: format_name(&name),
                     damage,
                     ability_name: ability_label,
-                    ability_damage: ability_damage_used,
+                    ability_damage: ability_damage_used || chosen.is_some(),
+                    heal: 0,
+                    effectiveness,
+                    missed,
                 });
             }
             TurnActor::Enemy => {
@@ -1313,12 +2605,19 @@ fn build_combo_hits(
                     enemy_attack(state, enemy_level),
                     player_defense(state),
                 );
+                let enemy_type = enemy_types(state).into_iter().next();
+                let damage = weather_scaled(state, damage, enemy_type.as_deref());
+                let (move_name, damage, heal) = trainer_move(state, damage);
+                let missed = damage > 0 && weather_miss(state);
                 hits.push(ComboHit {
                     actor: TurnActor::Enemy,
                     name: format_name(&enemy_name),
-                    damage,
-                    ability_name: None,
+                    damage: if missed { 0 } else { damage },
+                    ability_name: move_name.map(str::to_string),
                     ability_damage: false,
+                    heal,
+                    effectiveness: Effectiveness::Normal,
+                    missed,
                 });
             }
         }
@@ -1326,12 +2625,113 @@ fn build_combo_hits(
     hits
 }
 
+/// Picks the move for a trainer's Pokemon, given the damage a plain attack
+/// would do: Recover when it's below a third of its HP, a sure hit when that
+/// would knock the player out, otherwise a coin flip between a plain attack
+/// and Take Down. Wild and boss Pokemon always attack plainly.
+///
+/// Returns the move name (`None` for a plain attack), its damage and the HP
+/// it heals.
+fn trainer_move(state: &mut AppState, damage: u16) -> (Option<&'static str>, u16, u16) {
+    let Some(battle) = state.battle.as_ref() else {
+        return (None, damage, 0);
+    };
+    let Some(trainer) = battle.trainer.as_ref() else {
+        return (None, damage, 0);
+    };
+    let (enemy_hp, enemy_hp_max, player_hp) =
+        (battle.enemy_hp, battle.enemy_hp_max, battle.player_hp);
+    if !trainer.recovered && enemy_hp > 0 && enemy_hp < enemy_hp_max / 3 {
+        if let Some(trainer) = state.battle.as_mut().and_then(|b| b.trainer.as_mut()) {
+            trainer.recovered = true;
+        }
+        return (Some(RECOVER), 0, (enemy_hp_max / 2).max(1));
+    }
+    if damage >= player_hp || next_rand(state).is_multiple_of(2) {
+        return (None, damage, 0);
+    }
+    if next_rand(state).is_multiple_of(4) {
+        return (Some(TAKE_DOWN), 0, 0);
+    }
+    (Some(TAKE_DOWN), damage.saturating_mul(3) / 2, 0)
+}
+
+/// How the opponent is named in battle messages, e.g. "Wild Pidgey" or
+/// "Fisher Dana's Magikarp".
+fn enemy_label(battle: &BattleState) -> String {
+    let name = format_name(&battle.enemy_name);
+    match (battle.kind, battle.trainer.as_ref()) {
+        (BattleKind::Boss, _) => format!("Boss {name}"),
+        (BattleKind::Trainer, Some(trainer)) => format!("{}'s {name}", trainer.name),
+        _ => format!("Wild {name}"),
+    }
+}
+
+/// While the trainer has Pokemon left, a fainted one doesn't end the battle:
+/// it counts as a defeat, earns its XP and the next one is sent out.
+fn send_out_next_trainer_mon(state: &mut AppState, message: &str) -> Option<Effect> {
+    let battle = state.battle.as_mut()?;
+    let fainted = (battle.enemy_name.clone(), battle.enemy_level);
+    let trainer = battle.trainer.as_mut()?;
+    let next = trainer.send_next()?.clone();
+    let trainer_name = trainer.name.clone();
+    battle.enemy_name = next.species.clone();
+    battle.enemy_level = next.level.clamp(1, MAX_LEVEL);
+    battle.enemy_hp = 1;
+    battle.enemy_hp_max = 1;
+    battle.combo_hits.clear();
+    battle.stage = BattleStage::Intro;
+
+    record_defeat(state, &fainted.0);
+    sync_active_hp_from_battle(state);
+    let (gained, _) = gain_battle_exp(state, fainted.1);
+    sync_battle_from_active(state);
+    state.enemy_info = None;
+    state.enemy_sprite.reset();
+    state.enemy_sprite.loading = true;
+    if let Some(battle) = state.battle.as_mut() {
+        battle.message = format!(
+            "{message} Gained {gained} XP. {trainer_name} sent out {}!",
+            format_name(&next.species)
+        );
+    }
+    Some(Effect::LoadPokemon {
+        target: SpriteTarget::Enemy,
+        name: next.species,
+    })
+}
+
 fn apply_combo_hit(state: &mut AppState, hit: ComboHit) -> (bool, Option<Effect>) {
-    let (battle_kind, enemy_name) = match state.battle.as_ref() {
-        Some(battle) => (battle.kind, battle.enemy_name.clone()),
+    let (enemy, enemy_name) = match state.battle.as_ref() {
+        Some(battle) => (enemy_label(battle), battle.enemy_name.clone()),
         None => return (false, None),
     };
     match hit.actor {
+        TurnActor::Enemy if hit.heal > 0 => {
+            if let Some(battle) = state.battle.as_mut() {
+                let from_hp = battle.enemy_hp;
+                battle.enemy_hp = battle
+                    .enemy_hp
+                    .saturating_add(hit.heal)
+                    .min(battle.enemy_hp_max);
+                let anim = HitAnim::new(
+                    Side::Enemy,
+                    from_hp,
+                    battle.enemy_hp,
+                    battle.enemy_hp_max,
+                    hit.effectiveness,
+                );
+                replay::push_hit(&mut state.battle_log, state.tick, battle, anim);
+                battle.message = format!(
+                    "{} used {}! Restored {} HP.",
+                    enemy,
+                    hit.ability_name.as_deref().unwrap_or(RECOVER),
+                    hit.heal
+                );
+            }
+            tick_ability_cooldowns(state);
+            (false, None)
+        }
         TurnActor::Enemy => {
             let mut damage = hit.damage;
             let mut fainted = false;
@@ -1344,22 +2744,29 @@ fn apply_combo_hit(state: &mut AppState, hit: ComboHit) -> (bool, Option<Effect>
                         battle.guard_pct = 0;
                     }
                 }
+                let from_hp = battle.player_hp;
                 battle.player_hp = battle.player_hp.saturating_sub(damage);
                 fainted = battle.player_hp == 0;
+                let anim = HitAnim::new(
+                    Side::Player,
+                    from_hp,
+                    battle.player_hp,
+                    battle.player_hp_max,
+                    Effectiveness::Normal,
+                );
+                replay::push_hit(&mut state.battle_log, state.tick, battle, anim);
             }
             sync_active_hp_from_battle(state);
             tick_ability_cooldowns(state);
 
-            let prefix = match battle_kind {
-                BattleKind::Boss => "Boss",
-                BattleKind::Wild => "Wild",
+            let mut message = match hit.ability_name.as_deref() {
+                None if hit.missed => format!("{}'s attack missed!", enemy),
+                Some(move_name) if hit.damage == 0 => {
+                    format!("{} used {}, but it missed!", enemy, move_name)
+                }
+                Some(move_name) => format!("{} used {} for {}!", enemy, move_name, damage),
+                None => format!("{} hit you for {}!", enemy, damage),
             };
-            let mut message = format!(
-                "{} {} hit you for {}!",
-                prefix,
-                format_name(&enemy_name),
-                damage
-            );
 
             if fainted {
                 let dead_index = state.active_party_index;
@@ -1405,18 +2812,32 @@ fn apply_combo_hit(state: &mut AppState, hit: ComboHit) -> (bool, Option<Effect>
             if !alive {
                 return (false, None);
             }
-            let mut enemy_hp = match state.battle.as_ref() {
-                Some(battle) => battle.enemy_hp,
-                None => return (false, None),
+            let Some(battle) = state.battle.as_mut() else {
+                return (false, None);
             };
-            enemy_hp = enemy_hp.saturating_sub(hit.damage);
-            let enemy_fainted = enemy_hp == 0;
-            if let Some(battle) = state.battle.as_mut() {
-                battle.enemy_hp = enemy_hp;
-            }
+            let from_hp = battle.enemy_hp;
+            battle.enemy_hp = from_hp.saturating_sub(hit.damage);
+            let enemy_fainted = battle.enemy_hp == 0;
+            let anim = HitAnim::new(
+                Side::Enemy,
+                from_hp,
+                battle.enemy_hp,
+                battle.enemy_hp_max,
+                hit.effectiveness,
+            );
+            replay::push_hit(&mut state.battle_log, state.tick, battle, anim);
 
-            let mut message = if let Some(ability_name) = hit.ability_name.as_deref() {
-                if hit.ability_damage {
+            let mut message = if hit.missed {
+                match hit.ability_name.as_deref() {
+                    Some(ability_name) => {
+                        format!("{} used {}, but it missed!", hit.name, ability_name)
+                    }
+                    None => format!("{}'s attack missed!", hit.name),
+                }
+            } else if let Some(ability_name) = hit.ability_name.as_deref() {
+                if hit.effectiveness == Effectiveness::Immune {
+                    format!("{} used {}!", hit.name, ability_name)
+                } else if hit.ability_damage {
                     format!("{} used {} for {}!", hit.name, ability_name, hit.damage)
                 } else {
                     format!(
@@ -1427,31 +2848,277 @@ fn apply_combo_hit(state: &mut AppState, hit: ComboHit) -> (bool, Option<Effect>
             } else {
                 format!("{} hit for {}!", hit.name, hit.damage)
             };
+            if let Some(note) = hit.effectiveness.message().filter(|_| !hit.missed) {
+                message = format!("{message} {note}");
+            }
 
             if enemy_fainted {
                 message = format!("{} {} fainted!", message, format_name(&enemy_name));
+                if let Some(effect) = send_out_next_trainer_mon(state, &message) {
+                    return (true, Some(effect));
+                }
                 if let Some(battle) = state.battle.as_mut() {
                     battle.stage = BattleStage::Victory;
                     battle.message = message;
                 }
                 return (true, None);
             }
-
+
+            if let Some(battle) = state.battle.as_mut() {
+                battle.message = message;
+            }
+            (false, None)
+        }
+    }
+}
+
+fn heal_active_member(state: &mut AppState, amount: u16) {
+    if let Some(member) = state.active_member_mut() {
+        let max_hp = calc_hp(member.info.hp, member.level).max(1);
+        member.hp = member.hp.saturating_add(amount).min(max_hp);
+    }
+    sync_legacy_from_active(state);
+    sync_battle_from_active(state);
+}
+
+/// Why the opponent can't be caught, if it can't: only wild Pokemon can.
+fn catch_refusal(state: &AppState) -> Option<&'static str> {
+    match state.battle.as_ref().map(|battle| battle.kind) {
+        Some(BattleKind::Trainer) => Some("You can't catch a trainer's Pokemon!"),
+        Some(BattleKind::Boss) => Some("You can't catch this Pokemon!"),
+        _ => None,
+    }
+}
+
+/// Odds a ball catches a wild Pokemon at `hp` of `hp_max`. A Poke Ball goes
+/// from 20% at full health to 80% on the brink of fainting; `catch_rate`
+/// scales that in percent, so good enough balls become a sure thing.
+fn catch_chance(hp: u16, hp_max: u16, catch_rate: u16) -> f32 {
+    let hp_ratio = if hp_max == 0 {
+        1.0
+    } else {
+        hp as f32 / hp_max as f32
+    };
+    let base = (0.2 + 0.6 * (1.0 - hp_ratio)).clamp(0.2, 0.8);
+    (base * catch_rate as f32 / 100.0).min(1.0)
+}
+
+/// Throws one `ball` at the wild Pokemon out. A catch ends the battle; a
+/// miss gives the enemy its turn.
+fn throw_ball(state: &mut AppState, ball: &ItemKind, catch_rate: u16) -> DispatchResult<Effect> {
+    take_item(state, ball);
+    let (enemy_hp, enemy_hp_max, enemy_level, enemy_name) = match state.battle.as_ref() {
+        Some(battle) => (
+            battle.enemy_hp,
+            battle.enemy_hp_max,
+            battle.enemy_level,
+            battle.enemy_name.clone(),
+        ),
+        None => return DispatchResult::unchanged(),
+    };
+    let chance = catch_chance(enemy_hp, enemy_hp_max, catch_rate);
+    // Rolls run 0.00 to 0.99, so a chance of 0 never catches and 1 always does.
+    let roll = (next_rand(state) % 100) as f32 / 100.0;
+    if roll < chance {
+        if state.party.len() >= PARTY_LIMIT && state.pc_box.len() >= BOX_CAPACITY {
+            let pending_damage = calc_damage(
+                state,
+                enemy_level,
+                enemy_attack(state, enemy_level),
+                player_defense(state),
+            );
+            if let Some(battle) = state.battle.as_mut() {
+                battle.stage = BattleStage::EnemyTurn;
+                battle.message = "Party and box are full!".to_string();
+                battle.pending_enemy_damage = Some(pending_damage);
+            }
+            return DispatchResult::changed();
+        }
+        if let Some(info) = state.enemy_info.clone() {
+            let level = enemy_level.max(1);
+            let max_hp = calc_hp(info.hp, level);
+            let ability_id = ability_id_for_species(state, &info.name);
+            let mut member = PartyMember {
+                info,
+                level,
+                exp: exp_for_level(level),
+                hp: max_hp,
+                ability_id,
+                ability_cd: 0,
+                moves: Vec::new(),
+                nickname: None,
+            };
+            member.learn_starting_moves();
+            let mut message = format!("Caught {}!", format_name(&enemy_name));
+            if state.party.len() >= PARTY_LIMIT {
+                state.pc_box.push(member);
+                message.push_str(" Sent to the PC box.");
+            } else {
+                state.party.push(member);
+            }
             if let Some(battle) = state.battle.as_mut() {
+                battle.captured = true;
+                battle.stage = BattleStage::Victory;
                 battle.message = message;
             }
-            (false, None)
+            state.speedrun.split(Milestone::FirstCatch);
+        } else {
+            let pending_damage = calc_damage(
+                state,
+                enemy_level,
+                enemy_attack(state, enemy_level),
+                player_defense(state),
+            );
+            if let Some(battle) = state.battle.as_mut() {
+                battle.stage = BattleStage::EnemyTurn;
+                battle.message = "It slipped away!".to_string();
+                battle.pending_enemy_damage = Some(pending_damage);
+            }
+        }
+    } else {
+        let pending_damage = calc_damage(
+            state,
+            enemy_level,
+            enemy_attack(state, enemy_level),
+            player_defense(state),
+        );
+        if let Some(battle) = state.battle.as_mut() {
+            battle.stage = BattleStage::EnemyTurn;
+            battle.message = "It broke free!".to_string();
+            battle.pending_enemy_damage = Some(pending_damage);
         }
     }
+    DispatchResult::changed()
 }
 
-fn heal_active_member(state: &mut AppState, amount: u16) {
+/// Ether in battle: tops up every move of the active Pokemon, then the enemy
+/// gets its turn like after a potion.
+fn use_pp_item(
+    state: &mut AppState,
+    kind: &ItemKind,
+    pp: u8,
+    enemy_level: u8,
+) -> DispatchResult<Effect> {
+    let pp_full = state.active_member().is_none_or(|member| member.pp_full());
+    if pp_full || !take_item(state, kind) {
+        if let Some(battle) = state.battle.as_mut() {
+            battle.stage = BattleStage::Menu;
+        }
+        push_message(
+            state,
+            if pp_full {
+                "PP is already full."
+            } else {
+                "No items left."
+            },
+        );
+        set_battle_menu_prompt(state);
+        return DispatchResult::changed();
+    }
     if let Some(member) = state.active_member_mut() {
-        let max_hp = calc_hp(member.info.hp, member.level).max(1);
-        member.hp = member.hp.saturating_add(amount).min(max_hp);
+        member.restore_pp(pp);
     }
-    sync_legacy_from_active(state);
-    sync_battle_from_active(state);
+    let pending_damage = calc_damage(
+        state,
+        enemy_level,
+        enemy_attack(state, enemy_level),
+        player_defense(state),
+    );
+    let item = items::name(state, kind);
+    let name = format_name(&state.player_name());
+    if let Some(battle) = state.battle.as_mut() {
+        battle.stage = BattleStage::EnemyTurn;
+        battle.message = format!("Used {}! {}'s PP was restored.", item, name);
+        battle.pending_enemy_damage = Some(pending_damage);
+    }
+    DispatchResult::changed()
+}
+
+/// X items: raise a stat of the Pokemon out for the rest of the battle, up
+/// to `MAX_BOOST_STAGES`. Costs the turn.
+fn use_boost_item(
+    state: &mut AppState,
+    kind: &ItemKind,
+    stat: BoostStat,
+    stages: u8,
+    enemy_level: u8,
+) -> DispatchResult<Effect> {
+    let current = match (state.battle.as_ref(), stat) {
+        (Some(battle), BoostStat::Attack) => battle.attack_stage,
+        (Some(battle), BoostStat::Defense) => battle.defense_stage,
+        (None, _) => return DispatchResult::unchanged(),
+    };
+    let maxed = current >= MAX_BOOST_STAGES;
+    if maxed || !take_item(state, kind) {
+        if let Some(battle) = state.battle.as_mut() {
+            battle.stage = BattleStage::Menu;
+        }
+        push_message(
+            state,
+            if maxed {
+                format!("{} won't go any higher!", stat.label())
+            } else {
+                "No items left.".to_string()
+            },
+        );
+        set_battle_menu_prompt(state);
+        return DispatchResult::changed();
+    }
+    let raised = current.saturating_add(stages).min(MAX_BOOST_STAGES);
+    if let Some(battle) = state.battle.as_mut() {
+        match stat {
+            BoostStat::Attack => battle.attack_stage = raised,
+            BoostStat::Defense => battle.defense_stage = raised,
+        }
+    }
+    let pending_damage = calc_damage(
+        state,
+        enemy_level,
+        enemy_attack(state, enemy_level),
+        player_defense(state),
+    );
+    let item = items::name(state, kind);
+    let name = format_name(&state.player_name());
+    let rose = if raised - current > 1 {
+        "rose sharply"
+    } else {
+        "rose"
+    };
+    if let Some(battle) = state.battle.as_mut() {
+        battle.stage = BattleStage::EnemyTurn;
+        battle.message = format!("Used {}! {}'s {} {}!", item, name, stat.label(), rose);
+        battle.pending_enemy_damage = Some(pending_damage);
+    }
+    DispatchResult::changed()
+}
+
+/// Party indexes of the members at 0 HP, the rows of the revive list.
+fn fainted_members(state: &AppState) -> Vec<usize> {
+    state
+        .party
+        .iter()
+        .enumerate()
+        .filter(|(_, member)| member.hp == 0)
+        .map(|(index, _)| index)
+        .collect()
+}
+
+/// A revive was picked from the bag: choose who gets it.
+fn open_revive_menu(state: &mut AppState) -> DispatchResult<Effect> {
+    if fainted_members(state).is_empty() {
+        if let Some(battle) = state.battle.as_mut() {
+            battle.stage = BattleStage::Menu;
+        }
+        push_message(state, "No fainted Pokemon to revive.");
+        set_battle_menu_prompt(state);
+        return DispatchResult::changed();
+    }
+    if let Some(battle) = state.battle.as_mut() {
+        battle.stage = BattleStage::ReviveMenu;
+        battle.revive_index = 0;
+        battle.message = "Revive which Pokemon?".to_string();
+    }
+    DispatchResult::changed()
 }
 
 fn tick_ability_cooldowns(state: &mut AppState) {
@@ -1465,19 +3132,20 @@ fn tick_ability_cooldowns(state: &mut AppState) {
 fn collect_pickup(state: &mut AppState, x: u16, y: u16) {
     if let Some(index) = state.pickups.iter().position(|p| p.x == x && p.y == y) {
         let pickup = state.pickups.remove(index);
-        add_item_to_inventory(state, pickup.kind, pickup.qty);
-        push_message(
-            state,
-            format!("Found {} x{}!", pickup.kind.label(), pickup.qty),
-        );
+        add_item_to_inventory(state, &pickup.kind, pickup.qty);
+        let name = items::name(state, &pickup.kind);
+        push_message(state, format!("Found {} x{}!", name, pickup.qty));
     }
 }
 
-fn add_item_to_inventory(state: &mut AppState, kind: ItemKind, qty: u16) {
-    if let Some(stack) = state.inventory.iter_mut().find(|stack| stack.kind == kind) {
+fn add_item_to_inventory(state: &mut AppState, kind: &ItemKind, qty: u16) {
+    if let Some(stack) = state.inventory.iter_mut().find(|stack| stack.kind == *kind) {
         stack.qty = stack.qty.saturating_add(qty);
     } else {
-        state.inventory.push(crate::state::ItemStack { kind, qty });
+        state.inventory.push(crate::state::ItemStack {
+            kind: kind.clone(),
+            qty,
+        });
     }
 }
 
@@ -1523,7 +3191,7 @@ fn spawn_pickups(state: &mut AppState) {
             state.pickups.push(Pickup {
                 x,
                 y,
-                kind: drop.kind,
+                kind: drop.kind.clone(),
                 qty: drop.qty.max(1),
             });
             occupied.insert((x, y));
@@ -1575,6 +3243,33 @@ fn record_defeat(state: &mut AppState, enemy_name: &str) {
     let entry = state.defeat_counts.entry(key.clone()).or_insert(0);
     *entry = entry.saturating_add(1);
     trigger_defeat_events(state, &key);
+    announce_quest_objectives(state, &key);
+}
+
+/// Lets the player know when a defeat just finished one of their quests.
+fn announce_quest_objectives(state: &mut AppState, species_key: &str) {
+    let quests = match state.scenario.as_ref() {
+        Some(scenario) => scenario.manifest.quests.clone(),
+        None => return,
+    };
+    for quest in quests {
+        let QuestObjective::Defeat { species, count } = &quest.objective else {
+            continue;
+        };
+        if normalize_species(species) != species_key {
+            continue;
+        }
+        let Some(entry) = state.quests.entry(&quest.id) else {
+            continue;
+        };
+        let defeats = state.defeat_counts.get(species_key).copied().unwrap_or(0);
+        if !entry.done && defeats.saturating_sub(entry.baseline) == *count {
+            push_message(
+                state,
+                format!("{}: objective complete! Time to report back.", quest.name),
+            );
+        }
+    }
 }
 
 fn trigger_defeat_events(state: &mut AppState, species_key: &str) {
@@ -1616,21 +3311,31 @@ fn apply_scenario(state: &mut AppState, scenario: ScenarioRuntime) {
             member.ability_id = ability_id_from_list(&species_abilities, &member.info.name);
         }
     }
+    // A rolled weather carries on from the save; a fixed one is the route's.
+    let spec = weather::spec(state);
+    if !spec.is_random() || state.player.steps == 0 {
+        let roll = next_rand(state);
+        state.weather = spec.pick(roll);
+    }
     ensure_pickups(state);
 }
 
-fn available_items(state: &AppState) -> Vec<(ItemKind, u16)> {
-    state
-        .inventory
-        .iter()
-        .filter(|stack| stack.qty > 0)
-        .filter(|stack| matches!(stack.kind, ItemKind::Potion | ItemKind::SuperPotion))
-        .map(|stack| (stack.kind, stack.qty))
-        .collect()
+/// Rerolls a random weather pool, announcing the change if there is one.
+fn change_weather(state: &mut AppState) {
+    let spec = weather::spec(state);
+    if !spec.is_random() {
+        return;
+    }
+    let roll = next_rand(state);
+    let next = spec.pick(roll);
+    if next != state.weather {
+        state.weather = next;
+        push_message(state, next.onset());
+    }
 }
 
-fn take_item(state: &mut AppState, kind: ItemKind) -> bool {
-    if let Some(stack) = state.inventory.iter_mut().find(|stack| stack.kind == kind) {
+fn take_item(state: &mut AppState, kind: &ItemKind) -> bool {
+    if let Some(stack) = state.inventory.iter_mut().find(|stack| stack.kind == *kind) {
         if stack.qty > 0 {
             stack.qty = stack.qty.saturating_sub(1);
             return true;
@@ -1639,19 +3344,6 @@ fn take_item(state: &mut AppState, kind: ItemKind) -> bool {
     false
 }
 
-fn pokeball_count(state: &AppState) -> u16 {
-    state
-        .inventory
-        .iter()
-        .find(|stack| stack.kind == ItemKind::PokeBall)
-        .map(|stack| stack.qty)
-        .unwrap_or(0)
-}
-
-fn take_pokeball(state: &mut AppState) -> bool {
-    take_item(state, ItemKind::PokeBall)
-}
-
 fn sync_legacy_from_active(state: &mut AppState) {
     let member = match state.active_member() {
         Some(member) => member.clone(),
@@ -1700,7 +3392,7 @@ fn switch_to_next_alive(state: &mut AppState) -> Option<String> {
         if let Some(member) = state.party.get(idx) {
             if member.hp > 0 {
                 next_index = Some(idx);
-                next_name = Some(format_name(&member.info.name));
+                next_name = Some(format_name(member.name()));
                 break;
             }
         }
@@ -1710,6 +3402,10 @@ fn switch_to_next_alive(state: &mut AppState) -> Option<String> {
         _ => return None,
     };
     state.active_party_index = idx;
+    if let Some(battle) = state.battle.as_mut() {
+        battle.attack_stage = 0;
+        battle.defense_stage = 0;
+    }
     sync_legacy_from_active(state);
     sync_battle_from_active(state);
     Some(name)
@@ -1725,6 +3421,19 @@ fn load_player_sprite_for_active(state: &mut AppState) -> Option<Effect> {
     })
 }
 
+/// [`award_exp`] plus the max HP the active member gains from leveling up.
+fn gain_battle_exp(state: &mut AppState, enemy_level: u8) -> (u32, u8) {
+    let old_max = state.player_max_hp();
+    let (gained, levels) = award_exp(state, enemy_level);
+    let new_max = state.player_max_hp();
+    if let Some(member) = state.active_member_mut() {
+        let hp_bonus = new_max.saturating_sub(old_max);
+        member.hp = member.hp.saturating_add(hp_bonus).min(new_max);
+    }
+    sync_legacy_from_active(state);
+    (gained, levels)
+}
+
 fn award_exp(state: &mut AppState, enemy_level: u8) -> (u32, u8) {
     let base_exp = state
         .enemy_info
@@ -1741,7 +3450,15 @@ fn award_exp(state: &mut AppState, enemy_level: u8) -> (u32, u8) {
             member.level = member.level.saturating_add(1);
         }
         let levels = member.level.saturating_sub(starting_level);
+        let name = format_name(member.name());
+        let learned = member.learn_moves_since(starting_level);
         sync_legacy_from_active(state);
+        for move_name in learned {
+            push_message(
+                state,
+                format!("{name} learned {}!", format_name(&move_name)),
+            );
+        }
         return (gained, levels);
     }
     let starting_level = state.player_level;
@@ -1758,17 +3475,21 @@ fn award_exp(state: &mut AppState, enemy_level: u8) -> (u32, u8) {
 fn starting_inventory() -> Vec<crate::state::ItemStack> {
     vec![
         crate::state::ItemStack {
-            kind: ItemKind::Potion,
+            kind: ItemKind::POTION,
             qty: 3,
         },
         crate::state::ItemStack {
-            kind: ItemKind::SuperPotion,
+            kind: ItemKind::SUPER_POTION,
             qty: 1,
         },
         crate::state::ItemStack {
-            kind: ItemKind::PokeBall,
+            kind: ItemKind::POKE_BALL,
             qty: 5,
         },
+        crate::state::ItemStack {
+            kind: ItemKind::ETHER,
+            qty: 1,
+        },
     ]
 }
 
@@ -1780,6 +3501,13 @@ fn normalize_loaded_state(state: &mut AppState) {
         state.player_level = MAX_LEVEL;
     }
     state.pause_menu.is_open = false;
+    state.quest_log_open = false;
+    state.dialogue = None;
+    state.fishing = None;
+    if state.mode == GameMode::Shop {
+        state.mode = GameMode::Overworld;
+    }
+    state.shop = None;
     let min_exp = exp_for_level(state.player_level);
     if state.player_exp < min_exp {
         state.player_exp = min_exp;
@@ -1789,10 +3517,10 @@ fn normalize_loaded_state(state: &mut AppState) {
     } else if !state
         .inventory
         .iter()
-        .any(|stack| stack.kind == ItemKind::PokeBall)
+        .any(|stack| stack.kind == ItemKind::POKE_BALL)
     {
         state.inventory.push(crate::state::ItemStack {
-            kind: ItemKind::PokeBall,
+            kind: ItemKind::POKE_BALL,
             qty: 5,
         });
     }
@@ -1808,18 +3536,33 @@ fn normalize_loaded_state(state: &mut AppState) {
             };
             let exp = state.player_exp.max(exp_for_level(level));
             let ability_id = ability_id_for_species(state, &info.name);
-            state.party.push(PartyMember {
+            let mut member = PartyMember {
                 info,
                 level,
                 exp,
                 hp,
                 ability_id,
                 ability_cd: 0,
-            });
+                moves: Vec::new(),
+                nickname: None,
+            };
+            member.learn_starting_moves();
+            state.party.push(member);
             state.active_party_index = 0;
         }
     }
 
+    // A prompt saved mid-load asks again; one for a member that's gone is dropped
+    let party_len = state.party.len();
+    state.evolution = state
+        .evolution
+        .take()
+        .filter(|prompt| prompt.member_index < party_len)
+        .map(|prompt| EvolutionPrompt {
+            loading: false,
+            ..prompt
+        });
+
     if !state.party.is_empty() {
         if state.active_party_index >= state.party.len() {
             state.active_party_index = 0;
@@ -1889,6 +3632,7 @@ fn menu_confirm(state: &mut AppState) -> DispatchResult<Effect> {
                 selected: 0,
                 preview_info: None,
                 preview_sprite: SpriteState::default(),
+                hardcore: false,
             });
             // Load preview for first starter
             if let Some(first) = starters.first() {
@@ -1972,12 +3716,24 @@ fn starter_confirm(state: &mut AppState) -> DispatchResult<Effect> {
     state.boss_defeated = false;
     state.fired_event_ids.clear();
     state.defeat_counts.clear();
+    state.quests = Default::default();
+    state.dialogue = None;
+    state.fishing = None;
+    state.gauntlet = None;
+    state.badges.clear();
+    state.money = STARTING_MONEY;
+    state.shop = None;
     state.pickups.clear();
+    state.speedrun.start();
+    state.hardcore = select.hardcore;
     ensure_pickups(state);
     push_message(
         state,
         format!("You chose {}! Let's go!", format_name(&name)),
     );
+    if state.hardcore {
+        push_message(state, "Hardcore run: fainted Pokemon are gone for good.");
+    }
 
     DispatchResult::changed_with(Effect::LoadPokemon {
         target: SpriteTarget::Player,
@@ -1998,16 +3754,301 @@ fn pause_confirm(state: &mut AppState) -> DispatchResult<Effect> {
                 state: Box::new(state.clone()),
             })
         }
-        2 | _ => {
+        2 => options_open(state),
+        3 => party_open(state),
+        4 => box_open(state),
+        5 => {
+            state.stats_open = true;
+            DispatchResult::changed()
+        }
+        6 => {
+            state.quest_log_open = true;
+            DispatchResult::changed()
+        }
+        7 => replay_start(state),
+        _ => {
             // Quit to Menu
             state.pause_menu.is_open = false;
+            state.speedrun.running = false;
             state.mode = GameMode::MainMenu;
             state.menu = Some(MenuState {
                 selected: 0,
                 has_save: false,
+                save_hardcore: false,
             });
             state.battle = None;
             DispatchResult::changed_with(Effect::CheckSaveExists)
         }
     }
 }
+
+fn party_open(state: &mut AppState) -> DispatchResult<Effect> {
+    if state.mode != GameMode::Overworld {
+        push_message(state, "There's no time to sort the party mid-battle.");
+        return DispatchResult::changed();
+    }
+    state.pause_menu.is_open = false;
+    state.party_screen = PartyScreenState {
+        is_open: true,
+        selected: state.active_party_index,
+        ..PartyScreenState::default()
+    };
+    DispatchResult::changed()
+}
+
+/// Moves the cursor, or while a member is picked up, swaps it with the
+/// neighbour in that direction. Sprites and the lead move with their
+/// Pokemon.
+fn party_move(state: &mut AppState, delta: i16) -> DispatchResult<Effect> {
+    let len = state.party.len();
+    if len == 0 {
+        return DispatchResult::unchanged();
+    }
+    let current = state.party_screen.selected.min(len - 1);
+    if !state.party_screen.moving {
+        let next = (current as i64 + delta as i64).rem_euclid(len as i64) as usize;
+        if next == current {
+            return DispatchResult::unchanged();
+        }
+        state.party_screen.selected = next;
+        return DispatchResult::changed();
+    }
+    let next = (current as i64 + delta as i64).clamp(0, len as i64 - 1) as usize;
+    if next == current {
+        return DispatchResult::unchanged();
+    }
+    ensure_party_sprites(state);
+    state.party.swap(current, next);
+    state.party_sprites.swap(current, next);
+    if state.active_party_index == current {
+        state.active_party_index = next;
+    } else if state.active_party_index == next {
+        state.active_party_index = current;
+    }
+    state.party_screen.selected = next;
+    DispatchResult::changed()
+}
+
+/// Makes the selected member the one sent out first, and the one following
+/// the player around the map.
+fn party_set_lead(state: &mut AppState) -> DispatchResult<Effect> {
+    if state.party_screen.moving {
+        state.party_screen.moving = false;
+        return DispatchResult::changed();
+    }
+    let index = state.party_screen.selected;
+    let Some(member) = state.party.get(index) else {
+        return DispatchResult::unchanged();
+    };
+    if index == state.active_party_index {
+        return DispatchResult::unchanged();
+    }
+    let name = format_name(member.name());
+    if member.hp == 0 {
+        push_message(state, format!("{name} has fainted and can't lead."));
+        return DispatchResult::changed();
+    }
+    state.active_party_index = index;
+    sync_legacy_from_active(state);
+    push_message(state, format!("{name} takes the lead!"));
+    match load_player_sprite_for_active(state) {
+        Some(effect) => DispatchResult::changed_with(effect),
+        None => DispatchResult::changed(),
+    }
+}
+
+/// Gives the selected member the typed nickname; an empty one goes back to
+/// the species name.
+fn party_name_confirm(state: &mut AppState) -> DispatchResult<Effect> {
+    let Some(typed) = state.party_screen.naming.take() else {
+        return DispatchResult::unchanged();
+    };
+    let Some(member) = state.party.get_mut(state.party_screen.selected) else {
+        return DispatchResult::changed();
+    };
+    let nickname = Some(typed.trim().to_string()).filter(|name| !name.is_empty());
+    if nickname == member.nickname {
+        return DispatchResult::changed();
+    }
+    let species = format_name(&member.info.name);
+    let message = match &nickname {
+        Some(name) => format!("{species} is now called {}.", format_name(name)),
+        None => format!("{species} no longer has a nickname."),
+    };
+    member.nickname = nickname;
+    push_message(state, message);
+    DispatchResult::changed()
+}
+
+fn box_open(state: &mut AppState) -> DispatchResult<Effect> {
+    if state.mode != GameMode::Overworld {
+        push_message(state, "The PC can't be reached mid-battle.");
+        return DispatchResult::changed();
+    }
+    state.pause_menu.is_open = false;
+    state.box_screen = BoxScreenState {
+        is_open: true,
+        ..BoxScreenState::default()
+    };
+    DispatchResult::changed()
+}
+
+fn box_move(state: &mut AppState, delta: i16) -> DispatchResult<Effect> {
+    let len = match state.box_screen.pane {
+        BoxPane::Party => state.party.len(),
+        BoxPane::Box => state.pc_box.len(),
+    };
+    if len == 0 {
+        return DispatchResult::unchanged();
+    }
+    let current = state.box_screen.selected().min(len - 1);
+    let next = (current as i64 + delta as i64).rem_euclid(len as i64) as usize;
+    let screen = &mut state.box_screen;
+    if next == screen.selected() {
+        return DispatchResult::unchanged();
+    }
+    match screen.pane {
+        BoxPane::Party => screen.party_index = next,
+        BoxPane::Box => screen.box_index = next,
+    }
+    screen.release_armed = false;
+    DispatchResult::changed()
+}
+
+/// Deposits the selected party member or withdraws the selected boxed one.
+/// Deposited Pokemon are healed, like at a real PC, and the party always
+/// keeps at least one.
+fn box_transfer(state: &mut AppState) -> DispatchResult<Effect> {
+    state.box_screen.release_armed = false;
+    match state.box_screen.pane {
+        BoxPane::Party => {
+            let index = state.box_screen.party_index;
+            let Some(name) = state
+                .party
+                .get(index)
+                .map(|member| member.name().to_string())
+            else {
+                return DispatchResult::unchanged();
+            };
+            if state.party.len() <= 1 {
+                push_message(
+                    state,
+                    format!("{} is your last Pokemon.", format_name(&name)),
+                );
+                return DispatchResult::changed();
+            }
+            if state.pc_box.len() >= BOX_CAPACITY {
+                push_message(state, "The box is full.");
+                return DispatchResult::changed();
+            }
+            let (mut member, effect) = remove_party_member(state, index);
+            member.hp = calc_hp(member.info.hp, member.level).max(1);
+            member.ability_cd = 0;
+            member.restore_pp(u8::MAX);
+            state.pc_box.push(member);
+            if let Some(effect) = effect {
+                return DispatchResult::changed_with(effect);
+            }
+        }
+        BoxPane::Box => {
+            let index = state.box_screen.box_index;
+            if index >= state.pc_box.len() {
+                return DispatchResult::unchanged();
+            }
+            if state.party.len() >= PARTY_LIMIT {
+                push_message(state, "Your party is full.");
+                return DispatchResult::changed();
+            }
+            let member = state.pc_box.remove(index);
+            state.party.push(member);
+            ensure_party_sprites(state);
+            state.box_screen.box_index = index.min(state.pc_box.len().saturating_sub(1));
+        }
+    }
+    DispatchResult::changed()
+}
+
+/// Lets the selected Pokemon go. The first press only arms it; the screen
+/// asks for a second one.
+fn box_release(state: &mut AppState) -> DispatchResult<Effect> {
+    let index = state.box_screen.selected();
+    let name = match state.box_screen.pane {
+        BoxPane::Party => state.party.get(index),
+        BoxPane::Box => state.pc_box.get(index),
+    }
+    .map(|member| format_name(member.name()));
+    let Some(name) = name else {
+        return DispatchResult::unchanged();
+    };
+    if state.box_screen.pane == BoxPane::Party && state.party.len() <= 1 {
+        push_message(state, format!("{} is your last Pokemon.", name));
+        return DispatchResult::changed();
+    }
+    if !state.box_screen.release_armed {
+        state.box_screen.release_armed = true;
+        return DispatchResult::changed();
+    }
+    state.box_screen.release_armed = false;
+    let mut effect = None;
+    match state.box_screen.pane {
+        BoxPane::Party => effect = remove_party_member(state, index).1,
+        BoxPane::Box => {
+            state.pc_box.remove(index);
+            state.box_screen.box_index = index.min(state.pc_box.len().saturating_sub(1));
+        }
+    }
+    push_message(state, format!("{} was released. Bye, {}!", name, name));
+    match effect {
+        Some(effect) => DispatchResult::changed_with(effect),
+        None => DispatchResult::changed(),
+    }
+}
+
+/// Takes a member out of the party, keeping sprites and the active index
+/// lined up. When the active member leaves, the overworld sprite is reloaded
+/// for the one taking its place.
+fn remove_party_member(state: &mut AppState, index: usize) -> (PartyMember, Option<Effect>) {
+    ensure_party_sprites(state);
+    let member = state.party.remove(index);
+    state.party_sprites.remove(index);
+    let was_active = index == state.active_party_index;
+    if index < state.active_party_index {
+        state.active_party_index -= 1;
+    }
+    if state.active_party_index >= state.party.len() {
+        state.active_party_index = state.party.len().saturating_sub(1);
+    }
+    state.box_screen.party_index = index.min(state.party.len().saturating_sub(1));
+    sync_legacy_from_active(state);
+    let effect = if was_active {
+        load_player_sprite_for_active(state)
+    } else {
+        None
+    };
+    (member, effect)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn catch_chance_rises_as_hp_drops() {
+        assert_eq!(catch_chance(40, 40, 100), 0.2);
+        assert!((catch_chance(1, 100, 100) - 0.794).abs() < 1e-4);
+        assert_eq!(catch_chance(0, 40, 100), 0.8);
+    }
+
+    #[test]
+    fn catch_rate_scales_the_chance_up_to_certain() {
+        assert!((catch_chance(40, 40, 150) - 0.3).abs() < 1e-6);
+        assert_eq!(catch_chance(0, 40, 150), 1.0);
+        assert_eq!(catch_chance(40, 40, 1000), 1.0);
+        assert_eq!(catch_chance(0, 40, 0), 0.0);
+    }
+
+    #[test]
+    fn catch_chance_without_max_hp_counts_as_full_health() {
+        assert_eq!(catch_chance(0, 0, 100), 0.2);
+    }
+}
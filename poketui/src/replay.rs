@@ -0,0 +1,240 @@
+//! Battle logs and their replays. While a battle is on, every roll of the
+//! rng, every hit and every change to what the battle screen shows goes into
+//! [`BattleLog`], each entry stamped with the ticks since the one before, so
+//! the idle stretches between them cost nothing. `Replay Battle` in the pause
+//! menu plays the last log back at the speed it was recorded, through the
+//! ordinary battle screen: frames are swapped in as they come due and hits
+//! queue their animations again. Nothing is re-rolled, so a replay always
+//! shows what happened, even after the combat code has changed since.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::anim::{self, HitAnim};
+use crate::state::BattleState;
+
+/// Ticks the last frame stays up after the log runs out.
+pub const OUTRO_TICKS: u32 = 20;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub enum LogEvent {
+    /// A number drawn from the rng.
+    Roll(u32),
+    /// A hit or heal, misses included.
+    Hit(HitAnim),
+    /// The battle as drawn, minus its animation queue.
+    Frame(Box<BattleState>),
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct LogEntry {
+    /// Ticks since the entry before.
+    pub wait: u32,
+    pub event: LogEvent,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct BattleLog {
+    pub entries: Vec<LogEntry>,
+    /// Tick of the newest entry, which the next one's wait counts from.
+    last_tick: u64,
+}
+
+impl BattleLog {
+    /// An empty log for a battle starting on `tick`.
+    pub fn new(tick: u64) -> Self {
+        Self {
+            entries: Vec::new(),
+            last_tick: tick,
+        }
+    }
+
+    pub fn record(&mut self, tick: u64, event: LogEvent) {
+        let wait = tick.saturating_sub(self.last_tick).min(u32::MAX as u64) as u32;
+        self.last_tick = tick;
+        self.entries.push(LogEntry { wait, event });
+    }
+
+    /// Whether there's a battle to replay: one frame at least.
+    pub fn has_frames(&self) -> bool {
+        self.entries
+            .iter()
+            .any(|entry| matches!(entry.event, LogEvent::Frame(_)))
+    }
+
+    fn last_frame(&self) -> Option<&BattleState> {
+        self.entries
+            .iter()
+            .rev()
+            .find_map(|entry| match &entry.event {
+                LogEvent::Frame(frame) => Some(frame.as_ref()),
+                _ => None,
+            })
+    }
+
+    /// Rolls and hits among the first `count` entries.
+    pub fn counts(&self, count: usize) -> (usize, usize) {
+        self.entries
+            .iter()
+            .take(count)
+            .fold((0, 0), |(rolls, hits), entry| match entry.event {
+                LogEvent::Roll(_) => (rolls + 1, hits),
+                LogEvent::Hit(_) => (rolls, hits + 1),
+                LogEvent::Frame(_) => (rolls, hits),
+            })
+    }
+}
+
+/// Queues `anim` on `battle` and logs it as a hit.
+pub fn push_hit(log: &mut BattleLog, tick: u64, battle: &mut BattleState, anim: HitAnim) {
+    log.record(tick, LogEvent::Hit(anim.clone()));
+    anim::push(battle, anim);
+}
+
+/// Logs a frame if `battle` looks different from the last one.
+pub fn capture(log: &mut BattleLog, tick: u64, battle: &mut BattleState) {
+    let anims = std::mem::take(&mut battle.anims);
+    if log.last_frame() != Some(&*battle) {
+        log.record(tick, LogEvent::Frame(Box::new(battle.clone())));
+    }
+    battle.anims = anims;
+}
+
+/// Where a replay has got to.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ReplayState {
+    /// Next entry to play.
+    pub cursor: usize,
+    /// Ticks until it plays.
+    pub wait: u32,
+}
+
+impl ReplayState {
+    /// A replay of `log` from its first frame, which it also returns.
+    pub fn start(log: &BattleLog) -> Option<(Self, BattleState)> {
+        let first = log
+            .entries
+            .iter()
+            .position(|entry| matches!(entry.event, LogEvent::Frame(_)))?;
+        let LogEvent::Frame(frame) = &log.entries[first].event else {
+            return None;
+        };
+        let cursor = first + 1;
+        let replay = Self {
+            cursor,
+            // Counted from the tick after this one, like `advance` does.
+            wait: log
+                .entries
+                .get(cursor)
+                .map_or(OUTRO_TICKS, |entry| entry.wait.saturating_sub(1)),
+        };
+        Some((replay, frame.as_ref().clone()))
+    }
+
+    /// Plays one tick of `log` onto `battle`. Returns whether any entry came
+    /// due.
+    pub fn advance(&mut self, log: &BattleLog, battle: &mut BattleState) -> bool {
+        if self.wait > 0 {
+            self.wait -= 1;
+            return false;
+        }
+        let mut played = false;
+        while let Some(entry) = log.entries.get(self.cursor) {
+            if played && entry.wait > 0 {
+                self.wait = entry.wait - 1;
+                return true;
+            }
+            match &entry.event {
+                LogEvent::Roll(_) => {}
+                LogEvent::Hit(hit) => anim::push(battle, hit.clone()),
+                LogEvent::Frame(frame) => {
+                    let anims = std::mem::take(&mut battle.anims);
+                    *battle = frame.as_ref().clone();
+                    battle.anims = anims;
+                }
+            }
+            self.cursor += 1;
+            played = true;
+        }
+        if played {
+            self.wait = OUTRO_TICKS;
+        }
+        played
+    }
+
+    /// The log has run out, the outro has been shown and the last
+    /// animation has played.
+    pub fn finished(&self, log: &BattleLog, battle: &BattleState) -> bool {
+        self.cursor >= log.entries.len() && self.wait == 0 && !anim::is_playing(battle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::state::BattleKind;
+
+    fn battle(message: &str) -> BattleState {
+        let mut battle = BattleState::new("Pidgey".into(), 3, 20, 20, BattleKind::Wild);
+        battle.message = message.into();
+        battle
+    }
+
+    /// A frame on tick 100, a roll on 102 and a second frame on 105.
+    fn short_log() -> BattleLog {
+        let mut log = BattleLog::new(100);
+        capture(&mut log, 100, &mut battle("A wild Pidgey appeared!"));
+        capture(&mut log, 101, &mut battle("A wild Pidgey appeared!"));
+        log.record(102, LogEvent::Roll(7));
+        capture(&mut log, 105, &mut battle("Pidgey fainted!"));
+        log
+    }
+
+    #[test]
+    fn capture_skips_frames_that_look_the_same() {
+        let log = short_log();
+        let waits: Vec<u32> = log.entries.iter().map(|entry| entry.wait).collect();
+        assert_eq!(waits, vec![0, 2, 3]);
+        assert_eq!(log.counts(log.entries.len()), (1, 0));
+    }
+
+    #[test]
+    fn start_restores_the_first_frame() {
+        let log = short_log();
+        let (replay, shown) = ReplayState::start(&log).unwrap();
+        assert_eq!(shown, battle("A wild Pidgey appeared!"));
+        assert_eq!(replay.cursor, 1);
+    }
+
+    #[test]
+    fn frames_play_on_their_recorded_tick_and_then_the_outro() {
+        let log = short_log();
+        let (mut replay, mut shown) = ReplayState::start(&log).unwrap();
+
+        let mut changed_on = None;
+        for tick in 1..=5 {
+            assert!(!replay.finished(&log, &shown));
+            replay.advance(&log, &mut shown);
+            if changed_on.is_none() && shown.message == "Pidgey fainted!" {
+                changed_on = Some(tick);
+            }
+        }
+        assert_eq!(changed_on, Some(5));
+        assert_eq!(shown, battle("Pidgey fainted!"));
+
+        for _ in 0..OUTRO_TICKS {
+            assert!(!replay.finished(&log, &shown));
+            assert!(!replay.advance(&log, &mut shown));
+        }
+        assert!(replay.finished(&log, &shown));
+    }
+
+    #[test]
+    fn a_log_without_frames_has_nothing_to_replay() {
+        let mut log = BattleLog::new(0);
+        assert!(ReplayState::start(&log).is_none());
+        log.record(4, LogEvent::Roll(1));
+        assert!(!log.has_frames());
+        assert!(ReplayState::start(&log).is_none());
+    }
+}
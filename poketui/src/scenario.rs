@@ -4,12 +4,87 @@ use std::path::Path;
 use tui_map::core::TileKind;
 use tui_map::parse::{parse_char_grid, Legend, ParseOptions, TrimMode};
 
-use crate::state::{ItemKind, MapState};
+use crate::items::{self, ItemDef};
+use crate::state::{AppState, ItemKind, ItemStack, MapState};
+use crate::weather::WeatherSpec;
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct ScenarioRuntime {
     pub manifest: ScenarioManifest,
     pub map: MapState,
+    #[serde(default)]
+    pub backdrops: Vec<Backdrop>,
+}
+
+impl ScenarioRuntime {
+    pub fn zone_at(&self, x: u16, y: u16) -> Option<&ZoneSpec> {
+        self.manifest.zones.iter().find(|zone| zone.contains(x, y))
+    }
+
+    /// Backdrop id for a battle starting on this tile: the zone's, else the scenario default.
+    pub fn backdrop_at(&self, x: u16, y: u16) -> Option<String> {
+        self.zone_at(x, y)
+            .map(|zone| zone.backdrop.clone())
+            .or_else(|| self.manifest.default_backdrop.clone())
+    }
+
+    pub fn backdrop(&self, id: &str) -> Option<&Backdrop> {
+        self.backdrops.iter().find(|backdrop| backdrop.id == id)
+    }
+
+    pub fn trainer_at(&self, x: u16, y: u16) -> Option<&TrainerSpec> {
+        self.manifest
+            .trainers
+            .iter()
+            .find(|trainer| trainer.x == x && trainer.y == y)
+    }
+
+    pub fn trainer(&self, id: &str) -> Option<&TrainerSpec> {
+        self.manifest
+            .trainers
+            .iter()
+            .find(|trainer| trainer.id == id)
+    }
+
+    pub fn npc_at(&self, x: u16, y: u16) -> Option<&NpcSpec> {
+        self.manifest
+            .npcs
+            .iter()
+            .find(|npc| npc.x == x && npc.y == y)
+    }
+
+    pub fn npc(&self, id: &str) -> Option<&NpcSpec> {
+        self.manifest.npcs.iter().find(|npc| npc.id == id)
+    }
+
+    pub fn quest(&self, id: &str) -> Option<&QuestSpec> {
+        self.manifest.quests.iter().find(|quest| quest.id == id)
+    }
+
+    pub fn gauntlet_at(&self, x: u16, y: u16) -> Option<&GauntletSpec> {
+        self.manifest
+            .gauntlets
+            .iter()
+            .find(|gauntlet| gauntlet.x == x && gauntlet.y == y)
+    }
+
+    pub fn shop_at(&self, x: u16, y: u16) -> Option<&ShopSpec> {
+        self.manifest
+            .shops
+            .iter()
+            .find(|shop| shop.x == x && shop.y == y)
+    }
+
+    pub fn shop(&self, id: &str) -> Option<&ShopSpec> {
+        self.manifest.shops.iter().find(|shop| shop.id == id)
+    }
+
+    pub fn gauntlet(&self, id: &str) -> Option<&GauntletSpec> {
+        self.manifest
+            .gauntlets
+            .iter()
+            .find(|gauntlet| gauntlet.id == id)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -21,6 +96,9 @@ pub struct ScenarioManifest {
     pub starters: Vec<String>,
     #[serde(default)]
     pub wild_pool: Vec<String>,
+    /// Species hooked by fishing; the built-in pool when empty.
+    #[serde(default)]
+    pub water_pool: Vec<String>,
     #[serde(default)]
     pub events: Vec<ScenarioEvent>,
     #[serde(default)]
@@ -29,6 +107,266 @@ pub struct ScenarioManifest {
     pub abilities: Vec<AbilitySpec>,
     #[serde(default)]
     pub species_abilities: Vec<SpeciesAbility>,
+    #[serde(default)]
+    pub zones: Vec<ZoneSpec>,
+    #[serde(default)]
+    pub backdrops: Vec<BackdropSpec>,
+    #[serde(default)]
+    pub default_backdrop: Option<String>,
+    #[serde(default)]
+    pub trainers: Vec<TrainerSpec>,
+    #[serde(default)]
+    pub npcs: Vec<NpcSpec>,
+    #[serde(default)]
+    pub quests: Vec<QuestSpec>,
+    /// Quest whose turn-in teaches Surf; without one, water stays off limits.
+    #[serde(default)]
+    pub surf_quest: Option<String>,
+    #[serde(default)]
+    pub gauntlets: Vec<GauntletSpec>,
+    #[serde(default)]
+    pub shops: Vec<ShopSpec>,
+    /// Items beyond the built-in ones, or built-ins redefined by id.
+    #[serde(default)]
+    pub items: Vec<ItemDef>,
+    #[serde(default)]
+    pub weather: WeatherSpec,
+}
+
+/// An NPC standing on the map who challenges the player once they step next
+/// to them. Beaten trainers stay put but don't battle again.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct TrainerSpec {
+    pub id: String,
+    pub name: String,
+    pub x: u16,
+    pub y: u16,
+    /// Sent out in order; the next one comes in when the current one faints.
+    pub party: Vec<TrainerMon>,
+    #[serde(default)]
+    pub intro: Option<String>,
+    #[serde(default)]
+    pub defeat: Option<String>,
+}
+
+/// A mart; stepping onto its tile opens the shop.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ShopSpec {
+    pub id: String,
+    pub name: String,
+    pub x: u16,
+    pub y: u16,
+    pub stock: Vec<ShopItem>,
+    #[serde(default)]
+    pub greeting: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ShopItem {
+    pub kind: ItemKind,
+    /// Overrides the item's usual price.
+    #[serde(default)]
+    pub price: Option<u32>,
+}
+
+impl ShopItem {
+    pub fn price(&self, state: &AppState) -> u32 {
+        self.price
+            .unwrap_or_else(|| items::price(state, &self.kind))
+    }
+}
+
+/// A gym or boss hall. Talking at its door starts its battles one after
+/// another with no return to the overworld in between; winning them all
+/// earns the badge, losing one ends the run.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct GauntletSpec {
+    pub id: String,
+    pub name: String,
+    pub x: u16,
+    pub y: u16,
+    pub battles: Vec<GauntletBattle>,
+    /// What the party gets back between battles.
+    #[serde(default)]
+    pub heal: HealPolicy,
+    pub badge: String,
+    #[serde(default)]
+    pub intro: Option<String>,
+}
+
+impl GauntletSpec {
+    /// The opponent of battle `stage`, as a trainer standing at the door.
+    pub fn trainer(&self, stage: usize) -> Option<TrainerSpec> {
+        let battle = self.battles.get(stage)?;
+        Some(TrainerSpec {
+            id: format!("{}:{}", self.id, stage),
+            name: battle.name.clone(),
+            x: self.x,
+            y: self.y,
+            party: battle.party.clone(),
+            intro: battle.intro.clone(),
+            defeat: battle.defeat.clone(),
+        })
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct GauntletBattle {
+    pub name: String,
+    pub party: Vec<TrainerMon>,
+    #[serde(default)]
+    pub intro: Option<String>,
+    #[serde(default)]
+    pub defeat: Option<String>,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum HealPolicy {
+    /// Damage and spent PP carry over.
+    #[default]
+    None,
+    /// HP and PP fully restored.
+    Full,
+    /// Every party member gets back `percent` of their max HP.
+    Percent { percent: u8 },
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct TrainerMon {
+    pub species: String,
+    pub level: u8,
+}
+
+/// A townsperson the player can talk to. Conversation starts at the first
+/// dialogue node; an NPC with a `quest` hands it out through a choice with
+/// `accept_quest`, and takes it back once the objective is met.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct NpcSpec {
+    pub id: String,
+    pub name: String,
+    pub x: u16,
+    pub y: u16,
+    pub dialogue: Vec<DialogueNode>,
+    #[serde(default)]
+    pub quest: Option<String>,
+}
+
+impl NpcSpec {
+    pub fn node(&self, id: &str) -> Option<&DialogueNode> {
+        self.dialogue.iter().find(|node| node.id == id)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct DialogueNode {
+    pub id: String,
+    pub text: String,
+    /// Empty means the conversation ends after this line.
+    #[serde(default)]
+    pub choices: Vec<DialogueChoice>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct DialogueChoice {
+    pub label: String,
+    /// Node to go to; none ends the conversation.
+    #[serde(default)]
+    pub next: Option<String>,
+    #[serde(default)]
+    pub accept_quest: bool,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct QuestSpec {
+    pub id: String,
+    pub name: String,
+    pub description: String,
+    pub objective: QuestObjective,
+    #[serde(default)]
+    pub reward: Vec<ItemStack>,
+    /// Said when the player comes back before finishing.
+    #[serde(default)]
+    pub reminder: Option<String>,
+    /// Said when the player comes back after turning it in.
+    #[serde(default)]
+    pub thanks: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum QuestObjective {
+    /// Defeat `count` wild or trainer Pokemon of `species` after accepting.
+    Defeat { species: String, count: u16 },
+    /// Bring `qty` of `item`; they're handed over on turn-in. RON needs the
+    /// item id quoted here, e.g. `item: "Potion"`.
+    Fetch { item: ItemKind, qty: u16 },
+}
+
+/// A rectangle of the map whose encounters use a particular battle backdrop.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ZoneSpec {
+    pub id: String,
+    pub name: String,
+    pub x: u16,
+    pub y: u16,
+    pub width: u16,
+    pub height: u16,
+    pub backdrop: String,
+}
+
+impl ZoneSpec {
+    pub fn contains(&self, x: u16, y: u16) -> bool {
+        x >= self.x
+            && y >= self.y
+            && x < self.x.saturating_add(self.width)
+            && y < self.y.saturating_add(self.height)
+    }
+}
+
+/// Battle backdrop art: a text file of glyphs, colored through `palette`.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct BackdropSpec {
+    pub id: String,
+    pub path: String,
+    #[serde(default)]
+    pub palette: Vec<BackdropColor>,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct BackdropColor {
+    pub ch: char,
+    pub fg: (u8, u8, u8),
+    pub bg: (u8, u8, u8),
+}
+
+/// A loaded backdrop, ready to tile across a battle panel.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Backdrop {
+    pub id: String,
+    pub rows: Vec<Vec<char>>,
+    pub palette: Vec<BackdropColor>,
+}
+
+impl Backdrop {
+    /// Glyph and colors for a cell, scaling rows to `height` and tiling columns.
+    pub fn cell(&self, col: u16, row: u16, height: u16) -> Option<(char, &BackdropColor)> {
+        if self.rows.is_empty() || height == 0 {
+            return None;
+        }
+        let art_row = (row as usize * self.rows.len()) / height as usize;
+        let line = self.rows.get(art_row.min(self.rows.len() - 1))?;
+        if line.is_empty() {
+            return None;
+        }
+        let ch = line[col as usize % line.len()];
+        let color = self
+            .palette
+            .iter()
+            .find(|entry| entry.ch == ch)
+            .or_else(|| self.palette.first())?;
+        Some((ch, color))
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -131,7 +469,36 @@ pub async fn load_scenario(path: &Path) -> Result<ScenarioRuntime, String> {
     )
     .map_err(|e| format!("Failed to parse map: {}", e))?;
     let map = MapState::from_grid(grid);
-    Ok(ScenarioRuntime { manifest, map })
+    let mut backdrops = Vec::with_capacity(manifest.backdrops.len());
+    for spec in &manifest.backdrops {
+        backdrops.push(load_backdrop(path, spec).await?);
+    }
+    Ok(ScenarioRuntime {
+        manifest,
+        map,
+        backdrops,
+    })
+}
+
+async fn load_backdrop(dir: &Path, spec: &BackdropSpec) -> Result<Backdrop, String> {
+    let art_path = dir.join(&spec.path);
+    let art = tokio::fs::read_to_string(&art_path)
+        .await
+        .map_err(|e| format!("Failed to read {}: {}", art_path.display(), e))?;
+    let mut rows: Vec<Vec<char>> = art.lines().map(|line| line.chars().collect()).collect();
+    while rows.last().is_some_and(|row| row.is_empty()) {
+        rows.pop();
+    }
+    // Pad to a common width so columns tile seamlessly.
+    let width = rows.iter().map(Vec::len).max().unwrap_or(0);
+    for row in &mut rows {
+        row.resize(width, ' ');
+    }
+    Ok(Backdrop {
+        id: spec.id.clone(),
+        rows,
+        palette: spec.palette.clone(),
+    })
 }
 
 fn default_true() -> bool {
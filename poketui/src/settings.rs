@@ -0,0 +1,283 @@
+//! Preferences that outlive any one save: starting a new game or loading
+//! another keeps them. They are edited on the options screen (pause menu,
+//! Options) and written to `settings.json` next to the save after every
+//! change.
+
+use std::path::Path;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tui_persist::SaveFile;
+use tui_theme::Palette;
+
+use crate::anim::AnimSpeed;
+use crate::controls::ControlPreset;
+use crate::gamepad::PadLayout;
+use crate::state::MessagePacing;
+
+/// Loudest setting of the attack sound; 0 mutes it.
+pub const MAX_VOLUME: u8 = 10;
+const DEFAULT_VOLUME: u8 = 5;
+/// Preset the game ships with, before a user picks another.
+pub const DEFAULT_PALETTE: &str = "meadow";
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(default)]
+pub struct Settings {
+    pub controls: ControlPreset,
+    /// Button layout for gamepads, or `Off` to ignore them.
+    pub gamepad: PadLayout,
+    pub pacing: MessagePacing,
+    /// Attack sound volume, 0 to `MAX_VOLUME`.
+    pub volume: u8,
+    pub anim_speed: AnimSpeed,
+    /// Palette preset the next launch starts from. The theme is installed
+    /// once at startup, and a `theme.toml` preset still wins over this.
+    pub palette: String,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            controls: ControlPreset::default(),
+            gamepad: PadLayout::default(),
+            pacing: MessagePacing::default(),
+            volume: DEFAULT_VOLUME,
+            anim_speed: AnimSpeed::default(),
+            palette: DEFAULT_PALETTE.to_string(),
+        }
+    }
+}
+
+/// `settings.json` in `dir`, the directory the save lives in.
+pub fn file(dir: &Path) -> SaveFile {
+    SaveFile::new(dir.join("settings.json"))
+}
+
+/// The settings in `file`, or the defaults when it's missing or unreadable.
+/// Fields it doesn't have yet take their default values.
+pub fn load(file: &SaveFile) -> Settings {
+    file.load().unwrap_or_default()
+}
+
+/// A row on the options screen, top to bottom.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OptionRow {
+    Volume,
+    AutoAdvance,
+    MessageDelay,
+    Animations,
+    Controls,
+    Gamepad,
+    Palette,
+}
+
+impl OptionRow {
+    pub const ALL: [OptionRow; 7] = [
+        OptionRow::Volume,
+        OptionRow::AutoAdvance,
+        OptionRow::MessageDelay,
+        OptionRow::Animations,
+        OptionRow::Controls,
+        OptionRow::Gamepad,
+        OptionRow::Palette,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            OptionRow::Volume => "Sound",
+            OptionRow::AutoAdvance => "Auto-advance",
+            OptionRow::MessageDelay => "Message delay",
+            OptionRow::Animations => "Battle animations",
+            OptionRow::Controls => "Controls",
+            OptionRow::Gamepad => "Gamepad",
+            OptionRow::Palette => "Palette",
+        }
+    }
+
+    /// The current value as the screen shows it; sliders get a bar.
+    pub fn value(self, settings: &Settings) -> String {
+        match self {
+            OptionRow::Volume if settings.volume == 0 => "Off".to_string(),
+            OptionRow::Volume => slider(settings.volume, MAX_VOLUME),
+            OptionRow::AutoAdvance => on_off(settings.pacing.auto_advance).to_string(),
+            OptionRow::MessageDelay => {
+                let pacing = &settings.pacing;
+                let steps = |ms: u64| (ms / MessagePacing::DELAY_STEP_MS) as u8;
+                let seconds = format!("{:.2}", pacing.delay_ms as f64 / 1000.0);
+                format!(
+                    "{} {}s",
+                    slider(
+                        steps(pacing.delay_ms.saturating_sub(MessagePacing::MIN_DELAY_MS)),
+                        steps(MessagePacing::MAX_DELAY_MS - MessagePacing::MIN_DELAY_MS)
+                    ),
+                    seconds.trim_end_matches('0').trim_end_matches('.')
+                )
+            }
+            OptionRow::Animations => settings.anim_speed.label().to_string(),
+            OptionRow::Controls => settings.controls.label().to_string(),
+            OptionRow::Gamepad => settings.gamepad.label().to_string(),
+            OptionRow::Palette => settings.palette.clone(),
+        }
+    }
+
+    /// Shown under the list while the row is highlighted.
+    pub fn note(self, settings: &Settings) -> Option<&'static str> {
+        match self {
+            OptionRow::MessageDelay => Some("Only used while auto-advance is on"),
+            OptionRow::Gamepad if !cfg!(feature = "gamepad") => {
+                Some("Built without the gamepad feature; pads are ignored")
+            }
+            OptionRow::Gamepad => Some(settings.gamepad.note()),
+            OptionRow::Palette => Some("Takes effect the next time the game starts"),
+            _ => None,
+        }
+    }
+}
+
+/// `■■■■■□□□□□`-style bar with `value` of `max` filled, ten cells at most.
+fn slider(value: u8, max: u8) -> String {
+    let cells = max.min(10) as usize;
+    let filled = (value.min(max) as usize * cells).div_ceil(max.max(1) as usize);
+    format!("{}{}", "■".repeat(filled), "□".repeat(cells - filled))
+}
+
+fn on_off(on: bool) -> &'static str {
+    if on {
+        "On"
+    } else {
+        "Off"
+    }
+}
+
+/// Moves `row` by `step`: sliders go up or down and stop at their ends,
+/// toggles flip, and choices cycle around. Returns whether anything changed.
+pub fn adjust(settings: &mut Settings, row: OptionRow, step: i16) -> bool {
+    match row {
+        OptionRow::Volume => {
+            let volume = (settings.volume as i16 + step).clamp(0, MAX_VOLUME as i16) as u8;
+            let changed = volume != settings.volume;
+            settings.volume = volume;
+            changed
+        }
+        OptionRow::AutoAdvance => {
+            settings.pacing.auto_advance = !settings.pacing.auto_advance;
+            true
+        }
+        OptionRow::MessageDelay => {
+            let pacing = &mut settings.pacing;
+            let delta = MessagePacing::DELAY_STEP_MS as i64 * step as i64;
+            let delay = (pacing.delay_ms as i64 + delta).clamp(
+                MessagePacing::MIN_DELAY_MS as i64,
+                MessagePacing::MAX_DELAY_MS as i64,
+            ) as u64;
+            let changed = delay != pacing.delay_ms;
+            pacing.delay_ms = delay;
+            changed
+        }
+        OptionRow::Animations => {
+            settings.anim_speed = settings.anim_speed.cycle(step);
+            true
+        }
+        OptionRow::Controls => {
+            settings.controls = settings.controls.cycle(step);
+            true
+        }
+        OptionRow::Gamepad => {
+            settings.gamepad = settings.gamepad.cycle(step);
+            true
+        }
+        OptionRow::Palette => {
+            let names: Vec<&str> = Palette::PRESETS.iter().map(|(name, _)| *name).collect();
+            let len = names.len() as i16;
+            // A hand-edited name that isn't a preset counts as the first one.
+            let index = names
+                .iter()
+                .position(|name| name.eq_ignore_ascii_case(&settings.palette))
+                .unwrap_or(0) as i16;
+            settings.palette = names[(index + step).rem_euclid(len) as usize].to_string();
+            true
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use crossterm::event::KeyCode;
+
+    use super::*;
+    use crate::controls::{self, Input};
+    use crate::gamepad::{self, PadButton};
+
+    /// A fresh directory for one test's `settings.json`.
+    fn save_dir(name: &str) -> PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("poketui-settings-{name}-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn chosen_control_preset_rebinds_keys_after_a_restart() {
+        let dir = save_dir("controls");
+        let mut settings = load(&file(&dir));
+        assert_eq!(controls::input(settings.controls, KeyCode::Char('k')), None);
+
+        assert!(adjust(&mut settings, OptionRow::Controls, 1));
+        file(&dir).save(&settings).unwrap();
+
+        let restarted = load(&file(&dir));
+        assert_eq!(restarted.controls, ControlPreset::Vim);
+        assert_eq!(
+            controls::input(restarted.controls, KeyCode::Char('k')),
+            Some(Input::Up)
+        );
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn turning_pads_off_sticks_across_restarts() {
+        let dir = save_dir("gamepad");
+        let mut settings = load(&file(&dir));
+        assert_eq!(
+            gamepad::input(settings.gamepad, PadButton::South),
+            Some(Input::Confirm)
+        );
+
+        settings.gamepad = PadLayout::Off;
+        file(&dir).save(&settings).unwrap();
+
+        let restarted = load(&file(&dir));
+        assert_eq!(gamepad::input(restarted.gamepad, PadButton::South), None);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn settings_from_before_the_gamepad_row_keep_their_options() {
+        let dir = save_dir("older");
+        fs::write(
+            dir.join("settings.json"),
+            r#"{"controls": "Vim", "volume": 7}"#,
+        )
+        .unwrap();
+
+        let settings = load(&file(&dir));
+        assert_eq!(settings.controls, ControlPreset::Vim);
+        assert_eq!(settings.volume, 7);
+        assert_eq!(settings.gamepad, PadLayout::Xbox);
+        assert_eq!(settings.palette, DEFAULT_PALETTE);
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn unreadable_settings_start_from_the_defaults() {
+        let dir = save_dir("corrupt");
+        fs::write(dir.join("settings.json"), "{ not json").unwrap();
+
+        assert_eq!(load(&file(&dir)), Settings::default());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,95 @@
+//! Marts: what a shop sells, what the bag fetches when sold back, and the
+//! prize money battles pay out.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::items;
+use crate::scenario::ShopSpec;
+use crate::state::{AppState, BattleKind, BattleState, ItemKind};
+
+/// Money a new game starts with.
+pub const STARTING_MONEY: u32 = 1000;
+
+/// Prize per level of the opponent's strongest Pokemon.
+const WILD_PRIZE_PER_LEVEL: u32 = 5;
+const TRAINER_PRIZE_PER_LEVEL: u32 = 40;
+const BOSS_PRIZE_PER_LEVEL: u32 = 100;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum ShopTab {
+    #[default]
+    Buy,
+    Sell,
+}
+
+/// The shop screen the player is standing in.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ShopState {
+    pub shop_id: String,
+    #[serde(default)]
+    pub tab: ShopTab,
+    #[serde(default)]
+    pub selected: usize,
+    /// Outcome of the last purchase or sale, shown under the list.
+    #[serde(default)]
+    pub notice: Option<String>,
+}
+
+/// One line of the shop list.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ShopRow {
+    pub kind: ItemKind,
+    pub price: u32,
+    /// How many the player has, on the sell tab.
+    pub owned: Option<u16>,
+}
+
+/// Items sell back for half their mart price.
+pub fn sell_price(state: &AppState, kind: &ItemKind) -> u32 {
+    items::price(state, kind) / 2
+}
+
+/// The rows of `tab`: the shop's stock to buy, or the bag to sell from.
+pub fn rows(state: &AppState, shop: &ShopSpec, tab: ShopTab) -> Vec<ShopRow> {
+    match tab {
+        ShopTab::Buy => shop
+            .stock
+            .iter()
+            .map(|item| ShopRow {
+                kind: item.kind.clone(),
+                price: item.price(state),
+                owned: None,
+            })
+            .collect(),
+        ShopTab::Sell => state
+            .inventory
+            .iter()
+            .filter(|stack| stack.qty > 0)
+            .map(|stack| ShopRow {
+                kind: stack.kind.clone(),
+                price: sell_price(state, &stack.kind),
+                owned: Some(stack.qty),
+            })
+            .collect(),
+    }
+}
+
+/// Money for winning `battle`, scaled by the opponent's strongest Pokemon.
+pub fn prize_money(battle: &BattleState) -> u32 {
+    let (per_level, level) = match (battle.kind, battle.trainer.as_ref()) {
+        (BattleKind::Wild, _) => (WILD_PRIZE_PER_LEVEL, battle.enemy_level),
+        (BattleKind::Boss, _) => (BOSS_PRIZE_PER_LEVEL, battle.enemy_level),
+        (BattleKind::Trainer, trainer) => (
+            TRAINER_PRIZE_PER_LEVEL,
+            trainer
+                .and_then(|trainer| trainer.party.iter().map(|mon| mon.level).max())
+                .unwrap_or(battle.enemy_level),
+        ),
+    };
+    per_level * level as u32
+}
+
+pub fn format_money(amount: u32) -> String {
+    format!("₽{amount}")
+}
@@ -0,0 +1,117 @@
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum Milestone {
+    FirstCatch,
+    Relic,
+    Boss,
+}
+
+impl Milestone {
+    pub const ALL: [Milestone; 3] = [Milestone::FirstCatch, Milestone::Relic, Milestone::Boss];
+
+    pub fn label(self) -> &'static str {
+        match self {
+            Milestone::FirstCatch => "First catch",
+            Milestone::Relic => "Relic",
+            Milestone::Boss => "Boss",
+        }
+    }
+
+    /// Reaching this milestone ends the run.
+    pub fn is_final(self) -> bool {
+        matches!(self, Milestone::Boss)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Split {
+    pub milestone: Milestone,
+    pub at_ms: u64,
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct SpeedrunState {
+    /// Set from `--speedrun`; the timer stays hidden and idle otherwise
+    #[serde(default)]
+    pub enabled: bool,
+    pub running: bool,
+    pub elapsed_ms: u64,
+    pub splits: Vec<Split>,
+}
+
+impl SpeedrunState {
+    /// Reset and start the clock for a new game.
+    pub fn start(&mut self) {
+        self.running = self.enabled;
+        self.elapsed_ms = 0;
+        self.splits.clear();
+    }
+
+    /// Advance the clock by one tick. Returns true if time moved.
+    pub fn advance(&mut self, ms: u64) -> bool {
+        if !self.running {
+            return false;
+        }
+        self.elapsed_ms = self.elapsed_ms.saturating_add(ms);
+        true
+    }
+
+    /// Record a split the first time a milestone is reached during a run.
+    /// Returns true if the split was new; final milestones also stop the clock.
+    pub fn split(&mut self, milestone: Milestone) -> bool {
+        if !self.running || self.split_time(milestone).is_some() {
+            return false;
+        }
+        self.splits.push(Split {
+            milestone,
+            at_ms: self.elapsed_ms,
+        });
+        if milestone.is_final() {
+            self.running = false;
+        }
+        true
+    }
+
+    pub fn split_time(&self, milestone: Milestone) -> Option<u64> {
+        self.splits
+            .iter()
+            .find(|split| split.milestone == milestone)
+            .map(|split| split.at_ms)
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.splits.iter().any(|split| split.milestone.is_final())
+    }
+
+    /// Plain-text splits file written when the run completes.
+    pub fn splits_report(&self, route: &str) -> String {
+        let mut report = format!("poketui speedrun - {}\n\n", route);
+        for milestone in Milestone::ALL {
+            let time = self
+                .split_time(milestone)
+                .map(format_time)
+                .unwrap_or_else(|| "-".to_string());
+            report.push_str(&format!("{:<12} {:>10}\n", milestone.label(), time));
+        }
+        report.push_str(&format!(
+            "\n{:<12} {:>10}\n",
+            "Final",
+            format_time(self.elapsed_ms)
+        ));
+        report
+    }
+}
+
+/// `m:ss.t`, or `h:mm:ss.t` past the hour.
+pub fn format_time(ms: u64) -> String {
+    let tenths = (ms / 100) % 10;
+    let secs = ms / 1000;
+    let (hours, minutes, seconds) = (secs / 3600, (secs / 60) % 60, secs % 60);
+    if hours > 0 {
+        format!("{}:{:02}:{:02}.{}", hours, minutes, seconds, tenths)
+    } else {
+        format!("{}:{:02}.{}", minutes, seconds, tenths)
+    }
+}
@@ -1,3 +1,10 @@
+//! Kitty sprites drawn over the ratatui buffer after each frame.
+//!
+//! Every sprite sits on a [`SpriteLayer`] and is cut down to a clip rect,
+//! usually the inside of the panel drawing it. Opening a layer with
+//! [`open_layer`] hides every sprite below it, which is how modals keep the
+//! map and panels from showing through.
+
 use std::collections::HashMap;
 use std::io::{self, Write};
 use std::sync::{Arc, Mutex, OnceLock};
@@ -5,32 +12,74 @@ use std::sync::{Arc, Mutex, OnceLock};
 use crossterm::{cursor::MoveTo, queue, style::Print};
 use ratatui::backend::{Backend, ClearType, CrosstermBackend, WindowSize};
 use ratatui::buffer::Cell;
-use ratatui::layout::{Position, Size};
+use ratatui::layout::{Position, Rect, Size};
+use sprite_cache::{kitty, CellCrop, SpriteFrame};
+
+/// Stacking order, lowest first.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum SpriteLayer {
+    #[default]
+    Map,
+    Panel,
+    Modal,
+}
+
+impl SpriteLayer {
+    /// Kitty z-index. Zero and up draws over text, like unlayered images.
+    fn z_index(self) -> i32 {
+        self as i32
+    }
+}
 
 #[derive(Debug, Clone)]
 pub struct SpriteEntry {
     pub id: u32,
-    pub x: u16,
-    pub y: u16,
-    pub data: String,
+    pub layer: SpriteLayer,
+    /// Where the whole sprite would go, scaled to these cells.
+    pub area: Rect,
+    /// Cells the sprite may draw into; the rest of it is cut off.
+    pub clip: Rect,
+    pub frame: SpriteFrame,
+}
+
+impl SpriteEntry {
+    /// The cells left after clipping, or `None` when nothing is left.
+    fn visible(&self) -> Option<Rect> {
+        let visible = self.area.intersection(self.clip);
+        (!visible.is_empty()).then_some(visible)
+    }
 }
 
 #[derive(Default, Debug, Clone)]
 pub struct SpriteRegistry {
     sprites: Vec<SpriteEntry>,
+    top: SpriteLayer,
 }
 
 impl SpriteRegistry {
-    pub fn set(&mut self, id: u32, x: u16, y: u16, data: String) {
-        self.sprites.push(SpriteEntry { id, x, y, data });
+    pub fn set(&mut self, entry: SpriteEntry) {
+        self.sprites.retain(|sprite| sprite.id != entry.id);
+        self.sprites.push(entry);
+    }
+
+    /// Hides every sprite below `layer` until the next [`SpriteRegistry::clear`].
+    pub fn open(&mut self, layer: SpriteLayer) {
+        self.top = self.top.max(layer);
     }
 
     pub fn clear(&mut self) {
         self.sprites.clear();
+        self.top = SpriteLayer::default();
     }
 
-    pub fn entries(&self) -> Vec<SpriteEntry> {
-        self.sprites.clone()
+    /// Sprites that should be on screen, with what is left of them after
+    /// clipping.
+    fn visible(&self) -> Vec<(SpriteEntry, Rect)> {
+        self.sprites
+            .iter()
+            .filter(|sprite| sprite.layer >= self.top)
+            .filter_map(|sprite| Some((sprite.clone(), sprite.visible()?)))
+            .collect()
     }
 }
 
@@ -42,10 +91,24 @@ pub fn sprite_registry() -> Arc<Mutex<SpriteRegistry>> {
         .clone()
 }
 
-pub fn set_sprite(id: u32, x: u16, y: u16, data: String) {
+/// Draws `frame` scaled to `area` on `layer`, cut down to `clip`.
+pub fn set_sprite(id: u32, layer: SpriteLayer, area: Rect, clip: Rect, frame: &SpriteFrame) {
+    let registry = sprite_registry();
+    let mut registry = registry.lock().expect("sprite registry lock");
+    registry.set(SpriteEntry {
+        id,
+        layer,
+        area,
+        clip,
+        frame: frame.clone(),
+    });
+}
+
+/// Hides every sprite below `layer` for the rest of this frame.
+pub fn open_layer(layer: SpriteLayer) {
     let registry = sprite_registry();
     let mut registry = registry.lock().expect("sprite registry lock");
-    registry.set(id, x, y, data);
+    registry.open(layer);
 }
 
 pub fn clear_sprites() {
@@ -54,11 +117,20 @@ pub fn clear_sprites() {
     registry.clear();
 }
 
+/// What was last sent for one sprite id.
+#[derive(Debug, Clone, PartialEq)]
+struct Placed {
+    layer: SpriteLayer,
+    area: Rect,
+    visible: Rect,
+    frame: SpriteFrame,
+}
+
 #[derive(Debug, Clone)]
 pub struct SpriteBackend<W: Write> {
     inner: CrosstermBackend<W>,
     registry: Arc<Mutex<SpriteRegistry>>,
-    prev_entries: HashMap<u32, SpriteEntry>,
+    prev_entries: HashMap<u32, Placed>,
 }
 
 impl<W: Write> SpriteBackend<W> {
@@ -79,39 +151,52 @@ impl<W: Write> Backend for SpriteBackend<W> {
         self.inner.draw(content)?;
         let sprites = {
             let registry = self.registry.lock().expect("sprite registry lock");
-            registry.entries()
+            registry.visible()
         };
         let mut current_entries = HashMap::with_capacity(sprites.len());
-        for entry in sprites {
-            current_entries.insert(entry.id, entry);
+        for (entry, visible) in sprites {
+            let placed = Placed {
+                layer: entry.layer,
+                area: entry.area,
+                visible,
+                frame: entry.frame,
+            };
+            current_entries.insert(entry.id, placed);
         }
 
-        // Delete sprites that disappeared or changed
+        // Delete sprites that disappeared, were hidden or changed
         for (id, prev) in &self.prev_entries {
-            match current_entries.get(id) {
-                None => {
-                    queue!(self.inner, Print(format!("\x1b_Ga=d,d=i,i={id}\x1b\\")))?;
-                }
-                Some(cur)
-                    if cur.x != prev.x || cur.y != prev.y || cur.data != prev.data =>
-                {
-                    queue!(self.inner, Print(format!("\x1b_Ga=d,d=i,i={id}\x1b\\")))?;
-                }
-                _ => {}
+            if current_entries.get(id) != Some(prev) {
+                queue!(self.inner, Print(kitty::delete_image(*id)))?;
             }
         }
 
         // Draw sprites that are new or changed
         for (id, cur) in &current_entries {
-            match self.prev_entries.get(id) {
-                None => {
-                    queue!(self.inner, MoveTo(cur.x, cur.y), Print(&cur.data))?;
-                }
-                Some(prev) if cur.x != prev.x || cur.y != prev.y || cur.data != prev.data => {
-                    queue!(self.inner, MoveTo(cur.x, cur.y), Print(&cur.data))?;
-                }
-                _ => {}
+            if self.prev_entries.get(id) == Some(cur) {
+                continue;
             }
+            let crop = CellCrop {
+                x: cur.visible.x - cur.area.x,
+                y: cur.visible.y - cur.area.y,
+                cols: cur.visible.width,
+                rows: cur.visible.height,
+            };
+            let Ok(sequence) = kitty::kitty_sequence_cropped(
+                &cur.frame,
+                cur.area.width,
+                cur.area.height,
+                crop,
+                cur.layer.z_index(),
+                *id,
+            ) else {
+                continue;
+            };
+            queue!(
+                self.inner,
+                MoveTo(cur.visible.x, cur.visible.y),
+                Print(sequence)
+            )?;
         }
 
         self.prev_entries = current_entries;
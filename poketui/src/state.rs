@@ -1,11 +1,26 @@
+use schemars::gen::SchemaGenerator;
+use schemars::schema::Schema;
 use schemars::JsonSchema;
-use serde::{Deserialize, Serialize};
+use serde::de::{self, EnumAccess, VariantAccess, Visitor};
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+use sprite_cache::SpriteData;
+use std::borrow::Cow;
 use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
 use tui_map::core::{MapGrid, MapRead, MapSize, TileKind};
 use tui_dispatch_debug::debug::{ron_string, DebugSection, DebugState};
 
-use crate::scenario::ScenarioRuntime;
-use crate::sprite::SpriteData;
+use crate::anim::HitAnim;
+use crate::hunt::HuntStats;
+use crate::moves::{Effectiveness, KnownMove, MoveInfo, MAX_MOVES};
+use crate::quest::QuestLog;
+use crate::replay::{BattleLog, ReplayState};
+use crate::scenario::{ScenarioRuntime, TrainerMon};
+use crate::settings::Settings;
+use crate::shop::{ShopState, STARTING_MONEY};
+use crate::speedrun::SpeedrunState;
+use crate::water::FishingState;
+use crate::weather::Weather;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum Direction {
@@ -45,6 +60,31 @@ pub struct PokemonInfo {
     pub sprite_back_default: Option<String>,
     pub sprite_front_animated: Option<String>,
     pub sprite_back_animated: Option<String>,
+    #[serde(default)]
+    pub types: Vec<String>,
+    /// Damaging level-up moves, lowest level first.
+    #[serde(default)]
+    pub learnset: Vec<MoveInfo>,
+    #[serde(default)]
+    pub evolution: Option<Evolution>,
+}
+
+/// What a species evolves into by levelling up, and from which level.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Evolution {
+    pub into: String,
+    pub level: u8,
+}
+
+/// A party member asking to evolve, shown once the battle that levelled it
+/// is over.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct EvolutionPrompt {
+    pub member_index: usize,
+    pub into: String,
+    /// Confirmed; waiting on the evolved species to load.
+    #[serde(default)]
+    pub loading: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -57,31 +97,145 @@ pub struct PartyMember {
     pub ability_id: Option<String>,
     #[serde(default)]
     pub ability_cd: u8,
+    #[serde(default)]
+    pub moves: Vec<KnownMove>,
+    /// Set on the party screen; the species name stands in until then.
+    #[serde(default)]
+    pub nickname: Option<String>,
 }
 
-#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
-pub enum ItemKind {
-    Potion,
-    SuperPotion,
-    PokeBall,
-}
+impl PartyMember {
+    /// Nickname if it has one, else the species.
+    pub fn name(&self) -> &str {
+        self.nickname.as_deref().unwrap_or(&self.info.name)
+    }
 
-impl ItemKind {
-    pub fn label(self) -> &'static str {
-        match self {
-            ItemKind::Potion => "Potion",
-            ItemKind::SuperPotion => "Super Potion",
-            ItemKind::PokeBall => "Poke Ball",
+    /// Fills the move list with the latest moves the species knows by its
+    /// level, for a Pokemon that has just joined.
+    pub fn learn_starting_moves(&mut self) {
+        let known: Vec<&MoveInfo> = self
+            .info
+            .learnset
+            .iter()
+            .filter(|mv| mv.level <= self.level)
+            .collect();
+        let skip = known.len().saturating_sub(MAX_MOVES);
+        self.moves = known[skip..]
+            .iter()
+            .map(|&mv| KnownMove::from(mv))
+            .collect();
+    }
+
+    /// Learns the moves unlocked by levelling up from `from`, forgetting the
+    /// oldest once four are known. Returns the names of the new moves.
+    pub fn learn_moves_since(&mut self, from: u8) -> Vec<String> {
+        let mut learned = Vec::new();
+        for mv in &self.info.learnset {
+            if mv.level <= from || mv.level > self.level {
+                continue;
+            }
+            if self.moves.iter().any(|known| known.name == mv.name) {
+                continue;
+            }
+            if self.moves.len() >= MAX_MOVES {
+                self.moves.remove(0);
+            }
+            self.moves.push(KnownMove::from(mv));
+            learned.push(mv.name.clone());
         }
+        learned
     }
 
-    pub fn heal_amount(self) -> u16 {
-        match self {
-            ItemKind::Potion => 20,
-            ItemKind::SuperPotion => 50,
-            ItemKind::PokeBall => 0,
+    pub fn restore_pp(&mut self, amount: u8) {
+        for mv in &mut self.moves {
+            mv.pp = mv.pp.saturating_add(amount).min(mv.max_pp);
         }
     }
+
+    pub fn pp_full(&self) -> bool {
+        self.moves.iter().all(|mv| mv.pp >= mv.max_pp)
+    }
+}
+
+/// Id of an item in the bag, a shop or a scenario manifest. The built-in
+/// items keep the names they had back when this was a plain enum, so older
+/// saves and manifests still read; anything else is defined by the
+/// scenario's `items` list (see `items::def`).
+///
+/// In RON, items are written as bare identifiers (`kind: GreatBall`), except
+/// inside internally tagged enums such as quest objectives, where RON loses
+/// the name and it has to be quoted (`item: "Potion"`).
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct ItemKind(Cow<'static, str>);
+
+impl ItemKind {
+    pub const POTION: ItemKind = ItemKind(Cow::Borrowed("Potion"));
+    pub const SUPER_POTION: ItemKind = ItemKind(Cow::Borrowed("SuperPotion"));
+    pub const POKE_BALL: ItemKind = ItemKind(Cow::Borrowed("PokeBall"));
+    pub const ETHER: ItemKind = ItemKind(Cow::Borrowed("Ether"));
+
+    pub fn new(id: impl Into<String>) -> Self {
+        Self(Cow::Owned(id.into()))
+    }
+
+    pub fn id(&self) -> &str {
+        &self.0
+    }
+}
+
+impl Serialize for ItemKind {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0)
+    }
+}
+
+impl<'de> Deserialize<'de> for ItemKind {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        // Asking for an enum is what gets RON to hand over a bare identifier;
+        // JSON saves come through as a plain string either way.
+        deserializer.deserialize_enum("ItemKind", &[], ItemKindVisitor)
+    }
+}
+
+struct ItemKindVisitor;
+
+impl<'de> Visitor<'de> for ItemKindVisitor {
+    type Value = ItemKind;
+
+    fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
+        formatter.write_str("an item id")
+    }
+
+    fn visit_str<E: de::Error>(self, id: &str) -> Result<ItemKind, E> {
+        Ok(ItemKind::new(id))
+    }
+
+    fn visit_enum<A: EnumAccess<'de>>(self, data: A) -> Result<ItemKind, A::Error> {
+        let (VariantId(kind), variant) = data.variant()?;
+        variant.unit_variant()?;
+        Ok(kind)
+    }
+}
+
+/// The variant name of an item written as a unit variant.
+struct VariantId(ItemKind);
+
+impl<'de> Deserialize<'de> for VariantId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        deserializer
+            .deserialize_identifier(ItemKindVisitor)
+            .map(VariantId)
+    }
+}
+
+impl JsonSchema for ItemKind {
+    fn schema_name() -> String {
+        "ItemKind".to_string()
+    }
+
+    fn json_schema(generator: &mut SchemaGenerator) -> Schema {
+        String::json_schema(generator)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -259,6 +413,10 @@ impl MapState {
         matches!(self.tile(x, y), Tile::Grass)
     }
 
+    pub fn is_water(&self, x: u16, y: u16) -> bool {
+        matches!(self.tile(x, y), Tile::Water)
+    }
+
     fn index(&self, x: u16, y: u16) -> usize {
         (y as usize * self.width as usize) + x as usize
     }
@@ -299,12 +457,16 @@ pub enum GameMode {
     PokemonSelect,
     Overworld,
     Battle,
+    Shop,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct MenuState {
     pub selected: usize,
     pub has_save: bool,
+    /// The save on disk is a hardcore run; shown as a badge on Continue.
+    #[serde(default)]
+    pub save_hardcore: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -313,6 +475,37 @@ pub struct PokemonSelectState {
     pub selected: usize,
     pub preview_info: Option<PokemonInfo>,
     pub preview_sprite: SpriteState,
+    #[serde(default)]
+    pub hardcore: bool,
+}
+
+/// Auto-advance for battle and overworld messages.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct MessagePacing {
+    /// Off means every message waits for Enter
+    pub auto_advance: bool,
+    /// How long a message stays up before moving on
+    pub delay_ms: u64,
+}
+
+impl Default for MessagePacing {
+    fn default() -> Self {
+        Self {
+            auto_advance: false,
+            delay_ms: 1500,
+        }
+    }
+}
+
+impl MessagePacing {
+    pub const MIN_DELAY_MS: u64 = 500;
+    pub const MAX_DELAY_MS: u64 = 5000;
+    pub const DELAY_STEP_MS: u64 = 250;
+
+    /// Ticks a message stays up, rounded up so short delays still show it once.
+    pub fn delay_ticks(&self) -> u16 {
+        self.delay_ms.div_ceil(TICK_MS).max(1) as u16
+    }
 }
 
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -321,11 +514,82 @@ pub struct PauseMenuState {
     pub selected: usize,
 }
 
+/// The options screen, opened over the pause menu.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct OptionsScreenState {
+    pub is_open: bool,
+    /// Index into `OptionRow::ALL`.
+    pub selected: usize,
+}
+
+/// A gauntlet being fought through.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct GauntletRun {
+    pub id: String,
+    /// Index of the battle in progress.
+    pub stage: usize,
+    /// Someone levelled up along the way; evolution waits until the run ends.
+    #[serde(default)]
+    pub leveled: bool,
+}
+
+/// A conversation with a scenario NPC in progress.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct DialogueState {
+    pub npc_id: String,
+    /// Id of the dialogue node on screen.
+    pub node: String,
+    /// Highlighted choice.
+    pub selected: usize,
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum BoxPane {
+    #[default]
+    Party,
+    Box,
+}
+
+/// The PC box screen, opened from the pause menu.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct BoxScreenState {
+    pub is_open: bool,
+    pub pane: BoxPane,
+    pub party_index: usize,
+    pub box_index: usize,
+    /// Release was pressed once on the selection; pressing it again lets go.
+    pub release_armed: bool,
+}
+
+/// The party screen, opened from the pause menu.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct PartyScreenState {
+    pub is_open: bool,
+    pub selected: usize,
+    /// The selected member is picked up: up and down carry it along the
+    /// party instead of moving the cursor.
+    pub moving: bool,
+    /// Nickname being typed for the selected member.
+    pub naming: Option<String>,
+}
+
+impl BoxScreenState {
+    pub fn selected(&self) -> usize {
+        match self.pane {
+            BoxPane::Party => self.party_index,
+            BoxPane::Box => self.box_index,
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
 pub enum BattleStage {
     Intro,
     Menu,
+    MoveMenu,
     ItemMenu,
+    /// Picking which fainted party member a revive is used on.
+    ReviveMenu,
     PlayerCombo,
     EnemyTurn,
     Victory,
@@ -337,6 +601,36 @@ pub enum BattleStage {
 pub enum BattleKind {
     Wild,
     Boss,
+    Trainer,
+}
+
+/// The opponent's side of a trainer battle.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct TrainerState {
+    pub id: String,
+    pub name: String,
+    pub party: Vec<TrainerMon>,
+    /// Index into `party` of the Pokemon currently out.
+    pub active: usize,
+    /// The Pokemon out has already used its one Recover.
+    #[serde(default)]
+    pub recovered: bool,
+}
+
+impl TrainerState {
+    /// Sends out the next Pokemon in the party, if there is one left.
+    pub fn send_next(&mut self) -> Option<&TrainerMon> {
+        if self.active + 1 >= self.party.len() {
+            return None;
+        }
+        self.active += 1;
+        self.recovered = false;
+        self.party.get(self.active)
+    }
+
+    pub fn remaining(&self) -> usize {
+        self.party.len().saturating_sub(self.active)
+    }
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -353,17 +647,40 @@ pub struct BattleState {
     pub enemy_hp_max: u16,
     pub menu_index: usize,
     #[serde(default)]
+    pub move_index: usize,
+    #[serde(default)]
     pub item_index: usize,
+    /// Row of the fainted-member list while a revive is being used.
+    #[serde(default)]
+    pub revive_index: usize,
     #[serde(default)]
     pub combo_hits: Vec<ComboHit>,
     #[serde(default)]
     pub guard_pct: u8,
     #[serde(default)]
     pub guard_turns: u8,
+    /// Stages X items have raised the Pokemon out by; each is worth half the
+    /// stat again. Gone when it faints or the battle ends.
+    #[serde(default)]
+    pub attack_stage: u8,
+    #[serde(default)]
+    pub defense_stage: u8,
     #[serde(default)]
     pub captured: bool,
     pub message: String,
     pub pending_enemy_damage: Option<u16>,
+    /// Scenario zone the encounter started in.
+    #[serde(default)]
+    pub zone: Option<String>,
+    #[serde(default)]
+    pub backdrop: Option<String>,
+    #[serde(default)]
+    pub trainer: Option<TrainerState>,
+    #[serde(default)]
+    pub shiny: bool,
+    /// Hit animations still to play, front first.
+    #[serde(default)]
+    pub anims: VecDeque<HitAnim>,
 }
 
 impl BattleState {
@@ -384,13 +701,22 @@ impl BattleState {
             enemy_hp: 1,
             enemy_hp_max: 1,
             menu_index: 0,
+            move_index: 0,
             item_index: 0,
+            revive_index: 0,
             combo_hits: Vec::new(),
             guard_pct: 0,
             guard_turns: 0,
+            attack_stage: 0,
+            defense_stage: 0,
             captured: false,
             message: "A wild Pokemon appeared!".to_string(),
             pending_enemy_damage: None,
+            zone: None,
+            backdrop: None,
+            trainer: None,
+            shiny: false,
+            anims: VecDeque::new(),
         }
     }
 }
@@ -417,6 +743,14 @@ pub struct ComboHit {
     pub ability_name: Option<String>,
     #[serde(default)]
     pub ability_damage: bool,
+    /// HP the enemy restores instead of attacking.
+    #[serde(default)]
+    pub heal: u16,
+    #[serde(default)]
+    pub effectiveness: Effectiveness,
+    /// Thrown off by the weather; `damage` is 0.
+    #[serde(default)]
+    pub missed: bool,
 }
 
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -443,6 +777,39 @@ pub struct AppState {
     pub party_sprites: Vec<SpriteState>,
     #[serde(default)]
     pub active_party_index: usize,
+    /// Caught Pokemon that didn't fit in the party, oldest first.
+    #[serde(default)]
+    pub pc_box: Vec<PartyMember>,
+    #[serde(default)]
+    pub box_screen: BoxScreenState,
+    #[serde(default)]
+    pub party_screen: PartyScreenState,
+    #[serde(default)]
+    pub hunt: HuntStats,
+    #[serde(default)]
+    pub evolution: Option<EvolutionPrompt>,
+    /// The shiny hunting page is open over the pause menu.
+    #[serde(default)]
+    pub stats_open: bool,
+    #[serde(default)]
+    pub dialogue: Option<DialogueState>,
+    /// A line is out; the overworld waits on the bite.
+    #[serde(default)]
+    pub fishing: Option<FishingState>,
+    #[serde(default)]
+    pub quests: QuestLog,
+    /// The quest log is open over the pause menu.
+    #[serde(default)]
+    pub quest_log_open: bool,
+    #[serde(default)]
+    pub gauntlet: Option<GauntletRun>,
+    /// Ids of the gauntlets cleared, in the order their badges were won.
+    #[serde(default)]
+    pub badges: Vec<String>,
+    #[serde(default = "default_money")]
+    pub money: u32,
+    #[serde(default)]
+    pub shop: Option<ShopState>,
     // Legacy fields for save migration
     pub player_info: Option<PokemonInfo>,
     #[serde(default = "default_player_level")]
@@ -455,8 +822,15 @@ pub struct AppState {
     pub inventory: Vec<ItemStack>,
     #[serde(default)]
     pub message_queue: VecDeque<String>,
+    /// Ticks the current message has been on screen, for auto-advance.
     #[serde(default)]
     pub message_timer: u16,
+    /// Kept in the settings file rather than with the run; a loaded game
+    /// takes on the current ones.
+    #[serde(default)]
+    pub settings: Settings,
+    #[serde(default)]
+    pub options: OptionsScreenState,
     #[serde(default)]
     pub wild_wins: u16,
     #[serde(default)]
@@ -465,14 +839,31 @@ pub struct AppState {
     pub boss_defeated: bool,
     #[serde(default)]
     pub fired_event_ids: HashSet<String>,
+    /// Ids of the scenario trainers already beaten.
+    #[serde(default)]
+    pub defeated_trainers: HashSet<String>,
     #[serde(default)]
     pub defeat_counts: HashMap<String, u16>,
     #[serde(default)]
     pub pickups: Vec<Pickup>,
+    #[serde(default)]
+    pub speedrun: SpeedrunState,
+    /// Permadeath run: fainted party members are lost for good and a defeat
+    /// deletes the save.
+    #[serde(default)]
+    pub hardcore: bool,
+    #[serde(default)]
+    pub weather: Weather,
     pub enemy_info: Option<PokemonInfo>,
     pub player_sprite: SpriteState,
     pub enemy_sprite: SpriteState,
     pub battle: Option<BattleState>,
+    /// Rolls, hits and frames of the battle going on, or the last one.
+    #[serde(default)]
+    pub battle_log: BattleLog,
+    /// Set while `battle` is a replay of `battle_log` rather than a fight.
+    #[serde(default)]
+    pub replay: Option<ReplayState>,
     pub menu: Option<MenuState>,
     pub pokemon_select: Option<PokemonSelectState>,
     pub pause_menu: PauseMenuState,
@@ -502,6 +893,20 @@ impl AppState {
             party: Vec::new(),
             party_sprites: Vec::new(),
             active_party_index: 0,
+            pc_box: Vec::new(),
+            box_screen: BoxScreenState::default(),
+            party_screen: PartyScreenState::default(),
+            hunt: HuntStats::default(),
+            evolution: None,
+            stats_open: false,
+            dialogue: None,
+            fishing: None,
+            quests: QuestLog::default(),
+            quest_log_open: false,
+            gauntlet: None,
+            badges: Vec::new(),
+            money: default_money(),
+            shop: None,
             player_info: None,
             player_level: default_player_level(),
             player_exp: default_player_exp(),
@@ -509,19 +914,28 @@ impl AppState {
             inventory: default_inventory(),
             message_queue: VecDeque::new(),
             message_timer: 0,
+            settings: Settings::default(),
+            options: OptionsScreenState::default(),
             wild_wins: 0,
             has_relic: false,
             boss_defeated: false,
             fired_event_ids: HashSet::new(),
+            defeated_trainers: HashSet::new(),
             defeat_counts: HashMap::new(),
             pickups: Vec::new(),
+            speedrun: SpeedrunState::default(),
+            hardcore: false,
+            weather: Weather::Clear,
             enemy_info: None,
             player_sprite: SpriteState::default(),
             enemy_sprite: SpriteState::default(),
             battle: None,
+            battle_log: BattleLog::default(),
+            replay: None,
             menu: Some(MenuState {
                 selected: 0,
                 has_save: false,
+                save_hardcore: false,
             }),
             pokemon_select: None,
             pause_menu: PauseMenuState::default(),
@@ -534,7 +948,7 @@ impl AppState {
 
     pub fn player_name(&self) -> String {
         self.active_member()
-            .map(|member| member.info.name.clone())
+            .map(|member| member.name().to_string())
             .or_else(|| self.player_info.as_ref().map(|info| info.name.clone()))
             .unwrap_or_else(|| "partner".to_string())
     }
@@ -596,18 +1010,38 @@ impl DebugState for AppState {
         let mut sections = vec![
             DebugSection::new("Mode")
                 .entry("mode", ron_string(&self.mode))
-                .entry("message", ron_string(&self.message)),
+                .entry("message", ron_string(&self.message))
+                .entry("hardcore", ron_string(&self.hardcore)),
             DebugSection::new("Player")
                 .entry("x", ron_string(&self.player.x))
                 .entry("y", ron_string(&self.player.y))
                 .entry("steps", ron_string(&self.player.steps)),
         ];
 
+        if self.speedrun.enabled {
+            sections.push(
+                DebugSection::new("Speedrun")
+                    .entry("running", ron_string(&self.speedrun.running))
+                    .entry("elapsed_ms", ron_string(&self.speedrun.elapsed_ms))
+                    .entry("splits", ron_string(&self.speedrun.splits)),
+            );
+        }
+
+        if let Some(run) = &self.gauntlet {
+            sections.push(
+                DebugSection::new("Gauntlet")
+                    .entry("id", ron_string(&run.id))
+                    .entry("stage", ron_string(&run.stage)),
+            );
+        }
+
         if let Some(battle) = &self.battle {
             sections.push(
                 DebugSection::new("Battle")
                     .entry("stage", ron_string(&battle.stage))
+                    .entry("kind", ron_string(&battle.kind))
                     .entry("enemy", ron_string(&battle.enemy_name))
+                    .entry("shiny", ron_string(&battle.shiny))
                     .entry("player_hp", ron_string(&battle.player_hp))
                     .entry("enemy_hp", ron_string(&battle.enemy_hp)),
             );
@@ -625,8 +1059,17 @@ fn seed_from_time() -> u64 {
     (now.as_secs() << 32) ^ now.subsec_nanos() as u64
 }
 
+/// Interval of the `Tick` subscription; game clocks advance by this much per tick.
+pub const TICK_MS: u64 = 120;
+
 pub const MAX_LEVEL: u8 = 100;
 
+/// Pokemon that travel with the player; catches past this go to the PC box.
+pub const PARTY_LIMIT: usize = 3;
+pub const BOX_CAPACITY: usize = 30;
+/// Longest nickname the party screen takes.
+pub const NICKNAME_LIMIT: usize = 10;
+
 pub fn exp_for_level(level: u8) -> u32 {
     let level = level.max(1) as u32;
     level.pow(3)
@@ -670,18 +1113,22 @@ fn default_scenario_dir() -> String {
     "assets/scenarios/lakeside".to_string()
 }
 
+fn default_money() -> u32 {
+    STARTING_MONEY
+}
+
 fn default_inventory() -> Vec<ItemStack> {
     vec![
         ItemStack {
-            kind: ItemKind::Potion,
+            kind: ItemKind::POTION,
             qty: 3,
         },
         ItemStack {
-            kind: ItemKind::SuperPotion,
+            kind: ItemKind::SUPER_POTION,
             qty: 1,
         },
         ItemStack {
-            kind: ItemKind::PokeBall,
+            kind: ItemKind::POKE_BALL,
             qty: 5,
         },
     ]
@@ -6,30 +6,32 @@ use ratatui::{
     widgets::{block::Title, Block, BorderType, Borders, Paragraph, Wrap},
     Frame,
 };
+use sprite_cache::SpriteData;
 use std::sync::OnceLock;
 use tui_map::core::TileKind;
 use tui_map::render::{Camera, MapRenderer, RenderConfig, TextureVariant, TilePalette, TileTheme};
 use tui_dispatch::{EventKind, EventOutcome, RenderContext};
+use tui_theme::theme;
 
 use crate::action::Action;
-use crate::sprite;
-use crate::sprite_backend;
+use crate::anim::{self, Side};
+use crate::controls::{self, ControlPreset, Input};
+use crate::items;
+use crate::quest::{self, QuestStatus};
+use crate::scenario::{Backdrop, DialogueNode, NpcSpec};
+use crate::settings::OptionRow;
+use crate::shop::{self, ShopTab};
+use crate::speedrun::{self, Milestone};
+use crate::sprite_backend::{self, SpriteLayer};
 use crate::state::{
-    calc_hp, AppState, BattleKind, BattleStage, Direction as MoveDir, GameMode,
+    calc_hp, calc_stat, AppState, BattleKind, BattleStage, BoxPane, Direction as MoveDir, GameMode,
+    PartyMember, BOX_CAPACITY, PARTY_LIMIT,
 };
+use crate::water::FishingPhase;
+use crate::weather::Weather;
 
-const BG_BASE: Color = Color::Rgb(24, 36, 26);
-const BG_PANEL: Color = Color::Rgb(34, 58, 38);
-const BG_PANEL_ALT: Color = Color::Rgb(28, 48, 32);
-const BG_HEADER: Color = Color::Rgb(26, 46, 34);
-const TEXT_MAIN: Color = Color::Rgb(228, 236, 214);
-const TEXT_DIM: Color = Color::Rgb(172, 186, 160);
-const ACCENT_GREEN: Color = Color::Rgb(104, 204, 120);
-const ACCENT_GOLD: Color = Color::Rgb(222, 196, 120);
-const HIGHLIGHT_BG: Color = ACCENT_GREEN;
-const HIGHLIGHT_TEXT: Color = Color::Rgb(16, 26, 18);
-const BORDER_ACCENT: Color = Color::Rgb(74, 98, 82);
 const CELL_ASPECT: f32 = 2.0;
+const PAUSE_OPTIONS: usize = 9;
 const MAP_TILES_V: u16 = 9;
 
 const SPRITE_ID_PLAYER_MAP: u32 = 2;
@@ -47,6 +49,8 @@ const TILE_WALL: Color = Color::Rgb(66, 74, 66);
 const TILE_WALL_ALT: Color = Color::Rgb(60, 68, 60);
 const TILE_WATER: Color = Color::Rgb(48, 86, 146);
 const TILE_WATER_ALT: Color = Color::Rgb(52, 92, 150);
+const WEATHER_RAIN: Color = Color::Rgb(150, 186, 236);
+const WEATHER_SAND: Color = Color::Rgb(222, 196, 140);
 static MAP_RENDERER: OnceLock<MapRenderer> = OnceLock::new();
 
 fn adjust_color(color: Color, delta: i16) -> Color {
@@ -137,7 +141,10 @@ const SPRITE_ID_STARTER_PREVIEW: u32 = 5;
 
 pub fn render(frame: &mut Frame, area: Rect, state: &AppState, _ctx: RenderContext) {
     sprite_backend::clear_sprites();
-    frame.render_widget(Block::default().style(Style::default().bg(BG_BASE)), area);
+    frame.render_widget(
+        Block::default().style(Style::default().bg(theme().bg)),
+        area,
+    );
     match state.mode {
         GameMode::MainMenu => render_main_menu(frame, area, state),
         GameMode::PokemonSelect => render_pokemon_select(frame, area, state),
@@ -146,14 +153,54 @@ pub fn render(frame: &mut Frame, area: Rect, state: &AppState, _ctx: RenderConte
             if state.pause_menu.is_open {
                 render_pause_menu(frame, area, state);
             }
+            if state.options.is_open {
+                render_options_screen(frame, area, state);
+            }
+            if state.stats_open {
+                render_stats_screen(frame, area, state);
+            }
+            if state.quest_log_open {
+                render_quest_log(frame, area, state);
+            }
+            if state.box_screen.is_open {
+                render_box_screen(frame, area, state);
+            }
+            if state.party_screen.is_open {
+                render_party_screen(frame, area, state);
+            }
+            if state.dialogue.is_some() {
+                render_dialogue(frame, area, state);
+            }
+            if state.fishing.is_some() {
+                render_fishing(frame, area, state);
+            }
         }
         GameMode::Battle => {
             render_battle(frame, area, state);
+            if state.replay.is_some() {
+                render_replay_banner(frame, area, state);
+            }
             if state.pause_menu.is_open {
                 render_pause_menu(frame, area, state);
             }
+            if state.options.is_open {
+                render_options_screen(frame, area, state);
+            }
+            if state.stats_open {
+                render_stats_screen(frame, area, state);
+            }
+            if state.quest_log_open {
+                render_quest_log(frame, area, state);
+            }
+        }
+        GameMode::Shop => {
+            render_overworld(frame, area, state);
+            render_shop(frame, area, state);
         }
     }
+    if state.evolution.is_some() {
+        render_evolution_prompt(frame, area, state);
+    }
     if state.message.is_some() {
         render_message_modal(frame, area, state);
     }
@@ -170,34 +217,109 @@ pub fn handle_event(event: &EventKind, state: &AppState) -> EventOutcome<Action>
 }
 
 fn handle_key(key: KeyEvent, state: &AppState) -> EventOutcome<Action> {
+    // A nickname takes raw keys, so no preset can claim the letters.
+    if state.party_screen.naming.is_some() {
+        let action = match key.code {
+            KeyCode::Char(ch) => Action::PartyNameInput(ch),
+            KeyCode::Backspace => Action::PartyNameBackspace,
+            KeyCode::Enter => Action::PartyNameConfirm,
+            KeyCode::Esc => Action::PartyNameCancel,
+            _ => return EventOutcome::ignored(),
+        };
+        return EventOutcome::action(action);
+    }
+    let Some(input) = controls::input(state.settings.controls, key.code) else {
+        return EventOutcome::ignored();
+    };
+    handle_input(input, state)
+}
+
+/// Routes a key press or gamepad button, once it's an [`Input`], to whatever
+/// screen or overlay is on top.
+pub fn handle_input(input: Input, state: &AppState) -> EventOutcome<Action> {
+    // Keys never get here while naming; a pad can only finish or cancel.
+    if state.party_screen.naming.is_some() {
+        return match input {
+            Input::Confirm => EventOutcome::action(Action::PartyNameConfirm),
+            Input::Back => EventOutcome::action(Action::PartyNameCancel),
+            _ => EventOutcome::ignored(),
+        };
+    }
+    // A replay only plays back; any way out stops it.
+    if state.replay.is_some() {
+        return match input {
+            Input::Back | Input::Confirm => EventOutcome::action(Action::ReplayStop),
+            _ => EventOutcome::ignored(),
+        };
+    }
     if state.message.is_some() {
-        return match key.code {
-            KeyCode::Enter | KeyCode::Char('z') | KeyCode::Char('Z') | KeyCode::Char(' ') => {
-                EventOutcome::action(Action::MessageNext)
-            }
+        return match input {
+            Input::Confirm => EventOutcome::action(Action::MessageNext),
+            _ => EventOutcome::ignored(),
+        };
+    }
+    if let Some(prompt) = state.evolution.as_ref() {
+        if prompt.loading {
+            return EventOutcome::ignored();
+        }
+        return match input {
+            Input::Confirm => EventOutcome::action(Action::EvolutionConfirm),
+            Input::Back => EventOutcome::action(Action::EvolutionCancel),
+            _ => EventOutcome::ignored(),
+        };
+    }
+    if state.dialogue.is_some() {
+        return handle_dialogue_input(input, state);
+    }
+    if state.fishing.is_some() {
+        return match input {
+            Input::Confirm => EventOutcome::action(Action::FishReel),
+            Input::Back => EventOutcome::action(Action::FishCancel),
+            _ => EventOutcome::ignored(),
+        };
+    }
+    if state.box_screen.is_open {
+        return handle_box_input(input);
+    }
+    if state.party_screen.is_open {
+        return handle_party_input(input, state);
+    }
+    if state.stats_open {
+        return match input {
+            Input::Back | Input::Confirm => EventOutcome::action(Action::StatsClose),
+            _ => EventOutcome::ignored(),
+        };
+    }
+    if state.quest_log_open {
+        return match input {
+            Input::Back | Input::Confirm => EventOutcome::action(Action::QuestLogClose),
             _ => EventOutcome::ignored(),
         };
     }
+    if state.options.is_open {
+        return handle_options_input(input);
+    }
     // Handle pause menu if open
     if state.pause_menu.is_open {
-        return handle_pause_key(key, state);
+        return handle_pause_input(input, state);
     }
 
     match state.mode {
-        GameMode::MainMenu => handle_menu_key(key, state),
-        GameMode::PokemonSelect => handle_pokemon_select_key(key, state),
-        GameMode::Overworld => handle_overworld_key(key, state),
-        GameMode::Battle => handle_battle_key(key, state),
+        GameMode::MainMenu => handle_menu_input(input, state),
+        GameMode::PokemonSelect => handle_pokemon_select_input(input, state),
+        GameMode::Overworld => handle_overworld_input(input),
+        GameMode::Battle => handle_battle_input(input, state),
+        GameMode::Shop => handle_shop_input(input),
     }
 }
 
-fn handle_menu_key(key: KeyEvent, state: &AppState) -> EventOutcome<Action> {
+fn handle_menu_input(input: Input, state: &AppState) -> EventOutcome<Action> {
     let Some(menu) = state.menu.as_ref() else {
         return EventOutcome::ignored();
     };
 
-    match key.code {
-        KeyCode::Up | KeyCode::Char('w') => {
+    match input {
+        Input::Up => {
             let new_idx = if menu.selected == 0 {
                 if menu.has_save {
                     2
@@ -209,7 +331,7 @@ fn handle_menu_key(key: KeyEvent, state: &AppState) -> EventOutcome<Action> {
             };
             EventOutcome::action(Action::MenuSelect(new_idx))
         }
-        KeyCode::Down | KeyCode::Char('s') => {
+        Input::Down => {
             let max = if menu.has_save { 2 } else { 1 };
             let new_idx = if menu.selected >= max {
                 0
@@ -218,7 +340,7 @@ fn handle_menu_key(key: KeyEvent, state: &AppState) -> EventOutcome<Action> {
             };
             EventOutcome::action(Action::MenuSelect(new_idx))
         }
-        KeyCode::Enter | KeyCode::Char('z') | KeyCode::Char('Z') => {
+        Input::Confirm => {
             // Don't allow selecting Continue if no save
             if menu.selected == 1 && !menu.has_save {
                 return EventOutcome::ignored();
@@ -233,13 +355,13 @@ fn handle_menu_key(key: KeyEvent, state: &AppState) -> EventOutcome<Action> {
     }
 }
 
-fn handle_pokemon_select_key(key: KeyEvent, state: &AppState) -> EventOutcome<Action> {
+fn handle_pokemon_select_input(input: Input, state: &AppState) -> EventOutcome<Action> {
     let Some(select) = state.pokemon_select.as_ref() else {
         return EventOutcome::ignored();
     };
 
-    match key.code {
-        KeyCode::Up | KeyCode::Char('w') => {
+    match input {
+        Input::Up => {
             let new_idx = if select.selected == 0 {
                 select.starters.len().saturating_sub(1)
             } else {
@@ -247,7 +369,7 @@ fn handle_pokemon_select_key(key: KeyEvent, state: &AppState) -> EventOutcome<Ac
             };
             EventOutcome::action(Action::StarterSelect(new_idx))
         }
-        KeyCode::Down | KeyCode::Char('s') => {
+        Input::Down => {
             let new_idx = if select.selected >= select.starters.len().saturating_sub(1) {
                 0
             } else {
@@ -255,10 +377,9 @@ fn handle_pokemon_select_key(key: KeyEvent, state: &AppState) -> EventOutcome<Ac
             };
             EventOutcome::action(Action::StarterSelect(new_idx))
         }
-        KeyCode::Enter | KeyCode::Char('z') | KeyCode::Char('Z') => {
-            EventOutcome::action(Action::StarterConfirm)
-        }
-        KeyCode::Esc => {
+        Input::Confirm => EventOutcome::action(Action::StarterConfirm),
+        Input::Toggle => EventOutcome::action(Action::StarterToggleHardcore),
+        Input::Back => {
             // Go back to main menu
             EventOutcome::action(Action::Init)
         }
@@ -266,82 +387,152 @@ fn handle_pokemon_select_key(key: KeyEvent, state: &AppState) -> EventOutcome<Ac
     }
 }
 
-fn handle_overworld_key(key: KeyEvent, _state: &AppState) -> EventOutcome<Action> {
-    let action = match key.code {
-        KeyCode::Up | KeyCode::Char('w') => Some(Action::Move(MoveDir::Up)),
-        KeyCode::Down | KeyCode::Char('s') => Some(Action::Move(MoveDir::Down)),
-        KeyCode::Left | KeyCode::Char('a') => Some(Action::Move(MoveDir::Left)),
-        KeyCode::Right | KeyCode::Char('d') => Some(Action::Move(MoveDir::Right)),
-        KeyCode::Esc => Some(Action::PauseOpen),
+fn handle_overworld_input(input: Input) -> EventOutcome<Action> {
+    let action = match input {
+        Input::Up => Some(Action::Move(MoveDir::Up)),
+        Input::Down => Some(Action::Move(MoveDir::Down)),
+        Input::Left => Some(Action::Move(MoveDir::Left)),
+        Input::Right => Some(Action::Move(MoveDir::Right)),
+        Input::Confirm => Some(Action::Talk),
+        Input::Back => Some(Action::PauseOpen),
         _ => None,
     };
     EventOutcome::from(action)
 }
 
-fn handle_battle_key(key: KeyEvent, state: &AppState) -> EventOutcome<Action> {
-    let Some(battle) = state.battle.as_ref() else {
+fn handle_dialogue_input(input: Input, state: &AppState) -> EventOutcome<Action> {
+    let Some(dialogue) = state.dialogue.as_ref() else {
         return EventOutcome::ignored();
     };
-
-    if battle.stage == BattleStage::ItemMenu {
-        let action = match key.code {
-            KeyCode::Esc => Some(Action::BattleItemCancel),
-            KeyCode::Enter | KeyCode::Char('z') | KeyCode::Char('Z') => Some(Action::BattleConfirm),
-            KeyCode::Up | KeyCode::Left => Some(Action::BattleMenuPrev),
-            KeyCode::Down | KeyCode::Right => Some(Action::BattleMenuNext),
-            _ => None,
-        };
-        return EventOutcome::from(action);
+    let choices = dialogue_node(state)
+        .map(|(_, node)| node.choices.len())
+        .unwrap_or(0);
+    match input {
+        Input::Back => EventOutcome::action(Action::DialogueClose),
+        Input::Up if choices > 0 => {
+            let new_idx = (dialogue.selected + choices - 1) % choices;
+            EventOutcome::action(Action::DialogueSelect(new_idx))
+        }
+        Input::Down if choices > 0 => {
+            let new_idx = (dialogue.selected + 1) % choices;
+            EventOutcome::action(Action::DialogueSelect(new_idx))
+        }
+        Input::Confirm => EventOutcome::action(Action::DialogueConfirm),
+        _ => EventOutcome::ignored(),
     }
+}
+
+fn handle_battle_input(input: Input, state: &AppState) -> EventOutcome<Action> {
+    let Some(battle) = state.battle.as_ref() else {
+        return EventOutcome::ignored();
+    };
 
-    if matches!(key.code, KeyCode::Esc) {
-        return EventOutcome::action(Action::PauseOpen);
-    }
     if matches!(
-        key.code,
-        KeyCode::Enter | KeyCode::Char('z') | KeyCode::Char('Z')
+        battle.stage,
+        BattleStage::MoveMenu | BattleStage::ItemMenu | BattleStage::ReviveMenu
     ) {
-        return EventOutcome::action(Action::BattleConfirm);
-    }
-    if battle.stage == BattleStage::Menu {
-        let action = match key.code {
-            KeyCode::Up | KeyCode::Left => Some(Action::BattleMenuPrev),
-            KeyCode::Down | KeyCode::Right => Some(Action::BattleMenuNext),
+        let action = match input {
+            Input::Back if battle.stage == BattleStage::MoveMenu => Some(Action::BattleMoveCancel),
+            Input::Back => Some(Action::BattleItemCancel),
+            Input::Confirm => Some(Action::BattleConfirm),
+            Input::Up | Input::Left => Some(Action::BattleMenuPrev),
+            Input::Down | Input::Right => Some(Action::BattleMenuNext),
             _ => None,
         };
         return EventOutcome::from(action);
     }
-    EventOutcome::ignored()
+
+    match input {
+        Input::Back => EventOutcome::action(Action::PauseOpen),
+        Input::Confirm => EventOutcome::action(Action::BattleConfirm),
+        Input::Up | Input::Left if battle.stage == BattleStage::Menu => {
+            EventOutcome::action(Action::BattleMenuPrev)
+        }
+        Input::Down | Input::Right if battle.stage == BattleStage::Menu => {
+            EventOutcome::action(Action::BattleMenuNext)
+        }
+        _ => EventOutcome::ignored(),
+    }
 }
 
-fn handle_pause_key(key: KeyEvent, state: &AppState) -> EventOutcome<Action> {
-    match key.code {
-        KeyCode::Esc => EventOutcome::action(Action::PauseClose),
-        KeyCode::Up | KeyCode::Char('w') => {
+fn handle_pause_input(input: Input, state: &AppState) -> EventOutcome<Action> {
+    let last = PAUSE_OPTIONS - 1;
+    match input {
+        Input::Back => EventOutcome::action(Action::PauseClose),
+        Input::Up => {
             let new_idx = if state.pause_menu.selected == 0 {
-                2
+                last
             } else {
                 state.pause_menu.selected - 1
             };
             EventOutcome::action(Action::PauseSelect(new_idx))
         }
-        KeyCode::Down | KeyCode::Char('s') => {
-            let new_idx = if state.pause_menu.selected >= 2 {
+        Input::Down => {
+            let new_idx = if state.pause_menu.selected >= last {
                 0
             } else {
                 state.pause_menu.selected + 1
             };
             EventOutcome::action(Action::PauseSelect(new_idx))
         }
-        KeyCode::Enter | KeyCode::Char('z') | KeyCode::Char('Z') => {
-            EventOutcome::action(Action::PauseConfirm)
-        }
+        Input::Confirm => EventOutcome::action(Action::PauseConfirm),
         _ => EventOutcome::ignored(),
     }
 }
 
+fn handle_options_input(input: Input) -> EventOutcome<Action> {
+    let action = match input {
+        Input::Back => Action::OptionsClose,
+        Input::Up => Action::OptionsMove(-1),
+        Input::Down => Action::OptionsMove(1),
+        Input::Left => Action::OptionsAdjust(-1),
+        Input::Right | Input::Confirm => Action::OptionsAdjust(1),
+        _ => return EventOutcome::ignored(),
+    };
+    EventOutcome::action(action)
+}
+
+fn handle_shop_input(input: Input) -> EventOutcome<Action> {
+    let action = match input {
+        Input::Back => Action::ShopClose,
+        Input::Up => Action::ShopMove(-1),
+        Input::Down => Action::ShopMove(1),
+        Input::Left | Input::Right | Input::Switch => Action::ShopSwitchTab,
+        Input::Confirm => Action::ShopConfirm,
+        _ => return EventOutcome::ignored(),
+    };
+    EventOutcome::action(action)
+}
+
+fn handle_party_input(input: Input, state: &AppState) -> EventOutcome<Action> {
+    let action = match input {
+        Input::Back if state.party_screen.moving => Action::PartyToggleMoving,
+        Input::Back => Action::PartyClose,
+        Input::Up | Input::Left => Action::PartyMove(-1),
+        Input::Down | Input::Right => Action::PartyMove(1),
+        Input::Confirm => Action::PartySetLead,
+        Input::Switch => Action::PartyToggleMoving,
+        Input::Rename => Action::PartyRename,
+        _ => return EventOutcome::ignored(),
+    };
+    EventOutcome::action(action)
+}
+
+fn handle_box_input(input: Input) -> EventOutcome<Action> {
+    let action = match input {
+        Input::Back => Action::BoxClose,
+        Input::Up => Action::BoxMove(-1),
+        Input::Down => Action::BoxMove(1),
+        Input::Left | Input::Right | Input::Switch => Action::BoxSwitchPane,
+        Input::Confirm => Action::BoxTransfer,
+        Input::Release => Action::BoxRelease,
+        _ => return EventOutcome::ignored(),
+    };
+    EventOutcome::action(action)
+}
+
 fn render_main_menu(frame: &mut Frame, area: Rect, state: &AppState) {
-    let block = panel_block(" POKETUI ", BG_PANEL);
+    let block = panel_block(" POKETUI ", theme().panel);
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
@@ -365,13 +556,13 @@ fn render_main_menu(frame: &mut Frame, area: Rect, state: &AppState) {
         Line::from(Span::styled(
             "POKETUI",
             Style::default()
-                .fg(ACCENT_GREEN)
+                .fg(theme().accent)
                 .add_modifier(Modifier::BOLD),
         )),
         Line::from(""),
         Line::from(Span::styled(
             "A Pokemon-inspired adventure",
-            Style::default().fg(TEXT_DIM),
+            Style::default().fg(theme().text_dim),
         )),
         Line::from(""),
         Line::from(""),
@@ -385,13 +576,26 @@ fn render_main_menu(frame: &mut Frame, area: Rect, state: &AppState) {
     };
 
     for (idx, label) in options.iter().enumerate() {
-        lines.push(menu_line(label, idx == menu.selected));
+        let mut line = menu_line(label, idx == menu.selected);
+        if *label == "Continue" && menu.save_hardcore {
+            line.push_span(Span::styled(
+                " HARDCORE",
+                Style::default()
+                    .fg(theme().danger)
+                    .add_modifier(Modifier::BOLD),
+            ));
+        }
+        lines.push(line);
     }
 
     lines.push(Line::from(""));
     lines.push(Line::from(Span::styled(
-        "Arrows/WASD: Navigate  |  Z/Enter: Select",
-        Style::default().fg(TEXT_DIM),
+        format!(
+            "{}: Navigate  |  {}: Select",
+            state.settings.controls.move_keys(),
+            state.settings.controls.confirm_key()
+        ),
+        Style::default().fg(theme().text_dim),
     )));
 
     let paragraph = Paragraph::new(Text::from(lines))
@@ -401,7 +605,7 @@ fn render_main_menu(frame: &mut Frame, area: Rect, state: &AppState) {
 }
 
 fn render_pokemon_select(frame: &mut Frame, area: Rect, state: &AppState) {
-    let block = panel_block(" CHOOSE YOUR PARTNER ", BG_PANEL);
+    let block = panel_block(" CHOOSE YOUR PARTNER ", theme().panel);
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
@@ -420,8 +624,8 @@ fn render_pokemon_select(frame: &mut Frame, area: Rect, state: &AppState) {
         .borders(Borders::ALL)
         .title("Starters")
         .border_type(BorderType::Rounded)
-        .style(Style::default().bg(BG_PANEL_ALT).fg(TEXT_MAIN))
-        .border_style(Style::default().fg(BORDER_ACCENT));
+        .style(Style::default().bg(theme().panel_alt).fg(theme().text))
+        .border_style(Style::default().fg(theme().border));
     let list_inner = list_block.inner(layout[0]);
     frame.render_widget(list_block, layout[0]);
 
@@ -431,9 +635,26 @@ fn render_pokemon_select(frame: &mut Frame, area: Rect, state: &AppState) {
         list_lines.push(menu_line(&format_name(name), is_selected));
     }
     list_lines.push(Line::from(""));
+    let (mode_label, mode_color) = if select.hardcore {
+        ("On", theme().danger)
+    } else {
+        ("Off", theme().text_dim)
+    };
+    list_lines.push(Line::from(vec![
+        Span::styled("Hardcore: ", Style::default().fg(theme().text)),
+        Span::styled(
+            mode_label,
+            Style::default().fg(mode_color).add_modifier(Modifier::BOLD),
+        ),
+    ]));
+    list_lines.push(Line::from(Span::styled(
+        format!("{}: Toggle", state.settings.controls.toggle_key()),
+        Style::default().fg(theme().text_dim),
+    )));
+    list_lines.push(Line::from(""));
     list_lines.push(Line::from(Span::styled(
         "ESC: Back",
-        Style::default().fg(TEXT_DIM),
+        Style::default().fg(theme().text_dim),
     )));
     let list_para = Paragraph::new(Text::from(list_lines)).wrap(Wrap { trim: true });
     frame.render_widget(list_para, list_inner);
@@ -443,8 +664,8 @@ fn render_pokemon_select(frame: &mut Frame, area: Rect, state: &AppState) {
         .borders(Borders::ALL)
         .title("Preview")
         .border_type(BorderType::Rounded)
-        .style(Style::default().bg(BG_PANEL_ALT).fg(TEXT_MAIN))
-        .border_style(Style::default().fg(BORDER_ACCENT));
+        .style(Style::default().bg(theme().panel_alt).fg(theme().text))
+        .border_style(Style::default().fg(theme().border));
     let preview_inner = preview_block.inner(layout[1]);
     frame.render_widget(preview_block, layout[1]);
 
@@ -464,16 +685,18 @@ fn render_pokemon_select(frame: &mut Frame, area: Rect, state: &AppState) {
             0.7,
         );
         let sprite_frame = sprite_data.frame(select.preview_sprite.frame_index);
-        if let Ok(sequence) =
-            sprite::kitty_sequence(sprite_frame, cols, rows, SPRITE_ID_STARTER_PREVIEW)
-        {
-            let offset_x = sprite_area.x + (sprite_area.width.saturating_sub(cols)) / 2;
-            let offset_y = sprite_area.y + (sprite_area.height.saturating_sub(rows)) / 2;
-            sprite_backend::set_sprite(SPRITE_ID_STARTER_PREVIEW, offset_x, offset_y, sequence);
-        }
+        let offset_x = sprite_area.x + (sprite_area.width.saturating_sub(cols)) / 2;
+        let offset_y = sprite_area.y + (sprite_area.height.saturating_sub(rows)) / 2;
+        sprite_backend::set_sprite(
+            SPRITE_ID_STARTER_PREVIEW,
+            SpriteLayer::Panel,
+            Rect::new(offset_x, offset_y, cols, rows),
+            sprite_area,
+            sprite_frame,
+        );
     } else if select.preview_sprite.loading {
         let loading = Paragraph::new("[Loading...]")
-            .style(Style::default().fg(TEXT_DIM))
+            .style(Style::default().fg(theme().text_dim))
             .alignment(Alignment::Center);
         frame.render_widget(loading, preview_layout[0]);
     }
@@ -484,7 +707,7 @@ fn render_pokemon_select(frame: &mut Frame, area: Rect, state: &AppState) {
             Line::from(Span::styled(
                 format_name(&info.name).to_ascii_uppercase(),
                 Style::default()
-                    .fg(ACCENT_GREEN)
+                    .fg(theme().accent)
                     .add_modifier(Modifier::BOLD),
             )),
             Line::from(format!(
@@ -497,8 +720,11 @@ fn render_pokemon_select(frame: &mut Frame, area: Rect, state: &AppState) {
             )),
             Line::from(""),
             Line::from(Span::styled(
-                "Z/Enter: Choose this Pokemon!",
-                Style::default().fg(ACCENT_GOLD),
+                format!(
+                    "{}: Choose this Pokemon!",
+                    state.settings.controls.confirm_key()
+                ),
+                Style::default().fg(theme().gold),
             )),
         ];
         let stats_para = Paragraph::new(Text::from(stats_lines))
@@ -523,6 +749,13 @@ fn dim_background(frame: &mut Frame, area: Rect) {
     }
 }
 
+/// Dims `area` behind a modal drawn over it.
+fn clear_modal_area(frame: &mut Frame, area: Rect) {
+    // Keep sprites underneath from showing through the modal
+    sprite_backend::open_layer(SpriteLayer::Modal);
+    dim_background(frame, area);
+}
+
 fn fill_area(frame: &mut Frame, area: Rect, bg: Color, fg: Color) {
     let buf = frame.buffer_mut();
     for y in area.y..area.y + area.height {
@@ -533,38 +766,46 @@ fn fill_area(frame: &mut Frame, area: Rect, bg: Color, fg: Color) {
 }
 
 fn render_pause_menu(frame: &mut Frame, area: Rect, state: &AppState) {
-    // Clear sprites so they don't show through the modal
-    sprite_backend::clear_sprites();
-
-    // Dim the background
-    dim_background(frame, area);
+    clear_modal_area(frame, area);
 
     // Draw modal in center
+    let timer_lines = speedrun_lines(state);
     let modal_width = 24;
-    let modal_height = 10;
+    let modal_height = 15 + timer_lines.len() as u16;
     let modal_x = area.x + (area.width.saturating_sub(modal_width)) / 2;
     let modal_y = area.y + (area.height.saturating_sub(modal_height)) / 2;
     let modal_area = Rect::new(modal_x, modal_y, modal_width, modal_height);
 
     // Clear the modal area first (fill with spaces and background color)
-    fill_area(frame, modal_area, BG_PANEL, TEXT_MAIN);
+    fill_area(frame, modal_area, theme().panel, theme().text);
 
-    let block = panel_block(" PAUSED ", BG_PANEL);
+    let block = panel_block(" PAUSED ", theme().panel);
     let inner = block.inner(modal_area);
     frame.render_widget(block, modal_area);
 
-    let options = ["Resume", "Save Game", "Quit to Menu"];
+    let options = [
+        "Resume",
+        "Save Game",
+        "Options",
+        "Party",
+        "PC Box",
+        "Shiny Stats",
+        "Quest Log",
+        "Replay Battle",
+        "Quit to Menu",
+    ];
     let mut lines = Vec::new();
     lines.push(Line::from(""));
 
     for (idx, label) in options.iter().enumerate() {
         lines.push(menu_line(label, idx == state.pause_menu.selected));
     }
+    lines.extend(timer_lines);
 
     lines.push(Line::from(""));
     lines.push(Line::from(Span::styled(
         "ESC: Close",
-        Style::default().fg(TEXT_DIM),
+        Style::default().fg(theme().text_dim),
     )));
 
     let paragraph = Paragraph::new(Text::from(lines))
@@ -573,16 +814,715 @@ fn render_pause_menu(frame: &mut Frame, area: Rect, state: &AppState) {
     frame.render_widget(paragraph, inner);
 }
 
+fn render_options_screen(frame: &mut Frame, area: Rect, state: &AppState) {
+    clear_modal_area(frame, area);
+
+    let modal_width = 46.min(area.width);
+    let modal_height = (OptionRow::ALL.len() as u16 + 8).min(area.height);
+    let modal_area = Rect::new(
+        area.x + area.width.saturating_sub(modal_width) / 2,
+        area.y + area.height.saturating_sub(modal_height) / 2,
+        modal_width,
+        modal_height,
+    );
+    fill_area(frame, modal_area, theme().panel, theme().text);
+    let block = panel_block(" OPTIONS ", theme().panel);
+    let inner = block.inner(modal_area);
+    frame.render_widget(block, modal_area);
+
+    let dim = Style::default().fg(theme().text_dim);
+    let selected = OptionRow::ALL.get(state.options.selected).copied();
+    let mut lines = vec![Line::from("")];
+    for row in OptionRow::ALL {
+        let label = format!("{:<18}{:>18}", row.name(), row.value(&state.settings));
+        lines.push(menu_line(&label, Some(row) == selected));
+    }
+    lines.push(Line::from(""));
+    let note = selected
+        .and_then(|row| row.note(&state.settings))
+        .unwrap_or_default();
+    lines.push(Line::from(Span::styled(note, dim)));
+    lines.push(Line::from(Span::styled("←/→: Change  ESC: Back", dim)));
+
+    frame.render_widget(
+        Paragraph::new(Text::from(lines)).alignment(Alignment::Center),
+        inner,
+    );
+}
+
+fn render_party_screen(frame: &mut Frame, area: Rect, state: &AppState) {
+    clear_modal_area(frame, area);
+
+    let screen = &state.party_screen;
+    let modal_width = 64.min(area.width);
+    let modal_height = 16.min(area.height);
+    let modal_area = Rect::new(
+        area.x + area.width.saturating_sub(modal_width) / 2,
+        area.y + area.height.saturating_sub(modal_height) / 2,
+        modal_width,
+        modal_height,
+    );
+    fill_area(frame, modal_area, theme().panel, theme().text);
+    let block = panel_block(" PARTY ", theme().panel);
+    let inner = block.inner(modal_area);
+    frame.render_widget(block, modal_area);
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(2)])
+        .split(inner);
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(layout[0]);
+
+    let dim = Style::default().fg(theme().text_dim);
+    let mut list = vec![Line::from(Span::styled(
+        format!("PARTY {}/{}", state.party.len(), PARTY_LIMIT),
+        Style::default()
+            .fg(theme().accent)
+            .add_modifier(Modifier::BOLD),
+    ))];
+    for (idx, member) in state.party.iter().enumerate() {
+        let lead = if idx == state.active_party_index {
+            "*"
+        } else {
+            " "
+        };
+        let label = format!("{lead}{}", box_member_label(member));
+        if screen.moving && idx == screen.selected {
+            list.push(Line::from(Span::styled(
+                label,
+                Style::default()
+                    .fg(theme().gold)
+                    .add_modifier(Modifier::BOLD),
+            )));
+        } else {
+            list.push(menu_line(&label, idx == screen.selected));
+        }
+    }
+    list.push(Line::from(""));
+    list.push(Line::from(Span::styled("* leads in battle", dim)));
+    frame.render_widget(Paragraph::new(Text::from(list)), columns[0]);
+
+    if let Some(member) = state.party.get(screen.selected) {
+        frame.render_widget(
+            Paragraph::new(Text::from(party_member_details(member))).wrap(Wrap { trim: true }),
+            columns[1],
+        );
+    }
+
+    let confirm = state.settings.controls.confirm_key();
+    let footer = match (&screen.naming, screen.moving) {
+        (Some(typed), _) => Line::from(vec![
+            Span::styled(
+                format!("Nickname: {typed}_"),
+                Style::default()
+                    .fg(theme().accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            Span::styled("  Enter: Done  ESC: Cancel", dim),
+        ]),
+        (None, true) => Line::from(Span::styled(
+            format!(
+                "{}: Move  {confirm}: Drop",
+                state.settings.controls.move_keys()
+            ),
+            dim,
+        )),
+        (None, false) => Line::from(Span::styled(
+            format!("{confirm}: Lead  Tab: Move  N: Nickname  ESC: Close"),
+            dim,
+        )),
+    };
+    frame.render_widget(
+        Paragraph::new(Text::from(vec![Line::from(""), footer])).alignment(Alignment::Center),
+        layout[1],
+    );
+}
+
+/// Name, species, stats at the member's level and moves, for the party
+/// screen.
+fn party_member_details(member: &PartyMember) -> Vec<Line<'static>> {
+    let mut lines = vec![Line::from(Span::styled(
+        format_name(member.name()).to_ascii_uppercase(),
+        Style::default()
+            .fg(theme().accent)
+            .add_modifier(Modifier::BOLD),
+    ))];
+    if member.nickname.is_some() {
+        lines.push(Line::from(format_name(&member.info.name)));
+    }
+    let types = member
+        .info
+        .types
+        .iter()
+        .map(|kind| kind.to_uppercase())
+        .collect::<Vec<_>>()
+        .join("/");
+    lines.push(Line::from(Span::styled(
+        types,
+        Style::default().fg(theme().text_dim),
+    )));
+    let stat = |base: u16| calc_stat(base, member.level);
+    lines.push(Line::from(format!(
+        "ATK {:>3}  DEF {:>3}  SPD {:>3}",
+        stat(member.info.attack),
+        stat(member.info.defense),
+        stat(member.info.speed)
+    )));
+    lines.push(Line::from(""));
+    for mv in &member.moves {
+        lines.push(Line::from(format!(
+            "{:<14} {:>2}/{}",
+            format_name(&mv.name),
+            mv.pp,
+            mv.max_pp
+        )));
+    }
+    lines
+}
+
+fn render_box_screen(frame: &mut Frame, area: Rect, state: &AppState) {
+    clear_modal_area(frame, area);
+
+    let screen = &state.box_screen;
+    let rows = PARTY_LIMIT.max(state.pc_box.len().min(12)) as u16;
+    let modal_width = 60.min(area.width);
+    let modal_height = (rows + 6).min(area.height);
+    let modal_area = Rect::new(
+        area.x + area.width.saturating_sub(modal_width) / 2,
+        area.y + area.height.saturating_sub(modal_height) / 2,
+        modal_width,
+        modal_height,
+    );
+    fill_area(frame, modal_area, theme().panel, theme().text);
+    let block = panel_block(" PC BOX ", theme().panel);
+    let inner = block.inner(modal_area);
+    frame.render_widget(block, modal_area);
+
+    let layout = Layout::default()
+        .direction(Direction::Vertical)
+        .constraints([Constraint::Min(3), Constraint::Length(2)])
+        .split(inner);
+    let columns = Layout::default()
+        .direction(Direction::Horizontal)
+        .constraints([Constraint::Percentage(50), Constraint::Percentage(50)])
+        .split(layout[0]);
+
+    let panes = [
+        (
+            BoxPane::Party,
+            format!("PARTY {}/{}", state.party.len(), PARTY_LIMIT),
+            &state.party,
+            screen.party_index,
+        ),
+        (
+            BoxPane::Box,
+            format!("BOX {}/{}", state.pc_box.len(), BOX_CAPACITY),
+            &state.pc_box,
+            screen.box_index,
+        ),
+    ];
+    for ((pane, title, members, selected), column) in panes.into_iter().zip(columns.iter()) {
+        let focused = screen.pane == pane;
+        let title_style = if focused {
+            Style::default()
+                .fg(theme().accent)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            Style::default().fg(theme().text_dim)
+        };
+        let mut lines = vec![Line::from(Span::styled(title, title_style))];
+        if members.is_empty() {
+            lines.push(Line::from(Span::styled(
+                "(empty)",
+                Style::default().fg(theme().text_dim),
+            )));
+        }
+        // Keep the selection in view when the box outgrows the modal.
+        let visible = column.height.saturating_sub(1).max(1) as usize;
+        let skip = (selected + 1).saturating_sub(visible);
+        for (idx, member) in members.iter().enumerate().skip(skip).take(visible) {
+            lines.push(menu_line(
+                &box_member_label(member),
+                focused && idx == selected,
+            ));
+        }
+        frame.render_widget(Paragraph::new(Text::from(lines)), *column);
+    }
+
+    let selected_name = match screen.pane {
+        BoxPane::Party => state.party.get(screen.party_index),
+        BoxPane::Box => state.pc_box.get(screen.box_index),
+    }
+    .map(|member| format_name(member.name()));
+    let footer = match (screen.release_armed, selected_name) {
+        (true, Some(name)) => Line::from(Span::styled(
+            format!("Release {name}? Press R again."),
+            Style::default()
+                .fg(theme().danger)
+                .add_modifier(Modifier::BOLD),
+        )),
+        _ => {
+            let verb = match screen.pane {
+                BoxPane::Party => "Deposit",
+                BoxPane::Box => "Withdraw",
+            };
+            Line::from(Span::styled(
+                format!("Enter: {verb}  R: Release  Tab: Switch  ESC: Close"),
+                Style::default().fg(theme().text_dim),
+            ))
+        }
+    };
+    frame.render_widget(
+        Paragraph::new(Text::from(vec![Line::from(""), footer])).alignment(Alignment::Center),
+        layout[1],
+    );
+}
+
+fn render_shop(frame: &mut Frame, area: Rect, state: &AppState) {
+    let Some((screen, spec)) = state.shop.as_ref().and_then(|screen| {
+        let spec = state.scenario.as_ref()?.shop(&screen.shop_id)?;
+        Some((screen, spec))
+    }) else {
+        return;
+    };
+    clear_modal_area(frame, area);
+
+    let rows = shop::rows(state, spec, screen.tab);
+    let modal_width = 44.min(area.width);
+    let modal_height = (rows.len().clamp(1, 10) as u16 + 9).min(area.height);
+    let modal_area = Rect::new(
+        area.x + area.width.saturating_sub(modal_width) / 2,
+        area.y + area.height.saturating_sub(modal_height) / 2,
+        modal_width,
+        modal_height,
+    );
+    fill_area(frame, modal_area, theme().panel, theme().text);
+    let block = panel_block(
+        format!(" {} ", spec.name.to_ascii_uppercase()),
+        theme().panel,
+    );
+    let inner = block.inner(modal_area);
+    frame.render_widget(block, modal_area);
+
+    let dim = Style::default().fg(theme().text_dim);
+    let tab_style = |tab: ShopTab| {
+        if screen.tab == tab {
+            Style::default()
+                .fg(theme().accent)
+                .add_modifier(Modifier::BOLD)
+        } else {
+            dim
+        }
+    };
+    let mut lines = vec![
+        Line::from(vec![
+            Span::styled("BUY", tab_style(ShopTab::Buy)),
+            Span::raw("    "),
+            Span::styled("SELL", tab_style(ShopTab::Sell)),
+        ]),
+        Line::from(Span::styled(
+            format!("Money {}", shop::format_money(state.money)),
+            Style::default().fg(theme().gold),
+        )),
+        Line::from(""),
+    ];
+    if rows.is_empty() {
+        let empty = match screen.tab {
+            ShopTab::Buy => "Nothing for sale.",
+            ShopTab::Sell => "Bag is empty.",
+        };
+        lines.push(Line::from(Span::styled(empty, dim)));
+    }
+    // Keep the selection in view when the list outgrows the modal.
+    let visible = inner.height.saturating_sub(7).max(1) as usize;
+    let skip = (screen.selected + 1).saturating_sub(visible);
+    for (idx, row) in rows.iter().enumerate().skip(skip).take(visible) {
+        let name = items::name(state, &row.kind);
+        let label = match row.owned {
+            Some(owned) => format!("{} x{}", name, owned),
+            None => name,
+        };
+        lines.push(menu_line(
+            &format!("{:<18}{:>8}", label, shop::format_money(row.price)),
+            idx == screen.selected,
+        ));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        screen.notice.clone().unwrap_or_default(),
+        Style::default().fg(theme().text),
+    )));
+    let verb = match screen.tab {
+        ShopTab::Buy => "Buy",
+        ShopTab::Sell => "Sell",
+    };
+    lines.push(Line::from(Span::styled(
+        format!("Enter: {verb}  Tab: Switch  ESC: Leave"),
+        dim,
+    )));
+
+    frame.render_widget(
+        Paragraph::new(Text::from(lines)).alignment(Alignment::Center),
+        inner,
+    );
+}
+
+fn render_stats_screen(frame: &mut Frame, area: Rect, state: &AppState) {
+    clear_modal_area(frame, area);
+
+    let hunt = &state.hunt;
+    let rows = hunt.encounters.len().clamp(1, 12) as u16;
+    let modal_width = 44.min(area.width);
+    let modal_height = (rows + 9).min(area.height);
+    let modal_area = Rect::new(
+        area.x + area.width.saturating_sub(modal_width) / 2,
+        area.y + area.height.saturating_sub(modal_height) / 2,
+        modal_width,
+        modal_height,
+    );
+    fill_area(frame, modal_area, theme().panel, theme().text);
+    let block = panel_block(" SHINY STATS ", theme().panel);
+    let inner = block.inner(modal_area);
+    frame.render_widget(block, modal_area);
+
+    let dim = Style::default().fg(theme().text_dim);
+    let chain = match hunt.chain_species.as_deref() {
+        Some(species) => format!("{} x{}", format_name(species), hunt.chain),
+        None => "-".to_string(),
+    };
+    let mut lines = vec![
+        Line::from(format!(
+            "Encounters {:>5}   Shinies {:>3}",
+            hunt.total_encounters(),
+            hunt.total_shinies()
+        )),
+        Line::from(format!("Chain {chain}  (best {})", hunt.best_chain)),
+        Line::from(Span::styled(
+            format!(
+                "Next odds 1/{}",
+                hunt.odds_for(hunt.chain_species.as_deref().unwrap_or_default())
+            ),
+            dim,
+        )),
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("{:<14}{:>8}{:>8}", "SPECIES", "SEEN", "SHINY"),
+            Style::default()
+                .fg(theme().accent)
+                .add_modifier(Modifier::BOLD),
+        )),
+    ];
+    if hunt.encounters.is_empty() {
+        lines.push(Line::from(Span::styled("No wild encounters yet", dim)));
+    }
+    // Most-met species first; that's where a hunt is going on.
+    let mut species: Vec<(&String, &u32)> = hunt.encounters.iter().collect();
+    species.sort_by(|a, b| b.1.cmp(a.1).then_with(|| a.0.cmp(b.0)));
+    let visible = inner.height.saturating_sub(7) as usize;
+    for (name, seen) in species.into_iter().take(visible.max(1)) {
+        let shinies = hunt.shinies.get(name).copied().unwrap_or(0);
+        let style = if shinies > 0 {
+            Style::default().fg(theme().accent)
+        } else {
+            Style::default().fg(theme().text)
+        };
+        lines.push(Line::from(Span::styled(
+            format!("{:<14}{:>8}{:>8}", format_name(name), seen, shinies),
+            style,
+        )));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled("ESC: Back", dim)));
+
+    frame.render_widget(
+        Paragraph::new(Text::from(lines)).alignment(Alignment::Center),
+        inner,
+    );
+}
+
+fn render_quest_log(frame: &mut Frame, area: Rect, state: &AppState) {
+    clear_modal_area(frame, area);
+
+    let quests: Vec<_> = state
+        .quests
+        .entries
+        .iter()
+        .filter_map(|entry| state.scenario.as_ref()?.quest(&entry.id))
+        .collect();
+    let badges: Vec<&str> = state
+        .badges
+        .iter()
+        .filter_map(|id| state.scenario.as_ref()?.gauntlet(id))
+        .map(|spec| spec.badge.as_str())
+        .collect();
+    let badge_rows = if badges.is_empty() { 0 } else { 2 };
+    let rows = (quests.len() * 2).clamp(1, 12) as u16 + badge_rows;
+    let modal_width = 48.min(area.width);
+    let modal_height = (rows + 6).min(area.height);
+    let modal_area = Rect::new(
+        area.x + area.width.saturating_sub(modal_width) / 2,
+        area.y + area.height.saturating_sub(modal_height) / 2,
+        modal_width,
+        modal_height,
+    );
+    fill_area(frame, modal_area, theme().panel, theme().text);
+    let block = panel_block(" QUEST LOG ", theme().panel);
+    let inner = block.inner(modal_area);
+    frame.render_widget(block, modal_area);
+
+    let dim = Style::default().fg(theme().text_dim);
+    let mut lines = vec![Line::from("")];
+    if !badges.is_empty() {
+        lines.push(Line::from(Span::styled(
+            format!("Badges: {}", badges.join(", ")),
+            Style::default()
+                .fg(theme().gold)
+                .add_modifier(Modifier::BOLD),
+        )));
+        lines.push(Line::from(""));
+    }
+    if quests.is_empty() {
+        lines.push(Line::from(Span::styled(
+            "No quests yet. Talk to people!",
+            dim,
+        )));
+    }
+    // Open quests first; finished ones sink to the bottom.
+    let mut quests: Vec<_> = quests
+        .into_iter()
+        .map(|spec| (spec, quest::status(state, spec)))
+        .collect();
+    quests.sort_by_key(|(_, status)| *status == QuestStatus::Done);
+    let visible = inner.height.saturating_sub(3 + badge_rows) as usize / 2;
+    for (spec, status) in quests.into_iter().take(visible.max(1)) {
+        let (progress, style) = match status {
+            QuestStatus::InProgress { progress, goal } => (
+                format!("{progress}/{goal}"),
+                Style::default().fg(theme().text),
+            ),
+            QuestStatus::Ready => (
+                "Report back".to_string(),
+                Style::default()
+                    .fg(theme().accent)
+                    .add_modifier(Modifier::BOLD),
+            ),
+            QuestStatus::Done | QuestStatus::NotTaken => ("Done".to_string(), dim),
+        };
+        let name_width = (inner.width as usize).saturating_sub(progress.len() + 1);
+        lines.push(Line::from(Span::styled(
+            format!("{:<name_width$} {progress}", spec.name),
+            style,
+        )));
+        lines.push(Line::from(Span::styled(spec.description.clone(), dim)));
+    }
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled("ESC: Back", dim)));
+
+    frame.render_widget(
+        Paragraph::new(Text::from(lines)).alignment(Alignment::Center),
+        inner,
+    );
+}
+
+/// The NPC being talked to and the dialogue node on screen.
+fn dialogue_node(state: &AppState) -> Option<(&NpcSpec, &DialogueNode)> {
+    let dialogue = state.dialogue.as_ref()?;
+    let npc = state.scenario.as_ref()?.npc(&dialogue.npc_id)?;
+    let node = npc.node(&dialogue.node)?;
+    Some((npc, node))
+}
+
+fn render_dialogue(frame: &mut Frame, area: Rect, state: &AppState) {
+    let (Some(dialogue), Some((npc, node))) = (state.dialogue.as_ref(), dialogue_node(state))
+    else {
+        return;
+    };
+    sprite_backend::open_layer(SpriteLayer::Modal);
+
+    let modal_width = 56.min(area.width);
+    let text_rows = (node.text.len() as u16 / modal_width.saturating_sub(4).max(1)) + 1;
+    let modal_height = (text_rows + node.choices.len() as u16 + 5).min(area.height);
+    let modal_area = Rect::new(
+        area.x + area.width.saturating_sub(modal_width) / 2,
+        area.y + area.height.saturating_sub(modal_height),
+        modal_width,
+        modal_height,
+    );
+    fill_area(frame, modal_area, theme().panel, theme().text);
+    let block = panel_block(format!(" {} ", npc.name.to_uppercase()), theme().panel);
+    let inner = block.inner(modal_area);
+    frame.render_widget(block, modal_area);
+
+    let mut lines = vec![Line::from(node.text.clone()), Line::from("")];
+    for (idx, choice) in node.choices.iter().enumerate() {
+        lines.push(menu_line(&choice.label, idx == dialogue.selected));
+    }
+    let verb = if node.choices.is_empty() {
+        "Continue"
+    } else {
+        "Choose"
+    };
+    let footer = format!(
+        "{}: {verb}  ESC: Leave",
+        state.settings.controls.confirm_key()
+    );
+    lines.push(Line::from(""));
+    lines.push(Line::from(Span::styled(
+        footer,
+        Style::default().fg(theme().text_dim),
+    )));
+    frame.render_widget(
+        Paragraph::new(Text::from(lines)).wrap(Wrap { trim: true }),
+        inner,
+    );
+}
+
+fn render_fishing(frame: &mut Frame, area: Rect, state: &AppState) {
+    let Some(fishing) = state.fishing.as_ref() else {
+        return;
+    };
+    sprite_backend::open_layer(SpriteLayer::Modal);
+
+    let modal_width = 40.min(area.width);
+    let modal_height = 6.min(area.height);
+    let modal_area = Rect::new(
+        area.x + area.width.saturating_sub(modal_width) / 2,
+        area.y + area.height.saturating_sub(modal_height),
+        modal_width,
+        modal_height,
+    );
+    fill_area(frame, modal_area, theme().panel, theme().text);
+    let block = panel_block(" FISHING ", theme().panel);
+    let inner = block.inner(modal_area);
+    frame.render_widget(block, modal_area);
+
+    let status = match fishing.phase {
+        FishingPhase::Waiting => Line::from(Span::styled(
+            "The line is out. Wait for a bite...",
+            Style::default().fg(theme().text),
+        )),
+        FishingPhase::Bite => Line::from(Span::styled(
+            "! Something's biting !",
+            Style::default()
+                .fg(theme().gold)
+                .add_modifier(Modifier::BOLD),
+        )),
+    };
+    let footer = format!(
+        "{}: Reel in  ESC: Pack up",
+        state.settings.controls.confirm_key()
+    );
+    let lines = vec![
+        status,
+        Line::from(""),
+        Line::from(Span::styled(footer, Style::default().fg(theme().text_dim))),
+    ];
+    frame.render_widget(
+        Paragraph::new(Text::from(lines))
+            .alignment(Alignment::Center)
+            .wrap(Wrap { trim: true }),
+        inner,
+    );
+}
+
+fn render_evolution_prompt(frame: &mut Frame, area: Rect, state: &AppState) {
+    let Some(prompt) = state.evolution.as_ref() else {
+        return;
+    };
+    let Some(member) = state.party.get(prompt.member_index) else {
+        return;
+    };
+    clear_modal_area(frame, area);
+
+    let modal_width = 44.min(area.width);
+    let modal_height = 7.min(area.height);
+    let modal_area = Rect::new(
+        area.x + area.width.saturating_sub(modal_width) / 2,
+        area.y + area.height.saturating_sub(modal_height) / 2,
+        modal_width,
+        modal_height,
+    );
+    fill_area(frame, modal_area, theme().panel, theme().text);
+    let block = panel_block(" EVOLUTION ", theme().panel);
+    let inner = block.inner(modal_area);
+    frame.render_widget(block, modal_area);
+
+    let footer = if prompt.loading {
+        "Evolving...".to_string()
+    } else {
+        format!(
+            "{}: Evolve  ESC: Stop",
+            state.settings.controls.confirm_key()
+        )
+    };
+    let lines = vec![
+        Line::from(format!("What? {} is evolving!", format_name(member.name()))),
+        Line::from(Span::styled(
+            format!("Into {}", format_name(&prompt.into)),
+            Style::default()
+                .fg(theme().accent)
+                .add_modifier(Modifier::BOLD),
+        )),
+        Line::from(""),
+        Line::from(Span::styled(footer, Style::default().fg(theme().text_dim))),
+    ];
+    frame.render_widget(
+        Paragraph::new(Text::from(lines)).alignment(Alignment::Center),
+        inner,
+    );
+}
+
+fn box_member_label(member: &PartyMember) -> String {
+    let max_hp = calc_hp(member.info.hp, member.level).max(1);
+    format!(
+        "{:<12} Lv{:<3} {:>3}/{}",
+        format_name(member.name()),
+        member.level,
+        member.hp,
+        max_hp
+    )
+}
+
+/// Timer and split rows for the pause menu; empty unless `--speedrun` is on.
+fn speedrun_lines(state: &AppState) -> Vec<Line<'static>> {
+    let run = &state.speedrun;
+    if !run.enabled {
+        return Vec::new();
+    }
+    let mut lines = vec![
+        Line::from(""),
+        Line::from(Span::styled(
+            format!("Time {}", speedrun::format_time(run.elapsed_ms)),
+            Style::default()
+                .fg(theme().text)
+                .add_modifier(Modifier::BOLD),
+        )),
+    ];
+    for milestone in Milestone::ALL {
+        let (time, color) = match run.split_time(milestone) {
+            Some(ms) => (speedrun::format_time(ms), theme().gold),
+            None => ("--:--.-".to_string(), theme().text_dim),
+        };
+        lines.push(Line::from(vec![
+            Span::styled(
+                format!("{:<12}", milestone.label()),
+                Style::default().fg(theme().text_dim),
+            ),
+            Span::styled(format!("{:>8}", time), Style::default().fg(color)),
+        ]));
+    }
+    lines
+}
+
 fn render_message_modal(frame: &mut Frame, area: Rect, state: &AppState) {
     let Some(message) = state.message.as_deref() else {
         return;
     };
 
-    // Clear sprites so they don't show through the modal
-    sprite_backend::clear_sprites();
-
-    // Dim the background
-    dim_background(frame, area);
+    clear_modal_area(frame, area);
 
     let modal_width = area.width.min(70).saturating_sub(4).max(28);
     let modal_height = area.height.min(9).saturating_sub(4).max(5);
@@ -590,41 +1530,43 @@ fn render_message_modal(frame: &mut Frame, area: Rect, state: &AppState) {
     let modal_y = area.y + (area.height.saturating_sub(modal_height)) / 2;
     let modal_area = Rect::new(modal_x, modal_y, modal_width, modal_height);
 
-    fill_area(frame, modal_area, BG_PANEL, TEXT_MAIN);
+    fill_area(frame, modal_area, theme().panel, theme().text);
 
     let title = if state.mode == GameMode::Battle {
         " BATTLE "
     } else {
         " MESSAGE "
     };
-    let block = panel_block(title, BG_PANEL);
+    let block = panel_block(title, theme().panel);
     let inner = block.inner(modal_area);
     frame.render_widget(block, modal_area);
 
     let lines = vec![
-        Line::from(Span::styled(message, Style::default().fg(TEXT_MAIN))),
+        Line::from(Span::styled(message, Style::default().fg(theme().text))),
         Line::from(""),
         Line::from(Span::styled(
-            "Enter/Z: Continue",
-            Style::default().fg(TEXT_DIM),
+            format!("{}: Continue", state.settings.controls.confirm_key()),
+            Style::default().fg(theme().text_dim),
         )),
     ];
     let paragraph = Paragraph::new(Text::from(lines))
-        .style(Style::default().fg(TEXT_MAIN))
+        .style(Style::default().fg(theme().text))
         .wrap(Wrap { trim: true })
         .alignment(Alignment::Left);
     frame.render_widget(paragraph, inner);
 }
 
-fn render_battle_message_modal(frame: &mut Frame, area: Rect, message: &str) {
+fn render_battle_message_modal(
+    frame: &mut Frame,
+    area: Rect,
+    message: &str,
+    controls: ControlPreset,
+) {
     if message.is_empty() {
         return;
     }
 
-    // Clear sprites so they don't show through the modal
-    sprite_backend::clear_sprites();
-
-    dim_background(frame, area);
+    clear_modal_area(frame, area);
 
     let modal_width = area.width.min(72).saturating_sub(6).max(30);
     let modal_height = area.height.min(9).saturating_sub(4).max(5);
@@ -632,22 +1574,22 @@ fn render_battle_message_modal(frame: &mut Frame, area: Rect, message: &str) {
     let modal_y = area.y + (area.height.saturating_sub(modal_height)) / 2;
     let modal_area = Rect::new(modal_x, modal_y, modal_width, modal_height);
 
-    fill_area(frame, modal_area, BG_PANEL, TEXT_MAIN);
+    fill_area(frame, modal_area, theme().panel, theme().text);
 
-    let block = panel_block(" BATTLE ", BG_PANEL);
+    let block = panel_block(" BATTLE ", theme().panel);
     let inner = block.inner(modal_area);
     frame.render_widget(block, modal_area);
 
     let lines = vec![
-        Line::from(Span::styled(message, Style::default().fg(TEXT_MAIN))),
+        Line::from(Span::styled(message, Style::default().fg(theme().text))),
         Line::from(""),
         Line::from(Span::styled(
-            "Enter/Z: Continue",
-            Style::default().fg(TEXT_DIM),
+            format!("{}: Continue", controls.confirm_key()),
+            Style::default().fg(theme().text_dim),
         )),
     ];
     let paragraph = Paragraph::new(Text::from(lines))
-        .style(Style::default().fg(TEXT_MAIN))
+        .style(Style::default().fg(theme().text))
         .wrap(Wrap { trim: true })
         .alignment(Alignment::Left);
     frame.render_widget(paragraph, inner);
@@ -656,7 +1598,7 @@ fn render_battle_message_modal(frame: &mut Frame, area: Rect, message: &str) {
 fn render_overworld(frame: &mut Frame, area: Rect, state: &AppState) {
     if area.width < 30 || area.height < 16 {
         let warning = Paragraph::new("Terminal too small - expand window.")
-            .style(Style::default().fg(TEXT_DIM))
+            .style(Style::default().fg(theme().text_dim))
             .alignment(Alignment::Center)
             .wrap(Wrap { trim: true });
         frame.render_widget(warning, area);
@@ -679,7 +1621,7 @@ fn render_overworld(frame: &mut Frame, area: Rect, state: &AppState) {
 }
 
 fn render_overworld_header(frame: &mut Frame, area: Rect, state: &AppState) {
-    let block = panel_block(" ROUTE ", BG_HEADER);
+    let block = panel_block(" ROUTE ", theme().panel_alt);
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
@@ -690,20 +1632,41 @@ fn render_overworld_header(frame: &mut Frame, area: Rect, state: &AppState) {
         Span::styled(
             title,
             Style::default()
-                .fg(ACCENT_GREEN)
+                .fg(theme().accent)
                 .add_modifier(Modifier::BOLD),
         ),
         Span::raw("  •  "),
         Span::styled(
             format!("Partner {} Lv {}", player, level),
-            Style::default().fg(TEXT_MAIN),
+            Style::default().fg(theme().text),
         ),
         Span::raw("  •  "),
         Span::styled(
             format!("Steps {}", state.player.steps),
-            Style::default().fg(TEXT_DIM),
+            Style::default().fg(theme().text_dim),
+        ),
+        Span::raw("  •  "),
+        Span::styled(
+            state.weather.label(),
+            Style::default().fg(weather_color(state.weather)),
         ),
     ]);
+    let line = if state.speedrun.enabled {
+        let color = if state.speedrun.is_finished() {
+            theme().gold
+        } else {
+            theme().text
+        };
+        let mut spans = line.spans;
+        spans.push(Span::raw("  •  "));
+        spans.push(Span::styled(
+            format!("Time {}", speedrun::format_time(state.speedrun.elapsed_ms)),
+            Style::default().fg(color),
+        ));
+        Line::from(spans)
+    } else {
+        line
+    };
     let paragraph = Paragraph::new(Text::from(vec![line]))
         .alignment(Alignment::Left)
         .wrap(Wrap { trim: true });
@@ -724,7 +1687,7 @@ fn render_overworld_body(frame: &mut Frame, area: Rect, state: &AppState, show_h
 }
 
 fn render_overworld_panel(frame: &mut Frame, area: Rect, state: &AppState) {
-    let block = panel_block("HUD", BG_PANEL_ALT);
+    let block = panel_block("HUD", theme().panel_alt);
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
@@ -742,43 +1705,47 @@ fn render_overworld_panel(frame: &mut Frame, area: Rect, state: &AppState) {
     let exp_current = state.exp_progress();
     let exp_next = state.exp_to_next_level().max(1);
     let party_count = state.party.len();
-    let balls = pokeball_count(state);
+    let balls = items::ball_count(state);
     let bag_summary = bag_summary(state);
     let lines = vec![
         Line::from(Span::styled(
             format_name(&state.player_name()),
             Style::default()
-                .fg(ACCENT_GREEN)
+                .fg(theme().accent)
                 .add_modifier(Modifier::BOLD),
         )),
         Line::from(format!("Lv {}", state.active_level())),
         Line::from(""),
-        meter_line("HP", hp_current as u32, hp_max as u32, 12, ACCENT_GREEN),
-        meter_line("EXP", exp_current, exp_next, 12, ACCENT_GOLD),
+        meter_line("HP", hp_current as u32, hp_max as u32, 12, theme().accent),
+        meter_line("EXP", exp_current, exp_next, 12, theme().gold),
         Line::from(""),
         Line::from(Span::styled(
             format!("Party: {}/3", party_count),
-            Style::default().fg(TEXT_MAIN),
+            Style::default().fg(theme().text),
         )),
         Line::from(Span::styled(
             format!("Balls: x{}", balls),
-            Style::default().fg(TEXT_MAIN),
+            Style::default().fg(theme().text),
+        )),
+        Line::from(Span::styled(
+            format!("Money: {}", shop::format_money(state.money)),
+            Style::default().fg(theme().text),
         )),
         Line::from(Span::styled(
             format!("Bag: {}", bag_summary),
-            Style::default().fg(TEXT_DIM),
+            Style::default().fg(theme().text_dim),
         )),
         if state.boss_defeated {
             Line::from(Span::styled(
                 "Demo complete!",
                 Style::default()
-                    .fg(ACCENT_GOLD)
+                    .fg(theme().gold)
                     .add_modifier(Modifier::BOLD),
             ))
         } else if state.has_relic {
             Line::from(Span::styled(
                 "Relic acquired",
-                Style::default().fg(ACCENT_GOLD),
+                Style::default().fg(theme().gold),
             ))
         } else {
             Line::from("")
@@ -792,22 +1759,23 @@ fn render_overworld_panel(frame: &mut Frame, area: Rect, state: &AppState) {
         frame,
         layout[1],
         state,
-        BG_PANEL_ALT,
+        theme().panel_alt,
         SPRITE_ID_PARTY_BASE,
         0.55,
         1.0,
         false,
+        None,
     );
 }
 
 fn render_map(frame: &mut Frame, area: Rect, state: &AppState) {
-    let block = panel_block(state.map.name.as_str(), BG_PANEL_ALT);
+    let block = panel_block(state.map.name.as_str(), theme().panel_alt);
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
     if inner.width < 8 || inner.height < 4 {
         let warning = Paragraph::new("Resize for map view.")
-            .style(Style::default().fg(TEXT_DIM))
+            .style(Style::default().fg(theme().text_dim))
             .alignment(Alignment::Center)
             .wrap(Wrap { trim: true });
         frame.render_widget(warning, inner);
@@ -835,11 +1803,66 @@ fn render_map(frame: &mut Frame, area: Rect, state: &AppState) {
         }
         if let Some((center_x, center_y)) = render.marker_cell(pickup.x, pickup.y) {
             if let Some(cell) = buf.cell_mut((center_x, center_y)) {
-                cell.set_fg(ACCENT_GOLD).set_char('*');
+                cell.set_fg(theme().gold).set_char('*');
             }
         }
     }
 
+    if let Some(scenario) = state.scenario.as_ref() {
+        for trainer in &scenario.manifest.trainers {
+            let color = if state.defeated_trainers.contains(&trainer.id) {
+                theme().text_dim
+            } else {
+                theme().danger
+            };
+            if let Some((center_x, center_y)) = render.marker_cell(trainer.x, trainer.y) {
+                if let Some(cell) = buf.cell_mut((center_x, center_y)) {
+                    cell.set_fg(color).set_char('T');
+                }
+            }
+        }
+        for shop in &scenario.manifest.shops {
+            if let Some((center_x, center_y)) = render.marker_cell(shop.x, shop.y) {
+                if let Some(cell) = buf.cell_mut((center_x, center_y)) {
+                    cell.set_fg(theme().gold).set_char('$');
+                }
+            }
+        }
+        for gauntlet in &scenario.manifest.gauntlets {
+            let color = if state.badges.contains(&gauntlet.id) {
+                theme().text_dim
+            } else {
+                theme().accent
+            };
+            if let Some((center_x, center_y)) = render.marker_cell(gauntlet.x, gauntlet.y) {
+                if let Some(cell) = buf.cell_mut((center_x, center_y)) {
+                    cell.set_fg(color).set_char('G');
+                }
+            }
+        }
+        for npc in &scenario.manifest.npcs {
+            // Gold when they have a quest to give or one to take back
+            let has_news = npc
+                .quest
+                .as_deref()
+                .and_then(|id| scenario.quest(id))
+                .is_some_and(|spec| {
+                    matches!(
+                        quest::status(state, spec),
+                        QuestStatus::NotTaken | QuestStatus::Ready
+                    )
+                });
+            let color = if has_news { theme().gold } else { theme().text };
+            if let Some((center_x, center_y)) = render.marker_cell(npc.x, npc.y) {
+                if let Some(cell) = buf.cell_mut((center_x, center_y)) {
+                    cell.set_fg(color).set_char('N');
+                }
+            }
+        }
+    }
+
+    paint_weather(buf, inner, state.weather, state.tick);
+
     let player_sprite = match state.player.facing {
         MoveDir::Right => state
             .player_sprite
@@ -852,18 +1875,70 @@ fn render_map(frame: &mut Frame, area: Rect, state: &AppState) {
         if let Some((tile_x, tile_y)) = render.tile_cell_origin(state.player.x, state.player.y) {
             let (cols, rows) = sprite_fit(sprite, render.cols_per_tile, render.rows_per_tile);
             let sprite_frame = sprite.frame(state.player_sprite.frame_index);
-            if let Ok(sequence) = sprite::kitty_sequence(sprite_frame, cols, rows, SPRITE_ID_PLAYER_MAP)
-            {
-                let offset_x = tile_x + render.cols_per_tile.saturating_sub(cols) / 2;
-                let offset_y = tile_y + render.rows_per_tile.saturating_sub(rows) / 2;
-                sprite_backend::set_sprite(SPRITE_ID_PLAYER_MAP, offset_x, offset_y, sequence);
+            let offset_x = tile_x + render.cols_per_tile.saturating_sub(cols) / 2;
+            let offset_y = tile_y + render.rows_per_tile.saturating_sub(rows) / 2;
+            sprite_backend::set_sprite(
+                SPRITE_ID_PLAYER_MAP,
+                SpriteLayer::Map,
+                Rect::new(offset_x, offset_y, cols, rows),
+                inner,
+                sprite_frame,
+            );
+        }
+    }
+}
+
+fn weather_color(weather: Weather) -> Color {
+    match weather {
+        Weather::Clear => theme().text_dim,
+        Weather::Rain => WEATHER_RAIN,
+        Weather::Sandstorm => WEATHER_SAND,
+        Weather::HarshSun => theme().gold,
+    }
+}
+
+/// Draws the weather over the map view. Rain and sand are scattered glyphs
+/// that drift with the tick; harsh sun washes the whole view out.
+fn paint_weather(buf: &mut ratatui::buffer::Buffer, area: Rect, weather: Weather, tick: u64) {
+    let (glyph, color, density) = match weather {
+        Weather::Clear => return,
+        Weather::HarshSun => {
+            for y in area.top()..area.bottom() {
+                for x in area.left()..area.right() {
+                    if let Some(cell) = buf.cell_mut((x, y)) {
+                        let bg = adjust_color(cell.bg, 28);
+                        cell.set_bg(bg);
+                    }
+                }
+            }
+            return;
+        }
+        Weather::Rain => ('╲', WEATHER_RAIN, 9),
+        Weather::Sandstorm => ('·', WEATHER_SAND, 6),
+    };
+    // Rain falls down and to the right; sand blows sideways.
+    let (drift_x, drift_y) = match weather {
+        Weather::Rain => (tick, tick),
+        _ => (tick * 2, 0),
+    };
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            let (sx, sy) = (
+                (x as u64).wrapping_sub(drift_x),
+                (y as u64).wrapping_sub(drift_y),
+            );
+            let hash = sx.wrapping_mul(73_856_093) ^ sy.wrapping_mul(19_349_663);
+            if hash % density == 0 {
+                if let Some(cell) = buf.cell_mut((x, y)) {
+                    cell.set_char(glyph).set_fg(color);
+                }
             }
         }
     }
 }
 
 fn render_overworld_status(frame: &mut Frame, area: Rect, state: &AppState, show_hud: bool) {
-    let block = panel_block("STATUS", BG_PANEL_ALT);
+    let block = panel_block("STATUS", theme().panel_alt);
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
@@ -873,14 +1948,18 @@ fn render_overworld_status(frame: &mut Frame, area: Rect, state: &AppState, show
         .unwrap_or("Wander the grass to find Pokemon.");
     if show_hud {
         let lines = vec![
-            Line::from(Span::styled(message, Style::default().fg(TEXT_MAIN))),
+            Line::from(Span::styled(message, Style::default().fg(theme().text))),
             Line::from(Span::styled(
-                "Arrows/WASD move  |  Esc menu",
-                Style::default().fg(TEXT_DIM),
+                format!(
+                    "{} move  |  {} talk  |  Esc menu",
+                    state.settings.controls.move_keys(),
+                    state.settings.controls.confirm_key()
+                ),
+                Style::default().fg(theme().text_dim),
             )),
         ];
         let paragraph = Paragraph::new(Text::from(lines))
-            .style(Style::default().fg(TEXT_MAIN))
+            .style(Style::default().fg(theme().text))
             .wrap(Wrap { trim: true });
         frame.render_widget(paragraph, inner);
     } else {
@@ -895,7 +1974,7 @@ fn render_overworld_status(frame: &mut Frame, area: Rect, state: &AppState, show
         let exp_next = state.exp_to_next_level().max(1);
         let bag_summary = bag_summary(state);
         let party_count = state.party.len();
-        let balls = pokeball_count(state);
+        let balls = items::ball_count(state);
         let bar_width = if area.width >= 80 { 26 } else { 16 };
         let layout = Layout::default()
             .direction(Direction::Vertical)
@@ -908,54 +1987,62 @@ fn render_overworld_status(frame: &mut Frame, area: Rect, state: &AppState, show
                 hp_current as u32,
                 hp_max as u32,
                 bar_width,
-                ACCENT_GREEN,
+                theme().accent,
             ),
-            meter_line("EXP", exp_current, exp_next, bar_width, ACCENT_GOLD),
+            meter_line("EXP", exp_current, exp_next, bar_width, theme().gold),
             Line::from(format!(
-                "Steps {}  |  Party {}/3  |  Balls x{}",
-                state.player.steps, party_count, balls
+                "Steps {}  |  Party {}/3  |  Balls x{}  |  {}",
+                state.player.steps,
+                party_count,
+                balls,
+                shop::format_money(state.money)
             )),
             Line::from(format!("Bag {}", bag_summary)),
             if state.boss_defeated {
                 Line::from(Span::styled(
                     "Demo complete!",
                     Style::default()
-                        .fg(ACCENT_GOLD)
+                        .fg(theme().gold)
                         .add_modifier(Modifier::BOLD),
                 ))
             } else if state.has_relic {
                 Line::from(Span::styled(
                     "Relic acquired",
-                    Style::default().fg(ACCENT_GOLD),
+                    Style::default().fg(theme().gold),
                 ))
             } else {
                 Line::from("")
             },
-            Line::from(Span::styled(message, Style::default().fg(TEXT_MAIN))),
+            Line::from(Span::styled(message, Style::default().fg(theme().text))),
             Line::from(Span::styled(
-                "Arrows/WASD move  |  Esc menu",
-                Style::default().fg(TEXT_DIM),
+                format!(
+                    "{} move  |  {} talk  |  Esc menu",
+                    state.settings.controls.move_keys(),
+                    state.settings.controls.confirm_key()
+                ),
+                Style::default().fg(theme().text_dim),
             )),
         ];
         let paragraph = Paragraph::new(Text::from(lines))
-            .style(Style::default().fg(TEXT_MAIN))
+            .style(Style::default().fg(theme().text))
             .wrap(Wrap { trim: true });
         frame.render_widget(paragraph, layout[0]);
         render_party_sprite_strip(
             frame,
             layout[1],
             state,
-            BG_PANEL_ALT,
+            theme().panel_alt,
             SPRITE_ID_PARTY_BASE + 10,
             0.55,
             1.0,
             false,
+            None,
         );
     }
 }
 
 fn render_battle(frame: &mut Frame, area: Rect, state: &AppState) {
-    let block = Block::default().style(Style::default().bg(BG_BASE));
+    let block = Block::default().style(Style::default().bg(theme().bg));
     frame.render_widget(block, area);
 
     // Command box is fixed at bottom, pokemon panels split the rest
@@ -976,29 +2063,80 @@ fn render_battle(frame: &mut Frame, area: Rect, state: &AppState) {
     render_enemy_panel(frame, pokemon_layout[0], state);
     render_player_panel(frame, pokemon_layout[1], state);
     render_battle_command(frame, layout[1], state);
+    if state.battle.as_ref().is_some_and(anim::flashing) {
+        frame
+            .buffer_mut()
+            .set_style(layout[0], Style::default().add_modifier(Modifier::REVERSED));
+    }
 
     if let Some(battle) = state.battle.as_ref().filter(|battle| battle_should_show_modal(battle)) {
-        render_battle_message_modal(frame, area, &battle.message);
+        render_battle_message_modal(frame, area, &battle.message, state.settings.controls);
     }
 }
 
+/// Top row of the battle screen while a replay plays: what it is, how far
+/// into the log it has got, and how to leave.
+fn render_replay_banner(frame: &mut Frame, area: Rect, state: &AppState) {
+    let Some(replay) = state.replay.as_ref() else {
+        return;
+    };
+    let (rolls, hits) = state.battle_log.counts(replay.cursor);
+    let line = Line::from(vec![
+        Span::styled(
+            " ▶ REPLAY ",
+            Style::default()
+                .fg(theme().highlight_text)
+                .bg(theme().highlight)
+                .add_modifier(Modifier::BOLD),
+        ),
+        Span::styled(
+            format!(" {rolls} rolls · {hits} hits  ESC: Stop "),
+            Style::default().fg(theme().text_dim).bg(theme().panel),
+        ),
+    ]);
+    let row = Rect::new(area.x, area.y, area.width, 1.min(area.height));
+    frame.render_widget(Paragraph::new(line).alignment(Alignment::Right), row);
+}
+
 fn render_enemy_panel(frame: &mut Frame, area: Rect, state: &AppState) {
     let enemy_name = state
         .battle
         .as_ref()
         .map(|battle| format_name(&battle.enemy_name))
         .unwrap_or_else(|| "Enemy".to_string());
-    let is_boss = state
+    let kind = state
         .battle
         .as_ref()
-        .map(|battle| battle.kind == crate::state::BattleKind::Boss)
-        .unwrap_or(false);
-    let title = if is_boss {
-        format!(" BOSS {} ", enemy_name.to_ascii_uppercase())
-    } else {
-        format!(" WILD {} ", enemy_name.to_ascii_uppercase())
+        .map(|battle| battle.kind)
+        .unwrap_or(BattleKind::Wild);
+    let trainer = state
+        .battle
+        .as_ref()
+        .and_then(|battle| battle.trainer.as_ref());
+    let mut title = match (kind, trainer) {
+        (BattleKind::Boss, _) => format!(" BOSS {} ", enemy_name.to_ascii_uppercase()),
+        (BattleKind::Trainer, Some(trainer)) => format!(
+            " {}'S {} ({} LEFT) ",
+            trainer.name.to_ascii_uppercase(),
+            enemy_name.to_ascii_uppercase(),
+            trainer.remaining()
+        ),
+        _ if state.battle.as_ref().is_some_and(|battle| battle.shiny) => {
+            format!(" WILD SHINY {} ", enemy_name.to_ascii_uppercase())
+        }
+        _ => format!(" WILD {} ", enemy_name.to_ascii_uppercase()),
     };
-    let block = panel_block(title.as_str(), BG_PANEL_ALT);
+    if let Some((name, stage, total)) = gauntlet_progress(state) {
+        title.push_str(&format!(
+            "- {} {}/{} ",
+            name.to_ascii_uppercase(),
+            stage,
+            total
+        ));
+    } else if let Some(zone) = battle_zone_name(state) {
+        title.push_str(&format!("- {} ", zone.to_ascii_uppercase()));
+    }
+    let block = panel_block(title.as_str(), theme().panel_alt);
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
@@ -1016,18 +2154,28 @@ fn render_enemy_stats(frame: &mut Frame, area: Rect, state: &AppState) {
         return;
     };
     let bar_width = area.width.saturating_sub(6).min(16).max(8) as usize;
-    let lines = vec![
-        hp_line_scaled(battle.enemy_hp, battle.enemy_hp_max, bar_width),
+    let (hp, hp_max) = anim::shown_hp(battle, Side::Enemy);
+    let mut lines = vec![
+        hp_line_scaled(hp, hp_max, bar_width),
         Line::from(Span::styled(
             format!("Lv {}", battle.enemy_level),
-            Style::default().fg(TEXT_DIM),
+            Style::default().fg(theme().text_dim),
         )),
     ];
-    let paragraph = Paragraph::new(Text::from(lines)).style(Style::default().fg(TEXT_MAIN));
+    if state.weather != Weather::Clear {
+        lines.push(Line::from(Span::styled(
+            state.weather.label(),
+            Style::default().fg(weather_color(state.weather)),
+        )));
+    }
+    let paragraph = Paragraph::new(Text::from(lines)).style(Style::default().fg(theme().text));
     frame.render_widget(paragraph, area);
 }
 
 fn render_enemy_sprite(frame: &mut Frame, area: Rect, state: &AppState) {
+    if let Some(backdrop) = battle_backdrop(state) {
+        paint_backdrop(frame, area, backdrop);
+    }
     if state
         .battle
         .as_ref()
@@ -1037,17 +2185,26 @@ fn render_enemy_sprite(frame: &mut Frame, area: Rect, state: &AppState) {
         return;
     }
     if let Some(sprite) = state.enemy_sprite.sprite.as_ref() {
-        let (cols, rows) = sprite_fit(sprite, area.width, area.height.saturating_sub(1));
+        let shake = state
+            .battle
+            .as_ref()
+            .map_or(0, |battle| anim::shake_offset(battle, Side::Enemy));
+        let sprite_area = shaken(area, shake);
+        let (cols, rows) = sprite_fit(sprite, sprite_area.width, area.height.saturating_sub(1));
         let sprite_frame = sprite.frame(state.enemy_sprite.frame_index);
-        if let Ok(sequence) =
-            sprite::kitty_sequence(sprite_frame, cols, rows, SPRITE_ID_ENEMY_BATTLE)
-        {
-            // Center horizontally, align to bottom
-            let offset_x = area.x.saturating_add(area.width.saturating_sub(cols) / 2);
-            let offset_y = area.y.saturating_add(area.height.saturating_sub(rows));
-            sprite_backend::set_sprite(SPRITE_ID_ENEMY_BATTLE, offset_x, offset_y, sequence);
-            return;
-        }
+        // Center horizontally, align to bottom
+        let offset_x = sprite_area
+            .x
+            .saturating_add(sprite_area.width.saturating_sub(cols) / 2);
+        let offset_y = area.y.saturating_add(area.height.saturating_sub(rows));
+        sprite_backend::set_sprite(
+            SPRITE_ID_ENEMY_BATTLE,
+            SpriteLayer::Panel,
+            Rect::new(offset_x, offset_y, cols, rows),
+            area,
+            sprite_frame,
+        );
+        return;
     }
 
     let content = if state.enemy_sprite.loading {
@@ -1055,8 +2212,8 @@ fn render_enemy_sprite(frame: &mut Frame, area: Rect, state: &AppState) {
     } else {
         "[no sprite]"
     };
-    let paragraph = Paragraph::new(content)
-        .style(Style::default().fg(TEXT_DIM))
+    // Styled line rather than a paragraph style, so the backdrop keeps its colors.
+    let paragraph = Paragraph::new(Line::styled(content, Style::default().fg(theme().text_dim)))
         .alignment(Alignment::Center);
     frame.render_widget(paragraph, area);
 }
@@ -1064,7 +2221,7 @@ fn render_enemy_sprite(frame: &mut Frame, area: Rect, state: &AppState) {
 fn render_player_panel(frame: &mut Frame, area: Rect, state: &AppState) {
     let player_name = format_name(&state.player_name());
     let title = format!(" {} ", player_name.to_ascii_uppercase());
-    let block = panel_block(title.as_str(), BG_PANEL);
+    let block = panel_block(title.as_str(), theme().panel);
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
@@ -1076,11 +2233,12 @@ fn render_player_panel(frame: &mut Frame, area: Rect, state: &AppState) {
         frame,
         layout[0],
         state,
-        BG_PANEL,
+        theme().panel,
         SPRITE_ID_PARTY_BASE + 20,
         0.88,
         1.12,
         true,
+        battle_backdrop(state),
     );
     render_player_stats(frame, layout[1], state);
 }
@@ -1089,21 +2247,21 @@ fn render_player_stats(frame: &mut Frame, area: Rect, state: &AppState) {
     let (current, max) = state
         .battle
         .as_ref()
-        .map(|battle| (battle.player_hp, battle.player_hp_max))
+        .map(|battle| anim::shown_hp(battle, Side::Player))
         .unwrap_or((state.player_max_hp(), state.player_max_hp()));
     let bar_width = area.width.saturating_sub(6).min(16).max(8) as usize;
     let mut lines = vec![
         hp_line_scaled(current, max, bar_width),
         Line::from(Span::styled(
             format!("Lv {}", state.active_level()),
-            Style::default().fg(TEXT_DIM),
+            Style::default().fg(theme().text_dim),
         )),
     ];
     if let Some(member) = state.active_member() {
         if member.ability_cd > 0 {
             lines.push(Line::from(Span::styled(
                 format!("Ability CD: {}", member.ability_cd),
-                Style::default().fg(TEXT_DIM),
+                Style::default().fg(theme().text_dim),
             )));
         }
     }
@@ -1111,16 +2269,16 @@ fn render_player_stats(frame: &mut Frame, area: Rect, state: &AppState) {
         if battle.guard_turns > 0 && battle.guard_pct > 0 {
             lines.push(Line::from(Span::styled(
                 format!("Guard -{}% ({}t)", battle.guard_pct, battle.guard_turns),
-                Style::default().fg(ACCENT_GOLD),
+                Style::default().fg(theme().gold),
             )));
         }
     }
-    let paragraph = Paragraph::new(Text::from(lines)).style(Style::default().fg(TEXT_MAIN));
+    let paragraph = Paragraph::new(Text::from(lines)).style(Style::default().fg(theme().text));
     frame.render_widget(paragraph, area);
 }
 
 fn render_battle_command(frame: &mut Frame, area: Rect, state: &AppState) {
-    let block = panel_block("COMMAND", BG_PANEL_ALT);
+    let block = panel_block("COMMAND", theme().panel_alt);
     let inner = block.inner(area);
     frame.render_widget(block, area);
 
@@ -1141,44 +2299,52 @@ fn render_battle_command(frame: &mut Frame, area: Rect, state: &AppState) {
             .split(inner)
     };
 
-    render_battle_prompt(frame, sections[0], battle);
+    render_battle_prompt(frame, sections[0], battle, state.settings.controls);
     render_battle_actions(frame, sections[1], state, battle);
 }
 
-fn render_battle_prompt(frame: &mut Frame, area: Rect, battle: &crate::state::BattleState) {
+fn render_battle_prompt(
+    frame: &mut Frame,
+    area: Rect,
+    battle: &crate::state::BattleState,
+    controls: ControlPreset,
+) {
     let mut lines = Vec::new();
-    if matches!(battle.stage, BattleStage::Menu | BattleStage::ItemMenu) {
+    if matches!(
+        battle.stage,
+        BattleStage::Menu | BattleStage::MoveMenu | BattleStage::ItemMenu | BattleStage::ReviveMenu
+    ) {
         lines.push(Line::from(Span::styled(
             battle.message.clone(),
-            Style::default().fg(TEXT_MAIN),
+            Style::default().fg(theme().text),
         )));
         lines.push(Line::from(Span::styled(
-            "Arrows/WASD: Navigate",
-            Style::default().fg(TEXT_DIM),
+            format!("{}: Navigate", controls.move_keys()),
+            Style::default().fg(theme().text_dim),
         )));
-        if battle.stage == BattleStage::ItemMenu {
+        if battle.stage != BattleStage::Menu {
             lines.push(Line::from(Span::styled(
-                "Z/Enter: Use  |  Esc: Back",
-                Style::default().fg(TEXT_DIM),
+                format!("{}: Use  |  Esc: Back", controls.confirm_key()),
+                Style::default().fg(theme().text_dim),
             )));
         } else {
             lines.push(Line::from(Span::styled(
-                "Z/Enter: Select",
-                Style::default().fg(TEXT_DIM),
+                format!("{}: Select", controls.confirm_key()),
+                Style::default().fg(theme().text_dim),
             )));
         }
     } else {
         lines.push(Line::from(Span::styled(
             "Resolving turn...",
-            Style::default().fg(TEXT_DIM),
+            Style::default().fg(theme().text_dim),
         )));
         lines.push(Line::from(Span::styled(
-            "Enter/Z: Continue",
-            Style::default().fg(TEXT_DIM),
+            format!("{}: Continue", controls.confirm_key()),
+            Style::default().fg(theme().text_dim),
         )));
     }
     let paragraph = Paragraph::new(Text::from(lines))
-        .style(Style::default().fg(TEXT_MAIN))
+        .style(Style::default().fg(theme().text))
         .wrap(Wrap { trim: true });
     frame.render_widget(paragraph, area);
 }
@@ -1191,14 +2357,16 @@ fn render_battle_actions(
 ) {
     let lines = match battle.stage {
         BattleStage::Menu => battle_menu_lines(battle.menu_index, battle.kind),
+        BattleStage::MoveMenu => battle_move_lines(state, battle.move_index),
         BattleStage::ItemMenu => battle_item_lines(state, battle.item_index),
+        BattleStage::ReviveMenu => battle_revive_lines(state, battle.revive_index),
         _ => vec![Line::from(Span::styled(
-            "Enter/Z: Continue",
-            Style::default().fg(TEXT_DIM),
+            format!("{}: Continue", state.settings.controls.confirm_key()),
+            Style::default().fg(theme().text_dim),
         ))],
     };
     let paragraph = Paragraph::new(Text::from(lines))
-        .style(Style::default().fg(TEXT_MAIN))
+        .style(Style::default().fg(theme().text))
         .wrap(Wrap { trim: true });
     frame.render_widget(paragraph, area);
 }
@@ -1207,23 +2375,23 @@ fn battle_menu_lines(selected: usize, kind: BattleKind) -> Vec<Line<'static>> {
     let options = ["FIGHT", "BAG", "CATCH", "ABILITY", "RUN"];
     let mut lines = Vec::new();
     for (idx, label) in options.iter().enumerate() {
-        let disabled = kind == BattleKind::Boss && (idx == 2 || idx == 4);
+        let disabled = kind != BattleKind::Wild && (idx == 2 || idx == 4);
         let style = if idx == selected {
             if disabled {
                 Style::default()
-                    .fg(TEXT_DIM)
-                    .bg(adjust_color(BG_PANEL_ALT, 10))
+                    .fg(theme().text_dim)
+                    .bg(adjust_color(theme().panel_alt, 10))
                     .add_modifier(Modifier::BOLD)
             } else {
                 Style::default()
-                    .fg(HIGHLIGHT_TEXT)
-                    .bg(HIGHLIGHT_BG)
+                    .fg(theme().highlight_text)
+                    .bg(theme().highlight)
                     .add_modifier(Modifier::BOLD)
             }
         } else if disabled {
-            Style::default().fg(TEXT_DIM)
+            Style::default().fg(theme().text_dim)
         } else {
-            Style::default().fg(TEXT_MAIN)
+            Style::default().fg(theme().text)
         };
         lines.push(Line::from(Span::styled(label.to_string(), style)));
     }
@@ -1231,7 +2399,18 @@ fn battle_menu_lines(selected: usize, kind: BattleKind) -> Vec<Line<'static>> {
 }
 
 fn battle_should_show_modal(battle: &crate::state::BattleState) -> bool {
-    !matches!(battle.stage, BattleStage::Menu | BattleStage::ItemMenu)
+    !matches!(
+        battle.stage,
+        BattleStage::Menu | BattleStage::MoveMenu | BattleStage::ItemMenu | BattleStage::ReviveMenu
+    )
+}
+
+/// `area` narrowed from one side so whatever is centered in it moves
+/// `offset` columns: right for positive, left for negative.
+fn shaken(area: Rect, offset: i16) -> Rect {
+    let trim = offset.unsigned_abs().saturating_mul(2).min(area.width);
+    let x = if offset > 0 { area.x + trim } else { area.x };
+    Rect::new(x, area.y, area.width - trim, area.height)
 }
 
 fn hp_line_scaled(current: u16, max: u16, width: usize) -> Line<'static> {
@@ -1246,9 +2425,9 @@ fn hp_line_scaled(current: u16, max: u16, width: usize) -> Line<'static> {
     let filled_bar = "█".repeat(filled);
     let empty_bar = "░".repeat(empty);
     let color = if ratio > 0.5 {
-        ACCENT_GREEN
+        theme().accent
     } else if ratio > 0.2 {
-        ACCENT_GOLD
+        theme().gold
     } else {
         Color::Rgb(220, 96, 96)
     };
@@ -1263,12 +2442,15 @@ fn hp_line_scaled(current: u16, max: u16, width: usize) -> Line<'static> {
                 .bg(bar_bg)
                 .add_modifier(Modifier::BOLD),
         ),
-        Span::styled(empty_bar, Style::default().fg(TEXT_DIM).bg(bar_bg_dim)),
+        Span::styled(
+            empty_bar,
+            Style::default().fg(theme().text_dim).bg(bar_bg_dim),
+        ),
         Span::raw(format!(" {}/{}", current, max)),
     ])
 }
 
-fn sprite_fit(sprite: &sprite::SpriteData, max_cols: u16, max_rows: u16) -> (u16, u16) {
+fn sprite_fit(sprite: &SpriteData, max_cols: u16, max_rows: u16) -> (u16, u16) {
     if max_cols == 0 || max_rows == 0 || sprite.height == 0 {
         return (max_cols, max_rows);
     }
@@ -1285,12 +2467,7 @@ fn sprite_fit(sprite: &sprite::SpriteData, max_cols: u16, max_rows: u16) -> (u16
     (max_cols.max(1), rows.min(max_rows).max(1))
 }
 
-fn sprite_fit_scaled(
-    sprite: &sprite::SpriteData,
-    max_cols: u16,
-    max_rows: u16,
-    scale: f32,
-) -> (u16, u16) {
+fn sprite_fit_scaled(sprite: &SpriteData, max_cols: u16, max_rows: u16, scale: f32) -> (u16, u16) {
     let scale = scale.clamp(0.1, 1.0);
     let cols = ((max_cols as f32) * scale).floor().max(1.0) as u16;
     let rows = ((max_rows as f32) * scale).floor().max(1.0) as u16;
@@ -1305,8 +2482,8 @@ where
         .borders(Borders::ALL)
         .border_type(BorderType::Rounded)
         .title(title)
-        .style(Style::default().bg(bg).fg(TEXT_MAIN))
-        .border_style(Style::default().fg(BORDER_ACCENT))
+        .style(Style::default().bg(bg).fg(theme().text))
+        .border_style(Style::default().fg(theme().border))
 }
 
 fn meter_line(label: &str, current: u32, max: u32, width: usize, color: Color) -> Line<'static> {
@@ -1317,50 +2494,68 @@ fn meter_line(label: &str, current: u32, max: u32, width: usize, color: Color) -
     let filled_bar = "█".repeat(filled);
     let empty_bar = "░".repeat(empty);
     Line::from(vec![
-        Span::styled(format!("{label} "), Style::default().fg(TEXT_DIM)),
+        Span::styled(format!("{label} "), Style::default().fg(theme().text_dim)),
         Span::styled(
             filled_bar,
             Style::default().fg(color).add_modifier(Modifier::BOLD),
         ),
-        Span::styled(empty_bar, Style::default().fg(TEXT_DIM)),
-        Span::styled(format!(" {current}/{max}"), Style::default().fg(TEXT_DIM)),
+        Span::styled(empty_bar, Style::default().fg(theme().text_dim)),
+        Span::styled(
+            format!(" {current}/{max}"),
+            Style::default().fg(theme().text_dim),
+        ),
     ])
 }
 
 fn menu_line(label: &str, selected: bool) -> Line<'static> {
     let style = if selected {
         Style::default()
-            .fg(HIGHLIGHT_TEXT)
-            .bg(HIGHLIGHT_BG)
+            .fg(theme().highlight_text)
+            .bg(theme().highlight)
             .add_modifier(Modifier::BOLD)
     } else {
-        Style::default().fg(TEXT_MAIN)
+        Style::default().fg(theme().text)
     };
     Line::from(Span::styled(label.to_string(), style))
 }
 
+/// The active Pokemon's moves with type and PP; ones out of PP are dimmed.
+fn battle_move_lines(state: &AppState, selected: usize) -> Vec<Line<'static>> {
+    let Some(member) = state.active_member() else {
+        return Vec::new();
+    };
+    member
+        .moves
+        .iter()
+        .enumerate()
+        .map(|(idx, mv)| {
+            let label = format!(
+                "{:<13} {:<8} {:>2}/{}",
+                format_name(&mv.name).to_uppercase(),
+                mv.move_type.to_uppercase(),
+                mv.pp,
+                mv.max_pp
+            );
+            if mv.pp == 0 && idx != selected {
+                Line::from(Span::styled(label, Style::default().fg(theme().text_dim)))
+            } else {
+                menu_line(&label, idx == selected)
+            }
+        })
+        .collect()
+}
+
 fn battle_item_lines(state: &AppState, selected: usize) -> Vec<Line<'static>> {
     let mut lines = Vec::new();
     let mut entries: Vec<(String, bool)> = Vec::new();
-    for (idx, stack) in state
-        .inventory
-        .iter()
-        .filter(|stack| stack.qty > 0)
-        .filter(|stack| {
-            matches!(
-                stack.kind,
-                crate::state::ItemKind::Potion | crate::state::ItemKind::SuperPotion
-            )
-        })
-        .enumerate()
-    {
-        let label = format!("{} x{}", stack.kind.label(), stack.qty);
+    for (idx, (kind, qty)) in items::usable(state).into_iter().enumerate() {
+        let label = format!("{} x{}", items::name(state, &kind), qty);
         entries.push((label, idx == selected));
     }
     if entries.is_empty() {
         lines.push(Line::from(Span::styled(
             "Bag is empty.",
-            Style::default().fg(TEXT_DIM),
+            Style::default().fg(theme().text_dim),
         )));
         return lines;
     }
@@ -1370,20 +2565,39 @@ fn battle_item_lines(state: &AppState, selected: usize) -> Vec<Line<'static>> {
     lines
 }
 
+/// Fainted party members a revive can bring back, by name and level.
+fn battle_revive_lines(state: &AppState, selected: usize) -> Vec<Line<'static>> {
+    state
+        .party
+        .iter()
+        .filter(|member| member.hp == 0)
+        .enumerate()
+        .map(|(idx, member)| {
+            let label = format!("{:<12} Lv {}", format_name(member.name()), member.level);
+            menu_line(&label, idx == selected)
+        })
+        .collect()
+}
+
 fn bag_summary(state: &AppState) -> String {
-    let mut items = Vec::new();
+    let mut held = Vec::new();
     for stack in &state.inventory {
-        if stack.qty > 0 && stack.kind != crate::state::ItemKind::PokeBall {
-            items.push(format!("{} x{}", stack.kind.label(), stack.qty));
+        if stack.qty > 0 && !items::is_ball(state, &stack.kind) {
+            held.push(format!(
+                "{} x{}",
+                items::name(state, &stack.kind),
+                stack.qty
+            ));
         }
     }
-    if items.is_empty() {
+    if held.is_empty() {
         "empty".to_string()
     } else {
-        items.join(", ")
+        held.join(", ")
     }
 }
 
+#[allow(clippy::too_many_arguments)]
 fn render_party_sprite_strip(
     frame: &mut Frame,
     area: Rect,
@@ -1393,18 +2607,17 @@ fn render_party_sprite_strip(
     scale: f32,
     active_boost: f32,
     show_shadow: bool,
+    backdrop: Option<&Backdrop>,
 ) {
     if area.width == 0 || area.height == 0 || state.party.is_empty() {
         return;
     }
-    let buf = frame.buffer_mut();
-    for y in area.y..area.y.saturating_add(area.height) {
-        for x in area.x..area.x.saturating_add(area.width) {
-            if let Some(cell) = buf.cell_mut((x, y)) {
-                cell.set_bg(bg).set_fg(bg).set_char(' ');
-            }
-        }
+    match backdrop {
+        Some(backdrop) => paint_backdrop(frame, area, backdrop),
+        None => fill_area(frame, area, bg, bg),
     }
+    let buf = frame.buffer_mut();
+    let battle = state.battle.as_ref();
     let slots = state.party.len().max(1);
     let slot_width = (area.width / slots as u16).max(1);
     for (idx, member) in state.party.iter().enumerate() {
@@ -1425,18 +2638,18 @@ fn render_party_sprite_strip(
         let can_draw_shadow = is_active && show_shadow && slot.height >= 2;
         let reserved_rows = (if draw_hp_bar { 1 } else { 0 }) + (if can_draw_shadow { 1 } else { 0 });
         let sprite_height = slot.height.saturating_sub(reserved_rows as u16);
-        let sprite_area = Rect::new(slot.x, slot.y, slot.width, sprite_height.max(1));
+        let mut sprite_area = Rect::new(slot.x, slot.y, slot.width, sprite_height.max(1));
+        if let Some(battle) = battle.filter(|_| is_active) {
+            sprite_area = shaken(sprite_area, anim::shake_offset(battle, Side::Player));
+        }
         let mut sprite_drawn = false;
 
         if let Some(sprite_state) = sprite_state {
             if let Some(sprite) = sprite_state.sprite.as_ref() {
-            if sprite_height > 0 {
-                let (cols, rows) =
-                    sprite_fit_scaled(sprite, sprite_area.width, sprite_area.height, scale);
-                let frame_data = sprite.frame(sprite_state.frame_index);
-                if let Ok(sequence) =
-                    sprite::kitty_sequence(frame_data, cols, rows, sprite_base + idx as u32)
-                {
+                if sprite_height > 0 {
+                    let (cols, rows) =
+                        sprite_fit_scaled(sprite, sprite_area.width, sprite_area.height, scale);
+                    let frame_data = sprite.frame(sprite_state.frame_index);
                     let offset_x = sprite_area
                         .x
                         .saturating_add(sprite_area.width.saturating_sub(cols) / 2);
@@ -1445,14 +2658,14 @@ fn render_party_sprite_strip(
                         .saturating_add(sprite_area.height.saturating_sub(rows) / 2);
                     sprite_backend::set_sprite(
                         sprite_base + idx as u32,
-                        offset_x,
-                        offset_y,
-                        sequence,
+                        SpriteLayer::Panel,
+                        Rect::new(offset_x, offset_y, cols, rows),
+                        sprite_area.intersection(area),
+                        frame_data,
                     );
                     sprite_drawn = true;
                 }
             }
-            }
         }
 
         if can_draw_shadow {
@@ -1480,8 +2693,12 @@ fn render_party_sprite_strip(
         };
 
         if let Some(bar_y) = bar_y {
-            let max_hp = calc_hp(member.info.hp, member.level).max(1);
-            let ratio = (member.hp.min(max_hp) as f32) / (max_hp as f32);
+            let (hp, max_hp) = match battle.filter(|_| is_active) {
+                Some(battle) => anim::shown_hp(battle, Side::Player),
+                None => (member.hp, calc_hp(member.info.hp, member.level)),
+            };
+            let max_hp = max_hp.max(1);
+            let ratio = (hp.min(max_hp) as f32) / (max_hp as f32);
             let bar_width = slot
                 .width
                 .saturating_sub(2)
@@ -1493,9 +2710,9 @@ fn render_party_sprite_strip(
                 .saturating_add(slot.width.saturating_sub(bar_width) / 2);
             let filled = ((ratio * bar_width as f32).round() as u16).min(bar_width);
             let color = if ratio > 0.5 {
-                ACCENT_GREEN
+                theme().accent
             } else if ratio > 0.2 {
-                ACCENT_GOLD
+                theme().gold
             } else {
                 Color::Rgb(220, 96, 96)
             };
@@ -1503,7 +2720,7 @@ fn render_party_sprite_strip(
                 let (ch, fg) = if i < filled {
                     ('█', color)
                 } else {
-                    ('░', TEXT_DIM)
+                    ('░', theme().text_dim)
                 };
                 if let Some(cell) = buf.cell_mut((start_x + i, bar_y)) {
                     cell.set_bg(bg).set_fg(fg).set_char(ch);
@@ -1521,19 +2738,52 @@ fn render_party_sprite_strip(
             let cx = sprite_area.x + sprite_area.width / 2;
             let cy = sprite_area.y + sprite_area.height / 2;
             if let Some(cell) = buf.cell_mut((cx, cy)) {
-                cell.set_bg(bg).set_fg(TEXT_DIM).set_char(label);
+                cell.set_bg(bg).set_fg(theme().text_dim).set_char(label);
             }
         }
     }
 }
 
-fn pokeball_count(state: &AppState) -> u16 {
-    state
-        .inventory
+fn battle_zone_name(state: &AppState) -> Option<&str> {
+    let id = state.battle.as_ref()?.zone.as_deref()?;
+    let scenario = state.scenario.as_ref()?;
+    scenario
+        .manifest
+        .zones
         .iter()
-        .find(|stack| stack.kind == crate::state::ItemKind::PokeBall)
-        .map(|stack| stack.qty)
-        .unwrap_or(0)
+        .find(|zone| zone.id == id)
+        .map(|zone| zone.name.as_str())
+}
+
+/// Gauntlet name with the battle number and count, while one is being fought.
+fn gauntlet_progress(state: &AppState) -> Option<(&str, usize, usize)> {
+    let run = state.gauntlet.as_ref()?;
+    let spec = state.scenario.as_ref()?.gauntlet(&run.id)?;
+    Some((spec.name.as_str(), run.stage + 1, spec.battles.len()))
+}
+
+fn battle_backdrop(state: &AppState) -> Option<&Backdrop> {
+    let id = state.battle.as_ref()?.backdrop.as_deref()?;
+    state.scenario.as_ref()?.backdrop(id)
+}
+
+/// Paint backdrop art into the cell buffer; kitty sprites placed afterwards sit on top of it.
+fn paint_backdrop(frame: &mut Frame, area: Rect, backdrop: &Backdrop) {
+    let buf = frame.buffer_mut();
+    for row in 0..area.height {
+        for col in 0..area.width {
+            let Some((ch, color)) = backdrop.cell(col, row, area.height) else {
+                continue;
+            };
+            let (fr, fg, fb) = color.fg;
+            let (br, bg, bb) = color.bg;
+            if let Some(cell) = buf.cell_mut((area.x + col, area.y + row)) {
+                cell.set_char(ch)
+                    .set_fg(Color::Rgb(fr, fg, fb))
+                    .set_bg(Color::Rgb(br, bg, bb));
+            }
+        }
+    }
 }
 
 fn format_name(name: &str) -> String {
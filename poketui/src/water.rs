@@ -0,0 +1,71 @@
+//! Fishing and Surf. Facing water and pressing `Z` or `E` casts a line; after
+//! a random wait something bites, and reeling in while the `!` is up hooks a
+//! Pokemon from the scenario's `water_pool`. Reel in early and there's
+//! nothing on the line; wait too long and it gets away. Turning in the
+//! scenario's `surf_quest` teaches Surf, after which water can be walked on.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::state::AppState;
+
+/// Fished up when a scenario has no `water_pool`.
+pub const DEFAULT_WATER_POOL: [&str; 4] = ["magikarp", "poliwag", "goldeen", "tentacool"];
+/// Shortest wait for a bite, in ticks.
+const MIN_WAIT_TICKS: u16 = 10;
+/// The wait runs up to this many ticks past the shortest.
+const WAIT_SPREAD: u32 = 25;
+/// How long a bite lasts; a bit under a second.
+pub const BITE_TICKS: u16 = 7;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum FishingPhase {
+    /// The line is out and nothing has bitten yet.
+    Waiting,
+    /// Something's on the line; reel in now.
+    Bite,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct FishingState {
+    pub phase: FishingPhase,
+    /// Ticks left in the phase.
+    pub ticks: u16,
+}
+
+impl FishingState {
+    /// A fresh cast, its wait picked by `roll`.
+    pub fn cast(roll: u32) -> Self {
+        Self {
+            phase: FishingPhase::Waiting,
+            ticks: MIN_WAIT_TICKS + (roll % (WAIT_SPREAD + 1)) as u16,
+        }
+    }
+
+    /// Advances a tick. Returns whether a bite went by without a reel.
+    pub fn tick(&mut self) -> bool {
+        self.ticks = self.ticks.saturating_sub(1);
+        if self.ticks > 0 {
+            return false;
+        }
+        match self.phase {
+            FishingPhase::Waiting => {
+                self.phase = FishingPhase::Bite;
+                self.ticks = BITE_TICKS;
+                false
+            }
+            FishingPhase::Bite => true,
+        }
+    }
+}
+
+/// Whether the player has learned Surf: the scenario names a `surf_quest`
+/// and it has been turned in.
+pub fn can_surf(state: &AppState) -> bool {
+    state
+        .scenario
+        .as_ref()
+        .and_then(|scenario| scenario.manifest.surf_quest.as_deref())
+        .and_then(|id| state.quests.entry(id))
+        .is_some_and(|entry| entry.done)
+}
@@ -0,0 +1,139 @@
+//! Route weather. A scenario sets it in its manifest, either fixed or rolled
+//! from a weighted pool, and a rolled one changes every `WEATHER_STEPS` steps.
+//! Weather is drawn over the map and bends battles: rain and harsh sun scale
+//! Water and Fire moves, a sandstorm throws attacks off and wears down every
+//! Pokemon that isn't Rock, Ground or Steel at the end of each turn.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::state::AppState;
+
+/// Overworld steps between rolls of a random weather pool.
+pub const WEATHER_STEPS: u32 = 60;
+/// Chance an attack lands in a sandstorm, in percent.
+const SANDSTORM_ACCURACY: u32 = 85;
+/// A sandstorm takes this fraction of max HP each turn.
+const SANDSTORM_CHIP_DIVISOR: u16 = 16;
+const SAND_PROOF_TYPES: [&str; 3] = ["rock", "ground", "steel"];
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum Weather {
+    #[default]
+    Clear,
+    Rain,
+    Sandstorm,
+    HarshSun,
+}
+
+impl Weather {
+    pub fn label(self) -> &'static str {
+        match self {
+            Weather::Clear => "Clear",
+            Weather::Rain => "Rain",
+            Weather::Sandstorm => "Sandstorm",
+            Weather::HarshSun => "Harsh sun",
+        }
+    }
+
+    /// Said when the weather turns to this.
+    pub fn onset(self) -> &'static str {
+        match self {
+            Weather::Clear => "The skies cleared.",
+            Weather::Rain => "It started to rain.",
+            Weather::Sandstorm => "A sandstorm kicked up!",
+            Weather::HarshSun => "The sunlight turned harsh!",
+        }
+    }
+
+    /// Damage scale for a move of `move_type`, in percent.
+    pub fn damage_percent(self, move_type: &str) -> u32 {
+        match (self, move_type) {
+            (Weather::Rain, "water") | (Weather::HarshSun, "fire") => 150,
+            (Weather::Rain, "fire") | (Weather::HarshSun, "water") => 50,
+            _ => 100,
+        }
+    }
+
+    pub fn accuracy_percent(self) -> u32 {
+        match self {
+            Weather::Sandstorm => SANDSTORM_ACCURACY,
+            _ => 100,
+        }
+    }
+
+    /// HP a Pokemon of `types` loses at the end of a turn.
+    pub fn chip_damage(self, types: &[String], hp_max: u16) -> u16 {
+        let sand_proof = types
+            .iter()
+            .any(|kind| SAND_PROOF_TYPES.contains(&kind.as_str()));
+        if self != Weather::Sandstorm || sand_proof {
+            return 0;
+        }
+        (hp_max / SANDSTORM_CHIP_DIVISOR).max(1)
+    }
+}
+
+/// A scenario's weather as written in its manifest.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum WeatherSpec {
+    Fixed {
+        weather: Weather,
+    },
+    /// Rolled on arrival and again every `WEATHER_STEPS` steps.
+    Random {
+        pool: Vec<WeatherChance>,
+    },
+}
+
+impl Default for WeatherSpec {
+    fn default() -> Self {
+        WeatherSpec::Fixed {
+            weather: Weather::Clear,
+        }
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct WeatherChance {
+    pub weather: Weather,
+    pub weight: u16,
+}
+
+impl WeatherSpec {
+    /// The weather for a `roll`, any random number.
+    pub fn pick(&self, roll: u32) -> Weather {
+        match self {
+            WeatherSpec::Fixed { weather } => *weather,
+            WeatherSpec::Random { pool } => {
+                let total: u32 = pool.iter().map(|chance| chance.weight as u32).sum();
+                if total == 0 {
+                    return Weather::Clear;
+                }
+                let mut roll = roll % total;
+                for chance in pool {
+                    if roll < chance.weight as u32 {
+                        return chance.weather;
+                    }
+                    roll -= chance.weight as u32;
+                }
+                Weather::Clear
+            }
+        }
+    }
+
+    pub fn is_random(&self) -> bool {
+        matches!(self, WeatherSpec::Random { .. })
+    }
+}
+
+/// The loaded scenario's weather spec, clear skies without one.
+pub fn spec(state: &AppState) -> WeatherSpec {
+    state
+        .scenario
+        .as_ref()
+        .map(|scenario| scenario.manifest.weather.clone())
+        .unwrap_or_default()
+}
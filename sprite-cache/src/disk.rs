@@ -0,0 +1,81 @@
+//! Downloads kept on disk between runs, one file per URL.
+//!
+//! Files live at `<root>/<kind>/<sha256 of the key>`. Failures to read or
+//! write are treated as a miss: the cache only ever saves a download.
+
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+use tokio::fs;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DiskCache {
+    root: PathBuf,
+}
+
+impl DiskCache {
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        Self { root: root.into() }
+    }
+
+    /// `~/.cache/<app>`, or `./.cache/<app>` without a home directory.
+    pub fn for_app(app: &str) -> Self {
+        let base = std::env::var("HOME")
+            .map(PathBuf::from)
+            .unwrap_or_else(|_| PathBuf::from("."));
+        Self::new(base.join(".cache").join(app))
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    pub fn path(&self, kind: &str, key: &str) -> PathBuf {
+        let mut hasher = Sha256::new();
+        hasher.update(key.as_bytes());
+        let digest = hex::encode(hasher.finalize());
+        self.root.join(kind).join(digest)
+    }
+
+    pub async fn read(&self, kind: &str, key: &str) -> Option<Vec<u8>> {
+        fs::read(self.path(kind, key)).await.ok()
+    }
+
+    pub async fn write(&self, kind: &str, key: &str, bytes: &[u8]) {
+        let path = self.path(kind, key);
+        if let Some(parent) = path.parent() {
+            let _ = fs::create_dir_all(parent).await;
+        }
+        let _ = fs::write(path, bytes).await;
+    }
+
+    /// Drops an entry, e.g. one that turned out to be corrupt.
+    pub async fn remove(&self, kind: &str, key: &str) {
+        let _ = fs::remove_file(self.path(kind, key)).await;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn entries_round_trip_per_kind() {
+        let root = std::env::temp_dir().join(format!("sprite-cache-test-{}", std::process::id()));
+        let cache = DiskCache::new(&root);
+        let url = "https://example.com/sprites/25.png";
+
+        assert_eq!(cache.read("http", url).await, None);
+        cache.write("http", url, b"pikachu").await;
+        assert_eq!(
+            cache.read("http", url).await.as_deref(),
+            Some(&b"pikachu"[..])
+        );
+        assert_eq!(cache.read("audio", url).await, None);
+        assert!(cache.path("http", url).starts_with(root.join("http")));
+
+        cache.remove("http", url).await;
+        assert_eq!(cache.read("http", url).await, None);
+        let _ = std::fs::remove_dir_all(root);
+    }
+}
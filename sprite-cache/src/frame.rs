@@ -0,0 +1,258 @@
+use std::io::Cursor;
+
+use base64::{engine::general_purpose, Engine as _};
+use image::{codecs::gif::GifDecoder, AnimationDecoder, GenericImageView, RgbaImage};
+use serde::{Deserialize, Serialize};
+
+/// Kitty's `f=` value for raw 8-bit RGBA pixels.
+pub const FORMAT_RGBA: u32 = 32;
+/// Kitty's `f=` value for a PNG file.
+pub const FORMAT_PNG: u32 = 100;
+
+/// One frame, base64 encoded so it can go straight into a kitty escape.
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SpriteFrame {
+    pub payload: String,
+    pub width: u32,
+    pub height: u32,
+    /// [`FORMAT_RGBA`] for decoded GIF frames, [`FORMAT_PNG`] for still images.
+    pub format: u32,
+}
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct SpriteData {
+    pub frames: Vec<SpriteFrame>,
+    pub width: u32,
+    pub height: u32,
+}
+
+impl SpriteData {
+    pub fn frame(&self, index: usize) -> &SpriteFrame {
+        let idx = if self.frames.is_empty() {
+            0
+        } else {
+            index % self.frames.len()
+        };
+        &self.frames[idx]
+    }
+
+    /// Moves an animation on by one tick, switching to the next frame every
+    /// `step` ticks. Returns whether the frame changed; stills never do.
+    pub fn advance(&self, index: &mut usize, tick: &mut u64, step: u64) -> bool {
+        if self.frames.len() <= 1 {
+            return false;
+        }
+        *tick = tick.wrapping_add(1);
+        if !tick.is_multiple_of(step.max(1)) {
+            return false;
+        }
+        *index = (*index + 1) % self.frames.len();
+        true
+    }
+
+    /// Returns a horizontally flipped version of this sprite
+    pub fn flipped(&self) -> SpriteData {
+        SpriteData {
+            frames: self.frames.iter().map(|f| f.flipped()).collect(),
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+impl SpriteFrame {
+    /// Returns a horizontally flipped version of this frame, or the frame
+    /// unchanged if it can't be decoded.
+    pub fn flipped(&self) -> SpriteFrame {
+        let Ok(image) = frame_rgba(self) else {
+            return self.clone();
+        };
+        let flipped = image::imageops::flip_horizontal(&image);
+        let bytes = if self.format == FORMAT_RGBA {
+            flipped.into_raw()
+        } else {
+            let mut buf = Vec::new();
+            if flipped
+                .write_to(&mut Cursor::new(&mut buf), image::ImageFormat::Png)
+                .is_err()
+            {
+                return self.clone();
+            }
+            buf
+        };
+        SpriteFrame {
+            payload: general_purpose::STANDARD.encode(bytes),
+            ..self.clone()
+        }
+    }
+}
+
+/// Animated GIFs become one raw RGBA frame each; anything else `image` can
+/// read is kept as the original file in a single frame.
+pub fn decode_sprite(bytes: &[u8], url: &str) -> Result<SpriteData, String> {
+    if is_gif(bytes, url) {
+        let decoder = GifDecoder::new(Cursor::new(bytes)).map_err(|err| err.to_string())?;
+        let frames = decoder
+            .into_frames()
+            .collect_frames()
+            .map_err(|err| err.to_string())?;
+        let mut sprite_frames = Vec::new();
+        for frame in frames {
+            let buffer = frame.into_buffer();
+            let (width, height) = buffer.dimensions();
+            let encoded = general_purpose::STANDARD.encode(buffer.as_raw());
+            sprite_frames.push(SpriteFrame {
+                payload: encoded,
+                width,
+                height,
+                format: FORMAT_RGBA,
+            });
+        }
+        if let Some(first) = sprite_frames.first() {
+            let (width, height) = (first.width, first.height);
+            return Ok(SpriteData {
+                frames: sprite_frames,
+                width,
+                height,
+            });
+        }
+    }
+
+    let image = image::load_from_memory(bytes).map_err(|err| err.to_string())?;
+    let (width, height) = image.dimensions();
+    let encoded = general_purpose::STANDARD.encode(bytes);
+    Ok(SpriteData {
+        frames: vec![SpriteFrame {
+            payload: encoded,
+            width,
+            height,
+            format: FORMAT_PNG,
+        }],
+        width,
+        height,
+    })
+}
+
+/// Decoded pixels of a frame, for the renderers that can't hand the terminal a PNG.
+pub fn frame_rgba(frame: &SpriteFrame) -> Result<RgbaImage, String> {
+    let bytes = general_purpose::STANDARD
+        .decode(&frame.payload)
+        .map_err(|err| err.to_string())?;
+    if frame.format == FORMAT_RGBA {
+        return RgbaImage::from_raw(frame.width, frame.height, bytes)
+            .ok_or_else(|| "sprite frame has the wrong size".to_string());
+    }
+    let image = image::load_from_memory(&bytes).map_err(|err| err.to_string())?;
+    Ok(image.to_rgba8())
+}
+
+fn is_gif(bytes: &[u8], url: &str) -> bool {
+    if url.ends_with(".gif") {
+        return true;
+    }
+    bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a")
+}
+
+#[cfg(test)]
+pub(crate) mod tests {
+    use super::*;
+
+    use image::codecs::gif::GifEncoder;
+    use image::{Delay, Frame, Rgba};
+
+    /// 2x2 raw frame: red, transparent / blue, blue.
+    pub(crate) fn tiny_frame() -> SpriteFrame {
+        let pixels = [255, 0, 0, 255, 0, 0, 0, 0, 0, 0, 255, 255, 0, 0, 255, 255];
+        SpriteFrame {
+            payload: general_purpose::STANDARD.encode(pixels),
+            width: 2,
+            height: 2,
+            format: FORMAT_RGBA,
+        }
+    }
+
+    fn png_bytes(image: &RgbaImage) -> Vec<u8> {
+        let mut buf = Vec::new();
+        image
+            .write_to(&mut Cursor::new(&mut buf), image::ImageFormat::Png)
+            .expect("png");
+        buf
+    }
+
+    #[test]
+    fn gifs_decode_to_raw_frames_and_pngs_stay_whole() {
+        let mut gif = Vec::new();
+        {
+            let mut encoder = GifEncoder::new(&mut gif);
+            for shade in [0, 255] {
+                let image = RgbaImage::from_pixel(3, 2, Rgba([shade, 0, 0, 255]));
+                let frame = Frame::from_parts(image, 0, 0, Delay::from_numer_denom_ms(100, 1));
+                encoder.encode_frame(frame).expect("gif frame");
+            }
+        }
+        let animated = decode_sprite(&gif, "front.gif").expect("gif");
+        assert_eq!(animated.frames.len(), 2);
+        assert_eq!((animated.width, animated.height), (3, 2));
+        assert!(animated.frames.iter().all(|f| f.format == FORMAT_RGBA));
+
+        let png = png_bytes(&RgbaImage::from_pixel(4, 5, Rgba([0, 0, 0, 255])));
+        let still = decode_sprite(&png, "front.png").expect("png");
+        assert_eq!(still.frames.len(), 1);
+        assert_eq!(still.frames[0].format, FORMAT_PNG);
+        assert_eq!(
+            still.frames[0].payload,
+            general_purpose::STANDARD.encode(&png)
+        );
+
+        assert!(decode_sprite(b"not an image", "front.png").is_err());
+    }
+
+    #[test]
+    fn flipping_mirrors_raw_and_png_frames() {
+        let raw = tiny_frame();
+        let flipped = frame_rgba(&raw.flipped()).expect("raw");
+        assert_eq!(flipped.get_pixel(0, 0).0, [0, 0, 0, 0]);
+        assert_eq!(flipped.get_pixel(1, 0).0, [255, 0, 0, 255]);
+
+        let png = SpriteFrame {
+            payload: general_purpose::STANDARD.encode(png_bytes(&frame_rgba(&raw).unwrap())),
+            format: FORMAT_PNG,
+            ..raw.clone()
+        };
+        let flipped_png = png.flipped();
+        assert_eq!(flipped_png.format, FORMAT_PNG);
+        assert_eq!(frame_rgba(&flipped_png).unwrap(), flipped);
+
+        let broken = SpriteFrame {
+            payload: "%%%".to_string(),
+            ..raw
+        };
+        assert_eq!(broken.flipped(), broken);
+    }
+
+    #[test]
+    fn animations_step_and_wrap() {
+        let sprite = SpriteData {
+            frames: vec![tiny_frame(); 3],
+            width: 2,
+            height: 2,
+        };
+        let (mut index, mut tick) = (0, 0);
+        assert!(!sprite.advance(&mut index, &mut tick, 2));
+        assert!(sprite.advance(&mut index, &mut tick, 2));
+        assert_eq!(index, 1);
+        for _ in 0..4 {
+            sprite.advance(&mut index, &mut tick, 2);
+        }
+        assert_eq!(index, 0);
+        assert_eq!(sprite.frame(7), &sprite.frames[1]);
+
+        let still = SpriteData {
+            frames: vec![tiny_frame()],
+            ..sprite
+        };
+        assert!(!still.advance(&mut index, &mut tick, 1));
+    }
+}
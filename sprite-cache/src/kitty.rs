@@ -0,0 +1,145 @@
+//! Kitty graphics protocol escapes.
+
+use crate::frame::SpriteFrame;
+
+/// Kitty caps each escape's payload at 4096 bytes.
+const CHUNK_SIZE: usize = 4096;
+
+/// Escapes that draw `frame` at the cursor, scaled to `cols` x `rows` cells
+/// (0 keeps the image's own size). With an `id` the image can later be
+/// removed on its own with [`delete_image`].
+pub fn kitty_sequence(
+    frame: &SpriteFrame,
+    cols: u16,
+    rows: u16,
+    id: Option<u32>,
+) -> Result<String, String> {
+    let mut params = String::new();
+    if let Some(id) = id {
+        params.push_str(&format!(",i={id}"));
+    }
+    if cols > 0 {
+        params.push_str(&format!(",c={cols}"));
+    }
+    if rows > 0 {
+        params.push_str(&format!(",r={rows}"));
+    }
+    transmit(frame, &params)
+}
+
+/// Cells of a `cols` x `rows` placement to keep, relative to its top left.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CellCrop {
+    pub x: u16,
+    pub y: u16,
+    pub cols: u16,
+    pub rows: u16,
+}
+
+/// Like [`kitty_sequence`], but only the `crop` part of the placement is
+/// drawn, at the cursor, and stacked at `z` against other images.
+pub fn kitty_sequence_cropped(
+    frame: &SpriteFrame,
+    cols: u16,
+    rows: u16,
+    crop: CellCrop,
+    z: i32,
+    id: u32,
+) -> Result<String, String> {
+    let (cols, rows) = (u32::from(cols.max(1)), u32::from(rows.max(1)));
+    let to_px = |cell: u16, cells: u32, size: u32| u32::from(cell) * size / cells;
+    let x = to_px(crop.x, cols, frame.width);
+    let y = to_px(crop.y, rows, frame.height);
+    let w = to_px(crop.x.saturating_add(crop.cols), cols, frame.width).saturating_sub(x);
+    let h = to_px(crop.y.saturating_add(crop.rows), rows, frame.height).saturating_sub(y);
+    let params = format!(
+        ",i={id},x={x},y={y},w={w},h={h},c={},r={},z={z}",
+        crop.cols, crop.rows
+    );
+    transmit(frame, &params)
+}
+
+/// Sends `frame` in as many escapes as it takes, with `params` (each
+/// starting with a comma) added to the first one.
+fn transmit(frame: &SpriteFrame, params: &str) -> Result<String, String> {
+    let mut sequences = String::new();
+    let payload = frame.payload.as_bytes();
+    let total_chunks = payload.len().div_ceil(CHUNK_SIZE);
+
+    for (index, chunk) in payload.chunks(CHUNK_SIZE).enumerate() {
+        let more = u8::from(index + 1 < total_chunks);
+        let chunk_str = std::str::from_utf8(chunk).map_err(|err| err.to_string())?;
+        if index == 0 {
+            sequences.push_str(&format!(
+                "\x1b_Gf={},s={},v={},a=T,t=d{params},m={more};{chunk_str}\x1b\\",
+                frame.format, frame.width, frame.height
+            ));
+        } else {
+            sequences.push_str(&format!("\x1b_Gm={more};{chunk_str}\x1b\\"));
+        }
+    }
+    Ok(sequences)
+}
+
+/// Removes the image drawn with `id`.
+pub fn delete_image(id: u32) -> String {
+    format!("\x1b_Ga=d,d=i,i={id}\x1b\\")
+}
+
+/// Removes every image on screen.
+pub fn delete_all() -> &'static str {
+    "\x1b_Ga=d,d=a\x1b\\"
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frame::tests::tiny_frame;
+
+    #[test]
+    fn small_frames_fit_one_escape() {
+        let frame = tiny_frame();
+        let sequence = kitty_sequence(&frame, 4, 2, Some(7)).expect("kitty");
+        assert_eq!(
+            sequence,
+            format!(
+                "\x1b_Gf=32,s=2,v=2,a=T,t=d,i=7,c=4,r=2,m=0;{}\x1b\\",
+                frame.payload
+            )
+        );
+        let unsized_frame = kitty_sequence(&frame, 0, 0, None).expect("kitty");
+        assert!(unsized_frame.starts_with("\x1b_Gf=32,s=2,v=2,a=T,t=d,m=0;"));
+    }
+
+    #[test]
+    fn crops_pick_the_matching_source_pixels() {
+        let frame = SpriteFrame {
+            width: 40,
+            height: 20,
+            ..tiny_frame()
+        };
+        let crop = CellCrop {
+            x: 1,
+            y: 0,
+            cols: 3,
+            rows: 1,
+        };
+        let sequence = kitty_sequence_cropped(&frame, 4, 2, crop, -1, 9).expect("kitty");
+        assert!(sequence
+            .starts_with("\x1b_Gf=32,s=40,v=20,a=T,t=d,i=9,x=10,y=0,w=30,h=10,c=3,r=1,z=-1,m=0;"));
+    }
+
+    #[test]
+    fn large_payloads_are_chunked() {
+        let frame = SpriteFrame {
+            payload: "A".repeat(CHUNK_SIZE * 2 + 10),
+            ..tiny_frame()
+        };
+        let sequence = kitty_sequence(&frame, 0, 0, None).expect("kitty");
+        let escapes: Vec<&str> = sequence.split("\x1b\\").filter(|s| !s.is_empty()).collect();
+        assert_eq!(escapes.len(), 3);
+        assert!(escapes[0].contains(",m=1;"));
+        assert!(escapes[1].starts_with("\x1b_Gm=1;"));
+        assert_eq!(escapes[2], format!("\x1b_Gm=0;{}", "A".repeat(10)));
+    }
+}
@@ -0,0 +1,15 @@
+//! Sprites shared by the PokeAPI apps.
+//!
+//! [`frame`] decodes downloaded PNGs and GIFs into base64 frames and steps
+//! through animations, [`kitty`] and [`raster`] turn a frame into something a
+//! terminal can draw, and [`disk`] keeps downloads between runs.
+
+pub mod disk;
+pub mod frame;
+pub mod kitty;
+pub mod raster;
+
+pub use disk::DiskCache;
+pub use frame::{decode_sprite, frame_rgba, SpriteData, SpriteFrame};
+pub use kitty::{kitty_sequence, kitty_sequence_cropped, CellCrop};
+pub use raster::{halfblock_cells, sixel_sequence, HalfBlock};
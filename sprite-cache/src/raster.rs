@@ -0,0 +1,131 @@
+//! Frames for terminals without the kitty protocol: sixel escapes and
+//! half-block cells.
+
+use std::collections::BTreeMap;
+
+use image::imageops::{self, FilterType};
+
+use crate::frame::{frame_rgba, SpriteFrame};
+
+/// One cell of a half-block sprite: top and bottom pixel, `None` where transparent.
+pub type HalfBlock = [Option<(u8, u8, u8)>; 2];
+
+/// `cols` x `rows` cells, row-major, two pixels per cell. Nearest-neighbour
+/// scaling keeps the pixel art crisp.
+pub fn halfblock_cells(
+    frame: &SpriteFrame,
+    cols: u16,
+    rows: u16,
+) -> Result<Vec<HalfBlock>, String> {
+    let image = frame_rgba(frame)?;
+    let scaled = imageops::resize(
+        &image,
+        u32::from(cols.max(1)),
+        u32::from(rows.max(1)) * 2,
+        FilterType::Nearest,
+    );
+    let pixel = |x: u32, y: u32| {
+        let [r, g, b, a] = scaled.get_pixel(x, y).0;
+        (a >= 128).then_some((r, g, b))
+    };
+    let mut cells = Vec::with_capacity(usize::from(cols) * usize::from(rows));
+    for row in 0..scaled.height() / 2 {
+        for col in 0..scaled.width() {
+            cells.push([pixel(col, row * 2), pixel(col, row * 2 + 1)]);
+        }
+    }
+    Ok(cells)
+}
+
+/// Sixel image scaled to `width` x `height` pixels, colors snapped to a 6x6x6
+/// cube. Transparent pixels are left unpainted.
+pub fn sixel_sequence(frame: &SpriteFrame, width: u32, height: u32) -> Result<String, String> {
+    let image = frame_rgba(frame)?;
+    let scaled = imageops::resize(&image, width.max(1), height.max(1), FilterType::Nearest);
+    let (width, height) = scaled.dimensions();
+    let mut out = format!("\x1bP0;1;0q\"1;1;{width};{height}");
+    let mut defined = [false; 216];
+    for top in (0..height).step_by(6) {
+        // Palette index -> one sixel bit column per pixel column.
+        let mut bands: BTreeMap<usize, Vec<u8>> = BTreeMap::new();
+        for x in 0..width {
+            for dy in 0..6.min(height - top) {
+                let [r, g, b, a] = scaled.get_pixel(x, top + dy).0;
+                if a < 128 {
+                    continue;
+                }
+                let bits = bands
+                    .entry(cube_index(r, g, b))
+                    .or_insert_with(|| vec![0; width as usize]);
+                bits[x as usize] |= 1 << dy;
+            }
+        }
+        for (pass, (color, bits)) in bands.iter().enumerate() {
+            if pass > 0 {
+                out.push('$');
+            }
+            if !defined[*color] {
+                defined[*color] = true;
+                let (r, g, b) = (color / 36, color / 6 % 6, color % 6);
+                out.push_str(&format!("#{color};2;{};{};{}", r * 20, g * 20, b * 20));
+            }
+            out.push_str(&format!("#{color}"));
+            push_sixel_run(&mut out, bits);
+        }
+        out.push('-');
+    }
+    out.push_str("\x1b\\");
+    Ok(out)
+}
+
+fn cube_index(r: u8, g: u8, b: u8) -> usize {
+    let level = |value: u8| (usize::from(value) * 5 + 127) / 255;
+    level(r) * 36 + level(g) * 6 + level(b)
+}
+
+/// Sixel characters for one color pass, run-length encoded.
+fn push_sixel_run(out: &mut String, bits: &[u8]) {
+    let mut index = 0;
+    while index < bits.len() {
+        let value = bits[index];
+        let run = bits[index..]
+            .iter()
+            .take_while(|bit| **bit == value)
+            .count();
+        let ch = char::from(63 + value);
+        if run > 3 {
+            out.push_str(&format!("!{run}{ch}"));
+        } else {
+            out.extend(std::iter::repeat_n(ch, run));
+        }
+        index += run;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::frame::tests::tiny_frame;
+
+    #[test]
+    fn halfblocks_pair_rows_and_keep_transparency() {
+        let cells = halfblock_cells(&tiny_frame(), 2, 1).expect("cells");
+        assert_eq!(
+            cells,
+            vec![
+                [Some((255, 0, 0)), Some((0, 0, 255))],
+                [None, Some((0, 0, 255))],
+            ]
+        );
+    }
+
+    #[test]
+    fn sixel_paints_each_color_once_per_band() {
+        let sixel = sixel_sequence(&tiny_frame(), 2, 2).expect("sixel");
+        assert!(sixel.starts_with("\x1bP0;1;0q\"1;1;2;2"));
+        assert!(sixel.ends_with("-\x1b\\"));
+        // Blue fills the bottom row (bit 1) of both columns, red the top-left pixel.
+        assert!(sixel.contains("#5;2;0;0;100#5AA"));
+        assert!(sixel.contains("$#180;2;100;0;0#180@?"));
+    }
+}
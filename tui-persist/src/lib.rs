@@ -0,0 +1,300 @@
+//! JSON save files shared by the games.
+//!
+//! [`SaveFile::save`] writes to a temporary file next to the save and renames
+//! it into place, so a crash mid-write never leaves half a save behind. The
+//! previous save is kept as `<name>.bak` (older ones as `<name>.bak.2`, ...),
+//! and [`SaveFile::load`] falls back to them when the save itself can't be
+//! read. Every save carries a `save_version`; older ones go through the
+//! migration hook before they are deserialized.
+
+use std::ffi::OsString;
+use std::fs::{self, File};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use serde::de::DeserializeOwned;
+use serde::Serialize;
+use serde_json::Value;
+
+/// Top-level key holding the schema version in every save.
+pub const VERSION_KEY: &str = "save_version";
+const DEFAULT_BACKUPS: usize = 2;
+
+/// Upgrades a save written at version `from` to the current schema, in place.
+pub type Migrate = fn(from: u32, save: &mut Value) -> Result<(), String>;
+
+#[derive(thiserror::Error, Debug)]
+pub enum LoadError {
+    #[error("Save file not found.")]
+    NotFound,
+    #[error("Failed to read save file: {0}")]
+    Io(io::Error),
+    #[error("Save file corrupted: {0}")]
+    Corrupt(#[from] serde_json::Error),
+    #[error("Save file is from a newer version (v{found}, this build reads up to v{supported})")]
+    TooNew { found: u32, supported: u32 },
+    #[error("Failed to upgrade save from v{from}: {message}")]
+    Migration { from: u32, message: String },
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum SaveError {
+    #[error("Failed to serialize: {0}")]
+    Serialize(#[from] serde_json::Error),
+    #[error("Failed to write save file: {0}")]
+    Io(#[from] io::Error),
+}
+
+#[derive(Clone, Debug)]
+pub struct SaveFile {
+    path: PathBuf,
+    backups: usize,
+    version: u32,
+    migrate: Option<Migrate>,
+}
+
+impl SaveFile {
+    /// A save at `path`, version 0, keeping two backups.
+    pub fn new(path: impl Into<PathBuf>) -> Self {
+        Self {
+            path: path.into(),
+            backups: DEFAULT_BACKUPS,
+            version: 0,
+            migrate: None,
+        }
+    }
+
+    /// How many previous saves to keep; 0 keeps none.
+    pub fn backups(mut self, backups: usize) -> Self {
+        self.backups = backups;
+        self
+    }
+
+    /// The schema version written with every save. Loading a save with a
+    /// higher one fails with [`LoadError::TooNew`]. Saves without a
+    /// `save_version` predate versioning and load as version 0.
+    pub fn version(mut self, version: u32) -> Self {
+        self.version = version;
+        self
+    }
+
+    /// Runs on saves older than [`SaveFile::version`]. Without a hook they
+    /// are loaded as they are.
+    pub fn migrate(mut self, migrate: Migrate) -> Self {
+        self.migrate = Some(migrate);
+        self
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    pub fn exists(&self) -> bool {
+        self.path.exists()
+    }
+
+    /// `<name>.bak` for the newest backup, `<name>.bak.<n>` for older ones.
+    pub fn backup_path(&self, n: usize) -> PathBuf {
+        if n <= 1 {
+            self.with_suffix(".bak")
+        } else {
+            self.with_suffix(&format!(".bak.{n}"))
+        }
+    }
+
+    pub fn save<T: Serialize>(&self, value: &T) -> Result<(), SaveError> {
+        let mut json = serde_json::to_value(value)?;
+        if let Value::Object(map) = &mut json {
+            map.insert(VERSION_KEY.to_string(), self.version.into());
+        }
+        let bytes = serde_json::to_vec_pretty(&json)?;
+
+        if let Some(parent) = self.path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(parent)?;
+        }
+        let temp = self.with_suffix(".tmp");
+        let mut file = File::create(&temp)?;
+        file.write_all(&bytes)?;
+        file.sync_all()?;
+        drop(file);
+
+        self.rotate_backups()?;
+        fs::rename(&temp, &self.path)?;
+        Ok(())
+    }
+
+    /// The save, or the newest backup that loads if the save itself is
+    /// unreadable. A missing save is [`LoadError::NotFound`] even when
+    /// backups exist, so a deleted save stays deleted.
+    pub fn load<T: DeserializeOwned>(&self) -> Result<T, LoadError> {
+        match self.load_from(&self.path) {
+            Err(err @ (LoadError::Io(_) | LoadError::Corrupt(_))) => (1..=self.backups)
+                .find_map(|n| self.load_from(&self.backup_path(n)).ok())
+                .ok_or(err),
+            result => result,
+        }
+    }
+
+    /// Removes the save and its backups. Missing files are fine.
+    pub fn delete(&self) -> io::Result<()> {
+        let backups = (1..=self.backups).map(|n| self.backup_path(n));
+        for path in std::iter::once(self.path.clone()).chain(backups) {
+            match fs::remove_file(&path) {
+                Err(err) if err.kind() != io::ErrorKind::NotFound => return Err(err),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    fn load_from<T: DeserializeOwned>(&self, path: &Path) -> Result<T, LoadError> {
+        let bytes = fs::read(path).map_err(|err| match err.kind() {
+            io::ErrorKind::NotFound => LoadError::NotFound,
+            _ => LoadError::Io(err),
+        })?;
+        let mut json: Value = serde_json::from_slice(&bytes)?;
+        let found = json
+            .get(VERSION_KEY)
+            .and_then(Value::as_u64)
+            .map_or(0, |version| version as u32);
+        if found > self.version {
+            return Err(LoadError::TooNew {
+                found,
+                supported: self.version,
+            });
+        }
+        if found < self.version {
+            if let Some(migrate) = self.migrate {
+                migrate(found, &mut json).map_err(|message| LoadError::Migration {
+                    from: found,
+                    message,
+                })?;
+            }
+        }
+        if let Value::Object(map) = &mut json {
+            map.remove(VERSION_KEY);
+        }
+        Ok(serde_json::from_value(json)?)
+    }
+
+    /// Shifts every backup one slot older and copies the current save into
+    /// the newest. Copying rather than moving keeps the save in place until
+    /// the new one is renamed over it.
+    fn rotate_backups(&self) -> io::Result<()> {
+        if self.backups == 0 || !self.path.exists() {
+            return Ok(());
+        }
+        for n in (1..self.backups).rev() {
+            let from = self.backup_path(n);
+            if from.exists() {
+                fs::rename(&from, self.backup_path(n + 1))?;
+            }
+        }
+        fs::copy(&self.path, self.backup_path(1))?;
+        Ok(())
+    }
+
+    fn with_suffix(&self, suffix: &str) -> PathBuf {
+        let mut name = OsString::from(self.path.as_os_str());
+        name.push(suffix);
+        PathBuf::from(name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Serialize, Deserialize)]
+    struct Run {
+        floor: u32,
+        #[serde(default)]
+        name: String,
+    }
+
+    fn scratch(test: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("tui-persist-{}-{test}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        dir.join("save.json")
+    }
+
+    fn run(floor: u32) -> Run {
+        Run {
+            floor,
+            name: "ash".to_string(),
+        }
+    }
+
+    #[test]
+    fn saves_round_trip_and_rotate_backups() {
+        let save = SaveFile::new(scratch("rotate")).version(3);
+        assert!(matches!(save.load::<Run>(), Err(LoadError::NotFound)));
+
+        for floor in 1..=4 {
+            save.save(&run(floor)).unwrap();
+        }
+        assert_eq!(save.load::<Run>().unwrap(), run(4));
+        let written: Value = serde_json::from_slice(&fs::read(save.path()).unwrap()).unwrap();
+        assert_eq!(written[VERSION_KEY], 3);
+
+        let backup = |n| fs::read_to_string(save.backup_path(n)).unwrap();
+        assert!(backup(1).contains("\"floor\": 3"));
+        assert!(backup(2).contains("\"floor\": 2"));
+        assert!(!save.backup_path(3).exists());
+        assert!(!save.path().with_extension("json.tmp").exists());
+
+        save.delete().unwrap();
+        assert!(!save.exists() && !save.backup_path(1).exists());
+        assert!(matches!(save.load::<Run>(), Err(LoadError::NotFound)));
+        let _ = fs::remove_dir_all(save.path().parent().unwrap());
+    }
+
+    #[test]
+    fn corrupt_saves_fall_back_to_the_newest_good_backup() {
+        let save = SaveFile::new(scratch("corrupt"));
+        save.save(&run(1)).unwrap();
+        save.save(&run(2)).unwrap();
+        fs::write(save.path(), "{ half a save").unwrap();
+        assert_eq!(save.load::<Run>().unwrap(), run(1));
+
+        let strict = save.clone().backups(0);
+        assert!(matches!(strict.load::<Run>(), Err(LoadError::Corrupt(_))));
+        let _ = fs::remove_dir_all(save.path().parent().unwrap());
+    }
+
+    #[test]
+    fn versions_gate_and_migrate_old_saves() {
+        let path = scratch("versions");
+        SaveFile::new(&path).version(5).save(&run(7)).unwrap();
+        let older = SaveFile::new(&path).version(4);
+        assert!(matches!(
+            older.load::<Run>(),
+            Err(LoadError::TooNew {
+                found: 5,
+                supported: 4
+            })
+        ));
+
+        fs::write(&path, r#"{ "level": 9 }"#).unwrap();
+        let rename_level: Migrate = |from, save| {
+            assert_eq!(from, 0);
+            let level = save["level"].take();
+            save["floor"] = level;
+            Ok(())
+        };
+        let current = SaveFile::new(&path).version(1).migrate(rename_level);
+        let loaded: Run = current.load().unwrap();
+        assert_eq!(loaded.floor, 9);
+
+        let failing = SaveFile::new(&path)
+            .version(1)
+            .migrate(|_, _| Err("unknown layout".to_string()));
+        let err = failing.load::<Run>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Failed to upgrade save from v0: unknown layout"
+        );
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+}
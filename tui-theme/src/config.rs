@@ -0,0 +1,151 @@
+//! User overrides from `theme.toml`.
+//!
+//! The file lives at `~/.config/<app>/theme.toml` (or under
+//! `$XDG_CONFIG_HOME`). It can pick another preset and replace single colors:
+//!
+//! ```toml
+//! preset = "tavern"
+//!
+//! [colors]
+//! accent = "#e4b058"
+//! text_dim = { r = 150, g = 150, b = 150 }
+//! ```
+
+use std::path::PathBuf;
+
+use serde::Deserialize;
+
+use crate::palette::Palette;
+use crate::rgb::Rgb;
+
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct ThemeFile {
+    /// Preset to start from instead of the app's own.
+    pub preset: Option<String>,
+    pub colors: PaletteOverrides,
+}
+
+/// Any subset of a [`Palette`]'s colors.
+#[derive(Clone, Debug, Default, PartialEq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct PaletteOverrides {
+    pub bg: Option<Rgb>,
+    pub panel: Option<Rgb>,
+    pub panel_alt: Option<Rgb>,
+    pub highlight: Option<Rgb>,
+    pub highlight_text: Option<Rgb>,
+    pub text: Option<Rgb>,
+    pub text_dim: Option<Rgb>,
+    pub border: Option<Rgb>,
+    pub accent: Option<Rgb>,
+    pub gold: Option<Rgb>,
+    pub danger: Option<Rgb>,
+    pub success: Option<Rgb>,
+}
+
+impl PaletteOverrides {
+    pub fn apply(&self, palette: &mut Palette) {
+        let slots = [
+            (self.bg, &mut palette.bg),
+            (self.panel, &mut palette.panel),
+            (self.panel_alt, &mut palette.panel_alt),
+            (self.highlight, &mut palette.highlight),
+            (self.highlight_text, &mut palette.highlight_text),
+            (self.text, &mut palette.text),
+            (self.text_dim, &mut palette.text_dim),
+            (self.border, &mut palette.border),
+            (self.accent, &mut palette.accent),
+            (self.gold, &mut palette.gold),
+            (self.danger, &mut palette.danger),
+            (self.success, &mut palette.success),
+        ];
+        for (value, slot) in slots {
+            if let Some(value) = value {
+                *slot = value;
+            }
+        }
+    }
+}
+
+impl ThemeFile {
+    /// The palette this file describes on top of `fallback`. An unknown
+    /// preset keeps `fallback` and is reported.
+    pub fn resolve(&self, fallback: Palette) -> (Palette, Option<String>) {
+        let mut warning = None;
+        let mut palette = match &self.preset {
+            Some(name) => Palette::preset(name).unwrap_or_else(|| {
+                warning = Some(format!("unknown preset `{name}`"));
+                fallback
+            }),
+            None => fallback,
+        };
+        self.colors.apply(&mut palette);
+        (palette, warning)
+    }
+}
+
+/// `default_preset` with the user's `theme.toml` for `app` applied, plus a
+/// note on anything in the file that couldn't be used. A missing file is
+/// not a problem.
+pub fn load(app: &str, default_preset: &str) -> (Palette, Option<String>) {
+    let fallback = Palette::preset(default_preset).unwrap_or_default();
+    let text = match std::fs::read_to_string(config_path(app)) {
+        Ok(text) => text,
+        Err(err) if err.kind() == std::io::ErrorKind::NotFound => return (fallback, None),
+        Err(err) => return (fallback, Some(format!("theme.toml: {err}"))),
+    };
+    match toml::from_str::<ThemeFile>(&text) {
+        Ok(file) => {
+            let (palette, warning) = file.resolve(fallback);
+            (
+                palette,
+                warning.map(|warning| format!("theme.toml: {warning}")),
+            )
+        }
+        Err(err) => (fallback, Some(format!("theme.toml: {}", err.message()))),
+    }
+}
+
+pub fn config_path(app: &str) -> PathBuf {
+    let base = std::env::var("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|_| std::env::var("HOME").map(|home| PathBuf::from(home).join(".config")))
+        .unwrap_or_else(|_| PathBuf::from("."));
+    base.join(app).join("theme.toml")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn theme_files_pick_a_preset_and_override_colors() {
+        let file: ThemeFile = toml::from_str(
+            r##"
+            preset = "meadow"
+
+            [colors]
+            accent = "#e4b058"
+            text_dim = { r = 1, g = 2, b = 3 }
+            "##,
+        )
+        .unwrap();
+        let (palette, warning) = file.resolve(Palette::POKEDEX);
+        assert_eq!(warning, None);
+        assert_eq!(palette.accent, Rgb::new(228, 176, 88));
+        assert_eq!(palette.text_dim, Rgb::new(1, 2, 3));
+        assert_eq!(palette.bg, Palette::MEADOW.bg);
+    }
+
+    #[test]
+    fn unknown_presets_and_keys_are_reported() {
+        let file: ThemeFile = toml::from_str("preset = \"neon\"").unwrap();
+        let (palette, warning) = file.resolve(Palette::SKY);
+        assert_eq!(palette, Palette::SKY);
+        assert_eq!(warning.as_deref(), Some("unknown preset `neon`"));
+
+        assert!(toml::from_str::<ThemeFile>("[colors]\nglow = \"#ffffff\"").is_err());
+        assert!(toml::from_str::<ThemeFile>("[colors]\naccent = \"teal\"").is_err());
+    }
+}
@@ -0,0 +1,17 @@
+//! Color palettes shared by the TUI apps.
+//!
+//! Each app starts from a named [`Palette`] preset, lets the user override it
+//! from `theme.toml` ([`config`]), and installs the result once at startup.
+//! UI code then reads resolved terminal colors from [`theme()`].
+
+pub mod config;
+pub mod palette;
+pub mod rgb;
+#[cfg(feature = "ratatui")]
+pub mod theme;
+
+pub use config::{load, PaletteOverrides, ThemeFile};
+pub use palette::Palette;
+pub use rgb::Rgb;
+#[cfg(feature = "ratatui")]
+pub use theme::{install, theme, Theme};
@@ -0,0 +1,157 @@
+use serde::{Deserialize, Serialize};
+
+use crate::rgb::Rgb;
+
+/// How far an unfocused variant fades toward the panel color.
+const UNFOCUSED_FADE: f32 = 0.4;
+
+/// The colors an app's chrome is drawn with, by role rather than hue.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+pub struct Palette {
+    /// Behind everything.
+    pub bg: Rgb,
+    pub panel: Rgb,
+    /// Nested panels, headers and input fields.
+    pub panel_alt: Rgb,
+    /// Behind the selected row.
+    pub highlight: Rgb,
+    pub highlight_text: Rgb,
+    pub text: Rgb,
+    pub text_dim: Rgb,
+    /// Unfocused panel borders.
+    pub border: Rgb,
+    /// Focused borders, headings and the app's signature color.
+    pub accent: Rgb,
+    /// Secondary accent: titles, prices, warnings.
+    pub gold: Rgb,
+    pub danger: Rgb,
+    pub success: Rgb,
+}
+
+impl Palette {
+    /// Deep blue with teal, for the Pokedex browser.
+    pub const POKEDEX: Palette = Palette {
+        bg: Rgb::new(12, 18, 28),
+        panel: Rgb::new(20, 32, 46),
+        panel_alt: Rgb::new(26, 40, 58),
+        highlight: Rgb::new(28, 92, 110),
+        highlight_text: Rgb::new(232, 242, 244),
+        text: Rgb::new(232, 242, 244),
+        text_dim: Rgb::new(176, 195, 207),
+        border: Rgb::new(176, 195, 207),
+        accent: Rgb::new(72, 204, 184),
+        gold: Rgb::new(228, 176, 88),
+        danger: Rgb::new(236, 104, 96),
+        success: Rgb::new(118, 214, 122),
+    };
+
+    /// Grass greens, for the overworld game.
+    pub const MEADOW: Palette = Palette {
+        bg: Rgb::new(24, 36, 26),
+        panel: Rgb::new(34, 58, 38),
+        panel_alt: Rgb::new(28, 48, 32),
+        highlight: Rgb::new(104, 204, 120),
+        highlight_text: Rgb::new(16, 26, 18),
+        text: Rgb::new(228, 236, 214),
+        text_dim: Rgb::new(172, 186, 160),
+        border: Rgb::new(74, 98, 82),
+        accent: Rgb::new(104, 204, 120),
+        gold: Rgb::new(222, 196, 120),
+        danger: Rgb::new(220, 96, 96),
+        success: Rgb::new(104, 204, 120),
+    };
+
+    /// Charcoal and candlelight, for the dungeon crawler.
+    pub const TAVERN: Palette = Palette {
+        bg: Rgb::new(16, 18, 20),
+        panel: Rgb::new(26, 28, 32),
+        panel_alt: Rgb::new(34, 37, 42),
+        highlight: Rgb::new(52, 70, 66),
+        highlight_text: Rgb::new(232, 232, 232),
+        text: Rgb::new(232, 232, 232),
+        text_dim: Rgb::new(160, 160, 160),
+        border: Rgb::new(160, 160, 160),
+        accent: Rgb::new(126, 200, 180),
+        gold: Rgb::new(222, 196, 120),
+        danger: Rgb::new(204, 90, 90),
+        success: Rgb::new(126, 200, 140),
+    };
+
+    /// Slate grays, for the weather app; its accent follows the weather.
+    pub const SKY: Palette = Palette {
+        bg: Rgb::new(20, 20, 28),
+        panel: Rgb::new(35, 35, 45),
+        panel_alt: Rgb::new(50, 50, 60),
+        highlight: Rgb::new(60, 60, 80),
+        highlight_text: Rgb::new(235, 235, 240),
+        text: Rgb::new(229, 229, 229),
+        text_dim: Rgb::new(170, 170, 180),
+        border: Rgb::new(70, 70, 85),
+        accent: Rgb::new(90, 200, 220),
+        gold: Rgb::new(250, 210, 80),
+        danger: Rgb::new(230, 80, 80),
+        success: Rgb::new(110, 200, 120),
+    };
+
+    pub const PRESETS: &'static [(&'static str, Palette)] = &[
+        ("pokedex", Palette::POKEDEX),
+        ("meadow", Palette::MEADOW),
+        ("tavern", Palette::TAVERN),
+        ("sky", Palette::SKY),
+    ];
+
+    pub fn preset(name: &str) -> Option<Palette> {
+        Palette::PRESETS
+            .iter()
+            .find(|(preset, _)| preset.eq_ignore_ascii_case(name))
+            .map(|(_, palette)| *palette)
+    }
+
+    /// `color` as drawn on a focused element, or faded toward the panel
+    /// when the element doesn't have focus.
+    pub fn focus_variant(&self, color: Rgb, focused: bool) -> Rgb {
+        if focused {
+            color
+        } else {
+            color.lerp(self.panel, UNFOCUSED_FADE)
+        }
+    }
+
+    /// Border of a panel: the accent while it has focus.
+    pub fn focus_border(&self, focused: bool) -> Rgb {
+        if focused {
+            self.accent
+        } else {
+            self.border
+        }
+    }
+}
+
+impl Default for Palette {
+    fn default() -> Self {
+        Palette::POKEDEX
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn presets_are_found_by_name() {
+        assert_eq!(Palette::preset("meadow"), Some(Palette::MEADOW));
+        assert_eq!(Palette::preset("Tavern"), Some(Palette::TAVERN));
+        assert_eq!(Palette::preset("neon"), None);
+    }
+
+    #[test]
+    fn unfocused_variants_fade_toward_the_panel() {
+        let palette = Palette::TAVERN;
+        assert_eq!(palette.focus_variant(palette.danger, true), palette.danger);
+        let faded = palette.focus_variant(palette.danger, false);
+        assert!(faded.r < palette.danger.r && faded.r > palette.panel.r);
+        assert_eq!(palette.focus_border(true), palette.accent);
+        assert_eq!(palette.focus_border(false), palette.border);
+    }
+}
@@ -0,0 +1,95 @@
+use serde::{Deserialize, Serialize};
+
+/// A plain RGB triple, kept separate from ratatui's `Color` so it can live in
+/// serializable state and config files.
+///
+/// Deserializes from either `"#rrggbb"` or `{ r, g, b }`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[cfg_attr(feature = "schemars", derive(schemars::JsonSchema))]
+#[serde(try_from = "RgbRepr")]
+pub struct Rgb {
+    pub r: u8,
+    pub g: u8,
+    pub b: u8,
+}
+
+impl Rgb {
+    pub const fn new(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// Parses `#rrggbb` (the `#` is optional).
+    pub fn parse_hex(text: &str) -> Result<Rgb, String> {
+        let digits = text.trim().trim_start_matches('#');
+        if digits.len() != 6 || !digits.chars().all(|ch| ch.is_ascii_hexdigit()) {
+            return Err(format!("`{text}` is not a #rrggbb color"));
+        }
+        let channel = |at: usize| u8::from_str_radix(&digits[at..at + 2], 16).unwrap_or(0);
+        Ok(Rgb::new(channel(0), channel(2), channel(4)))
+    }
+
+    pub fn hex(self) -> String {
+        format!("#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+
+    /// Linear blend toward `to`; `t` is clamped to 0..=1.
+    pub fn lerp(self, to: Rgb, t: f32) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+        Rgb::new(mix(self.r, to.r), mix(self.g, to.g), mix(self.b, to.b))
+    }
+
+    #[cfg(feature = "ratatui")]
+    pub fn color(self) -> ratatui::style::Color {
+        ratatui::style::Color::Rgb(self.r, self.g, self.b)
+    }
+}
+
+#[cfg(feature = "ratatui")]
+impl From<Rgb> for ratatui::style::Color {
+    fn from(rgb: Rgb) -> Self {
+        rgb.color()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum RgbRepr {
+    Hex(String),
+    Channels { r: u8, g: u8, b: u8 },
+}
+
+impl TryFrom<RgbRepr> for Rgb {
+    type Error = String;
+
+    fn try_from(repr: RgbRepr) -> Result<Self, Self::Error> {
+        match repr {
+            RgbRepr::Hex(text) => Rgb::parse_hex(&text),
+            RgbRepr::Channels { r, g, b } => Ok(Rgb::new(r, g, b)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn hex_round_trips_and_rejects_junk() {
+        let teal = Rgb::parse_hex("#48ccb8").unwrap();
+        assert_eq!(teal, Rgb::new(72, 204, 184));
+        assert_eq!(teal.hex(), "#48ccb8");
+        assert_eq!(Rgb::parse_hex("48CCB8"), Ok(teal));
+        assert!(Rgb::parse_hex("#48ccb").is_err());
+        assert!(Rgb::parse_hex("#48ccbz").is_err());
+    }
+
+    #[test]
+    fn lerp_clamps_to_the_endpoints() {
+        let black = Rgb::new(0, 0, 0);
+        let white = Rgb::new(255, 255, 255);
+        assert_eq!(black.lerp(white, 0.5), Rgb::new(128, 128, 128));
+        assert_eq!(black.lerp(white, 2.0), white);
+        assert_eq!(black.lerp(white, -1.0), black);
+    }
+}
@@ -0,0 +1,71 @@
+//! Resolved terminal colors for the installed palette.
+
+use std::sync::OnceLock;
+
+use ratatui::style::Color;
+
+use crate::palette::Palette;
+
+static INSTALLED: OnceLock<Theme> = OnceLock::new();
+
+/// A [`Palette`] as ratatui colors, plus the faded variants UI code reaches
+/// for when something isn't focused.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Theme {
+    pub palette: Palette,
+    pub bg: Color,
+    pub panel: Color,
+    pub panel_alt: Color,
+    pub highlight: Color,
+    pub highlight_text: Color,
+    pub text: Color,
+    pub text_dim: Color,
+    pub border: Color,
+    pub accent: Color,
+    pub gold: Color,
+    pub danger: Color,
+    pub success: Color,
+    pub accent_muted: Color,
+    pub gold_muted: Color,
+    pub danger_muted: Color,
+}
+
+impl Theme {
+    pub fn new(palette: Palette) -> Self {
+        let muted = |rgb| palette.focus_variant(rgb, false).color();
+        Self {
+            palette,
+            bg: palette.bg.color(),
+            panel: palette.panel.color(),
+            panel_alt: palette.panel_alt.color(),
+            highlight: palette.highlight.color(),
+            highlight_text: palette.highlight_text.color(),
+            text: palette.text.color(),
+            text_dim: palette.text_dim.color(),
+            border: palette.border.color(),
+            accent: palette.accent.color(),
+            gold: palette.gold.color(),
+            danger: palette.danger.color(),
+            success: palette.success.color(),
+            accent_muted: muted(palette.accent),
+            gold_muted: muted(palette.gold),
+            danger_muted: muted(palette.danger),
+        }
+    }
+
+    /// [`Palette::focus_border`] as a terminal color.
+    pub fn focus_border(&self, focused: bool) -> Color {
+        self.palette.focus_border(focused).color()
+    }
+}
+
+/// Makes `palette` the one [`theme`] returns. Only the first call counts;
+/// returns whether this was it.
+pub fn install(palette: Palette) -> bool {
+    INSTALLED.set(Theme::new(palette)).is_ok()
+}
+
+/// The installed theme, or the default palette's if none was installed.
+pub fn theme() -> &'static Theme {
+    INSTALLED.get_or_init(|| Theme::new(Palette::default()))
+}
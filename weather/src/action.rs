@@ -3,7 +3,8 @@
 use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 
-use crate::state::{Location, WeatherData};
+use crate::quota::CallUsage;
+use crate::state::{DailySample, EnsembleBand, HourlySample, Location, WeatherData};
 
 /// Application actions with automatic category inference
 #[derive(tui_dispatch::Action, Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
@@ -19,6 +20,28 @@ pub enum Action {
     /// Result: Weather fetch failed
     WeatherDidError(String),
 
+    /// Result: Hourly forecast for the commute planner loaded
+    HourlyDidLoad(Vec<HourlySample>),
+
+    /// Result: Hourly forecast fetch failed
+    HourlyDidError(String),
+
+    /// Result: Daily archive temperatures for the garden planner loaded
+    ArchiveDidLoad(Vec<DailySample>),
+
+    /// Result: Archive fetch failed
+    ArchiveDidError(String),
+
+    /// Result: Ensemble spread for the forecast spread panel loaded
+    EnsembleDidLoad(Vec<EnsembleBand>),
+
+    /// Result: Ensemble fetch failed
+    EnsembleDidError(String),
+
+    // ===== Quota category =====
+    /// Result: Fresh request counts from the api layer
+    QuotaDidUpdate(CallUsage),
+
     // ===== Search category =====
     /// Open city search overlay
     SearchOpen,
@@ -44,10 +67,44 @@ pub enum Action {
     /// Confirm selection - switch to selected city
     SearchConfirm,
 
+    // ===== Wizard category =====
+    /// Flip the unit on the wizard's units step
+    WizardToggleUnit,
+
+    /// Go on from the wizard's units step
+    WizardNext,
+
+    /// Go back a wizard step
+    WizardBack,
+
+    /// Refresh interval text changed on the wizard's last step
+    WizardRefreshChange(String),
+
+    /// Submit the refresh interval and save the config
+    WizardRefreshSubmit(String),
+
+    /// Result: Config written; the weather view takes over
+    WizardDidSave,
+
+    /// Result: Config could not be written
+    WizardDidError(String),
+
     // ===== UI category =====
     /// Toggle between Celsius and Fahrenheit
     UiToggleUnits,
 
+    /// Cycle the display language
+    UiCycleLanguage,
+
+    /// Show or hide the commute planner panel
+    UiToggleCommute,
+
+    /// Show or hide the garden planner panel
+    UiToggleGarden,
+
+    /// Show or hide the forecast spread panel
+    UiToggleEnsemble,
+
     /// Force a re-render (for cursor movement, etc.)
     Render,
 
@@ -55,6 +112,9 @@ pub enum Action {
     /// Periodic tick for loading animation
     Tick,
 
+    /// Auto-refresh timer; fetches once the quota-stretched interval has passed
+    RefreshTick,
+
     /// Exit the application
     Quit,
 }
@@ -0,0 +1,171 @@
+//! Per-location weather cache for stale-while-revalidate
+//!
+//! Every fetch that lands is kept against its city. Picking a city from
+//! search shows what was last seen there right away, or failing that what a
+//! city close by was doing, while the fresh fetch runs behind it.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::state::{HourlySample, Location, WeatherData};
+
+/// Cities remembered; the least recently fetched one drops out first
+pub const CACHE_CAPACITY: usize = 12;
+
+/// Two results closer than this are the same place (km)
+const SAME_PLACE_KM: f64 = 5.0;
+
+/// A cached city this close stands in for one that has never been fetched (km)
+const NEARBY_KM: f64 = 60.0;
+
+const EARTH_RADIUS_KM: f64 = 6371.0;
+
+/// The last data seen for one city
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct CachedWeather {
+    pub location: Location,
+    pub weather: WeatherData,
+    pub hourly: Option<Vec<HourlySample>>,
+}
+
+/// Where data shown ahead of a fetch came from
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub enum Provisional {
+    /// Last fetch for this city
+    Cached,
+    /// Last fetch for a city nearby, named here
+    Estimated { from: String },
+}
+
+/// Recently fetched cities, most recent first
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct WeatherCache {
+    entries: Vec<CachedWeather>,
+}
+
+impl WeatherCache {
+    /// Remember `weather` for `location`, keeping its hourly data if the
+    /// conditions are a refresh of the same place.
+    pub fn store(&mut self, location: &Location, weather: WeatherData) {
+        let hourly = self
+            .position(location)
+            .and_then(|index| self.entries.remove(index).hourly);
+        self.entries.insert(
+            0,
+            CachedWeather {
+                location: location.clone(),
+                weather,
+                hourly,
+            },
+        );
+        self.entries.truncate(CACHE_CAPACITY);
+    }
+
+    /// Attach hourly samples to the entry for `location`, if it has one
+    pub fn store_hourly(&mut self, location: &Location, hourly: Vec<HourlySample>) {
+        if let Some(index) = self.position(location) {
+            self.entries[index].hourly = Some(hourly);
+        }
+    }
+
+    /// Data to show for `location` until its own fetch lands: its own entry,
+    /// else the closest entry within [`NEARBY_KM`].
+    pub fn lookup(&self, location: &Location) -> Option<(&CachedWeather, Provisional)> {
+        let (entry, km) = self
+            .entries
+            .iter()
+            .map(|entry| (entry, distance_km(&entry.location, location)))
+            .min_by(|a, b| a.1.total_cmp(&b.1))?;
+        if km < SAME_PLACE_KM {
+            Some((entry, Provisional::Cached))
+        } else if km < NEARBY_KM {
+            let from = entry.location.name.clone();
+            Some((entry, Provisional::Estimated { from }))
+        } else {
+            None
+        }
+    }
+
+    fn position(&self, location: &Location) -> Option<usize> {
+        self.entries
+            .iter()
+            .position(|entry| distance_km(&entry.location, location) < SAME_PLACE_KM)
+    }
+}
+
+/// Great-circle distance between two locations
+fn distance_km(a: &Location, b: &Location) -> f64 {
+    let (lat_a, lat_b) = (a.lat.to_radians(), b.lat.to_radians());
+    let d_lat = lat_b - lat_a;
+    let d_lon = (b.lon - a.lon).to_radians();
+    let h = (d_lat / 2.0).sin().powi(2) + lat_a.cos() * lat_b.cos() * (d_lon / 2.0).sin().powi(2);
+    2.0 * EARTH_RADIUS_KM * h.sqrt().asin()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn city(name: &str, lat: f64, lon: f64) -> Location {
+        Location {
+            name: name.into(),
+            lat,
+            lon,
+        }
+    }
+
+    fn conditions(temperature: f32) -> WeatherData {
+        WeatherData {
+            temperature,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_lookup_prefers_the_same_city_then_a_nearby_one() {
+        let mut cache = WeatherCache::default();
+        let berlin = city("Berlin", 52.52, 13.405);
+        let potsdam = city("Potsdam", 52.39, 13.065);
+        let munich = city("Munich", 48.137, 11.575);
+        cache.store(&berlin, conditions(12.0));
+
+        let (entry, provisional) = cache.lookup(&berlin).unwrap();
+        assert_eq!(entry.weather.temperature, 12.0);
+        assert_eq!(provisional, Provisional::Cached);
+
+        let (_, provisional) = cache.lookup(&potsdam).unwrap();
+        assert_eq!(
+            provisional,
+            Provisional::Estimated {
+                from: "Berlin".into()
+            }
+        );
+        assert!(cache.lookup(&munich).is_none());
+    }
+
+    #[test]
+    fn test_store_refreshes_in_place_and_keeps_hourly() {
+        let mut cache = WeatherCache::default();
+        let kyiv = city("Kyiv", 50.45, 30.52);
+        cache.store(&kyiv, conditions(3.0));
+        cache.store_hourly(&kyiv, vec![HourlySample::default()]);
+        cache.store(&kyiv, conditions(4.0));
+
+        assert_eq!(cache.entries.len(), 1);
+        let (entry, _) = cache.lookup(&kyiv).unwrap();
+        assert_eq!(entry.weather.temperature, 4.0);
+        assert_eq!(entry.hourly.as_ref().map(Vec::len), Some(1));
+    }
+
+    #[test]
+    fn test_oldest_city_drops_out_at_capacity() {
+        let mut cache = WeatherCache::default();
+        let first = city("First", 0.0, 0.0);
+        cache.store(&first, conditions(0.0));
+        for i in 1..=CACHE_CAPACITY {
+            cache.store(&city("Other", 0.0, i as f64 * 10.0), conditions(0.0));
+        }
+        assert_eq!(cache.entries.len(), CACHE_CAPACITY);
+        assert!(cache.lookup(&first).is_none());
+    }
+}
@@ -0,0 +1,303 @@
+//! Commute planner: slice the hourly forecast into two daily time windows
+//!
+//! Each window gets one summary per forecast day with a simple verdict, so the
+//! panel can answer "how will the ride in and the ride home look this week?".
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::state::HourlySample;
+
+/// Days of forecast shown in the planner (including today)
+pub const PLANNER_DAYS: usize = 5;
+
+/// A daily time window in local hours, `start` inclusive and `end` exclusive.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct TimeWindow {
+    pub start: u8,
+    pub end: u8,
+}
+
+impl TimeWindow {
+    pub fn contains(&self, hour: u8) -> bool {
+        (self.start..self.end).contains(&hour)
+    }
+
+    /// `08–09` style label
+    pub fn label(&self) -> String {
+        format!("{:02}–{:02}", self.start, self.end)
+    }
+}
+
+/// Parser for `--morning` / `--evening`, e.g. `8-9` or `17-18`
+pub fn parse_window(value: &str) -> Result<TimeWindow, String> {
+    let (start, end) = value
+        .split_once('-')
+        .ok_or_else(|| format!("expected START-END hours, got '{value}'"))?;
+    let parse_hour = |text: &str| {
+        text.trim()
+            .trim_end_matches(":00")
+            .parse::<u8>()
+            .map_err(|_| format!("invalid hour '{}'", text.trim()))
+    };
+    let window = TimeWindow {
+        start: parse_hour(start)?,
+        end: parse_hour(end)?,
+    };
+    if window.start >= window.end || window.end > 24 {
+        return Err(format!(
+            "window '{value}' must satisfy 0 <= start < end <= 24"
+        ));
+    }
+    Ok(window)
+}
+
+/// The two windows compared by the planner
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct CommuteConfig {
+    pub morning: TimeWindow,
+    pub evening: TimeWindow,
+}
+
+impl Default for CommuteConfig {
+    fn default() -> Self {
+        Self {
+            morning: TimeWindow { start: 8, end: 9 },
+            evening: TimeWindow { start: 17, end: 18 },
+        }
+    }
+}
+
+/// Overall call for a window on a given day
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Verdict {
+    Good,
+    Fair,
+    Poor,
+}
+
+/// Conditions within one window on one day
+#[derive(Clone, Debug, PartialEq)]
+pub struct WindowSummary {
+    /// Local date, `YYYY-MM-DD`
+    pub date: String,
+    pub temp_min: f32,
+    pub temp_max: f32,
+    pub precip_max: u8,
+    pub wind_max: f32,
+    /// Most severe weather code seen in the window
+    pub weather_code: u8,
+    pub verdict: Verdict,
+}
+
+/// Rough badness ranking of WMO codes for commuting
+fn code_severity(code: u8) -> u8 {
+    match code {
+        95..=99 => 4,
+        56 | 57 | 66 | 67 | 71..=77 | 85 | 86 => 3,
+        61..=65 | 80..=82 => 2,
+        45 | 48 | 51..=55 => 1,
+        _ => 0,
+    }
+}
+
+fn verdict(summary: &WindowSummary) -> Verdict {
+    let severity = code_severity(summary.weather_code);
+    if severity >= 3
+        || summary.precip_max >= 60
+        || summary.wind_max >= 40.0
+        || summary.temp_min <= -10.0
+    {
+        Verdict::Poor
+    } else if severity >= 1
+        || summary.precip_max >= 30
+        || summary.wind_max >= 25.0
+        || summary.temp_min < 0.0
+        || summary.temp_max > 32.0
+    {
+        Verdict::Fair
+    } else {
+        Verdict::Good
+    }
+}
+
+/// Split an `YYYY-MM-DDTHH:MM` timestamp into date and hour.
+fn split_time(time: &str) -> Option<(&str, u8)> {
+    let (date, clock) = time.split_once('T')?;
+    let hour = clock.get(..2)?.parse().ok()?;
+    Some((date, hour))
+}
+
+/// Summarize `window` for each of the first `days` dates in `hourly`.
+///
+/// Days where the forecast has no samples inside the window are skipped.
+pub fn slice_window(
+    hourly: &[HourlySample],
+    window: TimeWindow,
+    days: usize,
+) -> Vec<WindowSummary> {
+    let mut summaries: Vec<WindowSummary> = Vec::new();
+    let mut dates_seen = 0;
+    let mut last_date = "";
+    for sample in hourly {
+        let Some((date, hour)) = split_time(&sample.time) else {
+            continue;
+        };
+        if date != last_date {
+            last_date = date;
+            dates_seen += 1;
+            if dates_seen > days {
+                break;
+            }
+        }
+        if !window.contains(hour) {
+            continue;
+        }
+        match summaries.last_mut() {
+            Some(summary) if summary.date == date => {
+                summary.temp_min = summary.temp_min.min(sample.temperature);
+                summary.temp_max = summary.temp_max.max(sample.temperature);
+                summary.precip_max = summary.precip_max.max(sample.precipitation_probability);
+                summary.wind_max = summary.wind_max.max(sample.wind_speed);
+                if code_severity(sample.weather_code) > code_severity(summary.weather_code) {
+                    summary.weather_code = sample.weather_code;
+                }
+            }
+            _ => summaries.push(WindowSummary {
+                date: date.to_string(),
+                temp_min: sample.temperature,
+                temp_max: sample.temperature,
+                precip_max: sample.precipitation_probability,
+                wind_max: sample.wind_speed,
+                weather_code: sample.weather_code,
+                verdict: Verdict::Good,
+            }),
+        }
+    }
+    for summary in &mut summaries {
+        summary.verdict = verdict(summary);
+    }
+    summaries
+}
+
+/// Day of week for a `YYYY-MM-DD` date, 0 = Sunday.
+pub fn weekday(date: &str) -> Option<usize> {
+    let mut parts = date.splitn(3, '-').map(|part| part.parse::<i32>().ok());
+    let (mut year, month, day) = (parts.next()??, parts.next()??, parts.next()??);
+    if !(1..=12).contains(&month) {
+        return None;
+    }
+    // Sakamoto's method
+    const OFFSETS: [i32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+    if month < 3 {
+        year -= 1;
+    }
+    let index = (year + year / 4 - year / 100 + year / 400 + OFFSETS[month as usize - 1] + day)
+        .rem_euclid(7);
+    Some(index as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sample(time: &str, temperature: f32, precip: u8, code: u8, wind: f32) -> HourlySample {
+        HourlySample {
+            time: time.into(),
+            temperature,
+            precipitation_probability: precip,
+            weather_code: code,
+            wind_speed: wind,
+            pressure: None,
+        }
+    }
+
+    #[test]
+    fn test_parse_window() {
+        assert_eq!(parse_window("8-9"), Ok(TimeWindow { start: 8, end: 9 }));
+        assert_eq!(
+            parse_window("17:00-18:00"),
+            Ok(TimeWindow { start: 17, end: 18 })
+        );
+        assert!(parse_window("17:00-18:30").is_err(), "only whole hours");
+        assert_eq!(
+            parse_window(" 7 - 10 "),
+            Ok(TimeWindow { start: 7, end: 10 })
+        );
+        assert!(parse_window("9-8").is_err());
+        assert!(parse_window("22-25").is_err());
+        assert!(parse_window("morning").is_err());
+    }
+
+    #[test]
+    fn test_slice_window_groups_by_day() {
+        let hourly = vec![
+            sample("2024-06-03T07:00", 10.0, 0, 0, 5.0),
+            sample("2024-06-03T08:00", 12.0, 10, 1, 8.0),
+            sample("2024-06-03T09:00", 14.0, 20, 2, 9.0),
+            sample("2024-06-03T10:00", 16.0, 90, 95, 50.0),
+            sample("2024-06-04T08:00", 11.0, 70, 63, 12.0),
+            sample("2024-06-04T09:00", 13.0, 40, 3, 14.0),
+        ];
+        let window = TimeWindow { start: 8, end: 10 };
+        let summaries = slice_window(&hourly, window, 5);
+
+        assert_eq!(summaries.len(), 2);
+        let first = &summaries[0];
+        assert_eq!(first.date, "2024-06-03");
+        assert_eq!((first.temp_min, first.temp_max), (12.0, 14.0));
+        assert_eq!(first.precip_max, 20);
+        assert_eq!(first.weather_code, 1);
+        assert_eq!(first.verdict, Verdict::Good);
+
+        let second = &summaries[1];
+        assert_eq!(second.weather_code, 63);
+        assert_eq!(second.verdict, Verdict::Poor);
+    }
+
+    #[test]
+    fn test_slice_window_limits_days() {
+        let hourly: Vec<_> = (1..=7)
+            .map(|day| sample(&format!("2024-06-{day:02}T08:00"), 15.0, 0, 0, 5.0))
+            .collect();
+        let summaries = slice_window(&hourly, TimeWindow { start: 8, end: 9 }, 5);
+        assert_eq!(summaries.len(), 5);
+        assert_eq!(summaries[4].date, "2024-06-05");
+    }
+
+    #[test]
+    fn test_slice_window_skips_days_without_samples() {
+        let hourly = vec![
+            sample("2024-06-03T18:00", 15.0, 0, 0, 5.0),
+            sample("2024-06-04T08:00", 15.0, 0, 0, 5.0),
+        ];
+        let summaries = slice_window(&hourly, TimeWindow { start: 8, end: 9 }, 5);
+        assert_eq!(summaries.len(), 1);
+        assert_eq!(summaries[0].date, "2024-06-04");
+    }
+
+    #[test]
+    fn test_verdict_thresholds() {
+        let fair = slice_window(
+            &[sample("2024-06-03T08:00", 5.0, 35, 2, 5.0)],
+            TimeWindow { start: 8, end: 9 },
+            1,
+        );
+        assert_eq!(fair[0].verdict, Verdict::Fair);
+        let windy = slice_window(
+            &[sample("2024-06-03T08:00", 5.0, 0, 0, 45.0)],
+            TimeWindow { start: 8, end: 9 },
+            1,
+        );
+        assert_eq!(windy[0].verdict, Verdict::Poor);
+    }
+
+    #[test]
+    fn test_weekday() {
+        assert_eq!(weekday("2024-06-03"), Some(1)); // Monday
+        assert_eq!(weekday("2000-01-01"), Some(6)); // Saturday
+        assert_eq!(weekday("2024-02-29"), Some(4)); // Thursday
+        assert_eq!(weekday("2024-13-01"), None);
+        assert_eq!(weekday("today"), None);
+    }
+}
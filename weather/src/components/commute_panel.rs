@@ -0,0 +1,151 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span, Text as TextBlock},
+    widgets::{Cell, Row, Table},
+};
+use tui_theme::theme;
+
+use super::{Component, resource_panel};
+use crate::action::Action;
+use crate::commute::{self, PLANNER_DAYS, TimeWindow, Verdict, WindowSummary};
+use crate::i18n::{self, Language, Text};
+use crate::state::{AppState, HourlySample};
+
+/// Side-by-side forecast for the morning and evening commute windows.
+pub struct CommutePanel;
+
+pub struct CommutePanelProps<'a> {
+    pub state: &'a AppState,
+    pub accent: Color,
+}
+
+const DAY_COLUMN_WIDTH: u16 = 12;
+
+impl Component<Action> for CommutePanel {
+    type Props<'a> = CommutePanelProps<'a>;
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        let state = props.state;
+        resource_panel(
+            frame,
+            area,
+            state,
+            Text::Commute,
+            props.accent,
+            &state.hourly,
+            |frame, inner, hourly| render_table(frame, inner, state, hourly),
+        );
+    }
+}
+
+fn render_table(frame: &mut Frame, area: Rect, state: &AppState, hourly: &[HourlySample]) {
+    let lang = state.language;
+    let windows = [
+        (Text::Morning, state.commute.morning),
+        (Text::Evening, state.commute.evening),
+    ];
+    let summaries: Vec<Vec<WindowSummary>> = windows
+        .iter()
+        .map(|&(_, window)| commute::slice_window(hourly, window, PLANNER_DAYS))
+        .collect();
+
+    let header = Row::new(
+        std::iter::once(Cell::from("")).chain(
+            windows
+                .iter()
+                .map(|&(label, window)| window_heading(label, window, lang)),
+        ),
+    )
+    .style(Style::default().fg(theme().text_dim).bold())
+    .bottom_margin(1);
+
+    let mut dates: Vec<&str> = summaries
+        .iter()
+        .flatten()
+        .map(|summary| summary.date.as_str())
+        .collect();
+    dates.sort_unstable();
+    dates.dedup();
+
+    let rows = dates.into_iter().map(|date| {
+        let day = Cell::from(day_label(date, lang)).style(Style::default().fg(theme().text_dim));
+        let cells = summaries.iter().map(|days| {
+            days.iter()
+                .find(|summary| summary.date == date)
+                .map(|summary| summary_cell(summary, state))
+                .unwrap_or_else(|| {
+                    Cell::from(Span::styled("—", Style::default().fg(Color::DarkGray)))
+                })
+        });
+        Row::new(std::iter::once(day).chain(cells))
+            .height(2)
+            .bottom_margin(1)
+    });
+
+    let table = Table::new(
+        rows,
+        [
+            Constraint::Length(DAY_COLUMN_WIDTH),
+            Constraint::Fill(1),
+            Constraint::Fill(1),
+        ],
+    )
+    .header(header)
+    .column_spacing(2);
+    frame.render_widget(table, area);
+}
+
+fn window_heading(label: Text, window: TimeWindow, lang: Language) -> Cell<'static> {
+    Cell::from(format!("{} {}", i18n::text(label, lang), window.label()))
+}
+
+/// `Mon 06-03` style label; falls back to the raw date if it doesn't parse.
+fn day_label(date: &str, lang: Language) -> String {
+    match commute::weekday(date) {
+        Some(index) => format!(
+            "{} {}",
+            i18n::weekday(index, lang),
+            date.get(5..).unwrap_or(date)
+        ),
+        None => date.to_string(),
+    }
+}
+
+/// Two-line cell: verdict and temperature range, then rain chance and wind.
+fn summary_cell(summary: &WindowSummary, state: &AppState) -> Cell<'static> {
+    let (verdict, color) = match summary.verdict {
+        Verdict::Good => (Text::VerdictGood, theme().success),
+        Verdict::Fair => (Text::VerdictFair, theme().gold),
+        Verdict::Poor => (Text::VerdictPoor, theme().danger),
+    };
+    let temps = if summary.temp_min == summary.temp_max {
+        state.unit.format(summary.temp_min)
+    } else {
+        format!(
+            "{} … {}",
+            state.unit.format(summary.temp_min),
+            state.unit.format(summary.temp_max)
+        )
+    };
+    Cell::from(TextBlock::from(vec![
+        Line::from(vec![
+            Span::styled(
+                format!("{} ", i18n::text(verdict, state.language)),
+                Style::default().fg(color).bold(),
+            ),
+            Span::raw(temps),
+        ]),
+        Line::from(vec![
+            Span::styled(
+                format!("☂ {}%", summary.precip_max),
+                Style::default().fg(Color::Rgb(120, 170, 255)),
+            ),
+            Span::styled(
+                format!("  ≋ {:.0} km/h", summary.wind_max),
+                Style::default().fg(Color::DarkGray),
+            ),
+        ]),
+    ]))
+}
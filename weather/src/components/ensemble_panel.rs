@@ -0,0 +1,258 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+use tui_theme::theme;
+
+use super::{Component, centered_row, resource_panel};
+use crate::action::Action;
+use crate::commute;
+use crate::ensemble::{self, Confidence};
+use crate::i18n::{self, Text};
+use crate::state::{AppState, EnsembleBand, TempUnit};
+
+/// Temperature chart with the min–max range across ensemble members drawn as
+/// a band behind the median.
+pub struct EnsemblePanel;
+
+pub struct EnsemblePanelProps<'a> {
+    pub state: &'a AppState,
+    pub accent: Color,
+}
+
+/// Width of the temperature labels left of the plot, gap included.
+const AXIS_WIDTH: u16 = 6;
+
+/// Smallest temperature span (°C) the y axis covers, so a flat forecast
+/// doesn't blow small differences up to the full chart height.
+const MIN_AXIS_SPAN: f32 = 4.0;
+
+/// How far the band color is pulled toward the background.
+const BAND_DIM: f32 = 0.65;
+
+const BAND_GLYPH: &str = "█";
+const MEDIAN_GLYPH: &str = "━";
+
+impl Component<Action> for EnsemblePanel {
+    type Props<'a> = EnsemblePanelProps<'a>;
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        let state = props.state;
+        resource_panel(
+            frame,
+            area,
+            state,
+            Text::Spread,
+            props.accent,
+            &state.ensemble,
+            |frame, inner, bands| render_chart(frame, inner, state, props.accent, bands),
+        );
+    }
+}
+
+fn render_chart(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    accent: Color,
+    bands: &[EnsembleBand],
+) {
+    let (Some(confidence), Some(spread), Some((low, high))) = (
+        ensemble::confidence(bands),
+        ensemble::mean_spread(bands),
+        ensemble::range(bands),
+    ) else {
+        frame.render_widget(
+            Paragraph::new(Line::from("—").centered()).fg(Color::DarkGray),
+            centered_row(area),
+        );
+        return;
+    };
+
+    let [summary_area, _, chart_area, label_area] = Layout::vertical([
+        Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Min(3),
+        Constraint::Length(1),
+    ])
+    .areas(area);
+    frame.render_widget(
+        Paragraph::new(summary_line(state, confidence, spread)),
+        summary_area,
+    );
+
+    let [axis_area, plot_area] =
+        Layout::horizontal([Constraint::Length(AXIS_WIDTH), Constraint::Fill(1)]).areas(chart_area);
+    if plot_area.width == 0 || plot_area.height == 0 {
+        return;
+    }
+    let columns = resample(bands, plot_area.width as usize);
+    let (low, high) = pad_span(low, high);
+    let last_row = plot_area.height - 1;
+    let row_of = |celsius: f32| {
+        let t = (high - celsius) / (high - low);
+        (t * last_row as f32).round().clamp(0.0, last_row as f32) as u16
+    };
+
+    let band_color = state
+        .accent
+        .current()
+        .lerp(theme().palette.bg, BAND_DIM)
+        .color();
+    let buffer = frame.buffer_mut();
+    for (x, column) in (plot_area.x..).zip(&columns) {
+        for row in row_of(column.max)..=row_of(column.min) {
+            buffer[(x, plot_area.y + row)]
+                .set_symbol(BAND_GLYPH)
+                .set_fg(band_color);
+        }
+        buffer[(x, plot_area.y + row_of(column.median))]
+            .set_symbol(MEDIAN_GLYPH)
+            .set_fg(accent)
+            .set_bg(band_color);
+    }
+
+    for (row, celsius) in [
+        (0, high),
+        (last_row / 2, (high + low) / 2.0),
+        (last_row, low),
+    ] {
+        let label = format!(
+            "{:>width$.0}°",
+            state.unit.convert(celsius),
+            width = AXIS_WIDTH as usize - 2
+        );
+        buffer.set_string(
+            axis_area.x,
+            axis_area.y + row,
+            label,
+            Style::default().fg(Color::DarkGray),
+        );
+    }
+
+    // Weekday under the first column of each day, skipped if it would overlap
+    let mut free_from = label_area.x;
+    let mut previous_date = "";
+    for (x, column) in (label_area.x + AXIS_WIDTH..).zip(&columns) {
+        let date = column.time.get(..10).unwrap_or(&column.time);
+        if date == previous_date {
+            continue;
+        }
+        previous_date = date;
+        let Some(index) = commute::weekday(date) else {
+            continue;
+        };
+        let label = i18n::weekday(index, state.language);
+        let width = label.chars().count() as u16;
+        if x < free_from || x + width > label_area.right() {
+            continue;
+        }
+        buffer.set_string(
+            x,
+            label_area.y,
+            label,
+            Style::default().fg(theme().text_dim),
+        );
+        free_from = x + width + 1;
+    }
+}
+
+fn summary_line(state: &AppState, confidence: Confidence, spread: f32) -> Line<'static> {
+    let lang = state.language;
+    let (level, color) = match confidence {
+        Confidence::High => (Text::ConfidenceHigh, theme().success),
+        Confidence::Medium => (Text::ConfidenceMedium, theme().gold),
+        Confidence::Low => (Text::ConfidenceLow, theme().danger),
+    };
+    let spread = match state.unit {
+        TempUnit::Celsius => format!("{spread:.1}°C"),
+        TempUnit::Fahrenheit => format!("{:.1}°F", spread * 9.0 / 5.0),
+    };
+    Line::from(vec![
+        Span::styled(
+            format!("{}: ", i18n::text(Text::Confidence, lang)),
+            Style::default().fg(theme().text_dim),
+        ),
+        Span::styled(i18n::text(level, lang), Style::default().fg(color).bold()),
+        Span::styled(
+            format!(
+                " · {}",
+                i18n::text(Text::MeanSpread, lang).replace("{n}", &spread)
+            ),
+            Style::default().fg(Color::DarkGray),
+        ),
+    ])
+}
+
+/// Squeeze the hours into at most `width` columns: each column keeps the
+/// widest range of the hours it covers and the mean of their medians.
+fn resample(bands: &[EnsembleBand], width: usize) -> Vec<EnsembleBand> {
+    let hours_per_column = bands.len().div_ceil(width.max(1)).max(1);
+    bands
+        .chunks(hours_per_column)
+        .map(|hours| EnsembleBand {
+            time: hours[0].time.clone(),
+            min: hours
+                .iter()
+                .map(|hour| hour.min)
+                .fold(f32::INFINITY, f32::min),
+            median: hours.iter().map(|hour| hour.median).sum::<f32>() / hours.len() as f32,
+            max: hours
+                .iter()
+                .map(|hour| hour.max)
+                .fold(f32::NEG_INFINITY, f32::max),
+        })
+        .collect()
+}
+
+/// Widen a narrow temperature range symmetrically to `MIN_AXIS_SPAN`.
+fn pad_span(low: f32, high: f32) -> (f32, f32) {
+    let missing = MIN_AXIS_SPAN - (high - low);
+    if missing > 0.0 {
+        (low - missing / 2.0, high + missing / 2.0)
+    } else {
+        (low, high)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hour(time: &str, min: f32, median: f32, max: f32) -> EnsembleBand {
+        EnsembleBand {
+            time: time.into(),
+            min,
+            median,
+            max,
+        }
+    }
+
+    #[test]
+    fn test_resample_keeps_widest_range() {
+        let bands = vec![
+            hour("2024-06-03T00:00", 10.0, 11.0, 12.0),
+            hour("2024-06-03T01:00", 9.0, 10.0, 13.0),
+            hour("2024-06-03T02:00", 8.0, 9.0, 10.0),
+        ];
+
+        let columns = resample(&bands, 2);
+        assert_eq!(
+            columns,
+            vec![
+                hour("2024-06-03T00:00", 9.0, 10.5, 13.0),
+                hour("2024-06-03T02:00", 8.0, 9.0, 10.0),
+            ]
+        );
+        assert_eq!(resample(&bands, 10).len(), 3);
+    }
+
+    #[test]
+    fn test_pad_span_widens_flat_forecasts() {
+        assert_eq!(pad_span(10.0, 11.0), (8.5, 12.5));
+        assert_eq!(pad_span(0.0, 20.0), (0.0, 20.0));
+    }
+}
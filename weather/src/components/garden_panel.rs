@@ -0,0 +1,126 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Cell, Paragraph, Row, Table},
+};
+use tui_theme::theme;
+
+use super::{Component, centered_row, resource_panel};
+use crate::action::Action;
+use crate::garden::{self, GardenSummary};
+use crate::i18n::{self, Text};
+use crate::state::{AppState, DailySample, TempUnit};
+
+/// Growing degree days this season and typical frost dates for the location.
+pub struct GardenPanel;
+
+pub struct GardenPanelProps<'a> {
+    pub state: &'a AppState,
+    pub accent: Color,
+}
+
+const LABEL_COLUMN_WIDTH: u16 = 28;
+
+impl Component<Action> for GardenPanel {
+    type Props<'a> = GardenPanelProps<'a>;
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        let state = props.state;
+        resource_panel(
+            frame,
+            area,
+            state,
+            Text::Garden,
+            props.accent,
+            &state.archive,
+            |frame, inner, days| render_summary(frame, inner, state, days),
+        );
+    }
+}
+
+fn render_summary(frame: &mut Frame, area: Rect, state: &AppState, days: &[DailySample]) {
+    let Some(summary) = garden::summarize(days, state.garden, state.southern_hemisphere()) else {
+        frame.render_widget(
+            Paragraph::new(Line::from("—").centered()).fg(Color::DarkGray),
+            centered_row(area),
+        );
+        return;
+    };
+    let lang = state.language;
+    let label =
+        |key: Text| Cell::from(i18n::text(key, lang)).style(Style::default().fg(theme().text_dim));
+    let note = |text: String| {
+        Row::new([Cell::from(""), Cell::from(text)]).style(Style::default().fg(Color::DarkGray))
+    };
+
+    let none = || {
+        Cell::from(Span::styled(
+            i18n::text(Text::NoFrost, lang),
+            Style::default().fg(Color::DarkGray),
+        ))
+    };
+    let frost_cell = |day: Option<u16>| match day {
+        Some(day) => Cell::from(Span::styled(
+            format!("~ {}", garden::month_day(day)),
+            Style::default().fg(Color::Rgb(150, 200, 255)).bold(),
+        )),
+        None => none(),
+    };
+    let frost_free = match summary.frost.frost_free_days() {
+        Some(days) => Cell::from(format!("{days} {}", i18n::text(Text::Days, lang))),
+        None => none(),
+    };
+
+    let rows = vec![
+        Row::new([
+            label(Text::DegreeDays),
+            degree_days_cell(&summary, state.unit),
+        ]),
+        note(format!(
+            "{} {} · {} {} → {}",
+            i18n::text(Text::Base, lang),
+            state.unit.format(state.garden.base),
+            i18n::text(Text::Since, lang),
+            summary
+                .season_start
+                .get(5..)
+                .unwrap_or(&summary.season_start),
+            summary.through.get(5..).unwrap_or(&summary.through),
+        ))
+        .bottom_margin(1),
+        Row::new([
+            label(Text::LastFrost),
+            frost_cell(summary.frost.last_spring),
+        ]),
+        Row::new([
+            label(Text::FirstFrost),
+            frost_cell(summary.frost.first_autumn),
+        ]),
+        Row::new([label(Text::FrostFree), frost_free]),
+        note(i18n::text(Text::FrostYears, lang).replace("{n}", &summary.frost.years.to_string())),
+    ];
+
+    let table = Table::new(
+        rows,
+        [Constraint::Length(LABEL_COLUMN_WIDTH), Constraint::Fill(1)],
+    )
+    .column_spacing(2);
+    frame.render_widget(table, area);
+}
+
+/// Accumulated degree days in the display unit (°F degree days are 1.8× larger).
+fn degree_days_cell(summary: &GardenSummary, unit: TempUnit) -> Cell<'static> {
+    let (value, suffix) = match unit {
+        TempUnit::Celsius => (summary.degree_days, "°C·d"),
+        TempUnit::Fahrenheit => (summary.degree_days * 9.0 / 5.0, "°F·d"),
+    };
+    Cell::from(Line::from(vec![
+        Span::styled(
+            format!("{value:.0}"),
+            Style::default().fg(theme().success).bold(),
+        ),
+        Span::styled(format!(" {suffix}"), Style::default().fg(theme().text_dim)),
+    ]))
+}
@@ -0,0 +1,102 @@
+use ratatui::{
+    Frame,
+    buffer::Buffer,
+    layout::{Alignment, Rect},
+    style::{Color, Style},
+    widgets::{Block, BorderType, Borders, Widget},
+};
+use tui_theme::theme;
+
+use super::Component;
+use crate::action::Action;
+use crate::state::LOADING_ANIM_CYCLE_TICKS;
+
+/// Placeholder that sketches where content will appear while weather is loading.
+///
+/// Areas taller than two rows get a rounded panel outline; shorter areas become a
+/// single bar. A highlight band sweeps left to right once per animation cycle.
+pub struct LoadingSkeleton;
+
+pub struct LoadingSkeletonProps {
+    /// Animation frame counter from state
+    pub tick_count: u32,
+    /// Width of the sketched block, centered in the area
+    pub width: u16,
+    /// Optional title drawn into the panel outline
+    pub label: Option<&'static str>,
+}
+
+const SKELETON_BASE: (u8, u8, u8) = (45, 45, 55);
+const SKELETON_SHINE: (u8, u8, u8) = (95, 95, 110);
+/// Half-width of the shimmer band, in cells
+const SHIMMER_RADIUS: f32 = 6.0;
+
+/// How far into the sweeping highlight band a column is, 0 outside it to 1
+/// at its center.
+pub(super) fn shimmer_strength(column: u16, width: u16, tick_count: u32) -> f32 {
+    let cycle = LOADING_ANIM_CYCLE_TICKS.max(1);
+    let phase = (tick_count % cycle) as f32 / cycle as f32;
+    // Start and end off-screen so the band fully enters and leaves
+    let span = width as f32 + SHIMMER_RADIUS * 2.0;
+    let center = phase * span - SHIMMER_RADIUS;
+    let distance = (column as f32 - center).abs();
+    (1.0 - distance / SHIMMER_RADIUS).clamp(0.0, 1.0)
+}
+
+fn shimmer_color(column: u16, width: u16, tick_count: u32) -> Color {
+    let t = shimmer_strength(column, width, tick_count);
+    let mix = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+    Color::Rgb(
+        mix(SKELETON_BASE.0, SKELETON_SHINE.0),
+        mix(SKELETON_BASE.1, SKELETON_SHINE.1),
+        mix(SKELETON_BASE.2, SKELETON_SHINE.2),
+    )
+}
+
+fn fill_shimmer(buf: &mut Buffer, area: Rect, tick_count: u32) {
+    for y in area.top()..area.bottom() {
+        for x in area.left()..area.right() {
+            let color = shimmer_color(x - area.x, area.width, tick_count);
+            if let Some(cell) = buf.cell_mut((x, y)) {
+                cell.set_symbol(" ").set_bg(color);
+            }
+        }
+    }
+}
+
+impl Component<Action> for LoadingSkeleton {
+    type Props<'a> = LoadingSkeletonProps;
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        if area.width == 0 || area.height == 0 {
+            return;
+        }
+
+        let width = props.width.min(area.width);
+        let block_area = Rect {
+            x: area.x + (area.width - width) / 2,
+            width,
+            ..area
+        };
+
+        let inner = if block_area.height > 2 {
+            let mut block = Block::default()
+                .borders(Borders::ALL)
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(theme().border));
+            if let Some(label) = props.label {
+                block = block
+                    .title(label)
+                    .title_alignment(Alignment::Center)
+                    .title_style(Style::default().fg(Color::DarkGray));
+            }
+            let inner = block.inner(block_area);
+            block.render(block_area, frame.buffer_mut());
+            inner
+        } else {
+            block_area
+        };
+
+        fill_shimmer(frame.buffer_mut(), inner, props.tick_count);
+    }
+}
@@ -10,9 +10,11 @@ use ratatui::{
     widgets::Paragraph,
 };
 use std::cmp::Ordering;
+use tui_theme::theme;
 
 use super::Component;
 use crate::action::Action;
+use crate::quota::CallUsage;
 use crate::state::{LOADING_ANIM_CYCLE_TICKS, Location};
 
 pub struct LocationHeader;
@@ -22,6 +24,10 @@ pub struct LocationHeaderProps<'a> {
     pub temperature: Option<f32>,
     pub is_animating: bool,
     pub tick_count: u32,
+    pub api_usage: CallUsage,
+    pub daily_limit: u32,
+    /// Color of the separator dot on the coordinates line
+    pub accent: Color,
 }
 
 /// Overhead inside the header area: 1 spacer + 1 coords line.
@@ -106,6 +112,21 @@ fn sample_color(stops: &[(f32, ArtColor)], position: f32) -> ArtColor {
     stops.last().unwrap().1
 }
 
+/// API calls in the last hour and day, with the refresh slowdown once it kicks in.
+fn quota_span(usage: CallUsage, daily_limit: u32) -> Span<'static> {
+    let counts = format!("⇅ {}/h {}/d", usage.last_hour, usage.last_day);
+    let stretch = usage.stretch(daily_limit);
+    if stretch == 1 {
+        return Span::styled(counts, Style::default().fg(Color::DarkGray));
+    }
+    let color = if usage.pressure(daily_limit) >= 1.0 {
+        theme().danger
+    } else {
+        theme().gold
+    };
+    Span::styled(format!("{counts} ×{stretch}"), Style::default().fg(color))
+}
+
 impl Component<Action> for LocationHeader {
     type Props<'a> = LocationHeaderProps<'a>;
 
@@ -134,10 +155,14 @@ impl Component<Action> for LocationHeader {
         let city_widget = ArtBox::new(&renderer, &props.location.name);
         frame.render_widget(city_widget, chunks[0]);
 
-        let coords_line = Line::from(vec![Span::styled(
-            format!("{:.2}°N, {:.2}°E", props.location.lat, props.location.lon),
-            Style::default().fg(Color::DarkGray),
-        )])
+        let coords_line = Line::from(vec![
+            Span::styled(
+                format!("{:.2}°N, {:.2}°E", props.location.lat, props.location.lon),
+                Style::default().fg(Color::DarkGray),
+            ),
+            Span::styled("  ·  ", Style::default().fg(props.accent)),
+            quota_span(props.api_usage, props.daily_limit),
+        ])
         .centered();
         frame.render_widget(Paragraph::new(coords_line), chunks[2]);
     }
@@ -1,12 +1,101 @@
+pub mod commute_panel;
+pub mod ensemble_panel;
+pub mod garden_panel;
+pub mod loading_skeleton;
 pub mod location_header;
+pub mod particle_backdrop;
+pub mod precip_strip;
+pub mod pressure_badge;
 pub mod search_overlay;
+pub mod setup_wizard;
 pub mod weather_body;
 pub mod weather_display;
 
 // Re-export core Component trait
 pub use tui_dispatch::Component;
 
+pub use commute_panel::{CommutePanel, CommutePanelProps};
+pub use ensemble_panel::{EnsemblePanel, EnsemblePanelProps};
+pub use garden_panel::{GardenPanel, GardenPanelProps};
+pub use loading_skeleton::{LoadingSkeleton, LoadingSkeletonProps};
 pub use location_header::{LocationHeader, LocationHeaderProps};
+pub use particle_backdrop::{ParticleBackdrop, ParticleBackdropProps};
+pub use precip_strip::{PrecipStrip, PrecipStripProps};
+pub use pressure_badge::{PressureBadge, PressureBadgeProps};
 pub use search_overlay::{SearchOverlay, SearchOverlayProps};
+pub use setup_wizard::{SetupWizard, SetupWizardProps};
 pub use weather_body::{WeatherBody, WeatherBodyProps};
 pub use weather_display::{ERROR_ICON, WeatherDisplay, WeatherDisplayProps};
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Flex, Layout, Rect},
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, BorderType, Borders, Padding, Paragraph},
+};
+use tui_dispatch::DataResource;
+use tui_theme::theme;
+
+use crate::i18n::{self, Text};
+use crate::state::AppState;
+
+/// Rounded panel titled `title · city` around data that loads on its own.
+/// `body` draws the loaded data into the inner area; until then the panel
+/// shows the error or a loading skeleton on its middle row.
+fn resource_panel<T>(
+    frame: &mut Frame,
+    area: Rect,
+    state: &AppState,
+    title: Text,
+    accent: Color,
+    resource: &DataResource<T>,
+    body: impl FnOnce(&mut Frame, Rect, &T),
+) {
+    let title = format!(
+        " {} · {} ",
+        i18n::text(title, state.language),
+        state.current_location().name
+    );
+    let block = Block::default()
+        .borders(Borders::ALL)
+        .border_type(BorderType::Rounded)
+        .border_style(Style::default().fg(theme().border))
+        .padding(Padding::horizontal(1))
+        .title(Span::styled(title, Style::default().fg(accent).bold()));
+    let inner = block.inner(area);
+    frame.render_widget(block, area);
+
+    match resource {
+        DataResource::Loaded(data) => body(frame, inner, data),
+        DataResource::Failed(error) => {
+            let message = Line::from(vec![
+                Span::styled(
+                    format!("{}: ", i18n::text(Text::Error, state.language)),
+                    Style::default().fg(theme().danger).bold(),
+                ),
+                Span::styled(error.as_str(), Style::default().fg(theme().danger_muted)),
+            ]);
+            frame.render_widget(Paragraph::new(message.centered()), centered_row(inner));
+        }
+        DataResource::Loading | DataResource::Empty => {
+            let mut skeleton = LoadingSkeleton;
+            skeleton.render(
+                frame,
+                centered_row(inner),
+                LoadingSkeletonProps {
+                    tick_count: state.tick_count,
+                    width: 24,
+                    label: Some(i18n::text(Text::Loading, state.language)),
+                },
+            );
+        }
+    }
+}
+
+/// The middle row of `area`.
+fn centered_row(area: Rect) -> Rect {
+    Layout::vertical([Constraint::Length(1)])
+        .flex(Flex::Center)
+        .split(area)[0]
+}
@@ -0,0 +1,53 @@
+use ratatui::{Frame, buffer::Buffer, layout::Rect, style::Color};
+use tui_theme::theme;
+
+use super::Component;
+use crate::action::Action;
+use crate::particles::{self, ParticleLayer};
+use crate::theme::Rgb;
+
+/// How far particles fade from the accent toward the background.
+const PARTICLE_DIM: f32 = 0.55;
+
+/// Rain, snow, or clouds composited into the empty cells of whatever was
+/// rendered underneath, so they read as a backdrop and never touch text.
+pub struct ParticleBackdrop;
+
+pub struct ParticleBackdropProps {
+    pub layer: ParticleLayer,
+    pub frame: u32,
+    /// Current condition accent; particles use a dimmed copy
+    pub accent: Rgb,
+}
+
+/// A cell is open when it and its horizontal neighbours are blank and
+/// unstyled, which keeps a one-cell margin around every word and sprite.
+fn is_open(buf: &Buffer, area: Rect, x: u16, y: u16) -> bool {
+    let blank = |x: u16| {
+        let cell = &buf[(x, y)];
+        cell.symbol() == " " && cell.bg == Color::Reset
+    };
+    let left = x.checked_sub(1).filter(|&x| x >= area.x);
+    let right = Some(x + 1).filter(|&x| x < area.right());
+    blank(x) && left.is_none_or(blank) && right.is_none_or(blank)
+}
+
+impl Component<Action> for ParticleBackdrop {
+    type Props<'a> = ParticleBackdropProps;
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        let color = props.accent.lerp(theme().palette.bg, PARTICLE_DIM).color();
+        let particles = particles::particles(props.layer, props.frame, area.width, area.height);
+        let buf = frame.buffer_mut();
+        // Decide every cell against the layer underneath before drawing, so a
+        // cloud puff doesn't crowd out its own next glyph
+        let placed: Vec<_> = particles
+            .into_iter()
+            .map(|particle| (area.x + particle.x, area.y + particle.y, particle.glyph))
+            .filter(|&(x, y, _)| is_open(buf, area, x, y))
+            .collect();
+        for (x, y, glyph) in placed {
+            buf[(x, y)].set_char(glyph).set_fg(color);
+        }
+    }
+}
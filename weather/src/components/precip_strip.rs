@@ -0,0 +1,104 @@
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Color, Style},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+
+use super::Component;
+use crate::action::Action;
+use crate::state::HourlySample;
+
+/// Hours shown in the strip, starting with the current one.
+pub const PRECIP_STRIP_HOURS: usize = 12;
+
+/// Rows used by the strip: bars + hour labels.
+pub const PRECIP_STRIP_HEIGHT: u16 = 2;
+
+/// Bar cells per hour, plus one gap column between hours.
+const BAR_WIDTH: usize = 2;
+
+const LEVELS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+/// Precipitation probability per upcoming hour, as a row of bars with hour labels.
+pub struct PrecipStrip;
+
+pub struct PrecipStripProps<'a> {
+    /// Hourly forecast, first sample = current hour
+    pub hourly: &'a [HourlySample],
+}
+
+/// Bar glyph for a probability; 0% still draws the lowest level as a baseline.
+fn bar_glyph(probability: u8) -> char {
+    let index = (probability.min(100) as usize * (LEVELS.len() - 1) + 50) / 100;
+    LEVELS[index]
+}
+
+fn bar_color(probability: u8) -> Color {
+    match probability {
+        0..=19 => Color::Rgb(70, 80, 100),
+        20..=49 => Color::Rgb(100, 150, 220),
+        50..=79 => Color::Rgb(80, 130, 255),
+        _ => Color::Rgb(60, 100, 255),
+    }
+}
+
+/// `HH` from an `YYYY-MM-DDTHH:MM` timestamp.
+fn hour_label(time: &str) -> &str {
+    time.split_once('T')
+        .and_then(|(_, clock)| clock.get(..2))
+        .unwrap_or("--")
+}
+
+impl Component<Action> for PrecipStrip {
+    type Props<'a> = PrecipStripProps<'a>;
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        let hours = &props.hourly[..props.hourly.len().min(PRECIP_STRIP_HOURS)];
+        if hours.is_empty() || area.height < PRECIP_STRIP_HEIGHT {
+            return;
+        }
+
+        let mut bars = Vec::with_capacity(hours.len() * 2);
+        let mut labels = Vec::with_capacity(hours.len() * 2);
+        for (i, sample) in hours.iter().enumerate() {
+            if i > 0 {
+                bars.push(Span::raw(" "));
+                labels.push(Span::raw(" "));
+            }
+            let probability = sample.precipitation_probability;
+            bars.push(Span::styled(
+                bar_glyph(probability).to_string().repeat(BAR_WIDTH),
+                Style::default().fg(bar_color(probability)),
+            ));
+            labels.push(Span::styled(
+                format!("{:<BAR_WIDTH$}", hour_label(&sample.time)),
+                Style::default().fg(Color::DarkGray),
+            ));
+        }
+
+        let rows = Layout::vertical([Constraint::Length(1), Constraint::Length(1)]).split(area);
+        frame.render_widget(Paragraph::new(Line::from(bars).centered()), rows[0]);
+        frame.render_widget(Paragraph::new(Line::from(labels).centered()), rows[1]);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bar_glyph_scales_with_probability() {
+        assert_eq!(bar_glyph(0), '▁');
+        assert_eq!(bar_glyph(50), '▅');
+        assert_eq!(bar_glyph(100), '█');
+        assert_eq!(bar_glyph(255), '█');
+    }
+
+    #[test]
+    fn test_hour_label() {
+        assert_eq!(hour_label("2024-06-03T07:00"), "07");
+        assert_eq!(hour_label("garbage"), "--");
+    }
+}
@@ -0,0 +1,111 @@
+use ratatui::{
+    Frame,
+    layout::Rect,
+    style::{Color, Style, Stylize},
+    text::{Line, Span},
+    widgets::Paragraph,
+};
+use tui_theme::theme;
+
+use super::Component;
+use crate::action::Action;
+use crate::i18n::{self, Language, Text};
+use crate::pressure::{Advisory, DayPart, Trend};
+
+/// A chip with the pressure trend and its 24-hour change, e.g. `▼ -8 hPa/24h`.
+pub struct PressureBadge;
+
+pub struct PressureBadgeProps<'a> {
+    pub advisory: &'a Advisory,
+}
+
+fn when_text(when: DayPart) -> Text {
+    match when {
+        DayPart::ThisMorning => Text::ThisMorning,
+        DayPart::ThisAfternoon => Text::ThisAfternoon,
+        DayPart::ThisEvening => Text::ThisEvening,
+        DayPart::Overnight => Text::Overnight,
+        DayPart::TomorrowMorning => Text::TomorrowMorning,
+        DayPart::TomorrowAfternoon => Text::TomorrowAfternoon,
+        DayPart::TomorrowEvening => Text::TomorrowEvening,
+    }
+}
+
+/// The advisory as a phrase for the summary line, e.g. "rapid pressure drop
+/// expected this evening".
+pub fn advisory_summary(advisory: &Advisory, lang: Language) -> String {
+    let template = match (advisory.trend, advisory.rapid) {
+        (Trend::Falling, false) => Text::PressureFalling,
+        (Trend::Falling, true) => Text::PressureFallingFast,
+        (Trend::Rising, false) => Text::PressureRising,
+        (Trend::Rising, true) => Text::PressureRisingFast,
+    };
+    i18n::text(template, lang).replace("{when}", i18n::text(when_text(advisory.when), lang))
+}
+
+fn badge_label(advisory: &Advisory) -> String {
+    let arrow = match advisory.trend {
+        Trend::Falling => '▼',
+        Trend::Rising => '▲',
+    };
+    format!(" {arrow} {:+.0} hPa/24h ", advisory.change)
+}
+
+impl Component<Action> for PressureBadge {
+    type Props<'a> = PressureBadgeProps<'a>;
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        let background = if props.advisory.rapid {
+            theme().danger
+        } else {
+            theme().gold
+        };
+        let badge = Span::styled(
+            badge_label(props.advisory),
+            Style::default().fg(Color::Black).bg(background).bold(),
+        );
+        frame.render_widget(Paragraph::new(Line::from(badge).centered()), area);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn advisory(trend: Trend, rapid: bool, change: f32, when: DayPart) -> Advisory {
+        Advisory {
+            trend,
+            rapid,
+            change,
+            time: "2024-06-03T18:00".into(),
+            when,
+        }
+    }
+
+    #[test]
+    fn test_advisory_summary() {
+        let drop = advisory(Trend::Falling, true, -8.0, DayPart::ThisEvening);
+        assert_eq!(
+            advisory_summary(&drop, Language::English),
+            "rapid pressure drop expected this evening"
+        );
+        assert_eq!(
+            advisory_summary(&drop, Language::German),
+            "starker Druckabfall heute Abend erwartet"
+        );
+
+        let rise = advisory(Trend::Rising, false, 6.0, DayPart::TomorrowMorning);
+        assert_eq!(
+            advisory_summary(&rise, Language::French),
+            "hausse de pression attendue demain matin"
+        );
+    }
+
+    #[test]
+    fn test_badge_label() {
+        let drop = advisory(Trend::Falling, true, -8.4, DayPart::Overnight);
+        assert_eq!(badge_label(&drop), " ▼ -8 hPa/24h ");
+        let rise = advisory(Trend::Rising, false, 5.6, DayPart::Overnight);
+        assert_eq!(badge_label(&rise), " ▲ +6 hPa/24h ");
+    }
+}
@@ -1,4 +1,4 @@
-use crossterm::event::KeyCode;
+use crossterm::event::{KeyCode, MouseButton, MouseEvent, MouseEventKind};
 use ratatui::{
     Frame,
     layout::{Constraint, Layout, Rect},
@@ -11,6 +11,7 @@ use tui_dispatch_components::{
     SelectListBehavior, SelectListProps, SelectListStyle, SelectionStyle, TextInput,
     TextInputProps, TextInputStyle, centered_rect, highlight_substring,
 };
+use tui_theme::theme;
 
 use super::Component;
 use crate::action::Action;
@@ -21,6 +22,8 @@ pub struct SearchOverlay {
     list: SelectList,
     modal: Modal,
     was_open: bool,
+    /// Results list area from the last render, used for mouse hit-testing
+    results_area: Rect,
 }
 
 pub struct SearchOverlayProps<'a> {
@@ -28,8 +31,12 @@ pub struct SearchOverlayProps<'a> {
     pub results: &'a [Location],
     pub selected: usize,
     pub is_focused: bool,
+    /// Input placeholder, localized by the caller
+    pub placeholder: &'a str,
     #[allow(unused)]
     pub error: Option<&'a str>,
+    /// Highlight for the matched part of each result
+    pub accent: Color,
     // Action constructors
     pub on_query_change: fn(String) -> Action,
     pub on_query_submit: fn(String) -> Action,
@@ -43,6 +50,7 @@ impl Default for SearchOverlay {
             list: SelectList::new(),
             modal: Modal::new(),
             was_open: false,
+            results_area: Rect::default(),
         }
     }
 }
@@ -62,13 +70,53 @@ impl SearchOverlay {
     fn reset(&mut self) {
         self.input = TextInput::new();
         self.list = SelectList::new();
+        self.results_area = Rect::default();
     }
 
-    fn result_items(results: &[Location], query: &str) -> Vec<Line<'static>> {
+    /// Map a terminal cell to a result index, accounting for list padding and scroll.
+    fn result_index_at(
+        &self,
+        column: u16,
+        row: u16,
+        count: usize,
+        selected: usize,
+    ) -> Option<usize> {
+        // Matches the `Padding::all(1)` used when rendering the list
+        let inner = Rect {
+            x: self.results_area.x + 1,
+            y: self.results_area.y + 1,
+            width: self.results_area.width.saturating_sub(2),
+            height: self.results_area.height.saturating_sub(2),
+        };
+        if inner.height == 0 || !inner.contains((column, row).into()) {
+            return None;
+        }
+        let visible = inner.height as usize;
+        let offset = (selected + 1).saturating_sub(visible);
+        let index = offset + (row - inner.y) as usize;
+        (index < count).then_some(index)
+    }
+
+    fn handle_mouse(&self, mouse: &MouseEvent, props: &SearchOverlayProps<'_>) -> Vec<Action> {
+        if mouse.kind != MouseEventKind::Down(MouseButton::Left) {
+            return Vec::new();
+        }
+        let Some(index) =
+            self.result_index_at(mouse.column, mouse.row, props.results.len(), props.selected)
+        else {
+            return Vec::new();
+        };
+        // Clicking the highlighted result confirms it, like pressing Enter
+        if index == props.selected {
+            vec![Action::SearchConfirm]
+        } else {
+            vec![(props.on_select)(index)]
+        }
+    }
+
+    fn result_items(results: &[Location], query: &str, accent: Color) -> Vec<Line<'static>> {
         let base = Style::default().fg(Color::Reset);
-        let highlight = Style::default()
-            .fg(Color::Yellow)
-            .add_modifier(Modifier::BOLD);
+        let highlight = Style::default().fg(accent).add_modifier(Modifier::BOLD);
         results
             .iter()
             .map(|loc| highlight_substring(&loc.name, query, base, highlight))
@@ -88,8 +136,21 @@ impl Component<Action> for SearchOverlay {
             return Vec::new();
         }
 
-        let EventKind::Key(key) = event else {
-            return Vec::new();
+        let key = match event {
+            EventKind::Key(key) => key,
+            EventKind::Mouse(mouse) => return self.handle_mouse(mouse, &props),
+            EventKind::Scroll { delta, .. } => {
+                if props.results.is_empty() {
+                    return Vec::new();
+                }
+                let max_index = props.results.len() - 1;
+                let next = props.selected.saturating_add_signed(*delta).min(max_index);
+                if next == props.selected {
+                    return Vec::new();
+                }
+                return vec![(props.on_select)(next)];
+            }
+            _ => return Vec::new(),
         };
 
         // Handle special keys first
@@ -105,7 +166,7 @@ impl Component<Action> for SearchOverlay {
             // Up/down always navigate the list (if results exist)
             KeyCode::Down | KeyCode::Up => {
                 if !props.results.is_empty() {
-                    let items = Self::result_items(props.results, props.query);
+                    let items = Self::result_items(props.results, props.query, props.accent);
                     let list_props = SelectListProps {
                         items: &items,
                         count: items.len(),
@@ -139,7 +200,7 @@ impl Component<Action> for SearchOverlay {
         // All other keys go to the input
         let input_props = TextInputProps {
             value: props.query,
-            placeholder: "Search for a city...",
+            placeholder: props.placeholder,
             is_focused: true,
             style: TextInputStyle {
                 base: BaseStyle {
@@ -168,7 +229,11 @@ impl Component<Action> for SearchOverlay {
         }
 
         let SearchOverlay {
-            input, list, modal, ..
+            input,
+            list,
+            modal,
+            results_area,
+            ..
         } = self;
         let modal_area = centered_rect(60, 12, area);
         let mut render_content = |frame: &mut Frame, content_area: Rect| {
@@ -181,13 +246,13 @@ impl Component<Action> for SearchOverlay {
             // Input with lighter background
             let input_props = TextInputProps {
                 value: props.query,
-                placeholder: "Search for a city...",
+                placeholder: props.placeholder,
                 is_focused: props.is_focused,
                 style: TextInputStyle {
                     base: BaseStyle {
                         border: None,
                         padding: Padding::all(1),
-                        bg: Some(Color::Rgb(50, 50, 60)),
+                        bg: Some(theme().panel_alt),
                         fg: None,
                     },
                     placeholder_style: None,
@@ -199,7 +264,7 @@ impl Component<Action> for SearchOverlay {
             };
             input.render(frame, chunks[0], input_props);
 
-            let items = Self::result_items(props.results, props.query);
+            let items = Self::result_items(props.results, props.query, props.accent);
             let list_props = SelectListProps {
                 items: &items,
                 count: items.len(),
@@ -220,6 +285,7 @@ impl Component<Action> for SearchOverlay {
                 render_item: &|item| item.clone(),
             };
             list.render(frame, chunks[1], list_props);
+            *results_area = chunks[1];
         };
 
         modal.render(
@@ -231,7 +297,7 @@ impl Component<Action> for SearchOverlay {
                 area: modal_area,
                 style: ModalStyle {
                     base: BaseStyle {
-                        bg: Some(Color::Rgb(35, 35, 45)),
+                        bg: Some(theme().panel),
                         padding: Padding::default(),
                         border: None,
                         fg: None,
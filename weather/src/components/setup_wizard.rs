@@ -0,0 +1,254 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Rect},
+    style::{Style, Stylize},
+    text::{Line, Span},
+    widgets::{Block, Paragraph},
+};
+use tui_dispatch::EventKind;
+use tui_dispatch_components::centered_rect;
+use tui_theme::theme;
+
+use super::{Component, SearchOverlay, SearchOverlayProps};
+use crate::action::Action;
+use crate::i18n::{self, Text};
+use crate::state::{AppState, TempUnit};
+use crate::wizard::{Wizard, WizardStep};
+
+/// First-run setup. The city and refresh steps are the search overlay, the
+/// units step a two-way choice in the same spot, with a step banner above
+/// and key hints or the last error below.
+#[derive(Default)]
+pub struct SetupWizard {
+    search: SearchOverlay,
+}
+
+pub struct SetupWizardProps<'a> {
+    pub state: &'a AppState,
+    pub is_focused: bool,
+}
+
+fn step_title(step: WizardStep) -> Text {
+    match step {
+        WizardStep::City => Text::SetupCity,
+        WizardStep::Units => Text::SetupUnits,
+        WizardStep::Refresh => Text::SetupRefresh,
+    }
+}
+
+impl SetupWizard {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overlay props for the text steps: the city step is the regular city
+    /// search, the refresh step a bare input with no results.
+    fn overlay_props<'a>(
+        state: &'a AppState,
+        wizard: &'a Wizard,
+        is_focused: bool,
+    ) -> SearchOverlayProps<'a> {
+        let city = SearchOverlayProps {
+            query: &state.search_query,
+            results: &state.search_results,
+            selected: state.search_selected,
+            is_focused,
+            placeholder: i18n::text(Text::SearchPlaceholder, state.language),
+            error: state.search_error.as_deref(),
+            accent: state.accent.color(),
+            on_query_change: Action::SearchQueryChange,
+            on_query_submit: Action::SearchQuerySubmit,
+            on_select: Action::SearchSelect,
+        };
+        match wizard.step {
+            WizardStep::Refresh => SearchOverlayProps {
+                query: &wizard.refresh_input,
+                results: &[],
+                selected: 0,
+                placeholder: i18n::text(Text::SetupRefresh, state.language),
+                error: wizard.error.as_deref(),
+                on_query_change: Action::WizardRefreshChange,
+                on_query_submit: Action::WizardRefreshSubmit,
+                ..city
+            },
+            _ => city,
+        }
+    }
+
+    fn render_units(frame: &mut Frame, area: Rect, unit: TempUnit) {
+        let option = |label: &'static str, option: TempUnit| {
+            if option == unit {
+                Span::styled(
+                    format!(" ▸ {label} "),
+                    Style::default()
+                        .fg(theme().highlight_text)
+                        .bg(theme().highlight)
+                        .bold(),
+                )
+            } else {
+                Span::styled(
+                    format!("   {label} "),
+                    Style::default().fg(theme().text_dim),
+                )
+            }
+        };
+        let choice = Line::from(vec![
+            option("°C", TempUnit::Celsius),
+            Span::raw("    "),
+            option("°F", TempUnit::Fahrenheit),
+        ])
+        .centered();
+        let sample = Line::from(Span::styled(
+            unit.format(20.0),
+            Style::default().fg(theme().text_dim),
+        ))
+        .centered();
+
+        frame.render_widget(Block::default().bg(theme().panel), area);
+        let rows = Layout::vertical([
+            Constraint::Fill(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Fill(1),
+        ])
+        .split(area);
+        frame.render_widget(Paragraph::new(choice), rows[1]);
+        frame.render_widget(Paragraph::new(sample), rows[3]);
+    }
+
+    fn footer(state: &AppState, wizard: &Wizard) -> Line<'static> {
+        if let Some(error) = &wizard.error {
+            return Line::from(Span::styled(
+                error.clone(),
+                Style::default().fg(theme().danger),
+            ))
+            .centered();
+        }
+        let lang = state.language;
+        let escape = match wizard.step {
+            WizardStep::City => Text::Quit,
+            _ => Text::Back,
+        };
+        let key = Style::default().fg(state.accent.color()).bold();
+        let label = Style::default().fg(theme().text_dim);
+        Line::from(vec![
+            Span::styled("Enter ", key),
+            Span::styled(i18n::text(Text::Next, lang), label),
+            Span::raw("  "),
+            Span::styled("Esc ", key),
+            Span::styled(i18n::text(escape, lang), label),
+        ])
+        .centered()
+    }
+}
+
+impl Component<Action> for SetupWizard {
+    type Props<'a> = SetupWizardProps<'a>;
+
+    fn handle_event(
+        &mut self,
+        event: &EventKind,
+        props: Self::Props<'_>,
+    ) -> impl IntoIterator<Item = Action> {
+        let Some(wizard) = props.state.wizard.as_ref() else {
+            return Vec::new();
+        };
+        if !props.is_focused || wizard.saving {
+            return Vec::new();
+        }
+
+        if wizard.step == WizardStep::Units {
+            let EventKind::Key(key) = event else {
+                return Vec::new();
+            };
+            return match key.code {
+                KeyCode::Left
+                | KeyCode::Right
+                | KeyCode::Up
+                | KeyCode::Down
+                | KeyCode::Tab
+                | KeyCode::Char(' ')
+                | KeyCode::Char('u') => vec![Action::WizardToggleUnit],
+                KeyCode::Enter => vec![Action::WizardNext],
+                KeyCode::Esc => vec![Action::WizardBack],
+                _ => Vec::new(),
+            };
+        }
+
+        // Esc in the overlay closes it; here that means quitting setup
+        // from the first step and going back a step from the last.
+        let escape = match wizard.step {
+            WizardStep::City => Action::Quit,
+            _ => Action::WizardBack,
+        };
+        self.search.set_open(true);
+        let overlay = Self::overlay_props(props.state, wizard, true);
+        self.search
+            .handle_event(event, overlay)
+            .into_iter()
+            .map(|action| match action {
+                Action::SearchClose => escape.clone(),
+                action => action,
+            })
+            .collect()
+    }
+
+    fn render(&mut self, frame: &mut Frame, area: Rect, props: Self::Props<'_>) {
+        let Some(wizard) = props.state.wizard.as_ref() else {
+            self.search.set_open(false);
+            return;
+        };
+        if area.width < 20 || area.height < 12 {
+            return;
+        }
+
+        // Leaving the overlay for the units step resets its input on return.
+        self.search.set_open(wizard.step != WizardStep::Units);
+
+        let card = centered_rect(60, 12, area);
+        let lang = props.state.language;
+        let banner = Line::from(vec![
+            Span::styled(
+                i18n::text(Text::SetupTitle, lang),
+                Style::default().fg(props.state.accent.color()).bold(),
+            ),
+            Span::styled(
+                format!(" · {}/{} · ", wizard.step.number(), WizardStep::COUNT),
+                Style::default().fg(theme().text_dim),
+            ),
+            Span::styled(
+                i18n::text(step_title(wizard.step), lang),
+                Style::default().fg(theme().text),
+            ),
+        ])
+        .centered();
+        let banner_area = Rect {
+            y: card.y.saturating_sub(2),
+            height: 1,
+            ..card
+        };
+        frame.render_widget(Paragraph::new(banner), banner_area);
+
+        match wizard.step {
+            WizardStep::Units => Self::render_units(frame, card, wizard.unit),
+            _ => {
+                let overlay = Self::overlay_props(props.state, wizard, props.is_focused);
+                self.search.render(frame, area, overlay);
+            }
+        }
+
+        let footer_area = Rect {
+            y: card.bottom() + 1,
+            height: 1,
+            ..card
+        };
+        if footer_area.bottom() <= area.bottom() {
+            frame.render_widget(
+                Paragraph::new(Self::footer(props.state, wizard)),
+                footer_area,
+            );
+        }
+    }
+}
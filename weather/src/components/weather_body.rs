@@ -11,10 +11,16 @@ use ratatui::{
 };
 
 use tui_dispatch::DataResource;
+use tui_theme::theme;
 
-use super::{Component, ERROR_ICON, LocationHeader, LocationHeaderProps};
-use super::location_header::HEADER_OVERHEAD;
+use super::{
+    Component, ERROR_ICON, LoadingSkeleton, LoadingSkeletonProps, LocationHeader,
+    LocationHeaderProps, PrecipStrip, PrecipStripProps, PressureBadge, PressureBadgeProps,
+};
 use crate::action::Action;
+use crate::cache::Provisional;
+use crate::i18n::{self, Language, Text};
+use crate::pressure;
 use crate::sprites::{self, SpriteSize};
 use crate::state::{AppState, WeatherData};
 
@@ -22,10 +28,15 @@ pub struct WeatherBody;
 
 pub struct WeatherBodyProps<'a> {
     pub state: &'a AppState,
+    /// Condition accent for the header and key prompts
+    pub accent: Color,
 }
 
-/// Fixed rows: blank + blank + description.
-const LAYOUT_FIXED: u16 = 3;
+/// Fixed rows: blank + blank + description + pressure badge + precipitation strip.
+const LAYOUT_FIXED: u16 = 4 + PRECIP_STRIP_HEIGHT;
+
+/// Skeleton width matching a full strip: 2-cell bars with 1-cell gaps.
+const PRECIP_STRIP_WIDTH: u16 = PRECIP_STRIP_HOURS as u16 * 3 - 1;
 
 /// Text cap tiers: (header_cap, temp_cap).
 /// terminus(6), miniwi(4), plain(1) — with HEADER_OVERHEAD added to header.
@@ -73,6 +84,22 @@ fn compute_layout(area_height: u16) -> LayoutSizing {
     }
 }
 
+/// The "updating…" note on provisional data, with the skeleton's highlight
+/// band sweeping across it in the accent color.
+fn updating_spans(state: &AppState, accent: Color) -> Vec<Span<'static>> {
+    let label = i18n::text(Text::Updating, state.language);
+    let width = label.chars().count() as u16;
+    label
+        .chars()
+        .enumerate()
+        .map(|(column, ch)| {
+            let lit = shimmer_strength(column as u16, width, state.tick_count) > 0.3;
+            let color = if lit { accent } else { theme().text_dim };
+            Span::styled(ch.to_string(), Style::default().fg(color))
+        })
+        .collect()
+}
+
 // ============================================================================
 // Component
 // ============================================================================
@@ -85,21 +112,24 @@ impl Component<Action> for WeatherBody {
 
         let view = WeatherView::from_state(props.state);
         match view {
-            WeatherView::Error(error) => render_error(frame, area, error),
+            WeatherView::Error(error) => {
+                render_error(frame, area, error, props.state.language, props.accent)
+            }
             WeatherView::Ready(weather) => {
-                render_ready(frame, area, props.state, weather, &sizing);
+                render_ready(frame, area, &props, weather, &sizing);
             }
             WeatherView::Loading => {
-                render_placeholder(frame, area, props.state, &sizing, "Loading...");
+                render_loading(frame, area, &props, &sizing);
             }
             WeatherView::Empty => {
-                render_placeholder_hint(frame, area, props.state, &sizing);
+                render_placeholder_hint(frame, area, &props, &sizing);
             }
         }
     }
 }
 
-fn render_header(frame: &mut Frame, area: Rect, state: &AppState) {
+fn render_header(frame: &mut Frame, area: Rect, props: &WeatherBodyProps) {
+    let state = props.state;
     let mut header = LocationHeader;
     header.render(
         frame,
@@ -109,6 +139,9 @@ fn render_header(frame: &mut Frame, area: Rect, state: &AppState) {
             temperature: state.weather.data().map(|w| w.temperature),
             is_animating: state.loading_anim_active(),
             tick_count: state.tick_count,
+            api_usage: state.api_usage,
+            daily_limit: state.daily_limit,
+            accent: props.accent,
         },
     );
 }
@@ -121,6 +154,8 @@ fn make_layout(area: Rect, sizing: &LayoutSizing) -> std::rc::Rc<[Rect]> {
         Constraint::Length(1),
         Constraint::Max(sizing.temp_cap),
         Constraint::Length(1),
+        Constraint::Length(1),
+        Constraint::Length(PRECIP_STRIP_HEIGHT),
     ])
     .flex(Flex::Center)
     .split(area)
@@ -129,13 +164,14 @@ fn make_layout(area: Rect, sizing: &LayoutSizing) -> std::rc::Rc<[Rect]> {
 fn render_ready(
     frame: &mut Frame,
     area: Rect,
-    state: &AppState,
+    props: &WeatherBodyProps,
     weather: &WeatherData,
     sizing: &LayoutSizing,
 ) {
+    let state = props.state;
     let chunks = make_layout(area, sizing);
 
-    render_header(frame, chunks[0], state);
+    render_header(frame, chunks[0], props);
 
     // Sprite or emoji
     match sizing.sprite {
@@ -163,52 +199,113 @@ fn render_ready(
         .with_fill(temperature_gradient(weather.temperature));
     frame.render_widget(ArtBox::new(&renderer, &temp_text), chunks[4]);
 
-    // Description
-    let desc = Line::from(vec![Span::styled(
-        weather.description.to_string(),
-        Style::default().fg(Color::Gray),
-    )])
-    .centered();
-    frame.render_widget(Paragraph::new(desc), chunks[5]);
+    // Description, followed by the pressure advisory if there is one
+    let advisory = state
+        .hourly
+        .data()
+        .and_then(|hourly| pressure::advise(hourly, state.pressure));
+    let mut summary = vec![Span::styled(
+        i18n::describe(weather.weather_code, state.language),
+        Style::default().fg(theme().text_dim),
+    )];
+    if let Some(advisory) = &advisory {
+        summary.push(Span::styled("  ·  ", Style::default().fg(props.accent)));
+        summary.push(Span::styled(
+            advisory_summary(advisory, state.language),
+            Style::default().fg(theme().text_dim),
+        ));
+    }
+    if let Some(provisional) = &state.provisional {
+        summary.push(Span::styled("  ·  ", Style::default().fg(props.accent)));
+        if let Provisional::Estimated { from } = provisional {
+            let estimate = i18n::text(Text::EstimateFrom, state.language).replace("{city}", from);
+            summary.push(Span::styled(
+                estimate,
+                Style::default().fg(theme().text_dim),
+            ));
+            summary.push(Span::raw(" "));
+        }
+        summary.extend(updating_spans(state, props.accent));
+    }
+    frame.render_widget(Paragraph::new(Line::from(summary).centered()), chunks[5]);
+
+    if let Some(advisory) = &advisory {
+        let mut badge = PressureBadge;
+        badge.render(frame, chunks[6], PressureBadgeProps { advisory });
+    }
+
+    render_precip(frame, chunks[7], state);
 }
 
-fn render_placeholder(
-    frame: &mut Frame,
-    area: Rect,
-    state: &AppState,
-    sizing: &LayoutSizing,
-    message: &str,
-) {
+/// Precipitation strip once hourly data is in, a shimmer bar while it loads.
+fn render_precip(frame: &mut Frame, area: Rect, state: &AppState) {
+    match &state.hourly {
+        DataResource::Loaded(hourly) => {
+            let mut strip = PrecipStrip;
+            strip.render(frame, area, PrecipStripProps { hourly });
+        }
+        DataResource::Loading => render_precip_skeleton(frame, area, state),
+        DataResource::Empty | DataResource::Failed(_) => {}
+    }
+}
+
+fn render_precip_skeleton(frame: &mut Frame, area: Rect, state: &AppState) {
+    let mut skeleton = LoadingSkeleton;
+    skeleton.render(
+        frame,
+        area,
+        LoadingSkeletonProps {
+            tick_count: state.tick_count,
+            width: PRECIP_STRIP_WIDTH,
+            label: None,
+        },
+    );
+}
+
+fn render_loading(frame: &mut Frame, area: Rect, props: &WeatherBodyProps, sizing: &LayoutSizing) {
+    let state = props.state;
     let chunks = make_layout(area, sizing);
-    render_header(frame, chunks[0], state);
-
-    let msg = Line::from(vec![Span::styled(
-        message,
-        Style::default().fg(Color::DarkGray),
-    )])
-    .centered();
-    frame.render_widget(Paragraph::new(msg), chunks[5]);
+    render_header(frame, chunks[0], props);
+
+    let mut skeleton = LoadingSkeleton;
+    let sprite_width = match sizing.sprite {
+        Some(size) => sprites::get_sprite(sprites::WeatherCondition::ClearSky, size).width() as u16,
+        None => 2,
+    };
+    // Sprite, temperature, and description slots, sized like the loaded view
+    let slots = [
+        (chunks[2], sprite_width, None),
+        (chunks[4], 24, Some(i18n::text(Text::Loading, state.language))),
+        (chunks[5], 16, None),
+    ];
+    for (slot, width, label) in slots {
+        skeleton.render(
+            frame,
+            slot,
+            LoadingSkeletonProps {
+                tick_count: state.tick_count,
+                width,
+                label,
+            },
+        );
+    }
+    render_precip_skeleton(frame, chunks[7], state);
 }
 
 fn render_placeholder_hint(
     frame: &mut Frame,
     area: Rect,
-    state: &AppState,
+    props: &WeatherBodyProps,
     sizing: &LayoutSizing,
 ) {
     let chunks = make_layout(area, sizing);
-    render_header(frame, chunks[0], state);
+    render_header(frame, chunks[0], props);
 
-    let hint = Line::from(vec![
-        Span::styled("Press ", Style::default().fg(Color::DarkGray)),
-        Span::styled("r", Style::default().fg(Color::Cyan).bold()),
-        Span::styled(" to fetch weather", Style::default().fg(Color::DarkGray)),
-    ])
-    .centered();
+    let hint = key_prompt(Text::FetchPrompt, props.state.language, props.accent).centered();
     frame.render_widget(Paragraph::new(hint), chunks[5]);
 }
 
-fn render_error(frame: &mut Frame, area: Rect, error: &str) {
+fn render_error(frame: &mut Frame, area: Rect, error: &str, lang: Language, accent: Color) {
     let chunks = Layout::vertical([
         Constraint::Length(1), // blank
         Constraint::Length(1), // icon
@@ -227,8 +324,8 @@ fn render_error(frame: &mut Frame, area: Rect, error: &str) {
     frame.render_widget(
         Paragraph::new(
             Line::from(vec![Span::styled(
-                "Error",
-                Style::default().fg(Color::Red).bold(),
+                i18n::text(Text::Error, lang),
+                Style::default().fg(theme().danger).bold(),
             )])
             .centered(),
         ),
@@ -238,21 +335,14 @@ fn render_error(frame: &mut Frame, area: Rect, error: &str) {
         Paragraph::new(
             Line::from(vec![Span::styled(
                 error.to_string(),
-                Style::default().fg(Color::Rgb(200, 100, 100)),
+                Style::default().fg(theme().danger_muted),
             )])
             .centered(),
         ),
         chunks[3],
     );
     frame.render_widget(
-        Paragraph::new(
-            Line::from(vec![
-                Span::styled("Press ", Style::default().fg(Color::DarkGray)),
-                Span::styled("r", Style::default().fg(Color::Cyan).bold()),
-                Span::styled(" to retry", Style::default().fg(Color::DarkGray)),
-            ])
-            .centered(),
-        ),
+        Paragraph::new(key_prompt(Text::RetryPrompt, lang, accent).centered()),
         chunks[5],
     );
 }
@@ -261,6 +351,16 @@ fn render_error(frame: &mut Frame, area: Rect, error: &str) {
 // Helpers
 // ============================================================================
 
+/// Render a "press r to ..." prompt with the key highlighted.
+fn key_prompt(prompt: Text, lang: Language, accent: Color) -> Line<'static> {
+    let (before, after) = i18n::split_prompt(i18n::text(prompt, lang));
+    Line::from(vec![
+        Span::styled(before, Style::default().fg(Color::DarkGray)),
+        Span::styled("r", Style::default().fg(accent).bold()),
+        Span::styled(after, Style::default().fg(Color::DarkGray)),
+    ])
+}
+
 enum WeatherView<'a> {
     Error(&'a str),
     Ready(&'a WeatherData),
@@ -1,13 +1,18 @@
-use crossterm::event::KeyCode;
+use crossterm::event::{KeyCode, MouseButton, MouseEventKind};
 use ratatui::layout::{Constraint, Layout};
 use ratatui::prelude::{Frame, Rect};
+use ratatui::style::{Style, Stylize};
 use tui_dispatch::EventKind;
 use tui_dispatch_components::{
     StatusBar, StatusBarHint, StatusBarProps, StatusBarSection, StatusBarStyle,
 };
 
-use super::{Component, WeatherBody, WeatherBodyProps};
+use super::{
+    CommutePanel, CommutePanelProps, Component, EnsemblePanel, EnsemblePanelProps, GardenPanel,
+    GardenPanelProps, ParticleBackdrop, ParticleBackdropProps, WeatherBody, WeatherBodyProps,
+};
 use crate::action::Action;
+use crate::i18n::{self, Language, Text};
 use crate::state::AppState;
 
 pub const ERROR_ICON: &str = "\u{26a0}\u{fe0f}";
@@ -17,9 +22,52 @@ pub struct WeatherDisplayProps<'a> {
     pub is_focused: bool,
 }
 
+/// Help bar entries: (key, label, action). Each entry doubles as a clickable button.
+const HINTS: [(&str, Text, Action); 8] = [
+    ("r", Text::Refresh, Action::WeatherFetch),
+    ("/", Text::Search, Action::SearchOpen),
+    ("u", Text::Units, Action::UiToggleUnits),
+    ("c", Text::Commute, Action::UiToggleCommute),
+    ("g", Text::Garden, Action::UiToggleGarden),
+    ("e", Text::Spread, Action::UiToggleEnsemble),
+    ("l", Text::Lang, Action::UiCycleLanguage),
+    ("q", Text::Quit, Action::Quit),
+];
+
+/// Gap between hints in the centered status bar section.
+const HINT_GAP: u16 = 2;
+
 /// The main weather display component
 #[derive(Default)]
-pub struct WeatherDisplay;
+pub struct WeatherDisplay {
+    /// Help bar area from the last render, used for mouse hit-testing
+    status_area: Rect,
+}
+
+impl WeatherDisplay {
+    /// Find the hint button under the given cell, if any.
+    fn hint_at(&self, column: u16, row: u16, lang: Language) -> Option<Action> {
+        let area = self.status_area;
+        if row != area.y || !area.contains((column, row).into()) {
+            return None;
+        }
+        let widths: Vec<u16> = HINTS
+            .iter()
+            .map(|&(key, label, _)| {
+                (key.chars().count() + 1 + i18n::text(label, lang).chars().count()) as u16
+            })
+            .collect();
+        let total = widths.iter().sum::<u16>() + HINT_GAP * (widths.len() as u16 - 1);
+        let mut x = area.x + area.width.saturating_sub(total) / 2;
+        for ((_, _, action), width) in HINTS.iter().zip(widths) {
+            if (x..x + width).contains(&column) {
+                return Some(action.clone());
+            }
+            x += width + HINT_GAP;
+        }
+        None
+    }
+}
 
 impl Component<Action> for WeatherDisplay {
     type Props<'a> = WeatherDisplayProps<'a>;
@@ -38,9 +86,16 @@ impl Component<Action> for WeatherDisplay {
                 KeyCode::Char('r') | KeyCode::F(5) => Some(Action::WeatherFetch),
                 KeyCode::Char('/') => Some(Action::SearchOpen),
                 KeyCode::Char('u') => Some(Action::UiToggleUnits),
+                KeyCode::Char('c') => Some(Action::UiToggleCommute),
+                KeyCode::Char('g') => Some(Action::UiToggleGarden),
+                KeyCode::Char('e') => Some(Action::UiToggleEnsemble),
+                KeyCode::Char('l') => Some(Action::UiCycleLanguage),
                 KeyCode::Char('q') | KeyCode::Esc => Some(Action::Quit),
                 _ => None,
             },
+            EventKind::Mouse(mouse) if mouse.kind == MouseEventKind::Down(MouseButton::Left) => {
+                self.hint_at(mouse.column, mouse.row, props.state.language)
+            }
             _ => None,
         }
     }
@@ -52,8 +107,38 @@ impl Component<Action> for WeatherDisplay {
         ])
         .split(area);
 
-        let mut body = WeatherBody;
-        body.render(frame, chunks[0], WeatherBodyProps { state: props.state });
+        let state = props.state;
+        let accent = state.accent.color();
+        if state.show_commute {
+            let mut panel = CommutePanel;
+            panel.render(frame, chunks[0], CommutePanelProps { state, accent });
+        } else if state.show_garden {
+            let mut panel = GardenPanel;
+            panel.render(frame, chunks[0], GardenPanelProps { state, accent });
+        } else if state.show_ensemble {
+            let mut panel = EnsemblePanel;
+            panel.render(frame, chunks[0], EnsemblePanelProps { state, accent });
+        } else {
+            let mut body = WeatherBody;
+            body.render(frame, chunks[0], WeatherBodyProps { state, accent });
+        }
+        if let Some(layer) = state.particle_layer() {
+            let props = ParticleBackdropProps {
+                layer,
+                frame: state.particles.frame(layer),
+                accent: state.accent.current(),
+            };
+            let mut backdrop = ParticleBackdrop;
+            backdrop.render(frame, chunks[0], props);
+        }
+
+        self.status_area = chunks[1];
+        let hints: Vec<_> = HINTS
+            .iter()
+            .map(|&(key, label, _)| {
+                StatusBarHint::new(key, i18n::text(label, props.state.language))
+            })
+            .collect();
 
         let mut status_bar = StatusBar::new();
         <StatusBar as Component<Action>>::render(
@@ -62,14 +147,12 @@ impl Component<Action> for WeatherDisplay {
             chunks[1],
             StatusBarProps {
                 left: StatusBarSection::empty(),
-                center: StatusBarSection::hints(&[
-                    StatusBarHint::new("r", "refresh"),
-                    StatusBarHint::new("/", "search"),
-                    StatusBarHint::new("u", "units"),
-                    StatusBarHint::new("q", "quit"),
-                ]),
+                center: StatusBarSection::hints(&hints),
                 right: StatusBarSection::empty(),
-                style: StatusBarStyle::default(),
+                style: StatusBarStyle {
+                    hint_key: Style::default().fg(accent).bold(),
+                    ..StatusBarStyle::default()
+                },
                 is_focused: false,
             },
         );
@@ -84,7 +167,7 @@ mod tests {
 
     #[test]
     fn test_handle_event_refresh() {
-        let mut component = WeatherDisplay;
+        let mut component = WeatherDisplay::default();
         let state = AppState::default();
         let props = WeatherDisplayProps {
             state: &state,
@@ -101,7 +184,7 @@ mod tests {
 
     #[test]
     fn test_handle_event_quit() {
-        let mut component = WeatherDisplay;
+        let mut component = WeatherDisplay::default();
         let state = AppState::default();
         let props = WeatherDisplayProps {
             state: &state,
@@ -117,7 +200,7 @@ mod tests {
 
     #[test]
     fn test_handle_event_unfocused_ignores() {
-        let mut component = WeatherDisplay;
+        let mut component = WeatherDisplay::default();
         let state = AppState::default();
         let props = WeatherDisplayProps {
             state: &state,
@@ -131,12 +214,43 @@ mod tests {
         actions.assert_empty();
     }
 
+    #[test]
+    fn test_click_status_bar_hint() {
+        let mut render = RenderHarness::new(80, 24);
+        let mut component = WeatherDisplay::default();
+        let state = AppState::default();
+
+        let output = render.render_to_string_plain(|frame| {
+            let props = WeatherDisplayProps {
+                state: &state,
+                is_focused: true,
+            };
+            component.render(frame, frame.area(), props);
+        });
+
+        let status_line = output.lines().last().unwrap();
+        let column = status_line.find("units").unwrap() as u16 + 2;
+        let click = EventKind::Mouse(crossterm::event::MouseEvent {
+            kind: MouseEventKind::Down(MouseButton::Left),
+            column,
+            row: 23,
+            modifiers: crossterm::event::KeyModifiers::NONE,
+        });
+        let props = WeatherDisplayProps {
+            state: &state,
+            is_focused: true,
+        };
+
+        let actions: Vec<_> = component.handle_event(&click, props).into_iter().collect();
+        actions.assert_first(Action::UiToggleUnits);
+    }
+
     #[test]
     fn test_render_loading() {
         use tui_dispatch::DataResource;
 
         let mut render = RenderHarness::new(60, 24);
-        let mut component = WeatherDisplay;
+        let mut component = WeatherDisplay::default();
 
         let state = AppState {
             weather: DataResource::Loading,
@@ -159,7 +273,7 @@ mod tests {
         use tui_dispatch::DataResource;
 
         let mut render = RenderHarness::new(60, 24);
-        let mut component = WeatherDisplay;
+        let mut component = WeatherDisplay::default();
 
         let state = AppState {
             weather: DataResource::Loaded(WeatherData {
@@ -180,4 +294,272 @@ mod tests {
 
         assert!(output.contains("Clear sky"));
     }
+
+    #[test]
+    fn test_render_weather_localized() {
+        use crate::i18n::Language;
+        use tui_dispatch::DataResource;
+
+        let mut render = RenderHarness::new(80, 24);
+        let mut component = WeatherDisplay::default();
+
+        let state = AppState {
+            weather: DataResource::Loaded(WeatherData {
+                temperature: 8.0,
+                weather_code: 63,
+                description: "Rain".into(),
+            }),
+            language: Language::German,
+            ..Default::default()
+        };
+
+        let output = render.render_to_string_plain(|frame| {
+            let props = WeatherDisplayProps {
+                state: &state,
+                is_focused: true,
+            };
+            component.render(frame, frame.area(), props);
+        });
+
+        assert!(output.contains("Regen"));
+        assert!(output.contains("Sprache"));
+        assert!(!output.contains("Rain"));
+    }
+
+    #[test]
+    fn test_render_rain_particles_around_text() {
+        use tui_dispatch::DataResource;
+
+        let mut render = RenderHarness::new(80, 24);
+        let mut component = WeatherDisplay::default();
+
+        let mut state = AppState {
+            weather: DataResource::Loaded(WeatherData {
+                temperature: 14.0,
+                weather_code: 65,
+                description: "Heavy rain".into(),
+            }),
+            ..Default::default()
+        };
+        state.particles.tick = 90;
+
+        let mut draw = |state: &AppState| {
+            render.render_to_string_plain(|frame| {
+                let props = WeatherDisplayProps {
+                    state,
+                    is_focused: true,
+                };
+                component.render(frame, frame.area(), props);
+            })
+        };
+        let rainy = draw(&state);
+        assert!(rainy.contains('|'));
+        assert!(rainy.contains(" Rain "));
+
+        state.particles.enabled = false;
+        let plain = draw(&state);
+        assert!(!plain.contains('|'));
+    }
+
+    #[test]
+    fn test_render_quota_indicator() {
+        use crate::quota::CallUsage;
+        use tui_dispatch::DataResource;
+
+        let mut render = RenderHarness::new(80, 24);
+        let mut component = WeatherDisplay::default();
+
+        let state = AppState {
+            weather: DataResource::Loaded(WeatherData::default()),
+            api_usage: CallUsage {
+                last_hour: 12,
+                last_day: 1_000,
+            },
+            daily_limit: 1_000,
+            ..Default::default()
+        };
+
+        let output = render.render_to_string_plain(|frame| {
+            let props = WeatherDisplayProps {
+                state: &state,
+                is_focused: true,
+            };
+            component.render(frame, frame.area(), props);
+        });
+
+        assert!(output.contains("⇅ 12/h 1000/d ×8"));
+    }
+
+    #[test]
+    fn test_render_pressure_advisory() {
+        use crate::pressure::PressureConfig;
+        use crate::state::HourlySample;
+        use tui_dispatch::DataResource;
+
+        let mut render = RenderHarness::new(80, 24);
+        let mut component = WeatherDisplay::default();
+
+        // Level through the afternoon, then 9 hPa down by 21:00
+        let hourly = (0..25)
+            .map(|i| {
+                let hour = 12 + i;
+                HourlySample {
+                    time: format!("2024-06-{:02}T{:02}:00", 3 + hour / 24, hour % 24),
+                    pressure: Some(1016.0 - 3.0 * (hour.clamp(18, 21) - 18) as f32),
+                    ..Default::default()
+                }
+            })
+            .collect();
+        let mut state = AppState {
+            weather: DataResource::Loaded(WeatherData {
+                temperature: 18.0,
+                weather_code: 3,
+                description: "Overcast".into(),
+            }),
+            hourly: DataResource::Loaded(hourly),
+            pressure: PressureConfig {
+                enabled: true,
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let mut draw = |state: &AppState| {
+            render.render_to_string_plain(|frame| {
+                let props = WeatherDisplayProps {
+                    state,
+                    is_focused: true,
+                };
+                component.render(frame, frame.area(), props);
+            })
+        };
+        let output = draw(&state);
+        assert!(output.contains("Overcast  ·  rapid pressure drop expected this evening"));
+        assert!(output.contains("▼ -9 hPa/24h"));
+
+        state.pressure.enabled = false;
+        let output = draw(&state);
+        assert!(!output.contains("pressure"));
+        assert!(!output.contains("hPa"));
+    }
+
+    #[test]
+    fn test_render_commute_panel() {
+        use crate::state::HourlySample;
+        use tui_dispatch::DataResource;
+
+        let mut render = RenderHarness::new(80, 24);
+        let mut component = WeatherDisplay::default();
+
+        let sample = |time: &str, precipitation_probability| HourlySample {
+            time: time.into(),
+            temperature: 14.0,
+            precipitation_probability,
+            weather_code: 0,
+            wind_speed: 10.0,
+            pressure: None,
+        };
+        let state = AppState {
+            show_commute: true,
+            hourly: DataResource::Loaded(vec![
+                sample("2024-06-03T08:00", 0),
+                sample("2024-06-03T17:00", 80),
+            ]),
+            ..Default::default()
+        };
+
+        let output = render.render_to_string_plain(|frame| {
+            let props = WeatherDisplayProps {
+                state: &state,
+                is_focused: true,
+            };
+            component.render(frame, frame.area(), props);
+        });
+
+        assert!(output.contains("Morning 08–09"));
+        assert!(output.contains("Mon 06-03"));
+        assert!(output.contains("good"));
+        assert!(output.contains("poor"));
+    }
+
+    #[test]
+    fn test_render_garden_panel() {
+        use crate::state::DailySample;
+        use tui_dispatch::DataResource;
+
+        let mut render = RenderHarness::new(80, 24);
+        let mut component = WeatherDisplay::default();
+
+        let day = |date: &str, temp_min| DailySample {
+            date: date.into(),
+            temp_min,
+            temp_max: 20.0,
+        };
+        let state = AppState {
+            show_garden: true,
+            archive: DataResource::Loaded(vec![
+                day("2023-04-15", -1.0),
+                day("2023-10-20", -3.0),
+                day("2024-05-01", 10.0),
+                day("2024-05-02", 12.0),
+            ]),
+            ..Default::default()
+        };
+
+        let output = render.render_to_string_plain(|frame| {
+            let props = WeatherDisplayProps {
+                state: &state,
+                is_focused: true,
+            };
+            component.render(frame, frame.area(), props);
+        });
+
+        assert!(output.contains("Growing degree days"));
+        assert!(output.contains("11 °C·d"));
+        assert!(output.contains("~ 04-15"));
+        assert!(output.contains("~ 10-20"));
+        assert!(output.contains("188 days"));
+    }
+
+    #[test]
+    fn test_render_ensemble_panel() {
+        use crate::state::{EnsembleBand, TempUnit};
+        use tui_dispatch::DataResource;
+
+        let mut render = RenderHarness::new(80, 24);
+        let mut component = WeatherDisplay::default();
+
+        let bands = (0..48)
+            .map(|hour| EnsembleBand {
+                time: format!("2024-06-{:02}T{:02}:00", 3 + hour / 24, hour % 24),
+                min: 11.0 - hour as f32 / 24.0,
+                median: 12.0,
+                max: 13.0 + hour as f32 / 24.0,
+            })
+            .collect();
+        let state = AppState {
+            show_ensemble: true,
+            ensemble: DataResource::Loaded(bands),
+            unit: TempUnit::Fahrenheit,
+            ..Default::default()
+        };
+
+        let output = render.render_to_string_plain(|frame| {
+            let props = WeatherDisplayProps {
+                state: &state,
+                is_focused: true,
+            };
+            component.render(frame, frame.area(), props);
+        });
+
+        assert!(output.contains("Confidence: medium"));
+        assert!(output.contains("by 7.1°F on average"));
+        assert!(output.contains("━"));
+        assert!(output.contains("█"));
+        assert!(output.contains("Mon"));
+        assert!(output.contains("Tue"));
+        // The axis spans the widest hour: ~15°C at the top, ~9°C at the bottom
+        assert!(output.contains("59°"));
+        assert!(output.contains("48°"));
+    }
 }
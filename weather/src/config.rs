@@ -0,0 +1,119 @@
+//! Saved settings, written by the first-run wizard
+//!
+//! Lives beside the theme file as `config.json`. Flags on the command line
+//! still win over anything saved here.
+
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::state::{Location, TempUnit};
+
+/// Auto-refresh interval when neither the config nor `--refresh-interval` sets one
+pub const DEFAULT_REFRESH_SECS: u64 = 30;
+
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Config {
+    /// Default city, stored geocoded so startup needs no lookup
+    pub city: Location,
+    #[serde(default)]
+    pub unit: TempUnit,
+    /// Auto-refresh interval in seconds
+    #[serde(default = "default_refresh")]
+    pub refresh_interval: u64,
+}
+
+fn default_refresh() -> u64 {
+    DEFAULT_REFRESH_SECS
+}
+
+/// `~/.config/weather/config.json`, or under `$XDG_CONFIG_HOME` when set
+pub fn path() -> PathBuf {
+    tui_theme::config::config_path("weather").with_file_name("config.json")
+}
+
+/// The saved config, or `None` before the first run has written one
+pub fn load(path: &Path) -> Result<Option<Config>, String> {
+    let text = match fs::read_to_string(path) {
+        Ok(text) => text,
+        Err(e) if e.kind() == ErrorKind::NotFound => return Ok(None),
+        Err(e) => return Err(format!("{}: {e}", path.display())),
+    };
+    serde_json::from_str(&text)
+        .map(Some)
+        .map_err(|e| format!("{}: {e}", path.display()))
+}
+
+pub fn save(path: &Path, config: &Config) -> Result<(), String> {
+    if let Some(dir) = path.parent() {
+        fs::create_dir_all(dir).map_err(|e| format!("{}: {e}", dir.display()))?;
+    }
+    let json = serde_json::to_string_pretty(config).map_err(|e| e.to_string())?;
+    fs::write(path, json + "\n").map_err(|e| format!("{}: {e}", path.display()))
+}
+
+/// Parser for the wizard's refresh step, with the same floor as
+/// `--refresh-interval`. Leaving it blank keeps the default.
+pub fn parse_refresh_interval(value: &str) -> Result<u64, String> {
+    if value.trim().is_empty() {
+        return Ok(DEFAULT_REFRESH_SECS);
+    }
+    match value.trim().parse::<u64>() {
+        Ok(secs) if secs >= 1 => Ok(secs),
+        _ => Err(format!(
+            "invalid refresh interval '{value}' (whole seconds, at least 1)"
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kyoto() -> Config {
+        Config {
+            city: Location {
+                name: "Kyoto, Japan".into(),
+                lat: 35.0116,
+                lon: 135.7681,
+            },
+            unit: TempUnit::Fahrenheit,
+            refresh_interval: 120,
+        }
+    }
+
+    #[test]
+    fn test_save_then_load_round_trips() {
+        let dir = std::env::temp_dir().join(format!("weather-config-{}", std::process::id()));
+        let path = dir.join("nested").join("config.json");
+        assert_eq!(load(&path), Ok(None));
+
+        save(&path, &kyoto()).unwrap();
+        assert_eq!(load(&path), Ok(Some(kyoto())));
+
+        fs::write(&path, "{ not json").unwrap();
+        assert!(load(&path).unwrap_err().contains("config.json"));
+        fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn test_missing_fields_fall_back_to_defaults() {
+        let config: Config =
+            serde_json::from_str(r#"{"city": {"name": "Oslo", "lat": 59.9, "lon": 10.7}}"#)
+                .unwrap();
+        assert_eq!(config.unit, TempUnit::Celsius);
+        assert_eq!(config.refresh_interval, DEFAULT_REFRESH_SECS);
+    }
+
+    #[test]
+    fn test_parse_refresh_interval() {
+        assert_eq!(parse_refresh_interval(" 90 "), Ok(90));
+        assert_eq!(parse_refresh_interval(""), Ok(DEFAULT_REFRESH_SECS));
+        assert!(parse_refresh_interval("0").is_err());
+        assert!(parse_refresh_interval("-5").is_err());
+        assert!(parse_refresh_interval("soon").is_err());
+    }
+}
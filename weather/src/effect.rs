@@ -1,10 +1,20 @@
 //! Effects - side effects declared by the reducer
 
+use crate::config::Config;
+
 /// Side effects that can be triggered by actions
 #[derive(Debug, Clone)]
 pub enum Effect {
     /// Fetch weather data for the given coordinates
     FetchWeather { lat: f64, lon: f64 },
+    /// Fetch the hourly forecast used by the commute planner
+    FetchHourly { lat: f64, lon: f64 },
+    /// Fetch daily archive temperatures used by the garden planner
+    FetchArchive { lat: f64, lon: f64 },
+    /// Fetch ensemble member temperatures for the forecast spread panel
+    FetchEnsemble { lat: f64, lon: f64 },
     /// Search for cities matching the query
     SearchCities { query: String },
+    /// Write the config the setup wizard collected
+    SaveConfig { config: Config },
 }
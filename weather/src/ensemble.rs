@@ -0,0 +1,164 @@
+//! Forecast confidence from ensemble spread
+//!
+//! An ensemble model reruns the same forecast dozens of times from slightly
+//! perturbed starting conditions. Where the members agree the band between the
+//! coldest and warmest run stays narrow; where it fans out, the forecast for
+//! that hour is a guess.
+
+use crate::state::EnsembleBand;
+
+/// Open-Meteo model queried for members: DWD ICON, 40 members, global coverage
+pub const ENSEMBLE_MODEL: &str = "icon_seamless";
+
+/// Days of ensemble forecast shown (including today)
+pub const ENSEMBLE_DAYS: usize = 5;
+
+// Average spread (°C) at or below which the forecast is called high / medium
+// confidence; anything wider is low.
+const HIGH_CONFIDENCE_SPREAD: f32 = 2.5;
+const MEDIUM_CONFIDENCE_SPREAD: f32 = 5.0;
+
+/// Overall call on how far to trust the forecast
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Confidence {
+    High,
+    Medium,
+    Low,
+}
+
+impl EnsembleBand {
+    /// Distance between the coldest and warmest member, in °C
+    pub fn spread(&self) -> f32 {
+        self.max - self.min
+    }
+}
+
+/// Reduce parallel member series to one band per hour.
+///
+/// Members are matched to `times` by index; missing values (nulls past a
+/// member's horizon, or a short series) are ignored, and hours without any
+/// member value are dropped.
+pub fn bands(times: Vec<String>, members: &[Vec<Option<f32>>]) -> Vec<EnsembleBand> {
+    times
+        .into_iter()
+        .enumerate()
+        .filter_map(|(i, time)| {
+            let mut values: Vec<f32> = members
+                .iter()
+                .filter_map(|member| member.get(i).copied().flatten())
+                .filter(|value| value.is_finite())
+                .collect();
+            if values.is_empty() {
+                return None;
+            }
+            values.sort_by(f32::total_cmp);
+            Some(EnsembleBand {
+                time,
+                min: values[0],
+                median: median(&values),
+                max: values[values.len() - 1],
+            })
+        })
+        .collect()
+}
+
+fn median(sorted: &[f32]) -> f32 {
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2.0
+    } else {
+        sorted[mid]
+    }
+}
+
+/// Mean spread over all hours, in °C
+pub fn mean_spread(bands: &[EnsembleBand]) -> Option<f32> {
+    if bands.is_empty() {
+        return None;
+    }
+    Some(bands.iter().map(EnsembleBand::spread).sum::<f32>() / bands.len() as f32)
+}
+
+pub fn confidence(bands: &[EnsembleBand]) -> Option<Confidence> {
+    let spread = mean_spread(bands)?;
+    Some(if spread <= HIGH_CONFIDENCE_SPREAD {
+        Confidence::High
+    } else if spread <= MEDIUM_CONFIDENCE_SPREAD {
+        Confidence::Medium
+    } else {
+        Confidence::Low
+    })
+}
+
+/// Lowest member minimum and highest member maximum, for scaling a chart
+pub fn range(bands: &[EnsembleBand]) -> Option<(f32, f32)> {
+    let low = bands.iter().map(|band| band.min).reduce(f32::min)?;
+    let high = bands.iter().map(|band| band.max).reduce(f32::max)?;
+    Some((low, high))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn band(min: f32, max: f32) -> EnsembleBand {
+        EnsembleBand {
+            time: "2024-06-03T00:00".into(),
+            min,
+            median: (min + max) / 2.0,
+            max,
+        }
+    }
+
+    #[test]
+    fn test_bands_take_min_median_max_per_hour() {
+        let times = vec![
+            "2024-06-03T00:00".to_string(),
+            "2024-06-03T01:00".to_string(),
+            "2024-06-03T02:00".to_string(),
+        ];
+        let members = vec![
+            vec![Some(10.0), Some(12.0), None],
+            vec![Some(14.0), Some(11.0), None],
+            vec![Some(11.0)],
+            vec![Some(13.0), Some(f32::NAN), None],
+        ];
+
+        let bands = bands(times, &members);
+
+        assert_eq!(
+            bands,
+            vec![
+                EnsembleBand {
+                    time: "2024-06-03T00:00".into(),
+                    min: 10.0,
+                    median: 12.0,
+                    max: 14.0,
+                },
+                EnsembleBand {
+                    time: "2024-06-03T01:00".into(),
+                    min: 11.0,
+                    median: 11.5,
+                    max: 12.0,
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_confidence_follows_mean_spread() {
+        assert_eq!(confidence(&[]), None);
+        assert_eq!(confidence(&[band(10.0, 11.0)]), Some(Confidence::High));
+        assert_eq!(
+            confidence(&[band(10.0, 11.0), band(10.0, 18.0)]),
+            Some(Confidence::Medium)
+        );
+        assert_eq!(confidence(&[band(0.0, 9.0)]), Some(Confidence::Low));
+    }
+
+    #[test]
+    fn test_range() {
+        assert_eq!(range(&[]), None);
+        assert_eq!(range(&[band(3.0, 5.0), band(-1.0, 4.0)]), Some((-1.0, 5.0)));
+    }
+}
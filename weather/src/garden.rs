@@ -0,0 +1,361 @@
+//! Garden planner: growing degree days and frost dates from archive data
+//!
+//! Degree days accumulate from the start of the current growing season. Frost
+//! dates are medians over the last few complete years, which is how seed
+//! catalogues quote "average last frost" for a region.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::state::DailySample;
+
+/// Complete past years used for the frost date estimates
+pub const FROST_YEARS: i32 = 5;
+
+/// Daily minimum (°C) at or below which a day counts as a frost
+pub const FROST_THRESHOLD: f32 = 0.0;
+
+/// Day of year (non-leap) splitting the first half of the year from the second
+const MID_YEAR: u16 = 182;
+
+/// Cumulative days before each month in a non-leap year
+const MONTH_OFFSETS: [u16; 12] = [0, 31, 59, 90, 120, 151, 181, 212, 243, 273, 304, 334];
+
+/// Growing degree day settings
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct GardenConfig {
+    /// Base temperature in °C below which plants don't develop
+    pub base: f32,
+}
+
+impl Default for GardenConfig {
+    fn default() -> Self {
+        Self { base: 10.0 }
+    }
+}
+
+/// Parser for `--gdd-base`, in °C (e.g. `10`, or `4.5` for cool-season crops)
+pub fn parse_base(value: &str) -> Result<f32, String> {
+    let base: f32 = value
+        .trim()
+        .trim_end_matches("°C")
+        .parse()
+        .map_err(|_| format!("invalid base temperature '{value}'"))?;
+    if !(-10.0..=30.0).contains(&base) {
+        return Err(format!(
+            "base temperature {base} must be between -10 and 30 °C"
+        ));
+    }
+    Ok(base)
+}
+
+/// Degree days contributed by one day (simple averaging method).
+pub fn degree_days(sample: &DailySample, base: f32) -> f32 {
+    ((sample.temp_max + sample.temp_min) / 2.0 - base).max(0.0)
+}
+
+/// Split a `YYYY-MM-DD` date into year, month and day.
+fn parse_date(date: &str) -> Option<(i32, u16, u16)> {
+    let mut parts = date.splitn(3, '-');
+    let year = parts.next()?.parse().ok()?;
+    let month: u16 = parts.next()?.parse().ok()?;
+    let day: u16 = parts.next()?.parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+    Some((year, month, day))
+}
+
+/// 1-based day of year on a non-leap calendar, so dates line up across years.
+///
+/// 29 February shares a day with 28 February.
+pub fn day_of_year(date: &str) -> Option<u16> {
+    let (_, month, day) = parse_date(date)?;
+    Some(MONTH_OFFSETS[month as usize - 1] + day.min(28 + (month != 2) as u16 * 3))
+}
+
+/// `MM-DD` label for a non-leap day of year.
+pub fn month_day(day_of_year: u16) -> String {
+    let day_of_year = day_of_year.clamp(1, 365);
+    let month = MONTH_OFFSETS
+        .iter()
+        .rposition(|&offset| offset < day_of_year)
+        .unwrap_or(0);
+    format!("{:02}-{:02}", month + 1, day_of_year - MONTH_OFFSETS[month])
+}
+
+/// Civil `YYYY-MM-DD` date for a count of days since 1970-01-01.
+pub fn civil_date(days_since_epoch: i64) -> String {
+    // Howard Hinnant's days-to-civil algorithm
+    let z = days_since_epoch + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + (month <= 2) as i64;
+    format!("{year:04}-{month:02}-{day:02}")
+}
+
+/// First day of the growing season containing `date`.
+///
+/// Northern seasons run from 1 January, southern ones from 1 July.
+pub fn season_start(date: &str, southern: bool) -> Option<String> {
+    let (year, month, _) = parse_date(date)?;
+    Some(match (southern, month >= 7) {
+        (false, _) => format!("{year:04}-01-01"),
+        (true, true) => format!("{year:04}-07-01"),
+        (true, false) => format!("{:04}-07-01", year - 1),
+    })
+}
+
+/// Median last and first frost days of year over complete archive years
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct FrostDates {
+    /// Last frost before the warm season, as a non-leap day of year
+    pub last_spring: Option<u16>,
+    /// First frost after the warm season, as a non-leap day of year
+    pub first_autumn: Option<u16>,
+    /// Complete years the medians were taken over
+    pub years: usize,
+}
+
+impl FrostDates {
+    /// Days between the median last and first frosts.
+    pub fn frost_free_days(&self) -> Option<u16> {
+        let (last, first) = (self.last_spring?, self.first_autumn?);
+        if first > last {
+            Some(first - last)
+        } else {
+            // Southern hemisphere: the warm season wraps the new year
+            Some(first + 365 - last)
+        }
+    }
+}
+
+fn median(mut values: Vec<u16>) -> Option<u16> {
+    values.sort_unstable();
+    let mid = values.len() / 2;
+    match values.len() {
+        0 => None,
+        len if len % 2 == 0 => Some((values[mid - 1] + values[mid]) / 2),
+        _ => Some(values[mid]),
+    }
+}
+
+/// Estimate frost dates from every complete calendar year in `days`.
+///
+/// The year of the most recent sample is still in progress and is left out.
+/// Years without a frost on one side of the warm season don't count towards
+/// that side's median.
+pub fn frost_dates(days: &[DailySample], southern: bool) -> FrostDates {
+    let Some(current_year) = days.last().and_then(|day| parse_date(&day.date)) else {
+        return FrostDates::default();
+    };
+    let current_year = current_year.0;
+
+    let mut years: Vec<i32> = Vec::new();
+    let mut lasts: Vec<u16> = Vec::new();
+    let mut firsts: Vec<u16> = Vec::new();
+    let mut year_last: Option<u16> = None;
+    let mut year_first: Option<u16> = None;
+    for sample in days {
+        let Some((year, _, _)) = parse_date(&sample.date) else {
+            continue;
+        };
+        if year >= current_year {
+            break;
+        }
+        if years.last() != Some(&year) {
+            lasts.extend(year_last.take());
+            firsts.extend(year_first.take());
+            years.push(year);
+        }
+        if sample.temp_min > FROST_THRESHOLD {
+            continue;
+        }
+        let Some(day) = day_of_year(&sample.date) else {
+            continue;
+        };
+        // North: spring frosts in the first half, autumn frosts in the second.
+        // South: the other way round.
+        if (day < MID_YEAR) != southern {
+            year_last = Some(day);
+        } else if year_first.is_none() {
+            year_first = Some(day);
+        }
+    }
+    lasts.extend(year_last);
+    firsts.extend(year_first);
+
+    FrostDates {
+        last_spring: median(lasts),
+        first_autumn: median(firsts),
+        years: years.len(),
+    }
+}
+
+/// Everything the garden panel shows
+#[derive(Clone, Debug, PartialEq)]
+pub struct GardenSummary {
+    /// First day of the current season, `YYYY-MM-DD`
+    pub season_start: String,
+    /// Most recent archive day included, `YYYY-MM-DD`
+    pub through: String,
+    /// Degree days (°C) accumulated since `season_start`
+    pub degree_days: f32,
+    pub frost: FrostDates,
+}
+
+/// Summarize the archive for a location; `None` until there is any data.
+pub fn summarize(
+    days: &[DailySample],
+    config: GardenConfig,
+    southern: bool,
+) -> Option<GardenSummary> {
+    let through = days.last()?.date.clone();
+    let season_start = season_start(&through, southern)?;
+    let degree_days = days
+        .iter()
+        .filter(|day| day.date >= season_start)
+        .map(|day| degree_days(day, config.base))
+        .sum();
+    Some(GardenSummary {
+        season_start,
+        through,
+        degree_days,
+        frost: frost_dates(days, southern),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn day(date: &str, temp_min: f32, temp_max: f32) -> DailySample {
+        DailySample {
+            date: date.into(),
+            temp_min,
+            temp_max,
+        }
+    }
+
+    /// A year of mild days with frosts on the given `MM-DD` dates.
+    fn year_with_frosts(year: i32, frosts: &[&str]) -> Vec<DailySample> {
+        (0..365)
+            .map(|offset| {
+                let date = format!("{year}-{}", month_day(offset + 1));
+                let cold = frosts.iter().any(|frost| date.ends_with(frost));
+                day(&date, if cold { -2.0 } else { 5.0 }, 15.0)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_parse_base() {
+        assert_eq!(parse_base("10"), Ok(10.0));
+        assert_eq!(parse_base(" 4.5 "), Ok(4.5));
+        assert_eq!(parse_base("8°C"), Ok(8.0));
+        assert!(parse_base("45").is_err());
+        assert!(parse_base("warm").is_err());
+    }
+
+    #[test]
+    fn test_degree_days() {
+        assert_eq!(degree_days(&day("2024-06-01", 12.0, 24.0), 10.0), 8.0);
+        assert_eq!(degree_days(&day("2024-06-01", 2.0, 14.0), 10.0), 0.0);
+        assert_eq!(degree_days(&day("2024-06-01", 2.0, 14.0), 5.0), 3.0);
+    }
+
+    #[test]
+    fn test_day_of_year_round_trip() {
+        assert_eq!(day_of_year("2023-01-01"), Some(1));
+        assert_eq!(day_of_year("2023-03-01"), Some(60));
+        assert_eq!(day_of_year("2024-02-29"), Some(59));
+        assert_eq!(day_of_year("2023-12-31"), Some(365));
+        assert_eq!(day_of_year("2023-13-01"), None);
+        for day in [1, 59, 60, 181, 182, 365] {
+            let date = format!("2023-{}", month_day(day));
+            assert_eq!(day_of_year(&date), Some(day), "{date}");
+        }
+    }
+
+    #[test]
+    fn test_civil_date() {
+        assert_eq!(civil_date(0), "1970-01-01");
+        assert_eq!(civil_date(19_782), "2024-02-29");
+        assert_eq!(civil_date(20_088), "2024-12-31");
+        assert_eq!(civil_date(-1), "1969-12-31");
+    }
+
+    #[test]
+    fn test_season_start_by_hemisphere() {
+        assert_eq!(
+            season_start("2024-05-10", false).as_deref(),
+            Some("2024-01-01")
+        );
+        assert_eq!(
+            season_start("2024-05-10", true).as_deref(),
+            Some("2023-07-01")
+        );
+        assert_eq!(
+            season_start("2024-09-10", true).as_deref(),
+            Some("2024-07-01")
+        );
+    }
+
+    #[test]
+    fn test_frost_dates_take_median_of_complete_years() {
+        let mut days = Vec::new();
+        days.extend(year_with_frosts(2021, &["04-02", "04-20", "10-15"]));
+        days.extend(year_with_frosts(2022, &["04-10", "10-01", "11-20"]));
+        days.extend(year_with_frosts(2023, &["03-30", "10-25"]));
+        // The current year is incomplete and ignored
+        days.extend(year_with_frosts(2024, &["06-01"]));
+
+        let frost = frost_dates(&days, false);
+        assert_eq!(frost.years, 3);
+        assert_eq!(frost.last_spring.map(month_day).as_deref(), Some("04-10"));
+        assert_eq!(frost.first_autumn.map(month_day).as_deref(), Some("10-15"));
+        assert_eq!(frost.frost_free_days(), Some(288 - 100));
+    }
+
+    #[test]
+    fn test_frost_dates_southern_hemisphere() {
+        let mut days = Vec::new();
+        days.extend(year_with_frosts(2022, &["05-20", "07-10", "09-05"]));
+        days.extend(year_with_frosts(2023, &["05-10", "09-15"]));
+        days.extend(year_with_frosts(2024, &[]));
+
+        let frost = frost_dates(&days, true);
+        assert_eq!(frost.first_autumn.map(month_day).as_deref(), Some("05-15"));
+        assert_eq!(frost.last_spring.map(month_day).as_deref(), Some("09-10"));
+        assert_eq!(frost.frost_free_days(), Some(135 + 365 - 253));
+    }
+
+    #[test]
+    fn test_frost_free_years_have_no_estimate() {
+        let days = [year_with_frosts(2022, &[]), year_with_frosts(2023, &[])].concat();
+        let frost = frost_dates(&days, false);
+        assert_eq!(frost.years, 1);
+        assert_eq!(frost.last_spring, None);
+        assert_eq!(frost.frost_free_days(), None);
+    }
+
+    #[test]
+    fn test_summarize_accumulates_current_season() {
+        let days = vec![
+            day("2023-12-31", 20.0, 30.0),
+            day("2024-01-01", 10.0, 20.0),
+            day("2024-01-02", 14.0, 26.0),
+        ];
+        let summary = summarize(&days, GardenConfig::default(), false).unwrap();
+        assert_eq!(summary.season_start, "2024-01-01");
+        assert_eq!(summary.through, "2024-01-02");
+        assert_eq!(summary.degree_days, 5.0 + 10.0);
+
+        assert_eq!(summarize(&[], GardenConfig::default(), false), None);
+    }
+}
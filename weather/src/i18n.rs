@@ -0,0 +1,606 @@
+//! Localized strings for weather conditions and UI chrome
+//!
+//! Components look strings up here instead of embedding English text, so the
+//! language can change at runtime without refetching weather data.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+/// Supported display languages
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Default, Serialize, Deserialize, JsonSchema)]
+pub enum Language {
+    #[default]
+    English,
+    Spanish,
+    German,
+    French,
+    Ukrainian,
+}
+
+impl Language {
+    pub const ALL: [Language; 5] = [
+        Language::English,
+        Language::Spanish,
+        Language::German,
+        Language::French,
+        Language::Ukrainian,
+    ];
+
+    /// ISO 639-1 code, as accepted by `--lang`
+    pub fn code(self) -> &'static str {
+        match self {
+            Language::English => "en",
+            Language::Spanish => "es",
+            Language::German => "de",
+            Language::French => "fr",
+            Language::Ukrainian => "uk",
+        }
+    }
+
+    pub fn from_code(code: &str) -> Option<Self> {
+        let code = code.trim().to_ascii_lowercase();
+        Self::ALL.into_iter().find(|lang| lang.code() == code)
+    }
+
+    /// Next language in the runtime cycle
+    pub fn next(self) -> Self {
+        let index = Self::ALL.iter().position(|&lang| lang == self).unwrap_or(0);
+        Self::ALL[(index + 1) % Self::ALL.len()]
+    }
+}
+
+/// Parser for the `--lang` CLI flag
+pub fn parse_language(value: &str) -> Result<Language, String> {
+    Language::from_code(value).ok_or_else(|| {
+        let codes: Vec<_> = Language::ALL.iter().map(|lang| lang.code()).collect();
+        format!(
+            "unknown language '{value}' (expected one of: {})",
+            codes.join(", ")
+        )
+    })
+}
+
+/// UI strings that are not weather descriptions
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Text {
+    Refresh,
+    Search,
+    Units,
+    Lang,
+    Quit,
+    Loading,
+    Error,
+    /// Contains a `{key}` marker where the key is highlighted
+    FetchPrompt,
+    /// Contains a `{key}` marker where the key is highlighted
+    RetryPrompt,
+    SearchPlaceholder,
+    SetupTitle,
+    SetupCity,
+    SetupUnits,
+    SetupRefresh,
+    Next,
+    Back,
+    Commute,
+    Morning,
+    Evening,
+    VerdictGood,
+    VerdictFair,
+    VerdictPoor,
+    Garden,
+    DegreeDays,
+    Base,
+    Since,
+    LastFrost,
+    FirstFrost,
+    FrostFree,
+    Days,
+    /// Contains an `{n}` marker for the number of years
+    FrostYears,
+    NoFrost,
+    Spread,
+    Confidence,
+    ConfidenceHigh,
+    ConfidenceMedium,
+    ConfidenceLow,
+    /// Contains an `{n}` marker for the mean spread
+    MeanSpread,
+    /// Contains a `{when}` marker for the day part
+    PressureFalling,
+    /// Contains a `{when}` marker for the day part
+    PressureFallingFast,
+    /// Contains a `{when}` marker for the day part
+    PressureRising,
+    /// Contains a `{when}` marker for the day part
+    PressureRisingFast,
+    ThisMorning,
+    ThisAfternoon,
+    ThisEvening,
+    Overnight,
+    TomorrowMorning,
+    TomorrowAfternoon,
+    TomorrowEvening,
+    Updating,
+    /// Contains a `{city}` marker for the nearby city
+    EstimateFrom,
+}
+
+/// Translate a UI string.
+pub fn text(key: Text, lang: Language) -> &'static str {
+    use Language::*;
+    match (key, lang) {
+        (Text::Refresh, English) => "refresh",
+        (Text::Refresh, Spanish) => "actualizar",
+        (Text::Refresh, German) => "aktualisieren",
+        (Text::Refresh, French) => "actualiser",
+        (Text::Refresh, Ukrainian) => "оновити",
+
+        (Text::Search, English) => "search",
+        (Text::Search, Spanish) => "buscar",
+        (Text::Search, German) => "suchen",
+        (Text::Search, French) => "chercher",
+        (Text::Search, Ukrainian) => "пошук",
+
+        (Text::Units, English) => "units",
+        (Text::Units, Spanish) => "unidades",
+        (Text::Units, German) => "Einheiten",
+        (Text::Units, French) => "unités",
+        (Text::Units, Ukrainian) => "одиниці",
+
+        (Text::Lang, English) => "lang",
+        (Text::Lang, Spanish) => "idioma",
+        (Text::Lang, German) => "Sprache",
+        (Text::Lang, French) => "langue",
+        (Text::Lang, Ukrainian) => "мова",
+
+        (Text::Quit, English) => "quit",
+        (Text::Quit, Spanish) => "salir",
+        (Text::Quit, German) => "beenden",
+        (Text::Quit, French) => "quitter",
+        (Text::Quit, Ukrainian) => "вийти",
+
+        (Text::Loading, English) => " loading ",
+        (Text::Loading, Spanish) => " cargando ",
+        (Text::Loading, German) => " lädt ",
+        (Text::Loading, French) => " chargement ",
+        (Text::Loading, Ukrainian) => " завантаження ",
+
+        (Text::Error, English) => "Error",
+        (Text::Error, Spanish) => "Error",
+        (Text::Error, German) => "Fehler",
+        (Text::Error, French) => "Erreur",
+        (Text::Error, Ukrainian) => "Помилка",
+
+        (Text::FetchPrompt, English) => "Press {key} to fetch weather",
+        (Text::FetchPrompt, Spanish) => "Pulsa {key} para obtener el tiempo",
+        (Text::FetchPrompt, German) => "Drücke {key}, um das Wetter abzurufen",
+        (Text::FetchPrompt, French) => "Appuyez sur {key} pour obtenir la météo",
+        (Text::FetchPrompt, Ukrainian) => "Натисніть {key}, щоб отримати погоду",
+
+        (Text::RetryPrompt, English) => "Press {key} to retry",
+        (Text::RetryPrompt, Spanish) => "Pulsa {key} para reintentar",
+        (Text::RetryPrompt, German) => "Drücke {key} für einen neuen Versuch",
+        (Text::RetryPrompt, French) => "Appuyez sur {key} pour réessayer",
+        (Text::RetryPrompt, Ukrainian) => "Натисніть {key}, щоб повторити",
+
+        (Text::SearchPlaceholder, English) => "Search for a city...",
+        (Text::SearchPlaceholder, Spanish) => "Buscar una ciudad...",
+        (Text::SearchPlaceholder, German) => "Stadt suchen...",
+        (Text::SearchPlaceholder, French) => "Rechercher une ville...",
+        (Text::SearchPlaceholder, Ukrainian) => "Пошук міста...",
+
+        (Text::SetupTitle, English) => "First-run setup",
+        (Text::SetupTitle, Spanish) => "Configuración inicial",
+        (Text::SetupTitle, German) => "Ersteinrichtung",
+        (Text::SetupTitle, French) => "Configuration initiale",
+        (Text::SetupTitle, Ukrainian) => "Початкове налаштування",
+
+        (Text::SetupCity, English) => "Default city",
+        (Text::SetupCity, Spanish) => "Ciudad predeterminada",
+        (Text::SetupCity, German) => "Standardstadt",
+        (Text::SetupCity, French) => "Ville par défaut",
+        (Text::SetupCity, Ukrainian) => "Місто за замовчуванням",
+
+        (Text::SetupUnits, English) => "Temperature units",
+        (Text::SetupUnits, Spanish) => "Unidades de temperatura",
+        (Text::SetupUnits, German) => "Temperatureinheit",
+        (Text::SetupUnits, French) => "Unités de température",
+        (Text::SetupUnits, Ukrainian) => "Одиниці температури",
+
+        (Text::SetupRefresh, English) => "Refresh every (seconds)",
+        (Text::SetupRefresh, Spanish) => "Actualizar cada (segundos)",
+        (Text::SetupRefresh, German) => "Aktualisieren alle (Sekunden)",
+        (Text::SetupRefresh, French) => "Actualiser toutes les (secondes)",
+        (Text::SetupRefresh, Ukrainian) => "Оновлювати кожні (секунд)",
+
+        (Text::Next, English) => "next",
+        (Text::Next, Spanish) => "siguiente",
+        (Text::Next, German) => "weiter",
+        (Text::Next, French) => "suivant",
+        (Text::Next, Ukrainian) => "далі",
+
+        (Text::Back, English) => "back",
+        (Text::Back, Spanish) => "atrás",
+        (Text::Back, German) => "zurück",
+        (Text::Back, French) => "retour",
+        (Text::Back, Ukrainian) => "назад",
+
+        (Text::Commute, English) => "commute",
+        (Text::Commute, Spanish) => "trayecto",
+        (Text::Commute, German) => "Pendeln",
+        (Text::Commute, French) => "trajet",
+        (Text::Commute, Ukrainian) => "дорога",
+
+        (Text::Morning, English) => "Morning",
+        (Text::Morning, Spanish) => "Mañana",
+        (Text::Morning, German) => "Morgens",
+        (Text::Morning, French) => "Matin",
+        (Text::Morning, Ukrainian) => "Ранок",
+
+        (Text::Evening, English) => "Evening",
+        (Text::Evening, Spanish) => "Tarde",
+        (Text::Evening, German) => "Abends",
+        (Text::Evening, French) => "Soir",
+        (Text::Evening, Ukrainian) => "Вечір",
+
+        (Text::VerdictGood, English) => "good",
+        (Text::VerdictGood, Spanish) => "bien",
+        (Text::VerdictGood, German) => "gut",
+        (Text::VerdictGood, French) => "bon",
+        (Text::VerdictGood, Ukrainian) => "добре",
+
+        (Text::VerdictFair, English) => "fair",
+        (Text::VerdictFair, Spanish) => "regular",
+        (Text::VerdictFair, German) => "mäßig",
+        (Text::VerdictFair, French) => "moyen",
+        (Text::VerdictFair, Ukrainian) => "так собі",
+
+        (Text::VerdictPoor, English) => "poor",
+        (Text::VerdictPoor, Spanish) => "mal",
+        (Text::VerdictPoor, German) => "schlecht",
+        (Text::VerdictPoor, French) => "mauvais",
+        (Text::VerdictPoor, Ukrainian) => "погано",
+
+        (Text::Garden, English) => "garden",
+        (Text::Garden, Spanish) => "huerto",
+        (Text::Garden, German) => "Garten",
+        (Text::Garden, French) => "jardin",
+        (Text::Garden, Ukrainian) => "город",
+
+        (Text::DegreeDays, English) => "Growing degree days",
+        (Text::DegreeDays, Spanish) => "Grados-día de crecimiento",
+        (Text::DegreeDays, German) => "Wachstumsgradtage",
+        (Text::DegreeDays, French) => "Degrés-jours de croissance",
+        (Text::DegreeDays, Ukrainian) => "Градусо-дні вегетації",
+
+        (Text::Base, English) => "base",
+        (Text::Base, Spanish) => "base",
+        (Text::Base, German) => "Basis",
+        (Text::Base, French) => "base",
+        (Text::Base, Ukrainian) => "база",
+
+        (Text::Since, English) => "since",
+        (Text::Since, Spanish) => "desde",
+        (Text::Since, German) => "seit",
+        (Text::Since, French) => "depuis",
+        (Text::Since, Ukrainian) => "з",
+
+        (Text::LastFrost, English) => "Last spring frost",
+        (Text::LastFrost, Spanish) => "Última helada primaveral",
+        (Text::LastFrost, German) => "Letzter Frühjahrsfrost",
+        (Text::LastFrost, French) => "Dernière gelée printanière",
+        (Text::LastFrost, Ukrainian) => "Останній весняний заморозок",
+
+        (Text::FirstFrost, English) => "First autumn frost",
+        (Text::FirstFrost, Spanish) => "Primera helada otoñal",
+        (Text::FirstFrost, German) => "Erster Herbstfrost",
+        (Text::FirstFrost, French) => "Première gelée automnale",
+        (Text::FirstFrost, Ukrainian) => "Перший осінній заморозок",
+
+        (Text::FrostFree, English) => "Frost-free season",
+        (Text::FrostFree, Spanish) => "Temporada sin heladas",
+        (Text::FrostFree, German) => "Frostfreie Zeit",
+        (Text::FrostFree, French) => "Saison sans gel",
+        (Text::FrostFree, Ukrainian) => "Безморозний період",
+
+        (Text::Days, English) => "days",
+        (Text::Days, Spanish) => "días",
+        (Text::Days, German) => "Tage",
+        (Text::Days, French) => "jours",
+        (Text::Days, Ukrainian) => "дн.",
+
+        (Text::FrostYears, English) => "median of the last {n} years",
+        (Text::FrostYears, Spanish) => "mediana de los últimos {n} años",
+        (Text::FrostYears, German) => "Median der letzten {n} Jahre",
+        (Text::FrostYears, French) => "médiane des {n} dernières années",
+        (Text::FrostYears, Ukrainian) => "медіана за останні {n} р.",
+
+        (Text::NoFrost, English) => "none recorded",
+        (Text::NoFrost, Spanish) => "sin registros",
+        (Text::NoFrost, German) => "keiner erfasst",
+        (Text::NoFrost, French) => "aucune relevée",
+        (Text::NoFrost, Ukrainian) => "не зафіксовано",
+
+        (Text::Spread, English) => "spread",
+        (Text::Spread, Spanish) => "dispersión",
+        (Text::Spread, German) => "Streuung",
+        (Text::Spread, French) => "dispersion",
+        (Text::Spread, Ukrainian) => "розкид",
+
+        (Text::Confidence, English) => "Confidence",
+        (Text::Confidence, Spanish) => "Confianza",
+        (Text::Confidence, German) => "Verlässlichkeit",
+        (Text::Confidence, French) => "Confiance",
+        (Text::Confidence, Ukrainian) => "Впевненість",
+
+        (Text::ConfidenceHigh, English) => "high",
+        (Text::ConfidenceHigh, Spanish) => "alta",
+        (Text::ConfidenceHigh, German) => "hoch",
+        (Text::ConfidenceHigh, French) => "élevée",
+        (Text::ConfidenceHigh, Ukrainian) => "висока",
+
+        (Text::ConfidenceMedium, English) => "medium",
+        (Text::ConfidenceMedium, Spanish) => "media",
+        (Text::ConfidenceMedium, German) => "mittel",
+        (Text::ConfidenceMedium, French) => "moyenne",
+        (Text::ConfidenceMedium, Ukrainian) => "середня",
+
+        (Text::ConfidenceLow, English) => "low",
+        (Text::ConfidenceLow, Spanish) => "baja",
+        (Text::ConfidenceLow, German) => "niedrig",
+        (Text::ConfidenceLow, French) => "faible",
+        (Text::ConfidenceLow, Ukrainian) => "низька",
+
+        (Text::MeanSpread, English) => "members disagree by {n} on average",
+        (Text::MeanSpread, Spanish) => "los miembros difieren {n} de media",
+        (Text::MeanSpread, German) => "Mitglieder weichen im Mittel um {n} ab",
+        (Text::MeanSpread, French) => "écart moyen entre membres de {n}",
+        (Text::MeanSpread, Ukrainian) => "члени ансамблю розходяться в середньому на {n}",
+
+        (Text::PressureFalling, English) => "pressure drop expected {when}",
+        (Text::PressureFalling, Spanish) => "se espera una bajada de presión {when}",
+        (Text::PressureFalling, German) => "Druckabfall {when} erwartet",
+        (Text::PressureFalling, French) => "baisse de pression attendue {when}",
+        (Text::PressureFalling, Ukrainian) => "очікується падіння тиску {when}",
+
+        (Text::PressureFallingFast, English) => "rapid pressure drop expected {when}",
+        (Text::PressureFallingFast, Spanish) => "se espera una bajada brusca de presión {when}",
+        (Text::PressureFallingFast, German) => "starker Druckabfall {when} erwartet",
+        (Text::PressureFallingFast, French) => "forte baisse de pression attendue {when}",
+        (Text::PressureFallingFast, Ukrainian) => "очікується різке падіння тиску {when}",
+
+        (Text::PressureRising, English) => "pressure rise expected {when}",
+        (Text::PressureRising, Spanish) => "se espera una subida de presión {when}",
+        (Text::PressureRising, German) => "Druckanstieg {when} erwartet",
+        (Text::PressureRising, French) => "hausse de pression attendue {when}",
+        (Text::PressureRising, Ukrainian) => "очікується зростання тиску {when}",
+
+        (Text::PressureRisingFast, English) => "rapid pressure rise expected {when}",
+        (Text::PressureRisingFast, Spanish) => "se espera una subida brusca de presión {when}",
+        (Text::PressureRisingFast, German) => "starker Druckanstieg {when} erwartet",
+        (Text::PressureRisingFast, French) => "forte hausse de pression attendue {when}",
+        (Text::PressureRisingFast, Ukrainian) => "очікується різке зростання тиску {when}",
+
+        (Text::ThisMorning, English) => "this morning",
+        (Text::ThisMorning, Spanish) => "esta mañana",
+        (Text::ThisMorning, German) => "heute Morgen",
+        (Text::ThisMorning, French) => "ce matin",
+        (Text::ThisMorning, Ukrainian) => "сьогодні вранці",
+
+        (Text::ThisAfternoon, English) => "this afternoon",
+        (Text::ThisAfternoon, Spanish) => "esta tarde",
+        (Text::ThisAfternoon, German) => "heute Nachmittag",
+        (Text::ThisAfternoon, French) => "cet après-midi",
+        (Text::ThisAfternoon, Ukrainian) => "сьогодні вдень",
+
+        (Text::ThisEvening, English) => "this evening",
+        (Text::ThisEvening, Spanish) => "esta noche",
+        (Text::ThisEvening, German) => "heute Abend",
+        (Text::ThisEvening, French) => "ce soir",
+        (Text::ThisEvening, Ukrainian) => "сьогодні ввечері",
+
+        (Text::Overnight, English) => "overnight",
+        (Text::Overnight, Spanish) => "de madrugada",
+        (Text::Overnight, German) => "in der Nacht",
+        (Text::Overnight, French) => "cette nuit",
+        (Text::Overnight, Ukrainian) => "вночі",
+
+        (Text::TomorrowMorning, English) => "tomorrow morning",
+        (Text::TomorrowMorning, Spanish) => "mañana por la mañana",
+        (Text::TomorrowMorning, German) => "morgen früh",
+        (Text::TomorrowMorning, French) => "demain matin",
+        (Text::TomorrowMorning, Ukrainian) => "завтра вранці",
+
+        (Text::TomorrowAfternoon, English) => "tomorrow afternoon",
+        (Text::TomorrowAfternoon, Spanish) => "mañana por la tarde",
+        (Text::TomorrowAfternoon, German) => "morgen Nachmittag",
+        (Text::TomorrowAfternoon, French) => "demain après-midi",
+        (Text::TomorrowAfternoon, Ukrainian) => "завтра вдень",
+
+        (Text::TomorrowEvening, English) => "tomorrow evening",
+        (Text::TomorrowEvening, Spanish) => "mañana por la noche",
+        (Text::TomorrowEvening, German) => "morgen Abend",
+        (Text::TomorrowEvening, French) => "demain soir",
+        (Text::TomorrowEvening, Ukrainian) => "завтра ввечері",
+
+        (Text::Updating, English) => "updating…",
+        (Text::Updating, Spanish) => "actualizando…",
+        (Text::Updating, German) => "wird aktualisiert…",
+        (Text::Updating, French) => "mise à jour…",
+        (Text::Updating, Ukrainian) => "оновлення…",
+
+        (Text::EstimateFrom, English) => "estimate from {city}",
+        (Text::EstimateFrom, Spanish) => "estimación según {city}",
+        (Text::EstimateFrom, German) => "geschätzt nach {city}",
+        (Text::EstimateFrom, French) => "estimation d'après {city}",
+        (Text::EstimateFrom, Ukrainian) => "оцінка за {city}",
+    }
+}
+
+/// Split a prompt around its `{key}` marker: (before, after).
+pub fn split_prompt(prompt: &'static str) -> (&'static str, &'static str) {
+    prompt.split_once("{key}").unwrap_or((prompt, ""))
+}
+
+/// Short weekday name, 0 = Sunday.
+pub fn weekday(index: usize, lang: Language) -> &'static str {
+    let names = match lang {
+        Language::English => ["Sun", "Mon", "Tue", "Wed", "Thu", "Fri", "Sat"],
+        Language::Spanish => ["dom", "lun", "mar", "mié", "jue", "vie", "sáb"],
+        Language::German => ["So", "Mo", "Di", "Mi", "Do", "Fr", "Sa"],
+        Language::French => ["dim", "lun", "mar", "mer", "jeu", "ven", "sam"],
+        Language::Ukrainian => ["Нд", "Пн", "Вт", "Ср", "Чт", "Пт", "Сб"],
+    };
+    names[index % names.len()]
+}
+
+/// Describe a WMO weather code in the given language.
+pub fn describe(code: u8, lang: Language) -> &'static str {
+    let [en, es, de, fr, uk] = match code {
+        0 => [
+            "Clear sky",
+            "Cielo despejado",
+            "Klarer Himmel",
+            "Ciel dégagé",
+            "Ясне небо",
+        ],
+        1 => [
+            "Mainly clear",
+            "Mayormente despejado",
+            "Überwiegend klar",
+            "Plutôt dégagé",
+            "Переважно ясно",
+        ],
+        2 => [
+            "Partly cloudy",
+            "Parcialmente nublado",
+            "Teilweise bewölkt",
+            "Partiellement nuageux",
+            "Мінлива хмарність",
+        ],
+        3 => ["Overcast", "Cubierto", "Bedeckt", "Couvert", "Похмуро"],
+        45 | 48 => ["Fog", "Niebla", "Nebel", "Brouillard", "Туман"],
+        51 | 53 | 55 => ["Drizzle", "Llovizna", "Nieselregen", "Bruine", "Мряка"],
+        56 | 57 => [
+            "Freezing drizzle",
+            "Llovizna helada",
+            "Gefrierender Nieselregen",
+            "Bruine verglaçante",
+            "Крижана мряка",
+        ],
+        61 | 63 | 65 => ["Rain", "Lluvia", "Regen", "Pluie", "Дощ"],
+        66 | 67 => [
+            "Freezing rain",
+            "Lluvia helada",
+            "Gefrierender Regen",
+            "Pluie verglaçante",
+            "Крижаний дощ",
+        ],
+        71 | 73 | 75 => ["Snow", "Nieve", "Schnee", "Neige", "Сніг"],
+        77 => [
+            "Snow grains",
+            "Granos de nieve",
+            "Schneegriesel",
+            "Neige en grains",
+            "Снігова крупа",
+        ],
+        80..=82 => [
+            "Rain showers",
+            "Chubascos",
+            "Regenschauer",
+            "Averses de pluie",
+            "Зливи",
+        ],
+        85 | 86 => [
+            "Snow showers",
+            "Chubascos de nieve",
+            "Schneeschauer",
+            "Averses de neige",
+            "Снігопади",
+        ],
+        95 => ["Thunderstorm", "Tormenta", "Gewitter", "Orage", "Гроза"],
+        96 | 99 => [
+            "Thunderstorm with hail",
+            "Tormenta con granizo",
+            "Gewitter mit Hagel",
+            "Orage avec grêle",
+            "Гроза з градом",
+        ],
+        _ => ["Unknown", "Desconocido", "Unbekannt", "Inconnu", "Невідомо"],
+    };
+    match lang {
+        Language::English => en,
+        Language::Spanish => es,
+        Language::German => de,
+        Language::French => fr,
+        Language::Ukrainian => uk,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_language_codes_round_trip() {
+        for lang in Language::ALL {
+            assert_eq!(Language::from_code(lang.code()), Some(lang));
+        }
+        assert_eq!(Language::from_code(" DE "), Some(Language::German));
+        assert_eq!(Language::from_code("xx"), None);
+        assert!(parse_language("xx").unwrap_err().contains("en, es"));
+    }
+
+    #[test]
+    fn test_language_cycle_visits_all() {
+        let mut lang = Language::English;
+        for _ in 0..Language::ALL.len() {
+            lang = lang.next();
+        }
+        assert_eq!(lang, Language::English);
+    }
+
+    #[test]
+    fn test_describe_localizes_codes() {
+        assert_eq!(describe(0, Language::English), "Clear sky");
+        assert_eq!(describe(63, Language::German), "Regen");
+        assert_eq!(describe(200, Language::French), "Inconnu");
+    }
+
+    #[test]
+    fn test_prompts_have_key_marker() {
+        for lang in Language::ALL {
+            for prompt in [Text::FetchPrompt, Text::RetryPrompt] {
+                let (before, _) = split_prompt(text(prompt, lang));
+                assert_ne!(before, text(prompt, lang), "{prompt:?} in {lang:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_estimate_has_city_marker() {
+        for lang in Language::ALL {
+            assert!(
+                text(Text::EstimateFrom, lang).contains("{city}"),
+                "{lang:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_pressure_advisories_have_when_marker() {
+        for lang in Language::ALL {
+            for advisory in [
+                Text::PressureFalling,
+                Text::PressureFallingFast,
+                Text::PressureRising,
+                Text::PressureRisingFast,
+            ] {
+                assert!(
+                    text(advisory, lang).contains("{when}"),
+                    "{advisory:?} in {lang:?}"
+                );
+            }
+        }
+    }
+}
@@ -4,8 +4,19 @@
 
 pub mod action;
 pub mod api;
+pub mod cache;
+pub mod commute;
 pub mod components;
+pub mod config;
 pub mod effect;
+pub mod ensemble;
+pub mod garden;
+pub mod i18n;
+pub mod particles;
+pub mod pressure;
+pub mod quota;
 pub mod reducer;
 pub mod sprites;
 pub mod state;
+pub mod theme;
+pub mod wizard;
@@ -1,11 +1,12 @@
 //! Weather TUI - tui-dispatch example
 
 use std::cell::RefCell;
+use std::collections::VecDeque;
 use std::io;
 use std::rc::Rc;
 use std::time::Duration;
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use crossterm::{
     event::{DisableMouseCapture, EnableMouseCapture},
     execute,
@@ -13,8 +14,8 @@ use crossterm::{
 };
 use ratatui::{Frame, Terminal, backend::CrosstermBackend, layout::Rect};
 use tui_dispatch::{
-    EffectContext, EffectStoreLike, EffectStoreWithMiddleware, EventBus, EventContext, EventKind,
-    EventRoutingState, HandlerResponse, Keybindings, RenderContext, TaskKey,
+    EffectContext, EffectStore, EffectStoreLike, EffectStoreWithMiddleware, EventBus, EventContext,
+    EventKind, EventRoutingState, HandlerResponse, Keybindings, RenderContext, TaskKey,
 };
 use tui_dispatch_components::centered_rect;
 use tui_dispatch_debug::debug::DebugLayer;
@@ -24,45 +25,126 @@ use tui_dispatch_debug::{
 use weather::action::Action;
 use weather::api;
 use weather::api::GeocodingError;
+use weather::commute::{self, CommuteConfig, TimeWindow};
 use weather::components::{
-    Component, SearchOverlay, SearchOverlayProps, WeatherDisplay, WeatherDisplayProps,
+    Component, SearchOverlay, SearchOverlayProps, SetupWizard, SetupWizardProps, WeatherDisplay,
+    WeatherDisplayProps,
 };
+use weather::config::{self, DEFAULT_REFRESH_SECS};
 use weather::effect::Effect;
+use weather::garden::{self, GardenConfig};
+use weather::i18n::{self, Language, Text};
+use weather::pressure::{self, PressureConfig};
+use weather::quota::{self, USAGE_POLL_SECS};
 use weather::reducer::reducer;
-use weather::state::{AppState, LOADING_ANIM_TICK_MS};
+use weather::state::{AppState, LOADING_ANIM_TICK_MS, Location};
+use weather::wizard;
+
+/// City shown when nothing is saved and setup is skipped (`--print-json`)
+const DEFAULT_CITY: &str = "Kyiv";
 
 /// Weather TUI - tui-dispatch framework example
 #[derive(Parser, Debug)]
 #[command(name = "weather")]
 #[command(about = "A weather TUI demonstrating tui-dispatch patterns")]
 struct Args {
-    /// City name to look up (uses Open-Meteo geocoding)
-    #[arg(long, short, default_value = "Kyiv")]
-    city: String,
+    /// City name to look up (uses Open-Meteo geocoding); defaults to the saved city
+    #[arg(long, short)]
+    city: Option<String>,
 
-    /// Refresh interval in seconds (minimum 1)
-    #[arg(long, short, default_value = "30", value_parser = clap::value_parser!(u64).range(1..))]
-    refresh_interval: u64,
+    /// Refresh interval in seconds (minimum 1); defaults to the saved one, or 30
+    #[arg(long, short, value_parser = clap::value_parser!(u64).range(1..))]
+    refresh_interval: Option<u64>,
+
+    /// Soft limit on API calls per day; auto-refresh slows down as usage nears it
+    #[arg(long, default_value_t = quota::DEFAULT_DAILY_LIMIT, value_parser = quota::parse_limit)]
+    daily_limit: u32,
+
+    /// Display language (en, es, de, fr, uk); cycle at runtime with `l`
+    #[arg(long, default_value = "en", value_parser = i18n::parse_language)]
+    lang: Language,
+
+    /// Morning commute window for the planner (local hours, e.g. 8-9)
+    #[arg(long, default_value = "8-9", value_parser = commute::parse_window)]
+    morning: TimeWindow,
+
+    /// Evening commute window for the planner (local hours, e.g. 17-18)
+    #[arg(long, default_value = "17-18", value_parser = commute::parse_window)]
+    evening: TimeWindow,
+
+    /// Base temperature in °C for growing degree days in the garden planner
+    #[arg(long, default_value = "10", value_parser = garden::parse_base)]
+    gdd_base: f32,
+
+    /// Warn about large swings in air pressure over the next 24 hours
+    #[arg(long)]
+    pressure_advisory: bool,
+
+    /// 24-hour pressure change in hPa that triggers the advisory
+    #[arg(long, default_value = "5", value_parser = pressure::parse_threshold)]
+    pressure_swing: f32,
+
+    /// 3-hour pressure change in hPa that counts as rapid
+    #[arg(long, default_value = "3", value_parser = pressure::parse_threshold)]
+    pressure_rapid: f32,
+
+    /// Keep the UI accent neutral instead of tinting it by the current condition
+    #[arg(long)]
+    no_accent: bool,
+
+    /// Turn off the rain, snow, and cloud particles behind the weather
+    #[arg(long)]
+    no_particles: bool,
+
+    /// Base URL of a self-hosted Open-Meteo instance (e.g. http://localhost:8080),
+    /// used for the forecast, archive, and ensemble APIs
+    #[arg(long, value_parser = api::parse_base)]
+    api_base: Option<String>,
+
+    /// Base URL of a self-hosted geocoding API for city lookups
+    #[arg(long, value_parser = api::parse_base)]
+    geocoding_base: Option<String>,
+
+    /// Send every request through this proxy (e.g. http://proxy.example:3128)
+    #[arg(long, value_parser = api::parse_proxy)]
+    proxy: Option<String>,
+
+    /// Skip the TUI: fetch once, print JSON to stdout, and exit
+    #[arg(long, value_enum, num_args = 0..=1, default_missing_value = "weather")]
+    print_json: Option<JsonOutput>,
 
     #[command(flatten)]
     debug: DebugCliArgs,
 }
 
+/// What `--print-json` writes to stdout
+#[derive(ValueEnum, Clone, Copy, Debug)]
+enum JsonOutput {
+    /// Just the fetched `WeatherData`
+    Weather,
+    /// The whole `AppState` after the fetch settles
+    State,
+}
+
 #[derive(tui_dispatch::ComponentId, Clone, Copy, PartialEq, Eq, Hash, Debug)]
 enum WeatherComponentId {
     Display,
     Search,
+    Wizard,
 }
 
 #[derive(tui_dispatch::BindingContext, Clone, Copy, PartialEq, Eq, Hash)]
 enum WeatherContext {
     Main,
     Search,
+    Wizard,
 }
 
 impl EventRoutingState<WeatherComponentId, WeatherContext> for AppState {
     fn focused(&self) -> Option<WeatherComponentId> {
-        if self.search_mode {
+        if self.wizard.is_some() {
+            Some(WeatherComponentId::Wizard)
+        } else if self.search_mode {
             Some(WeatherComponentId::Search)
         } else {
             Some(WeatherComponentId::Display)
@@ -70,7 +152,9 @@ impl EventRoutingState<WeatherComponentId, WeatherContext> for AppState {
     }
 
     fn modal(&self) -> Option<WeatherComponentId> {
-        if self.search_mode {
+        if self.wizard.is_some() {
+            Some(WeatherComponentId::Wizard)
+        } else if self.search_mode {
             Some(WeatherComponentId::Search)
         } else {
             None
@@ -81,6 +165,7 @@ impl EventRoutingState<WeatherComponentId, WeatherContext> for AppState {
         match id {
             WeatherComponentId::Display => WeatherContext::Main,
             WeatherComponentId::Search => WeatherContext::Search,
+            WeatherComponentId::Wizard => WeatherContext::Wizard,
         }
     }
 
@@ -94,42 +179,90 @@ async fn main() -> io::Result<()> {
     let Args {
         city,
         refresh_interval,
+        daily_limit,
+        lang,
+        morning,
+        evening,
+        gdd_base,
+        pressure_advisory,
+        pressure_swing,
+        pressure_rapid,
+        no_accent,
+        no_particles,
+        api_base,
+        geocoding_base,
+        proxy,
+        print_json,
         debug: debug_args,
     } = Args::parse();
 
+    api::configure(api::ApiConfig {
+        api_base,
+        geocoding_base,
+        proxy,
+    })
+    .map_err(io::Error::other)?;
+
+    let (palette, theme_warning) = tui_theme::load("weather", "sky");
+    tui_theme::install(palette);
+
     let debug = DebugSession::new(debug_args);
 
     // Export JSON schemas if requested
     debug.save_state_schema::<AppState>().map_err(debug_error)?;
     debug.save_actions_schema::<Action>().map_err(debug_error)?;
 
+    let saved = match config::load(&config::path()) {
+        Ok(saved) => saved,
+        Err(e) => {
+            eprintln!("Error: Could not read the config file.");
+            eprintln!("Details: {}", e);
+            std::process::exit(1);
+        }
+    };
+    let refresh_interval = refresh_interval
+        .or(saved.as_ref().map(|saved| saved.refresh_interval))
+        .unwrap_or(DEFAULT_REFRESH_SECS);
+    let unit = saved.as_ref().map(|saved| saved.unit).unwrap_or_default();
+    // First launch: nothing saved and no city asked for, so run setup.
+    // Headless output has no one to ask.
+    let first_run = saved.is_none() && city.is_none() && print_json.is_none();
+
     let state = debug
         .load_state_or_else_async(move || async move {
-            let location = match api::geocode_city(&city).await {
-                Ok(loc) => loc,
-                Err(e) => {
-                    match e {
-                        GeocodingError::NotFound(city) => {
-                            eprintln!(
-                                "Error: City '{}' not found. Please check the spelling.",
-                                city
-                            );
-                            eprintln!("Examples: 'London', 'Tokyo', 'New York'");
-                        }
-                        GeocodingError::Request(e) => {
-                            eprintln!("Error: Could not connect to geocoding service.");
-                            eprintln!("Details: {}", e);
-                        }
-                    }
-                    std::process::exit(1);
+            let mut state = match (city, saved) {
+                (None, Some(saved)) => AppState::new(saved.city),
+                (None, None) if first_run => {
+                    let mut state = AppState::default();
+                    wizard::open(&mut state);
+                    state
+                }
+                (city, _) => {
+                    let city = city.unwrap_or_else(|| DEFAULT_CITY.to_string());
+                    AppState::new(geocode_or_exit(&city).await)
                 }
             };
-
-            Ok::<AppState, io::Error>(AppState::new(location))
+            state.unit = unit;
+            state.language = lang;
+            state.commute = CommuteConfig { morning, evening };
+            state.garden = GardenConfig { base: gdd_base };
+            state.pressure = PressureConfig {
+                enabled: pressure_advisory,
+                swing: pressure_swing,
+                rapid: pressure_rapid,
+            };
+            state.daily_limit = daily_limit;
+            state.accent.enabled = !no_accent;
+            state.particles.enabled = !no_particles;
+            Ok::<AppState, io::Error>(state)
         })
         .await
         .map_err(debug_error)?;
 
+    if let Some(output) = print_json {
+        return print_json_once(state, output).await;
+    }
+
     let replay_actions = debug.load_replay_items().map_err(debug_error)?;
 
     let (middleware, action_recorder) = debug.middleware_with_recorder();
@@ -166,6 +299,9 @@ async fn main() -> io::Result<()> {
     if use_alt_screen {
         terminal.show_cursor()?;
     }
+    if let Some(warning) = theme_warning {
+        eprintln!("{warning}");
+    }
 
     let run_output = result?;
     run_output.write_render_output()?;
@@ -176,16 +312,41 @@ async fn main() -> io::Result<()> {
     Ok(())
 }
 
+/// Look up `city`, or explain what went wrong and exit
+async fn geocode_or_exit(city: &str) -> Location {
+    match api::geocode_city(city).await {
+        Ok(loc) => loc,
+        Err(e) => {
+            match e {
+                GeocodingError::NotFound(city) => {
+                    eprintln!(
+                        "Error: City '{}' not found. Please check the spelling.",
+                        city
+                    );
+                    eprintln!("Examples: 'London', 'Tokyo', 'New York'");
+                }
+                GeocodingError::Request(e) => {
+                    eprintln!("Error: Could not connect to geocoding service.");
+                    eprintln!("Details: {}", e);
+                }
+            }
+            std::process::exit(1);
+        }
+    }
+}
+
 struct WeatherUi {
     display: WeatherDisplay,
     search: SearchOverlay,
+    wizard: SetupWizard,
 }
 
 impl WeatherUi {
     fn new() -> Self {
         Self {
-            display: WeatherDisplay,
+            display: WeatherDisplay::default(),
             search: SearchOverlay::new(),
+            wizard: SetupWizard::new(),
         }
     }
 
@@ -197,6 +358,19 @@ impl WeatherUi {
         render_ctx: RenderContext,
         event_ctx: &mut EventContext<WeatherComponentId>,
     ) {
+        // Setup takes the whole screen; there is no weather to show behind it yet.
+        if state.wizard.is_some() {
+            event_ctx.set_component_area(WeatherComponentId::Wizard, area);
+            let props = SetupWizardProps {
+                state,
+                is_focused: render_ctx.is_focused(),
+            };
+            self.wizard.render(frame, area, props);
+            return;
+        }
+        event_ctx
+            .component_areas
+            .remove(&WeatherComponentId::Wizard);
         event_ctx.set_component_area(WeatherComponentId::Display, area);
 
         let props = WeatherDisplayProps {
@@ -214,7 +388,9 @@ impl WeatherUi {
                 results: &state.search_results,
                 selected: state.search_selected,
                 is_focused: render_ctx.is_focused(),
+                placeholder: i18n::text(Text::SearchPlaceholder, state.language),
                 error: state.search_error.as_deref(),
+                accent: state.accent.color(),
                 on_query_change: Action::SearchQueryChange,
                 on_query_submit: Action::SearchQuerySubmit,
                 on_select: Action::SearchSelect,
@@ -263,7 +439,9 @@ impl WeatherUi {
             results: &state.search_results,
             selected: state.search_selected,
             is_focused: true,
+            placeholder: i18n::text(Text::SearchPlaceholder, state.language),
             error: state.search_error.as_deref(),
+            accent: state.accent.color(),
             on_query_change: Action::SearchQueryChange,
             on_query_submit: Action::SearchQuerySubmit,
             on_select: Action::SearchSelect,
@@ -275,6 +453,23 @@ impl WeatherUi {
             needs_render: false,
         }
     }
+
+    fn handle_wizard_event(
+        &mut self,
+        event: &EventKind,
+        state: &AppState,
+    ) -> HandlerResponse<Action> {
+        let props = SetupWizardProps {
+            state,
+            is_focused: true,
+        };
+        let actions: Vec<_> = self.wizard.handle_event(event, props).into_iter().collect();
+        HandlerResponse {
+            actions,
+            consumed: true,
+            needs_render: false,
+        }
+    }
 }
 
 fn debug_error(error: DebugSessionError) -> io::Error {
@@ -306,6 +501,13 @@ async fn run_app<B: ratatui::backend::Backend>(
             .handle_search_event(&event.kind, state)
     });
 
+    let ui_wizard = Rc::clone(&ui);
+    bus.register(WeatherComponentId::Wizard, move |event, state| {
+        ui_wizard
+            .borrow_mut()
+            .handle_wizard_event(&event.kind, state)
+    });
+
     // Re-render on terminal resize (no action needed, just redraw)
     bus.register_global(|event, _state| match event.kind {
         EventKind::Resize(_, _) => HandlerResponse::ignored().with_render(),
@@ -334,7 +536,13 @@ async fn run_app<B: ratatui::backend::Backend>(
                 runtime.subscriptions().interval(
                     "refresh",
                     Duration::from_secs(refresh_interval),
-                    || Action::WeatherFetch,
+                    || Action::RefreshTick,
+                );
+
+                runtime.subscriptions().interval(
+                    "quota",
+                    Duration::from_secs(USAGE_POLL_SECS),
+                    || Action::QuotaDidUpdate(api::call_usage()),
                 );
             },
             &mut bus,
@@ -349,16 +557,87 @@ async fn run_app<B: ratatui::backend::Backend>(
         .await
 }
 
+/// Headless mode: drive a single `WeatherFetch` through the store, running
+/// each effect inline until no more are emitted, then print the result.
+async fn print_json_once(state: AppState, output: JsonOutput) -> io::Result<()> {
+    let mut store = EffectStore::new(state, reducer);
+    let mut pending = VecDeque::from([Action::WeatherFetch]);
+    while let Some(action) = pending.pop_front() {
+        for effect in store.dispatch(action).effects {
+            match effect {
+                Effect::FetchWeather { lat, lon } => {
+                    pending.push_back(load_weather(lat, lon).await)
+                }
+                Effect::FetchHourly { lat, lon } => pending.push_back(load_hourly(lat, lon).await),
+                Effect::FetchArchive { lat, lon } => {
+                    pending.push_back(load_archive(lat, lon).await)
+                }
+                Effect::FetchEnsemble { lat, lon } => {
+                    pending.push_back(load_ensemble(lat, lon).await)
+                }
+                // Only reachable from search mode and setup, which never open headless
+                Effect::SearchCities { .. } | Effect::SaveConfig { .. } => {}
+            }
+        }
+    }
+
+    let state = store.state();
+    if let Some(error) = state.weather.error() {
+        eprintln!("Error: Could not fetch weather.");
+        eprintln!("Details: {}", error);
+        std::process::exit(1);
+    }
+    let json = match output {
+        JsonOutput::Weather => serde_json::to_string_pretty(&state.weather.data()),
+        JsonOutput::State => serde_json::to_string_pretty(state),
+    }
+    .map_err(io::Error::other)?;
+    println!("{json}");
+    Ok(())
+}
+
+async fn load_weather(lat: f64, lon: f64) -> Action {
+    match api::fetch_weather_data(lat, lon).await {
+        Ok(data) => Action::WeatherDidLoad(data),
+        Err(e) => Action::WeatherDidError(e),
+    }
+}
+
+async fn load_hourly(lat: f64, lon: f64) -> Action {
+    match api::fetch_hourly_forecast(lat, lon).await {
+        Ok(samples) => Action::HourlyDidLoad(samples),
+        Err(e) => Action::HourlyDidError(e),
+    }
+}
+
+async fn load_archive(lat: f64, lon: f64) -> Action {
+    match api::fetch_daily_archive(lat, lon).await {
+        Ok(days) => Action::ArchiveDidLoad(days),
+        Err(e) => Action::ArchiveDidError(e),
+    }
+}
+
+async fn load_ensemble(lat: f64, lon: f64) -> Action {
+    match api::fetch_ensemble(lat, lon).await {
+        Ok(bands) => Action::EnsembleDidLoad(bands),
+        Err(e) => Action::EnsembleDidError(e),
+    }
+}
+
 /// Handle effects by spawning tasks
 fn handle_effect(effect: Effect, ctx: &mut EffectContext<Action>) {
     match effect {
         Effect::FetchWeather { lat, lon } => {
-            ctx.tasks().spawn("weather", async move {
-                match api::fetch_weather_data(lat, lon).await {
-                    Ok(data) => Action::WeatherDidLoad(data),
-                    Err(e) => Action::WeatherDidError(e),
-                }
-            });
+            ctx.tasks().spawn("weather", load_weather(lat, lon));
+        }
+        Effect::FetchHourly { lat, lon } => {
+            ctx.tasks().spawn("hourly", load_hourly(lat, lon));
+        }
+        Effect::FetchArchive { lat, lon } => {
+            ctx.tasks().spawn("archive", load_archive(lat, lon));
+        }
+        Effect::FetchEnsemble { lat, lon } => {
+            ctx.tasks().spawn("ensemble", load_ensemble(lat, lon));
         }
         Effect::SearchCities { query } => {
             let query = query.trim().to_string();
@@ -374,5 +653,24 @@ fn handle_effect(effect: Effect, ctx: &mut EffectContext<Action>) {
                     }
                 });
         }
+        Effect::SaveConfig { config } => {
+            // The new interval applies from here; ticks are ignored until the
+            // wizard closes anyway.
+            ctx.subscriptions().interval(
+                "refresh",
+                Duration::from_secs(config.refresh_interval),
+                || Action::RefreshTick,
+            );
+            ctx.tasks().spawn("config", async move {
+                let saved =
+                    tokio::task::spawn_blocking(move || config::save(&config::path(), &config))
+                        .await;
+                match saved {
+                    Ok(Ok(())) => Action::WizardDidSave,
+                    Ok(Err(e)) => Action::WizardDidError(e),
+                    Err(e) => Action::WizardDidError(e.to_string()),
+                }
+            });
+        }
     }
 }
@@ -0,0 +1,307 @@
+//! Ambient particles drawn behind the weather body
+//!
+//! Rain streaks, snowflakes, and drifting cloud puffs, picked from the WMO code
+//! and thickened by its intensity. Positions are a pure function of the
+//! particle frame and the area size, so the state only carries a tick counter
+//! and a resize never leaves particles stranded off-screen.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ParticleKind {
+    Rain,
+    Snow,
+    Cloud,
+}
+
+impl ParticleKind {
+    /// Animation ticks per particle frame: rain falls fast, clouds barely move.
+    pub fn ticks_per_frame(self) -> u32 {
+        match self {
+            ParticleKind::Rain => 3,
+            ParticleKind::Snow => 10,
+            ParticleKind::Cloud => 30,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Intensity {
+    Light,
+    Moderate,
+    Heavy,
+}
+
+/// What falls or drifts across the screen, and how much of it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct ParticleLayer {
+    pub kind: ParticleKind,
+    pub intensity: Intensity,
+}
+
+impl ParticleLayer {
+    /// The layer a WMO code calls for; clear skies and unknown codes get none.
+    pub fn for_code(code: u8) -> Option<Self> {
+        use Intensity::*;
+        use ParticleKind::*;
+        let (kind, intensity) = match code {
+            1 => (Cloud, Light),
+            2 => (Cloud, Moderate),
+            3 | 45 | 48 => (Cloud, Heavy),
+            51 | 53 | 56 | 61 | 66 | 80 => (Rain, Light),
+            55 | 57 | 63 | 81 => (Rain, Moderate),
+            65 | 67 | 82 | 95..=99 => (Rain, Heavy),
+            71 | 77 | 85 => (Snow, Light),
+            73 => (Snow, Moderate),
+            75 | 86 => (Snow, Heavy),
+            _ => return None,
+        };
+        Some(Self { kind, intensity })
+    }
+
+    /// Per-mille chance that a column lane carries a drop or flake.
+    fn density(self) -> u64 {
+        match self.intensity {
+            Intensity::Light => 90,
+            Intensity::Moderate => 180,
+            Intensity::Heavy => 320,
+        }
+    }
+}
+
+/// One glyph of the layer, relative to the top-left of the area.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Particle {
+    pub x: u16,
+    pub y: u16,
+    pub glyph: char,
+}
+
+/// Particle animation state: just a tick counter, advanced while a layer shows.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct ParticleField {
+    /// Off hides the layer and stops its ticks from re-rendering
+    pub enabled: bool,
+    pub tick: u32,
+}
+
+impl Default for ParticleField {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            tick: 0,
+        }
+    }
+}
+
+impl ParticleField {
+    pub fn frame(&self, layer: ParticleLayer) -> u32 {
+        self.tick / layer.kind.ticks_per_frame()
+    }
+
+    /// Advance one animation tick. Returns whether the particles moved.
+    pub fn step(&mut self, layer: Option<ParticleLayer>) -> bool {
+        let Some(layer) = layer.filter(|_| self.enabled) else {
+            return false;
+        };
+        self.tick = self.tick.wrapping_add(1);
+        self.tick.is_multiple_of(layer.kind.ticks_per_frame())
+    }
+}
+
+/// Cloud puffs, widest first; each drifts one cell right per frame.
+const PUFFS: [&str; 3] = [".-~~~-.", "(~~~)", "~~"];
+
+/// Every particle of `layer` at `frame` inside a `width` x `height` area.
+pub fn particles(layer: ParticleLayer, frame: u32, width: u16, height: u16) -> Vec<Particle> {
+    if width == 0 || height == 0 {
+        return Vec::new();
+    }
+    match layer.kind {
+        ParticleKind::Rain | ParticleKind::Snow => falling(layer, frame, width, height),
+        ParticleKind::Cloud => drifting(layer, frame, width, height),
+    }
+}
+
+fn falling(layer: ParticleLayer, frame: u32, width: u16, height: u16) -> Vec<Particle> {
+    let lanes = if layer.intensity == Intensity::Heavy {
+        2
+    } else {
+        1
+    };
+    let span = height as u64;
+    let mut out = Vec::new();
+    for x in 0..width {
+        for lane in 0..lanes {
+            let seed = mix(x as u64, lane, layer.kind as u64);
+            if seed % 1000 >= layer.density() {
+                continue;
+            }
+            // A gap after each pass so a column doesn't read as a solid line
+            let period = span + (seed >> 10) % span.max(4);
+            let y = (frame as u64 + (seed >> 20)) % period;
+            if y >= span {
+                continue;
+            }
+            let y = y as u16;
+            if layer.kind == ParticleKind::Rain {
+                let glyph = match layer.intensity {
+                    Intensity::Light => '\'',
+                    Intensity::Moderate | Intensity::Heavy => '|',
+                };
+                out.push(Particle { x, y, glyph });
+                if layer.intensity == Intensity::Heavy && y > 0 {
+                    out.push(Particle { x, y: y - 1, glyph });
+                }
+            } else {
+                let sway = [0, 1, 0, -1][((frame as u64 / 2 + seed) % 4) as usize];
+                let Some(x) = x.checked_add_signed(sway).filter(|&x| x < width) else {
+                    continue;
+                };
+                let glyph = if (seed >> 40).is_multiple_of(3) {
+                    '.'
+                } else {
+                    '*'
+                };
+                out.push(Particle { x, y, glyph });
+            }
+        }
+    }
+    out
+}
+
+fn drifting(layer: ParticleLayer, frame: u32, width: u16, height: u16) -> Vec<Particle> {
+    let per_forty = match layer.intensity {
+        Intensity::Light => 1,
+        Intensity::Moderate => 2,
+        Intensity::Heavy => 3,
+    };
+    let count = (width as u64 * per_forty).div_ceil(40);
+    // Clouds keep to the top third, above the temperature
+    let rows = (height as u64 / 3).max(1);
+    let mut out = Vec::new();
+    for index in 0..count {
+        let seed = mix(index, 0, ParticleKind::Cloud as u64);
+        let puff = PUFFS[(seed % PUFFS.len() as u64) as usize];
+        let len = puff.chars().count() as u64;
+        let row = ((seed >> 8) % rows) as u16;
+        let lap = width as u64 + len;
+        let head = (frame as u64 + (seed >> 20)) % lap;
+        for (offset, glyph) in puff.chars().enumerate() {
+            let x = (head + offset as u64).checked_sub(len);
+            if let Some(x) = x.filter(|&x| x < width as u64) {
+                out.push(Particle {
+                    x: x as u16,
+                    y: row,
+                    glyph,
+                });
+            }
+        }
+    }
+    out
+}
+
+/// SplitMix64 over a column, lane, and kind: a stable pseudo-random seed.
+fn mix(a: u64, b: u64, c: u64) -> u64 {
+    let mut z = a
+        .wrapping_mul(0x9e37_79b9_7f4a_7c15)
+        .wrapping_add(b.wrapping_mul(0xbf58_476d_1ce4_e5b9))
+        .wrapping_add(c.wrapping_mul(0x94d0_49bb_1331_11eb))
+        .wrapping_add(0x9e37_79b9_7f4a_7c15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    z ^ (z >> 31)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn layer(kind: ParticleKind, intensity: Intensity) -> ParticleLayer {
+        ParticleLayer { kind, intensity }
+    }
+
+    #[test]
+    fn test_codes_map_to_layers() {
+        assert_eq!(ParticleLayer::for_code(0), None);
+        assert_eq!(
+            ParticleLayer::for_code(63),
+            Some(layer(ParticleKind::Rain, Intensity::Moderate))
+        );
+        assert_eq!(
+            ParticleLayer::for_code(95),
+            Some(layer(ParticleKind::Rain, Intensity::Heavy))
+        );
+        assert_eq!(
+            ParticleLayer::for_code(75),
+            Some(layer(ParticleKind::Snow, Intensity::Heavy))
+        );
+        assert_eq!(
+            ParticleLayer::for_code(45),
+            Some(layer(ParticleKind::Cloud, Intensity::Heavy))
+        );
+        assert_eq!(ParticleLayer::for_code(200), None);
+    }
+
+    #[test]
+    fn test_particles_stay_inside_the_area() {
+        for kind in [ParticleKind::Rain, ParticleKind::Snow, ParticleKind::Cloud] {
+            for frame in 0..200 {
+                for p in particles(layer(kind, Intensity::Heavy), frame, 37, 11) {
+                    assert!(p.x < 37 && p.y < 11, "{kind:?} frame {frame}: {p:?}");
+                }
+            }
+        }
+        assert!(particles(layer(ParticleKind::Rain, Intensity::Heavy), 3, 0, 10).is_empty());
+    }
+
+    #[test]
+    fn test_heavier_weather_draws_more() {
+        let count = |intensity| {
+            (0..50)
+                .map(|frame| particles(layer(ParticleKind::Rain, intensity), frame, 80, 20).len())
+                .sum::<usize>()
+        };
+        assert!(count(Intensity::Light) < count(Intensity::Moderate));
+        assert!(count(Intensity::Moderate) < count(Intensity::Heavy));
+    }
+
+    #[test]
+    fn test_rain_falls_and_clouds_drift() {
+        let rain = layer(ParticleKind::Rain, Intensity::Moderate);
+        let now = particles(rain, 10, 80, 40);
+        let next = particles(rain, 11, 80, 40);
+        let falling = now
+            .iter()
+            .filter(|p| p.y + 1 < 40)
+            .all(|p| next.iter().any(|n| n.x == p.x && n.y == p.y + 1));
+        assert!(!now.is_empty() && falling);
+
+        let cloud = layer(ParticleKind::Cloud, Intensity::Light);
+        let now = particles(cloud, 100, 80, 24);
+        let next = particles(cloud, 101, 80, 24);
+        assert!(now.iter().all(|p| p.y < 8));
+        assert!(
+            now.iter()
+                .filter(|p| p.x + 1 < 80)
+                .all(|p| next.iter().any(|n| n.x == p.x + 1 && n.y == p.y))
+        );
+    }
+
+    #[test]
+    fn test_field_steps_only_with_a_layer() {
+        let rain = layer(ParticleKind::Rain, Intensity::Light);
+        let mut field = ParticleField::default();
+        assert!(!field.step(None));
+        assert_eq!(field.tick, 0);
+
+        let moved: Vec<bool> = (0..6).map(|_| field.step(Some(rain))).collect();
+        assert_eq!(moved, [false, false, true, false, false, true]);
+        assert_eq!(field.frame(rain), 2);
+
+        field.enabled = false;
+        assert!(!field.step(Some(rain)));
+        assert_eq!(field.tick, 6);
+    }
+}
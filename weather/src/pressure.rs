@@ -0,0 +1,293 @@
+//! Pressure advisory: warn about large swings in sea-level pressure
+//!
+//! Some people get headaches or joint pain when the barometer moves quickly.
+//! The advisory looks at the next 24 hours of the hourly forecast: the net
+//! change over the day decides the direction, and the steepest three-hour
+//! stretch in that direction says how fast and when it happens.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::state::HourlySample;
+
+/// Hours ahead the net change is measured over
+pub const ADVISORY_HOURS: usize = 24;
+
+/// Span of the short-term tendency, as on a barograph
+pub const TENDENCY_HOURS: usize = 3;
+
+/// Advisory settings; off unless asked for with `--pressure-advisory`
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct PressureConfig {
+    pub enabled: bool,
+    /// Net change over 24 hours (hPa) that earns an advisory
+    pub swing: f32,
+    /// Change over 3 hours (hPa) that makes it a rapid one
+    pub rapid: f32,
+}
+
+impl Default for PressureConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            swing: 5.0,
+            rapid: 3.0,
+        }
+    }
+}
+
+/// Parser for `--pressure-swing` / `--pressure-rapid`, in hPa (e.g. `5`)
+pub fn parse_threshold(value: &str) -> Result<f32, String> {
+    let hpa: f32 = value
+        .trim()
+        .trim_end_matches("hPa")
+        .trim()
+        .parse()
+        .map_err(|_| format!("invalid pressure change '{value}'"))?;
+    if !(0.5..=30.0).contains(&hpa) {
+        return Err(format!(
+            "pressure change {hpa} must be between 0.5 and 30 hPa"
+        ));
+    }
+    Ok(hpa)
+}
+
+/// Which way the barometer is heading
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Trend {
+    Falling,
+    Rising,
+}
+
+/// Part of the day the steepest change falls in, relative to now
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum DayPart {
+    ThisMorning,
+    ThisAfternoon,
+    ThisEvening,
+    Overnight,
+    TomorrowMorning,
+    TomorrowAfternoon,
+    TomorrowEvening,
+}
+
+impl DayPart {
+    /// Day part of `time` (`YYYY-MM-DDTHH:MM`) as seen from `today` (`YYYY-MM-DD`).
+    ///
+    /// Small hours belong to the night that started the evening before, so
+    /// they are always "overnight".
+    pub fn of(time: &str, today: &str) -> Option<Self> {
+        let (date, clock) = time.split_once('T')?;
+        let hour: u8 = clock.get(..2)?.parse().ok()?;
+        let tomorrow = date > today;
+        Some(match (hour, tomorrow) {
+            (0..=4 | 22..=23, _) => DayPart::Overnight,
+            (5..=11, false) => DayPart::ThisMorning,
+            (12..=16, false) => DayPart::ThisAfternoon,
+            (_, false) => DayPart::ThisEvening,
+            (5..=11, true) => DayPart::TomorrowMorning,
+            (12..=16, true) => DayPart::TomorrowAfternoon,
+            (_, true) => DayPart::TomorrowEvening,
+        })
+    }
+}
+
+/// A swing worth warning about
+#[derive(Clone, Debug, PartialEq)]
+pub struct Advisory {
+    pub trend: Trend,
+    /// The steepest 3-hour change reached the rapid threshold
+    pub rapid: bool,
+    /// Net change over the next 24 hours, hPa
+    pub change: f32,
+    /// Start of the steepest 3-hour change, `YYYY-MM-DDTHH:MM`
+    pub time: String,
+    pub when: DayPart,
+}
+
+/// Check the hourly forecast for a swing past the configured thresholds.
+///
+/// Returns `None` when the advisory is off, when the forecast has no pressure
+/// readings to speak of, or when the barometer stays calm.
+pub fn advise(hourly: &[HourlySample], config: PressureConfig) -> Option<Advisory> {
+    if !config.enabled {
+        return None;
+    }
+    let readings: Vec<(&str, f32)> = hourly
+        .iter()
+        .take(ADVISORY_HOURS + 1)
+        .filter_map(|sample| Some((sample.time.as_str(), sample.pressure?)))
+        .collect();
+    if readings.len() <= TENDENCY_HOURS {
+        return None;
+    }
+
+    let change = readings[readings.len() - 1].1 - readings[0].1;
+    let trend = if change < 0.0 {
+        Trend::Falling
+    } else {
+        Trend::Rising
+    };
+    // Signed so that the advisory's direction always counts as positive
+    let toward = |from: f32, to: f32| match trend {
+        Trend::Falling => from - to,
+        Trend::Rising => to - from,
+    };
+    let (start, steepest) = readings
+        .windows(TENDENCY_HOURS + 1)
+        .map(|window| (window[0].0, toward(window[0].1, window[TENDENCY_HOURS].1)))
+        .fold(("", f32::MIN), |best, candidate| {
+            if candidate.1 > best.1 {
+                candidate
+            } else {
+                best
+            }
+        });
+
+    let rapid = steepest >= config.rapid;
+    if change.abs() < config.swing && !rapid {
+        return None;
+    }
+    let today = readings[0].0.split_once('T')?.0;
+    Some(Advisory {
+        trend,
+        rapid,
+        change,
+        time: start.to_string(),
+        when: DayPart::of(start, today)?,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Hourly samples from `start_hour` on 2024-06-03, one per pressure value.
+    fn series(start_hour: usize, pressures: &[f32]) -> Vec<HourlySample> {
+        pressures
+            .iter()
+            .enumerate()
+            .map(|(i, &pressure)| {
+                let hour = start_hour + i;
+                HourlySample {
+                    time: format!("2024-06-{:02}T{:02}:00", 3 + hour / 24, hour % 24),
+                    pressure: Some(pressure),
+                    ..Default::default()
+                }
+            })
+            .collect()
+    }
+
+    fn enabled() -> PressureConfig {
+        PressureConfig {
+            enabled: true,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_threshold() {
+        assert_eq!(parse_threshold("5"), Ok(5.0));
+        assert_eq!(parse_threshold(" 2.5 hPa"), Ok(2.5));
+        assert!(parse_threshold("0").is_err());
+        assert!(parse_threshold("lots").is_err());
+    }
+
+    #[test]
+    fn test_day_part_relative_to_today() {
+        let today = "2024-06-03";
+        assert_eq!(
+            DayPart::of("2024-06-03T09:00", today),
+            Some(DayPart::ThisMorning)
+        );
+        assert_eq!(
+            DayPart::of("2024-06-03T13:00", today),
+            Some(DayPart::ThisAfternoon)
+        );
+        assert_eq!(
+            DayPart::of("2024-06-03T18:00", today),
+            Some(DayPart::ThisEvening)
+        );
+        assert_eq!(
+            DayPart::of("2024-06-03T23:00", today),
+            Some(DayPart::Overnight)
+        );
+        assert_eq!(
+            DayPart::of("2024-06-04T03:00", today),
+            Some(DayPart::Overnight)
+        );
+        assert_eq!(
+            DayPart::of("2024-06-04T07:00", today),
+            Some(DayPart::TomorrowMorning)
+        );
+        assert_eq!(
+            DayPart::of("2024-06-04T19:00", today),
+            Some(DayPart::TomorrowEvening)
+        );
+        assert_eq!(DayPart::of("garbage", today), None);
+    }
+
+    #[test]
+    fn test_rapid_drop_timing() {
+        // Steady until 16:00, then 8 hPa gone in three hours, then level again
+        let mut pressures = vec![1015.0; 9];
+        pressures.extend([1012.0, 1009.0, 1007.0]);
+        pressures.extend(vec![1007.0; 13]);
+        let advisory = advise(&series(8, &pressures), enabled()).unwrap();
+
+        assert_eq!(advisory.trend, Trend::Falling);
+        assert!(advisory.rapid);
+        assert_eq!(advisory.change, -8.0);
+        assert_eq!(advisory.time, "2024-06-03T16:00");
+        assert_eq!(advisory.when, DayPart::ThisAfternoon);
+
+        let advisory = advise(&series(9, &pressures), enabled()).unwrap();
+        assert_eq!(advisory.when, DayPart::ThisEvening);
+    }
+
+    #[test]
+    fn test_slow_rise_past_swing_is_not_rapid() {
+        let pressures: Vec<f32> = (0..25).map(|hour| 1000.0 + hour as f32 * 0.25).collect();
+        let advisory = advise(&series(22, &pressures), enabled()).unwrap();
+
+        assert_eq!(advisory.trend, Trend::Rising);
+        assert!(!advisory.rapid);
+        assert_eq!(advisory.change, 6.0);
+        assert_eq!(advisory.when, DayPart::Overnight);
+    }
+
+    #[test]
+    fn test_calm_or_disabled_gives_no_advisory() {
+        let calm: Vec<f32> = (0..25).map(|hour| 1013.0 - hour as f32 * 0.1).collect();
+        assert_eq!(advise(&series(0, &calm), enabled()), None);
+
+        let stormy: Vec<f32> = (0..25).map(|hour| 1013.0 - hour as f32).collect();
+        assert!(advise(&series(0, &stormy), enabled()).is_some());
+        assert_eq!(advise(&series(0, &stormy), PressureConfig::default()), None);
+
+        // A tighter threshold catches the calm day too
+        let sensitive = PressureConfig {
+            swing: 2.0,
+            ..enabled()
+        };
+        assert!(advise(&series(0, &calm), sensitive).is_some());
+    }
+
+    #[test]
+    fn test_missing_pressure_readings() {
+        let mut samples = series(0, &[1013.0; 25]);
+        for sample in &mut samples {
+            sample.pressure = None;
+        }
+        assert_eq!(advise(&samples, enabled()), None);
+        assert_eq!(advise(&[], enabled()), None);
+    }
+
+    #[test]
+    fn test_only_the_next_day_counts() {
+        // The plunge starts 30 hours out, past the advisory horizon
+        let mut pressures = vec![1013.0; 30];
+        pressures.extend([1005.0, 998.0]);
+        assert_eq!(advise(&series(0, &pressures), enabled()), None);
+    }
+}
@@ -0,0 +1,138 @@
+//! API quota accounting and the refresh back-off it drives
+//!
+//! The api layer logs every request it sends in a [`CallLog`]. The reducer only
+//! sees periodic [`CallUsage`] snapshots, and stretches the refresh interval as
+//! usage approaches the soft limit so the app slows down instead of hitting it.
+
+use std::collections::VecDeque;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+const HOUR_SECS: u64 = 3_600;
+const DAY_SECS: u64 = 86_400;
+
+/// Open-Meteo's free tier allows 10,000 calls per day.
+pub const DEFAULT_DAILY_LIMIT: u32 = 10_000;
+/// Share of the soft limit at which refreshes start to slow down.
+pub const STRETCH_FROM: f32 = 0.75;
+/// Refresh interval multiplier once the soft limit is reached.
+pub const MAX_STRETCH: u32 = 8;
+/// Seconds between usage snapshots sent to the reducer.
+pub const USAGE_POLL_SECS: u64 = 5;
+
+/// Requests made over the trailing hour and day
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub struct CallUsage {
+    pub last_hour: u32,
+    pub last_day: u32,
+}
+
+impl CallUsage {
+    /// Fraction of the soft limit used, 1.0 at the limit.
+    ///
+    /// The daily total and the hourly pace are both checked; the hourly budget is
+    /// an even share of the daily one, so a burst slows refreshes before the day's
+    /// quota is gone.
+    pub fn pressure(&self, daily_limit: u32) -> f32 {
+        let daily_limit = daily_limit.max(1) as f32;
+        let hourly_limit = (daily_limit / 24.0).max(1.0);
+        (self.last_day as f32 / daily_limit).max(self.last_hour as f32 / hourly_limit)
+    }
+
+    /// Refresh interval multiplier: 1 below `STRETCH_FROM`, rising to
+    /// `MAX_STRETCH` at the soft limit.
+    pub fn stretch(&self, daily_limit: u32) -> u32 {
+        let pressure = self.pressure(daily_limit);
+        if pressure <= STRETCH_FROM {
+            return 1;
+        }
+        let over = ((pressure - STRETCH_FROM) / (1.0 - STRETCH_FROM)).min(1.0);
+        1 + (over * (MAX_STRETCH - 1) as f32).ceil() as u32
+    }
+}
+
+/// Send times (Unix seconds) of the requests made in the last day
+#[derive(Debug, Default)]
+pub struct CallLog {
+    calls: VecDeque<u64>,
+}
+
+impl CallLog {
+    pub const fn new() -> Self {
+        Self {
+            calls: VecDeque::new(),
+        }
+    }
+
+    pub fn record(&mut self, now: u64) {
+        self.calls.push_back(now);
+        self.prune(now);
+    }
+
+    pub fn usage(&mut self, now: u64) -> CallUsage {
+        self.prune(now);
+        let hour_start = now.saturating_sub(HOUR_SECS);
+        CallUsage {
+            last_hour: self.calls.iter().filter(|&&at| at > hour_start).count() as u32,
+            last_day: self.calls.len() as u32,
+        }
+    }
+
+    fn prune(&mut self, now: u64) {
+        let day_start = now.saturating_sub(DAY_SECS);
+        while self.calls.front().is_some_and(|&at| at <= day_start) {
+            self.calls.pop_front();
+        }
+    }
+}
+
+/// Parser for the `--daily-limit` CLI flag
+pub fn parse_limit(value: &str) -> Result<u32, String> {
+    match value.trim().parse::<u32>() {
+        Ok(limit) if limit > 0 => Ok(limit),
+        _ => Err(format!(
+            "invalid daily limit '{value}' (expected a positive number)"
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_log_counts_trailing_hour_and_day() {
+        let mut log = CallLog::new();
+        let now = 10 * DAY_SECS;
+        log.record(now - DAY_SECS);
+        log.record(now - 2 * HOUR_SECS);
+        log.record(now - 60);
+        log.record(now);
+
+        let usage = log.usage(now);
+        assert_eq!(usage.last_hour, 2);
+        assert_eq!(usage.last_day, 3, "calls a full day old are dropped");
+    }
+
+    #[test]
+    fn test_stretch_grows_towards_limit() {
+        let usage = |last_hour, last_day| CallUsage {
+            last_hour,
+            last_day,
+        };
+        assert_eq!(usage(10, 100).stretch(2_400), 1);
+        assert_eq!(usage(0, 1_850).stretch(2_400), 2);
+        assert_eq!(usage(0, 2_400).stretch(2_400), MAX_STRETCH);
+        assert_eq!(usage(0, 9_000).stretch(2_400), MAX_STRETCH);
+        // A burst within the hour counts against an even share of the day
+        assert_eq!(usage(100, 100).stretch(2_400), MAX_STRETCH);
+    }
+
+    #[test]
+    fn test_parse_limit() {
+        assert_eq!(parse_limit("500"), Ok(500));
+        assert!(parse_limit("0").is_err());
+        assert!(parse_limit("lots").is_err());
+    }
+}
@@ -3,43 +3,92 @@
 use tui_dispatch::{DataResource, DispatchResult};
 
 use crate::action::Action;
+use crate::config;
 use crate::effect::Effect;
-use crate::state::{AppState, LOADING_ANIM_CYCLE_TICKS};
+use crate::sprites::WeatherCondition;
+use crate::state::{AppState, LOADING_ANIM_CYCLE_TICKS, Location};
+use crate::theme;
+use crate::wizard::WizardStep;
 
 /// The reducer handles all state transitions
 pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
     match action {
         // ===== Weather actions =====
-        Action::WeatherFetch => {
-            if state.weather.is_loaded() {
-                state.is_refreshing = true;
-            } else {
-                state.weather = DataResource::Loading;
-            }
-            state.tick_count = 0;
-            state.loading_anim_ticks_remaining = 0;
-            let loc = state.current_location();
-            DispatchResult::changed_with(Effect::FetchWeather {
-                lat: loc.lat,
-                lon: loc.lon,
-            })
+        // Nothing is fetched until the setup wizard has a city.
+        Action::WeatherFetch | Action::RefreshTick if state.wizard.is_some() => {
+            DispatchResult::unchanged()
         }
 
+        Action::WeatherFetch => weather_fetch(state),
+
         Action::WeatherDidLoad(data) => {
+            let condition = WeatherCondition::from_code(data.weather_code);
+            state.accent.retarget(theme::condition_accent(condition));
+            state.weather_cache.store(&state.location, data.clone());
             state.weather = DataResource::Loaded(data);
             state.is_refreshing = false;
+            state.provisional = None;
             state.loading_anim_ticks_remaining = ticks_to_phase_zero(state.tick_count);
-            DispatchResult::changed()
+            // Hourly data follows each successful fetch for the same location
+            DispatchResult::changed_with(hourly_fetch(state))
         }
 
         Action::WeatherDidError(msg) => {
             state.weather = DataResource::Failed(msg);
             state.is_refreshing = false;
+            state.provisional = None;
             state.loading_anim_ticks_remaining = ticks_to_phase_zero(state.tick_count);
             DispatchResult::changed()
         }
 
+        Action::HourlyDidLoad(samples) => {
+            state
+                .weather_cache
+                .store_hourly(&state.location, samples.clone());
+            state.hourly = DataResource::Loaded(samples);
+            DispatchResult::changed()
+        }
+
+        Action::HourlyDidError(msg) => {
+            state.hourly = DataResource::Failed(msg);
+            DispatchResult::changed()
+        }
+
+        Action::ArchiveDidLoad(days) => {
+            state.archive = DataResource::Loaded(days);
+            DispatchResult::changed()
+        }
+
+        Action::ArchiveDidError(msg) => {
+            state.archive = DataResource::Failed(msg);
+            DispatchResult::changed()
+        }
+
+        Action::EnsembleDidLoad(bands) => {
+            state.ensemble = DataResource::Loaded(bands);
+            DispatchResult::changed()
+        }
+
+        Action::EnsembleDidError(msg) => {
+            state.ensemble = DataResource::Failed(msg);
+            DispatchResult::changed()
+        }
+
+        // ===== Quota actions =====
+        Action::QuotaDidUpdate(usage) => {
+            if usage == state.api_usage {
+                return DispatchResult::unchanged();
+            }
+            state.api_usage = usage;
+            DispatchResult::changed()
+        }
+
         // ===== Search actions =====
+        // The wizard's city step owns the overlay; Esc there quits instead.
+        Action::SearchOpen | Action::SearchClose if state.wizard.is_some() => {
+            DispatchResult::unchanged()
+        }
+
         Action::SearchOpen => {
             state.search_mode = true;
             state.search_query.clear();
@@ -58,6 +107,18 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
             DispatchResult::changed()
         }
 
+        // Debounced searches can resolve after the overlay closed; drop those.
+        Action::SearchQueryChange(_)
+        | Action::SearchQuerySubmit(_)
+        | Action::SearchDidLoad(_)
+        | Action::SearchDidError(_)
+        | Action::SearchSelect(_)
+        | Action::SearchConfirm
+            if !state.search_mode =>
+        {
+            DispatchResult::unchanged()
+        }
+
         Action::SearchQueryChange(query) => {
             state.search_query = query;
             state.search_selected = 0;
@@ -101,15 +162,31 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
             }
         }
 
+        Action::SearchConfirm if state.wizard.is_some() => {
+            let Some(location) = state.search_results.get(state.search_selected).cloned() else {
+                return DispatchResult::unchanged();
+            };
+            let Some(wizard) = state.wizard.as_mut() else {
+                return DispatchResult::unchanged();
+            };
+            wizard.city = Some(location);
+            wizard.step = WizardStep::Units;
+            state.search_mode = false;
+            state.search_query.clear();
+            state.search_results.clear();
+            state.search_error = None;
+            state.search_selected = 0;
+            DispatchResult::changed()
+        }
+
         Action::SearchConfirm => {
             let Some(location) = state.search_results.get(state.search_selected).cloned() else {
                 return DispatchResult::unchanged();
             };
 
             let (lat, lon) = (location.lat, location.lon);
+            show_provisional(state, &location);
             state.location = location;
-            state.weather = DataResource::Loading;
-            state.is_refreshing = false;
             state.search_mode = false;
             state.search_query.clear();
             state.search_results.clear();
@@ -117,7 +194,112 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
             state.search_selected = 0;
             state.tick_count = 0;
             state.loading_anim_ticks_remaining = 0;
-            DispatchResult::changed_with(Effect::FetchWeather { lat, lon })
+            state.archive = DataResource::Empty;
+            state.ensemble = DataResource::Empty;
+            let mut effects = vec![Effect::FetchWeather { lat, lon }];
+            if state.show_garden {
+                effects.push(archive_fetch(state));
+            }
+            if state.show_ensemble {
+                effects.push(ensemble_fetch(state));
+            }
+            DispatchResult::changed_with_many(effects)
+        }
+
+        // ===== Wizard actions =====
+        Action::WizardToggleUnit => {
+            let Some(wizard) = state.wizard.as_mut() else {
+                return DispatchResult::unchanged();
+            };
+            if wizard.step != WizardStep::Units {
+                return DispatchResult::unchanged();
+            }
+            wizard.unit = wizard.unit.toggle();
+            DispatchResult::changed()
+        }
+
+        Action::WizardNext => {
+            let Some(wizard) = state.wizard.as_mut() else {
+                return DispatchResult::unchanged();
+            };
+            if wizard.step != WizardStep::Units {
+                return DispatchResult::unchanged();
+            }
+            wizard.step = WizardStep::Refresh;
+            DispatchResult::changed()
+        }
+
+        Action::WizardBack => {
+            let Some(wizard) = state.wizard.as_mut() else {
+                return DispatchResult::unchanged();
+            };
+            if wizard.saving {
+                return DispatchResult::unchanged();
+            }
+            match wizard.step {
+                WizardStep::City => return DispatchResult::unchanged(),
+                WizardStep::Units => {
+                    wizard.step = WizardStep::City;
+                    state.search_mode = true;
+                }
+                WizardStep::Refresh => {
+                    wizard.step = WizardStep::Units;
+                    wizard.error = None;
+                }
+            }
+            DispatchResult::changed()
+        }
+
+        Action::WizardRefreshChange(input) => {
+            let Some(wizard) = state.wizard.as_mut() else {
+                return DispatchResult::unchanged();
+            };
+            wizard.refresh_input = input;
+            wizard.error = None;
+            DispatchResult::changed()
+        }
+
+        Action::WizardRefreshSubmit(input) => {
+            let Some(wizard) = state.wizard.as_mut() else {
+                return DispatchResult::unchanged();
+            };
+            if wizard.step != WizardStep::Refresh || wizard.saving {
+                return DispatchResult::unchanged();
+            }
+            wizard.refresh_input = input;
+            let refresh_interval = match config::parse_refresh_interval(&wizard.refresh_input) {
+                Ok(secs) => secs,
+                Err(e) => {
+                    wizard.error = Some(e);
+                    return DispatchResult::changed();
+                }
+            };
+            let Some(config) = wizard.config(refresh_interval) else {
+                return DispatchResult::unchanged();
+            };
+            wizard.error = None;
+            wizard.saving = true;
+            DispatchResult::changed_with(Effect::SaveConfig { config })
+        }
+
+        Action::WizardDidSave => {
+            let Some(wizard) = state.wizard.take() else {
+                return DispatchResult::unchanged();
+            };
+            if let Some(city) = wizard.city {
+                state.location = city;
+            }
+            state.unit = wizard.unit;
+            weather_fetch(state)
+        }
+
+        Action::WizardDidError(msg) => {
+            let Some(wizard) = state.wizard.as_mut() else {
+                return DispatchResult::unchanged();
+            };
+            wizard.saving = false;
+            wizard.error = Some(format!("could not save config: {msg}"));
+            DispatchResult::changed()
         }
 
         // ===== UI actions =====
@@ -126,6 +308,50 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
             DispatchResult::changed()
         }
 
+        Action::UiCycleLanguage => {
+            state.language = state.language.next();
+            DispatchResult::changed()
+        }
+
+        Action::UiToggleCommute => {
+            state.show_commute = !state.show_commute;
+            if state.show_commute {
+                state.show_garden = false;
+                state.show_ensemble = false;
+            }
+            if state.show_commute && (state.hourly.is_empty() || state.hourly.is_failed()) {
+                DispatchResult::changed_with(hourly_fetch(state))
+            } else {
+                DispatchResult::changed()
+            }
+        }
+
+        Action::UiToggleGarden => {
+            state.show_garden = !state.show_garden;
+            if state.show_garden {
+                state.show_commute = false;
+                state.show_ensemble = false;
+            }
+            if state.show_garden && (state.archive.is_empty() || state.archive.is_failed()) {
+                DispatchResult::changed_with(archive_fetch(state))
+            } else {
+                DispatchResult::changed()
+            }
+        }
+
+        Action::UiToggleEnsemble => {
+            state.show_ensemble = !state.show_ensemble;
+            if state.show_ensemble {
+                state.show_commute = false;
+                state.show_garden = false;
+            }
+            if state.show_ensemble && (state.ensemble.is_empty() || state.ensemble.is_failed()) {
+                DispatchResult::changed_with(ensemble_fetch(state))
+            } else {
+                DispatchResult::changed()
+            }
+        }
+
         Action::Render => DispatchResult::changed(),
 
         // ===== Global actions =====
@@ -136,16 +362,105 @@ pub fn reducer(state: &mut AppState, action: Action) -> DispatchResult<Effect> {
                 if state.loading_anim_ticks_remaining > 0 {
                     state.loading_anim_ticks_remaining -= 1;
                 }
+            }
+            let fading = state.accent.step();
+            let drifting = state.particles.step(state.particle_layer());
+            if animating || fading || drifting {
                 DispatchResult::changed()
             } else {
                 DispatchResult::unchanged()
             }
         }
 
+        Action::RefreshTick => {
+            state.refresh_ticks = state.refresh_ticks.saturating_add(1);
+            if state.refresh_ticks < state.refresh_stretch() {
+                return DispatchResult::unchanged();
+            }
+            weather_fetch(state)
+        }
+
         Action::Quit => DispatchResult::unchanged(),
     }
 }
 
+/// Start a weather fetch, keeping current data on screen if there is any.
+///
+/// Any fetch restarts the auto-refresh countdown, manual ones included.
+fn weather_fetch(state: &mut AppState) -> DispatchResult<Effect> {
+    if state.weather.is_loaded() {
+        state.is_refreshing = true;
+    } else {
+        state.weather = DataResource::Loading;
+    }
+    state.tick_count = 0;
+    state.loading_anim_ticks_remaining = 0;
+    state.refresh_ticks = 0;
+    let loc = state.current_location();
+    DispatchResult::changed_with(Effect::FetchWeather {
+        lat: loc.lat,
+        lon: loc.lon,
+    })
+}
+
+/// Optimistically show a newly picked city: its cached data, or a nearby
+/// city's, stays on screen as a refresh until the fetch lands. With nothing
+/// cached close enough, the display loads from scratch as before.
+fn show_provisional(state: &mut AppState, location: &Location) {
+    let Some((cached, provisional)) = state.weather_cache.lookup(location) else {
+        state.weather = DataResource::Loading;
+        state.is_refreshing = false;
+        state.provisional = None;
+        state.hourly = DataResource::Empty;
+        return;
+    };
+    let condition = WeatherCondition::from_code(cached.weather.weather_code);
+    state.accent.retarget(theme::condition_accent(condition));
+    state.weather = DataResource::Loaded(cached.weather.clone());
+    state.hourly = match &cached.hourly {
+        Some(samples) => DataResource::Loaded(samples.clone()),
+        None => DataResource::Empty,
+    };
+    state.is_refreshing = true;
+    state.provisional = Some(provisional);
+}
+
+/// Hourly forecast request for the precipitation strip and commute planner.
+///
+/// Previously loaded samples stay visible until the new ones arrive.
+fn hourly_fetch(state: &mut AppState) -> Effect {
+    if !state.hourly.is_loaded() {
+        state.hourly = DataResource::Loading;
+    }
+    let loc = state.current_location();
+    Effect::FetchHourly {
+        lat: loc.lat,
+        lon: loc.lon,
+    }
+}
+
+/// Archive request for the garden planner; history doesn't change, so this
+/// only runs when nothing is loaded for the current location.
+fn archive_fetch(state: &mut AppState) -> Effect {
+    state.archive = DataResource::Loading;
+    let loc = state.current_location();
+    Effect::FetchArchive {
+        lat: loc.lat,
+        lon: loc.lon,
+    }
+}
+
+/// Ensemble request for the forecast spread panel. Model runs land a few
+/// times a day, so reopening the panel reuses what is already loaded.
+fn ensemble_fetch(state: &mut AppState) -> Effect {
+    state.ensemble = DataResource::Loading;
+    let loc = state.current_location();
+    Effect::FetchEnsemble {
+        lat: loc.lat,
+        lon: loc.lon,
+    }
+}
+
 fn ticks_to_phase_zero(tick_count: u32) -> u32 {
     let cycle = LOADING_ANIM_CYCLE_TICKS.max(1);
     if tick_count == 0 {
@@ -155,10 +470,168 @@ fn ticks_to_phase_zero(tick_count: u32) -> u32 {
     if remainder == 0 { 0 } else { cycle - remainder }
 }
 
+/// State invariants the reducer must uphold for any action sequence.
+///
+/// Shared by the unit tests here and the property tests in `tests/reducer_props.rs`.
+pub mod invariants {
+    use crate::action::Action;
+    use crate::state::{AppState, LOADING_ANIM_CYCLE_TICKS};
+    use crate::theme;
+    use crate::wizard::WizardStep;
+
+    /// Check invariants that hold for every reachable state.
+    pub fn check(state: &AppState) -> Result<(), String> {
+        if state.search_results.is_empty() {
+            if state.search_selected != 0 {
+                return Err(format!(
+                    "search_selected {} with no results",
+                    state.search_selected
+                ));
+            }
+        } else if state.search_selected >= state.search_results.len() {
+            return Err(format!(
+                "search_selected {} out of bounds for {} results",
+                state.search_selected,
+                state.search_results.len()
+            ));
+        }
+
+        if !state.search_mode
+            && (!state.search_query.is_empty()
+                || !state.search_results.is_empty()
+                || state.search_error.is_some())
+        {
+            return Err("closed search overlay still holds search state".into());
+        }
+
+        let city_step = state
+            .wizard
+            .as_ref()
+            .map(|wizard| wizard.step == WizardStep::City);
+        if city_step.is_some_and(|city_step| city_step != state.search_mode) {
+            return Err("search overlay open outside the wizard's city step".into());
+        }
+
+        if state.is_refreshing && !state.weather.is_loaded() {
+            return Err("refreshing without previously loaded data".into());
+        }
+
+        if state.provisional.is_some() && !state.is_refreshing {
+            return Err("provisional data with no fetch to replace it".into());
+        }
+
+        if state.loading_anim_ticks_remaining > LOADING_ANIM_CYCLE_TICKS.max(1) {
+            return Err(format!(
+                "loading_anim_ticks_remaining {} exceeds one cycle",
+                state.loading_anim_ticks_remaining
+            ));
+        }
+
+        let open_panels = [state.show_commute, state.show_garden, state.show_ensemble]
+            .into_iter()
+            .filter(|&open| open)
+            .count();
+        if open_panels > 1 {
+            return Err(format!("{open_panels} planner panels open at once"));
+        }
+
+        if state.accent.fade_remaining > theme::ACCENT_FADE_TICKS {
+            return Err(format!(
+                "accent fade_remaining {} exceeds one fade",
+                state.accent.fade_remaining
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// Check per-action postconditions, then the general invariants.
+    pub fn check_transition(
+        before: &AppState,
+        action: &Action,
+        after: &AppState,
+    ) -> Result<(), String> {
+        let violation = match action {
+            Action::WeatherDidLoad(data)
+                if after.weather.data() != Some(data)
+                    || after.is_refreshing
+                    || after.hourly.is_empty() =>
+            {
+                Some("WeatherDidLoad must store data, clear refreshing, and request hourly data")
+            }
+            Action::WeatherDidError(_) if !after.weather.is_failed() || after.is_refreshing => {
+                Some("WeatherDidError must fail and clear refreshing")
+            }
+            Action::WeatherFetch if after.wizard.is_none() && !after.loading_anim_active() => {
+                Some("WeatherFetch must start the loading animation")
+            }
+            Action::RefreshTick if after.refresh_ticks >= after.refresh_stretch() => {
+                Some("RefreshTick must fetch once the stretched interval has passed")
+            }
+            Action::SearchClose if after.search_mode => {
+                Some("SearchClose must close the search overlay")
+            }
+            Action::SearchConfirm
+                if before.search_mode
+                    && before.wizard.is_none()
+                    && before.search_selected < before.search_results.len()
+                    && (after.search_mode
+                        || !(after.weather.is_loading() || after.is_refreshing)) =>
+            {
+                Some("SearchConfirm must close search and load the new city")
+            }
+            Action::SearchConfirm
+                if before.search_mode
+                    && before.wizard.is_some()
+                    && before.search_selected < before.search_results.len()
+                    && after.wizard.as_ref().map(|wizard| wizard.step)
+                        != Some(WizardStep::Units) =>
+            {
+                Some("SearchConfirm in the wizard must move on to the units step")
+            }
+            Action::WizardDidSave
+                if before.wizard.is_some()
+                    && (after.wizard.is_some() || !after.weather.is_loading()) =>
+            {
+                Some("WizardDidSave must close the wizard and load the saved city")
+            }
+            Action::UiToggleUnits if after.unit != before.unit.toggle() => {
+                Some("UiToggleUnits must flip the unit")
+            }
+            Action::UiCycleLanguage if after.language != before.language.next() => {
+                Some("UiCycleLanguage must advance the language")
+            }
+            Action::UiToggleCommute
+                if after.show_commute == before.show_commute
+                    || (after.show_commute && after.hourly.is_empty()) =>
+            {
+                Some("UiToggleCommute must flip the panel and request hourly data")
+            }
+            Action::UiToggleGarden
+                if after.show_garden == before.show_garden
+                    || (after.show_garden && after.archive.is_empty()) =>
+            {
+                Some("UiToggleGarden must flip the panel and request archive data")
+            }
+            Action::UiToggleEnsemble
+                if after.show_ensemble == before.show_ensemble
+                    || (after.show_ensemble && after.ensemble.is_empty()) =>
+            {
+                Some("UiToggleEnsemble must flip the panel and request ensemble data")
+            }
+            _ => None,
+        };
+        if let Some(message) = violation {
+            return Err(message.into());
+        }
+        check(after)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::state::WeatherData;
+    use crate::state::{TempUnit, WeatherData};
 
     #[test]
     fn test_weather_fetch_sets_loading() {
@@ -214,6 +687,22 @@ mod tests {
         assert_eq!(state.unit, crate::state::TempUnit::Celsius);
     }
 
+    #[test]
+    fn test_late_search_results_ignored_when_closed() {
+        let mut state = AppState::default();
+        let late = vec![crate::state::Location {
+            name: "Lviv, Ukraine".into(),
+            lat: 49.84,
+            lon: 24.03,
+        }];
+
+        let result = reducer(&mut state, Action::SearchDidLoad(late));
+
+        assert!(!result.changed);
+        assert!(state.search_results.is_empty());
+        assert_eq!(invariants::check(&state), Ok(()));
+    }
+
     #[test]
     fn test_tick_rerenders_during_loading_animation() {
         let mut state = AppState::default();
@@ -234,4 +723,304 @@ mod tests {
         let result = reducer(&mut state, Action::Tick);
         assert!(result.changed);
     }
+
+    #[test]
+    fn test_weather_load_fades_accent_toward_condition() {
+        let mut state = AppState::default();
+        let rain = WeatherData {
+            temperature: 12.0,
+            weather_code: 63,
+            description: "Rain".into(),
+        };
+
+        reducer(&mut state, Action::WeatherDidLoad(rain));
+        assert_eq!(state.accent.fade_remaining, theme::ACCENT_FADE_TICKS);
+        assert_eq!(state.accent.current(), theme::NEUTRAL_ACCENT);
+
+        // Ticks keep re-rendering until the fade lands, then go quiet again
+        state.loading_anim_ticks_remaining = 0;
+        state.particles.enabled = false;
+        for _ in 0..theme::ACCENT_FADE_TICKS {
+            assert!(reducer(&mut state, Action::Tick).changed);
+        }
+        assert!(!reducer(&mut state, Action::Tick).changed);
+        assert_eq!(
+            state.accent.current(),
+            theme::condition_accent(WeatherCondition::Rain)
+        );
+    }
+
+    #[test]
+    fn test_tick_drives_particles_for_precipitation() {
+        let mut state = AppState {
+            weather: DataResource::Loaded(WeatherData {
+                temperature: 2.0,
+                weather_code: 73,
+                description: "Snow".into(),
+            }),
+            ..Default::default()
+        };
+
+        // Snow moves every tenth tick and only those ticks re-render
+        let moved = (0..20)
+            .filter(|_| reducer(&mut state, Action::Tick).changed)
+            .count();
+        assert_eq!(moved, 2);
+        assert_eq!(state.particles.tick, 20);
+
+        // A planner panel hides the layer and the ticks go quiet
+        state.show_garden = true;
+        assert!(state.particle_layer().is_none());
+        for _ in 0..20 {
+            assert!(!reducer(&mut state, Action::Tick).changed);
+        }
+        assert_eq!(state.particles.tick, 20);
+    }
+
+    #[test]
+    fn test_weather_load_requests_hourly() {
+        let mut state = AppState::default();
+        let result = reducer(&mut state, Action::WeatherFetch);
+        assert!(matches!(result.effects[..], [Effect::FetchWeather { .. }]));
+
+        let result = reducer(&mut state, Action::WeatherDidLoad(WeatherData::default()));
+        assert!(state.hourly.is_loading());
+        assert!(matches!(result.effects[..], [Effect::FetchHourly { .. }]));
+
+        // A refresh keeps the previous samples on screen
+        reducer(&mut state, Action::HourlyDidLoad(Vec::new()));
+        reducer(&mut state, Action::WeatherDidLoad(WeatherData::default()));
+        assert!(state.hourly.is_loaded());
+    }
+
+    #[test]
+    fn test_refresh_interval_stretches_near_quota() {
+        let mut state = AppState {
+            daily_limit: 1_000,
+            ..Default::default()
+        };
+        let result = reducer(&mut state, Action::RefreshTick);
+        assert!(matches!(result.effects[..], [Effect::FetchWeather { .. }]));
+
+        let usage = crate::quota::CallUsage {
+            last_hour: 0,
+            last_day: 1_000,
+        };
+        reducer(&mut state, Action::QuotaDidUpdate(usage));
+        let stretch = state.refresh_stretch();
+        assert_eq!(stretch, crate::quota::MAX_STRETCH);
+        for _ in 1..stretch {
+            let result = reducer(&mut state, Action::RefreshTick);
+            assert!(result.effects.is_empty(), "skipped while over quota");
+        }
+        let result = reducer(&mut state, Action::RefreshTick);
+        assert!(matches!(result.effects[..], [Effect::FetchWeather { .. }]));
+
+        // Unchanged usage does not re-render
+        let result = reducer(&mut state, Action::QuotaDidUpdate(usage));
+        assert!(!result.changed);
+    }
+
+    #[test]
+    fn test_commute_panel_reuses_hourly() {
+        let mut state = AppState::default();
+        let result = reducer(&mut state, Action::UiToggleCommute);
+        assert!(state.show_commute);
+        assert!(state.hourly.is_loading());
+        assert!(matches!(result.effects[..], [Effect::FetchHourly { .. }]));
+
+        reducer(&mut state, Action::HourlyDidLoad(Vec::new()));
+        reducer(&mut state, Action::UiToggleCommute);
+        let result = reducer(&mut state, Action::UiToggleCommute);
+        assert!(result.effects.is_empty(), "loaded data is reused");
+    }
+
+    #[test]
+    fn test_garden_panel_fetches_archive_per_city() {
+        let mut state = AppState {
+            show_commute: true,
+            ..Default::default()
+        };
+        let result = reducer(&mut state, Action::UiToggleGarden);
+        assert!(state.show_garden && !state.show_commute);
+        assert!(state.archive.is_loading());
+        assert!(matches!(result.effects[..], [Effect::FetchArchive { .. }]));
+
+        reducer(&mut state, Action::ArchiveDidLoad(Vec::new()));
+        reducer(&mut state, Action::UiToggleGarden);
+        let result = reducer(&mut state, Action::UiToggleGarden);
+        assert!(result.effects.is_empty(), "loaded archive is reused");
+
+        // Switching city while the panel is open refetches history
+        reducer(&mut state, Action::SearchOpen);
+        reducer(
+            &mut state,
+            Action::SearchDidLoad(vec![crate::state::Location {
+                name: "Hobart, Australia".into(),
+                lat: -42.88,
+                lon: 147.33,
+            }]),
+        );
+        let result = reducer(&mut state, Action::SearchConfirm);
+        assert!(state.archive.is_loading());
+        assert!(matches!(
+            result.effects[..],
+            [Effect::FetchWeather { .. }, Effect::FetchArchive { .. }]
+        ));
+    }
+
+    #[test]
+    fn test_search_confirm_shows_cached_city_while_refetching() {
+        use crate::cache::Provisional;
+        use crate::state::{HourlySample, Location};
+
+        let kyiv = AppState::default().location;
+        let brovary = Location {
+            name: "Brovary, Ukraine".into(),
+            lat: 50.51,
+            lon: 30.79,
+        };
+        let pick = |state: &mut AppState, location: &Location| {
+            reducer(state, Action::SearchOpen);
+            reducer(state, Action::SearchDidLoad(vec![location.clone()]));
+            reducer(state, Action::SearchConfirm)
+        };
+
+        let mut state = AppState::default();
+        reducer(&mut state, Action::WeatherFetch);
+        let snow = WeatherData {
+            temperature: -4.0,
+            weather_code: 73,
+            description: "Snow".into(),
+        };
+        reducer(&mut state, Action::WeatherDidLoad(snow.clone()));
+        reducer(
+            &mut state,
+            Action::HourlyDidLoad(vec![HourlySample::default()]),
+        );
+
+        // Nearby city: Kyiv's data stands in, marked as an estimate
+        let result = pick(&mut state, &brovary);
+        assert!(matches!(result.effects[..], [Effect::FetchWeather { .. }]));
+        assert_eq!(state.weather.data(), Some(&snow));
+        assert!(state.is_refreshing && state.hourly.is_loaded());
+        assert_eq!(
+            state.provisional,
+            Some(Provisional::Estimated {
+                from: "Kyiv, Ukraine".into()
+            })
+        );
+        reducer(&mut state, Action::WeatherDidLoad(WeatherData::default()));
+        assert_eq!(state.provisional, None);
+
+        // Back to a city fetched before: its own data, no blank screen
+        pick(&mut state, &kyiv);
+        assert_eq!(state.weather.data(), Some(&snow));
+        assert_eq!(state.provisional, Some(Provisional::Cached));
+        reducer(&mut state, Action::WeatherDidError("timeout".into()));
+        assert!(state.weather.is_failed() && state.provisional.is_none());
+
+        // Far from anything cached: loads from scratch
+        pick(
+            &mut state,
+            &Location {
+                name: "Hobart, Australia".into(),
+                lat: -42.88,
+                lon: 147.33,
+            },
+        );
+        assert!(state.weather.is_loading() && !state.is_refreshing);
+        assert!(state.hourly.is_empty() && state.provisional.is_none());
+    }
+
+    #[test]
+    fn test_ensemble_panel_closes_other_panels() {
+        let mut state = AppState {
+            show_garden: true,
+            ..Default::default()
+        };
+        let result = reducer(&mut state, Action::UiToggleEnsemble);
+        assert!(state.show_ensemble && !state.show_garden);
+        assert!(state.ensemble.is_loading());
+        assert!(matches!(result.effects[..], [Effect::FetchEnsemble { .. }]));
+
+        reducer(&mut state, Action::EnsembleDidLoad(Vec::new()));
+        reducer(&mut state, Action::UiToggleCommute);
+        assert!(state.show_commute && !state.show_ensemble);
+        let result = reducer(&mut state, Action::UiToggleEnsemble);
+        assert!(result.effects.is_empty(), "loaded ensemble is reused");
+
+        // A failed fetch is retried on the next open
+        reducer(&mut state, Action::EnsembleDidError("timeout".into()));
+        reducer(&mut state, Action::UiToggleEnsemble);
+        let result = reducer(&mut state, Action::UiToggleEnsemble);
+        assert!(matches!(result.effects[..], [Effect::FetchEnsemble { .. }]));
+        assert_eq!(invariants::check(&state), Ok(()));
+    }
+
+    #[test]
+    fn test_wizard_walks_city_units_refresh_then_fetches() {
+        let mut state = AppState::default();
+        crate::wizard::open(&mut state);
+        assert!(reducer(&mut state, Action::WeatherFetch).effects.is_empty());
+        assert!(!reducer(&mut state, Action::SearchClose).changed);
+
+        let lisbon = crate::state::Location {
+            name: "Lisbon, Portugal".into(),
+            lat: 38.72,
+            lon: -9.14,
+        };
+        reducer(&mut state, Action::SearchDidLoad(vec![lisbon.clone()]));
+        let result = reducer(&mut state, Action::SearchConfirm);
+        assert!(result.effects.is_empty());
+        assert!(!state.search_mode);
+        assert_eq!(state.wizard.as_ref().unwrap().step, WizardStep::Units);
+
+        reducer(&mut state, Action::WizardToggleUnit);
+        reducer(&mut state, Action::WizardNext);
+        let result = reducer(&mut state, Action::WizardRefreshSubmit("0".into()));
+        assert!(result.effects.is_empty());
+        assert!(state.wizard.as_ref().unwrap().error.is_some());
+
+        let result = reducer(&mut state, Action::WizardRefreshSubmit("90".into()));
+        let [Effect::SaveConfig { config }] = &result.effects[..] else {
+            panic!("expected SaveConfig, got {:?}", result.effects);
+        };
+        assert_eq!(config.city, lisbon);
+        assert_eq!(config.unit, TempUnit::Fahrenheit);
+        assert_eq!(config.refresh_interval, 90);
+
+        let result = reducer(&mut state, Action::WizardDidSave);
+        assert!(state.wizard.is_none());
+        assert_eq!(state.location, lisbon);
+        assert_eq!(state.unit, TempUnit::Fahrenheit);
+        assert!(state.weather.is_loading());
+        assert!(matches!(result.effects[..], [Effect::FetchWeather { .. }]));
+        assert_eq!(invariants::check(&state), Ok(()));
+    }
+
+    #[test]
+    fn test_wizard_back_reopens_city_search_and_keeps_save_errors() {
+        let mut state = AppState::default();
+        crate::wizard::open(&mut state);
+        let wizard = state.wizard.as_mut().unwrap();
+        wizard.step = WizardStep::Refresh;
+        wizard.city = Some(state.location.clone());
+        state.search_mode = false;
+
+        reducer(&mut state, Action::WizardRefreshSubmit("60".into()));
+        reducer(
+            &mut state,
+            Action::WizardDidError("read-only file system".into()),
+        );
+        let wizard = state.wizard.as_ref().unwrap();
+        assert!(!wizard.saving);
+        assert!(wizard.error.as_deref().unwrap().contains("read-only"));
+
+        reducer(&mut state, Action::WizardBack);
+        reducer(&mut state, Action::WizardBack);
+        assert_eq!(state.wizard.as_ref().unwrap().step, WizardStep::City);
+        assert!(state.search_mode);
+        assert_eq!(invariants::check(&state), Ok(()));
+    }
 }
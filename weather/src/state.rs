@@ -4,6 +4,16 @@ use schemars::JsonSchema;
 use serde::{Deserialize, Serialize};
 use tui_dispatch::DataResource;
 
+use crate::cache::{Provisional, WeatherCache};
+use crate::commute::CommuteConfig;
+use crate::garden::GardenConfig;
+use crate::i18n::Language;
+use crate::particles::{ParticleField, ParticleLayer};
+use crate::pressure::PressureConfig;
+use crate::quota::{CallUsage, DEFAULT_DAILY_LIMIT};
+use crate::theme::AccentTheme;
+use crate::wizard::Wizard;
+
 /// Weather data from Open-Meteo API
 #[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct WeatherData {
@@ -12,6 +22,41 @@ pub struct WeatherData {
     pub description: String,
 }
 
+/// One hour of the hourly forecast, in the location's local time
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct HourlySample {
+    /// Local timestamp, `YYYY-MM-DDTHH:MM`
+    pub time: String,
+    pub temperature: f32,
+    /// Chance of precipitation, 0-100
+    pub precipitation_probability: u8,
+    pub weather_code: u8,
+    /// Wind speed in km/h
+    pub wind_speed: f32,
+    /// Sea-level pressure in hPa, when the forecast has it
+    #[serde(default)]
+    pub pressure: Option<f32>,
+}
+
+/// One day of observed temperatures from the weather archive
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct DailySample {
+    /// Local date, `YYYY-MM-DD`
+    pub date: String,
+    pub temp_min: f32,
+    pub temp_max: f32,
+}
+
+/// One hour of the ensemble forecast, reduced to the spread across members
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct EnsembleBand {
+    /// Local timestamp, `YYYY-MM-DDTHH:MM`
+    pub time: String,
+    pub min: f32,
+    pub median: f32,
+    pub max: f32,
+}
+
 /// A geographic location
 #[derive(Clone, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
 pub struct Location {
@@ -36,6 +81,14 @@ impl TempUnit {
         }
     }
 
+    /// A Celsius reading in this unit
+    pub fn convert(&self, celsius: f32) -> f32 {
+        match self {
+            TempUnit::Celsius => celsius,
+            TempUnit::Fahrenheit => celsius * 9.0 / 5.0 + 32.0,
+        }
+    }
+
     pub fn format(&self, celsius: f32) -> String {
         match self {
             TempUnit::Celsius => format!("{:.1}°C", celsius),
@@ -65,10 +118,83 @@ pub struct AppState {
     #[debug(section = "Weather", label = "Refreshing")]
     pub is_refreshing: bool,
 
+    /// Set while a newly picked city shows cached or nearby data ahead of its fetch
+    #[debug(section = "Weather", label = "Provisional", debug_fmt)]
+    pub provisional: Option<Provisional>,
+
+    /// Last data seen for recently fetched cities
+    #[debug(skip)]
+    pub weather_cache: WeatherCache,
+
     /// Temperature unit preference
     #[debug(section = "Weather", label = "Unit", debug_fmt)]
     pub unit: TempUnit,
 
+    /// Display language for descriptions and UI chrome
+    #[debug(section = "Weather", label = "Language", debug_fmt)]
+    pub language: Language,
+
+    /// Accent color following the current condition
+    #[debug(section = "Weather", label = "Accent", debug_fmt)]
+    pub accent: AccentTheme,
+
+    /// Rain, snow, or cloud particles behind the weather body
+    #[debug(section = "Weather", label = "Particles", debug_fmt)]
+    pub particles: ParticleField,
+
+    // --- Commute planner ---
+    /// Time windows compared by the planner
+    #[debug(section = "Commute", label = "Windows", debug_fmt)]
+    pub commute: CommuteConfig,
+
+    /// Whether the planner panel replaces the main view
+    #[debug(section = "Commute", label = "Open")]
+    pub show_commute: bool,
+
+    /// Hourly forecast, fetched while the planner is open
+    #[debug(skip)]
+    pub hourly: DataResource<Vec<HourlySample>>,
+
+    // --- Garden planner ---
+    /// Growing degree day base temperature
+    #[debug(section = "Garden", label = "Config", debug_fmt)]
+    pub garden: GardenConfig,
+
+    /// Whether the garden panel replaces the main view
+    #[debug(section = "Garden", label = "Open")]
+    pub show_garden: bool,
+
+    /// Daily archive temperatures, fetched while the garden panel is open
+    #[debug(skip)]
+    pub archive: DataResource<Vec<DailySample>>,
+
+    // --- Pressure advisory ---
+    /// Opt-in thresholds for the pressure swing badge
+    #[debug(section = "Pressure", label = "Config", debug_fmt)]
+    pub pressure: PressureConfig,
+
+    // --- Ensemble spread ---
+    /// Whether the forecast spread panel replaces the main view
+    #[debug(section = "Ensemble", label = "Open")]
+    pub show_ensemble: bool,
+
+    /// Hourly min/median/max across ensemble members, fetched while the panel is open
+    #[debug(skip)]
+    pub ensemble: DataResource<Vec<EnsembleBand>>,
+
+    // --- API quota ---
+    /// Requests sent over the trailing hour and day, polled from the api layer
+    #[debug(section = "Quota", label = "Usage", debug_fmt)]
+    pub api_usage: CallUsage,
+
+    /// Soft daily request limit; auto-refresh slows down as usage nears it
+    #[debug(section = "Quota", label = "Daily limit")]
+    pub daily_limit: u32,
+
+    /// Refresh timer ticks since the last weather fetch
+    #[debug(skip)]
+    pub refresh_ticks: u32,
+
     // --- Animation internals (skipped) ---
     /// Animation frame counter (for gradient seam)
     #[debug(skip)]
@@ -98,6 +224,11 @@ pub struct AppState {
     /// Selected index in search results
     #[debug(skip)]
     pub search_selected: usize,
+
+    // --- First-run setup ---
+    /// Setup wizard, open until the first config is saved
+    #[debug(section = "Setup", label = "Wizard", debug_fmt)]
+    pub wizard: Option<Wizard>,
 }
 
 impl AppState {
@@ -107,7 +238,24 @@ impl AppState {
             location,
             weather: DataResource::Empty,
             is_refreshing: false,
+            provisional: None,
+            weather_cache: WeatherCache::default(),
             unit: TempUnit::default(),
+            language: Language::default(),
+            accent: AccentTheme::default(),
+            particles: ParticleField::default(),
+            commute: CommuteConfig::default(),
+            show_commute: false,
+            hourly: DataResource::Empty,
+            garden: GardenConfig::default(),
+            show_garden: false,
+            archive: DataResource::Empty,
+            pressure: PressureConfig::default(),
+            show_ensemble: false,
+            ensemble: DataResource::Empty,
+            api_usage: CallUsage::default(),
+            daily_limit: DEFAULT_DAILY_LIMIT,
+            refresh_ticks: 0,
             tick_count: 0,
             loading_anim_ticks_remaining: 0,
             search_mode: false,
@@ -115,6 +263,7 @@ impl AppState {
             search_results: Vec::new(),
             search_error: None,
             search_selected: 0,
+            wizard: None,
         }
     }
 
@@ -123,9 +272,28 @@ impl AppState {
         &self.location
     }
 
+    /// Whether the current location is south of the equator (seasons flip)
+    pub fn southern_hemisphere(&self) -> bool {
+        self.location.lat < 0.0
+    }
+
+    /// How many refresh timer ticks each auto-refresh currently waits
+    pub fn refresh_stretch(&self) -> u32 {
+        self.api_usage.stretch(self.daily_limit)
+    }
+
     pub fn loading_anim_active(&self) -> bool {
         self.weather.is_loading() || self.is_refreshing || self.loading_anim_ticks_remaining > 0
     }
+
+    /// The particle layer behind the weather body, if the condition calls for
+    /// one. Planner panels are dense tables, so they never get one.
+    pub fn particle_layer(&self) -> Option<ParticleLayer> {
+        if !self.particles.enabled || self.show_commute || self.show_garden || self.show_ensemble {
+            return None;
+        }
+        ParticleLayer::for_code(self.weather.data()?.weather_code)
+    }
 }
 
 impl Default for AppState {
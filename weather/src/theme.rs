@@ -0,0 +1,138 @@
+//! Accent color derived from the current weather condition
+//!
+//! Rain tints the chrome blue, sun yellow, overcast gray. When a refresh brings
+//! a different condition the accent fades over to it on the animation tick
+//! instead of snapping. With the accent turned off everything stays neutral.
+
+use ratatui::style::Color;
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use tui_theme::Palette;
+
+use crate::sprites::WeatherCondition;
+
+pub use tui_theme::Rgb;
+
+/// Animation ticks a fade between two accents takes (~0.6s at 15ms per tick).
+pub const ACCENT_FADE_TICKS: u32 = 40;
+
+/// Accent used before any weather has loaded, or when theming is off.
+pub const NEUTRAL_ACCENT: Rgb = Palette::SKY.accent;
+
+/// The accent a condition paints the UI with.
+pub fn condition_accent(condition: WeatherCondition) -> Rgb {
+    match condition {
+        WeatherCondition::ClearSky => Rgb::new(250, 210, 80),
+        WeatherCondition::PartlyCloudy => Rgb::new(225, 200, 130),
+        WeatherCondition::Cloudy => Rgb::new(150, 155, 165),
+        WeatherCondition::Fog => Rgb::new(170, 178, 185),
+        WeatherCondition::Drizzle => Rgb::new(120, 170, 230),
+        WeatherCondition::Rain => Rgb::new(80, 140, 245),
+        WeatherCondition::Snow => Rgb::new(200, 225, 250),
+        WeatherCondition::Thunderstorm => Rgb::new(170, 130, 240),
+        WeatherCondition::Unknown => NEUTRAL_ACCENT,
+    }
+}
+
+/// Accent fade state: the color faded from, the one faded to, and how far along.
+#[derive(Clone, Copy, Debug, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct AccentTheme {
+    /// Off pins the accent to `NEUTRAL_ACCENT`
+    pub enabled: bool,
+    pub from: Rgb,
+    pub to: Rgb,
+    /// Ticks left before `to` is fully shown
+    pub fade_remaining: u32,
+}
+
+impl Default for AccentTheme {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            from: NEUTRAL_ACCENT,
+            to: NEUTRAL_ACCENT,
+            fade_remaining: 0,
+        }
+    }
+}
+
+impl AccentTheme {
+    /// The accent as of this tick.
+    pub fn current(&self) -> Rgb {
+        if !self.enabled {
+            return NEUTRAL_ACCENT;
+        }
+        let t = 1.0 - self.fade_remaining as f32 / ACCENT_FADE_TICKS as f32;
+        self.from.lerp(self.to, t)
+    }
+
+    pub fn color(&self) -> Color {
+        self.current().color()
+    }
+
+    /// Start fading toward `target` from wherever the accent is right now.
+    pub fn retarget(&mut self, target: Rgb) {
+        if !self.enabled || target == self.to {
+            return;
+        }
+        self.from = self.current();
+        self.to = target;
+        self.fade_remaining = ACCENT_FADE_TICKS;
+    }
+
+    /// Advance the fade by one tick. Returns whether the accent changed.
+    pub fn step(&mut self) -> bool {
+        if self.fade_remaining == 0 {
+            return false;
+        }
+        self.fade_remaining -= 1;
+        true
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fade_reaches_target_and_stops() {
+        let mut accent = AccentTheme::default();
+        let rain = condition_accent(WeatherCondition::Rain);
+        accent.retarget(rain);
+        assert_eq!(accent.current(), NEUTRAL_ACCENT);
+
+        for _ in 0..ACCENT_FADE_TICKS / 2 {
+            assert!(accent.step());
+        }
+        let halfway = accent.current();
+        assert_ne!(halfway, NEUTRAL_ACCENT);
+        assert_ne!(halfway, rain);
+
+        while accent.step() {}
+        assert_eq!(accent.current(), rain);
+    }
+
+    #[test]
+    fn test_retarget_mid_fade_starts_from_current_color() {
+        let mut accent = AccentTheme::default();
+        accent.retarget(condition_accent(WeatherCondition::Rain));
+        for _ in 0..10 {
+            accent.step();
+        }
+        let before = accent.current();
+        accent.retarget(condition_accent(WeatherCondition::ClearSky));
+        assert_eq!(accent.current(), before);
+        assert_eq!(accent.fade_remaining, ACCENT_FADE_TICKS);
+    }
+
+    #[test]
+    fn test_disabled_accent_stays_neutral() {
+        let mut accent = AccentTheme {
+            enabled: false,
+            ..Default::default()
+        };
+        accent.retarget(condition_accent(WeatherCondition::Rain));
+        assert!(!accent.step());
+        assert_eq!(accent.current(), NEUTRAL_ACCENT);
+    }
+}
@@ -0,0 +1,66 @@
+//! First-run setup wizard
+//!
+//! Shown when there is no config file yet: it asks for a default city, a
+//! temperature unit and a refresh interval, one step at a time, and the
+//! weather view only starts once the answers are saved. The city step is
+//! the ordinary city search with `search_mode` on; the refresh step reuses
+//! the same overlay as a plain text field.
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+
+use crate::config::Config;
+use crate::state::{AppState, Location, TempUnit};
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Serialize, Deserialize, JsonSchema)]
+pub enum WizardStep {
+    #[default]
+    City,
+    Units,
+    Refresh,
+}
+
+impl WizardStep {
+    pub const COUNT: usize = 3;
+
+    /// 1-based position, for the "1/3" in the banner
+    pub fn number(self) -> usize {
+        match self {
+            WizardStep::City => 1,
+            WizardStep::Units => 2,
+            WizardStep::Refresh => 3,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize, JsonSchema)]
+pub struct Wizard {
+    pub step: WizardStep,
+    /// City picked on the first step
+    pub city: Option<Location>,
+    pub unit: TempUnit,
+    /// Refresh interval as typed, parsed when the step is submitted; blank
+    /// keeps the default
+    pub refresh_input: String,
+    /// Why the last submit didn't go through
+    pub error: Option<String>,
+    /// Whether the config is being written
+    pub saving: bool,
+}
+
+impl Wizard {
+    /// What to save once every step is answered
+    pub fn config(&self, refresh_interval: u64) -> Option<Config> {
+        Some(Config {
+            city: self.city.clone()?,
+            unit: self.unit,
+            refresh_interval,
+        })
+    }
+}
+
+/// Opens the wizard on its city step
+pub fn open(state: &mut AppState) {
+    state.wizard = Some(Wizard::default());
+    state.search_mode = true;
+}
@@ -65,7 +65,7 @@ fn test_reducer_toggle_units() {
 fn test_component_keyboard_events() {
     // PATTERN: TestHarness for component testing
     let mut harness = TestHarness::<AppState, Action>::default();
-    let mut component = WeatherDisplay;
+    let mut component = WeatherDisplay::default();
 
     // PATTERN: send_keys helper - parse key strings, call handler
     // NumericComponentId is a simple built-in ComponentId type
@@ -88,7 +88,7 @@ fn test_component_keyboard_events() {
 #[test]
 fn test_component_ignores_when_unfocused() {
     let mut harness = TestHarness::<AppState, Action>::default();
-    let mut component = WeatherDisplay;
+    let mut component = WeatherDisplay::default();
 
     // When not focused, events should be ignored
     let actions = harness.send_keys::<NumericComponentId, _, _>("r q u", |state, event| {
@@ -112,7 +112,7 @@ fn test_dispatch_all() {
 #[test]
 fn test_keyboard_triggers_fetch() {
     let mut harness = EffectStoreTestHarness::new(AppState::default(), reducer);
-    let mut component = WeatherDisplay;
+    let mut component = WeatherDisplay::default();
 
     // Send 'r' key through component, get actions
     let actions = harness.send_keys::<NumericComponentId, _, _>("r", |state, event| {
@@ -141,7 +141,7 @@ fn test_keyboard_triggers_fetch() {
 #[test]
 fn test_keyboard_toggle_units() {
     let mut harness = EffectStoreTestHarness::new(state_with_weather(), reducer);
-    let mut component = WeatherDisplay;
+    let mut component = WeatherDisplay::default();
 
     harness.assert_state(|s| s.unit == TempUnit::Celsius);
 
@@ -172,7 +172,7 @@ fn test_keyboard_toggle_units() {
 #[test]
 fn test_render_loading_state() {
     let mut harness = EffectStoreTestHarness::new(AppState::default(), reducer);
-    let mut component = WeatherDisplay;
+    let mut component = WeatherDisplay::default();
 
     // Trigger loading
     harness.dispatch_collect(Action::WeatherFetch);
@@ -196,7 +196,7 @@ fn test_render_loading_state() {
 #[test]
 fn test_render_weather_data() {
     let mut harness = EffectStoreTestHarness::new(state_with_weather(), reducer);
-    let mut component = WeatherDisplay;
+    let mut component = WeatherDisplay::default();
 
     let output = harness.render_plain(60, 20, |frame, area, state| {
         let props = WeatherDisplayProps {
@@ -217,7 +217,7 @@ fn test_render_weather_data() {
 #[test]
 fn test_render_unit_toggle_changes_display() {
     let mut harness = EffectStoreTestHarness::new(state_with_weather(), reducer);
-    let mut component = WeatherDisplay;
+    let mut component = WeatherDisplay::default();
 
     // Render in Celsius
     let celsius_output = harness.render_plain(60, 20, |frame, area, state| {
@@ -0,0 +1,131 @@
+//! Property-based reducer tests
+//!
+//! FRAMEWORK PATTERN: Reducer invariants
+//! - Generate random action sequences, including out-of-order async results
+//! - Feed them through the reducer one by one
+//! - Check `reducer::invariants` after every step
+
+use proptest::prelude::*;
+use weather::{
+    action::Action,
+    quota::CallUsage,
+    reducer::{invariants, reducer},
+    state::{AppState, DailySample, EnsembleBand, HourlySample, Location, WeatherData},
+};
+
+fn location() -> impl Strategy<Value = Location> {
+    ("[A-Za-z ]{1,12}", -90.0f64..90.0, -180.0f64..180.0).prop_map(|(name, lat, lon)| Location {
+        name,
+        lat,
+        lon,
+    })
+}
+
+fn weather_data() -> impl Strategy<Value = WeatherData> {
+    (
+        -40.0f32..45.0,
+        prop::sample::select(vec![0u8, 2, 45, 61, 71, 95]),
+    )
+        .prop_map(|(temperature, weather_code)| WeatherData {
+            temperature,
+            weather_code,
+            description: format!("code {weather_code}"),
+        })
+}
+
+fn hourly_sample() -> impl Strategy<Value = HourlySample> {
+    (0u8..24, -20.0f32..40.0, 0u8..=100).prop_map(|(hour, temperature, precip)| HourlySample {
+        time: format!("2024-06-03T{hour:02}:00"),
+        temperature,
+        precipitation_probability: precip,
+        weather_code: 0,
+        wind_speed: 10.0,
+        pressure: None,
+    })
+}
+
+fn daily_sample() -> impl Strategy<Value = DailySample> {
+    (1u8..=28, -20.0f32..15.0, 0.0f32..20.0).prop_map(|(day, temp_min, spread)| DailySample {
+        date: format!("2024-03-{day:02}"),
+        temp_min,
+        temp_max: temp_min + spread,
+    })
+}
+
+fn ensemble_band() -> impl Strategy<Value = EnsembleBand> {
+    (0u8..24, -20.0f32..30.0, 0.0f32..10.0).prop_map(|(hour, min, spread)| EnsembleBand {
+        time: format!("2024-06-03T{hour:02}:00"),
+        min,
+        median: min + spread / 2.0,
+        max: min + spread,
+    })
+}
+
+fn action() -> impl Strategy<Value = Action> {
+    prop_oneof![
+        Just(Action::WeatherFetch),
+        weather_data().prop_map(Action::WeatherDidLoad),
+        "[a-z ]{0,12}".prop_map(Action::WeatherDidError),
+        prop::collection::vec(hourly_sample(), 0..8).prop_map(Action::HourlyDidLoad),
+        "[a-z ]{0,12}".prop_map(Action::HourlyDidError),
+        prop::collection::vec(daily_sample(), 0..8).prop_map(Action::ArchiveDidLoad),
+        "[a-z ]{0,12}".prop_map(Action::ArchiveDidError),
+        prop::collection::vec(ensemble_band(), 0..8).prop_map(Action::EnsembleDidLoad),
+        "[a-z ]{0,12}".prop_map(Action::EnsembleDidError),
+        Just(Action::SearchOpen),
+        Just(Action::SearchClose),
+        "[a-z ]{0,8}".prop_map(Action::SearchQueryChange),
+        "[a-z ]{0,8}".prop_map(Action::SearchQuerySubmit),
+        prop::collection::vec(location(), 0..6).prop_map(Action::SearchDidLoad),
+        "[a-z ]{0,12}".prop_map(Action::SearchDidError),
+        (0usize..8).prop_map(Action::SearchSelect),
+        Just(Action::SearchConfirm),
+        Just(Action::UiToggleUnits),
+        Just(Action::UiCycleLanguage),
+        Just(Action::UiToggleCommute),
+        Just(Action::UiToggleGarden),
+        Just(Action::UiToggleEnsemble),
+        Just(Action::Render),
+        Just(Action::Tick),
+        Just(Action::RefreshTick),
+        (0u32..500, 0u32..12_000).prop_map(|(last_hour, last_day)| {
+            Action::QuotaDidUpdate(CallUsage {
+                last_hour,
+                last_day,
+            })
+        }),
+        Just(Action::Quit),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn prop_reducer_upholds_invariants(actions in prop::collection::vec(action(), 0..64)) {
+        let mut state = AppState::default();
+        prop_assert_eq!(invariants::check(&state), Ok(()));
+
+        for action in actions {
+            let before = state.clone();
+            reducer(&mut state, action.clone());
+            let checked = invariants::check_transition(&before, &action, &state);
+            prop_assert!(checked.is_ok(), "after {:?}: {:?}", action, checked);
+        }
+    }
+
+    #[test]
+    fn prop_loading_animation_settles(actions in prop::collection::vec(action(), 0..32)) {
+        let mut state = AppState::default();
+        for action in actions {
+            reducer(&mut state, action);
+        }
+        // Resolve any in-flight fetch, then let the seam finish its cycle
+        if state.weather.is_loading() || state.is_refreshing {
+            reducer(&mut state, Action::WeatherDidError("timeout".into()));
+        }
+        for _ in 0..=weather::state::LOADING_ANIM_CYCLE_TICKS {
+            reducer(&mut state, Action::Tick);
+        }
+        prop_assert!(!state.loading_anim_active());
+        prop_assert_eq!(invariants::check(&state), Ok(()));
+    }
+}
@@ -7,15 +7,16 @@
 
 use tui_dispatch::{DataResource, testing::*};
 use weather::{
+    cache::Provisional,
     components::{Component, WeatherDisplay, WeatherDisplayProps},
-    state::{AppState, Location, TempUnit, WeatherData},
+    state::{AppState, HourlySample, Location, TempUnit, WeatherData},
 };
 
 #[test]
 fn test_render_loading_state() {
     // PATTERN: RenderHarness for visual testing
     let mut render = RenderHarness::new(60, 24);
-    let mut component = WeatherDisplay;
+    let mut component = WeatherDisplay::default();
 
     let state = AppState {
         weather: DataResource::Loading,
@@ -36,10 +37,34 @@ fn test_render_loading_state() {
     assert!(!output.is_empty(), "Should render something");
 }
 
+#[test]
+fn test_render_loading_skeleton() {
+    let mut render = RenderHarness::new(60, 30);
+    let mut component = WeatherDisplay::default();
+
+    let state = AppState {
+        weather: DataResource::Loading,
+        tick_count: 12,
+        ..Default::default()
+    };
+
+    let output = render.render_to_string_plain(|frame| {
+        let props = WeatherDisplayProps {
+            state: &state,
+            is_focused: true,
+        };
+        component.render(frame, frame.area(), props);
+    });
+
+    // Skeleton panels sketch the temperature slot while data is in flight
+    assert!(output.contains('╭'), "Should draw panel outline:\n{output}");
+    assert!(output.contains("loading"), "Should label the skeleton");
+}
+
 #[test]
 fn test_render_clear_weather() {
     let mut render = RenderHarness::new(50, 20);
-    let mut component = WeatherDisplay;
+    let mut component = WeatherDisplay::default();
 
     let state = AppState {
         weather: DataResource::Loaded(WeatherData {
@@ -65,7 +90,7 @@ fn test_render_clear_weather() {
 #[test]
 fn test_render_error_state() {
     let mut render = RenderHarness::new(50, 20);
-    let mut component = WeatherDisplay;
+    let mut component = WeatherDisplay::default();
 
     let state = AppState {
         weather: DataResource::Failed("Network error".into()),
@@ -91,7 +116,7 @@ fn test_render_error_state() {
 #[test]
 fn test_render_fahrenheit() {
     let mut render = RenderHarness::new(50, 20);
-    let mut component = WeatherDisplay;
+    let mut component = WeatherDisplay::default();
 
     let state = AppState {
         weather: DataResource::Loaded(WeatherData {
@@ -119,7 +144,7 @@ fn test_render_fahrenheit() {
 #[test]
 fn test_render_custom_location() {
     let mut render = RenderHarness::new(50, 20);
-    let mut component = WeatherDisplay;
+    let mut component = WeatherDisplay::default();
 
     let custom = Location {
         name: "My Beach House".into(),
@@ -144,7 +169,7 @@ fn test_render_custom_location() {
 #[test]
 fn test_render_help_bar() {
     let mut render = RenderHarness::new(80, 24);
-    let mut component = WeatherDisplay;
+    let mut component = WeatherDisplay::default();
 
     let state = AppState::default();
 
@@ -165,7 +190,7 @@ fn test_render_help_bar() {
 #[test]
 fn test_render_initial_state() {
     let mut render = RenderHarness::new(50, 20);
-    let mut component = WeatherDisplay;
+    let mut component = WeatherDisplay::default();
 
     let state = AppState::default();
 
@@ -187,7 +212,7 @@ fn test_render_initial_state() {
 #[test]
 fn test_render_rain_weather() {
     let mut render = RenderHarness::new(50, 20);
-    let mut component = WeatherDisplay;
+    let mut component = WeatherDisplay::default();
 
     let state = AppState {
         weather: DataResource::Loaded(WeatherData {
@@ -209,3 +234,74 @@ fn test_render_rain_weather() {
     // Temperature is now rendered as FIGlet ASCII art
     assert!(output.contains("Rain"), "Should show rain description");
 }
+
+#[test]
+fn test_render_provisional_estimate() {
+    let mut render = RenderHarness::new(80, 24);
+    let mut component = WeatherDisplay::default();
+
+    let state = AppState {
+        weather: DataResource::Loaded(WeatherData {
+            temperature: 9.0,
+            weather_code: 0,
+            description: "Clear sky".into(),
+        }),
+        is_refreshing: true,
+        provisional: Some(Provisional::Estimated {
+            from: "Kyiv, Ukraine".into(),
+        }),
+        ..Default::default()
+    };
+
+    let output = render.render_to_string_plain(|frame| {
+        let props = WeatherDisplayProps {
+            state: &state,
+            is_focused: true,
+        };
+        component.render(frame, frame.area(), props);
+    });
+
+    // A nearby city's data stays up, labelled, while the real fetch runs
+    assert!(
+        output.contains("Clear sky"),
+        "Should keep the data:\n{output}"
+    );
+    assert!(output.contains("estimate from Kyiv, Ukraine"));
+    assert!(output.contains("updating…"));
+}
+
+#[test]
+fn test_render_precip_strip() {
+    let mut render = RenderHarness::new(60, 24);
+    let mut component = WeatherDisplay::default();
+
+    let hourly = (0..24)
+        .map(|hour| HourlySample {
+            time: format!("2024-06-03T{hour:02}:00"),
+            precipitation_probability: if hour == 5 { 100 } else { 0 },
+            ..Default::default()
+        })
+        .collect();
+    let state = AppState {
+        weather: DataResource::Loaded(WeatherData {
+            temperature: 15.0,
+            weather_code: 61,
+            description: "Rain".into(),
+        }),
+        hourly: DataResource::Loaded(hourly),
+        ..Default::default()
+    };
+
+    let output = render.render_to_string_plain(|frame| {
+        let props = WeatherDisplayProps {
+            state: &state,
+            is_focused: true,
+        };
+        component.render(frame, frame.area(), props);
+    });
+
+    assert!(output.contains("██"), "Should draw a full bar for 100%");
+    assert!(output.contains("00 01"), "Should label the hours");
+    assert!(output.contains("10 11"), "Should cover twelve hours");
+    assert!(!output.contains("11 12"), "Should stop after twelve hours");
+}